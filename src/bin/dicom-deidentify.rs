@@ -0,0 +1,88 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::process::ExitCode;
+use dicom_viewer::deidentification::deidentifier::{Deidentifier, TemporalInformation};
+use dicom_viewer::deidentification::uid_map::UidMap;
+use dicom_viewer::files_finder::files_finder::{FilesFinder, FindFiles};
+
+const USAGE: &str = "Usage: dicom-deidentify <input directory> <output directory> <UID map CSV> [--keep-dates | --shift-dates <days>]";
+
+/// De-identifies all DICOM files of a directory into another one, keeping the directory layout.
+/// The UID map is extended if it exists, so studies de-identified separately keep consistent UIDs.
+/// Files which cannot be de-identified are skipped, the exit code reporting the failure.
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+
+    let (input_directory, output_directory, uid_map_path, options) = match args.as_slice() {
+        [_, input, output, uid_map, options @ ..] => (input, output, uid_map, options),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let temporal_information = match options {
+        [] => TemporalInformation::Removed,
+        [option] if option == "--keep-dates" => TemporalInformation::RetainedFullDates,
+        [option, days] if option == "--shift-dates" => match days.parse() {
+            Ok(days) => TemporalInformation::RetainedModifiedDates(days),
+            Err(_) => {
+                eprintln!("Invalid number of days: {}", days);
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let uid_map = match File::open(uid_map_path) {
+        Ok(file) => UidMap::read_csv(BufReader::new(file)),
+        Err(_) => Ok(UidMap::new()),
+    };
+
+    let uid_map = match uid_map {
+        Ok(uid_map) => uid_map,
+        Err(error) => {
+            eprintln!("Cannot read UID map {}: {}", uid_map_path, error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut deidentifier = Deidentifier::new()
+        .temporal_information(temporal_information)
+        .uid_map(uid_map);
+
+    let mut failed = false;
+    for input in FilesFinder::new().find_files(input_directory) {
+        let relative = Path::new(&input).strip_prefix(input_directory).unwrap_or(Path::new(&input));
+        let output = Path::new(output_directory).join(relative);
+
+        if let Some(parent) = output.parent() {
+            if let Err(error) = std::fs::create_dir_all(parent) {
+                eprintln!("Cannot create {}: {}", parent.display(), error);
+                return ExitCode::FAILURE;
+            }
+        }
+
+        match deidentifier.deidentify_file(&input, &output.to_string_lossy()) {
+            Ok(()) => println!("De-identified {}", input),
+            Err(error) => {
+                eprintln!("Skipping {}: {}", input, error);
+                failed = true;
+            },
+        }
+    }
+
+    let written = File::create(uid_map_path)
+        .and_then(|file| deidentifier.replaced_uids().write_csv(BufWriter::new(file)));
+
+    if let Err(error) = written {
+        eprintln!("Cannot write UID map {}: {}", uid_map_path, error);
+        return ExitCode::FAILURE;
+    }
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
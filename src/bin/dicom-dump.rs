@@ -0,0 +1,52 @@
+use std::process::ExitCode;
+use dicom_viewer::dicom_dump::dicom_dumper::DicomDumper;
+
+const USAGE: &str = "Usage: dicom-dump [--group <gggg>]... [--separate-meta] <file>";
+
+/// Prints all elements of a DICOM file with their offsets, nested items indented.
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+
+    let mut groups = Vec::new();
+    let mut separate_meta = false;
+    let mut file = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--group" => match args.next().and_then(|group| u16::from_str_radix(&group, 16).ok()) {
+                Some(group) => groups.push(group),
+                None => {
+                    eprintln!("--group expects a hexadecimal group number, e.g. 0028");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "--separate-meta" => separate_meta = true,
+            _ if file.is_none() && !arg.starts_with("--") => file = Some(arg),
+            _ => {
+                eprintln!("{}", USAGE);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let Some(file) = file else {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    };
+
+    let mut dumper = DicomDumper::new().groups(&groups);
+    if separate_meta {
+        dumper = dumper.separate_file_meta_information();
+    }
+
+    match dumper.dump_file(&file) {
+        Ok(dump) => {
+            print!("{}", dump);
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("Cannot read {}: {}", file, error);
+            ExitCode::FAILURE
+        }
+    }
+}
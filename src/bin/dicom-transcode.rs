@@ -0,0 +1,20 @@
+use std::process::ExitCode;
+use dicom_viewer::transcoder::transcoder::Transcoder;
+
+/// Rewrites a DICOM file in Explicit VR Little Endian, decoding JPEG 2000 pixel data.
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+
+    let [_, input, output] = args.as_slice() else {
+        eprintln!("Usage: dicom-transcode <input> <output>");
+        return ExitCode::FAILURE;
+    };
+
+    match Transcoder::new().transcode_file(input, output) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Cannot transcode {}: {}", input, error);
+            ExitCode::FAILURE
+        }
+    }
+}
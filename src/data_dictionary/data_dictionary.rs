@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use once_cell::sync::Lazy;
+use crate::dataset::private_creators::PrivateCreators;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_representation::ValueRepresentation;
+use crate::dicom_constants::value_representation::{LONG_STRING, OTHER_WORD, UNSIGNED_LONG};
+use super::private_dictionary::PRIVATE_DICTIONARY;
+use super::standard_dictionary::{MASKED_ELEMENTS_DICTIONARY, REPEATING_GROUPS_DICTIONARY, STANDARD_DICTIONARY};
+
+#[derive(Debug, Clone, Copy)]
+pub struct DictionaryEntry {
+    pub tag: Tag,
+    pub keyword: &'static str,
+    pub name: &'static str,
+    pub vr: &'static [ValueRepresentation],
+    pub vm: &'static str,
+    pub retired: bool,
+}
+
+/// Private attribute identified by its Private Creator, group and element within the reserved
+/// block, e.g. 0x0C for (0019,xx0C) of "SIEMENS MR HEADER".
+#[derive(Debug, Clone, Copy)]
+pub struct PrivateDictionaryEntry {
+    pub creator: &'static str,
+    pub group: u16,
+    pub element: u8,
+    pub keyword: &'static str,
+    pub name: &'static str,
+    pub vr: &'static [ValueRepresentation],
+    pub vm: &'static str,
+}
+
+const GROUP_LENGTH: DictionaryEntry = DictionaryEntry {
+    tag: Tag { group: 0x0000, element: 0x0000 },
+    keyword: "GenericGroupLength",
+    name: "Generic Group Length",
+    vr: &[UNSIGNED_LONG],
+    vm: "1",
+    retired: false
+};
+
+const PRIVATE_CREATOR: DictionaryEntry = DictionaryEntry {
+    tag: Tag { group: 0x0009, element: 0x0010 },
+    keyword: "PrivateCreator",
+    name: "Private Creator",
+    vr: &[LONG_STRING],
+    vm: "1",
+    retired: false
+};
+
+const REPEATING_GROUPS: [u16; 3] = [0x5000, 0x6000, 0x7F00];
+const REPEATING_GROUP_MASK: u16 = 0xFF00;
+
+static DICTIONARY: Lazy<HashMap<Tag, &'static DictionaryEntry>> = Lazy::new(|| {
+    STANDARD_DICTIONARY.iter().map(|entry| (entry.tag, entry)).collect()
+});
+
+static REPEATING_DICTIONARY: Lazy<HashMap<Tag, &'static DictionaryEntry>> = Lazy::new(|| {
+    REPEATING_GROUPS_DICTIONARY.iter().map(|entry| (entry.tag, entry)).collect()
+});
+
+static KEYWORDS: Lazy<HashMap<&'static str, &'static DictionaryEntry>> = Lazy::new(|| {
+    STANDARD_DICTIONARY.iter()
+        .chain(REPEATING_GROUPS_DICTIONARY.iter())
+        .chain(MASKED_ELEMENTS_DICTIONARY.iter().map(|(_, entry)| entry))
+        .map(|entry| (entry.keyword, entry))
+        .collect()
+});
+
+static PRIVATE: Lazy<HashMap<(&'static str, u16, u8), &'static PrivateDictionaryEntry>> = Lazy::new(|| {
+    PRIVATE_DICTIONARY.iter().map(|entry| ((entry.creator, entry.group, entry.element), entry)).collect()
+});
+
+pub struct DataDictionary {}
+
+impl DataDictionary {
+    pub fn get(tag: &Tag) -> Option<&'static DictionaryEntry> {
+        if let Some(entry) = DICTIONARY.get(tag) {
+            return Some(entry);
+        }
+
+        if let Some(entry) = Self::get_repeating_group_entry(tag) {
+            return Some(entry);
+        }
+
+        if tag.element == 0x0000 {
+            return Some(&GROUP_LENGTH);
+        }
+
+        if let Some(entry) = Self::get_masked_element_entry(tag) {
+            return Some(entry);
+        }
+
+        if PrivateCreators::is_private_creator(tag) {
+            return Some(&PRIVATE_CREATOR);
+        }
+
+        None
+    }
+
+    /// Looks up an entry by its keyword, e.g. "PatientName". Entries of repeating groups are
+    /// returned for their first group (e.g. 6000 for overlays) and ranges of elements for their
+    /// first element (e.g. (0020,3100) for Source Image IDs).
+    pub fn get_by_keyword(keyword: &str) -> Option<&'static DictionaryEntry> {
+        KEYWORDS.get(keyword).copied()
+    }
+
+    pub fn tag(keyword: &str) -> Option<Tag> {
+        Self::get_by_keyword(keyword).map(|entry| entry.tag)
+    }
+
+    /// Value representation used for the tag when the data set is encoded with implicit VR.
+    /// For attributes with several allowed VRs, OW takes precedence (e.g. Pixel Data is always
+    /// OW in Implicit VR Little Endian), otherwise the first listed VR is used.
+    pub fn implicit_value_representation(tag: &Tag) -> Option<ValueRepresentation> {
+        Self::preferred_value_representation(Self::get(tag)?.vr)
+    }
+
+    /// Looks up a private attribute, e.g. `get_private("SIEMENS MR HEADER", 0x0019, 0x0C)` for
+    /// the B value. Trailing spaces of the creator, as padded in data sets, are ignored.
+    pub fn get_private(creator: &str, group: u16, element: u8) -> Option<&'static PrivateDictionaryEntry> {
+        PRIVATE.get(&(creator.trim_end_matches([' ', '\0']), group, element)).copied()
+    }
+
+    /// Value representation of a private attribute encoded with implicit VR.
+    pub fn private_implicit_value_representation(creator: &str, tag: &Tag) -> Option<ValueRepresentation> {
+        let entry = Self::get_private(creator, tag.group, (tag.element & 0x00FF) as u8)?;
+        Self::preferred_value_representation(entry.vr)
+    }
+
+    fn preferred_value_representation(vr: &[ValueRepresentation]) -> Option<ValueRepresentation> {
+        vr.iter()
+            .find(|vr| vr.value == OTHER_WORD.value)
+            .or(vr.first())
+            .copied()
+    }
+
+    fn get_repeating_group_entry(tag: &Tag) -> Option<&'static DictionaryEntry> {
+        let group = tag.group & REPEATING_GROUP_MASK;
+
+        if tag.group & 1 == 1 || !REPEATING_GROUPS.contains(&group) {
+            return None;
+        }
+
+        REPEATING_DICTIONARY.get(&Tag { group, element: tag.element }).copied()
+    }
+
+    fn get_masked_element_entry(tag: &Tag) -> Option<&'static DictionaryEntry> {
+        MASKED_ELEMENTS_DICTIONARY.iter()
+            .find(|(mask, entry)| entry.tag.group == tag.group && entry.tag.element == tag.element & mask)
+            .map(|(_, entry)| entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dicom_constants::tags::{PIXEL_DATA, ROWS, TRANSFER_SYNTAX_UID};
+
+    #[test]
+    fn test_standard_tags() {
+        assert_eq!(DataDictionary::implicit_value_representation(&ROWS).unwrap().value, *b"US");
+        assert_eq!(DataDictionary::implicit_value_representation(&TRANSFER_SYNTAX_UID).unwrap().value, *b"UI");
+        assert_eq!(DataDictionary::get(&ROWS).unwrap().vm, "1");
+    }
+
+    #[test]
+    fn test_pixel_data_is_other_word() {
+        assert_eq!(DataDictionary::implicit_value_representation(&PIXEL_DATA).unwrap().value, *b"OW");
+    }
+
+    #[test]
+    fn test_repeating_groups() {
+        let overlay_data = Tag { group: 0x6002, element: 0x3000 };
+        assert_eq!(DataDictionary::implicit_value_representation(&overlay_data).unwrap().value, *b"OW");
+
+        let overlay_rows = Tag { group: 0x601E, element: 0x0010 };
+        assert_eq!(DataDictionary::implicit_value_representation(&overlay_rows).unwrap().value, *b"US");
+
+        let source_image_id = Tag { group: 0x0020, element: 0x3142 };
+        assert_eq!(DataDictionary::get(&source_image_id).unwrap().keyword, "SourceImageIDs");
+
+        let zonal_map = Tag { group: 0x1010, element: 0x0123 };
+        assert_eq!(DataDictionary::implicit_value_representation(&zonal_map).unwrap().value, *b"US");
+
+        let odd_group = Tag { group: 0x6001, element: 0x0010 };
+        assert_eq!(DataDictionary::implicit_value_representation(&odd_group).unwrap().value, *b"LO");
+    }
+
+    #[test]
+    fn test_group_length_and_private_tags() {
+        let group_length = Tag { group: 0x0009, element: 0x0000 };
+        assert_eq!(DataDictionary::implicit_value_representation(&group_length).unwrap().value, *b"UL");
+
+        let private_creator = Tag { group: 0x0029, element: 0x0010 };
+        assert_eq!(DataDictionary::implicit_value_representation(&private_creator).unwrap().value, *b"LO");
+
+        let private_element = Tag { group: 0x0029, element: 0x1010 };
+        assert!(DataDictionary::get(&private_element).is_none());
+    }
+
+    #[test]
+    fn test_private_tags() {
+        let b_value = DataDictionary::get_private("SIEMENS MR HEADER ", 0x0019, 0x0C).unwrap();
+        assert_eq!(b_value.name, "B Value");
+        assert_eq!(b_value.vr[0].value, *b"IS");
+
+        let scale_slope = Tag { group: 0x2005, element: 0x140E };
+        assert_eq!(DataDictionary::private_implicit_value_representation("Philips MR Imaging DD 001", &scale_slope).unwrap().value, *b"FL");
+        assert!(DataDictionary::private_implicit_value_representation("Philips Imaging DD 001", &scale_slope).is_none());
+        assert!(DataDictionary::get_private("SIEMENS MR HEADER", 0x0029, 0x0C).is_none());
+    }
+
+    #[test]
+    fn test_keywords() {
+        let patient_name = DataDictionary::get_by_keyword("PatientName").unwrap();
+        assert_eq!(patient_name.tag, Tag { group: 0x0010, element: 0x0010 });
+        assert_eq!(patient_name.name, "Patient's Name");
+        assert_eq!(patient_name.vr[0].value, *b"PN");
+        assert!(!patient_name.retired);
+
+        assert_eq!(DataDictionary::tag("Rows"), Some(ROWS));
+        assert_eq!(DataDictionary::get(&Tag { group: 0x0008, element: 0x0040 }).unwrap().keyword, "DataSetType");
+        assert!(DataDictionary::get(&Tag { group: 0x0008, element: 0x0040 }).unwrap().retired);
+        assert!(DataDictionary::get_by_keyword("NoSuchKeyword").is_none());
+    }
+}
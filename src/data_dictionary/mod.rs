@@ -0,0 +1,3 @@
+pub mod data_dictionary;
+mod standard_dictionary;
+mod private_dictionary;
\ No newline at end of file
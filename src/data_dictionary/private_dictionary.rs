@@ -0,0 +1,128 @@
+use crate::dicom_constants::value_representation::*;
+use super::data_dictionary::PrivateDictionaryEntry;
+
+/// Private attributes of GE, Siemens and Philips commonly used for protocol analysis,
+/// sorted by group, creator and element.
+pub(super) const PRIVATE_DICTIONARY: &[PrivateDictionaryEntry] = &[
+    // GEMS_IDEN_01 (0009)
+    PrivateDictionaryEntry { creator: "GEMS_IDEN_01", group: 0x0009, element: 0x01, keyword: "GEFullFidelity", name: "Full Fidelity", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_IDEN_01", group: 0x0009, element: 0x02, keyword: "GESuiteID", name: "Suite ID", vr: &[SHORT_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_IDEN_01", group: 0x0009, element: 0x04, keyword: "GEProductID", name: "Product ID", vr: &[SHORT_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_IDEN_01", group: 0x0009, element: 0x27, keyword: "GEImageActualDate", name: "Image Actual Date", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_IDEN_01", group: 0x0009, element: 0xE3, keyword: "GEEquipmentUID", name: "Equipment UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1" },
+    // GEMS_ACQU_01 (0019)
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x0F, keyword: "GEHorizontalFrameOfReference", name: "Horizontal Frame of Reference", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x18, keyword: "GEFirstScanRAS", name: "First Scan RAS", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x19, keyword: "GEFirstScanLocation", name: "First Scan Location", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x1A, keyword: "GELastScanRAS", name: "Last Scan RAS", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x1B, keyword: "GELastScanLocation", name: "Last Scan Location", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x23, keyword: "GETableSpeed", name: "Table Speed", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x24, keyword: "GEMidScanTime", name: "Mid Scan Time", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x25, keyword: "GEMidScanFlag", name: "Mid Scan Flag", vr: &[SIGNED_SHORT], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x26, keyword: "GEDegreesOfAzimuth", name: "Degrees of Azimuth", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x27, keyword: "GEGantryPeriod", name: "Gantry Period", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x9B, keyword: "GEPulseSequenceMode", name: "Pulse Sequence Mode", vr: &[SIGNED_SHORT], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x9C, keyword: "GEPulseSequenceName", name: "Pulse Sequence Name", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0x9E, keyword: "GEInternalPulseSequenceName", name: "Internal Pulse Sequence Name", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0xBB, keyword: "GEUserData20", name: "User Data 20", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_ACQU_01", group: 0x0019, element: 0xBC, keyword: "GEUserData21", name: "User Data 21", vr: &[DECIMAL_STRING], vm: "1" },
+    // SIEMENS MR HEADER (0019)
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x08, keyword: "SiemensCSAImageHeaderType", name: "CSA Image Header Type", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x09, keyword: "SiemensCSAImageHeaderVersion", name: "CSA Image Header Version", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x0A, keyword: "SiemensNumberOfImagesInMosaic", name: "Number of Images in Mosaic", vr: &[UNSIGNED_SHORT], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x0B, keyword: "SiemensSliceMeasurementDuration", name: "Slice Measurement Duration", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x0C, keyword: "SiemensBValue", name: "B Value", vr: &[INTEGER_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x0D, keyword: "SiemensDiffusionDirectionality", name: "Diffusion Directionality", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x0E, keyword: "SiemensDiffusionGradientDirection", name: "Diffusion Gradient Direction", vr: &[FLOATING_POINT_DOUBLE], vm: "3" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x0F, keyword: "SiemensGradientMode", name: "Gradient Mode", vr: &[SHORT_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x11, keyword: "SiemensFlowCompensation", name: "Flow Compensation", vr: &[SHORT_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x12, keyword: "SiemensTablePositionOrigin", name: "Table Position Origin", vr: &[SIGNED_LONG], vm: "3" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x13, keyword: "SiemensImageAbsoluteTablePosition", name: "Image Absolute Table Position", vr: &[SIGNED_LONG], vm: "3" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x14, keyword: "SiemensImageRelativeTablePosition", name: "Image Relative Table Position", vr: &[INTEGER_STRING], vm: "3" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x15, keyword: "SiemensSlicePositionPCS", name: "Slice Position PCS", vr: &[FLOATING_POINT_DOUBLE], vm: "3" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x16, keyword: "SiemensTimeAfterStart", name: "Time After Start", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x17, keyword: "SiemensSliceResolution", name: "Slice Resolution", vr: &[DECIMAL_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x18, keyword: "SiemensRealDwellTime", name: "Real Dwell Time", vr: &[INTEGER_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0019, element: 0x27, keyword: "SiemensBMatrix", name: "B Matrix", vr: &[FLOATING_POINT_DOUBLE], vm: "6" },
+    // GEMS_SERS_01 (0025)
+    PrivateDictionaryEntry { creator: "GEMS_SERS_01", group: 0x0025, element: 0x06, keyword: "GELastPulseSequenceUsed", name: "Last Pulse Sequence Used", vr: &[SIGNED_SHORT], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_SERS_01", group: 0x0025, element: 0x07, keyword: "GEImagesInSeries", name: "Images in Series", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_SERS_01", group: 0x0025, element: 0x11, keyword: "GENumberOfAcquisitions", name: "Number of Acquisitions", vr: &[SIGNED_SHORT], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_SERS_01", group: 0x0025, element: 0x14, keyword: "GENumberOfHeaderUpdates", name: "Number of Header Updates", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_SERS_01", group: 0x0025, element: 0x17, keyword: "GESeriesCompleteFlag", name: "Series Complete Flag", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_SERS_01", group: 0x0025, element: 0x18, keyword: "GENumberOfImagesArchived", name: "Number of Images Archived", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_SERS_01", group: 0x0025, element: 0x19, keyword: "GELastImageNumberUsed", name: "Last Image Number Used", vr: &[SIGNED_LONG], vm: "1" },
+    // SIEMENS CSA HEADER (0029)
+    PrivateDictionaryEntry { creator: "SIEMENS CSA HEADER", group: 0x0029, element: 0x08, keyword: "CSAImageHeaderType", name: "CSA Image Header Type", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS CSA HEADER", group: 0x0029, element: 0x09, keyword: "CSAImageHeaderVersion", name: "CSA Image Header Version", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS CSA HEADER", group: 0x0029, element: 0x10, keyword: "CSAImageHeaderInfo", name: "CSA Image Header Info", vr: &[OTHER_BYTE], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS CSA HEADER", group: 0x0029, element: 0x18, keyword: "CSASeriesHeaderType", name: "CSA Series Header Type", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS CSA HEADER", group: 0x0029, element: 0x19, keyword: "CSASeriesHeaderVersion", name: "CSA Series Header Version", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS CSA HEADER", group: 0x0029, element: 0x20, keyword: "CSASeriesHeaderInfo", name: "CSA Series Header Info", vr: &[OTHER_BYTE], vm: "1" },
+    // GEMS_PARM_01 (0043)
+    PrivateDictionaryEntry { creator: "GEMS_PARM_01", group: 0x0043, element: 0x2C, keyword: "GEEffectiveEchoSpacing", name: "Effective Echo Spacing", vr: &[SIGNED_SHORT], vm: "1" },
+    PrivateDictionaryEntry { creator: "GEMS_PARM_01", group: 0x0043, element: 0x39, keyword: "GESlopInt6To9", name: "Slop Int 6-9", vr: &[INTEGER_STRING], vm: "4" },
+    PrivateDictionaryEntry { creator: "GEMS_PARM_01", group: 0x0043, element: 0x83, keyword: "GEAssetRFactors", name: "ASSET R Factors", vr: &[DECIMAL_STRING], vm: "1-2" },
+    // SIEMENS MR HEADER (0051)
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x0A, keyword: "SiemensAcquisitionTimeText", name: "Acquisition Time Text", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x0B, keyword: "SiemensAcquisitionMatrixText", name: "Acquisition Matrix Text", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x0C, keyword: "SiemensFieldOfViewText", name: "Field of View Text", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x0D, keyword: "SiemensSlicePositionText", name: "Slice Position Text", vr: &[SHORT_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x0E, keyword: "SiemensImageOrientationText", name: "Image Orientation Text", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x0F, keyword: "SiemensCoilString", name: "Coil String", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x11, keyword: "SiemensPATModeText", name: "PAT Mode Text", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x12, keyword: "SiemensTablePositionText", name: "Table Position Text", vr: &[SHORT_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x13, keyword: "SiemensPositivePCSDirections", name: "Positive PCS Directions", vr: &[SHORT_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x16, keyword: "SiemensImageTypeText", name: "Image Type Text", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "SIEMENS MR HEADER", group: 0x0051, element: 0x17, keyword: "SiemensSliceThicknessText", name: "Slice Thickness Text", vr: &[SHORT_STRING], vm: "1" },
+    // Philips Imaging DD 001 (2001)
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x01, keyword: "PhilipsChemicalShift", name: "Chemical Shift", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x02, keyword: "PhilipsChemicalShiftNumberMR", name: "Chemical Shift Number MR", vr: &[INTEGER_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x03, keyword: "PhilipsDiffusionBFactor", name: "Diffusion B-Factor", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x04, keyword: "PhilipsDiffusionDirection", name: "Diffusion Direction", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x08, keyword: "PhilipsPhaseNumber", name: "Phase Number", vr: &[INTEGER_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x09, keyword: "PhilipsImagePrepulseDelay", name: "Image Prepulse Delay", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x0A, keyword: "PhilipsSliceNumberMR", name: "Slice Number MR", vr: &[INTEGER_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x0B, keyword: "PhilipsSliceOrientation", name: "Slice Orientation", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x10, keyword: "PhilipsCardiacSynchronization", name: "Cardiac Synchronization", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x11, keyword: "PhilipsDiffusionEchoTime", name: "Diffusion Echo Time", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x12, keyword: "PhilipsDynamicSeries", name: "Dynamic Series", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x13, keyword: "PhilipsEPIFactor", name: "EPI Factor", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x14, keyword: "PhilipsNumberOfEchoes", name: "Number of Echoes", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x15, keyword: "PhilipsNumberOfLocations", name: "Number of Locations", vr: &[SIGNED_SHORT], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x16, keyword: "PhilipsNumberOfPCDirections", name: "Number of PC Directions", vr: &[SIGNED_SHORT], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x17, keyword: "PhilipsNumberOfPhasesMR", name: "Number of Phases MR", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x18, keyword: "PhilipsNumberOfSlicesMR", name: "Number of Slices MR", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x19, keyword: "PhilipsPartialMatrixScanned", name: "Partial Matrix Scanned", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x1A, keyword: "PhilipsPCVelocity", name: "PC Velocity", vr: &[FLOATING_POINT_SINGLE], vm: "1-n" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x1B, keyword: "PhilipsPrepulseDelay", name: "Prepulse Delay", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x1C, keyword: "PhilipsPrepulseType", name: "Prepulse Type", vr: &[CODE_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x1D, keyword: "PhilipsReconstructionNumberMR", name: "Reconstruction Number MR", vr: &[INTEGER_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x20, keyword: "PhilipsScanningTechnique", name: "Scanning Technique Description MR", vr: &[LONG_STRING], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x22, keyword: "PhilipsWaterFatShift", name: "Water Fat Shift", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x5F, keyword: "PhilipsStackSequence", name: "Stack Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips Imaging DD 001", group: 0x2001, element: 0x60, keyword: "PhilipsNumberOfStacks", name: "Number of Stacks", vr: &[SIGNED_LONG], vm: "1" },
+    // Philips MR Imaging DD 001 (2005)
+    PrivateDictionaryEntry { creator: "Philips MR Imaging DD 001", group: 0x2005, element: 0x0D, keyword: "PhilipsScaleIntercept", name: "Scale Intercept", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips MR Imaging DD 001", group: 0x2005, element: 0x0E, keyword: "PhilipsScaleSlope", name: "Scale Slope", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips MR Imaging DD 001", group: 0x2005, element: 0x20, keyword: "PhilipsNumberOfChemicalShifts", name: "Number of Chemical Shifts", vr: &[SIGNED_LONG], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips MR Imaging DD 001", group: 0x2005, element: 0xB0, keyword: "PhilipsDiffusionDirectionRL", name: "Diffusion Direction RL", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips MR Imaging DD 001", group: 0x2005, element: 0xB1, keyword: "PhilipsDiffusionDirectionAP", name: "Diffusion Direction AP", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+    PrivateDictionaryEntry { creator: "Philips MR Imaging DD 001", group: 0x2005, element: 0xB2, keyword: "PhilipsDiffusionDirectionFH", name: "Diffusion Direction FH", vr: &[FLOATING_POINT_SINGLE], vm: "1" },
+];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use super::*;
+
+    #[test]
+    fn test_entries_are_sorted_and_unique() {
+        assert!(PRIVATE_DICTIONARY.windows(2).all(|entries| {
+            (entries[0].group, entries[0].creator, entries[0].element) < (entries[1].group, entries[1].creator, entries[1].element)
+        }));
+
+        let keywords: HashSet<&str> = PRIVATE_DICTIONARY.iter().map(|entry| entry.keyword).collect();
+        assert_eq!(keywords.len(), PRIVATE_DICTIONARY.len());
+    }
+}
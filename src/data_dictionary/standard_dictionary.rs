@@ -0,0 +1,2965 @@
+// Generated by `cargo xtask gen-dictionary` from the DICOM standard (PS3.6, PS3.7). Do not edit.
+use crate::dataset::tag::Tag;
+use crate::dicom_constants::value_representation::*;
+use super::data_dictionary::DictionaryEntry;
+
+pub(super) const STANDARD_DICTIONARY: &[DictionaryEntry] = &[
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0000 }, keyword: "CommandGroupLength", name: "Command Group Length", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0002 }, keyword: "AffectedSOPClassUID", name: "Affected SOP Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0003 }, keyword: "RequestedSOPClassUID", name: "Requested SOP Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0100 }, keyword: "CommandField", name: "Command Field", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0110 }, keyword: "MessageID", name: "Message ID", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0120 }, keyword: "MessageIDBeingRespondedTo", name: "Message ID Being Responded To", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0600 }, keyword: "MoveDestination", name: "Move Destination", vr: &[APPLICATION_ENTITY], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0700 }, keyword: "Priority", name: "Priority", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0800 }, keyword: "CommandDataSetType", name: "Command Data Set Type", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0900 }, keyword: "Status", name: "Status", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0901 }, keyword: "OffendingElement", name: "Offending Element", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0902 }, keyword: "ErrorComment", name: "Error Comment", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x0903 }, keyword: "ErrorID", name: "Error ID", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1000 }, keyword: "AffectedSOPInstanceUID", name: "Affected SOP Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1001 }, keyword: "RequestedSOPInstanceUID", name: "Requested SOP Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1002 }, keyword: "EventTypeID", name: "Event Type ID", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1005 }, keyword: "AttributeIdentifierList", name: "Attribute Identifier List", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1008 }, keyword: "ActionTypeID", name: "Action Type ID", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1020 }, keyword: "NumberOfRemainingSuboperations", name: "Number of Remaining Sub-operations", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1021 }, keyword: "NumberOfCompletedSuboperations", name: "Number of Completed Sub-operations", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1022 }, keyword: "NumberOfFailedSuboperations", name: "Number of Failed Sub-operations", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1023 }, keyword: "NumberOfWarningSuboperations", name: "Number of Warning Sub-operations", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1030 }, keyword: "MoveOriginatorApplicationEntityTitle", name: "Move Originator Application Entity Title", vr: &[APPLICATION_ENTITY], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0000, element: 0x1031 }, keyword: "MoveOriginatorMessageID", name: "Move Originator Message ID", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0000 }, keyword: "FileMetaInformationGroupLength", name: "File Meta Information Group Length", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0001 }, keyword: "FileMetaInformationVersion", name: "File Meta Information Version", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0002 }, keyword: "MediaStorageSOPClassUID", name: "Media Storage SOP Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0003 }, keyword: "MediaStorageSOPInstanceUID", name: "Media Storage SOP Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0010 }, keyword: "TransferSyntaxUID", name: "Transfer Syntax UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0012 }, keyword: "ImplementationClassUID", name: "Implementation Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0013 }, keyword: "ImplementationVersionName", name: "Implementation Version Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0016 }, keyword: "SourceApplicationEntityTitle", name: "Source Application Entity Title", vr: &[APPLICATION_ENTITY], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0017 }, keyword: "SendingApplicationEntityTitle", name: "Sending Application Entity Title", vr: &[APPLICATION_ENTITY], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0018 }, keyword: "ReceivingApplicationEntityTitle", name: "Receiving Application Entity Title", vr: &[APPLICATION_ENTITY], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0026 }, keyword: "SourcePresentationAddress", name: "Source Presentation Address", vr: &[UNIVERSAL_RESOURCE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0027 }, keyword: "SendingPresentationAddress", name: "Sending Presentation Address", vr: &[UNIVERSAL_RESOURCE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0028 }, keyword: "ReceivingPresentationAddress", name: "Receiving Presentation Address", vr: &[UNIVERSAL_RESOURCE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0031 }, keyword: "RTVMetaInformationVersion", name: "RTV Meta Information Version", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0032 }, keyword: "RTVCommunicationSOPClassUID", name: "RTV Communication SOP Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0033 }, keyword: "RTVCommunicationSOPInstanceUID", name: "RTV Communication SOP Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0035 }, keyword: "RTVSourceIdentifier", name: "RTV Source Identifier", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0036 }, keyword: "RTVFlowIdentifier", name: "RTV Flow Identifier", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0037 }, keyword: "RTVFlowRTPSamplingRate", name: "RTV Flow RTP Sampling Rate", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0038 }, keyword: "RTVFlowActualFrameDuration", name: "RTV Flow Actual Frame Duration", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0100 }, keyword: "PrivateInformationCreatorUID", name: "Private Information Creator UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0002, element: 0x0102 }, keyword: "PrivateInformation", name: "Private Information", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1130 }, keyword: "FileSetID", name: "File-set ID", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1141 }, keyword: "FileSetDescriptorFileID", name: "File-set Descriptor File ID", vr: &[CODE_STRING], vm: "1-8", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1142 }, keyword: "SpecificCharacterSetOfFileSetDescriptorFile", name: "Specific Character Set of File-set Descriptor File", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1200 }, keyword: "OffsetOfTheFirstDirectoryRecordOfTheRootDirectoryEntity", name: "Offset of the First Directory Record of the Root Directory Entity", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1202 }, keyword: "OffsetOfTheLastDirectoryRecordOfTheRootDirectoryEntity", name: "Offset of the Last Directory Record of the Root Directory Entity", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1212 }, keyword: "FileSetConsistencyFlag", name: "File-set Consistency Flag", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1220 }, keyword: "DirectoryRecordSequence", name: "Directory Record Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1400 }, keyword: "OffsetOfTheNextDirectoryRecord", name: "Offset of the Next Directory Record", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1410 }, keyword: "RecordInUseFlag", name: "Record In-use Flag", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1420 }, keyword: "OffsetOfReferencedLowerLevelDirectoryEntity", name: "Offset of Referenced Lower-Level Directory Entity", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1430 }, keyword: "DirectoryRecordType", name: "Directory Record Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1432 }, keyword: "PrivateRecordUID", name: "Private Record UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1500 }, keyword: "ReferencedFileID", name: "Referenced File ID", vr: &[CODE_STRING], vm: "1-8", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1504 }, keyword: "MRDRDirectoryRecordOffset", name: "MRDR Directory Record Offset", vr: &[UNSIGNED_LONG], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1510 }, keyword: "ReferencedSOPClassUIDInFile", name: "Referenced SOP Class UID in File", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1511 }, keyword: "ReferencedSOPInstanceUIDInFile", name: "Referenced SOP Instance UID in File", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1512 }, keyword: "ReferencedTransferSyntaxUIDInFile", name: "Referenced Transfer Syntax UID in File", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x151A }, keyword: "ReferencedRelatedGeneralSOPClassUIDInFile", name: "Referenced Related General SOP Class UID in File", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0004, element: 0x1600 }, keyword: "NumberOfReferences", name: "Number of References", vr: &[UNSIGNED_LONG], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0001 }, keyword: "LengthToEnd", name: "Length to End", vr: &[UNSIGNED_LONG], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0005 }, keyword: "SpecificCharacterSet", name: "Specific Character Set", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0006 }, keyword: "LanguageCodeSequence", name: "Language Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0008 }, keyword: "ImageType", name: "Image Type", vr: &[CODE_STRING], vm: "2-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0010 }, keyword: "RecognitionCode", name: "Recognition Code", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0012 }, keyword: "InstanceCreationDate", name: "Instance Creation Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0013 }, keyword: "InstanceCreationTime", name: "Instance Creation Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0014 }, keyword: "InstanceCreatorUID", name: "Instance Creator UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0015 }, keyword: "InstanceCoercionDateTime", name: "Instance Coercion DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0016 }, keyword: "SOPClassUID", name: "SOP Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0017 }, keyword: "AcquisitionUID", name: "Acquisition UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0018 }, keyword: "SOPInstanceUID", name: "SOP Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0019 }, keyword: "PyramidUID", name: "Pyramid UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x001A }, keyword: "RelatedGeneralSOPClassUID", name: "Related General SOP Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x001B }, keyword: "OriginalSpecializedSOPClassUID", name: "Original Specialized SOP Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0020 }, keyword: "StudyDate", name: "Study Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0021 }, keyword: "SeriesDate", name: "Series Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0022 }, keyword: "AcquisitionDate", name: "Acquisition Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0023 }, keyword: "ContentDate", name: "Content Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0024 }, keyword: "OverlayDate", name: "Overlay Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0025 }, keyword: "CurveDate", name: "Curve Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x002A }, keyword: "AcquisitionDateTime", name: "Acquisition DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0030 }, keyword: "StudyTime", name: "Study Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0031 }, keyword: "SeriesTime", name: "Series Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0032 }, keyword: "AcquisitionTime", name: "Acquisition Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0033 }, keyword: "ContentTime", name: "Content Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0034 }, keyword: "OverlayTime", name: "Overlay Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0035 }, keyword: "CurveTime", name: "Curve Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0040 }, keyword: "DataSetType", name: "Data Set Type", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0041 }, keyword: "DataSetSubtype", name: "Data Set Subtype", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0042 }, keyword: "NuclearMedicineSeriesType", name: "Nuclear Medicine Series Type", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0050 }, keyword: "AccessionNumber", name: "Accession Number", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0051 }, keyword: "IssuerOfAccessionNumberSequence", name: "Issuer of Accession Number Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0052 }, keyword: "QueryRetrieveLevel", name: "Query/Retrieve Level", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0053 }, keyword: "QueryRetrieveView", name: "Query/Retrieve View", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0054 }, keyword: "RetrieveAETitle", name: "Retrieve AE Title", vr: &[APPLICATION_ENTITY], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0055 }, keyword: "StationAETitle", name: "Station AE Title", vr: &[APPLICATION_ENTITY], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0056 }, keyword: "InstanceAvailability", name: "Instance Availability", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0058 }, keyword: "FailedSOPInstanceUIDList", name: "Failed SOP Instance UID List", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0060 }, keyword: "Modality", name: "Modality", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0061 }, keyword: "ModalitiesInStudy", name: "Modalities in Study", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0062 }, keyword: "SOPClassesInStudy", name: "SOP Classes in Study", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0063 }, keyword: "AnatomicRegionsInStudyCodeSequence", name: "Anatomic Regions in Study Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0064 }, keyword: "ConversionType", name: "Conversion Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0068 }, keyword: "PresentationIntentType", name: "Presentation Intent Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0070 }, keyword: "Manufacturer", name: "Manufacturer", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0080 }, keyword: "InstitutionName", name: "Institution Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0081 }, keyword: "InstitutionAddress", name: "Institution Address", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0082 }, keyword: "InstitutionCodeSequence", name: "Institution Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0090 }, keyword: "ReferringPhysicianName", name: "Referring Physician's Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0092 }, keyword: "ReferringPhysicianAddress", name: "Referring Physician's Address", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0094 }, keyword: "ReferringPhysicianTelephoneNumbers", name: "Referring Physician's Telephone Numbers", vr: &[SHORT_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0096 }, keyword: "ReferringPhysicianIdentificationSequence", name: "Referring Physician Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x009C }, keyword: "ConsultingPhysicianName", name: "Consulting Physician's Name", vr: &[PERSON_NAME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x009D }, keyword: "ConsultingPhysicianIdentificationSequence", name: "Consulting Physician Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0100 }, keyword: "CodeValue", name: "Code Value", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0101 }, keyword: "ExtendedCodeValue", name: "Extended Code Value", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0102 }, keyword: "CodingSchemeDesignator", name: "Coding Scheme Designator", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0103 }, keyword: "CodingSchemeVersion", name: "Coding Scheme Version", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0104 }, keyword: "CodeMeaning", name: "Code Meaning", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0105 }, keyword: "MappingResource", name: "Mapping Resource", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0106 }, keyword: "ContextGroupVersion", name: "Context Group Version", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0107 }, keyword: "ContextGroupLocalVersion", name: "Context Group Local Version", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0108 }, keyword: "ExtendedCodeMeaning", name: "Extended Code Meaning", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0109 }, keyword: "CodingSchemeResourcesSequence", name: "Coding Scheme Resources Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x010A }, keyword: "CodingSchemeURLType", name: "Coding Scheme URL Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x010B }, keyword: "ContextGroupExtensionFlag", name: "Context Group Extension Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x010C }, keyword: "CodingSchemeUID", name: "Coding Scheme UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x010D }, keyword: "ContextGroupExtensionCreatorUID", name: "Context Group Extension Creator UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x010E }, keyword: "CodingSchemeURL", name: "Coding Scheme URL", vr: &[UNIVERSAL_RESOURCE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x010F }, keyword: "ContextIdentifier", name: "Context Identifier", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0110 }, keyword: "CodingSchemeIdentificationSequence", name: "Coding Scheme Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0112 }, keyword: "CodingSchemeRegistry", name: "Coding Scheme Registry", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0114 }, keyword: "CodingSchemeExternalID", name: "Coding Scheme External ID", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0115 }, keyword: "CodingSchemeName", name: "Coding Scheme Name", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0116 }, keyword: "CodingSchemeResponsibleOrganization", name: "Coding Scheme Responsible Organization", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0117 }, keyword: "ContextUID", name: "Context UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0118 }, keyword: "MappingResourceUID", name: "Mapping Resource UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0119 }, keyword: "LongCodeValue", name: "Long Code Value", vr: &[UNLIMITED_CHARACTER], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0120 }, keyword: "URNCodeValue", name: "URN Code Value", vr: &[UNIVERSAL_RESOURCE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0121 }, keyword: "EquivalentCodeSequence", name: "Equivalent Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0122 }, keyword: "MappingResourceName", name: "Mapping Resource Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0123 }, keyword: "ContextGroupIdentificationSequence", name: "Context Group Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0124 }, keyword: "MappingResourceIdentificationSequence", name: "Mapping Resource Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0201 }, keyword: "TimezoneOffsetFromUTC", name: "Timezone Offset From UTC", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0220 }, keyword: "ResponsibleGroupCodeSequence", name: "Responsible Group Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0221 }, keyword: "EquipmentModality", name: "Equipment Modality", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0222 }, keyword: "ManufacturerRelatedModelGroup", name: "Manufacturer's Related Model Group", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0300 }, keyword: "PrivateDataElementCharacteristicsSequence", name: "Private Data Element Characteristics Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0301 }, keyword: "PrivateGroupReference", name: "Private Group Reference", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0302 }, keyword: "PrivateCreatorReference", name: "Private Creator Reference", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0303 }, keyword: "BlockIdentifyingInformationStatus", name: "Block Identifying Information Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0304 }, keyword: "NonidentifyingPrivateElements", name: "Nonidentifying Private Elements", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0305 }, keyword: "DeidentificationActionSequence", name: "Deidentification Action Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0306 }, keyword: "IdentifyingPrivateElements", name: "Identifying Private Elements", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0307 }, keyword: "DeidentificationAction", name: "Deidentification Action", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0308 }, keyword: "PrivateDataElement", name: "Private Data Element", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0309 }, keyword: "PrivateDataElementValueMultiplicity", name: "Private Data Element Value Multiplicity", vr: &[UNSIGNED_LONG], vm: "1-3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x030A }, keyword: "PrivateDataElementValueRepresentation", name: "Private Data Element Value Representation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x030B }, keyword: "PrivateDataElementNumberOfItems", name: "Private Data Element Number of Items", vr: &[UNSIGNED_LONG], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x030C }, keyword: "PrivateDataElementName", name: "Private Data Element Name", vr: &[UNLIMITED_CHARACTER], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x030D }, keyword: "PrivateDataElementKeyword", name: "Private Data Element Keyword", vr: &[UNLIMITED_CHARACTER], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x030E }, keyword: "PrivateDataElementDescription", name: "Private Data Element Description", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x030F }, keyword: "PrivateDataElementEncoding", name: "Private Data Element Encoding", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x0310 }, keyword: "PrivateDataElementDefinitionSequence", name: "Private Data Element Definition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1000 }, keyword: "NetworkID", name: "Network ID", vr: &[APPLICATION_ENTITY], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1010 }, keyword: "StationName", name: "Station Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1030 }, keyword: "StudyDescription", name: "Study Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1032 }, keyword: "ProcedureCodeSequence", name: "Procedure Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x103E }, keyword: "SeriesDescription", name: "Series Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x103F }, keyword: "SeriesDescriptionCodeSequence", name: "Series Description Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1040 }, keyword: "InstitutionalDepartmentName", name: "Institutional Department Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1041 }, keyword: "InstitutionalDepartmentTypeCodeSequence", name: "Institutional Department Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1048 }, keyword: "PhysiciansOfRecord", name: "Physician(s) of Record", vr: &[PERSON_NAME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1049 }, keyword: "PhysiciansOfRecordIdentificationSequence", name: "Physician(s) of Record Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1050 }, keyword: "PerformingPhysicianName", name: "Performing Physician's Name", vr: &[PERSON_NAME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1052 }, keyword: "PerformingPhysicianIdentificationSequence", name: "Performing Physician Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1060 }, keyword: "NameOfPhysiciansReadingStudy", name: "Name of Physician(s) Reading Study", vr: &[PERSON_NAME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1062 }, keyword: "PhysiciansReadingStudyIdentificationSequence", name: "Physician(s) Reading Study Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1070 }, keyword: "OperatorsName", name: "Operators' Name", vr: &[PERSON_NAME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1072 }, keyword: "OperatorIdentificationSequence", name: "Operator Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1080 }, keyword: "AdmittingDiagnosesDescription", name: "Admitting Diagnoses Description", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1084 }, keyword: "AdmittingDiagnosesCodeSequence", name: "Admitting Diagnoses Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1088 }, keyword: "PyramidDescription", name: "Pyramid Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1090 }, keyword: "ManufacturerModelName", name: "Manufacturer's Model Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1100 }, keyword: "ReferencedResultsSequence", name: "Referenced Results Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1110 }, keyword: "ReferencedStudySequence", name: "Referenced Study Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1111 }, keyword: "ReferencedPerformedProcedureStepSequence", name: "Referenced Performed Procedure Step Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1112 }, keyword: "ReferencedInstancesBySOPClassSequence", name: "Referenced Instances by SOP Class Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1115 }, keyword: "ReferencedSeriesSequence", name: "Referenced Series Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1120 }, keyword: "ReferencedPatientSequence", name: "Referenced Patient Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1125 }, keyword: "ReferencedVisitSequence", name: "Referenced Visit Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1130 }, keyword: "ReferencedOverlaySequence", name: "Referenced Overlay Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1134 }, keyword: "ReferencedStereometricInstanceSequence", name: "Referenced Stereometric Instance Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x113A }, keyword: "ReferencedWaveformSequence", name: "Referenced Waveform Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1140 }, keyword: "ReferencedImageSequence", name: "Referenced Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1145 }, keyword: "ReferencedCurveSequence", name: "Referenced Curve Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x114A }, keyword: "ReferencedInstanceSequence", name: "Referenced Instance Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x114B }, keyword: "ReferencedRealWorldValueMappingInstanceSequence", name: "Referenced Real World Value Mapping Instance Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1150 }, keyword: "ReferencedSOPClassUID", name: "Referenced SOP Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1155 }, keyword: "ReferencedSOPInstanceUID", name: "Referenced SOP Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1156 }, keyword: "DefinitionSourceSequence", name: "Definition Source Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x115A }, keyword: "SOPClassesSupported", name: "SOP Classes Supported", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1160 }, keyword: "ReferencedFrameNumber", name: "Referenced Frame Number", vr: &[INTEGER_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1161 }, keyword: "SimpleFrameList", name: "Simple Frame List", vr: &[UNSIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1162 }, keyword: "CalculatedFrameList", name: "Calculated Frame List", vr: &[UNSIGNED_LONG], vm: "3-3n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1163 }, keyword: "TimeRange", name: "Time Range", vr: &[FLOATING_POINT_DOUBLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1164 }, keyword: "FrameExtractionSequence", name: "Frame Extraction Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1167 }, keyword: "MultiFrameSourceSOPInstanceUID", name: "Multi-frame Source SOP Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1190 }, keyword: "RetrieveURL", name: "Retrieve URL", vr: &[UNIVERSAL_RESOURCE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1195 }, keyword: "TransactionUID", name: "Transaction UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1196 }, keyword: "WarningReason", name: "Warning Reason", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1197 }, keyword: "FailureReason", name: "Failure Reason", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1198 }, keyword: "FailedSOPSequence", name: "Failed SOP Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1199 }, keyword: "ReferencedSOPSequence", name: "Referenced SOP Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x119A }, keyword: "OtherFailuresSequence", name: "Other Failures Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1200 }, keyword: "StudiesContainingOtherReferencedInstancesSequence", name: "Studies Containing Other Referenced Instances Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x1250 }, keyword: "RelatedSeriesSequence", name: "Related Series Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2110 }, keyword: "LossyImageCompressionRetired", name: "Lossy Image Compression (Retired)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2111 }, keyword: "DerivationDescription", name: "Derivation Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2112 }, keyword: "SourceImageSequence", name: "Source Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2120 }, keyword: "StageName", name: "Stage Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2122 }, keyword: "StageNumber", name: "Stage Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2124 }, keyword: "NumberOfStages", name: "Number of Stages", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2127 }, keyword: "ViewName", name: "View Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2128 }, keyword: "ViewNumber", name: "View Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2129 }, keyword: "NumberOfEventTimers", name: "Number of Event Timers", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x212A }, keyword: "NumberOfViewsInStage", name: "Number of Views in Stage", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2130 }, keyword: "EventElapsedTimes", name: "Event Elapsed Time(s)", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2132 }, keyword: "EventTimerNames", name: "Event Timer Name(s)", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2133 }, keyword: "EventTimerSequence", name: "Event Timer Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2134 }, keyword: "EventTimeOffset", name: "Event Time Offset", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2135 }, keyword: "EventCodeSequence", name: "Event Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2142 }, keyword: "StartTrim", name: "Start Trim", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2143 }, keyword: "StopTrim", name: "Stop Trim", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2144 }, keyword: "RecommendedDisplayFrameRate", name: "Recommended Display Frame Rate", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2200 }, keyword: "TransducerPosition", name: "Transducer Position", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2204 }, keyword: "TransducerOrientation", name: "Transducer Orientation", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2208 }, keyword: "AnatomicStructure", name: "Anatomic Structure", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2218 }, keyword: "AnatomicRegionSequence", name: "Anatomic Region Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2220 }, keyword: "AnatomicRegionModifierSequence", name: "Anatomic Region Modifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2228 }, keyword: "PrimaryAnatomicStructureSequence", name: "Primary Anatomic Structure Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2229 }, keyword: "AnatomicStructureSpaceOrRegionSequence", name: "Anatomic Structure, Space or Region Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x2230 }, keyword: "PrimaryAnatomicStructureModifierSequence", name: "Primary Anatomic Structure Modifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x3001 }, keyword: "AlternateRepresentationSequence", name: "Alternate Representation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x3002 }, keyword: "AvailableTransferSyntaxUID", name: "Available Transfer Syntax UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x3010 }, keyword: "IrradiationEventUID", name: "Irradiation Event UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x3011 }, keyword: "SourceIrradiationEventSequence", name: "Source Irradiation Event Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x3012 }, keyword: "RadiopharmaceuticalAdministrationEventUID", name: "Radiopharmaceutical Administration Event UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x4000 }, keyword: "IdentifyingComments", name: "Identifying Comments", vr: &[LONG_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9007 }, keyword: "FrameType", name: "Frame Type", vr: &[CODE_STRING], vm: "4-5", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9092 }, keyword: "ReferencedImageEvidenceSequence", name: "Referenced Image Evidence Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9121 }, keyword: "ReferencedRawDataSequence", name: "Referenced Raw Data Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9123 }, keyword: "CreatorVersionUID", name: "Creator-Version UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9124 }, keyword: "DerivationImageSequence", name: "Derivation Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9154 }, keyword: "SourceImageEvidenceSequence", name: "Source Image Evidence Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9205 }, keyword: "PixelPresentation", name: "Pixel Presentation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9206 }, keyword: "VolumetricProperties", name: "Volumetric Properties", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9207 }, keyword: "VolumeBasedCalculationTechnique", name: "Volume Based Calculation Technique", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9208 }, keyword: "ComplexImageComponent", name: "Complex Image Component", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9209 }, keyword: "AcquisitionContrast", name: "Acquisition Contrast", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9215 }, keyword: "DerivationCodeSequence", name: "Derivation Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9237 }, keyword: "ReferencedPresentationStateSequence", name: "Referenced Presentation State Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9410 }, keyword: "ReferencedOtherPlaneSequence", name: "Referenced Other Plane Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9458 }, keyword: "FrameDisplaySequence", name: "Frame Display Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9459 }, keyword: "RecommendedDisplayFrameRateInFloat", name: "Recommended Display Frame Rate in Float", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0008, element: 0x9460 }, keyword: "SkipFrameRangeFlag", name: "Skip Frame Range Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0010 }, keyword: "PatientName", name: "Patient's Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0020 }, keyword: "PatientID", name: "Patient ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0021 }, keyword: "IssuerOfPatientID", name: "Issuer of Patient ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0022 }, keyword: "TypeOfPatientID", name: "Type of Patient ID", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0024 }, keyword: "IssuerOfPatientIDQualifiersSequence", name: "Issuer of Patient ID Qualifiers Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0026 }, keyword: "SourcePatientGroupIdentificationSequence", name: "Source Patient Group Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0027 }, keyword: "GroupOfPatientsIdentificationSequence", name: "Group of Patients Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0028 }, keyword: "SubjectRelativePositionInImage", name: "Subject Relative Position in Image", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0030 }, keyword: "PatientBirthDate", name: "Patient's Birth Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0032 }, keyword: "PatientBirthTime", name: "Patient's Birth Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0033 }, keyword: "PatientBirthDateInAlternativeCalendar", name: "Patient's Birth Date in Alternative Calendar", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0034 }, keyword: "PatientDeathDateInAlternativeCalendar", name: "Patient's Death Date in Alternative Calendar", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0035 }, keyword: "PatientAlternativeCalendar", name: "Patient's Alternative Calendar", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0040 }, keyword: "PatientSex", name: "Patient's Sex", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0050 }, keyword: "PatientInsurancePlanCodeSequence", name: "Patient's Insurance Plan Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0101 }, keyword: "PatientPrimaryLanguageCodeSequence", name: "Patient's Primary Language Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0102 }, keyword: "PatientPrimaryLanguageModifierCodeSequence", name: "Patient's Primary Language Modifier Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0200 }, keyword: "QualityControlSubject", name: "Quality Control Subject", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0201 }, keyword: "QualityControlSubjectTypeCodeSequence", name: "Quality Control Subject Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0212 }, keyword: "StrainDescription", name: "Strain Description", vr: &[UNLIMITED_CHARACTER], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0213 }, keyword: "StrainNomenclature", name: "Strain Nomenclature", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0214 }, keyword: "StrainStockNumber", name: "Strain Stock Number", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0215 }, keyword: "StrainSourceRegistryCodeSequence", name: "Strain Source Registry Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0216 }, keyword: "StrainStockSequence", name: "Strain Stock Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0217 }, keyword: "StrainSource", name: "Strain Source", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0218 }, keyword: "StrainAdditionalInformation", name: "Strain Additional Information", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0219 }, keyword: "StrainCodeSequence", name: "Strain Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0221 }, keyword: "GeneticModificationsSequence", name: "Genetic Modifications Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0222 }, keyword: "GeneticModificationsDescription", name: "Genetic Modifications Description", vr: &[UNLIMITED_CHARACTER], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0223 }, keyword: "GeneticModificationsNomenclature", name: "Genetic Modifications Nomenclature", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x0229 }, keyword: "GeneticModificationsCodeSequence", name: "Genetic Modifications Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1000 }, keyword: "OtherPatientIDs", name: "Other Patient IDs", vr: &[LONG_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1001 }, keyword: "OtherPatientNames", name: "Other Patient Names", vr: &[PERSON_NAME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1002 }, keyword: "OtherPatientIDsSequence", name: "Other Patient IDs Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1005 }, keyword: "PatientBirthName", name: "Patient's Birth Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1010 }, keyword: "PatientAge", name: "Patient's Age", vr: &[AGE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1020 }, keyword: "PatientSize", name: "Patient's Size", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1021 }, keyword: "PatientSizeCodeSequence", name: "Patient's Size Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1022 }, keyword: "PatientBodyMassIndex", name: "Patient's Body Mass Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1023 }, keyword: "MeasuredAPDimension", name: "Measured AP Dimension", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1024 }, keyword: "MeasuredLateralDimension", name: "Measured Lateral Dimension", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1030 }, keyword: "PatientWeight", name: "Patient's Weight", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1040 }, keyword: "PatientAddress", name: "Patient's Address", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1050 }, keyword: "InsurancePlanIdentification", name: "Insurance Plan Identification", vr: &[LONG_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1060 }, keyword: "PatientMotherBirthName", name: "Patient's Mother's Birth Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1080 }, keyword: "MilitaryRank", name: "Military Rank", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1081 }, keyword: "BranchOfService", name: "Branch of Service", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1090 }, keyword: "MedicalRecordLocator", name: "Medical Record Locator", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x1100 }, keyword: "ReferencedPatientPhotoSequence", name: "Referenced Patient Photo Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2000 }, keyword: "MedicalAlerts", name: "Medical Alerts", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2110 }, keyword: "Allergies", name: "Allergies", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2150 }, keyword: "CountryOfResidence", name: "Country of Residence", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2152 }, keyword: "RegionOfResidence", name: "Region of Residence", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2154 }, keyword: "PatientTelephoneNumbers", name: "Patient's Telephone Numbers", vr: &[SHORT_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2155 }, keyword: "PatientTelecomInformation", name: "Patient's Telecom Information", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2160 }, keyword: "EthnicGroup", name: "Ethnic Group", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2161 }, keyword: "EthnicGroupCodeSequence", name: "Ethnic Group Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2162 }, keyword: "EthnicGroups", name: "Ethnic Groups", vr: &[UNLIMITED_CHARACTER], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2180 }, keyword: "Occupation", name: "Occupation", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x21A0 }, keyword: "SmokingStatus", name: "Smoking Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x21B0 }, keyword: "AdditionalPatientHistory", name: "Additional Patient History", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x21C0 }, keyword: "PregnancyStatus", name: "Pregnancy Status", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x21D0 }, keyword: "LastMenstrualDate", name: "Last Menstrual Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x21F0 }, keyword: "PatientReligiousPreference", name: "Patient's Religious Preference", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2201 }, keyword: "PatientSpeciesDescription", name: "Patient Species Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2202 }, keyword: "PatientSpeciesCodeSequence", name: "Patient Species Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2203 }, keyword: "PatientSexNeutered", name: "Patient's Sex Neutered", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2210 }, keyword: "AnatomicalOrientationType", name: "Anatomical Orientation Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2292 }, keyword: "PatientBreedDescription", name: "Patient Breed Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2293 }, keyword: "PatientBreedCodeSequence", name: "Patient Breed Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2294 }, keyword: "BreedRegistrationSequence", name: "Breed Registration Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2295 }, keyword: "BreedRegistrationNumber", name: "Breed Registration Number", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2296 }, keyword: "BreedRegistryCodeSequence", name: "Breed Registry Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2297 }, keyword: "ResponsiblePerson", name: "Responsible Person", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2298 }, keyword: "ResponsiblePersonRole", name: "Responsible Person Role", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x2299 }, keyword: "ResponsibleOrganization", name: "Responsible Organization", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x4000 }, keyword: "PatientComments", name: "Patient Comments", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0010, element: 0x9431 }, keyword: "ExaminedBodyThickness", name: "Examined Body Thickness", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0010 }, keyword: "ClinicalTrialSponsorName", name: "Clinical Trial Sponsor Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0020 }, keyword: "ClinicalTrialProtocolID", name: "Clinical Trial Protocol ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0021 }, keyword: "ClinicalTrialProtocolName", name: "Clinical Trial Protocol Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0030 }, keyword: "ClinicalTrialSiteID", name: "Clinical Trial Site ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0031 }, keyword: "ClinicalTrialSiteName", name: "Clinical Trial Site Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0040 }, keyword: "ClinicalTrialSubjectID", name: "Clinical Trial Subject ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0042 }, keyword: "ClinicalTrialSubjectReadingID", name: "Clinical Trial Subject Reading ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0050 }, keyword: "ClinicalTrialTimePointID", name: "Clinical Trial Time Point ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0051 }, keyword: "ClinicalTrialTimePointDescription", name: "Clinical Trial Time Point Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0060 }, keyword: "ClinicalTrialCoordinatingCenterName", name: "Clinical Trial Coordinating Center Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0062 }, keyword: "PatientIdentityRemoved", name: "Patient Identity Removed", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0063 }, keyword: "DeidentificationMethod", name: "De-identification Method", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0064 }, keyword: "DeidentificationMethodCodeSequence", name: "De-identification Method Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0071 }, keyword: "ClinicalTrialSeriesID", name: "Clinical Trial Series ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0072 }, keyword: "ClinicalTrialSeriesDescription", name: "Clinical Trial Series Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0081 }, keyword: "ClinicalTrialProtocolEthicsCommitteeName", name: "Clinical Trial Protocol Ethics Committee Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0082 }, keyword: "ClinicalTrialProtocolEthicsCommitteeApprovalNumber", name: "Clinical Trial Protocol Ethics Committee Approval Number", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0083 }, keyword: "ConsentForClinicalTrialUseSequence", name: "Consent for Clinical Trial Use Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0084 }, keyword: "DistributionType", name: "Distribution Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0085 }, keyword: "ConsentForDistributionFlag", name: "Consent for Distribution Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0086 }, keyword: "EthicsCommitteeApprovalEffectivenessStartDate", name: "Ethics Committee Approval Effectiveness Start Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0012, element: 0x0087 }, keyword: "EthicsCommitteeApprovalEffectivenessEndDate", name: "Ethics Committee Approval Effectiveness End Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0010 }, keyword: "ContrastBolusAgent", name: "Contrast/Bolus Agent", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0012 }, keyword: "ContrastBolusAgentSequence", name: "Contrast/Bolus Agent Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0013 }, keyword: "ContrastBolusT1Relaxivity", name: "Contrast/Bolus T1 Relaxivity", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0014 }, keyword: "ContrastBolusAdministrationRouteSequence", name: "Contrast/Bolus Administration Route Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0015 }, keyword: "BodyPartExamined", name: "Body Part Examined", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0020 }, keyword: "ScanningSequence", name: "Scanning Sequence", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0021 }, keyword: "SequenceVariant", name: "Sequence Variant", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0022 }, keyword: "ScanOptions", name: "Scan Options", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0023 }, keyword: "MRAcquisitionType", name: "MR Acquisition Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0024 }, keyword: "SequenceName", name: "Sequence Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0025 }, keyword: "AngioFlag", name: "Angio Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0026 }, keyword: "InterventionDrugInformationSequence", name: "Intervention Drug Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0027 }, keyword: "InterventionDrugStopTime", name: "Intervention Drug Stop Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0028 }, keyword: "InterventionDrugDose", name: "Intervention Drug Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0029 }, keyword: "InterventionDrugCodeSequence", name: "Intervention Drug Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x002A }, keyword: "AdditionalDrugSequence", name: "Additional Drug Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0030 }, keyword: "Radionuclide", name: "Radionuclide", vr: &[LONG_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0031 }, keyword: "Radiopharmaceutical", name: "Radiopharmaceutical", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0032 }, keyword: "EnergyWindowCenterline", name: "Energy Window Centerline", vr: &[DECIMAL_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0033 }, keyword: "EnergyWindowTotalWidth", name: "Energy Window Total Width", vr: &[DECIMAL_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0034 }, keyword: "InterventionDrugName", name: "Intervention Drug Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0035 }, keyword: "InterventionDrugStartTime", name: "Intervention Drug Start Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0036 }, keyword: "InterventionSequence", name: "Intervention Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0037 }, keyword: "TherapyType", name: "Therapy Type", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0038 }, keyword: "InterventionStatus", name: "Intervention Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0039 }, keyword: "TherapyDescription", name: "Therapy Description", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x003A }, keyword: "InterventionDescription", name: "Intervention Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0040 }, keyword: "CineRate", name: "Cine Rate", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0042 }, keyword: "InitialCineRunState", name: "Initial Cine Run State", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0050 }, keyword: "SliceThickness", name: "Slice Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0060 }, keyword: "KVP", name: "KVP", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0070 }, keyword: "CountsAccumulated", name: "Counts Accumulated", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0071 }, keyword: "AcquisitionTerminationCondition", name: "Acquisition Termination Condition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0072 }, keyword: "EffectiveDuration", name: "Effective Duration", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0073 }, keyword: "AcquisitionStartCondition", name: "Acquisition Start Condition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0074 }, keyword: "AcquisitionStartConditionData", name: "Acquisition Start Condition Data", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0075 }, keyword: "AcquisitionTerminationConditionData", name: "Acquisition Termination Condition Data", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0080 }, keyword: "RepetitionTime", name: "Repetition Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0081 }, keyword: "EchoTime", name: "Echo Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0082 }, keyword: "InversionTime", name: "Inversion Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0083 }, keyword: "NumberOfAverages", name: "Number of Averages", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0084 }, keyword: "ImagingFrequency", name: "Imaging Frequency", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0085 }, keyword: "ImagedNucleus", name: "Imaged Nucleus", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0086 }, keyword: "EchoNumbers", name: "Echo Number(s)", vr: &[INTEGER_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0087 }, keyword: "MagneticFieldStrength", name: "Magnetic Field Strength", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0088 }, keyword: "SpacingBetweenSlices", name: "Spacing Between Slices", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0089 }, keyword: "NumberOfPhaseEncodingSteps", name: "Number of Phase Encoding Steps", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0090 }, keyword: "DataCollectionDiameter", name: "Data Collection Diameter", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0091 }, keyword: "EchoTrainLength", name: "Echo Train Length", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0093 }, keyword: "PercentSampling", name: "Percent Sampling", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0094 }, keyword: "PercentPhaseFieldOfView", name: "Percent Phase Field of View", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x0095 }, keyword: "PixelBandwidth", name: "Pixel Bandwidth", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1000 }, keyword: "DeviceSerialNumber", name: "Device Serial Number", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1002 }, keyword: "DeviceUID", name: "Device UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1003 }, keyword: "DeviceID", name: "Device ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1004 }, keyword: "PlateID", name: "Plate ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1005 }, keyword: "GeneratorID", name: "Generator ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1006 }, keyword: "GridID", name: "Grid ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1007 }, keyword: "CassetteID", name: "Cassette ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1008 }, keyword: "GantryID", name: "Gantry ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1009 }, keyword: "UniqueDeviceIdentifier", name: "Unique Device Identifier", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x100A }, keyword: "UDISequence", name: "UDI Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x100B }, keyword: "ManufacturerDeviceClassUID", name: "Manufacturer's Device Class UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1010 }, keyword: "SecondaryCaptureDeviceID", name: "Secondary Capture Device ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1011 }, keyword: "HardcopyCreationDeviceID", name: "Hardcopy Creation Device ID", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1012 }, keyword: "DateOfSecondaryCapture", name: "Date of Secondary Capture", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1014 }, keyword: "TimeOfSecondaryCapture", name: "Time of Secondary Capture", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1016 }, keyword: "SecondaryCaptureDeviceManufacturer", name: "Secondary Capture Device Manufacturer", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1017 }, keyword: "HardcopyDeviceManufacturer", name: "Hardcopy Device Manufacturer", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1018 }, keyword: "SecondaryCaptureDeviceManufacturerModelName", name: "Secondary Capture Device Manufacturer's Model Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1019 }, keyword: "SecondaryCaptureDeviceSoftwareVersions", name: "Secondary Capture Device Software Versions", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x101A }, keyword: "HardcopyDeviceSoftwareVersion", name: "Hardcopy Device Software Version", vr: &[LONG_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x101B }, keyword: "HardcopyDeviceManufacturerModelName", name: "Hardcopy Device Manufacturer's Model Name", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1020 }, keyword: "SoftwareVersions", name: "Software Versions", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1022 }, keyword: "VideoImageFormatAcquired", name: "Video Image Format Acquired", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1023 }, keyword: "DigitalImageFormatAcquired", name: "Digital Image Format Acquired", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1030 }, keyword: "ProtocolName", name: "Protocol Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1040 }, keyword: "ContrastBolusRoute", name: "Contrast/Bolus Route", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1041 }, keyword: "ContrastBolusVolume", name: "Contrast/Bolus Volume", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1042 }, keyword: "ContrastBolusStartTime", name: "Contrast/Bolus Start Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1043 }, keyword: "ContrastBolusStopTime", name: "Contrast/Bolus Stop Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1044 }, keyword: "ContrastBolusTotalDose", name: "Contrast/Bolus Total Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1045 }, keyword: "SyringeCounts", name: "Syringe Counts", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1046 }, keyword: "ContrastFlowRate", name: "Contrast Flow Rate", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1047 }, keyword: "ContrastFlowDuration", name: "Contrast Flow Duration", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1048 }, keyword: "ContrastBolusIngredient", name: "Contrast/Bolus Ingredient", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1049 }, keyword: "ContrastBolusIngredientConcentration", name: "Contrast/Bolus Ingredient Concentration", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1050 }, keyword: "SpatialResolution", name: "Spatial Resolution", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1060 }, keyword: "TriggerTime", name: "Trigger Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1061 }, keyword: "TriggerSourceOrType", name: "Trigger Source or Type", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1062 }, keyword: "NominalInterval", name: "Nominal Interval", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1063 }, keyword: "FrameTime", name: "Frame Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1064 }, keyword: "CardiacFramingType", name: "Cardiac Framing Type", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1065 }, keyword: "FrameTimeVector", name: "Frame Time Vector", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1066 }, keyword: "FrameDelay", name: "Frame Delay", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1067 }, keyword: "ImageTriggerDelay", name: "Image Trigger Delay", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1068 }, keyword: "MultiplexGroupTimeOffset", name: "Multiplex Group Time Offset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1069 }, keyword: "TriggerTimeOffset", name: "Trigger Time Offset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x106A }, keyword: "SynchronizationTrigger", name: "Synchronization Trigger", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x106C }, keyword: "SynchronizationChannel", name: "Synchronization Channel", vr: &[UNSIGNED_SHORT], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x106E }, keyword: "TriggerSamplePosition", name: "Trigger Sample Position", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1070 }, keyword: "RadiopharmaceuticalRoute", name: "Radiopharmaceutical Route", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1071 }, keyword: "RadiopharmaceuticalVolume", name: "Radiopharmaceutical Volume", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1072 }, keyword: "RadiopharmaceuticalStartTime", name: "Radiopharmaceutical Start Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1073 }, keyword: "RadiopharmaceuticalStopTime", name: "Radiopharmaceutical Stop Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1074 }, keyword: "RadionuclideTotalDose", name: "Radionuclide Total Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1075 }, keyword: "RadionuclideHalfLife", name: "Radionuclide Half Life", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1076 }, keyword: "RadionuclidePositronFraction", name: "Radionuclide Positron Fraction", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1077 }, keyword: "RadiopharmaceuticalSpecificActivity", name: "Radiopharmaceutical Specific Activity", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1078 }, keyword: "RadiopharmaceuticalStartDateTime", name: "Radiopharmaceutical Start DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1079 }, keyword: "RadiopharmaceuticalStopDateTime", name: "Radiopharmaceutical Stop DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1080 }, keyword: "BeatRejectionFlag", name: "Beat Rejection Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1081 }, keyword: "LowRRValue", name: "Low R-R Value", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1082 }, keyword: "HighRRValue", name: "High R-R Value", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1083 }, keyword: "IntervalsAcquired", name: "Intervals Acquired", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1084 }, keyword: "IntervalsRejected", name: "Intervals Rejected", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1085 }, keyword: "PVCRejection", name: "PVC Rejection", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1086 }, keyword: "SkipBeats", name: "Skip Beats", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1088 }, keyword: "HeartRate", name: "Heart Rate", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1090 }, keyword: "CardiacNumberOfImages", name: "Cardiac Number of Images", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1094 }, keyword: "TriggerWindow", name: "Trigger Window", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1100 }, keyword: "ReconstructionDiameter", name: "Reconstruction Diameter", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1110 }, keyword: "DistanceSourceToDetector", name: "Distance Source to Detector", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1111 }, keyword: "DistanceSourceToPatient", name: "Distance Source to Patient", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1114 }, keyword: "EstimatedRadiographicMagnificationFactor", name: "Estimated Radiographic Magnification Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1120 }, keyword: "GantryDetectorTilt", name: "Gantry/Detector Tilt", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1121 }, keyword: "GantryDetectorSlew", name: "Gantry/Detector Slew", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1130 }, keyword: "TableHeight", name: "Table Height", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1131 }, keyword: "TableTraverse", name: "Table Traverse", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1134 }, keyword: "TableMotion", name: "Table Motion", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1135 }, keyword: "TableVerticalIncrement", name: "Table Vertical Increment", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1136 }, keyword: "TableLateralIncrement", name: "Table Lateral Increment", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1137 }, keyword: "TableLongitudinalIncrement", name: "Table Longitudinal Increment", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1138 }, keyword: "TableAngle", name: "Table Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x113A }, keyword: "TableType", name: "Table Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1140 }, keyword: "RotationDirection", name: "Rotation Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1141 }, keyword: "AngularPosition", name: "Angular Position", vr: &[DECIMAL_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1142 }, keyword: "RadialPosition", name: "Radial Position", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1143 }, keyword: "ScanArc", name: "Scan Arc", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1144 }, keyword: "AngularStep", name: "Angular Step", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1145 }, keyword: "CenterOfRotationOffset", name: "Center of Rotation Offset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1146 }, keyword: "RotationOffset", name: "Rotation Offset", vr: &[DECIMAL_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1147 }, keyword: "FieldOfViewShape", name: "Field of View Shape", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1149 }, keyword: "FieldOfViewDimensions", name: "Field of View Dimension(s)", vr: &[INTEGER_STRING], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1150 }, keyword: "ExposureTime", name: "Exposure Time", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1151 }, keyword: "XRayTubeCurrent", name: "X-Ray Tube Current", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1152 }, keyword: "Exposure", name: "Exposure", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1153 }, keyword: "ExposureInuAs", name: "Exposure in uAs", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1154 }, keyword: "AveragePulseWidth", name: "Average Pulse Width", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1155 }, keyword: "RadiationSetting", name: "Radiation Setting", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1156 }, keyword: "RectificationType", name: "Rectification Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x115A }, keyword: "RadiationMode", name: "Radiation Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x115E }, keyword: "ImageAndFluoroscopyAreaDoseProduct", name: "Image and Fluoroscopy Area Dose Product", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1160 }, keyword: "FilterType", name: "Filter Type", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1161 }, keyword: "TypeOfFilters", name: "Type of Filters", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1162 }, keyword: "IntensifierSize", name: "Intensifier Size", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1164 }, keyword: "ImagerPixelSpacing", name: "Imager Pixel Spacing", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1166 }, keyword: "Grid", name: "Grid", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1170 }, keyword: "GeneratorPower", name: "Generator Power", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1180 }, keyword: "CollimatorGridName", name: "Collimator/grid Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1181 }, keyword: "CollimatorType", name: "Collimator Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1182 }, keyword: "FocalDistance", name: "Focal Distance", vr: &[INTEGER_STRING], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1183 }, keyword: "XFocusCenter", name: "X Focus Center", vr: &[DECIMAL_STRING], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1184 }, keyword: "YFocusCenter", name: "Y Focus Center", vr: &[DECIMAL_STRING], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1190 }, keyword: "FocalSpots", name: "Focal Spot(s)", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1191 }, keyword: "AnodeTargetMaterial", name: "Anode Target Material", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x11A0 }, keyword: "BodyPartThickness", name: "Body Part Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x11A2 }, keyword: "CompressionForce", name: "Compression Force", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x11A3 }, keyword: "CompressionPressure", name: "Compression Pressure", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x11A4 }, keyword: "PaddleDescription", name: "Paddle Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x11A5 }, keyword: "CompressionContactArea", name: "Compression Contact Area", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1200 }, keyword: "DateOfLastCalibration", name: "Date of Last Calibration", vr: &[DATE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1201 }, keyword: "TimeOfLastCalibration", name: "Time of Last Calibration", vr: &[TIME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1202 }, keyword: "DateTimeOfLastCalibration", name: "DateTime of Last Calibration", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1210 }, keyword: "ConvolutionKernel", name: "Convolution Kernel", vr: &[SHORT_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1240 }, keyword: "UpperLowerPixelValues", name: "Upper/Lower Pixel Values", vr: &[INTEGER_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1242 }, keyword: "ActualFrameDuration", name: "Actual Frame Duration", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1243 }, keyword: "CountRate", name: "Count Rate", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1250 }, keyword: "ReceiveCoilName", name: "Receive Coil Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1251 }, keyword: "TransmitCoilName", name: "Transmit Coil Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1260 }, keyword: "PlateType", name: "Plate Type", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1261 }, keyword: "PhosphorType", name: "Phosphor Type", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1271 }, keyword: "WaterEquivalentDiameter", name: "Water Equivalent Diameter", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1272 }, keyword: "WaterEquivalentDiameterCalculationMethodCodeSequence", name: "Water Equivalent Diameter Calculation Method Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1300 }, keyword: "ScanVelocity", name: "Scan Velocity", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1301 }, keyword: "WholeBodyTechnique", name: "Whole Body Technique", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1302 }, keyword: "ScanLength", name: "Scan Length", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1310 }, keyword: "AcquisitionMatrix", name: "Acquisition Matrix", vr: &[UNSIGNED_SHORT], vm: "4", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1312 }, keyword: "InPlanePhaseEncodingDirection", name: "In-plane Phase Encoding Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1314 }, keyword: "FlipAngle", name: "Flip Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1315 }, keyword: "VariableFlipAngleFlag", name: "Variable Flip Angle Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1316 }, keyword: "SAR", name: "SAR", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1318 }, keyword: "dBdt", name: "dB/dt", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1320 }, keyword: "B1rms", name: "B1rms", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1400 }, keyword: "AcquisitionDeviceProcessingDescription", name: "Acquisition Device Processing Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1401 }, keyword: "AcquisitionDeviceProcessingCode", name: "Acquisition Device Processing Code", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1402 }, keyword: "CassetteOrientation", name: "Cassette Orientation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1403 }, keyword: "CassetteSize", name: "Cassette Size", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1404 }, keyword: "ExposuresOnPlate", name: "Exposures on Plate", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1405 }, keyword: "RelativeXRayExposure", name: "Relative X-Ray Exposure", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1411 }, keyword: "ExposureIndex", name: "Exposure Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1412 }, keyword: "TargetExposureIndex", name: "Target Exposure Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1413 }, keyword: "DeviationIndex", name: "Deviation Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1450 }, keyword: "ColumnAngulation", name: "Column Angulation", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1460 }, keyword: "TomoLayerHeight", name: "Tomo Layer Height", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1470 }, keyword: "TomoAngle", name: "Tomo Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1480 }, keyword: "TomoTime", name: "Tomo Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1490 }, keyword: "TomoType", name: "Tomo Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1491 }, keyword: "TomoClass", name: "Tomo Class", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1495 }, keyword: "NumberOfTomosynthesisSourceImages", name: "Number of Tomosynthesis Source Images", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1500 }, keyword: "PositionerMotion", name: "Positioner Motion", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1508 }, keyword: "PositionerType", name: "Positioner Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1510 }, keyword: "PositionerPrimaryAngle", name: "Positioner Primary Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1511 }, keyword: "PositionerSecondaryAngle", name: "Positioner Secondary Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1520 }, keyword: "PositionerPrimaryAngleIncrement", name: "Positioner Primary Angle Increment", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1521 }, keyword: "PositionerSecondaryAngleIncrement", name: "Positioner Secondary Angle Increment", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1530 }, keyword: "DetectorPrimaryAngle", name: "Detector Primary Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1531 }, keyword: "DetectorSecondaryAngle", name: "Detector Secondary Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1600 }, keyword: "ShutterShape", name: "Shutter Shape", vr: &[CODE_STRING], vm: "1-3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1602 }, keyword: "ShutterLeftVerticalEdge", name: "Shutter Left Vertical Edge", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1604 }, keyword: "ShutterRightVerticalEdge", name: "Shutter Right Vertical Edge", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1606 }, keyword: "ShutterUpperHorizontalEdge", name: "Shutter Upper Horizontal Edge", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1608 }, keyword: "ShutterLowerHorizontalEdge", name: "Shutter Lower Horizontal Edge", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1610 }, keyword: "CenterOfCircularShutter", name: "Center of Circular Shutter", vr: &[INTEGER_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1612 }, keyword: "RadiusOfCircularShutter", name: "Radius of Circular Shutter", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1620 }, keyword: "VerticesOfThePolygonalShutter", name: "Vertices of the Polygonal Shutter", vr: &[INTEGER_STRING], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1622 }, keyword: "ShutterPresentationValue", name: "Shutter Presentation Value", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1623 }, keyword: "ShutterOverlayGroup", name: "Shutter Overlay Group", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1624 }, keyword: "ShutterPresentationColorCIELabValue", name: "Shutter Presentation Color CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1700 }, keyword: "CollimatorShape", name: "Collimator Shape", vr: &[CODE_STRING], vm: "1-3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1702 }, keyword: "CollimatorLeftVerticalEdge", name: "Collimator Left Vertical Edge", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1704 }, keyword: "CollimatorRightVerticalEdge", name: "Collimator Right Vertical Edge", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1706 }, keyword: "CollimatorUpperHorizontalEdge", name: "Collimator Upper Horizontal Edge", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1708 }, keyword: "CollimatorLowerHorizontalEdge", name: "Collimator Lower Horizontal Edge", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1710 }, keyword: "CenterOfCircularCollimator", name: "Center of Circular Collimator", vr: &[INTEGER_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1712 }, keyword: "RadiusOfCircularCollimator", name: "Radius of Circular Collimator", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1720 }, keyword: "VerticesOfThePolygonalCollimator", name: "Vertices of the Polygonal Collimator", vr: &[INTEGER_STRING], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1800 }, keyword: "AcquisitionTimeSynchronized", name: "Acquisition Time Synchronized", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1801 }, keyword: "TimeSource", name: "Time Source", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1802 }, keyword: "TimeDistributionProtocol", name: "Time Distribution Protocol", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x1803 }, keyword: "NTPSourceAddress", name: "NTP Source Address", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x2001 }, keyword: "PageNumberVector", name: "Page Number Vector", vr: &[INTEGER_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x2002 }, keyword: "FrameLabelVector", name: "Frame Label Vector", vr: &[SHORT_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x2003 }, keyword: "FramePrimaryAngleVector", name: "Frame Primary Angle Vector", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x2004 }, keyword: "FrameSecondaryAngleVector", name: "Frame Secondary Angle Vector", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x2005 }, keyword: "SliceLocationVector", name: "Slice Location Vector", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x2006 }, keyword: "DisplayWindowLabelVector", name: "Display Window Label Vector", vr: &[SHORT_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x2010 }, keyword: "NominalScannedPixelSpacing", name: "Nominal Scanned Pixel Spacing", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x2020 }, keyword: "DigitizingDeviceTransportDirection", name: "Digitizing Device Transport Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x2030 }, keyword: "RotationOfScannedFilm", name: "Rotation of Scanned Film", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x3100 }, keyword: "IVUSAcquisition", name: "IVUS Acquisition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x3101 }, keyword: "IVUSPullbackRate", name: "IVUS Pullback Rate", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x3102 }, keyword: "IVUSGatedRate", name: "IVUS Gated Rate", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x3103 }, keyword: "IVUSPullbackStartFrameNumber", name: "IVUS Pullback Start Frame Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x3104 }, keyword: "IVUSPullbackStopFrameNumber", name: "IVUS Pullback Stop Frame Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x3105 }, keyword: "LesionNumber", name: "Lesion Number", vr: &[INTEGER_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x4000 }, keyword: "AcquisitionComments", name: "Acquisition Comments", vr: &[LONG_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5000 }, keyword: "OutputPower", name: "Output Power", vr: &[SHORT_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5010 }, keyword: "TransducerData", name: "Transducer Data", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5012 }, keyword: "FocusDepth", name: "Focus Depth", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5020 }, keyword: "ProcessingFunction", name: "Processing Function", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5021 }, keyword: "PostprocessingFunction", name: "Postprocessing Function", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5022 }, keyword: "MechanicalIndex", name: "Mechanical Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5024 }, keyword: "BoneThermalIndex", name: "Bone Thermal Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5026 }, keyword: "CranialThermalIndex", name: "Cranial Thermal Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5027 }, keyword: "SoftTissueThermalIndex", name: "Soft Tissue Thermal Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5028 }, keyword: "SoftTissueFocusThermalIndex", name: "Soft Tissue-focus Thermal Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5029 }, keyword: "SoftTissueSurfaceThermalIndex", name: "Soft Tissue-surface Thermal Index", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5050 }, keyword: "DepthOfScanField", name: "Depth of Scan Field", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5100 }, keyword: "PatientPosition", name: "Patient Position", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5101 }, keyword: "ViewPosition", name: "View Position", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x5104 }, keyword: "ProjectionEponymousNameCodeSequence", name: "Projection Eponymous Name Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6000 }, keyword: "Sensitivity", name: "Sensitivity", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6011 }, keyword: "SequenceOfUltrasoundRegions", name: "Sequence of Ultrasound Regions", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6012 }, keyword: "RegionSpatialFormat", name: "Region Spatial Format", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6014 }, keyword: "RegionDataType", name: "Region Data Type", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6016 }, keyword: "RegionFlags", name: "Region Flags", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6018 }, keyword: "RegionLocationMinX0", name: "Region Location Min X0", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x601A }, keyword: "RegionLocationMinY0", name: "Region Location Min Y0", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x601C }, keyword: "RegionLocationMaxX1", name: "Region Location Max X1", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x601E }, keyword: "RegionLocationMaxY1", name: "Region Location Max Y1", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6020 }, keyword: "ReferencePixelX0", name: "Reference Pixel X0", vr: &[SIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6022 }, keyword: "ReferencePixelY0", name: "Reference Pixel Y0", vr: &[SIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6024 }, keyword: "PhysicalUnitsXDirection", name: "Physical Units X Direction", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6026 }, keyword: "PhysicalUnitsYDirection", name: "Physical Units Y Direction", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6028 }, keyword: "ReferencePixelPhysicalValueX", name: "Reference Pixel Physical Value X", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x602A }, keyword: "ReferencePixelPhysicalValueY", name: "Reference Pixel Physical Value Y", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x602C }, keyword: "PhysicalDeltaX", name: "Physical Delta X", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x602E }, keyword: "PhysicalDeltaY", name: "Physical Delta Y", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6030 }, keyword: "TransducerFrequency", name: "Transducer Frequency", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6031 }, keyword: "TransducerType", name: "Transducer Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6032 }, keyword: "PulseRepetitionFrequency", name: "Pulse Repetition Frequency", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6034 }, keyword: "DopplerCorrectionAngle", name: "Doppler Correction Angle", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6036 }, keyword: "SteeringAngle", name: "Steering Angle", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6039 }, keyword: "DopplerSampleVolumeXPosition", name: "Doppler Sample Volume X Position", vr: &[SIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x603B }, keyword: "DopplerSampleVolumeYPosition", name: "Doppler Sample Volume Y Position", vr: &[SIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x603D }, keyword: "TMLinePositionX0", name: "TM-Line Position X0", vr: &[SIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x603F }, keyword: "TMLinePositionY0", name: "TM-Line Position Y0", vr: &[SIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6041 }, keyword: "TMLinePositionX1", name: "TM-Line Position X1", vr: &[SIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6043 }, keyword: "TMLinePositionY1", name: "TM-Line Position Y1", vr: &[SIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6044 }, keyword: "PixelComponentOrganization", name: "Pixel Component Organization", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6046 }, keyword: "PixelComponentMask", name: "Pixel Component Mask", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6048 }, keyword: "PixelComponentRangeStart", name: "Pixel Component Range Start", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x604A }, keyword: "PixelComponentRangeStop", name: "Pixel Component Range Stop", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x604C }, keyword: "PixelComponentPhysicalUnits", name: "Pixel Component Physical Units", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x604E }, keyword: "PixelComponentDataType", name: "Pixel Component Data Type", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6050 }, keyword: "NumberOfTableBreakPoints", name: "Number of Table Break Points", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6052 }, keyword: "TableOfXBreakPoints", name: "Table of X Break Points", vr: &[UNSIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6054 }, keyword: "TableOfYBreakPoints", name: "Table of Y Break Points", vr: &[FLOATING_POINT_DOUBLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6056 }, keyword: "NumberOfTableEntries", name: "Number of Table Entries", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x6058 }, keyword: "TableOfPixelValues", name: "Table of Pixel Values", vr: &[UNSIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x605A }, keyword: "TableOfParameterValues", name: "Table of Parameter Values", vr: &[FLOATING_POINT_SINGLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7000 }, keyword: "DetectorConditionsNominalFlag", name: "Detector Conditions Nominal Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7001 }, keyword: "DetectorTemperature", name: "Detector Temperature", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7004 }, keyword: "DetectorType", name: "Detector Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7005 }, keyword: "DetectorConfiguration", name: "Detector Configuration", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7006 }, keyword: "DetectorDescription", name: "Detector Description", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7008 }, keyword: "DetectorMode", name: "Detector Mode", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x700A }, keyword: "DetectorID", name: "Detector ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x700C }, keyword: "DateOfLastDetectorCalibration", name: "Date of Last Detector Calibration", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x700E }, keyword: "TimeOfLastDetectorCalibration", name: "Time of Last Detector Calibration", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7010 }, keyword: "ExposuresOnDetectorSinceLastCalibration", name: "Exposures on Detector Since Last Calibration", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7011 }, keyword: "ExposuresOnDetectorSinceManufactured", name: "Exposures on Detector Since Manufactured", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7012 }, keyword: "DetectorTimeSinceLastExposure", name: "Detector Time Since Last Exposure", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7014 }, keyword: "DetectorActiveTime", name: "Detector Active Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7016 }, keyword: "DetectorActivationOffsetFromExposure", name: "Detector Activation Offset From Exposure", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x701A }, keyword: "DetectorBinning", name: "Detector Binning", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7020 }, keyword: "DetectorElementPhysicalSize", name: "Detector Element Physical Size", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7022 }, keyword: "DetectorElementSpacing", name: "Detector Element Spacing", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7024 }, keyword: "DetectorActiveShape", name: "Detector Active Shape", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7026 }, keyword: "DetectorActiveDimensions", name: "Detector Active Dimension(s)", vr: &[DECIMAL_STRING], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7028 }, keyword: "DetectorActiveOrigin", name: "Detector Active Origin", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x702A }, keyword: "DetectorManufacturerName", name: "Detector Manufacturer Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x702B }, keyword: "DetectorManufacturerModelName", name: "Detector Manufacturer's Model Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7030 }, keyword: "FieldOfViewOrigin", name: "Field of View Origin", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7032 }, keyword: "FieldOfViewRotation", name: "Field of View Rotation", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7034 }, keyword: "FieldOfViewHorizontalFlip", name: "Field of View Horizontal Flip", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7036 }, keyword: "PixelDataAreaOriginRelativeToFOV", name: "Pixel Data Area Origin Relative To FOV", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7038 }, keyword: "PixelDataAreaRotationAngleRelativeToFOV", name: "Pixel Data Area Rotation Angle Relative To FOV", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7040 }, keyword: "GridAbsorbingMaterial", name: "Grid Absorbing Material", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7041 }, keyword: "GridSpacingMaterial", name: "Grid Spacing Material", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7042 }, keyword: "GridThickness", name: "Grid Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7044 }, keyword: "GridPitch", name: "Grid Pitch", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7046 }, keyword: "GridAspectRatio", name: "Grid Aspect Ratio", vr: &[INTEGER_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7048 }, keyword: "GridPeriod", name: "Grid Period", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x704C }, keyword: "GridFocalDistance", name: "Grid Focal Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7050 }, keyword: "FilterMaterial", name: "Filter Material", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7052 }, keyword: "FilterThicknessMinimum", name: "Filter Thickness Minimum", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7054 }, keyword: "FilterThicknessMaximum", name: "Filter Thickness Maximum", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7056 }, keyword: "FilterBeamPathLengthMinimum", name: "Filter Beam Path Length Minimum", vr: &[FLOATING_POINT_SINGLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7058 }, keyword: "FilterBeamPathLengthMaximum", name: "Filter Beam Path Length Maximum", vr: &[FLOATING_POINT_SINGLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7060 }, keyword: "ExposureControlMode", name: "Exposure Control Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7062 }, keyword: "ExposureControlModeDescription", name: "Exposure Control Mode Description", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7064 }, keyword: "ExposureStatus", name: "Exposure Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x7065 }, keyword: "PhototimerSetting", name: "Phototimer Setting", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x8150 }, keyword: "ExposureTimeInuS", name: "Exposure Time in uS", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x8151 }, keyword: "XRayTubeCurrentInuA", name: "X-Ray Tube Current in uA", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9004 }, keyword: "ContentQualification", name: "Content Qualification", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9005 }, keyword: "PulseSequenceName", name: "Pulse Sequence Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9006 }, keyword: "MRImagingModifierSequence", name: "MR Imaging Modifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9008 }, keyword: "EchoPulseSequence", name: "Echo Pulse Sequence", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9009 }, keyword: "InversionRecovery", name: "Inversion Recovery", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9010 }, keyword: "FlowCompensation", name: "Flow Compensation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9011 }, keyword: "MultipleSpinEcho", name: "Multiple Spin Echo", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9012 }, keyword: "MultiPlanarExcitation", name: "Multi-planar Excitation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9014 }, keyword: "PhaseContrast", name: "Phase Contrast", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9015 }, keyword: "TimeOfFlightContrast", name: "Time of Flight Contrast", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9016 }, keyword: "Spoiling", name: "Spoiling", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9017 }, keyword: "SteadyStatePulseSequence", name: "Steady State Pulse Sequence", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9018 }, keyword: "EchoPlanarPulseSequence", name: "Echo Planar Pulse Sequence", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9019 }, keyword: "TagAngleFirstAxis", name: "Tag Angle First Axis", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9020 }, keyword: "MagnetizationTransfer", name: "Magnetization Transfer", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9021 }, keyword: "T2Preparation", name: "T2 Preparation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9022 }, keyword: "BloodSignalNulling", name: "Blood Signal Nulling", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9024 }, keyword: "SaturationRecovery", name: "Saturation Recovery", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9025 }, keyword: "SpectrallySelectedSuppression", name: "Spectrally Selected Suppression", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9026 }, keyword: "SpectrallySelectedExcitation", name: "Spectrally Selected Excitation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9027 }, keyword: "SpatialPresaturation", name: "Spatial Pre-saturation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9028 }, keyword: "Tagging", name: "Tagging", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9029 }, keyword: "OversamplingPhase", name: "Oversampling Phase", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9030 }, keyword: "TagSpacingFirstDimension", name: "Tag Spacing First Dimension", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9032 }, keyword: "GeometryOfKSpaceTraversal", name: "Geometry of k-Space Traversal", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9033 }, keyword: "SegmentedKSpaceTraversal", name: "Segmented k-Space Traversal", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9034 }, keyword: "RectilinearPhaseEncodeReordering", name: "Rectilinear Phase Encode Reordering", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9035 }, keyword: "TagThickness", name: "Tag Thickness", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9036 }, keyword: "PartialFourierDirection", name: "Partial Fourier Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9037 }, keyword: "CardiacSynchronizationTechnique", name: "Cardiac Synchronization Technique", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9041 }, keyword: "ReceiveCoilManufacturerName", name: "Receive Coil Manufacturer Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9042 }, keyword: "MRReceiveCoilSequence", name: "MR Receive Coil Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9043 }, keyword: "ReceiveCoilType", name: "Receive Coil Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9044 }, keyword: "QuadratureReceiveCoil", name: "Quadrature Receive Coil", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9045 }, keyword: "MultiCoilDefinitionSequence", name: "Multi-Coil Definition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9046 }, keyword: "MultiCoilConfiguration", name: "Multi-Coil Configuration", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9047 }, keyword: "MultiCoilElementName", name: "Multi-Coil Element Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9048 }, keyword: "MultiCoilElementUsed", name: "Multi-Coil Element Used", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9049 }, keyword: "MRTransmitCoilSequence", name: "MR Transmit Coil Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9050 }, keyword: "TransmitCoilManufacturerName", name: "Transmit Coil Manufacturer Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9051 }, keyword: "TransmitCoilType", name: "Transmit Coil Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9052 }, keyword: "SpectralWidth", name: "Spectral Width", vr: &[FLOATING_POINT_DOUBLE], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9053 }, keyword: "ChemicalShiftReference", name: "Chemical Shift Reference", vr: &[FLOATING_POINT_DOUBLE], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9054 }, keyword: "VolumeLocalizationTechnique", name: "Volume Localization Technique", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9058 }, keyword: "MRAcquisitionFrequencyEncodingSteps", name: "MR Acquisition Frequency Encoding Steps", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9059 }, keyword: "Decoupling", name: "De-coupling", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9060 }, keyword: "DecoupledNucleus", name: "De-coupled Nucleus", vr: &[CODE_STRING], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9061 }, keyword: "DecouplingFrequency", name: "De-coupling Frequency", vr: &[FLOATING_POINT_DOUBLE], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9062 }, keyword: "DecouplingMethod", name: "De-coupling Method", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9063 }, keyword: "DecouplingChemicalShiftReference", name: "De-coupling Chemical Shift Reference", vr: &[FLOATING_POINT_DOUBLE], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9064 }, keyword: "KSpaceFiltering", name: "k-space Filtering", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9065 }, keyword: "TimeDomainFiltering", name: "Time Domain Filtering", vr: &[CODE_STRING], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9066 }, keyword: "NumberOfZeroFills", name: "Number of Zero Fills", vr: &[UNSIGNED_SHORT], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9067 }, keyword: "BaselineCorrection", name: "Baseline Correction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9069 }, keyword: "ParallelReductionFactorInPlane", name: "Parallel Reduction Factor In-plane", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9070 }, keyword: "CardiacRRIntervalSpecified", name: "Cardiac R-R Interval Specified", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9073 }, keyword: "AcquisitionDuration", name: "Acquisition Duration", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9074 }, keyword: "FrameAcquisitionDateTime", name: "Frame Acquisition DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9075 }, keyword: "DiffusionDirectionality", name: "Diffusion Directionality", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9076 }, keyword: "DiffusionGradientDirectionSequence", name: "Diffusion Gradient Direction Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9077 }, keyword: "ParallelAcquisition", name: "Parallel Acquisition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9078 }, keyword: "ParallelAcquisitionTechnique", name: "Parallel Acquisition Technique", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9079 }, keyword: "InversionTimes", name: "Inversion Times", vr: &[FLOATING_POINT_DOUBLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9080 }, keyword: "MetaboliteMapDescription", name: "Metabolite Map Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9081 }, keyword: "PartialFourier", name: "Partial Fourier", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9082 }, keyword: "EffectiveEchoTime", name: "Effective Echo Time", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9083 }, keyword: "MetaboliteMapCodeSequence", name: "Metabolite Map Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9084 }, keyword: "ChemicalShiftSequence", name: "Chemical Shift Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9085 }, keyword: "CardiacSignalSource", name: "Cardiac Signal Source", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9087 }, keyword: "DiffusionBValue", name: "Diffusion b-value", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9089 }, keyword: "DiffusionGradientOrientation", name: "Diffusion Gradient Orientation", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9090 }, keyword: "VelocityEncodingDirection", name: "Velocity Encoding Direction", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9091 }, keyword: "VelocityEncodingMinimumValue", name: "Velocity Encoding Minimum Value", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9092 }, keyword: "VelocityEncodingAcquisitionSequence", name: "Velocity Encoding Acquisition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9093 }, keyword: "NumberOfKSpaceTrajectories", name: "Number of k-Space Trajectories", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9094 }, keyword: "CoverageOfKSpace", name: "Coverage of k-Space", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9095 }, keyword: "SpectroscopyAcquisitionPhaseRows", name: "Spectroscopy Acquisition Phase Rows", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9096 }, keyword: "ParallelReductionFactorInPlaneRetired", name: "Parallel Reduction Factor In-plane (Retired)", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9098 }, keyword: "TransmitterFrequency", name: "Transmitter Frequency", vr: &[FLOATING_POINT_DOUBLE], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9100 }, keyword: "ResonantNucleus", name: "Resonant Nucleus", vr: &[CODE_STRING], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9101 }, keyword: "FrequencyCorrection", name: "Frequency Correction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9103 }, keyword: "MRSpectroscopyFOVGeometrySequence", name: "MR Spectroscopy FOV/Geometry Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9104 }, keyword: "SlabThickness", name: "Slab Thickness", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9105 }, keyword: "SlabOrientation", name: "Slab Orientation", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9106 }, keyword: "MidSlabPosition", name: "Mid Slab Position", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9107 }, keyword: "MRSpatialSaturationSequence", name: "MR Spatial Saturation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9112 }, keyword: "MRTimingAndRelatedParametersSequence", name: "MR Timing and Related Parameters Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9114 }, keyword: "MREchoSequence", name: "MR Echo Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9115 }, keyword: "MRModifierSequence", name: "MR Modifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9117 }, keyword: "MRDiffusionSequence", name: "MR Diffusion Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9118 }, keyword: "CardiacSynchronizationSequence", name: "Cardiac Synchronization Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9119 }, keyword: "MRAveragesSequence", name: "MR Averages Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9125 }, keyword: "MRFOVGeometrySequence", name: "MR FOV/Geometry Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9126 }, keyword: "VolumeLocalizationSequence", name: "Volume Localization Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9127 }, keyword: "SpectroscopyAcquisitionDataColumns", name: "Spectroscopy Acquisition Data Columns", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9147 }, keyword: "DiffusionAnisotropyType", name: "Diffusion Anisotropy Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9151 }, keyword: "FrameReferenceDateTime", name: "Frame Reference DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9152 }, keyword: "MRMetaboliteMapSequence", name: "MR Metabolite Map Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9155 }, keyword: "ParallelReductionFactorOutOfPlane", name: "Parallel Reduction Factor out-of-plane", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9159 }, keyword: "SpectroscopyAcquisitionOutOfPlanePhaseSteps", name: "Spectroscopy Acquisition Out-of-plane Phase Steps", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9166 }, keyword: "BulkMotionStatus", name: "Bulk Motion Status", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9168 }, keyword: "ParallelReductionFactorSecondInPlane", name: "Parallel Reduction Factor Second In-plane", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9169 }, keyword: "CardiacBeatRejectionTechnique", name: "Cardiac Beat Rejection Technique", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9170 }, keyword: "RespiratoryMotionCompensationTechnique", name: "Respiratory Motion Compensation Technique", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9171 }, keyword: "RespiratorySignalSource", name: "Respiratory Signal Source", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9172 }, keyword: "BulkMotionCompensationTechnique", name: "Bulk Motion Compensation Technique", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9173 }, keyword: "BulkMotionSignalSource", name: "Bulk Motion Signal Source", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9174 }, keyword: "ApplicableSafetyStandardAgency", name: "Applicable Safety Standard Agency", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9175 }, keyword: "ApplicableSafetyStandardDescription", name: "Applicable Safety Standard Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9176 }, keyword: "OperatingModeSequence", name: "Operating Mode Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9177 }, keyword: "OperatingModeType", name: "Operating Mode Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9178 }, keyword: "OperatingMode", name: "Operating Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9179 }, keyword: "SpecificAbsorptionRateDefinition", name: "Specific Absorption Rate Definition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9180 }, keyword: "GradientOutputType", name: "Gradient Output Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9181 }, keyword: "SpecificAbsorptionRateValue", name: "Specific Absorption Rate Value", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9182 }, keyword: "GradientOutput", name: "Gradient Output", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9183 }, keyword: "FlowCompensationDirection", name: "Flow Compensation Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9184 }, keyword: "TaggingDelay", name: "Tagging Delay", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9185 }, keyword: "RespiratoryMotionCompensationTechniqueDescription", name: "Respiratory Motion Compensation Technique Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9186 }, keyword: "RespiratorySignalSourceID", name: "Respiratory Signal Source ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9195 }, keyword: "ChemicalShiftMinimumIntegrationLimitInHz", name: "Chemical Shift Minimum Integration Limit in Hz", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9196 }, keyword: "ChemicalShiftMaximumIntegrationLimitInHz", name: "Chemical Shift Maximum Integration Limit in Hz", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9197 }, keyword: "MRVelocityEncodingSequence", name: "MR Velocity Encoding Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9198 }, keyword: "FirstOrderPhaseCorrection", name: "First Order Phase Correction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9199 }, keyword: "WaterReferencedPhaseCorrection", name: "Water Referenced Phase Correction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9200 }, keyword: "MRSpectroscopyAcquisitionType", name: "MR Spectroscopy Acquisition Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9214 }, keyword: "RespiratoryCyclePosition", name: "Respiratory Cycle Position", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9217 }, keyword: "VelocityEncodingMaximumValue", name: "Velocity Encoding Maximum Value", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9218 }, keyword: "TagSpacingSecondDimension", name: "Tag Spacing Second Dimension", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9219 }, keyword: "TagAngleSecondAxis", name: "Tag Angle Second Axis", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9220 }, keyword: "FrameAcquisitionDuration", name: "Frame Acquisition Duration", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9226 }, keyword: "MRImageFrameTypeSequence", name: "MR Image Frame Type Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9227 }, keyword: "MRSpectroscopyFrameTypeSequence", name: "MR Spectroscopy Frame Type Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9231 }, keyword: "MRAcquisitionPhaseEncodingStepsInPlane", name: "MR Acquisition Phase Encoding Steps in-plane", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9232 }, keyword: "MRAcquisitionPhaseEncodingStepsOutOfPlane", name: "MR Acquisition Phase Encoding Steps out-of-plane", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9234 }, keyword: "SpectroscopyAcquisitionPhaseColumns", name: "Spectroscopy Acquisition Phase Columns", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9236 }, keyword: "CardiacCyclePosition", name: "Cardiac Cycle Position", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9239 }, keyword: "SpecificAbsorptionRateSequence", name: "Specific Absorption Rate Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9240 }, keyword: "RFEchoTrainLength", name: "RF Echo Train Length", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9241 }, keyword: "GradientEchoTrainLength", name: "Gradient Echo Train Length", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9250 }, keyword: "ArterialSpinLabelingContrast", name: "Arterial Spin Labeling Contrast", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9251 }, keyword: "MRArterialSpinLabelingSequence", name: "MR Arterial Spin Labeling Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9252 }, keyword: "ASLTechniqueDescription", name: "ASL Technique Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9253 }, keyword: "ASLSlabNumber", name: "ASL Slab Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9254 }, keyword: "ASLSlabThickness", name: "ASL Slab Thickness", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9255 }, keyword: "ASLSlabOrientation", name: "ASL Slab Orientation", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9256 }, keyword: "ASLMidSlabPosition", name: "ASL Mid Slab Position", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9257 }, keyword: "ASLContext", name: "ASL Context", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9258 }, keyword: "ASLPulseTrainDuration", name: "ASL Pulse Train Duration", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9259 }, keyword: "ASLCrusherFlag", name: "ASL Crusher Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x925A }, keyword: "ASLCrusherFlowLimit", name: "ASL Crusher Flow Limit", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x925B }, keyword: "ASLCrusherDescription", name: "ASL Crusher Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x925C }, keyword: "ASLBolusCutoffFlag", name: "ASL Bolus Cut-off Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x925D }, keyword: "ASLBolusCutoffTimingSequence", name: "ASL Bolus Cut-off Timing Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x925E }, keyword: "ASLBolusCutoffTechnique", name: "ASL Bolus Cut-off Technique", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x925F }, keyword: "ASLBolusCutoffDelayTime", name: "ASL Bolus Cut-off Delay Time", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9260 }, keyword: "ASLSlabSequence", name: "ASL Slab Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9295 }, keyword: "ChemicalShiftMinimumIntegrationLimitInppm", name: "Chemical Shift Minimum Integration Limit in ppm", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9296 }, keyword: "ChemicalShiftMaximumIntegrationLimitInppm", name: "Chemical Shift Maximum Integration Limit in ppm", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9297 }, keyword: "WaterReferenceAcquisition", name: "Water Reference Acquisition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9298 }, keyword: "EchoPeakPosition", name: "Echo Peak Position", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9301 }, keyword: "CTAcquisitionTypeSequence", name: "CT Acquisition Type Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9302 }, keyword: "AcquisitionType", name: "Acquisition Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9303 }, keyword: "TubeAngle", name: "Tube Angle", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9304 }, keyword: "CTAcquisitionDetailsSequence", name: "CT Acquisition Details Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9305 }, keyword: "RevolutionTime", name: "Revolution Time", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9306 }, keyword: "SingleCollimationWidth", name: "Single Collimation Width", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9307 }, keyword: "TotalCollimationWidth", name: "Total Collimation Width", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9308 }, keyword: "CTTableDynamicsSequence", name: "CT Table Dynamics Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9309 }, keyword: "TableSpeed", name: "Table Speed", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9310 }, keyword: "TableFeedPerRotation", name: "Table Feed per Rotation", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9311 }, keyword: "SpiralPitchFactor", name: "Spiral Pitch Factor", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9312 }, keyword: "CTGeometrySequence", name: "CT Geometry Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9313 }, keyword: "DataCollectionCenterPatient", name: "Data Collection Center (Patient)", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9314 }, keyword: "CTReconstructionSequence", name: "CT Reconstruction Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9315 }, keyword: "ReconstructionAlgorithm", name: "Reconstruction Algorithm", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9316 }, keyword: "ConvolutionKernelGroup", name: "Convolution Kernel Group", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9317 }, keyword: "ReconstructionFieldOfView", name: "Reconstruction Field of View", vr: &[FLOATING_POINT_DOUBLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9318 }, keyword: "ReconstructionTargetCenterPatient", name: "Reconstruction Target Center (Patient)", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9319 }, keyword: "ReconstructionAngle", name: "Reconstruction Angle", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9320 }, keyword: "ImageFilter", name: "Image Filter", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9321 }, keyword: "CTExposureSequence", name: "CT Exposure Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9322 }, keyword: "ReconstructionPixelSpacing", name: "Reconstruction Pixel Spacing", vr: &[FLOATING_POINT_DOUBLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9323 }, keyword: "ExposureModulationType", name: "Exposure Modulation Type", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9324 }, keyword: "EstimatedDoseSaving", name: "Estimated Dose Saving", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9325 }, keyword: "CTXRayDetailsSequence", name: "CT X-Ray Details Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9326 }, keyword: "CTPositionSequence", name: "CT Position Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9327 }, keyword: "TablePosition", name: "Table Position", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9328 }, keyword: "ExposureTimeInms", name: "Exposure Time in ms", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9329 }, keyword: "CTImageFrameTypeSequence", name: "CT Image Frame Type Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9330 }, keyword: "XRayTubeCurrentInmA", name: "X-Ray Tube Current in mA", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9332 }, keyword: "ExposureInmAs", name: "Exposure in mAs", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9333 }, keyword: "ConstantVolumeFlag", name: "Constant Volume Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9334 }, keyword: "FluoroscopyFlag", name: "Fluoroscopy Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9335 }, keyword: "DistanceSourceToDataCollectionCenter", name: "Distance Source to Data Collection Center", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9337 }, keyword: "ContrastBolusAgentNumber", name: "Contrast/Bolus Agent Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9338 }, keyword: "ContrastBolusIngredientCodeSequence", name: "Contrast/Bolus Ingredient Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9340 }, keyword: "ContrastAdministrationProfileSequence", name: "Contrast Administration Profile Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9341 }, keyword: "ContrastBolusUsageSequence", name: "Contrast/Bolus Usage Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9342 }, keyword: "ContrastBolusAgentAdministered", name: "Contrast/Bolus Agent Administered", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9343 }, keyword: "ContrastBolusAgentDetected", name: "Contrast/Bolus Agent Detected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9344 }, keyword: "ContrastBolusAgentPhase", name: "Contrast/Bolus Agent Phase", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9345 }, keyword: "CTDIvol", name: "CTDIvol", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9346 }, keyword: "CTDIPhantomTypeCodeSequence", name: "CTDI Phantom Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9351 }, keyword: "CalciumScoringMassFactorPatient", name: "Calcium Scoring Mass Factor Patient", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9352 }, keyword: "CalciumScoringMassFactorDevice", name: "Calcium Scoring Mass Factor Device", vr: &[FLOATING_POINT_SINGLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9353 }, keyword: "EnergyWeightingFactor", name: "Energy Weighting Factor", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9360 }, keyword: "CTAdditionalXRaySourceSequence", name: "CT Additional X-Ray Source Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9361 }, keyword: "MultienergyCTAcquisition", name: "Multi-energy CT Acquisition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9367 }, keyword: "XRaySourceID", name: "X-Ray Source ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9371 }, keyword: "XRayDetectorID", name: "X-Ray Detector ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9373 }, keyword: "XRayDetectorLabel", name: "X-Ray Detector Label", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x937B }, keyword: "MultienergyAcquisitionDescription", name: "Multi-energy Acquisition Description", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x937F }, keyword: "DecompositionDescription", name: "Decomposition Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9401 }, keyword: "ProjectionPixelCalibrationSequence", name: "Projection Pixel Calibration Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9402 }, keyword: "DistanceSourceToIsocenter", name: "Distance Source to Isocenter", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9403 }, keyword: "DistanceObjectToTableTop", name: "Distance Object to Table Top", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9404 }, keyword: "ObjectPixelSpacingInCenterOfBeam", name: "Object Pixel Spacing in Center of Beam", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9405 }, keyword: "PositionerPositionSequence", name: "Positioner Position Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9406 }, keyword: "TablePositionSequence", name: "Table Position Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9407 }, keyword: "CollimatorShapeSequence", name: "Collimator Shape Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9410 }, keyword: "PlanesInAcquisition", name: "Planes in Acquisition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9412 }, keyword: "XAXRFFrameCharacteristicsSequence", name: "XA/XRF Frame Characteristics Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9417 }, keyword: "FrameAcquisitionSequence", name: "Frame Acquisition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9420 }, keyword: "XRayReceptorType", name: "X-Ray Receptor Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9423 }, keyword: "AcquisitionProtocolName", name: "Acquisition Protocol Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9424 }, keyword: "AcquisitionProtocolDescription", name: "Acquisition Protocol Description", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9425 }, keyword: "ContrastBolusIngredientOpaque", name: "Contrast/Bolus Ingredient Opaque", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9426 }, keyword: "DistanceReceptorPlaneToDetectorHousing", name: "Distance Receptor Plane to Detector Housing", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9427 }, keyword: "IntensifierActiveShape", name: "Intensifier Active Shape", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9428 }, keyword: "IntensifierActiveDimensions", name: "Intensifier Active Dimension(s)", vr: &[FLOATING_POINT_SINGLE], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9429 }, keyword: "PhysicalDetectorSize", name: "Physical Detector Size", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9430 }, keyword: "PositionOfIsocenterProjection", name: "Position of Isocenter Projection", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9432 }, keyword: "FieldOfViewSequence", name: "Field of View Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9433 }, keyword: "FieldOfViewDescription", name: "Field of View Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9434 }, keyword: "ExposureControlSensingRegionsSequence", name: "Exposure Control Sensing Regions Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9435 }, keyword: "ExposureControlSensingRegionShape", name: "Exposure Control Sensing Region Shape", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9436 }, keyword: "ExposureControlSensingRegionLeftVerticalEdge", name: "Exposure Control Sensing Region Left Vertical Edge", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9437 }, keyword: "ExposureControlSensingRegionRightVerticalEdge", name: "Exposure Control Sensing Region Right Vertical Edge", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9438 }, keyword: "ExposureControlSensingRegionUpperHorizontalEdge", name: "Exposure Control Sensing Region Upper Horizontal Edge", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9439 }, keyword: "ExposureControlSensingRegionLowerHorizontalEdge", name: "Exposure Control Sensing Region Lower Horizontal Edge", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9440 }, keyword: "CenterOfCircularExposureControlSensingRegion", name: "Center of Circular Exposure Control Sensing Region", vr: &[SIGNED_SHORT], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9441 }, keyword: "RadiusOfCircularExposureControlSensingRegion", name: "Radius of Circular Exposure Control Sensing Region", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9442 }, keyword: "VerticesOfThePolygonalExposureControlSensingRegion", name: "Vertices of the Polygonal Exposure Control Sensing Region", vr: &[SIGNED_SHORT], vm: "2-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9447 }, keyword: "ColumnAngulationPatient", name: "Column Angulation (Patient)", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9449 }, keyword: "BeamAngle", name: "Beam Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9451 }, keyword: "FrameDetectorParametersSequence", name: "Frame Detector Parameters Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9452 }, keyword: "CalculatedAnatomyThickness", name: "Calculated Anatomy Thickness", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9455 }, keyword: "CalibrationSequence", name: "Calibration Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9456 }, keyword: "ObjectThicknessSequence", name: "Object Thickness Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9457 }, keyword: "PlaneIdentification", name: "Plane Identification", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9461 }, keyword: "FieldOfViewDimensionsInFloat", name: "Field of View Dimension(s) in Float", vr: &[FLOATING_POINT_SINGLE], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9462 }, keyword: "IsocenterReferenceSystemSequence", name: "Isocenter Reference System Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9463 }, keyword: "PositionerIsocenterPrimaryAngle", name: "Positioner Isocenter Primary Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9464 }, keyword: "PositionerIsocenterSecondaryAngle", name: "Positioner Isocenter Secondary Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9465 }, keyword: "PositionerIsocenterDetectorRotationAngle", name: "Positioner Isocenter Detector Rotation Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9466 }, keyword: "TableXPositionToIsocenter", name: "Table X Position to Isocenter", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9467 }, keyword: "TableYPositionToIsocenter", name: "Table Y Position to Isocenter", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9468 }, keyword: "TableZPositionToIsocenter", name: "Table Z Position to Isocenter", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9469 }, keyword: "TableHorizontalRotationAngle", name: "Table Horizontal Rotation Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9470 }, keyword: "TableHeadTiltAngle", name: "Table Head Tilt Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9471 }, keyword: "TableCradleTiltAngle", name: "Table Cradle Tilt Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9472 }, keyword: "FrameDisplayShutterSequence", name: "Frame Display Shutter Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9473 }, keyword: "AcquiredImageAreaDoseProduct", name: "Acquired Image Area Dose Product", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9474 }, keyword: "CArmPositionerTabletopRelationship", name: "C-arm Positioner Tabletop Relationship", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9476 }, keyword: "XRayGeometrySequence", name: "X-Ray Geometry Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9477 }, keyword: "IrradiationEventIdentificationSequence", name: "Irradiation Event Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9504 }, keyword: "XRay3DFrameTypeSequence", name: "X-Ray 3D Frame Type Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9506 }, keyword: "ContributingSourcesSequence", name: "Contributing Sources Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9507 }, keyword: "XRay3DAcquisitionSequence", name: "X-Ray 3D Acquisition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9508 }, keyword: "PrimaryPositionerScanArc", name: "Primary Positioner Scan Arc", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9509 }, keyword: "SecondaryPositionerScanArc", name: "Secondary Positioner Scan Arc", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9510 }, keyword: "PrimaryPositionerScanStartAngle", name: "Primary Positioner Scan Start Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9511 }, keyword: "SecondaryPositionerScanStartAngle", name: "Secondary Positioner Scan Start Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9514 }, keyword: "PrimaryPositionerIncrement", name: "Primary Positioner Increment", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9515 }, keyword: "SecondaryPositionerIncrement", name: "Secondary Positioner Increment", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9516 }, keyword: "StartAcquisitionDateTime", name: "Start Acquisition DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9517 }, keyword: "EndAcquisitionDateTime", name: "End Acquisition DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9518 }, keyword: "PrimaryPositionerIncrementSign", name: "Primary Positioner Increment Sign", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9519 }, keyword: "SecondaryPositionerIncrementSign", name: "Secondary Positioner Increment Sign", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9524 }, keyword: "ApplicationName", name: "Application Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9525 }, keyword: "ApplicationVersion", name: "Application Version", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9526 }, keyword: "ApplicationManufacturer", name: "Application Manufacturer", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9527 }, keyword: "AlgorithmType", name: "Algorithm Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9528 }, keyword: "AlgorithmDescription", name: "Algorithm Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9530 }, keyword: "XRay3DReconstructionSequence", name: "X-Ray 3D Reconstruction Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9531 }, keyword: "ReconstructionDescription", name: "Reconstruction Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9538 }, keyword: "PerProjectionAcquisitionSequence", name: "Per Projection Acquisition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9601 }, keyword: "DiffusionBMatrixSequence", name: "Diffusion b-matrix Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9602 }, keyword: "DiffusionBValueXX", name: "Diffusion b-value XX", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9603 }, keyword: "DiffusionBValueXY", name: "Diffusion b-value XY", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9604 }, keyword: "DiffusionBValueXZ", name: "Diffusion b-value XZ", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9605 }, keyword: "DiffusionBValueYY", name: "Diffusion b-value YY", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9606 }, keyword: "DiffusionBValueYZ", name: "Diffusion b-value YZ", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9607 }, keyword: "DiffusionBValueZZ", name: "Diffusion b-value ZZ", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9623 }, keyword: "FunctionalSyncPulse", name: "Functional Sync Pulse", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9701 }, keyword: "DecayCorrectionDateTime", name: "Decay Correction DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9715 }, keyword: "StartDensityThreshold", name: "Start Density Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9716 }, keyword: "StartRelativeDensityDifferenceThreshold", name: "Start Relative Density Difference Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9717 }, keyword: "StartCardiacTriggerCountThreshold", name: "Start Cardiac Trigger Count Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9718 }, keyword: "StartRespiratoryTriggerCountThreshold", name: "Start Respiratory Trigger Count Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9719 }, keyword: "TerminationCountsThreshold", name: "Termination Counts Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9720 }, keyword: "TerminationDensityThreshold", name: "Termination Density Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9721 }, keyword: "TerminationRelativeDensityThreshold", name: "Termination Relative Density Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9722 }, keyword: "TerminationTimeThreshold", name: "Termination Time Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9723 }, keyword: "TerminationCardiacTriggerCountThreshold", name: "Termination Cardiac Trigger Count Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9724 }, keyword: "TerminationRespiratoryTriggerCountThreshold", name: "Termination Respiratory Trigger Count Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9725 }, keyword: "DetectorGeometry", name: "Detector Geometry", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9726 }, keyword: "TransverseDetectorSeparation", name: "Transverse Detector Separation", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9727 }, keyword: "AxialDetectorDimension", name: "Axial Detector Dimension", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9729 }, keyword: "RadiopharmaceuticalAgentNumber", name: "Radiopharmaceutical Agent Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9732 }, keyword: "PETFrameAcquisitionSequence", name: "PET Frame Acquisition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9733 }, keyword: "PETDetectorMotionDetailsSequence", name: "PET Detector Motion Details Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9734 }, keyword: "PETTableDynamicsSequence", name: "PET Table Dynamics Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9735 }, keyword: "PETPositionSequence", name: "PET Position Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9736 }, keyword: "PETFrameCorrectionFactorsSequence", name: "PET Frame Correction Factors Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9737 }, keyword: "RadiopharmaceuticalUsageSequence", name: "Radiopharmaceutical Usage Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9738 }, keyword: "AttenuationCorrectionSource", name: "Attenuation Correction Source", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9739 }, keyword: "NumberOfIterations", name: "Number of Iterations", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9740 }, keyword: "NumberOfSubsets", name: "Number of Subsets", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9749 }, keyword: "PETReconstructionSequence", name: "PET Reconstruction Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9751 }, keyword: "PETFrameTypeSequence", name: "PET Frame Type Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9755 }, keyword: "TimeOfFlightInformationUsed", name: "Time of Flight Information Used", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9756 }, keyword: "ReconstructionType", name: "Reconstruction Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9758 }, keyword: "DecayCorrected", name: "Decay Corrected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9759 }, keyword: "AttenuationCorrected", name: "Attenuation Corrected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9760 }, keyword: "ScatterCorrected", name: "Scatter Corrected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9761 }, keyword: "DeadTimeCorrected", name: "Dead Time Corrected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9762 }, keyword: "GantryMotionCorrected", name: "Gantry Motion Corrected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9763 }, keyword: "PatientMotionCorrected", name: "Patient Motion Corrected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9764 }, keyword: "CountLossNormalizationCorrected", name: "Count Loss Normalization Corrected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9765 }, keyword: "RandomsCorrected", name: "Randoms Corrected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9766 }, keyword: "NonUniformRadialSamplingCorrected", name: "Non-uniform Radial Sampling Corrected", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9767 }, keyword: "SensitivityCalibrated", name: "Sensitivity Calibrated", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9768 }, keyword: "DetectorNormalizationCorrection", name: "Detector Normalization Correction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9769 }, keyword: "IterativeReconstructionMethod", name: "Iterative Reconstruction Method", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9770 }, keyword: "AttenuationCorrectionTemporalRelationship", name: "Attenuation Correction Temporal Relationship", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9771 }, keyword: "PatientPhysiologicalStateSequence", name: "Patient Physiological State Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9772 }, keyword: "PatientPhysiologicalStateCodeSequence", name: "Patient Physiological State Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9801 }, keyword: "DepthsOfFocus", name: "Depth(s) of Focus", vr: &[FLOATING_POINT_DOUBLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9803 }, keyword: "ExcludedIntervalsSequence", name: "Excluded Intervals Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9804 }, keyword: "ExclusionStartDateTime", name: "Exclusion Start DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9805 }, keyword: "ExclusionDuration", name: "Exclusion Duration", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9806 }, keyword: "USImageDescriptionSequence", name: "US Image Description Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9807 }, keyword: "ImageDataTypeSequence", name: "Image Data Type Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9808 }, keyword: "DataType", name: "Data Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9809 }, keyword: "TransducerScanPatternCodeSequence", name: "Transducer Scan Pattern Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x980B }, keyword: "AliasedDataType", name: "Aliased Data Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x980C }, keyword: "PositionMeasuringDeviceUsed", name: "Position Measuring Device Used", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x980D }, keyword: "TransducerGeometryCodeSequence", name: "Transducer Geometry Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x980E }, keyword: "TransducerBeamSteeringCodeSequence", name: "Transducer Beam Steering Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x980F }, keyword: "TransducerApplicationCodeSequence", name: "Transducer Application Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0x9810 }, keyword: "ZeroVelocityPixelValue", name: "Zero Velocity Pixel Value", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0xA001 }, keyword: "ContributingEquipmentSequence", name: "Contributing Equipment Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0xA002 }, keyword: "ContributionDateTime", name: "Contribution DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0018, element: 0xA003 }, keyword: "ContributionDescription", name: "Contribution Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x000D }, keyword: "StudyInstanceUID", name: "Study Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x000E }, keyword: "SeriesInstanceUID", name: "Series Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0010 }, keyword: "StudyID", name: "Study ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0011 }, keyword: "SeriesNumber", name: "Series Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0012 }, keyword: "AcquisitionNumber", name: "Acquisition Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0013 }, keyword: "InstanceNumber", name: "Instance Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0014 }, keyword: "IsotopeNumber", name: "Isotope Number", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0015 }, keyword: "PhaseNumber", name: "Phase Number", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0016 }, keyword: "IntervalNumber", name: "Interval Number", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0017 }, keyword: "TimeSlotNumber", name: "Time Slot Number", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0018 }, keyword: "AngleNumber", name: "Angle Number", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0019 }, keyword: "ItemNumber", name: "Item Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0020 }, keyword: "PatientOrientation", name: "Patient Orientation", vr: &[CODE_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0022 }, keyword: "OverlayNumber", name: "Overlay Number", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0024 }, keyword: "CurveNumber", name: "Curve Number", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0026 }, keyword: "LUTNumber", name: "LUT Number", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0027 }, keyword: "PyramidLabel", name: "Pyramid Label", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0030 }, keyword: "ImagePosition", name: "Image Position", vr: &[DECIMAL_STRING], vm: "3", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0032 }, keyword: "ImagePositionPatient", name: "Image Position (Patient)", vr: &[DECIMAL_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0035 }, keyword: "ImageOrientation", name: "Image Orientation", vr: &[DECIMAL_STRING], vm: "6", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0037 }, keyword: "ImageOrientationPatient", name: "Image Orientation (Patient)", vr: &[DECIMAL_STRING], vm: "6", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0050 }, keyword: "Location", name: "Location", vr: &[DECIMAL_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0052 }, keyword: "FrameOfReferenceUID", name: "Frame of Reference UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0060 }, keyword: "Laterality", name: "Laterality", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0062 }, keyword: "ImageLaterality", name: "Image Laterality", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0070 }, keyword: "ImageGeometryType", name: "Image Geometry Type", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0080 }, keyword: "MaskingImage", name: "Masking Image", vr: &[CODE_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x00AA }, keyword: "ReportNumber", name: "Report Number", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0100 }, keyword: "TemporalPositionIdentifier", name: "Temporal Position Identifier", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0105 }, keyword: "NumberOfTemporalPositions", name: "Number of Temporal Positions", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0110 }, keyword: "TemporalResolution", name: "Temporal Resolution", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0200 }, keyword: "SynchronizationFrameOfReferenceUID", name: "Synchronization Frame of Reference UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x0242 }, keyword: "SOPInstanceUIDOfConcatenationSource", name: "SOP Instance UID of Concatenation Source", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1000 }, keyword: "SeriesInStudy", name: "Series in Study", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1001 }, keyword: "AcquisitionsInSeries", name: "Acquisitions in Series", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1002 }, keyword: "ImagesInAcquisition", name: "Images in Acquisition", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1003 }, keyword: "ImagesInSeries", name: "Images in Series", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1004 }, keyword: "AcquisitionsInStudy", name: "Acquisitions in Study", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1005 }, keyword: "ImagesInStudy", name: "Images in Study", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1020 }, keyword: "Reference", name: "Reference", vr: &[LONG_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x103F }, keyword: "TargetPositionReferenceIndicator", name: "Target Position Reference Indicator", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1040 }, keyword: "PositionReferenceIndicator", name: "Position Reference Indicator", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1041 }, keyword: "SliceLocation", name: "Slice Location", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1070 }, keyword: "OtherStudyNumbers", name: "Other Study Numbers", vr: &[INTEGER_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1200 }, keyword: "NumberOfPatientRelatedStudies", name: "Number of Patient Related Studies", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1202 }, keyword: "NumberOfPatientRelatedSeries", name: "Number of Patient Related Series", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1204 }, keyword: "NumberOfPatientRelatedInstances", name: "Number of Patient Related Instances", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1206 }, keyword: "NumberOfStudyRelatedSeries", name: "Number of Study Related Series", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1208 }, keyword: "NumberOfStudyRelatedInstances", name: "Number of Study Related Instances", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x1209 }, keyword: "NumberOfSeriesRelatedInstances", name: "Number of Series Related Instances", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x3401 }, keyword: "ModifyingDeviceID", name: "Modifying Device ID", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x3402 }, keyword: "ModifiedImageID", name: "Modified Image ID", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x3403 }, keyword: "ModifiedImageDate", name: "Modified Image Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x3404 }, keyword: "ModifyingDeviceManufacturer", name: "Modifying Device Manufacturer", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x3405 }, keyword: "ModifiedImageTime", name: "Modified Image Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x3406 }, keyword: "ModifiedImageDescription", name: "Modified Image Description", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x4000 }, keyword: "ImageComments", name: "Image Comments", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x5000 }, keyword: "OriginalImageIdentification", name: "Original Image Identification", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x5002 }, keyword: "OriginalImageIdentificationNomenclature", name: "Original Image Identification Nomenclature", vr: &[LONG_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9056 }, keyword: "StackID", name: "Stack ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9057 }, keyword: "InStackPositionNumber", name: "In-Stack Position Number", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9071 }, keyword: "FrameAnatomySequence", name: "Frame Anatomy Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9072 }, keyword: "FrameLaterality", name: "Frame Laterality", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9111 }, keyword: "FrameContentSequence", name: "Frame Content Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9113 }, keyword: "PlanePositionSequence", name: "Plane Position Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9116 }, keyword: "PlaneOrientationSequence", name: "Plane Orientation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9128 }, keyword: "TemporalPositionIndex", name: "Temporal Position Index", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9153 }, keyword: "NominalCardiacTriggerDelayTime", name: "Nominal Cardiac Trigger Delay Time", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9154 }, keyword: "NominalCardiacTriggerTimePriorToRPeak", name: "Nominal Cardiac Trigger Time Prior To R-Peak", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9155 }, keyword: "ActualCardiacTriggerTimePriorToRPeak", name: "Actual Cardiac Trigger Time Prior To R-Peak", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9156 }, keyword: "FrameAcquisitionNumber", name: "Frame Acquisition Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9157 }, keyword: "DimensionIndexValues", name: "Dimension Index Values", vr: &[UNSIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9158 }, keyword: "FrameComments", name: "Frame Comments", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9161 }, keyword: "ConcatenationUID", name: "Concatenation UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9162 }, keyword: "InConcatenationNumber", name: "In-concatenation Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9163 }, keyword: "InConcatenationTotalNumber", name: "In-concatenation Total Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9164 }, keyword: "DimensionOrganizationUID", name: "Dimension Organization UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9165 }, keyword: "DimensionIndexPointer", name: "Dimension Index Pointer", vr: &[ATTRIBUTE_TAG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9167 }, keyword: "FunctionalGroupPointer", name: "Functional Group Pointer", vr: &[ATTRIBUTE_TAG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9170 }, keyword: "UnassignedSharedConvertedAttributesSequence", name: "Unassigned Shared Converted Attributes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9171 }, keyword: "UnassignedPerFrameConvertedAttributesSequence", name: "Unassigned Per-Frame Converted Attributes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9172 }, keyword: "ConversionSourceAttributesSequence", name: "Conversion Source Attributes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9213 }, keyword: "DimensionIndexPrivateCreator", name: "Dimension Index Private Creator", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9221 }, keyword: "DimensionOrganizationSequence", name: "Dimension Organization Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9222 }, keyword: "DimensionIndexSequence", name: "Dimension Index Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9228 }, keyword: "ConcatenationFrameOffsetNumber", name: "Concatenation Frame Offset Number", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9238 }, keyword: "FunctionalGroupPrivateCreator", name: "Functional Group Private Creator", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9241 }, keyword: "NominalPercentageOfCardiacPhase", name: "Nominal Percentage of Cardiac Phase", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9245 }, keyword: "NominalPercentageOfRespiratoryPhase", name: "Nominal Percentage of Respiratory Phase", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9246 }, keyword: "StartingRespiratoryAmplitude", name: "Starting Respiratory Amplitude", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9247 }, keyword: "StartingRespiratoryPhase", name: "Starting Respiratory Phase", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9248 }, keyword: "EndingRespiratoryAmplitude", name: "Ending Respiratory Amplitude", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9249 }, keyword: "EndingRespiratoryPhase", name: "Ending Respiratory Phase", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9250 }, keyword: "RespiratoryTriggerType", name: "Respiratory Trigger Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9251 }, keyword: "RRIntervalTimeNominal", name: "R-R Interval Time Nominal", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9252 }, keyword: "ActualCardiacTriggerDelayTime", name: "Actual Cardiac Trigger Delay Time", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9253 }, keyword: "RespiratorySynchronizationSequence", name: "Respiratory Synchronization Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9254 }, keyword: "RespiratoryIntervalTime", name: "Respiratory Interval Time", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9255 }, keyword: "NominalRespiratoryTriggerDelayTime", name: "Nominal Respiratory Trigger Delay Time", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9256 }, keyword: "RespiratoryTriggerDelayThreshold", name: "Respiratory Trigger Delay Threshold", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9257 }, keyword: "ActualRespiratoryTriggerDelayTime", name: "Actual Respiratory Trigger Delay Time", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9301 }, keyword: "ImagePositionVolume", name: "Image Position (Volume)", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9302 }, keyword: "ImageOrientationVolume", name: "Image Orientation (Volume)", vr: &[FLOATING_POINT_DOUBLE], vm: "6", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9307 }, keyword: "UltrasoundAcquisitionGeometry", name: "Ultrasound Acquisition Geometry", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9308 }, keyword: "ApexPosition", name: "Apex Position", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9309 }, keyword: "VolumeToTransducerMappingMatrix", name: "Volume to Transducer Mapping Matrix", vr: &[FLOATING_POINT_DOUBLE], vm: "16", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x930A }, keyword: "VolumeToTableMappingMatrix", name: "Volume to Table Mapping Matrix", vr: &[FLOATING_POINT_DOUBLE], vm: "16", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x930B }, keyword: "VolumeToTransducerRelationship", name: "Volume to Transducer Relationship", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x930C }, keyword: "PatientFrameOfReferenceSource", name: "Patient Frame of Reference Source", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x930D }, keyword: "TemporalPositionTimeOffset", name: "Temporal Position Time Offset", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x930E }, keyword: "PlanePositionVolumeSequence", name: "Plane Position (Volume) Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x930F }, keyword: "PlaneOrientationVolumeSequence", name: "Plane Orientation (Volume) Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9310 }, keyword: "TemporalPositionSequence", name: "Temporal Position Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9311 }, keyword: "DimensionOrganizationType", name: "Dimension Organization Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9312 }, keyword: "VolumeFrameOfReferenceUID", name: "Volume Frame of Reference UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9313 }, keyword: "TableFrameOfReferenceUID", name: "Table Frame of Reference UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9421 }, keyword: "DimensionDescriptionLabel", name: "Dimension Description Label", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9450 }, keyword: "PatientOrientationInFrameSequence", name: "Patient Orientation in Frame Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9453 }, keyword: "FrameLabel", name: "Frame Label", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9518 }, keyword: "AcquisitionIndex", name: "Acquisition Index", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9529 }, keyword: "ContributingSOPInstancesReferenceSequence", name: "Contributing SOP Instances Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0020, element: 0x9536 }, keyword: "ReconstructionIndex", name: "Reconstruction Index", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0001 }, keyword: "LightPathFilterPassThroughWavelength", name: "Light Path Filter Pass-Through Wavelength", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0002 }, keyword: "LightPathFilterPassBand", name: "Light Path Filter Pass Band", vr: &[UNSIGNED_SHORT], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0003 }, keyword: "ImagePathFilterPassThroughWavelength", name: "Image Path Filter Pass-Through Wavelength", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0004 }, keyword: "ImagePathFilterPassBand", name: "Image Path Filter Pass Band", vr: &[UNSIGNED_SHORT], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0005 }, keyword: "PatientEyeMovementCommanded", name: "Patient Eye Movement Commanded", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0006 }, keyword: "PatientEyeMovementCommandCodeSequence", name: "Patient Eye Movement Command Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0007 }, keyword: "SphericalLensPower", name: "Spherical Lens Power", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0008 }, keyword: "CylinderLensPower", name: "Cylinder Lens Power", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0009 }, keyword: "CylinderAxis", name: "Cylinder Axis", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x000A }, keyword: "EmmetropicMagnification", name: "Emmetropic Magnification", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x000B }, keyword: "IntraOcularPressure", name: "Intra Ocular Pressure", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x000C }, keyword: "HorizontalFieldOfView", name: "Horizontal Field of View", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x000D }, keyword: "PupilDilated", name: "Pupil Dilated", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x000E }, keyword: "DegreeOfDilation", name: "Degree of Dilation", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0010 }, keyword: "StereoBaselineAngle", name: "Stereo Baseline Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0011 }, keyword: "StereoBaselineDisplacement", name: "Stereo Baseline Displacement", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0012 }, keyword: "StereoHorizontalPixelOffset", name: "Stereo Horizontal Pixel Offset", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0013 }, keyword: "StereoVerticalPixelOffset", name: "Stereo Vertical Pixel Offset", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0014 }, keyword: "StereoRotation", name: "Stereo Rotation", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0015 }, keyword: "AcquisitionDeviceTypeCodeSequence", name: "Acquisition Device Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0016 }, keyword: "IlluminationTypeCodeSequence", name: "Illumination Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0017 }, keyword: "LightPathFilterTypeStackCodeSequence", name: "Light Path Filter Type Stack Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0018 }, keyword: "ImagePathFilterTypeStackCodeSequence", name: "Image Path Filter Type Stack Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0019 }, keyword: "LensesCodeSequence", name: "Lenses Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x001A }, keyword: "ChannelDescriptionCodeSequence", name: "Channel Description Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x001B }, keyword: "RefractiveStateSequence", name: "Refractive State Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x001C }, keyword: "MydriaticAgentCodeSequence", name: "Mydriatic Agent Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x001D }, keyword: "RelativeImagePositionCodeSequence", name: "Relative Image Position Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x001E }, keyword: "CameraAngleOfView", name: "Camera Angle of View", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0020 }, keyword: "StereoPairsSequence", name: "Stereo Pairs Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0021 }, keyword: "LeftImageSequence", name: "Left Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0022, element: 0x0022 }, keyword: "RightImageSequence", name: "Right Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0002 }, keyword: "SamplesPerPixel", name: "Samples per Pixel", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0003 }, keyword: "SamplesPerPixelUsed", name: "Samples per Pixel Used", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0004 }, keyword: "PhotometricInterpretation", name: "Photometric Interpretation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0005 }, keyword: "ImageDimensions", name: "Image Dimensions", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0006 }, keyword: "PlanarConfiguration", name: "Planar Configuration", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0008 }, keyword: "NumberOfFrames", name: "Number of Frames", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0009 }, keyword: "FrameIncrementPointer", name: "Frame Increment Pointer", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x000A }, keyword: "FrameDimensionPointer", name: "Frame Dimension Pointer", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0010 }, keyword: "Rows", name: "Rows", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0011 }, keyword: "Columns", name: "Columns", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0012 }, keyword: "Planes", name: "Planes", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0014 }, keyword: "UltrasoundColorDataPresent", name: "Ultrasound Color Data Present", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0030 }, keyword: "PixelSpacing", name: "Pixel Spacing", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0031 }, keyword: "ZoomFactor", name: "Zoom Factor", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0032 }, keyword: "ZoomCenter", name: "Zoom Center", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0034 }, keyword: "PixelAspectRatio", name: "Pixel Aspect Ratio", vr: &[INTEGER_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0040 }, keyword: "ImageFormat", name: "Image Format", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0050 }, keyword: "ManipulatedImage", name: "Manipulated Image", vr: &[LONG_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0051 }, keyword: "CorrectedImage", name: "Corrected Image", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x005F }, keyword: "CompressionRecognitionCode", name: "Compression Recognition Code", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0060 }, keyword: "CompressionCode", name: "Compression Code", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0061 }, keyword: "CompressionOriginator", name: "Compression Originator", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0062 }, keyword: "CompressionLabel", name: "Compression Label", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0063 }, keyword: "CompressionDescription", name: "Compression Description", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0065 }, keyword: "CompressionSequence", name: "Compression Sequence", vr: &[CODE_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0066 }, keyword: "CompressionStepPointers", name: "Compression Step Pointers", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0068 }, keyword: "RepeatInterval", name: "Repeat Interval", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0069 }, keyword: "BitsGrouped", name: "Bits Grouped", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0070 }, keyword: "PerimeterTable", name: "Perimeter Table", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0071 }, keyword: "PerimeterValue", name: "Perimeter Value", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0080 }, keyword: "PredictorRows", name: "Predictor Rows", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0081 }, keyword: "PredictorColumns", name: "Predictor Columns", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0082 }, keyword: "PredictorConstants", name: "Predictor Constants", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0090 }, keyword: "BlockedPixels", name: "Blocked Pixels", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0091 }, keyword: "BlockRows", name: "Block Rows", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0092 }, keyword: "BlockColumns", name: "Block Columns", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0093 }, keyword: "RowOverlap", name: "Row Overlap", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0094 }, keyword: "ColumnOverlap", name: "Column Overlap", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0100 }, keyword: "BitsAllocated", name: "Bits Allocated", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0101 }, keyword: "BitsStored", name: "Bits Stored", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0102 }, keyword: "HighBit", name: "High Bit", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0103 }, keyword: "PixelRepresentation", name: "Pixel Representation", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0104 }, keyword: "SmallestValidPixelValue", name: "Smallest Valid Pixel Value", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0105 }, keyword: "LargestValidPixelValue", name: "Largest Valid Pixel Value", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0106 }, keyword: "SmallestImagePixelValue", name: "Smallest Image Pixel Value", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0107 }, keyword: "LargestImagePixelValue", name: "Largest Image Pixel Value", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0108 }, keyword: "SmallestPixelValueInSeries", name: "Smallest Pixel Value in Series", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0109 }, keyword: "LargestPixelValueInSeries", name: "Largest Pixel Value in Series", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0110 }, keyword: "SmallestImagePixelValueInPlane", name: "Smallest Image Pixel Value in Plane", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0111 }, keyword: "LargestImagePixelValueInPlane", name: "Largest Image Pixel Value in Plane", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0120 }, keyword: "PixelPaddingValue", name: "Pixel Padding Value", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0121 }, keyword: "PixelPaddingRangeLimit", name: "Pixel Padding Range Limit", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0122 }, keyword: "FloatPixelPaddingValue", name: "Float Pixel Padding Value", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0123 }, keyword: "DoubleFloatPixelPaddingValue", name: "Double Float Pixel Padding Value", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0124 }, keyword: "FloatPixelPaddingRangeLimit", name: "Float Pixel Padding Range Limit", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0125 }, keyword: "DoubleFloatPixelPaddingRangeLimit", name: "Double Float Pixel Padding Range Limit", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0200 }, keyword: "ImageLocation", name: "Image Location", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0300 }, keyword: "QualityControlImage", name: "Quality Control Image", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0301 }, keyword: "BurnedInAnnotation", name: "Burned In Annotation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0302 }, keyword: "RecognizableVisualFeatures", name: "Recognizable Visual Features", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0303 }, keyword: "LongitudinalTemporalInformationModified", name: "Longitudinal Temporal Information Modified", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0304 }, keyword: "ReferencedColorPaletteInstanceUID", name: "Referenced Color Palette Instance UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0400 }, keyword: "TransformLabel", name: "Transform Label", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0401 }, keyword: "TransformVersionNumber", name: "Transform Version Number", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0402 }, keyword: "NumberOfTransformSteps", name: "Number of Transform Steps", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0403 }, keyword: "SequenceOfCompressedData", name: "Sequence of Compressed Data", vr: &[LONG_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0404 }, keyword: "DetailsOfCoefficients", name: "Details of Coefficients", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0700 }, keyword: "DCTLabel", name: "DCT Label", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0701 }, keyword: "DataBlockDescription", name: "Data Block Description", vr: &[CODE_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0702 }, keyword: "DataBlock", name: "Data Block", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0710 }, keyword: "NormalizationFactorFormat", name: "Normalization Factor Format", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0720 }, keyword: "ZonalMapNumberFormat", name: "Zonal Map Number Format", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0721 }, keyword: "ZonalMapLocation", name: "Zonal Map Location", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0722 }, keyword: "ZonalMapFormat", name: "Zonal Map Format", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0730 }, keyword: "AdaptiveMapFormat", name: "Adaptive Map Format", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0740 }, keyword: "CodeNumberFormat", name: "Code Number Format", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0A02 }, keyword: "PixelSpacingCalibrationType", name: "Pixel Spacing Calibration Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0A04 }, keyword: "PixelSpacingCalibrationDescription", name: "Pixel Spacing Calibration Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1040 }, keyword: "PixelIntensityRelationship", name: "Pixel Intensity Relationship", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1041 }, keyword: "PixelIntensityRelationshipSign", name: "Pixel Intensity Relationship Sign", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1050 }, keyword: "WindowCenter", name: "Window Center", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1051 }, keyword: "WindowWidth", name: "Window Width", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1052 }, keyword: "RescaleIntercept", name: "Rescale Intercept", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1053 }, keyword: "RescaleSlope", name: "Rescale Slope", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1054 }, keyword: "RescaleType", name: "Rescale Type", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1055 }, keyword: "WindowCenterWidthExplanation", name: "Window Center & Width Explanation", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1056 }, keyword: "VOILUTFunction", name: "VOI LUT Function", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1080 }, keyword: "GrayScale", name: "Gray Scale", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1090 }, keyword: "RecommendedViewingMode", name: "Recommended Viewing Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1100 }, keyword: "GrayLookupTableDescriptor", name: "Gray Lookup Table Descriptor", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "3", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1101 }, keyword: "RedPaletteColorLookupTableDescriptor", name: "Red Palette Color Lookup Table Descriptor", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1102 }, keyword: "GreenPaletteColorLookupTableDescriptor", name: "Green Palette Color Lookup Table Descriptor", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1103 }, keyword: "BluePaletteColorLookupTableDescriptor", name: "Blue Palette Color Lookup Table Descriptor", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1104 }, keyword: "AlphaPaletteColorLookupTableDescriptor", name: "Alpha Palette Color Lookup Table Descriptor", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1111 }, keyword: "LargeRedPaletteColorLookupTableDescriptor", name: "Large Red Palette Color Lookup Table Descriptor", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "4", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1112 }, keyword: "LargeGreenPaletteColorLookupTableDescriptor", name: "Large Green Palette Color Lookup Table Descriptor", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "4", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1113 }, keyword: "LargeBluePaletteColorLookupTableDescriptor", name: "Large Blue Palette Color Lookup Table Descriptor", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "4", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1199 }, keyword: "PaletteColorLookupTableUID", name: "Palette Color Lookup Table UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1200 }, keyword: "GrayLookupTableData", name: "Gray Lookup Table Data", vr: &[UNSIGNED_SHORT, SIGNED_SHORT, OTHER_WORD], vm: "1-n or 1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1201 }, keyword: "RedPaletteColorLookupTableData", name: "Red Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1202 }, keyword: "GreenPaletteColorLookupTableData", name: "Green Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1203 }, keyword: "BluePaletteColorLookupTableData", name: "Blue Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1204 }, keyword: "AlphaPaletteColorLookupTableData", name: "Alpha Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1211 }, keyword: "LargeRedPaletteColorLookupTableData", name: "Large Red Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1212 }, keyword: "LargeGreenPaletteColorLookupTableData", name: "Large Green Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1213 }, keyword: "LargeBluePaletteColorLookupTableData", name: "Large Blue Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1214 }, keyword: "LargePaletteColorLookupTableUID", name: "Large Palette Color Lookup Table UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1221 }, keyword: "SegmentedRedPaletteColorLookupTableData", name: "Segmented Red Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1222 }, keyword: "SegmentedGreenPaletteColorLookupTableData", name: "Segmented Green Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1223 }, keyword: "SegmentedBluePaletteColorLookupTableData", name: "Segmented Blue Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1224 }, keyword: "SegmentedAlphaPaletteColorLookupTableData", name: "Segmented Alpha Palette Color Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1230 }, keyword: "StoredValueColorRangeSequence", name: "Stored Value Color Range Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1231 }, keyword: "MinimumStoredValueMapped", name: "Minimum Stored Value Mapped", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1232 }, keyword: "MaximumStoredValueMapped", name: "Maximum Stored Value Mapped", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1300 }, keyword: "BreastImplantPresent", name: "Breast Implant Present", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1350 }, keyword: "PartialView", name: "Partial View", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1351 }, keyword: "PartialViewDescription", name: "Partial View Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1352 }, keyword: "PartialViewCodeSequence", name: "Partial View Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x135A }, keyword: "SpatialLocationsPreserved", name: "Spatial Locations Preserved", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1401 }, keyword: "DataFrameAssignmentSequence", name: "Data Frame Assignment Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1402 }, keyword: "DataPathAssignment", name: "Data Path Assignment", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1403 }, keyword: "BitsMappedToColorLookupTable", name: "Bits Mapped to Color Lookup Table", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1404 }, keyword: "BlendingLUT1Sequence", name: "Blending LUT 1 Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1405 }, keyword: "BlendingLUT1TransferFunction", name: "Blending LUT 1 Transfer Function", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1406 }, keyword: "BlendingWeightConstant", name: "Blending Weight Constant", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1407 }, keyword: "BlendingLookupTableDescriptor", name: "Blending Lookup Table Descriptor", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1408 }, keyword: "BlendingLookupTableData", name: "Blending Lookup Table Data", vr: &[OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x140B }, keyword: "EnhancedPaletteColorLookupTableSequence", name: "Enhanced Palette Color Lookup Table Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x140C }, keyword: "BlendingLUT2Sequence", name: "Blending LUT 2 Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x140D }, keyword: "BlendingLUT2TransferFunction", name: "Blending LUT 2 Transfer Function", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x140E }, keyword: "DataPathID", name: "Data Path ID", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x140F }, keyword: "RGBLUTTransferFunction", name: "RGB LUT Transfer Function", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x1410 }, keyword: "AlphaLUTTransferFunction", name: "Alpha LUT Transfer Function", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x2000 }, keyword: "ICCProfile", name: "ICC Profile", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x2002 }, keyword: "ColorSpace", name: "Color Space", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x2110 }, keyword: "LossyImageCompression", name: "Lossy Image Compression", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x2112 }, keyword: "LossyImageCompressionRatio", name: "Lossy Image Compression Ratio", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x2114 }, keyword: "LossyImageCompressionMethod", name: "Lossy Image Compression Method", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x3000 }, keyword: "ModalityLUTSequence", name: "Modality LUT Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x3001 }, keyword: "VariableModalityLUTSequence", name: "Variable Modality LUT Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x3002 }, keyword: "LUTDescriptor", name: "LUT Descriptor", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x3003 }, keyword: "LUTExplanation", name: "LUT Explanation", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x3004 }, keyword: "ModalityLUTType", name: "Modality LUT Type", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x3006 }, keyword: "LUTData", name: "LUT Data", vr: &[UNSIGNED_SHORT, OTHER_WORD], vm: "1-n or 1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x3010 }, keyword: "VOILUTSequence", name: "VOI LUT Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x3110 }, keyword: "SoftcopyVOILUTSequence", name: "Softcopy VOI LUT Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x4000 }, keyword: "ImagePresentationComments", name: "Image Presentation Comments", vr: &[LONG_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x5000 }, keyword: "BiPlaneAcquisitionSequence", name: "Bi-Plane Acquisition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6010 }, keyword: "RepresentativeFrameNumber", name: "Representative Frame Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6020 }, keyword: "FrameNumbersOfInterest", name: "Frame Numbers of Interest (FOI)", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6022 }, keyword: "FrameOfInterestDescription", name: "Frame of Interest Description", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6023 }, keyword: "FrameOfInterestType", name: "Frame of Interest Type", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6030 }, keyword: "MaskPointers", name: "Mask Pointer(s)", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6040 }, keyword: "RWavePointer", name: "R Wave Pointer", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6100 }, keyword: "MaskSubtractionSequence", name: "Mask Subtraction Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6101 }, keyword: "MaskOperation", name: "Mask Operation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6102 }, keyword: "ApplicableFrameRange", name: "Applicable Frame Range", vr: &[UNSIGNED_SHORT], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6110 }, keyword: "MaskFrameNumbers", name: "Mask Frame Numbers", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6112 }, keyword: "ContrastFrameAveraging", name: "Contrast Frame Averaging", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6114 }, keyword: "MaskSubPixelShift", name: "Mask Sub-pixel Shift", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6120 }, keyword: "TIDOffset", name: "TID Offset", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x6190 }, keyword: "MaskOperationExplanation", name: "Mask Operation Explanation", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x7000 }, keyword: "EquipmentAdministratorSequence", name: "Equipment Administrator Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x7001 }, keyword: "NumberOfDisplaySubsystems", name: "Number of Display Subsystems", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x7FE0 }, keyword: "PixelDataProviderURL", name: "Pixel Data Provider URL", vr: &[UNIVERSAL_RESOURCE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9001 }, keyword: "DataPointRows", name: "Data Point Rows", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9002 }, keyword: "DataPointColumns", name: "Data Point Columns", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9003 }, keyword: "SignalDomainColumns", name: "Signal Domain Columns", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9099 }, keyword: "LargestMonochromePixelValue", name: "Largest Monochrome Pixel Value", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9108 }, keyword: "DataRepresentation", name: "Data Representation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9110 }, keyword: "PixelMeasuresSequence", name: "Pixel Measures Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9132 }, keyword: "FrameVOILUTSequence", name: "Frame VOI LUT Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9145 }, keyword: "PixelValueTransformationSequence", name: "Pixel Value Transformation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9235 }, keyword: "SignalDomainRows", name: "Signal Domain Rows", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9411 }, keyword: "DisplayFilterPercentage", name: "Display Filter Percentage", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9415 }, keyword: "FramePixelShiftSequence", name: "Frame Pixel Shift Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9416 }, keyword: "SubtractionItemID", name: "Subtraction Item ID", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9422 }, keyword: "PixelIntensityRelationshipLUTSequence", name: "Pixel Intensity Relationship LUT Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9443 }, keyword: "FramePixelDataPropertiesSequence", name: "Frame Pixel Data Properties Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9444 }, keyword: "GeometricalProperties", name: "Geometrical Properties", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9445 }, keyword: "GeometricMaximumDistortion", name: "Geometric Maximum Distortion", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9446 }, keyword: "ImageProcessingApplied", name: "Image Processing Applied", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9454 }, keyword: "MaskSelectionMode", name: "Mask Selection Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9474 }, keyword: "LUTFunction", name: "LUT Function", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9478 }, keyword: "MaskVisibilityPercentage", name: "Mask Visibility Percentage", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9501 }, keyword: "PixelShiftSequence", name: "Pixel Shift Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9502 }, keyword: "RegionPixelShiftSequence", name: "Region Pixel Shift Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9503 }, keyword: "VerticesOfTheRegion", name: "Vertices of the Region", vr: &[SIGNED_SHORT], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9505 }, keyword: "MultiFramePresentationSequence", name: "Multi-frame Presentation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9506 }, keyword: "PixelShiftFrameRange", name: "Pixel Shift Frame Range", vr: &[UNSIGNED_SHORT], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9507 }, keyword: "LUTFrameRange", name: "LUT Frame Range", vr: &[UNSIGNED_SHORT], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9520 }, keyword: "ImageToEquipmentMappingMatrix", name: "Image to Equipment Mapping Matrix", vr: &[DECIMAL_STRING], vm: "16", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0028, element: 0x9537 }, keyword: "EquipmentCoordinateSystemIdentification", name: "Equipment Coordinate System Identification", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x000A }, keyword: "StudyStatusID", name: "Study Status ID", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x000C }, keyword: "StudyPriorityID", name: "Study Priority ID", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x0012 }, keyword: "StudyIDIssuer", name: "Study ID Issuer", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x0032 }, keyword: "StudyVerifiedDate", name: "Study Verified Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x0033 }, keyword: "StudyVerifiedTime", name: "Study Verified Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x0034 }, keyword: "StudyReadDate", name: "Study Read Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x0035 }, keyword: "StudyReadTime", name: "Study Read Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1000 }, keyword: "ScheduledStudyStartDate", name: "Scheduled Study Start Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1001 }, keyword: "ScheduledStudyStartTime", name: "Scheduled Study Start Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1010 }, keyword: "ScheduledStudyStopDate", name: "Scheduled Study Stop Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1011 }, keyword: "ScheduledStudyStopTime", name: "Scheduled Study Stop Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1020 }, keyword: "ScheduledStudyLocation", name: "Scheduled Study Location", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1021 }, keyword: "ScheduledStudyLocationAETitle", name: "Scheduled Study Location AE Title", vr: &[APPLICATION_ENTITY], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1030 }, keyword: "ReasonForStudy", name: "Reason for Study", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1031 }, keyword: "RequestingPhysicianIdentificationSequence", name: "Requesting Physician Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1032 }, keyword: "RequestingPhysician", name: "Requesting Physician", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1033 }, keyword: "RequestingService", name: "Requesting Service", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1034 }, keyword: "RequestingServiceCodeSequence", name: "Requesting Service Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1040 }, keyword: "StudyArrivalDate", name: "Study Arrival Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1041 }, keyword: "StudyArrivalTime", name: "Study Arrival Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1050 }, keyword: "StudyCompletionDate", name: "Study Completion Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1051 }, keyword: "StudyCompletionTime", name: "Study Completion Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1055 }, keyword: "StudyComponentStatusID", name: "Study Component Status ID", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1060 }, keyword: "RequestedProcedureDescription", name: "Requested Procedure Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1064 }, keyword: "RequestedProcedureCodeSequence", name: "Requested Procedure Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1066 }, keyword: "ReasonForVisit", name: "Reason for Visit", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1067 }, keyword: "ReasonForVisitCodeSequence", name: "Reason for Visit Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x1070 }, keyword: "RequestedContrastAgent", name: "Requested Contrast Agent", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0032, element: 0x4000 }, keyword: "StudyComments", name: "Study Comments", vr: &[LONG_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0004 }, keyword: "ReferencedPatientAliasSequence", name: "Referenced Patient Alias Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0008 }, keyword: "VisitStatusID", name: "Visit Status ID", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0010 }, keyword: "AdmissionID", name: "Admission ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0011 }, keyword: "IssuerOfAdmissionID", name: "Issuer of Admission ID", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0014 }, keyword: "IssuerOfAdmissionIDSequence", name: "Issuer of Admission ID Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0016 }, keyword: "RouteOfAdmissions", name: "Route of Admissions", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x001A }, keyword: "ScheduledAdmissionDate", name: "Scheduled Admission Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x001B }, keyword: "ScheduledAdmissionTime", name: "Scheduled Admission Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x001C }, keyword: "ScheduledDischargeDate", name: "Scheduled Discharge Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x001D }, keyword: "ScheduledDischargeTime", name: "Scheduled Discharge Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x001E }, keyword: "ScheduledPatientInstitutionResidence", name: "Scheduled Patient Institution Residence", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0020 }, keyword: "AdmittingDate", name: "Admitting Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0021 }, keyword: "AdmittingTime", name: "Admitting Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0030 }, keyword: "DischargeDate", name: "Discharge Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0032 }, keyword: "DischargeTime", name: "Discharge Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0040 }, keyword: "DischargeDiagnosisDescription", name: "Discharge Diagnosis Description", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0044 }, keyword: "DischargeDiagnosisCodeSequence", name: "Discharge Diagnosis Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0050 }, keyword: "SpecialNeeds", name: "Special Needs", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0060 }, keyword: "ServiceEpisodeID", name: "Service Episode ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0061 }, keyword: "IssuerOfServiceEpisodeID", name: "Issuer of Service Episode ID", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0062 }, keyword: "ServiceEpisodeDescription", name: "Service Episode Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0064 }, keyword: "IssuerOfServiceEpisodeIDSequence", name: "Issuer of Service Episode ID Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0100 }, keyword: "PertinentDocumentsSequence", name: "Pertinent Documents Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0300 }, keyword: "CurrentPatientLocation", name: "Current Patient Location", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0400 }, keyword: "PatientInstitutionResidence", name: "Patient's Institution Residence", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0500 }, keyword: "PatientState", name: "Patient State", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x0502 }, keyword: "PatientClinicalTrialParticipationSequence", name: "Patient Clinical Trial Participation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0038, element: 0x4000 }, keyword: "VisitComments", name: "Visit Comments", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0004 }, keyword: "WaveformOriginality", name: "Waveform Originality", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0005 }, keyword: "NumberOfWaveformChannels", name: "Number of Waveform Channels", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0010 }, keyword: "NumberOfWaveformSamples", name: "Number of Waveform Samples", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x001A }, keyword: "SamplingFrequency", name: "Sampling Frequency", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0020 }, keyword: "MultiplexGroupLabel", name: "Multiplex Group Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0200 }, keyword: "ChannelDefinitionSequence", name: "Channel Definition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0202 }, keyword: "WaveformChannelNumber", name: "Waveform Channel Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0203 }, keyword: "ChannelLabel", name: "Channel Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0205 }, keyword: "ChannelStatus", name: "Channel Status", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0208 }, keyword: "ChannelSourceSequence", name: "Channel Source Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0209 }, keyword: "ChannelSourceModifiersSequence", name: "Channel Source Modifiers Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x020A }, keyword: "SourceWaveformSequence", name: "Source Waveform Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x020C }, keyword: "ChannelDerivationDescription", name: "Channel Derivation Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0210 }, keyword: "ChannelSensitivity", name: "Channel Sensitivity", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0211 }, keyword: "ChannelSensitivityUnitsSequence", name: "Channel Sensitivity Units Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0212 }, keyword: "ChannelSensitivityCorrectionFactor", name: "Channel Sensitivity Correction Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0213 }, keyword: "ChannelBaseline", name: "Channel Baseline", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0214 }, keyword: "ChannelTimeSkew", name: "Channel Time Skew", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0215 }, keyword: "ChannelSampleSkew", name: "Channel Sample Skew", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0218 }, keyword: "ChannelOffset", name: "Channel Offset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x021A }, keyword: "WaveformBitsStored", name: "Waveform Bits Stored", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0220 }, keyword: "FilterLowFrequency", name: "Filter Low Frequency", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0221 }, keyword: "FilterHighFrequency", name: "Filter High Frequency", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0222 }, keyword: "NotchFilterFrequency", name: "Notch Filter Frequency", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0223 }, keyword: "NotchFilterBandwidth", name: "Notch Filter Bandwidth", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0230 }, keyword: "WaveformDataDisplayScale", name: "Waveform Data Display Scale", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0231 }, keyword: "WaveformDisplayBackgroundCIELabValue", name: "Waveform Display Background CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0240 }, keyword: "WaveformPresentationGroupSequence", name: "Waveform Presentation Group Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0241 }, keyword: "PresentationGroupNumber", name: "Presentation Group Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0242 }, keyword: "ChannelDisplaySequence", name: "Channel Display Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0244 }, keyword: "ChannelRecommendedDisplayCIELabValue", name: "Channel Recommended Display CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0245 }, keyword: "ChannelPosition", name: "Channel Position", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0246 }, keyword: "DisplayShadingFlag", name: "Display Shading Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0247 }, keyword: "FractionalChannelDisplayScale", name: "Fractional Channel Display Scale", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0248 }, keyword: "AbsoluteChannelDisplayScale", name: "Absolute Channel Display Scale", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0300 }, keyword: "MultiplexedAudioChannelsDescriptionCodeSequence", name: "Multiplexed Audio Channels Description Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0301 }, keyword: "ChannelIdentificationCode", name: "Channel Identification Code", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x003A, element: 0x0302 }, keyword: "ChannelMode", name: "Channel Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0001 }, keyword: "ScheduledStationAETitle", name: "Scheduled Station AE Title", vr: &[APPLICATION_ENTITY], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0002 }, keyword: "ScheduledProcedureStepStartDate", name: "Scheduled Procedure Step Start Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0003 }, keyword: "ScheduledProcedureStepStartTime", name: "Scheduled Procedure Step Start Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0004 }, keyword: "ScheduledProcedureStepEndDate", name: "Scheduled Procedure Step End Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0005 }, keyword: "ScheduledProcedureStepEndTime", name: "Scheduled Procedure Step End Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0006 }, keyword: "ScheduledPerformingPhysicianName", name: "Scheduled Performing Physician's Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0007 }, keyword: "ScheduledProcedureStepDescription", name: "Scheduled Procedure Step Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0008 }, keyword: "ScheduledProtocolCodeSequence", name: "Scheduled Protocol Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0009 }, keyword: "ScheduledProcedureStepID", name: "Scheduled Procedure Step ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x000A }, keyword: "StageCodeSequence", name: "Stage Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x000B }, keyword: "ScheduledPerformingPhysicianIdentificationSequence", name: "Scheduled Performing Physician Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0010 }, keyword: "ScheduledStationName", name: "Scheduled Station Name", vr: &[SHORT_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0011 }, keyword: "ScheduledProcedureStepLocation", name: "Scheduled Procedure Step Location", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0012 }, keyword: "PreMedication", name: "Pre-Medication", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0020 }, keyword: "ScheduledProcedureStepStatus", name: "Scheduled Procedure Step Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0026 }, keyword: "OrderPlacerIdentifierSequence", name: "Order Placer Identifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0027 }, keyword: "OrderFillerIdentifierSequence", name: "Order Filler Identifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0031 }, keyword: "LocalNamespaceEntityID", name: "Local Namespace Entity ID", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0032 }, keyword: "UniversalEntityID", name: "Universal Entity ID", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0033 }, keyword: "UniversalEntityIDType", name: "Universal Entity ID Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0035 }, keyword: "IdentifierTypeCode", name: "Identifier Type Code", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0036 }, keyword: "AssigningFacilitySequence", name: "Assigning Facility Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0039 }, keyword: "AssigningJurisdictionCodeSequence", name: "Assigning Jurisdiction Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x003A }, keyword: "AssigningAgencyOrDepartmentCodeSequence", name: "Assigning Agency or Department Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0100 }, keyword: "ScheduledProcedureStepSequence", name: "Scheduled Procedure Step Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0220 }, keyword: "ReferencedNonImageCompositeSOPInstanceSequence", name: "Referenced Non-Image Composite SOP Instance Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0241 }, keyword: "PerformedStationAETitle", name: "Performed Station AE Title", vr: &[APPLICATION_ENTITY], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0242 }, keyword: "PerformedStationName", name: "Performed Station Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0243 }, keyword: "PerformedLocation", name: "Performed Location", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0244 }, keyword: "PerformedProcedureStepStartDate", name: "Performed Procedure Step Start Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0245 }, keyword: "PerformedProcedureStepStartTime", name: "Performed Procedure Step Start Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0250 }, keyword: "PerformedProcedureStepEndDate", name: "Performed Procedure Step End Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0251 }, keyword: "PerformedProcedureStepEndTime", name: "Performed Procedure Step End Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0252 }, keyword: "PerformedProcedureStepStatus", name: "Performed Procedure Step Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0253 }, keyword: "PerformedProcedureStepID", name: "Performed Procedure Step ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0254 }, keyword: "PerformedProcedureStepDescription", name: "Performed Procedure Step Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0255 }, keyword: "PerformedProcedureTypeDescription", name: "Performed Procedure Type Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0260 }, keyword: "PerformedProtocolCodeSequence", name: "Performed Protocol Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0261 }, keyword: "PerformedProtocolType", name: "Performed Protocol Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0270 }, keyword: "ScheduledStepAttributesSequence", name: "Scheduled Step Attributes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0275 }, keyword: "RequestAttributesSequence", name: "Request Attributes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0280 }, keyword: "CommentsOnThePerformedProcedureStep", name: "Comments on the Performed Procedure Step", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0281 }, keyword: "PerformedProcedureStepDiscontinuationReasonCodeSequence", name: "Performed Procedure Step Discontinuation Reason Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0293 }, keyword: "QuantitySequence", name: "Quantity Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0294 }, keyword: "Quantity", name: "Quantity", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0295 }, keyword: "MeasuringUnitsSequence", name: "Measuring Units Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0296 }, keyword: "BillingItemSequence", name: "Billing Item Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0300 }, keyword: "TotalTimeOfFluoroscopy", name: "Total Time of Fluoroscopy", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0301 }, keyword: "TotalNumberOfExposures", name: "Total Number of Exposures", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0302 }, keyword: "EntranceDose", name: "Entrance Dose", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0303 }, keyword: "ExposedArea", name: "Exposed Area", vr: &[UNSIGNED_SHORT], vm: "1-2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0306 }, keyword: "DistanceSourceToEntrance", name: "Distance Source to Entrance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0307 }, keyword: "DistanceSourceToSupport", name: "Distance Source to Support", vr: &[DECIMAL_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x030E }, keyword: "ExposureDoseSequence", name: "Exposure Dose Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0310 }, keyword: "CommentsOnRadiationDose", name: "Comments on Radiation Dose", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0312 }, keyword: "XRayOutput", name: "X-Ray Output", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0314 }, keyword: "HalfValueLayer", name: "Half Value Layer", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0316 }, keyword: "OrganDose", name: "Organ Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0318 }, keyword: "OrganExposed", name: "Organ Exposed", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0320 }, keyword: "BillingProceduresSequence", name: "Billing Procedure Step Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0321 }, keyword: "FilmConsumptionSequence", name: "Film Consumption Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0324 }, keyword: "BillingSuppliesAndDevicesSequence", name: "Billing Supplies and Devices Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0330 }, keyword: "ReferencedProcedureStepSequence", name: "Referenced Procedure Step Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0340 }, keyword: "PerformedSeriesSequence", name: "Performed Series Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0400 }, keyword: "CommentsOnTheScheduledProcedureStep", name: "Comments on the Scheduled Procedure Step", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0440 }, keyword: "ProtocolContextSequence", name: "Protocol Context Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0441 }, keyword: "ContentItemModifierSequence", name: "Content Item Modifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0500 }, keyword: "ScheduledSpecimenSequence", name: "Scheduled Specimen Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x050A }, keyword: "SpecimenAccessionNumber", name: "Specimen Accession Number", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0512 }, keyword: "ContainerIdentifier", name: "Container Identifier", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0513 }, keyword: "IssuerOfTheContainerIdentifierSequence", name: "Issuer of the Container Identifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0515 }, keyword: "AlternateContainerIdentifierSequence", name: "Alternate Container Identifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0518 }, keyword: "ContainerTypeCodeSequence", name: "Container Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x051A }, keyword: "ContainerDescription", name: "Container Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0520 }, keyword: "ContainerComponentSequence", name: "Container Component Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0550 }, keyword: "SpecimenSequence", name: "Specimen Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0551 }, keyword: "SpecimenIdentifier", name: "Specimen Identifier", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0552 }, keyword: "SpecimenDescriptionSequenceTrial", name: "Specimen Description Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0553 }, keyword: "SpecimenDescriptionTrial", name: "Specimen Description (Trial)", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0554 }, keyword: "SpecimenUID", name: "Specimen UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0555 }, keyword: "AcquisitionContextSequence", name: "Acquisition Context Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0556 }, keyword: "AcquisitionContextDescription", name: "Acquisition Context Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0560 }, keyword: "SpecimenDescriptionSequence", name: "Specimen Description Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0562 }, keyword: "IssuerOfTheSpecimenIdentifierSequence", name: "Issuer of the Specimen Identifier Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x059A }, keyword: "SpecimenTypeCodeSequence", name: "Specimen Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0600 }, keyword: "SpecimenShortDescription", name: "Specimen Short Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0602 }, keyword: "SpecimenDetailedDescription", name: "Specimen Detailed Description", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0610 }, keyword: "SpecimenPreparationSequence", name: "Specimen Preparation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0612 }, keyword: "SpecimenPreparationStepContentItemSequence", name: "Specimen Preparation Step Content Item Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0620 }, keyword: "SpecimenLocalizationContentItemSequence", name: "Specimen Localization Content Item Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x06FA }, keyword: "SlideIdentifier", name: "Slide Identifier", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x0710 }, keyword: "WholeSlideMicroscopyImageFrameTypeSequence", name: "Whole Slide Microscopy Image Frame Type Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x071A }, keyword: "ImageCenterPointCoordinatesSequence", name: "Image Center Point Coordinates Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x072A }, keyword: "XOffsetInSlideCoordinateSystem", name: "X Offset in Slide Coordinate System", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x073A }, keyword: "YOffsetInSlideCoordinateSystem", name: "Y Offset in Slide Coordinate System", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x074A }, keyword: "ZOffsetInSlideCoordinateSystem", name: "Z Offset in Slide Coordinate System", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x08D8 }, keyword: "PixelSpacingSequence", name: "Pixel Spacing Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x08DA }, keyword: "CoordinateSystemAxisCodeSequence", name: "Coordinate System Axis Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x08EA }, keyword: "MeasurementUnitsCodeSequence", name: "Measurement Units Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x09F8 }, keyword: "VitalStainCodeSequenceTrial", name: "Vital Stain Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1001 }, keyword: "RequestedProcedureID", name: "Requested Procedure ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1002 }, keyword: "ReasonForTheRequestedProcedure", name: "Reason for the Requested Procedure", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1003 }, keyword: "RequestedProcedurePriority", name: "Requested Procedure Priority", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1004 }, keyword: "PatientTransportArrangements", name: "Patient Transport Arrangements", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1005 }, keyword: "RequestedProcedureLocation", name: "Requested Procedure Location", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1006 }, keyword: "PlacerOrderNumberProcedure", name: "Placer Order Number / Procedure", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1007 }, keyword: "FillerOrderNumberProcedure", name: "Filler Order Number / Procedure", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1008 }, keyword: "ConfidentialityCode", name: "Confidentiality Code", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1009 }, keyword: "ReportingPriority", name: "Reporting Priority", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x100A }, keyword: "ReasonForRequestedProcedureCodeSequence", name: "Reason for Requested Procedure Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1010 }, keyword: "NamesOfIntendedRecipientsOfResults", name: "Names of Intended Recipients of Results", vr: &[PERSON_NAME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1011 }, keyword: "IntendedRecipientsOfResultsIdentificationSequence", name: "Intended Recipients of Results Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1012 }, keyword: "ReasonForPerformedProcedureCodeSequence", name: "Reason For Performed Procedure Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1060 }, keyword: "RequestedProcedureDescriptionTrial", name: "Requested Procedure Description (Trial)", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1101 }, keyword: "PersonIdentificationCodeSequence", name: "Person Identification Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1102 }, keyword: "PersonAddress", name: "Person's Address", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1103 }, keyword: "PersonTelephoneNumbers", name: "Person's Telephone Numbers", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1104 }, keyword: "PersonTelecomInformation", name: "Person's Telecom Information", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x1400 }, keyword: "RequestedProcedureComments", name: "Requested Procedure Comments", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2001 }, keyword: "ReasonForTheImagingServiceRequest", name: "Reason for the Imaging Service Request", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2004 }, keyword: "IssueDateOfImagingServiceRequest", name: "Issue Date of Imaging Service Request", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2005 }, keyword: "IssueTimeOfImagingServiceRequest", name: "Issue Time of Imaging Service Request", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2006 }, keyword: "PlacerOrderNumberImagingServiceRequestRetired", name: "Placer Order Number / Imaging Service Request (Retired)", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2007 }, keyword: "FillerOrderNumberImagingServiceRequestRetired", name: "Filler Order Number / Imaging Service Request (Retired)", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2008 }, keyword: "OrderEnteredBy", name: "Order Entered By", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2009 }, keyword: "OrderEntererLocation", name: "Order Enterer's Location", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2010 }, keyword: "OrderCallbackPhoneNumber", name: "Order Callback Phone Number", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2011 }, keyword: "OrderCallbackTelecomInformation", name: "Order Callback Telecom Information", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2016 }, keyword: "PlacerOrderNumberImagingServiceRequest", name: "Placer Order Number / Imaging Service Request", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2017 }, keyword: "FillerOrderNumberImagingServiceRequest", name: "Filler Order Number / Imaging Service Request", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x2400 }, keyword: "ImagingServiceRequestComments", name: "Imaging Service Request Comments", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x3001 }, keyword: "ConfidentialityConstraintOnPatientDataDescription", name: "Confidentiality Constraint on Patient Data Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4001 }, keyword: "GeneralPurposeScheduledProcedureStepStatus", name: "General Purpose Scheduled Procedure Step Status", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4002 }, keyword: "GeneralPurposePerformedProcedureStepStatus", name: "General Purpose Performed Procedure Step Status", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4003 }, keyword: "GeneralPurposeScheduledProcedureStepPriority", name: "General Purpose Scheduled Procedure Step Priority", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4005 }, keyword: "ScheduledProcedureStepStartDateTime", name: "Scheduled Procedure Step Start DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4008 }, keyword: "ScheduledProcedureStepExpirationDateTime", name: "Scheduled Procedure Step Expiration DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4009 }, keyword: "HumanPerformerCodeSequence", name: "Human Performer Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4010 }, keyword: "ScheduledProcedureStepModificationDateTime", name: "Scheduled Procedure Step Modification DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4011 }, keyword: "ExpectedCompletionDateTime", name: "Expected Completion DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4018 }, keyword: "ScheduledWorkitemCodeSequence", name: "Scheduled Workitem Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4019 }, keyword: "PerformedWorkitemCodeSequence", name: "Performed Workitem Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4021 }, keyword: "InputInformationSequence", name: "Input Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4023 }, keyword: "ReferencedGeneralPurposeScheduledProcedureStepTransactionUID", name: "Referenced General Purpose Scheduled Procedure Step Transaction UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4025 }, keyword: "ScheduledStationNameCodeSequence", name: "Scheduled Station Name Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4026 }, keyword: "ScheduledStationClassCodeSequence", name: "Scheduled Station Class Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4027 }, keyword: "ScheduledStationGeographicLocationCodeSequence", name: "Scheduled Station Geographic Location Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4028 }, keyword: "PerformedStationNameCodeSequence", name: "Performed Station Name Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4029 }, keyword: "PerformedStationClassCodeSequence", name: "Performed Station Class Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4030 }, keyword: "PerformedStationGeographicLocationCodeSequence", name: "Performed Station Geographic Location Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4033 }, keyword: "OutputInformationSequence", name: "Output Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4034 }, keyword: "ScheduledHumanPerformersSequence", name: "Scheduled Human Performers Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4035 }, keyword: "ActualHumanPerformersSequence", name: "Actual Human Performers Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4036 }, keyword: "HumanPerformerOrganization", name: "Human Performer's Organization", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4037 }, keyword: "HumanPerformerName", name: "Human Performer's Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4050 }, keyword: "PerformedProcedureStepStartDateTime", name: "Performed Procedure Step Start DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4051 }, keyword: "PerformedProcedureStepEndDateTime", name: "Performed Procedure Step End DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x4052 }, keyword: "ProcedureStepCancellationDateTime", name: "Procedure Step Cancellation DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x8302 }, keyword: "EntranceDoseInmGy", name: "Entrance Dose in mGy", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9092 }, keyword: "ParametricMapFrameTypeSequence", name: "Parametric Map Frame Type Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9094 }, keyword: "ReferencedImageRealWorldValueMappingSequence", name: "Referenced Image Real World Value Mapping Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9096 }, keyword: "RealWorldValueMappingSequence", name: "Real World Value Mapping Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9098 }, keyword: "PixelValueMappingCodeSequence", name: "Pixel Value Mapping Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9210 }, keyword: "LUTLabel", name: "LUT Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9211 }, keyword: "RealWorldValueLastValueMapped", name: "Real World Value Last Value Mapped", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9212 }, keyword: "RealWorldValueLUTData", name: "Real World Value LUT Data", vr: &[FLOATING_POINT_DOUBLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9213 }, keyword: "DoubleFloatRealWorldValueLastValueMapped", name: "Double Float Real World Value Last Value Mapped", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9214 }, keyword: "DoubleFloatRealWorldValueFirstValueMapped", name: "Double Float Real World Value First Value Mapped", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9216 }, keyword: "RealWorldValueFirstValueMapped", name: "Real World Value First Value Mapped", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9220 }, keyword: "QuantityDefinitionSequence", name: "Quantity Definition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9224 }, keyword: "RealWorldValueIntercept", name: "Real World Value Intercept", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0x9225 }, keyword: "RealWorldValueSlope", name: "Real World Value Slope", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA007 }, keyword: "FindingsFlagTrial", name: "Findings Flag (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA010 }, keyword: "RelationshipType", name: "Relationship Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA020 }, keyword: "FindingsSequenceTrial", name: "Findings Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA021 }, keyword: "FindingsGroupUIDTrial", name: "Findings Group UID (Trial)", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA022 }, keyword: "ReferencedFindingsGroupUIDTrial", name: "Referenced Findings Group UID (Trial)", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA023 }, keyword: "FindingsGroupRecordingDateTrial", name: "Findings Group Recording Date (Trial)", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA024 }, keyword: "FindingsGroupRecordingTimeTrial", name: "Findings Group Recording Time (Trial)", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA026 }, keyword: "FindingsSourceCategoryCodeSequenceTrial", name: "Findings Source Category Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA027 }, keyword: "VerifyingOrganization", name: "Verifying Organization", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA028 }, keyword: "DocumentingOrganizationIdentifierCodeSequenceTrial", name: "Documenting Organization Identifier Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA030 }, keyword: "VerificationDateTime", name: "Verification DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA032 }, keyword: "ObservationDateTime", name: "Observation DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA033 }, keyword: "ObservationStartDateTime", name: "Observation Start DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA040 }, keyword: "ValueType", name: "Value Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA043 }, keyword: "ConceptNameCodeSequence", name: "Concept Name Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA047 }, keyword: "MeasurementPrecisionDescriptionTrial", name: "Measurement Precision Description (Trial)", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA050 }, keyword: "ContinuityOfContent", name: "Continuity Of Content", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA057 }, keyword: "UrgencyOrPriorityAlertsTrial", name: "Urgency or Priority Alerts (Trial)", vr: &[CODE_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA060 }, keyword: "SequencingIndicatorTrial", name: "Sequencing Indicator (Trial)", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA066 }, keyword: "DocumentIdentifierCodeSequenceTrial", name: "Document Identifier Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA067 }, keyword: "DocumentAuthorTrial", name: "Document Author (Trial)", vr: &[PERSON_NAME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA068 }, keyword: "DocumentAuthorIdentifierCodeSequenceTrial", name: "Document Author Identifier Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA070 }, keyword: "IdentifierCodeSequenceTrial", name: "Identifier Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA073 }, keyword: "VerifyingObserverSequence", name: "Verifying Observer Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA074 }, keyword: "ObjectBinaryIdentifierTrial", name: "Object Binary Identifier (Trial)", vr: &[OTHER_BYTE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA075 }, keyword: "VerifyingObserverName", name: "Verifying Observer Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA076 }, keyword: "DocumentingObserverIdentifierCodeSequenceTrial", name: "Documenting Observer Identifier Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA078 }, keyword: "AuthorObserverSequence", name: "Author Observer Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA07A }, keyword: "ParticipantSequence", name: "Participant Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA07C }, keyword: "CustodialOrganizationSequence", name: "Custodial Organization Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA080 }, keyword: "ParticipationType", name: "Participation Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA082 }, keyword: "ParticipationDateTime", name: "Participation DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA084 }, keyword: "ObserverType", name: "Observer Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA085 }, keyword: "ProcedureIdentifierCodeSequenceTrial", name: "Procedure Identifier Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA088 }, keyword: "VerifyingObserverIdentificationCodeSequence", name: "Verifying Observer Identification Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA089 }, keyword: "ObjectDirectoryBinaryIdentifierTrial", name: "Object Directory Binary Identifier (Trial)", vr: &[OTHER_BYTE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA090 }, keyword: "EquivalentCDADocumentSequence", name: "Equivalent CDA Document Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA0B0 }, keyword: "ReferencedWaveformChannels", name: "Referenced Waveform Channels", vr: &[UNSIGNED_SHORT], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA110 }, keyword: "DateOfDocumentOrVerbalTransactionTrial", name: "Date of Document or Verbal Transaction (Trial)", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA112 }, keyword: "TimeOfDocumentCreationOrVerbalTransactionTrial", name: "Time of Document Creation or Verbal Transaction (Trial)", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA120 }, keyword: "DateTime", name: "DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA121 }, keyword: "Date", name: "Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA122 }, keyword: "Time", name: "Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA123 }, keyword: "PersonName", name: "Person Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA124 }, keyword: "UID", name: "UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA125 }, keyword: "ReportStatusIDTrial", name: "Report Status ID (Trial)", vr: &[CODE_STRING], vm: "2", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA130 }, keyword: "TemporalRangeType", name: "Temporal Range Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA132 }, keyword: "ReferencedSamplePositions", name: "Referenced Sample Positions", vr: &[UNSIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA136 }, keyword: "ReferencedFrameNumbers", name: "Referenced Frame Numbers", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA138 }, keyword: "ReferencedTimeOffsets", name: "Referenced Time Offsets", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA13A }, keyword: "ReferencedDateTime", name: "Referenced DateTime", vr: &[DATE_TIME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA160 }, keyword: "TextValue", name: "Text Value", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA161 }, keyword: "FloatingPointValue", name: "Floating Point Value", vr: &[FLOATING_POINT_DOUBLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA162 }, keyword: "RationalNumeratorValue", name: "Rational Numerator Value", vr: &[SIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA163 }, keyword: "RationalDenominatorValue", name: "Rational Denominator Value", vr: &[UNSIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA167 }, keyword: "ObservationCategoryCodeSequenceTrial", name: "Observation Category Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA168 }, keyword: "ConceptCodeSequence", name: "Concept Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA16A }, keyword: "BibliographicCitationTrial", name: "Bibliographic Citation (Trial)", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA170 }, keyword: "PurposeOfReferenceCodeSequence", name: "Purpose of Reference Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA171 }, keyword: "ObservationUID", name: "Observation UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA172 }, keyword: "ReferencedObservationUIDTrial", name: "Referenced Observation UID (Trial)", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA173 }, keyword: "ReferencedObservationClassTrial", name: "Referenced Observation Class (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA174 }, keyword: "ReferencedObjectObservationClassTrial", name: "Referenced Object Observation Class (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA180 }, keyword: "AnnotationGroupNumber", name: "Annotation Group Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA192 }, keyword: "ObservationDateTrial", name: "Observation Date (Trial)", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA193 }, keyword: "ObservationTimeTrial", name: "Observation Time (Trial)", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA194 }, keyword: "MeasurementAutomationTrial", name: "Measurement Automation (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA195 }, keyword: "ModifierCodeSequence", name: "Modifier Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA224 }, keyword: "IdentificationDescriptionTrial", name: "Identification Description (Trial)", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA290 }, keyword: "CoordinatesSetGeometricTypeTrial", name: "Coordinates Set Geometric Type (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA296 }, keyword: "AlgorithmCodeSequenceTrial", name: "Algorithm Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA297 }, keyword: "AlgorithmDescriptionTrial", name: "Algorithm Description (Trial)", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA29A }, keyword: "PixelCoordinatesSetTrial", name: "Pixel Coordinates Set (Trial)", vr: &[SIGNED_LONG], vm: "2-2n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA300 }, keyword: "MeasuredValueSequence", name: "Measured Value Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA301 }, keyword: "NumericValueQualifierCodeSequence", name: "Numeric Value Qualifier Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA307 }, keyword: "CurrentObserverTrial", name: "Current Observer (Trial)", vr: &[PERSON_NAME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA30A }, keyword: "NumericValue", name: "Numeric Value", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA313 }, keyword: "ReferencedAccessionSequenceTrial", name: "Referenced Accession Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA33A }, keyword: "ReportStatusCommentTrial", name: "Report Status Comment (Trial)", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA340 }, keyword: "ProcedureContextSequenceTrial", name: "Procedure Context Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA352 }, keyword: "VerbalSourceTrial", name: "Verbal Source (Trial)", vr: &[PERSON_NAME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA353 }, keyword: "AddressTrial", name: "Address (Trial)", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA354 }, keyword: "TelephoneNumberTrial", name: "Telephone Number (Trial)", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA358 }, keyword: "VerbalSourceIdentifierCodeSequenceTrial", name: "Verbal Source Identifier Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA360 }, keyword: "PredecessorDocumentsSequence", name: "Predecessor Documents Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA370 }, keyword: "ReferencedRequestSequence", name: "Referenced Request Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA372 }, keyword: "PerformedProcedureCodeSequence", name: "Performed Procedure Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA375 }, keyword: "CurrentRequestedProcedureEvidenceSequence", name: "Current Requested Procedure Evidence Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA380 }, keyword: "ReportDetailSequenceTrial", name: "Report Detail Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA385 }, keyword: "PertinentOtherEvidenceSequence", name: "Pertinent Other Evidence Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA390 }, keyword: "HL7StructuredDocumentReferenceSequence", name: "HL7 Structured Document Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA402 }, keyword: "ObservationSubjectUIDTrial", name: "Observation Subject UID (Trial)", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA403 }, keyword: "ObservationSubjectClassTrial", name: "Observation Subject Class (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA404 }, keyword: "ObservationSubjectTypeCodeSequenceTrial", name: "Observation Subject Type Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA491 }, keyword: "CompletionFlag", name: "Completion Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA492 }, keyword: "CompletionFlagDescription", name: "Completion Flag Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA493 }, keyword: "VerificationFlag", name: "Verification Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA494 }, keyword: "ArchiveRequested", name: "Archive Requested", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA496 }, keyword: "PreliminaryFlag", name: "Preliminary Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA504 }, keyword: "ContentTemplateSequence", name: "Content Template Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA525 }, keyword: "IdenticalDocumentsSequence", name: "Identical Documents Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA600 }, keyword: "ObservationSubjectContextFlagTrial", name: "Observation Subject Context Flag (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA601 }, keyword: "ObserverContextFlagTrial", name: "Observer Context Flag (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA603 }, keyword: "ProcedureContextFlagTrial", name: "Procedure Context Flag (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA730 }, keyword: "ContentSequence", name: "Content Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA731 }, keyword: "RelationshipSequenceTrial", name: "Relationship Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA732 }, keyword: "RelationshipTypeCodeSequenceTrial", name: "Relationship Type Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA744 }, keyword: "LanguageCodeSequenceTrial", name: "Language Code Sequence (Trial)", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA801 }, keyword: "TabulatedValuesSequence", name: "Tabulated Values Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xA992 }, keyword: "UniformResourceLocatorTrial", name: "Uniform Resource Locator (Trial)", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xB020 }, keyword: "WaveformAnnotationSequence", name: "Waveform Annotation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xDB00 }, keyword: "TemplateIdentifier", name: "Template Identifier", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xDB06 }, keyword: "TemplateVersion", name: "Template Version", vr: &[DATE_TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xDB07 }, keyword: "TemplateLocalVersion", name: "Template Local Version", vr: &[DATE_TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xDB0B }, keyword: "TemplateExtensionFlag", name: "Template Extension Flag", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xDB0C }, keyword: "TemplateExtensionOrganizationUID", name: "Template Extension Organization UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xDB0D }, keyword: "TemplateExtensionCreatorUID", name: "Template Extension Creator UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xDB73 }, keyword: "ReferencedContentItemIdentifier", name: "Referenced Content Item Identifier", vr: &[UNSIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE001 }, keyword: "HL7InstanceIdentifier", name: "HL7 Instance Identifier", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE004 }, keyword: "HL7DocumentEffectiveTime", name: "HL7 Document Effective Time", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE006 }, keyword: "HL7DocumentTypeCodeSequence", name: "HL7 Document Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE008 }, keyword: "DocumentClassCodeSequence", name: "Document Class Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE010 }, keyword: "RetrieveURI", name: "Retrieve URI", vr: &[UNIVERSAL_RESOURCE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE011 }, keyword: "RetrieveLocationUID", name: "Retrieve Location UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE020 }, keyword: "TypeOfInstances", name: "Type of Instances", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE021 }, keyword: "DICOMRetrievalSequence", name: "DICOM Retrieval Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE022 }, keyword: "DICOMMediaRetrievalSequence", name: "DICOM Media Retrieval Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE023 }, keyword: "WADORetrievalSequence", name: "WADO Retrieval Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE024 }, keyword: "XDSRetrievalSequence", name: "XDS Retrieval Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE025 }, keyword: "WADORSRetrievalSequence", name: "WADO-RS Retrieval Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE030 }, keyword: "RepositoryUniqueID", name: "Repository Unique ID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0040, element: 0xE031 }, keyword: "HomeCommunityID", name: "Home Community ID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0042, element: 0x0010 }, keyword: "DocumentTitle", name: "Document Title", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0042, element: 0x0011 }, keyword: "EncapsulatedDocument", name: "Encapsulated Document", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0042, element: 0x0012 }, keyword: "MIMETypeOfEncapsulatedDocument", name: "MIME Type of Encapsulated Document", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0042, element: 0x0013 }, keyword: "SourceInstanceSequence", name: "Source Instance Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0042, element: 0x0014 }, keyword: "ListOfMIMETypes", name: "List of MIME Types", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0042, element: 0x0015 }, keyword: "EncapsulatedDocumentLength", name: "Encapsulated Document Length", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0004 }, keyword: "CalibrationImage", name: "Calibration Image", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0010 }, keyword: "DeviceSequence", name: "Device Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0012 }, keyword: "ContainerComponentTypeCodeSequence", name: "Container Component Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0013 }, keyword: "ContainerComponentThickness", name: "Container Component Thickness", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0014 }, keyword: "DeviceLength", name: "Device Length", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0015 }, keyword: "ContainerComponentWidth", name: "Container Component Width", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0016 }, keyword: "DeviceDiameter", name: "Device Diameter", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0017 }, keyword: "DeviceDiameterUnits", name: "Device Diameter Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0018 }, keyword: "DeviceVolume", name: "Device Volume", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0019 }, keyword: "InterMarkerDistance", name: "Inter-Marker Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x001A }, keyword: "ContainerComponentMaterial", name: "Container Component Material", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x001B }, keyword: "ContainerComponentID", name: "Container Component ID", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x001C }, keyword: "ContainerComponentLength", name: "Container Component Length", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x001D }, keyword: "ContainerComponentDiameter", name: "Container Component Diameter", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x001E }, keyword: "ContainerComponentDescription", name: "Container Component Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0020 }, keyword: "DeviceDescription", name: "Device Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0050, element: 0x0021 }, keyword: "LongDeviceDescription", name: "Long Device Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0010 }, keyword: "EnergyWindowVector", name: "Energy Window Vector", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0011 }, keyword: "NumberOfEnergyWindows", name: "Number of Energy Windows", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0012 }, keyword: "EnergyWindowInformationSequence", name: "Energy Window Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0013 }, keyword: "EnergyWindowRangeSequence", name: "Energy Window Range Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0014 }, keyword: "EnergyWindowLowerLimit", name: "Energy Window Lower Limit", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0015 }, keyword: "EnergyWindowUpperLimit", name: "Energy Window Upper Limit", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0016 }, keyword: "RadiopharmaceuticalInformationSequence", name: "Radiopharmaceutical Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0017 }, keyword: "ResidualSyringeCounts", name: "Residual Syringe Counts", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0018 }, keyword: "EnergyWindowName", name: "Energy Window Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0020 }, keyword: "DetectorVector", name: "Detector Vector", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0021 }, keyword: "NumberOfDetectors", name: "Number of Detectors", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0022 }, keyword: "DetectorInformationSequence", name: "Detector Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0030 }, keyword: "PhaseVector", name: "Phase Vector", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0031 }, keyword: "NumberOfPhases", name: "Number of Phases", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0032 }, keyword: "PhaseInformationSequence", name: "Phase Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0033 }, keyword: "NumberOfFramesInPhase", name: "Number of Frames in Phase", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0036 }, keyword: "PhaseDelay", name: "Phase Delay", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0038 }, keyword: "PauseBetweenFrames", name: "Pause Between Frames", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0039 }, keyword: "PhaseDescription", name: "Phase Description", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0050 }, keyword: "RotationVector", name: "Rotation Vector", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0051 }, keyword: "NumberOfRotations", name: "Number of Rotations", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0052 }, keyword: "RotationInformationSequence", name: "Rotation Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0053 }, keyword: "NumberOfFramesInRotation", name: "Number of Frames in Rotation", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0060 }, keyword: "RRIntervalVector", name: "R-R Interval Vector", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0061 }, keyword: "NumberOfRRIntervals", name: "Number of R-R Intervals", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0062 }, keyword: "GatedInformationSequence", name: "Gated Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0063 }, keyword: "DataInformationSequence", name: "Data Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0070 }, keyword: "TimeSlotVector", name: "Time Slot Vector", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0071 }, keyword: "NumberOfTimeSlots", name: "Number of Time Slots", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0072 }, keyword: "TimeSlotInformationSequence", name: "Time Slot Information Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0073 }, keyword: "TimeSlotTime", name: "Time Slot Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0080 }, keyword: "SliceVector", name: "Slice Vector", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0081 }, keyword: "NumberOfSlices", name: "Number of Slices", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0090 }, keyword: "AngularViewVector", name: "Angular View Vector", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0100 }, keyword: "TimeSliceVector", name: "Time Slice Vector", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0101 }, keyword: "NumberOfTimeSlices", name: "Number of Time Slices", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0200 }, keyword: "StartAngle", name: "Start Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0202 }, keyword: "TypeOfDetectorMotion", name: "Type of Detector Motion", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0210 }, keyword: "TriggerVector", name: "Trigger Vector", vr: &[INTEGER_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0211 }, keyword: "NumberOfTriggersInPhase", name: "Number of Triggers in Phase", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0220 }, keyword: "ViewCodeSequence", name: "View Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0222 }, keyword: "ViewModifierCodeSequence", name: "View Modifier Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0300 }, keyword: "RadionuclideCodeSequence", name: "Radionuclide Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0302 }, keyword: "AdministrationRouteCodeSequence", name: "Administration Route Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0304 }, keyword: "RadiopharmaceuticalCodeSequence", name: "Radiopharmaceutical Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0306 }, keyword: "CalibrationDataSequence", name: "Calibration Data Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0308 }, keyword: "EnergyWindowNumber", name: "Energy Window Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0400 }, keyword: "ImageID", name: "Image ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0410 }, keyword: "PatientOrientationCodeSequence", name: "Patient Orientation Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0412 }, keyword: "PatientOrientationModifierCodeSequence", name: "Patient Orientation Modifier Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0414 }, keyword: "PatientGantryRelationshipCodeSequence", name: "Patient Gantry Relationship Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0500 }, keyword: "SliceProgressionDirection", name: "Slice Progression Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x0501 }, keyword: "ScanProgressionDirection", name: "Scan Progression Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1000 }, keyword: "SeriesType", name: "Series Type", vr: &[CODE_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1001 }, keyword: "Units", name: "Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1002 }, keyword: "CountsSource", name: "Counts Source", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1004 }, keyword: "ReprojectionMethod", name: "Reprojection Method", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1006 }, keyword: "SUVType", name: "SUV Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1100 }, keyword: "RandomsCorrectionMethod", name: "Randoms Correction Method", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1101 }, keyword: "AttenuationCorrectionMethod", name: "Attenuation Correction Method", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1102 }, keyword: "DecayCorrection", name: "Decay Correction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1103 }, keyword: "ReconstructionMethod", name: "Reconstruction Method", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1104 }, keyword: "DetectorLinesOfResponseUsed", name: "Detector Lines of Response Used", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1105 }, keyword: "ScatterCorrectionMethod", name: "Scatter Correction Method", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1200 }, keyword: "AxialAcceptance", name: "Axial Acceptance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1201 }, keyword: "AxialMash", name: "Axial Mash", vr: &[INTEGER_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1202 }, keyword: "TransverseMash", name: "Transverse Mash", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1203 }, keyword: "DetectorElementSize", name: "Detector Element Size", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1210 }, keyword: "CoincidenceWindowWidth", name: "Coincidence Window Width", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1220 }, keyword: "SecondaryCountsType", name: "Secondary Counts Type", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1300 }, keyword: "FrameReferenceTime", name: "Frame Reference Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1310 }, keyword: "PrimaryPromptsCountsAccumulated", name: "Primary (Prompts) Counts Accumulated", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1311 }, keyword: "SecondaryCountsAccumulated", name: "Secondary Counts Accumulated", vr: &[INTEGER_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1320 }, keyword: "SliceSensitivityFactor", name: "Slice Sensitivity Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1321 }, keyword: "DecayFactor", name: "Decay Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1322 }, keyword: "DoseCalibrationFactor", name: "Dose Calibration Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1323 }, keyword: "ScatterFractionFactor", name: "Scatter Fraction Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1324 }, keyword: "DeadTimeFactor", name: "Dead Time Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1330 }, keyword: "ImageIndex", name: "Image Index", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1400 }, keyword: "CountsIncluded", name: "Counts Included", vr: &[CODE_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0054, element: 0x1401 }, keyword: "DeadTimeCorrectionFlag", name: "Dead Time Correction Flag", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0060, element: 0x3000 }, keyword: "HistogramSequence", name: "Histogram Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0060, element: 0x3002 }, keyword: "HistogramNumberOfBins", name: "Histogram Number of Bins", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0060, element: 0x3004 }, keyword: "HistogramFirstBinValue", name: "Histogram First Bin Value", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0060, element: 0x3006 }, keyword: "HistogramLastBinValue", name: "Histogram Last Bin Value", vr: &[UNSIGNED_SHORT, SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0060, element: 0x3008 }, keyword: "HistogramBinWidth", name: "Histogram Bin Width", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0060, element: 0x3010 }, keyword: "HistogramExplanation", name: "Histogram Explanation", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0060, element: 0x3020 }, keyword: "HistogramData", name: "Histogram Data", vr: &[UNSIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0001 }, keyword: "SegmentationType", name: "Segmentation Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0002 }, keyword: "SegmentSequence", name: "Segment Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0003 }, keyword: "SegmentedPropertyCategoryCodeSequence", name: "Segmented Property Category Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0004 }, keyword: "SegmentNumber", name: "Segment Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0005 }, keyword: "SegmentLabel", name: "Segment Label", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0006 }, keyword: "SegmentDescription", name: "Segment Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0008 }, keyword: "SegmentAlgorithmType", name: "Segment Algorithm Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0009 }, keyword: "SegmentAlgorithmName", name: "Segment Algorithm Name", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x000A }, keyword: "SegmentIdentificationSequence", name: "Segment Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x000B }, keyword: "ReferencedSegmentNumber", name: "Referenced Segment Number", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x000C }, keyword: "RecommendedDisplayGrayscaleValue", name: "Recommended Display Grayscale Value", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x000D }, keyword: "RecommendedDisplayCIELabValue", name: "Recommended Display CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x000E }, keyword: "MaximumFractionalValue", name: "Maximum Fractional Value", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x000F }, keyword: "SegmentedPropertyTypeCodeSequence", name: "Segmented Property Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0010 }, keyword: "SegmentationFractionalType", name: "Segmentation Fractional Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0011 }, keyword: "SegmentedPropertyTypeModifierCodeSequence", name: "Segmented Property Type Modifier Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0012 }, keyword: "UsedSegmentsSequence", name: "Used Segments Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0013 }, keyword: "SegmentsOverlap", name: "Segments Overlap", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0020 }, keyword: "TrackingID", name: "Tracking ID", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0062, element: 0x0021 }, keyword: "TrackingUID", name: "Tracking UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0064, element: 0x0002 }, keyword: "DeformableRegistrationSequence", name: "Deformable Registration Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0064, element: 0x0003 }, keyword: "SourceFrameOfReferenceUID", name: "Source Frame of Reference UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0064, element: 0x0005 }, keyword: "DeformableRegistrationGridSequence", name: "Deformable Registration Grid Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0064, element: 0x0007 }, keyword: "GridDimensions", name: "Grid Dimensions", vr: &[UNSIGNED_LONG], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0064, element: 0x0008 }, keyword: "GridResolution", name: "Grid Resolution", vr: &[FLOATING_POINT_DOUBLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0064, element: 0x0009 }, keyword: "VectorGridData", name: "Vector Grid Data", vr: &[OTHER_FLOAT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0064, element: 0x000F }, keyword: "PreDeformationMatrixRegistrationSequence", name: "Pre Deformation Matrix Registration Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0064, element: 0x0010 }, keyword: "PostDeformationMatrixRegistrationSequence", name: "Post Deformation Matrix Registration Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0001 }, keyword: "NumberOfSurfaces", name: "Number of Surfaces", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0002 }, keyword: "SurfaceSequence", name: "Surface Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0003 }, keyword: "SurfaceNumber", name: "Surface Number", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0004 }, keyword: "SurfaceComments", name: "Surface Comments", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0009 }, keyword: "SurfaceProcessing", name: "Surface Processing", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0010 }, keyword: "FiniteVolume", name: "Finite Volume", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0011 }, keyword: "Manifold", name: "Manifold", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0012 }, keyword: "SurfacePointsSequence", name: "Surface Points Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0015 }, keyword: "NumberOfSurfacePoints", name: "Number of Surface Points", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0016 }, keyword: "PointCoordinatesData", name: "Point Coordinates Data", vr: &[OTHER_FLOAT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0021 }, keyword: "VectorCoordinateData", name: "Vector Coordinate Data", vr: &[OTHER_FLOAT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0040 }, keyword: "LongPrimitivePointIndexList", name: "Long Primitive Point Index List", vr: &[OTHER_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0066, element: 0x0041 }, keyword: "LongTrianglePointIndexList", name: "Long Triangle Point Index List", vr: &[OTHER_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0001 }, keyword: "GraphicAnnotationSequence", name: "Graphic Annotation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0002 }, keyword: "GraphicLayer", name: "Graphic Layer", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0003 }, keyword: "BoundingBoxAnnotationUnits", name: "Bounding Box Annotation Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0004 }, keyword: "AnchorPointAnnotationUnits", name: "Anchor Point Annotation Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0005 }, keyword: "GraphicAnnotationUnits", name: "Graphic Annotation Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0006 }, keyword: "UnformattedTextValue", name: "Unformatted Text Value", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0008 }, keyword: "TextObjectSequence", name: "Text Object Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0009 }, keyword: "GraphicObjectSequence", name: "Graphic Object Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0010 }, keyword: "BoundingBoxTopLeftHandCorner", name: "Bounding Box Top Left Hand Corner", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0011 }, keyword: "BoundingBoxBottomRightHandCorner", name: "Bounding Box Bottom Right Hand Corner", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0012 }, keyword: "BoundingBoxTextHorizontalJustification", name: "Bounding Box Text Horizontal Justification", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0014 }, keyword: "AnchorPoint", name: "Anchor Point", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0015 }, keyword: "AnchorPointVisibility", name: "Anchor Point Visibility", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0020 }, keyword: "GraphicDimensions", name: "Graphic Dimensions", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0021 }, keyword: "NumberOfGraphicPoints", name: "Number of Graphic Points", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0022 }, keyword: "GraphicData", name: "Graphic Data", vr: &[FLOATING_POINT_SINGLE], vm: "2-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0023 }, keyword: "GraphicType", name: "Graphic Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0024 }, keyword: "GraphicFilled", name: "Graphic Filled", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0040 }, keyword: "ImageRotationRetired", name: "Image Rotation (Retired)", vr: &[INTEGER_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0041 }, keyword: "ImageHorizontalFlip", name: "Image Horizontal Flip", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0042 }, keyword: "ImageRotation", name: "Image Rotation", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0050 }, keyword: "DisplayedAreaTopLeftHandCornerTrial", name: "Displayed Area Top Left Hand Corner (Trial)", vr: &[UNSIGNED_SHORT], vm: "2", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0051 }, keyword: "DisplayedAreaBottomRightHandCornerTrial", name: "Displayed Area Bottom Right Hand Corner (Trial)", vr: &[UNSIGNED_SHORT], vm: "2", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0052 }, keyword: "DisplayedAreaTopLeftHandCorner", name: "Displayed Area Top Left Hand Corner", vr: &[SIGNED_LONG], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0053 }, keyword: "DisplayedAreaBottomRightHandCorner", name: "Displayed Area Bottom Right Hand Corner", vr: &[SIGNED_LONG], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x005A }, keyword: "DisplayedAreaSelectionSequence", name: "Displayed Area Selection Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0060 }, keyword: "GraphicLayerSequence", name: "Graphic Layer Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0062 }, keyword: "GraphicLayerOrder", name: "Graphic Layer Order", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0066 }, keyword: "GraphicLayerRecommendedDisplayGrayscaleValue", name: "Graphic Layer Recommended Display Grayscale Value", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0067 }, keyword: "GraphicLayerRecommendedDisplayRGBValue", name: "Graphic Layer Recommended Display RGB Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0068 }, keyword: "GraphicLayerDescription", name: "Graphic Layer Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0080 }, keyword: "ContentLabel", name: "Content Label", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0081 }, keyword: "ContentDescription", name: "Content Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0082 }, keyword: "PresentationCreationDate", name: "Presentation Creation Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0083 }, keyword: "PresentationCreationTime", name: "Presentation Creation Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0084 }, keyword: "ContentCreatorName", name: "Content Creator's Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0086 }, keyword: "ContentCreatorIdentificationCodeSequence", name: "Content Creator's Identification Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0087 }, keyword: "AlternateContentDescriptionSequence", name: "Alternate Content Description Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0100 }, keyword: "PresentationSizeMode", name: "Presentation Size Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0101 }, keyword: "PresentationPixelSpacing", name: "Presentation Pixel Spacing", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0102 }, keyword: "PresentationPixelAspectRatio", name: "Presentation Pixel Aspect Ratio", vr: &[INTEGER_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0103 }, keyword: "PresentationPixelMagnificationRatio", name: "Presentation Pixel Magnification Ratio", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0207 }, keyword: "GraphicGroupLabel", name: "Graphic Group Label", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0208 }, keyword: "GraphicGroupDescription", name: "Graphic Group Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0209 }, keyword: "CompoundGraphicSequence", name: "Compound Graphic Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0226 }, keyword: "CompoundGraphicInstanceID", name: "Compound Graphic Instance ID", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0227 }, keyword: "FontName", name: "Font Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0228 }, keyword: "FontNameType", name: "Font Name Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0229 }, keyword: "CSSFontName", name: "CSS Font Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0230 }, keyword: "RotationAngle", name: "Rotation Angle", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0231 }, keyword: "TextStyleSequence", name: "Text Style Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0232 }, keyword: "LineStyleSequence", name: "Line Style Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0233 }, keyword: "FillStyleSequence", name: "Fill Style Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0234 }, keyword: "GraphicGroupSequence", name: "Graphic Group Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0241 }, keyword: "TextColorCIELabValue", name: "Text Color CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0242 }, keyword: "HorizontalAlignment", name: "Horizontal Alignment", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0243 }, keyword: "VerticalAlignment", name: "Vertical Alignment", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0244 }, keyword: "ShadowStyle", name: "Shadow Style", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0245 }, keyword: "ShadowOffsetX", name: "Shadow Offset X", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0246 }, keyword: "ShadowOffsetY", name: "Shadow Offset Y", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0247 }, keyword: "ShadowColorCIELabValue", name: "Shadow Color CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0248 }, keyword: "Underlined", name: "Underlined", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0249 }, keyword: "Bold", name: "Bold", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0250 }, keyword: "Italic", name: "Italic", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0251 }, keyword: "PatternOnColorCIELabValue", name: "Pattern On Color CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0252 }, keyword: "PatternOffColorCIELabValue", name: "Pattern Off Color CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0253 }, keyword: "LineThickness", name: "Line Thickness", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0254 }, keyword: "LineDashingStyle", name: "Line Dashing Style", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0255 }, keyword: "LinePattern", name: "Line Pattern", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0256 }, keyword: "FillPattern", name: "Fill Pattern", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0257 }, keyword: "FillMode", name: "Fill Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0258 }, keyword: "ShadowOpacity", name: "Shadow Opacity", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0261 }, keyword: "GapLength", name: "Gap Length", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0262 }, keyword: "DiameterOfVisibility", name: "Diameter of Visibility", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0273 }, keyword: "RotationPoint", name: "Rotation Point", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0274 }, keyword: "TickAlignment", name: "Tick Alignment", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0278 }, keyword: "ShowTickLabel", name: "Show Tick Label", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0279 }, keyword: "TickLabelAlignment", name: "Tick Label Alignment", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0282 }, keyword: "CompoundGraphicUnits", name: "Compound Graphic Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0284 }, keyword: "PatternOnOpacity", name: "Pattern On Opacity", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0285 }, keyword: "PatternOffOpacity", name: "Pattern Off Opacity", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0287 }, keyword: "MajorTicksSequence", name: "Major Ticks Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0288 }, keyword: "TickPosition", name: "Tick Position", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0289 }, keyword: "TickLabel", name: "Tick Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0294 }, keyword: "CompoundGraphicType", name: "Compound Graphic Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0295 }, keyword: "GraphicGroupID", name: "Graphic Group ID", vr: &[UNSIGNED_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0306 }, keyword: "ShapeType", name: "Shape Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0308 }, keyword: "RegistrationSequence", name: "Registration Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0309 }, keyword: "MatrixRegistrationSequence", name: "Matrix Registration Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x030A }, keyword: "MatrixSequence", name: "Matrix Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x030B }, keyword: "FrameOfReferenceToDisplayedCoordinateSystemTransformationMatrix", name: "Frame of Reference to Displayed Coordinate System Transformation Matrix", vr: &[FLOATING_POINT_DOUBLE], vm: "16", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x030C }, keyword: "FrameOfReferenceTransformationMatrixType", name: "Frame of Reference Transformation Matrix Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x030D }, keyword: "RegistrationTypeCodeSequence", name: "Registration Type Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x030F }, keyword: "FiducialDescription", name: "Fiducial Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0310 }, keyword: "FiducialIdentifier", name: "Fiducial Identifier", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0311 }, keyword: "FiducialIdentifierCodeSequence", name: "Fiducial Identifier Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0312 }, keyword: "ContourUncertaintyRadius", name: "Contour Uncertainty Radius", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0314 }, keyword: "UsedFiducialsSequence", name: "Used Fiducials Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0318 }, keyword: "GraphicCoordinatesDataSequence", name: "Graphic Coordinates Data Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x031A }, keyword: "FiducialUID", name: "Fiducial UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x031C }, keyword: "FiducialSetSequence", name: "Fiducial Set Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x031E }, keyword: "FiducialSequence", name: "Fiducial Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0401 }, keyword: "GraphicLayerRecommendedDisplayCIELabValue", name: "Graphic Layer Recommended Display CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0402 }, keyword: "BlendingSequence", name: "Blending Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0403 }, keyword: "RelativeOpacity", name: "Relative Opacity", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0404 }, keyword: "ReferencedSpatialRegistrationSequence", name: "Referenced Spatial Registration Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x0405 }, keyword: "BlendingPosition", name: "Blending Position", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x1101 }, keyword: "PresentationDisplayCollectionUID", name: "Presentation Display Collection UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0070, element: 0x1102 }, keyword: "PresentationSequenceCollectionUID", name: "Presentation Sequence Collection UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0002 }, keyword: "HangingProtocolName", name: "Hanging Protocol Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0004 }, keyword: "HangingProtocolDescription", name: "Hanging Protocol Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0006 }, keyword: "HangingProtocolLevel", name: "Hanging Protocol Level", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0008 }, keyword: "HangingProtocolCreator", name: "Hanging Protocol Creator", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x000A }, keyword: "HangingProtocolCreationDateTime", name: "Hanging Protocol Creation DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x000C }, keyword: "HangingProtocolDefinitionSequence", name: "Hanging Protocol Definition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x000E }, keyword: "HangingProtocolUserIdentificationCodeSequence", name: "Hanging Protocol User Identification Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0010 }, keyword: "HangingProtocolUserGroupName", name: "Hanging Protocol User Group Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0012 }, keyword: "SourceHangingProtocolSequence", name: "Source Hanging Protocol Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0014 }, keyword: "NumberOfPriorsReferenced", name: "Number of Priors Referenced", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0020 }, keyword: "ImageSetsSequence", name: "Image Sets Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0022 }, keyword: "ImageSetSelectorSequence", name: "Image Set Selector Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0024 }, keyword: "ImageSetSelectorUsageFlag", name: "Image Set Selector Usage Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0026 }, keyword: "SelectorAttribute", name: "Selector Attribute", vr: &[ATTRIBUTE_TAG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0028 }, keyword: "SelectorValueNumber", name: "Selector Value Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0030 }, keyword: "TimeBasedImageSetsSequence", name: "Time Based Image Sets Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0032 }, keyword: "ImageSetNumber", name: "Image Set Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0034 }, keyword: "ImageSetSelectorCategory", name: "Image Set Selector Category", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0038 }, keyword: "RelativeTime", name: "Relative Time", vr: &[UNSIGNED_SHORT], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x003A }, keyword: "RelativeTimeUnits", name: "Relative Time Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x003C }, keyword: "AbstractPriorValue", name: "Abstract Prior Value", vr: &[SIGNED_SHORT], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x003E }, keyword: "AbstractPriorCodeSequence", name: "Abstract Prior Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0040 }, keyword: "ImageSetLabel", name: "Image Set Label", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0050 }, keyword: "SelectorAttributeVR", name: "Selector Attribute VR", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0052 }, keyword: "SelectorSequencePointer", name: "Selector Sequence Pointer", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0054 }, keyword: "SelectorSequencePointerPrivateCreator", name: "Selector Sequence Pointer Private Creator", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0056 }, keyword: "SelectorAttributePrivateCreator", name: "Selector Attribute Private Creator", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0060 }, keyword: "SelectorATValue", name: "Selector AT Value", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0062 }, keyword: "SelectorCSValue", name: "Selector CS Value", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0064 }, keyword: "SelectorISValue", name: "Selector IS Value", vr: &[INTEGER_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0066 }, keyword: "SelectorLOValue", name: "Selector LO Value", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0068 }, keyword: "SelectorLTValue", name: "Selector LT Value", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x006A }, keyword: "SelectorPNValue", name: "Selector PN Value", vr: &[PERSON_NAME], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x006C }, keyword: "SelectorSHValue", name: "Selector SH Value", vr: &[SHORT_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x006E }, keyword: "SelectorSTValue", name: "Selector ST Value", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0070 }, keyword: "SelectorUTValue", name: "Selector UT Value", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0072 }, keyword: "SelectorDSValue", name: "Selector DS Value", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0074 }, keyword: "SelectorFDValue", name: "Selector FD Value", vr: &[FLOATING_POINT_DOUBLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0076 }, keyword: "SelectorFLValue", name: "Selector FL Value", vr: &[FLOATING_POINT_SINGLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0078 }, keyword: "SelectorULValue", name: "Selector UL Value", vr: &[UNSIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x007A }, keyword: "SelectorUSValue", name: "Selector US Value", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x007C }, keyword: "SelectorSLValue", name: "Selector SL Value", vr: &[SIGNED_LONG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x007E }, keyword: "SelectorSSValue", name: "Selector SS Value", vr: &[SIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0080 }, keyword: "SelectorCodeSequenceValue", name: "Selector Code Sequence Value", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0100 }, keyword: "NumberOfScreens", name: "Number of Screens", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0102 }, keyword: "NominalScreenDefinitionSequence", name: "Nominal Screen Definition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0104 }, keyword: "NumberOfVerticalPixels", name: "Number of Vertical Pixels", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0106 }, keyword: "NumberOfHorizontalPixels", name: "Number of Horizontal Pixels", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0108 }, keyword: "DisplayEnvironmentSpatialPosition", name: "Display Environment Spatial Position", vr: &[FLOATING_POINT_DOUBLE], vm: "4", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x010A }, keyword: "ScreenMinimumGrayscaleBitDepth", name: "Screen Minimum Grayscale Bit Depth", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x010C }, keyword: "ScreenMinimumColorBitDepth", name: "Screen Minimum Color Bit Depth", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x010E }, keyword: "ApplicationMaximumRepaintTime", name: "Application Maximum Repaint Time", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0200 }, keyword: "DisplaySetsSequence", name: "Display Sets Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0202 }, keyword: "DisplaySetNumber", name: "Display Set Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0203 }, keyword: "DisplaySetLabel", name: "Display Set Label", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0204 }, keyword: "DisplaySetPresentationGroup", name: "Display Set Presentation Group", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0206 }, keyword: "DisplaySetPresentationGroupDescription", name: "Display Set Presentation Group Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0208 }, keyword: "PartialDataDisplayHandling", name: "Partial Data Display Handling", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0210 }, keyword: "SynchronizedScrollingSequence", name: "Synchronized Scrolling Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0212 }, keyword: "DisplaySetScrollingGroup", name: "Display Set Scrolling Group", vr: &[UNSIGNED_SHORT], vm: "2-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0214 }, keyword: "NavigationIndicatorSequence", name: "Navigation Indicator Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0216 }, keyword: "NavigationDisplaySet", name: "Navigation Display Set", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0218 }, keyword: "ReferenceDisplaySets", name: "Reference Display Sets", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0300 }, keyword: "ImageBoxesSequence", name: "Image Boxes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0302 }, keyword: "ImageBoxNumber", name: "Image Box Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0304 }, keyword: "ImageBoxLayoutType", name: "Image Box Layout Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0306 }, keyword: "ImageBoxTileHorizontalDimension", name: "Image Box Tile Horizontal Dimension", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0308 }, keyword: "ImageBoxTileVerticalDimension", name: "Image Box Tile Vertical Dimension", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0310 }, keyword: "ImageBoxScrollDirection", name: "Image Box Scroll Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0312 }, keyword: "ImageBoxSmallScrollType", name: "Image Box Small Scroll Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0314 }, keyword: "ImageBoxSmallScrollAmount", name: "Image Box Small Scroll Amount", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0316 }, keyword: "ImageBoxLargeScrollType", name: "Image Box Large Scroll Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0318 }, keyword: "ImageBoxLargeScrollAmount", name: "Image Box Large Scroll Amount", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0320 }, keyword: "ImageBoxOverlapPriority", name: "Image Box Overlap Priority", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0330 }, keyword: "CineRelativeToRealTime", name: "Cine Relative to Real-Time", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0400 }, keyword: "FilterOperationsSequence", name: "Filter Operations Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0402 }, keyword: "FilterByCategory", name: "Filter-by Category", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0404 }, keyword: "FilterByAttributePresence", name: "Filter-by Attribute Presence", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0406 }, keyword: "FilterByOperator", name: "Filter-by Operator", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0420 }, keyword: "StructuredDisplayBackgroundCIELabValue", name: "Structured Display Background CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0421 }, keyword: "EmptyImageBoxCIELabValue", name: "Empty Image Box CIELab Value", vr: &[UNSIGNED_SHORT], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0422 }, keyword: "StructuredDisplayImageBoxSequence", name: "Structured Display Image Box Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0424 }, keyword: "StructuredDisplayTextBoxSequence", name: "Structured Display Text Box Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0427 }, keyword: "ReferencedFirstFrameSequence", name: "Referenced First Frame Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0430 }, keyword: "ImageBoxSynchronizationSequence", name: "Image Box Synchronization Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0432 }, keyword: "SynchronizedImageBoxList", name: "Synchronized Image Box List", vr: &[UNSIGNED_SHORT], vm: "2-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0434 }, keyword: "TypeOfSynchronization", name: "Type of Synchronization", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0500 }, keyword: "BlendingOperationType", name: "Blending Operation Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0510 }, keyword: "ReformattingOperationType", name: "Reformatting Operation Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0512 }, keyword: "ReformattingThickness", name: "Reformatting Thickness", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0514 }, keyword: "ReformattingInterval", name: "Reformatting Interval", vr: &[FLOATING_POINT_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0516 }, keyword: "ReformattingOperationInitialViewDirection", name: "Reformatting Operation Initial View Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0520 }, keyword: "ThreeDRenderingType", name: "3D Rendering Type", vr: &[CODE_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0600 }, keyword: "SortingOperationsSequence", name: "Sorting Operations Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0602 }, keyword: "SortByCategory", name: "Sort-by Category", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0604 }, keyword: "SortingDirection", name: "Sorting Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0700 }, keyword: "DisplaySetPatientOrientation", name: "Display Set Patient Orientation", vr: &[CODE_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0702 }, keyword: "VOIType", name: "VOI Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0704 }, keyword: "PseudoColorType", name: "Pseudo-Color Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0705 }, keyword: "PseudoColorPaletteInstanceReferenceSequence", name: "Pseudo-Color Palette Instance Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0706 }, keyword: "ShowGrayscaleInverted", name: "Show Grayscale Inverted", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0710 }, keyword: "ShowImageTrueSizeFlag", name: "Show Image True Size Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0712 }, keyword: "ShowGraphicAnnotationFlag", name: "Show Graphic Annotation Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0714 }, keyword: "ShowPatientDemographicsFlag", name: "Show Patient Demographics Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0716 }, keyword: "ShowAcquisitionTechniquesFlag", name: "Show Acquisition Techniques Flag", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0717 }, keyword: "DisplaySetHorizontalJustification", name: "Display Set Horizontal Justification", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0072, element: 0x0718 }, keyword: "DisplaySetVerticalJustification", name: "Display Set Vertical Justification", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0088, element: 0x0130 }, keyword: "StorageMediaFileSetID", name: "Storage Media File-set ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0088, element: 0x0140 }, keyword: "StorageMediaFileSetUID", name: "Storage Media File-set UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0088, element: 0x0200 }, keyword: "IconImageSequence", name: "Icon Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0088, element: 0x0904 }, keyword: "TopicTitle", name: "Topic Title", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0088, element: 0x0906 }, keyword: "TopicSubject", name: "Topic Subject", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0088, element: 0x0910 }, keyword: "TopicAuthor", name: "Topic Author", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0088, element: 0x0912 }, keyword: "TopicKeywords", name: "Topic Keywords", vr: &[LONG_STRING], vm: "1-32", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x0100, element: 0x0410 }, keyword: "SOPInstanceStatus", name: "SOP Instance Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0100, element: 0x0420 }, keyword: "SOPAuthorizationDateTime", name: "SOP Authorization DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0100, element: 0x0424 }, keyword: "SOPAuthorizationComment", name: "SOP Authorization Comment", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0100, element: 0x0426 }, keyword: "AuthorizationEquipmentCertificationNumber", name: "Authorization Equipment Certification Number", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0005 }, keyword: "MACIDNumber", name: "MAC ID Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0010 }, keyword: "MACCalculationTransferSyntaxUID", name: "MAC Calculation Transfer Syntax UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0015 }, keyword: "MACAlgorithm", name: "MAC Algorithm", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0020 }, keyword: "DataElementsSigned", name: "Data Elements Signed", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0100 }, keyword: "DigitalSignatureUID", name: "Digital Signature UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0105 }, keyword: "DigitalSignatureDateTime", name: "Digital Signature DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0110 }, keyword: "CertificateType", name: "Certificate Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0115 }, keyword: "CertificateOfSigner", name: "Certificate of Signer", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0120 }, keyword: "Signature", name: "Signature", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0305 }, keyword: "CertifiedTimestampType", name: "Certified Timestamp Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0310 }, keyword: "CertifiedTimestamp", name: "Certified Timestamp", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0401 }, keyword: "DigitalSignaturePurposeCodeSequence", name: "Digital Signature Purpose Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0402 }, keyword: "ReferencedDigitalSignatureSequence", name: "Referenced Digital Signature Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0403 }, keyword: "ReferencedSOPInstanceMACSequence", name: "Referenced SOP Instance MAC Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0404 }, keyword: "MAC", name: "MAC", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0500 }, keyword: "EncryptedAttributesSequence", name: "Encrypted Attributes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0510 }, keyword: "EncryptedContentTransferSyntaxUID", name: "Encrypted Content Transfer Syntax UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0520 }, keyword: "EncryptedContent", name: "Encrypted Content", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0550 }, keyword: "ModifiedAttributesSequence", name: "Modified Attributes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0551 }, keyword: "NonconformingModifiedAttributesSequence", name: "Nonconforming Modified Attributes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0552 }, keyword: "NonconformingDataElementValue", name: "Nonconforming Data Element Value", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0561 }, keyword: "OriginalAttributesSequence", name: "Original Attributes Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0562 }, keyword: "AttributeModificationDateTime", name: "Attribute Modification DateTime", vr: &[DATE_TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0563 }, keyword: "ModifyingSystem", name: "Modifying System", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0564 }, keyword: "SourceOfPreviousValues", name: "Source of Previous Values", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0565 }, keyword: "ReasonForTheAttributeModification", name: "Reason for the Attribute Modification", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x0400, element: 0x0600 }, keyword: "InstanceOriginStatus", name: "Instance Origin Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x2030, element: 0x0020 }, keyword: "TextString", name: "Text String", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x2050, element: 0x0010 }, keyword: "PresentationLUTSequence", name: "Presentation LUT Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x2050, element: 0x0020 }, keyword: "PresentationLUTShape", name: "Presentation LUT Shape", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x2050, element: 0x0500 }, keyword: "ReferencedPresentationLUTSequence", name: "Referenced Presentation LUT Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x2200, element: 0x0002 }, keyword: "LabelText", name: "Label Text", vr: &[UNLIMITED_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x2200, element: 0x0005 }, keyword: "BarcodeValue", name: "Barcode Value", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0002 }, keyword: "RTImageLabel", name: "RT Image Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0003 }, keyword: "RTImageName", name: "RT Image Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0004 }, keyword: "RTImageDescription", name: "RT Image Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x000A }, keyword: "ReportedValuesOrigin", name: "Reported Values Origin", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x000C }, keyword: "RTImagePlane", name: "RT Image Plane", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x000D }, keyword: "XRayImageReceptorTranslation", name: "X-Ray Image Receptor Translation", vr: &[DECIMAL_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x000E }, keyword: "XRayImageReceptorAngle", name: "X-Ray Image Receptor Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0010 }, keyword: "RTImageOrientation", name: "RT Image Orientation", vr: &[DECIMAL_STRING], vm: "6", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0011 }, keyword: "ImagePlanePixelSpacing", name: "Image Plane Pixel Spacing", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0012 }, keyword: "RTImagePosition", name: "RT Image Position", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0020 }, keyword: "RadiationMachineName", name: "Radiation Machine Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0022 }, keyword: "RadiationMachineSAD", name: "Radiation Machine SAD", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0024 }, keyword: "RadiationMachineSSD", name: "Radiation Machine SSD", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0026 }, keyword: "RTImageSID", name: "RT Image SID", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0028 }, keyword: "SourceToReferenceObjectDistance", name: "Source to Reference Object Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0029 }, keyword: "FractionNumber", name: "Fraction Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0030 }, keyword: "ExposureSequence", name: "Exposure Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0032 }, keyword: "MetersetExposure", name: "Meterset Exposure", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0034 }, keyword: "DiaphragmPosition", name: "Diaphragm Position", vr: &[DECIMAL_STRING], vm: "4", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0040 }, keyword: "FluenceMapSequence", name: "Fluence Map Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0041 }, keyword: "FluenceDataSource", name: "Fluence Data Source", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0042 }, keyword: "FluenceDataScale", name: "Fluence Data Scale", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0050 }, keyword: "PrimaryFluenceModeSequence", name: "Primary Fluence Mode Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0051 }, keyword: "FluenceMode", name: "Fluence Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3002, element: 0x0052 }, keyword: "FluenceModeID", name: "Fluence Mode ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0001 }, keyword: "DVHType", name: "DVH Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0002 }, keyword: "DoseUnits", name: "Dose Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0004 }, keyword: "DoseType", name: "Dose Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0005 }, keyword: "SpatialTransformOfDose", name: "Spatial Transform of Dose", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0006 }, keyword: "DoseComment", name: "Dose Comment", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0008 }, keyword: "NormalizationPoint", name: "Normalization Point", vr: &[DECIMAL_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x000A }, keyword: "DoseSummationType", name: "Dose Summation Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x000C }, keyword: "GridFrameOffsetVector", name: "Grid Frame Offset Vector", vr: &[DECIMAL_STRING], vm: "2-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x000E }, keyword: "DoseGridScaling", name: "Dose Grid Scaling", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0010 }, keyword: "RTDoseROISequence", name: "RT Dose ROI Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0012 }, keyword: "DoseValue", name: "Dose Value", vr: &[DECIMAL_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0014 }, keyword: "TissueHeterogeneityCorrection", name: "Tissue Heterogeneity Correction", vr: &[CODE_STRING], vm: "1-3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0040 }, keyword: "DVHNormalizationPoint", name: "DVH Normalization Point", vr: &[DECIMAL_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0042 }, keyword: "DVHNormalizationDoseValue", name: "DVH Normalization Dose Value", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0050 }, keyword: "DVHSequence", name: "DVH Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0052 }, keyword: "DVHDoseScaling", name: "DVH Dose Scaling", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0054 }, keyword: "DVHVolumeUnits", name: "DVH Volume Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0056 }, keyword: "DVHNumberOfBins", name: "DVH Number of Bins", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0058 }, keyword: "DVHData", name: "DVH Data", vr: &[DECIMAL_STRING], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0060 }, keyword: "DVHReferencedROISequence", name: "DVH Referenced ROI Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0062 }, keyword: "DVHROIContributionType", name: "DVH ROI Contribution Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0070 }, keyword: "DVHMinimumDose", name: "DVH Minimum Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0072 }, keyword: "DVHMaximumDose", name: "DVH Maximum Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3004, element: 0x0074 }, keyword: "DVHMeanDose", name: "DVH Mean Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0002 }, keyword: "StructureSetLabel", name: "Structure Set Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0004 }, keyword: "StructureSetName", name: "Structure Set Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0006 }, keyword: "StructureSetDescription", name: "Structure Set Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0008 }, keyword: "StructureSetDate", name: "Structure Set Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0009 }, keyword: "StructureSetTime", name: "Structure Set Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0010 }, keyword: "ReferencedFrameOfReferenceSequence", name: "Referenced Frame of Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0012 }, keyword: "RTReferencedStudySequence", name: "RT Referenced Study Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0014 }, keyword: "RTReferencedSeriesSequence", name: "RT Referenced Series Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0016 }, keyword: "ContourImageSequence", name: "Contour Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0018 }, keyword: "PredecessorStructureSetSequence", name: "Predecessor Structure Set Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0020 }, keyword: "StructureSetROISequence", name: "Structure Set ROI Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0022 }, keyword: "ROINumber", name: "ROI Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0024 }, keyword: "ReferencedFrameOfReferenceUID", name: "Referenced Frame of Reference UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0026 }, keyword: "ROIName", name: "ROI Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0028 }, keyword: "ROIDescription", name: "ROI Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x002A }, keyword: "ROIDisplayColor", name: "ROI Display Color", vr: &[INTEGER_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x002C }, keyword: "ROIVolume", name: "ROI Volume", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0030 }, keyword: "RTRelatedROISequence", name: "RT Related ROI Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0033 }, keyword: "RTROIRelationship", name: "RT ROI Relationship", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0036 }, keyword: "ROIGenerationAlgorithm", name: "ROI Generation Algorithm", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0037 }, keyword: "ROIDerivationAlgorithmIdentificationSequence", name: "ROI Derivation Algorithm Identification Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0038 }, keyword: "ROIGenerationDescription", name: "ROI Generation Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0039 }, keyword: "ROIContourSequence", name: "ROI Contour Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0040 }, keyword: "ContourSequence", name: "Contour Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0042 }, keyword: "ContourGeometricType", name: "Contour Geometric Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0044 }, keyword: "ContourSlabThickness", name: "Contour Slab Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0045 }, keyword: "ContourOffsetVector", name: "Contour Offset Vector", vr: &[DECIMAL_STRING], vm: "3", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0046 }, keyword: "NumberOfContourPoints", name: "Number of Contour Points", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0048 }, keyword: "ContourNumber", name: "Contour Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0049 }, keyword: "AttachedContours", name: "Attached Contours", vr: &[INTEGER_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0050 }, keyword: "ContourData", name: "Contour Data", vr: &[DECIMAL_STRING], vm: "3-3n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0080 }, keyword: "RTROIObservationsSequence", name: "RT ROI Observations Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0082 }, keyword: "ObservationNumber", name: "Observation Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0084 }, keyword: "ReferencedROINumber", name: "Referenced ROI Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0085 }, keyword: "ROIObservationLabel", name: "ROI Observation Label", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0086 }, keyword: "RTROIIdentificationCodeSequence", name: "RT ROI Identification Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x0088 }, keyword: "ROIObservationDescription", name: "ROI Observation Description", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00A0 }, keyword: "RelatedRTROIObservationsSequence", name: "Related RT ROI Observations Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00A4 }, keyword: "RTROIInterpretedType", name: "RT ROI Interpreted Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00A6 }, keyword: "ROIInterpreter", name: "ROI Interpreter", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00B0 }, keyword: "ROIPhysicalPropertiesSequence", name: "ROI Physical Properties Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00B2 }, keyword: "ROIPhysicalProperty", name: "ROI Physical Property", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00B4 }, keyword: "ROIPhysicalPropertyValue", name: "ROI Physical Property Value", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00B6 }, keyword: "ROIElementalCompositionSequence", name: "ROI Elemental Composition Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00B7 }, keyword: "ROIElementalCompositionAtomicNumber", name: "ROI Elemental Composition Atomic Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00B8 }, keyword: "ROIElementalCompositionAtomicMassFraction", name: "ROI Elemental Composition Atomic Mass Fraction", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00C0 }, keyword: "FrameOfReferenceRelationshipSequence", name: "Frame of Reference Relationship Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00C2 }, keyword: "RelatedFrameOfReferenceUID", name: "Related Frame of Reference UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00C4 }, keyword: "FrameOfReferenceTransformationType", name: "Frame of Reference Transformation Type", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00C6 }, keyword: "FrameOfReferenceTransformationMatrix", name: "Frame of Reference Transformation Matrix", vr: &[DECIMAL_STRING], vm: "16", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3006, element: 0x00C8 }, keyword: "FrameOfReferenceTransformationComment", name: "Frame of Reference Transformation Comment", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0010 }, keyword: "MeasuredDoseReferenceSequence", name: "Measured Dose Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0012 }, keyword: "MeasuredDoseDescription", name: "Measured Dose Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0014 }, keyword: "MeasuredDoseType", name: "Measured Dose Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0016 }, keyword: "MeasuredDoseValue", name: "Measured Dose Value", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0020 }, keyword: "TreatmentSessionBeamSequence", name: "Treatment Session Beam Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0022 }, keyword: "CurrentFractionNumber", name: "Current Fraction Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0024 }, keyword: "TreatmentControlPointDate", name: "Treatment Control Point Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0025 }, keyword: "TreatmentControlPointTime", name: "Treatment Control Point Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x002A }, keyword: "TreatmentTerminationStatus", name: "Treatment Termination Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x002B }, keyword: "TreatmentTerminationCode", name: "Treatment Termination Code", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x002C }, keyword: "TreatmentVerificationStatus", name: "Treatment Verification Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0030 }, keyword: "ReferencedTreatmentRecordSequence", name: "Referenced Treatment Record Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0032 }, keyword: "SpecifiedPrimaryMeterset", name: "Specified Primary Meterset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0033 }, keyword: "SpecifiedSecondaryMeterset", name: "Specified Secondary Meterset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0036 }, keyword: "DeliveredPrimaryMeterset", name: "Delivered Primary Meterset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0037 }, keyword: "DeliveredSecondaryMeterset", name: "Delivered Secondary Meterset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x003A }, keyword: "SpecifiedTreatmentTime", name: "Specified Treatment Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x003B }, keyword: "DeliveredTreatmentTime", name: "Delivered Treatment Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0040 }, keyword: "ControlPointDeliverySequence", name: "Control Point Delivery Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0042 }, keyword: "SpecifiedMeterset", name: "Specified Meterset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0044 }, keyword: "DeliveredMeterset", name: "Delivered Meterset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0048 }, keyword: "DoseRateDelivered", name: "Dose Rate Delivered", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0050 }, keyword: "TreatmentSummaryCalculatedDoseReferenceSequence", name: "Treatment Summary Calculated Dose Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0054 }, keyword: "FirstTreatmentDate", name: "First Treatment Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0056 }, keyword: "MostRecentTreatmentDate", name: "Most Recent Treatment Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x005A }, keyword: "NumberOfFractionsDelivered", name: "Number of Fractions Delivered", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0060 }, keyword: "OverrideSequence", name: "Override Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0066 }, keyword: "OverrideReason", name: "Override Reason", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0080 }, keyword: "CalculatedDoseReferenceSequence", name: "Calculated Dose Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0082 }, keyword: "ReferencedMeasuredDoseReferenceNumber", name: "Referenced Measured Dose Reference Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0090 }, keyword: "CalculatedDoseReferenceDescription", name: "Calculated Dose Reference Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0092 }, keyword: "CalculatedDoseReferenceDoseValue", name: "Calculated Dose Reference Dose Value", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x00A0 }, keyword: "BeamLimitingDeviceLeafPairsSequence", name: "Beam Limiting Device Leaf Pairs Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x00B0 }, keyword: "RecordedWedgeSequence", name: "Recorded Wedge Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x00C0 }, keyword: "RecordedCompensatorSequence", name: "Recorded Compensator Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x00D0 }, keyword: "RecordedBlockSequence", name: "Recorded Block Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x00E0 }, keyword: "TreatmentSummaryMeasuredDoseReferenceSequence", name: "Treatment Summary Measured Dose Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0100 }, keyword: "RecordedSourceSequence", name: "Recorded Source Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0105 }, keyword: "SourceSerialNumber", name: "Source Serial Number", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0110 }, keyword: "TreatmentSessionApplicationSetupSequence", name: "Treatment Session Application Setup Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0116 }, keyword: "ApplicationSetupCheck", name: "Application Setup Check", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0120 }, keyword: "RecordedBrachyAccessoryDeviceSequence", name: "Recorded Brachy Accessory Device Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0122 }, keyword: "ReferencedBrachyAccessoryDeviceNumber", name: "Referenced Brachy Accessory Device Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0130 }, keyword: "RecordedChannelSequence", name: "Recorded Channel Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0132 }, keyword: "SpecifiedChannelTotalTime", name: "Specified Channel Total Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0134 }, keyword: "DeliveredChannelTotalTime", name: "Delivered Channel Total Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0136 }, keyword: "SpecifiedNumberOfPulses", name: "Specified Number of Pulses", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0138 }, keyword: "DeliveredNumberOfPulses", name: "Delivered Number of Pulses", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x013A }, keyword: "SpecifiedPulseRepetitionInterval", name: "Specified Pulse Repetition Interval", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x013C }, keyword: "DeliveredPulseRepetitionInterval", name: "Delivered Pulse Repetition Interval", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0140 }, keyword: "RecordedSourceApplicatorSequence", name: "Recorded Source Applicator Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0142 }, keyword: "ReferencedSourceApplicatorNumber", name: "Referenced Source Applicator Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0150 }, keyword: "RecordedChannelShieldSequence", name: "Recorded Channel Shield Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0152 }, keyword: "ReferencedChannelShieldNumber", name: "Referenced Channel Shield Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0160 }, keyword: "BrachyControlPointDeliveredSequence", name: "Brachy Control Point Delivered Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0162 }, keyword: "SafePositionExitDate", name: "Safe Position Exit Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0164 }, keyword: "SafePositionExitTime", name: "Safe Position Exit Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0166 }, keyword: "SafePositionReturnDate", name: "Safe Position Return Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0168 }, keyword: "SafePositionReturnTime", name: "Safe Position Return Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0200 }, keyword: "CurrentTreatmentStatus", name: "Current Treatment Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0202 }, keyword: "TreatmentStatusComment", name: "Treatment Status Comment", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0220 }, keyword: "FractionGroupSummarySequence", name: "Fraction Group Summary Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0223 }, keyword: "ReferencedFractionNumber", name: "Referenced Fraction Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0224 }, keyword: "FractionGroupType", name: "Fraction Group Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0230 }, keyword: "BeamStopperPosition", name: "Beam Stopper Position", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0240 }, keyword: "FractionStatusSummarySequence", name: "Fraction Status Summary Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0250 }, keyword: "TreatmentDate", name: "Treatment Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x3008, element: 0x0251 }, keyword: "TreatmentTime", name: "Treatment Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0002 }, keyword: "RTPlanLabel", name: "RT Plan Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0003 }, keyword: "RTPlanName", name: "RT Plan Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0004 }, keyword: "RTPlanDescription", name: "RT Plan Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0006 }, keyword: "RTPlanDate", name: "RT Plan Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0007 }, keyword: "RTPlanTime", name: "RT Plan Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0009 }, keyword: "TreatmentProtocols", name: "Treatment Protocols", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x000A }, keyword: "PlanIntent", name: "Plan Intent", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x000B }, keyword: "TreatmentSites", name: "Treatment Sites", vr: &[LONG_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x000C }, keyword: "RTPlanGeometry", name: "RT Plan Geometry", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x000E }, keyword: "PrescriptionDescription", name: "Prescription Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0010 }, keyword: "DoseReferenceSequence", name: "Dose Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0012 }, keyword: "DoseReferenceNumber", name: "Dose Reference Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0013 }, keyword: "DoseReferenceUID", name: "Dose Reference UID", vr: &[UNIQUE_IDENTIFIER_UID], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0014 }, keyword: "DoseReferenceStructureType", name: "Dose Reference Structure Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0015 }, keyword: "NominalBeamEnergyUnit", name: "Nominal Beam Energy Unit", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0016 }, keyword: "DoseReferenceDescription", name: "Dose Reference Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0018 }, keyword: "DoseReferencePointCoordinates", name: "Dose Reference Point Coordinates", vr: &[DECIMAL_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x001A }, keyword: "NominalPriorDose", name: "Nominal Prior Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0020 }, keyword: "DoseReferenceType", name: "Dose Reference Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0021 }, keyword: "ConstraintWeight", name: "Constraint Weight", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0022 }, keyword: "DeliveryWarningDose", name: "Delivery Warning Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0023 }, keyword: "DeliveryMaximumDose", name: "Delivery Maximum Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0025 }, keyword: "TargetMinimumDose", name: "Target Minimum Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0026 }, keyword: "TargetPrescriptionDose", name: "Target Prescription Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0027 }, keyword: "TargetMaximumDose", name: "Target Maximum Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0028 }, keyword: "TargetUnderdoseVolumeFraction", name: "Target Underdose Volume Fraction", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x002A }, keyword: "OrganAtRiskFullVolumeDose", name: "Organ at Risk Full-volume Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x002B }, keyword: "OrganAtRiskLimitDose", name: "Organ at Risk Limit Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x002C }, keyword: "OrganAtRiskMaximumDose", name: "Organ at Risk Maximum Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x002D }, keyword: "OrganAtRiskOverdoseVolumeFraction", name: "Organ at Risk Overdose Volume Fraction", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0040 }, keyword: "ToleranceTableSequence", name: "Tolerance Table Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0042 }, keyword: "ToleranceTableNumber", name: "Tolerance Table Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0043 }, keyword: "ToleranceTableLabel", name: "Tolerance Table Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0044 }, keyword: "GantryAngleTolerance", name: "Gantry Angle Tolerance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0046 }, keyword: "BeamLimitingDeviceAngleTolerance", name: "Beam Limiting Device Angle Tolerance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0048 }, keyword: "BeamLimitingDeviceToleranceSequence", name: "Beam Limiting Device Tolerance Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x004A }, keyword: "BeamLimitingDevicePositionTolerance", name: "Beam Limiting Device Position Tolerance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x004C }, keyword: "PatientSupportAngleTolerance", name: "Patient Support Angle Tolerance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x004E }, keyword: "TableTopEccentricAngleTolerance", name: "Table Top Eccentric Angle Tolerance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0051 }, keyword: "TableTopVerticalPositionTolerance", name: "Table Top Vertical Position Tolerance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0052 }, keyword: "TableTopLongitudinalPositionTolerance", name: "Table Top Longitudinal Position Tolerance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0053 }, keyword: "TableTopLateralPositionTolerance", name: "Table Top Lateral Position Tolerance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0055 }, keyword: "RTPlanRelationship", name: "RT Plan Relationship", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0070 }, keyword: "FractionGroupSequence", name: "Fraction Group Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0071 }, keyword: "FractionGroupNumber", name: "Fraction Group Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0072 }, keyword: "FractionGroupDescription", name: "Fraction Group Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0078 }, keyword: "NumberOfFractionsPlanned", name: "Number of Fractions Planned", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0079 }, keyword: "NumberOfFractionPatternDigitsPerDay", name: "Number of Fraction Pattern Digits Per Day", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x007A }, keyword: "RepeatFractionCycleLength", name: "Repeat Fraction Cycle Length", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x007B }, keyword: "FractionPattern", name: "Fraction Pattern", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0080 }, keyword: "NumberOfBeams", name: "Number of Beams", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0082 }, keyword: "BeamDoseSpecificationPoint", name: "Beam Dose Specification Point", vr: &[DECIMAL_STRING], vm: "3", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0084 }, keyword: "BeamDose", name: "Beam Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0086 }, keyword: "BeamMeterset", name: "Beam Meterset", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00A0 }, keyword: "NumberOfBrachyApplicationSetups", name: "Number of Brachy Application Setups", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00A2 }, keyword: "BrachyApplicationSetupDoseSpecificationPoint", name: "Brachy Application Setup Dose Specification Point", vr: &[DECIMAL_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00A4 }, keyword: "BrachyApplicationSetupDose", name: "Brachy Application Setup Dose", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00B0 }, keyword: "BeamSequence", name: "Beam Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00B2 }, keyword: "TreatmentMachineName", name: "Treatment Machine Name", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00B3 }, keyword: "PrimaryDosimeterUnit", name: "Primary Dosimeter Unit", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00B4 }, keyword: "SourceAxisDistance", name: "Source-Axis Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00B6 }, keyword: "BeamLimitingDeviceSequence", name: "Beam Limiting Device Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00B8 }, keyword: "RTBeamLimitingDeviceType", name: "RT Beam Limiting Device Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00BA }, keyword: "SourceToBeamLimitingDeviceDistance", name: "Source to Beam Limiting Device Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00BB }, keyword: "IsocenterToBeamLimitingDeviceDistance", name: "Isocenter to Beam Limiting Device Distance", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00BC }, keyword: "NumberOfLeafJawPairs", name: "Number of Leaf/Jaw Pairs", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00BE }, keyword: "LeafPositionBoundaries", name: "Leaf Position Boundaries", vr: &[DECIMAL_STRING], vm: "3-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00C0 }, keyword: "BeamNumber", name: "Beam Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00C2 }, keyword: "BeamName", name: "Beam Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00C3 }, keyword: "BeamDescription", name: "Beam Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00C4 }, keyword: "BeamType", name: "Beam Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00C6 }, keyword: "RadiationType", name: "Radiation Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00C7 }, keyword: "HighDoseTechniqueType", name: "High-Dose Technique Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00C8 }, keyword: "ReferenceImageNumber", name: "Reference Image Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00CA }, keyword: "PlannedVerificationImageSequence", name: "Planned Verification Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00CC }, keyword: "ImagingDeviceSpecificAcquisitionParameters", name: "Imaging Device-Specific Acquisition Parameters", vr: &[LONG_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00CE }, keyword: "TreatmentDeliveryType", name: "Treatment Delivery Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00D0 }, keyword: "NumberOfWedges", name: "Number of Wedges", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00D1 }, keyword: "WedgeSequence", name: "Wedge Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00D2 }, keyword: "WedgeNumber", name: "Wedge Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00D3 }, keyword: "WedgeType", name: "Wedge Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00D4 }, keyword: "WedgeID", name: "Wedge ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00D5 }, keyword: "WedgeAngle", name: "Wedge Angle", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00D6 }, keyword: "WedgeFactor", name: "Wedge Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00D8 }, keyword: "WedgeOrientation", name: "Wedge Orientation", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00DA }, keyword: "SourceToWedgeTrayDistance", name: "Source to Wedge Tray Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E0 }, keyword: "NumberOfCompensators", name: "Number of Compensators", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E1 }, keyword: "MaterialID", name: "Material ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E2 }, keyword: "TotalCompensatorTrayFactor", name: "Total Compensator Tray Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E3 }, keyword: "CompensatorSequence", name: "Compensator Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E4 }, keyword: "CompensatorNumber", name: "Compensator Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E5 }, keyword: "CompensatorID", name: "Compensator ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E6 }, keyword: "SourceToCompensatorTrayDistance", name: "Source to Compensator Tray Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E7 }, keyword: "CompensatorRows", name: "Compensator Rows", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E8 }, keyword: "CompensatorColumns", name: "Compensator Columns", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00E9 }, keyword: "CompensatorPixelSpacing", name: "Compensator Pixel Spacing", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00EA }, keyword: "CompensatorPosition", name: "Compensator Position", vr: &[DECIMAL_STRING], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00EB }, keyword: "CompensatorTransmissionData", name: "Compensator Transmission Data", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00EC }, keyword: "CompensatorThicknessData", name: "Compensator Thickness Data", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00ED }, keyword: "NumberOfBoli", name: "Number of Boli", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00EE }, keyword: "CompensatorType", name: "Compensator Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00F0 }, keyword: "NumberOfBlocks", name: "Number of Blocks", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00F2 }, keyword: "TotalBlockTrayFactor", name: "Total Block Tray Factor", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00F4 }, keyword: "BlockSequence", name: "Block Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00F5 }, keyword: "BlockTrayID", name: "Block Tray ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00F6 }, keyword: "SourceToBlockTrayDistance", name: "Source to Block Tray Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00F8 }, keyword: "BlockType", name: "Block Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00FA }, keyword: "BlockDivergence", name: "Block Divergence", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00FC }, keyword: "BlockNumber", name: "Block Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x00FE }, keyword: "BlockName", name: "Block Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0100 }, keyword: "BlockThickness", name: "Block Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0102 }, keyword: "BlockTransmission", name: "Block Transmission", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0104 }, keyword: "BlockNumberOfPoints", name: "Block Number of Points", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0106 }, keyword: "BlockData", name: "Block Data", vr: &[DECIMAL_STRING], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0107 }, keyword: "ApplicatorSequence", name: "Applicator Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0108 }, keyword: "ApplicatorID", name: "Applicator ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0109 }, keyword: "ApplicatorType", name: "Applicator Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x010A }, keyword: "ApplicatorDescription", name: "Applicator Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x010C }, keyword: "CumulativeDoseReferenceCoefficient", name: "Cumulative Dose Reference Coefficient", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x010E }, keyword: "FinalCumulativeMetersetWeight", name: "Final Cumulative Meterset Weight", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0110 }, keyword: "NumberOfControlPoints", name: "Number of Control Points", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0111 }, keyword: "ControlPointSequence", name: "Control Point Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0112 }, keyword: "ControlPointIndex", name: "Control Point Index", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0114 }, keyword: "NominalBeamEnergy", name: "Nominal Beam Energy", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0115 }, keyword: "DoseRateSet", name: "Dose Rate Set", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0116 }, keyword: "WedgePositionSequence", name: "Wedge Position Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0118 }, keyword: "WedgePosition", name: "Wedge Position", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x011A }, keyword: "BeamLimitingDevicePositionSequence", name: "Beam Limiting Device Position Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x011C }, keyword: "LeafJawPositions", name: "Leaf/Jaw Positions", vr: &[DECIMAL_STRING], vm: "2-2n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x011E }, keyword: "GantryAngle", name: "Gantry Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x011F }, keyword: "GantryRotationDirection", name: "Gantry Rotation Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0120 }, keyword: "BeamLimitingDeviceAngle", name: "Beam Limiting Device Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0121 }, keyword: "BeamLimitingDeviceRotationDirection", name: "Beam Limiting Device Rotation Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0122 }, keyword: "PatientSupportAngle", name: "Patient Support Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0123 }, keyword: "PatientSupportRotationDirection", name: "Patient Support Rotation Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0124 }, keyword: "TableTopEccentricAxisDistance", name: "Table Top Eccentric Axis Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0125 }, keyword: "TableTopEccentricAngle", name: "Table Top Eccentric Angle", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0126 }, keyword: "TableTopEccentricRotationDirection", name: "Table Top Eccentric Rotation Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0128 }, keyword: "TableTopVerticalPosition", name: "Table Top Vertical Position", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0129 }, keyword: "TableTopLongitudinalPosition", name: "Table Top Longitudinal Position", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x012A }, keyword: "TableTopLateralPosition", name: "Table Top Lateral Position", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x012C }, keyword: "IsocenterPosition", name: "Isocenter Position", vr: &[DECIMAL_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x012E }, keyword: "SurfaceEntryPoint", name: "Surface Entry Point", vr: &[DECIMAL_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0130 }, keyword: "SourceToSurfaceDistance", name: "Source to Surface Distance", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0131 }, keyword: "SourceToExternalContourDistance", name: "Source to External Contour Distance", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0132 }, keyword: "ExternalContourEntryPoint", name: "External Contour Entry Point", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0134 }, keyword: "CumulativeMetersetWeight", name: "Cumulative Meterset Weight", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0140 }, keyword: "TableTopPitchAngle", name: "Table Top Pitch Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0142 }, keyword: "TableTopPitchRotationDirection", name: "Table Top Pitch Rotation Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0144 }, keyword: "TableTopRollAngle", name: "Table Top Roll Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0146 }, keyword: "TableTopRollRotationDirection", name: "Table Top Roll Rotation Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0148 }, keyword: "HeadFixationAngle", name: "Head Fixation Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x014A }, keyword: "GantryPitchAngle", name: "Gantry Pitch Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x014C }, keyword: "GantryPitchRotationDirection", name: "Gantry Pitch Rotation Direction", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x014E }, keyword: "GantryPitchAngleTolerance", name: "Gantry Pitch Angle Tolerance", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0180 }, keyword: "PatientSetupSequence", name: "Patient Setup Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0182 }, keyword: "PatientSetupNumber", name: "Patient Setup Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0183 }, keyword: "PatientSetupLabel", name: "Patient Setup Label", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0184 }, keyword: "PatientAdditionalPosition", name: "Patient Additional Position", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0190 }, keyword: "FixationDeviceSequence", name: "Fixation Device Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0192 }, keyword: "FixationDeviceType", name: "Fixation Device Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0194 }, keyword: "FixationDeviceLabel", name: "Fixation Device Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0196 }, keyword: "FixationDeviceDescription", name: "Fixation Device Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0198 }, keyword: "FixationDevicePosition", name: "Fixation Device Position", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0199 }, keyword: "FixationDevicePitchAngle", name: "Fixation Device Pitch Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x019A }, keyword: "FixationDeviceRollAngle", name: "Fixation Device Roll Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01A0 }, keyword: "ShieldingDeviceSequence", name: "Shielding Device Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01A2 }, keyword: "ShieldingDeviceType", name: "Shielding Device Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01A4 }, keyword: "ShieldingDeviceLabel", name: "Shielding Device Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01A6 }, keyword: "ShieldingDeviceDescription", name: "Shielding Device Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01A8 }, keyword: "ShieldingDevicePosition", name: "Shielding Device Position", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01B0 }, keyword: "SetupTechnique", name: "Setup Technique", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01B2 }, keyword: "SetupTechniqueDescription", name: "Setup Technique Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01B4 }, keyword: "SetupDeviceSequence", name: "Setup Device Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01B6 }, keyword: "SetupDeviceType", name: "Setup Device Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01B8 }, keyword: "SetupDeviceLabel", name: "Setup Device Label", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01BA }, keyword: "SetupDeviceDescription", name: "Setup Device Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01BC }, keyword: "SetupDeviceParameter", name: "Setup Device Parameter", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01D0 }, keyword: "SetupReferenceDescription", name: "Setup Reference Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01D2 }, keyword: "TableTopVerticalSetupDisplacement", name: "Table Top Vertical Setup Displacement", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01D4 }, keyword: "TableTopLongitudinalSetupDisplacement", name: "Table Top Longitudinal Setup Displacement", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x01D6 }, keyword: "TableTopLateralSetupDisplacement", name: "Table Top Lateral Setup Displacement", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0200 }, keyword: "BrachyTreatmentTechnique", name: "Brachy Treatment Technique", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0202 }, keyword: "BrachyTreatmentType", name: "Brachy Treatment Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0206 }, keyword: "TreatmentMachineSequence", name: "Treatment Machine Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0210 }, keyword: "SourceSequence", name: "Source Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0212 }, keyword: "SourceNumber", name: "Source Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0214 }, keyword: "SourceType", name: "Source Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0216 }, keyword: "SourceManufacturer", name: "Source Manufacturer", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0218 }, keyword: "ActiveSourceDiameter", name: "Active Source Diameter", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x021A }, keyword: "ActiveSourceLength", name: "Active Source Length", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x021B }, keyword: "SourceModelID", name: "Source Model ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x021C }, keyword: "SourceDescription", name: "Source Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0222 }, keyword: "SourceEncapsulationNominalThickness", name: "Source Encapsulation Nominal Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0224 }, keyword: "SourceEncapsulationNominalTransmission", name: "Source Encapsulation Nominal Transmission", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0226 }, keyword: "SourceIsotopeName", name: "Source Isotope Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0228 }, keyword: "SourceIsotopeHalfLife", name: "Source Isotope Half Life", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0229 }, keyword: "SourceStrengthUnits", name: "Source Strength Units", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x022A }, keyword: "ReferenceAirKermaRate", name: "Reference Air Kerma Rate", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x022B }, keyword: "SourceStrength", name: "Source Strength", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x022C }, keyword: "SourceStrengthReferenceDate", name: "Source Strength Reference Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x022E }, keyword: "SourceStrengthReferenceTime", name: "Source Strength Reference Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0230 }, keyword: "ApplicationSetupSequence", name: "Application Setup Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0232 }, keyword: "ApplicationSetupType", name: "Application Setup Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0234 }, keyword: "ApplicationSetupNumber", name: "Application Setup Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0236 }, keyword: "ApplicationSetupName", name: "Application Setup Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0238 }, keyword: "ApplicationSetupManufacturer", name: "Application Setup Manufacturer", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0240 }, keyword: "TemplateNumber", name: "Template Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0242 }, keyword: "TemplateType", name: "Template Type", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0244 }, keyword: "TemplateName", name: "Template Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0250 }, keyword: "TotalReferenceAirKerma", name: "Total Reference Air Kerma", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0260 }, keyword: "BrachyAccessoryDeviceSequence", name: "Brachy Accessory Device Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0262 }, keyword: "BrachyAccessoryDeviceNumber", name: "Brachy Accessory Device Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0263 }, keyword: "BrachyAccessoryDeviceID", name: "Brachy Accessory Device ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0264 }, keyword: "BrachyAccessoryDeviceType", name: "Brachy Accessory Device Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0266 }, keyword: "BrachyAccessoryDeviceName", name: "Brachy Accessory Device Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x026A }, keyword: "BrachyAccessoryDeviceNominalThickness", name: "Brachy Accessory Device Nominal Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x026C }, keyword: "BrachyAccessoryDeviceNominalTransmission", name: "Brachy Accessory Device Nominal Transmission", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0280 }, keyword: "ChannelSequence", name: "Channel Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0282 }, keyword: "ChannelNumber", name: "Channel Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0284 }, keyword: "ChannelLength", name: "Channel Length", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0286 }, keyword: "ChannelTotalTime", name: "Channel Total Time", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0288 }, keyword: "SourceMovementType", name: "Source Movement Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x028A }, keyword: "NumberOfPulses", name: "Number of Pulses", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x028C }, keyword: "PulseRepetitionInterval", name: "Pulse Repetition Interval", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0290 }, keyword: "SourceApplicatorNumber", name: "Source Applicator Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0291 }, keyword: "SourceApplicatorID", name: "Source Applicator ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0292 }, keyword: "SourceApplicatorType", name: "Source Applicator Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0294 }, keyword: "SourceApplicatorName", name: "Source Applicator Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0296 }, keyword: "SourceApplicatorLength", name: "Source Applicator Length", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0298 }, keyword: "SourceApplicatorManufacturer", name: "Source Applicator Manufacturer", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x029C }, keyword: "SourceApplicatorWallNominalThickness", name: "Source Applicator Wall Nominal Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x029E }, keyword: "SourceApplicatorWallNominalTransmission", name: "Source Applicator Wall Nominal Transmission", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02A0 }, keyword: "SourceApplicatorStepSize", name: "Source Applicator Step Size", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02A2 }, keyword: "TransferTubeNumber", name: "Transfer Tube Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02A4 }, keyword: "TransferTubeLength", name: "Transfer Tube Length", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02B0 }, keyword: "ChannelShieldSequence", name: "Channel Shield Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02B2 }, keyword: "ChannelShieldNumber", name: "Channel Shield Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02B3 }, keyword: "ChannelShieldID", name: "Channel Shield ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02B4 }, keyword: "ChannelShieldName", name: "Channel Shield Name", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02B8 }, keyword: "ChannelShieldNominalThickness", name: "Channel Shield Nominal Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02BA }, keyword: "ChannelShieldNominalTransmission", name: "Channel Shield Nominal Transmission", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02C8 }, keyword: "FinalCumulativeTimeWeight", name: "Final Cumulative Time Weight", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02D0 }, keyword: "BrachyControlPointSequence", name: "Brachy Control Point Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02D2 }, keyword: "ControlPointRelativePosition", name: "Control Point Relative Position", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02D4 }, keyword: "ControlPoint3DPosition", name: "Control Point 3D Position", vr: &[DECIMAL_STRING], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02D6 }, keyword: "CumulativeTimeWeight", name: "Cumulative Time Weight", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02E0 }, keyword: "CompensatorDivergence", name: "Compensator Divergence", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02E1 }, keyword: "CompensatorMountingPosition", name: "Compensator Mounting Position", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02E2 }, keyword: "SourceToCompensatorDistance", name: "Source to Compensator Distance", vr: &[DECIMAL_STRING], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02E3 }, keyword: "TotalCompensatorTrayWaterEquivalentThickness", name: "Total Compensator Tray Water-Equivalent Thickness", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02E4 }, keyword: "IsocenterToCompensatorTrayDistance", name: "Isocenter to Compensator Tray Distance", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02E5 }, keyword: "CompensatorColumnOffset", name: "Compensator Column Offset", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02E6 }, keyword: "IsocenterToCompensatorDistances", name: "Isocenter to Compensator Distances", vr: &[FLOATING_POINT_SINGLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02E7 }, keyword: "CompensatorRelativeStoppingPowerRatio", name: "Compensator Relative Stopping Power Ratio", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02E8 }, keyword: "CompensatorMillingToolDiameter", name: "Compensator Milling Tool Diameter", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02EA }, keyword: "IonRangeCompensatorSequence", name: "Ion Range Compensator Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x02EB }, keyword: "CompensatorDescription", name: "Compensator Description", vr: &[LONG_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0302 }, keyword: "RadiationMassNumber", name: "Radiation Mass Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0304 }, keyword: "RadiationAtomicNumber", name: "Radiation Atomic Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0306 }, keyword: "RadiationChargeState", name: "Radiation Charge State", vr: &[SIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0308 }, keyword: "ScanMode", name: "Scan Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x030A }, keyword: "VirtualSourceAxisDistances", name: "Virtual Source-Axis Distances", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x030C }, keyword: "SnoutSequence", name: "Snout Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x030D }, keyword: "SnoutPosition", name: "Snout Position", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x030F }, keyword: "SnoutID", name: "Snout ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0312 }, keyword: "NumberOfRangeShifters", name: "Number of Range Shifters", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0314 }, keyword: "RangeShifterSequence", name: "Range Shifter Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0316 }, keyword: "RangeShifterNumber", name: "Range Shifter Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0318 }, keyword: "RangeShifterID", name: "Range Shifter ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0320 }, keyword: "RangeShifterType", name: "Range Shifter Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0322 }, keyword: "RangeShifterDescription", name: "Range Shifter Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0330 }, keyword: "NumberOfLateralSpreadingDevices", name: "Number of Lateral Spreading Devices", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0332 }, keyword: "LateralSpreadingDeviceSequence", name: "Lateral Spreading Device Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0334 }, keyword: "LateralSpreadingDeviceNumber", name: "Lateral Spreading Device Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0336 }, keyword: "LateralSpreadingDeviceID", name: "Lateral Spreading Device ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0338 }, keyword: "LateralSpreadingDeviceType", name: "Lateral Spreading Device Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x033A }, keyword: "LateralSpreadingDeviceDescription", name: "Lateral Spreading Device Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x033C }, keyword: "LateralSpreadingDeviceWaterEquivalentThickness", name: "Lateral Spreading Device Water Equivalent Thickness", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0340 }, keyword: "NumberOfRangeModulators", name: "Number of Range Modulators", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0342 }, keyword: "RangeModulatorSequence", name: "Range Modulator Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0344 }, keyword: "RangeModulatorNumber", name: "Range Modulator Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0346 }, keyword: "RangeModulatorID", name: "Range Modulator ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0348 }, keyword: "RangeModulatorType", name: "Range Modulator Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x034A }, keyword: "RangeModulatorDescription", name: "Range Modulator Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x034C }, keyword: "BeamCurrentModulationID", name: "Beam Current Modulation ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0350 }, keyword: "PatientSupportType", name: "Patient Support Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0352 }, keyword: "PatientSupportID", name: "Patient Support ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0354 }, keyword: "PatientSupportAccessoryCode", name: "Patient Support Accessory Code", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0355 }, keyword: "TrayAccessoryCode", name: "Tray Accessory Code", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0356 }, keyword: "FixationLightAzimuthalAngle", name: "Fixation Light Azimuthal Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0358 }, keyword: "FixationLightPolarAngle", name: "Fixation Light Polar Angle", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x035A }, keyword: "MetersetRate", name: "Meterset Rate", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0360 }, keyword: "RangeShifterSettingsSequence", name: "Range Shifter Settings Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0362 }, keyword: "RangeShifterSetting", name: "Range Shifter Setting", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0364 }, keyword: "IsocenterToRangeShifterDistance", name: "Isocenter to Range Shifter Distance", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0366 }, keyword: "RangeShifterWaterEquivalentThickness", name: "Range Shifter Water Equivalent Thickness", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0370 }, keyword: "LateralSpreadingDeviceSettingsSequence", name: "Lateral Spreading Device Settings Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0372 }, keyword: "LateralSpreadingDeviceSetting", name: "Lateral Spreading Device Setting", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0374 }, keyword: "IsocenterToLateralSpreadingDeviceDistance", name: "Isocenter to Lateral Spreading Device Distance", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0380 }, keyword: "RangeModulatorSettingsSequence", name: "Range Modulator Settings Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0382 }, keyword: "RangeModulatorGatingStartValue", name: "Range Modulator Gating Start Value", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0384 }, keyword: "RangeModulatorGatingStopValue", name: "Range Modulator Gating Stop Value", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0386 }, keyword: "RangeModulatorGatingStartWaterEquivalentThickness", name: "Range Modulator Gating Start Water Equivalent Thickness", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0388 }, keyword: "RangeModulatorGatingStopWaterEquivalentThickness", name: "Range Modulator Gating Stop Water Equivalent Thickness", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x038A }, keyword: "IsocenterToRangeModulatorDistance", name: "Isocenter to Range Modulator Distance", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0390 }, keyword: "ScanSpotTuneID", name: "Scan Spot Tune ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0392 }, keyword: "NumberOfScanSpotPositions", name: "Number of Scan Spot Positions", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0394 }, keyword: "ScanSpotPositionMap", name: "Scan Spot Position Map", vr: &[FLOATING_POINT_SINGLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0396 }, keyword: "ScanSpotMetersetWeights", name: "Scan Spot Meterset Weights", vr: &[FLOATING_POINT_SINGLE], vm: "1-n", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0398 }, keyword: "ScanningSpotSize", name: "Scanning Spot Size", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x039A }, keyword: "NumberOfPaintings", name: "Number of Paintings", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x03A0 }, keyword: "IonToleranceTableSequence", name: "Ion Tolerance Table Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x03A2 }, keyword: "IonBeamSequence", name: "Ion Beam Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x03A4 }, keyword: "IonBeamLimitingDeviceSequence", name: "Ion Beam Limiting Device Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x03A6 }, keyword: "IonBlockSequence", name: "Ion Block Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x03A8 }, keyword: "IonControlPointSequence", name: "Ion Control Point Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x03AA }, keyword: "IonWedgeSequence", name: "Ion Wedge Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x03AC }, keyword: "IonWedgePositionSequence", name: "Ion Wedge Position Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0401 }, keyword: "ReferencedSetupImageSequence", name: "Referenced Setup Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0402 }, keyword: "SetupImageComment", name: "Setup Image Comment", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0410 }, keyword: "MotionSynchronizationSequence", name: "Motion Synchronization Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0412 }, keyword: "ControlPointOrientation", name: "Control Point Orientation", vr: &[FLOATING_POINT_SINGLE], vm: "3", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0420 }, keyword: "GeneralAccessorySequence", name: "General Accessory Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0421 }, keyword: "GeneralAccessoryID", name: "General Accessory ID", vr: &[SHORT_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0422 }, keyword: "GeneralAccessoryDescription", name: "General Accessory Description", vr: &[SHORT_TEXT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0423 }, keyword: "GeneralAccessoryType", name: "General Accessory Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0424 }, keyword: "GeneralAccessoryNumber", name: "General Accessory Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0425 }, keyword: "SourceToGeneralAccessoryDistance", name: "Source to General Accessory Distance", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0431 }, keyword: "ApplicatorGeometrySequence", name: "Applicator Geometry Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0432 }, keyword: "ApplicatorApertureShape", name: "Applicator Aperture Shape", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0433 }, keyword: "ApplicatorOpening", name: "Applicator Opening", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0434 }, keyword: "ApplicatorOpeningX", name: "Applicator Opening X", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0435 }, keyword: "ApplicatorOpeningY", name: "Applicator Opening Y", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0436 }, keyword: "SourceToApplicatorMountingPositionDistance", name: "Source to Applicator Mounting Position Distance", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0440 }, keyword: "NumberOfBlockSlabItems", name: "Number of Block Slab Items", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0441 }, keyword: "BlockSlabSequence", name: "Block Slab Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0442 }, keyword: "BlockSlabThickness", name: "Block Slab Thickness", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0443 }, keyword: "BlockSlabNumber", name: "Block Slab Number", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0450 }, keyword: "DeviceMotionControlSequence", name: "Device Motion Control Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0451 }, keyword: "DeviceMotionExecutionMode", name: "Device Motion Execution Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0452 }, keyword: "DeviceMotionObservationMode", name: "Device Motion Observation Mode", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0453 }, keyword: "DeviceMotionParameterCodeSequence", name: "Device Motion Parameter Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0501 }, keyword: "DistalDepthFraction", name: "Distal Depth Fraction", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0502 }, keyword: "DistalDepth", name: "Distal Depth", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0503 }, keyword: "NominalRangeModulationFractions", name: "Nominal Range Modulation Fractions", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0504 }, keyword: "NominalRangeModulatedRegionDepths", name: "Nominal Range Modulated Region Depths", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0505 }, keyword: "DepthDoseParametersSequence", name: "Depth Dose Parameters Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0506 }, keyword: "DeliveredDepthDoseParametersSequence", name: "Delivered Depth Dose Parameters Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0507 }, keyword: "DeliveredDistalDepthFraction", name: "Delivered Distal Depth Fraction", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0508 }, keyword: "DeliveredDistalDepth", name: "Delivered Distal Depth", vr: &[FLOATING_POINT_SINGLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0509 }, keyword: "DeliveredNominalRangeModulationFractions", name: "Delivered Nominal Range Modulation Fractions", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0510 }, keyword: "DeliveredNominalRangeModulatedRegionDepths", name: "Delivered Nominal Range Modulated Region Depths", vr: &[FLOATING_POINT_SINGLE], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0511 }, keyword: "DeliveredReferenceDoseDefinition", name: "Delivered Reference Dose Definition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300A, element: 0x0512 }, keyword: "ReferenceDoseDefinition", name: "Reference Dose Definition", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0002 }, keyword: "ReferencedRTPlanSequence", name: "Referenced RT Plan Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0004 }, keyword: "ReferencedBeamSequence", name: "Referenced Beam Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0006 }, keyword: "ReferencedBeamNumber", name: "Referenced Beam Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0007 }, keyword: "ReferencedReferenceImageNumber", name: "Referenced Reference Image Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0008 }, keyword: "StartCumulativeMetersetWeight", name: "Start Cumulative Meterset Weight", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0009 }, keyword: "EndCumulativeMetersetWeight", name: "End Cumulative Meterset Weight", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x000A }, keyword: "ReferencedBrachyApplicationSetupSequence", name: "Referenced Brachy Application Setup Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x000C }, keyword: "ReferencedBrachyApplicationSetupNumber", name: "Referenced Brachy Application Setup Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x000E }, keyword: "ReferencedSourceNumber", name: "Referenced Source Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0020 }, keyword: "ReferencedFractionGroupSequence", name: "Referenced Fraction Group Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0022 }, keyword: "ReferencedFractionGroupNumber", name: "Referenced Fraction Group Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0040 }, keyword: "ReferencedVerificationImageSequence", name: "Referenced Verification Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0042 }, keyword: "ReferencedReferenceImageSequence", name: "Referenced Reference Image Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0050 }, keyword: "ReferencedDoseReferenceSequence", name: "Referenced Dose Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0051 }, keyword: "ReferencedDoseReferenceNumber", name: "Referenced Dose Reference Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0055 }, keyword: "BrachyReferencedDoseReferenceSequence", name: "Brachy Referenced Dose Reference Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0060 }, keyword: "ReferencedStructureSetSequence", name: "Referenced Structure Set Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x006A }, keyword: "ReferencedPatientSetupNumber", name: "Referenced Patient Setup Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0080 }, keyword: "ReferencedDoseSequence", name: "Referenced Dose Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x00A0 }, keyword: "ReferencedToleranceTableNumber", name: "Referenced Tolerance Table Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x00B0 }, keyword: "ReferencedBolusSequence", name: "Referenced Bolus Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x00C0 }, keyword: "ReferencedWedgeNumber", name: "Referenced Wedge Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x00D0 }, keyword: "ReferencedCompensatorNumber", name: "Referenced Compensator Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x00E0 }, keyword: "ReferencedBlockNumber", name: "Referenced Block Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x00F0 }, keyword: "ReferencedControlPointIndex", name: "Referenced Control Point Index", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x00F2 }, keyword: "ReferencedControlPointSequence", name: "Referenced Control Point Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x00F4 }, keyword: "ReferencedStartControlPointIndex", name: "Referenced Start Control Point Index", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x00F6 }, keyword: "ReferencedStopControlPointIndex", name: "Referenced Stop Control Point Index", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0100 }, keyword: "ReferencedRangeShifterNumber", name: "Referenced Range Shifter Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0102 }, keyword: "ReferencedLateralSpreadingDeviceNumber", name: "Referenced Lateral Spreading Device Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0104 }, keyword: "ReferencedRangeModulatorNumber", name: "Referenced Range Modulator Number", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0111 }, keyword: "OmittedBeamTaskSequence", name: "Omitted Beam Task Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0112 }, keyword: "ReasonForOmission", name: "Reason for Omission", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300C, element: 0x0113 }, keyword: "ReasonForOmissionDescription", name: "Reason for Omission Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300E, element: 0x0002 }, keyword: "ApprovalStatus", name: "Approval Status", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300E, element: 0x0004 }, keyword: "ReviewDate", name: "Review Date", vr: &[DATE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300E, element: 0x0005 }, keyword: "ReviewTime", name: "Review Time", vr: &[TIME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x300E, element: 0x0008 }, keyword: "ReviewerName", name: "Reviewer Name", vr: &[PERSON_NAME], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x4000, element: 0x0010 }, keyword: "Arbitrary", name: "Arbitrary", vr: &[LONG_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4000, element: 0x4000 }, keyword: "TextComments", name: "Text Comments", vr: &[LONG_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0040 }, keyword: "ResultsID", name: "Results ID", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0042 }, keyword: "ResultsIDIssuer", name: "Results ID Issuer", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0050 }, keyword: "ReferencedInterpretationSequence", name: "Referenced Interpretation Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x00FF }, keyword: "ReportProductionStatusTrial", name: "Report Production Status (Trial)", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0100 }, keyword: "InterpretationRecordedDate", name: "Interpretation Recorded Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0101 }, keyword: "InterpretationRecordedTime", name: "Interpretation Recorded Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0102 }, keyword: "InterpretationRecorder", name: "Interpretation Recorder", vr: &[PERSON_NAME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0103 }, keyword: "ReferenceToRecordedSound", name: "Reference to Recorded Sound", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0108 }, keyword: "InterpretationTranscriptionDate", name: "Interpretation Transcription Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0109 }, keyword: "InterpretationTranscriptionTime", name: "Interpretation Transcription Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x010A }, keyword: "InterpretationTranscriber", name: "Interpretation Transcriber", vr: &[PERSON_NAME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x010B }, keyword: "InterpretationText", name: "Interpretation Text", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x010C }, keyword: "InterpretationAuthor", name: "Interpretation Author", vr: &[PERSON_NAME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0111 }, keyword: "InterpretationApproverSequence", name: "Interpretation Approver Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0112 }, keyword: "InterpretationApprovalDate", name: "Interpretation Approval Date", vr: &[DATE], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0113 }, keyword: "InterpretationApprovalTime", name: "Interpretation Approval Time", vr: &[TIME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0114 }, keyword: "PhysicianApprovingInterpretation", name: "Physician Approving Interpretation", vr: &[PERSON_NAME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0115 }, keyword: "InterpretationDiagnosisDescription", name: "Interpretation Diagnosis Description", vr: &[LONG_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0117 }, keyword: "InterpretationDiagnosisCodeSequence", name: "Interpretation Diagnosis Code Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0118 }, keyword: "ResultsDistributionListSequence", name: "Results Distribution List Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0119 }, keyword: "DistributionName", name: "Distribution Name", vr: &[PERSON_NAME], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x011A }, keyword: "DistributionAddress", name: "Distribution Address", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0200 }, keyword: "InterpretationID", name: "Interpretation ID", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0202 }, keyword: "InterpretationIDIssuer", name: "Interpretation ID Issuer", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0210 }, keyword: "InterpretationTypeID", name: "Interpretation Type ID", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0212 }, keyword: "InterpretationStatusID", name: "Interpretation Status ID", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x0300 }, keyword: "Impressions", name: "Impressions", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4008, element: 0x4000 }, keyword: "ResultsComments", name: "Results Comments", vr: &[SHORT_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x4FFE, element: 0x0001 }, keyword: "MACParametersSequence", name: "MAC Parameters Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5200, element: 0x9229 }, keyword: "SharedFunctionalGroupsSequence", name: "Shared Functional Groups Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5200, element: 0x9230 }, keyword: "PerFrameFunctionalGroupsSequence", name: "Per-Frame Functional Groups Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5400, element: 0x0100 }, keyword: "WaveformSequence", name: "Waveform Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5400, element: 0x0110 }, keyword: "ChannelMinimumValue", name: "Channel Minimum Value", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5400, element: 0x0112 }, keyword: "ChannelMaximumValue", name: "Channel Maximum Value", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5400, element: 0x1004 }, keyword: "WaveformBitsAllocated", name: "Waveform Bits Allocated", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5400, element: 0x1006 }, keyword: "WaveformSampleInterpretation", name: "Waveform Sample Interpretation", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5400, element: 0x100A }, keyword: "WaveformPaddingValue", name: "Waveform Padding Value", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5400, element: 0x1010 }, keyword: "WaveformData", name: "Waveform Data", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5600, element: 0x0010 }, keyword: "FirstOrderPhaseCorrectionAngle", name: "First Order Phase Correction Angle", vr: &[OTHER_FLOAT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x5600, element: 0x0020 }, keyword: "SpectroscopyData", name: "Spectroscopy Data", vr: &[OTHER_FLOAT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x7FE0, element: 0x0001 }, keyword: "ExtendedOffsetTable", name: "Extended Offset Table", vr: &[OTHER_64_BIT_VERY_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x7FE0, element: 0x0002 }, keyword: "ExtendedOffsetTableLengths", name: "Extended Offset Table Lengths", vr: &[OTHER_64_BIT_VERY_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x7FE0, element: 0x0003 }, keyword: "EncapsulatedPixelDataValueTotalLength", name: "Encapsulated Pixel Data Value Total Length", vr: &[UNSIGNED_64_BIT_VERY_LONG], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x7FE0, element: 0x0008 }, keyword: "FloatPixelData", name: "Float Pixel Data", vr: &[OTHER_FLOAT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x7FE0, element: 0x0009 }, keyword: "DoubleFloatPixelData", name: "Double Float Pixel Data", vr: &[OTHER_DOUBLE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x7FE0, element: 0x0010 }, keyword: "PixelData", name: "Pixel Data", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x7FE0, element: 0x0020 }, keyword: "CoefficientsSDVN", name: "Coefficients SDVN", vr: &[OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x7FE0, element: 0x0030 }, keyword: "CoefficientsSDHN", name: "Coefficients SDHN", vr: &[OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x7FE0, element: 0x0040 }, keyword: "CoefficientsSDDN", name: "Coefficients SDDN", vr: &[OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0xFFFA, element: 0xFFFA }, keyword: "DigitalSignaturesSequence", name: "Digital Signatures Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0xFFFC, element: 0xFFFC }, keyword: "DataSetTrailingPadding", name: "Data Set Trailing Padding", vr: &[OTHER_BYTE], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0xFFFE, element: 0xE000 }, keyword: "Item", name: "Item", vr: &[], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0xFFFE, element: 0xE00D }, keyword: "ItemDelimitationItem", name: "Item Delimitation Item", vr: &[], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0xFFFE, element: 0xE0DD }, keyword: "SequenceDelimitationItem", name: "Sequence Delimitation Item", vr: &[], vm: "1", retired: false },
+];
+
+/// Entries of repeating groups (50xx curves, 60xx overlays, 7Fxx variable pixel data),
+/// stored with the low byte of the group cleared.
+pub(super) const REPEATING_GROUPS_DICTIONARY: &[DictionaryEntry] = &[
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0005 }, keyword: "CurveDimensions", name: "Curve Dimensions", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0010 }, keyword: "NumberOfPoints", name: "Number of Points", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0020 }, keyword: "TypeOfData", name: "Type of Data", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0022 }, keyword: "CurveDescription", name: "Curve Description", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0030 }, keyword: "AxisUnits", name: "Axis Units", vr: &[SHORT_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0040 }, keyword: "AxisLabels", name: "Axis Labels", vr: &[SHORT_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0103 }, keyword: "DataValueRepresentation", name: "Data Value Representation", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0104 }, keyword: "MinimumCoordinateValue", name: "Minimum Coordinate Value", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0105 }, keyword: "MaximumCoordinateValue", name: "Maximum Coordinate Value", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0106 }, keyword: "CurveRange", name: "Curve Range", vr: &[SHORT_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0110 }, keyword: "CurveDataDescriptor", name: "Curve Data Descriptor", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0112 }, keyword: "CoordinateStartValue", name: "Coordinate Start Value", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x0114 }, keyword: "CoordinateStepValue", name: "Coordinate Step Value", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x1001 }, keyword: "CurveActivationLayer", name: "Curve Activation Layer", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x2000 }, keyword: "AudioType", name: "Audio Type", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x2002 }, keyword: "AudioSampleFormat", name: "Audio Sample Format", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x2004 }, keyword: "NumberOfChannels", name: "Number of Channels", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x2006 }, keyword: "NumberOfSamples", name: "Number of Samples", vr: &[UNSIGNED_LONG], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x2008 }, keyword: "SampleRate", name: "Sample Rate", vr: &[UNSIGNED_LONG], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x200A }, keyword: "TotalTime", name: "Total Time", vr: &[UNSIGNED_LONG], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x200C }, keyword: "AudioSampleData", name: "Audio Sample Data", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x200E }, keyword: "AudioComments", name: "Audio Comments", vr: &[LONG_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x2500 }, keyword: "CurveLabel", name: "Curve Label", vr: &[LONG_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x2600 }, keyword: "CurveReferencedOverlaySequence", name: "Curve Referenced Overlay Sequence", vr: &[SEQUENCE_OF_ITEMS], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x2610 }, keyword: "CurveReferencedOverlayGroup", name: "Curve Referenced Overlay Group", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x5000, element: 0x3000 }, keyword: "CurveData", name: "Curve Data", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0010 }, keyword: "OverlayRows", name: "Overlay Rows", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0011 }, keyword: "OverlayColumns", name: "Overlay Columns", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0012 }, keyword: "OverlayPlanes", name: "Overlay Planes", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0015 }, keyword: "NumberOfFramesInOverlay", name: "Number of Frames in Overlay", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0022 }, keyword: "OverlayDescription", name: "Overlay Description", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0040 }, keyword: "OverlayType", name: "Overlay Type", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0045 }, keyword: "OverlaySubtype", name: "Overlay Subtype", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0050 }, keyword: "OverlayOrigin", name: "Overlay Origin", vr: &[SIGNED_SHORT], vm: "2", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0051 }, keyword: "ImageFrameOrigin", name: "Image Frame Origin", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0052 }, keyword: "OverlayPlaneOrigin", name: "Overlay Plane Origin", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0060 }, keyword: "OverlayCompressionCode", name: "Overlay Compression Code", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0061 }, keyword: "OverlayCompressionOriginator", name: "Overlay Compression Originator", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0062 }, keyword: "OverlayCompressionLabel", name: "Overlay Compression Label", vr: &[SHORT_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0063 }, keyword: "OverlayCompressionDescription", name: "Overlay Compression Description", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0066 }, keyword: "OverlayCompressionStepPointers", name: "Overlay Compression Step Pointers", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0068 }, keyword: "OverlayRepeatInterval", name: "Overlay Repeat Interval", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0069 }, keyword: "OverlayBitsGrouped", name: "Overlay Bits Grouped", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0100 }, keyword: "OverlayBitsAllocated", name: "Overlay Bits Allocated", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0102 }, keyword: "OverlayBitPosition", name: "Overlay Bit Position", vr: &[UNSIGNED_SHORT], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0110 }, keyword: "OverlayFormat", name: "Overlay Format", vr: &[CODE_STRING], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0200 }, keyword: "OverlayLocation", name: "Overlay Location", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0800 }, keyword: "OverlayCodeLabel", name: "Overlay Code Label", vr: &[CODE_STRING], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0802 }, keyword: "OverlayNumberOfTables", name: "Overlay Number of Tables", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0803 }, keyword: "OverlayCodeTableLocation", name: "Overlay Code Table Location", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x0804 }, keyword: "OverlayBitsForCodeWord", name: "Overlay Bits For Code Word", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1001 }, keyword: "OverlayActivationLayer", name: "Overlay Activation Layer", vr: &[CODE_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1100 }, keyword: "OverlayDescriptorGray", name: "Overlay Descriptor - Gray", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1101 }, keyword: "OverlayDescriptorRed", name: "Overlay Descriptor - Red", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1102 }, keyword: "OverlayDescriptorGreen", name: "Overlay Descriptor - Green", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1103 }, keyword: "OverlayDescriptorBlue", name: "Overlay Descriptor - Blue", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1200 }, keyword: "OverlaysGray", name: "Overlays - Gray", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1201 }, keyword: "OverlaysRed", name: "Overlays - Red", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1202 }, keyword: "OverlaysGreen", name: "Overlays - Green", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1203 }, keyword: "OverlaysBlue", name: "Overlays - Blue", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1301 }, keyword: "ROIArea", name: "ROI Area", vr: &[INTEGER_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1302 }, keyword: "ROIMean", name: "ROI Mean", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1303 }, keyword: "ROIStandardDeviation", name: "ROI Standard Deviation", vr: &[DECIMAL_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x1500 }, keyword: "OverlayLabel", name: "Overlay Label", vr: &[LONG_STRING], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x3000 }, keyword: "OverlayData", name: "Overlay Data", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: false },
+    DictionaryEntry { tag: Tag { group: 0x6000, element: 0x4000 }, keyword: "OverlayComments", name: "Overlay Comments", vr: &[LONG_TEXT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x7F00, element: 0x0010 }, keyword: "VariablePixelData", name: "Variable Pixel Data", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x7F00, element: 0x0011 }, keyword: "VariableNextDataGroup", name: "Variable Next Data Group", vr: &[UNSIGNED_SHORT], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x7F00, element: 0x0020 }, keyword: "VariableCoefficientsSDVN", name: "Variable Coefficients SDVN", vr: &[OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x7F00, element: 0x0030 }, keyword: "VariableCoefficientsSDHN", name: "Variable Coefficients SDHN", vr: &[OTHER_WORD], vm: "1", retired: true },
+    DictionaryEntry { tag: Tag { group: 0x7F00, element: 0x0040 }, keyword: "VariableCoefficientsSDDN", name: "Variable Coefficients SDDN", vr: &[OTHER_WORD], vm: "1", retired: true },
+];
+
+/// Entries of ranges of elements (e.g. (0020,31xx) Source Image IDs) with the mask of the
+/// element digits they match, stored with the masked digits cleared.
+pub(super) const MASKED_ELEMENTS_DICTIONARY: &[(u16, DictionaryEntry)] = &[
+    (0xFF00, DictionaryEntry { tag: Tag { group: 0x0020, element: 0x3100 }, keyword: "SourceImageIDs", name: "Source Image IDs", vr: &[CODE_STRING], vm: "1-n", retired: true }),
+    (0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0400 }, keyword: "RowsForNthOrderCoefficients", name: "Rows For Nth Order Coefficients", vr: &[UNSIGNED_SHORT], vm: "1", retired: true }),
+    (0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0401 }, keyword: "ColumnsForNthOrderCoefficients", name: "Columns For Nth Order Coefficients", vr: &[UNSIGNED_SHORT], vm: "1", retired: true }),
+    (0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0402 }, keyword: "CoefficientCoding", name: "Coefficient Coding", vr: &[LONG_STRING], vm: "1-n", retired: true }),
+    (0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0403 }, keyword: "CoefficientCodingPointers", name: "Coefficient Coding Pointers", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true }),
+    (0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0800 }, keyword: "CodeLabel", name: "Code Label", vr: &[LONG_STRING], vm: "1-n", retired: true }),
+    (0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0802 }, keyword: "NumberOfTables", name: "Number of Tables", vr: &[UNSIGNED_SHORT], vm: "1", retired: true }),
+    (0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0803 }, keyword: "CodeTableLocation", name: "Code Table Location", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true }),
+    (0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0804 }, keyword: "BitsForCodeWord", name: "Bits For Code Word", vr: &[UNSIGNED_SHORT], vm: "1", retired: true }),
+    (0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0808 }, keyword: "ImageDataLocation", name: "Image Data Location", vr: &[ATTRIBUTE_TAG], vm: "1-n", retired: true }),
+    (0x000F, DictionaryEntry { tag: Tag { group: 0x1000, element: 0x0000 }, keyword: "EscapeTriplet", name: "Escape Triplet", vr: &[UNSIGNED_SHORT], vm: "3", retired: true }),
+    (0x000F, DictionaryEntry { tag: Tag { group: 0x1000, element: 0x0001 }, keyword: "RunLengthTriplet", name: "Run Length Triplet", vr: &[UNSIGNED_SHORT], vm: "3", retired: true }),
+    (0x000F, DictionaryEntry { tag: Tag { group: 0x1000, element: 0x0002 }, keyword: "HuffmanTableSize", name: "Huffman Table Size", vr: &[UNSIGNED_SHORT], vm: "1", retired: true }),
+    (0x000F, DictionaryEntry { tag: Tag { group: 0x1000, element: 0x0003 }, keyword: "HuffmanTableTriplet", name: "Huffman Table Triplet", vr: &[UNSIGNED_SHORT], vm: "3", retired: true }),
+    (0x000F, DictionaryEntry { tag: Tag { group: 0x1000, element: 0x0004 }, keyword: "ShiftTableSize", name: "Shift Table Size", vr: &[UNSIGNED_SHORT], vm: "1", retired: true }),
+    (0x000F, DictionaryEntry { tag: Tag { group: 0x1000, element: 0x0005 }, keyword: "ShiftTableTriplet", name: "Shift Table Triplet", vr: &[UNSIGNED_SHORT], vm: "3", retired: true }),
+    (0x0000, DictionaryEntry { tag: Tag { group: 0x1010, element: 0x0000 }, keyword: "ZonalMap", name: "Zonal Map", vr: &[UNSIGNED_SHORT], vm: "1-n", retired: true }),
+];
@@ -2,10 +2,12 @@ extern crate byteorder;
 
 use std::io::Read;
 use std::io::Cursor;
-use std::rc::Rc;
+use std::sync::Arc;
 use crate::data_reader::string_decoder::StringDecoder;
+use crate::dataset::private_creators::PrivateCreators;
 use byteorder::{LittleEndian, BigEndian, ReadBytesExt};
 use memmap2::Mmap;
+use crate::dicom_file_parser::parse_error::{ParseError, ParseErrorKind};
 use crate::utils::submap::Submap;
 use crate::utils::endianness::Endianness;
 
@@ -30,10 +32,11 @@ impl SubreaderDesc {
 
 #[derive(Clone)]
 pub struct DataReader<'a>  {
-    file: Rc<Mmap>,
+    file: Arc<Mmap>,
     cursor: Cursor<&'a [u8]>,
     endianness: Endianness,
     string_decoder: StringDecoder,
+    private_creators: PrivateCreators,
 }
 
 pub enum Whence {
@@ -43,13 +46,14 @@ pub enum Whence {
 }
 
 impl DataReader<'_> {
-    pub fn new(file: Rc<Mmap>, endianness: Endianness) -> Self {
+    pub fn new(file: Arc<Mmap>, endianness: Endianness) -> Self {
         let mmap_as_slice = unsafe { std::slice::from_raw_parts(file.as_ptr(), file.len()) };
         Self {
             file,
             cursor: Cursor::new(mmap_as_slice),
             endianness,
             string_decoder: StringDecoder::new(),
+            private_creators: PrivateCreators::new(),
         }
     }
 
@@ -64,6 +68,7 @@ impl DataReader<'_> {
             cursor: Cursor::new(mmap_as_slice),
             endianness: submap.endianness,
             string_decoder: desc.string_decoder,
+            private_creators: PrivateCreators::new(),
         }
     }
 
@@ -78,74 +83,104 @@ impl DataReader<'_> {
     pub fn change_decoder(&mut self, character_set : String) {
         self.string_decoder.change_decoder(character_set);
     }
-    pub fn read_u8(&mut self) -> u8
+
+    pub fn string_decoder(&self) -> &StringDecoder {
+        &self.string_decoder
+    }
+
+    pub fn set_string_decoder(&mut self, string_decoder: StringDecoder) {
+        self.string_decoder = string_decoder;
+    }
+
+    /// Private Creators read so far in the current data set, to find VRs of private attributes.
+    pub fn private_creators(&self) -> &PrivateCreators {
+        &self.private_creators
+    }
+
+    pub fn private_creators_mut(&mut self) -> &mut PrivateCreators {
+        &mut self.private_creators
+    }
+
+    pub fn set_private_creators(&mut self, private_creators: PrivateCreators) {
+        self.private_creators = private_creators;
+    }
+    pub fn read_u8(&mut self) -> Result<u8, ParseError>
     {
-        self.cursor.read_u8().unwrap()
+        let offset = self.position();
+        self.cursor.read_u8().map_err(|_| Self::end_of_data(offset))
     }
 
-    pub fn read_i8(&mut self) -> i8
+    pub fn read_i8(&mut self) -> Result<i8, ParseError>
     {
-        self.read_u8() as i8
+        Ok(self.read_u8()? as i8)
     }
 
-    pub fn read_u16(&mut self) -> u16 {
+    pub fn read_u16(&mut self) -> Result<u16, ParseError> {
+        let offset = self.position();
         match self.endianness {
-            Endianness::Little => self.cursor.read_u16::<LittleEndian>().unwrap(),
-            Endianness::Big => self.cursor.read_u16::<BigEndian>().unwrap(),
-        }
+            Endianness::Little => self.cursor.read_u16::<LittleEndian>(),
+            Endianness::Big => self.cursor.read_u16::<BigEndian>(),
+        }.map_err(|_| Self::end_of_data(offset))
     }
 
-    pub fn read_i16(&mut self) -> i16
+    pub fn read_i16(&mut self) -> Result<i16, ParseError>
     {
-        self.read_u16() as i16
+        Ok(self.read_u16()? as i16)
     }
 
-    pub fn read_u32(&mut self) -> u32 {
+    pub fn read_u32(&mut self) -> Result<u32, ParseError> {
+        let offset = self.position();
         match self.endianness {
-            Endianness::Little => self.cursor.read_u32::<LittleEndian>().unwrap(),
-            Endianness::Big => self.cursor.read_u32::<BigEndian>().unwrap(),
-        }
+            Endianness::Little => self.cursor.read_u32::<LittleEndian>(),
+            Endianness::Big => self.cursor.read_u32::<BigEndian>(),
+        }.map_err(|_| Self::end_of_data(offset))
     }
 
-    pub fn read_i32(&mut self) -> i32
+    pub fn read_i32(&mut self) -> Result<i32, ParseError>
     {
-        self.read_u32() as i32
+        Ok(self.read_u32()? as i32)
     }
 
-    pub fn read_u64(&mut self) -> u64 {
+    pub fn read_u64(&mut self) -> Result<u64, ParseError> {
+        let offset = self.position();
         match self.endianness {
-            Endianness::Little => self.cursor.read_u64::<LittleEndian>().unwrap(),
-            Endianness::Big => self.cursor.read_u64::<BigEndian>().unwrap(),
-        }
+            Endianness::Little => self.cursor.read_u64::<LittleEndian>(),
+            Endianness::Big => self.cursor.read_u64::<BigEndian>(),
+        }.map_err(|_| Self::end_of_data(offset))
     }
 
-    pub fn read_i64(&mut self) -> i64
+    pub fn read_i64(&mut self) -> Result<i64, ParseError>
     {
-        self.read_u64() as i64
+        Ok(self.read_u64()? as i64)
     }
 
-    pub fn read_f32(&mut self) -> f32 {
+    pub fn read_f32(&mut self) -> Result<f32, ParseError> {
+        let offset = self.position();
         match self.endianness {
-            Endianness::Little => self.cursor.read_f32::<LittleEndian>().unwrap(),
-            Endianness::Big => self.cursor.read_f32::<BigEndian>().unwrap(),
-        }
+            Endianness::Little => self.cursor.read_f32::<LittleEndian>(),
+            Endianness::Big => self.cursor.read_f32::<BigEndian>(),
+        }.map_err(|_| Self::end_of_data(offset))
     }
 
-    pub fn read_f64(&mut self) -> f64 {
+    pub fn read_f64(&mut self) -> Result<f64, ParseError> {
+        let offset = self.position();
         match self.endianness {
-            Endianness::Little => self.cursor.read_f64::<LittleEndian>().unwrap(),
-            Endianness::Big => self.cursor.read_f64::<BigEndian>().unwrap(),
-        }
+            Endianness::Little => self.cursor.read_f64::<LittleEndian>(),
+            Endianness::Big => self.cursor.read_f64::<BigEndian>(),
+        }.map_err(|_| Self::end_of_data(offset))
     }
 
-    pub fn read_bytes(&mut self, size: usize) -> Vec<u8> {
+    pub fn read_bytes(&mut self, size: usize) -> Result<Vec<u8>, ParseError> {
+        self.ensure_available(size)?;
+
         let mut buffer = vec![0; size];
-        self.cursor.read_exact(&mut buffer).unwrap();
-        buffer
+        self.read_exact(&mut buffer)?;
+        Ok(buffer)
     }
 
-    pub fn read_exact(&mut self, buffer: &mut [u8]) {
-        self.cursor.read_exact(buffer).unwrap();
+    pub fn read_exact(&mut self, buffer: &mut [u8]) -> Result<(), ParseError> {
+        let offset = self.position();
+        self.cursor.read_exact(buffer).map_err(|_| Self::end_of_data(offset))
     }
 
     pub fn unconsumed(&self) -> isize {
@@ -156,22 +191,45 @@ impl DataReader<'_> {
         self.cursor.position() as usize
     }
 
-    pub fn seek(&mut self, whence: Whence, pos: usize) {
-        match whence {
-            Whence::Start => self.cursor.set_position(pos as u64),
-            Whence::End => self.cursor.set_position((self.cursor.get_ref().len() - pos - 1) as u64),
-            Whence::Current => self.cursor.set_position(self.cursor.position() + pos as u64),
+    pub fn seek(&mut self, whence: Whence, pos: usize) -> Result<(), ParseError> {
+        let len = self.cursor.get_ref().len();
+        let new_position = match whence {
+            Whence::Start => Some(pos),
+            Whence::End => len.checked_sub(pos + 1),
+            Whence::Current => self.position().checked_add(pos),
+        };
+
+        match new_position {
+            Some(new_position) if new_position <= len => {
+                self.cursor.set_position(new_position as u64);
+                Ok(())
+            },
+            _ => Err(Self::end_of_data(self.position()))
         }
     }
 
-    pub fn read_string(&mut self, size: usize) -> String {
-        let buffer = self.read_bytes(size);
-        self.string_decoder.decode(buffer)
+    pub fn read_string(&mut self, size: usize) -> Result<String, ParseError> {
+        let buffer = self.read_bytes(size)?;
+        Ok(self.string_decoder.decode(buffer))
     }
 
-    pub fn get_subreader_desc(&self, length: usize) -> SubreaderDesc {
+    pub fn get_subreader_desc(&self, length: usize) -> Result<SubreaderDesc, ParseError> {
+        self.ensure_available(length)?;
+
         let submap = Submap::new(self.file.clone(), self.cursor.position() as usize, self.cursor.position() as usize + length, self.endianness);
-        SubreaderDesc::new(submap, self.string_decoder.clone())
+        Ok(SubreaderDesc::new(submap, self.string_decoder.clone()))
+    }
+
+    fn ensure_available(&self, length: usize) -> Result<(), ParseError> {
+        if self.unconsumed() < 0 || (self.unconsumed() as usize) < length {
+            return Err(Self::end_of_data(self.position()));
+        }
+
+        Ok(())
+    }
+
+    fn end_of_data(offset: usize) -> ParseError {
+        ParseError::new(offset, ParseErrorKind::UnexpectedEndOfData)
     }
 }
 
@@ -1,89 +1,418 @@
 use std::fmt;
+use std::io;
 use encoding::all::*;
-use encoding::{EncodingRef, DecoderTrap};
+use encoding::{Encoding, EncodingRef, DecoderTrap, EncoderTrap};
+
+const ESCAPE: u8 = 0x1B;
+
+/// Character set designated to G0 - bytes 0x00-0x7F.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum GraphicSet {
+    Ascii,
+    JisX0201Romaji,
+    JisX0208,
+    JisX0212,
+}
+
+/// Code elements in use while decoding a value with ISO 2022 code extensions.
+/// G1 covers bytes 0x80-0xFF and is decoded with an encoding compatible in that range.
+#[derive(Clone, Copy)]
+struct CodeElements {
+    g0: GraphicSet,
+    g1: Option<EncodingRef>,
+}
+
+/// Code element designated by a term of Specific Character Set, with its escape sequence.
+#[derive(Clone, Copy)]
+enum Designation {
+    G0(GraphicSet, &'static [u8]),
+    G1(EncodingRef, &'static [u8]),
+}
+
+const ASCII_ESCAPE_SEQUENCE: &[u8] = b"\x1b(B";
 
 #[derive(Clone)]
 pub struct StringDecoder {
     decoder: EncodingRef,
+    code_extensions: Option<CodeElements>,
+    designations: Vec<Designation>,
 }
 
 impl fmt::Debug for StringDecoder {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.decoder.name())
+        match self.code_extensions {
+            Some(elements) => write!(f, "ISO 2022 ({:?}, {})", elements.g0,
+                                     elements.g1.map_or("none", |g1| g1.name())),
+            None => write!(f, "{}", self.decoder.name())
+        }
+    }
+}
+
+impl Default for StringDecoder {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl StringDecoder {
     pub fn new() -> Self {
-        Self { decoder: UTF_8 }
+        Self { decoder: UTF_8, code_extensions: None, designations: Vec::new() }
     }
     pub fn decode(&self, value: Vec<u8>) -> String {
-        let mut str = self.decoder.decode(&*value, DecoderTrap::Ignore).unwrap();
+        let mut str = match self.code_extensions {
+            Some(initial) => Self::decode_with_code_extensions(initial, &value),
+            None => self.decoder.decode(&value, DecoderTrap::Ignore).unwrap(),
+        };
         str = str.trim_end_matches('\0').to_string();
 
         str
     }
 
+    /// Encodes a value for writing in the current character set. With ISO 2022 code extensions,
+    /// escape sequences switch to the declared code element covering each character. Characters
+    /// which the character set cannot represent are reported as an error.
+    pub fn encode(&self, value: &str) -> io::Result<Vec<u8>> {
+        let encoded = match self.code_extensions {
+            Some(initial) => self.encode_with_code_extensions(initial, value),
+            None => self.decoder.encode(value, EncoderTrap::Strict).ok(),
+        };
+
+        encoded.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput,
+                                             format!("Cannot encode {:?} in {:?}", value, self)))
+    }
+
+    /// Switches the decoder according to Specific Character Set (0008,0005). Values are
+    /// separated with backslash; the first one defines the initial character set, and
+    /// ISO 2022 terms enable escape sequences switching between the code elements.
+    /// Unsupported terms are reported and the current decoder is kept.
     pub fn change_decoder(&mut self, character_set : String) {
-        if character_set == "ISO_IR 100" || character_set == "ISO 2022 IR 100" {
-            self.decoder = ISO_8859_1;
-            return;
-        }
+        let terms: Vec<&str> = character_set.split('\\')
+            .map(|term| term.trim_matches(|c: char| c == ' ' || c == '\0'))
+            .collect();
 
-        if character_set == "ISO_IR 101" || character_set == "ISO 2022 IR 101" {
-            self.decoder = ISO_8859_2;
-            return;
-        }
+        let initial_term = terms.first().copied().unwrap_or_default();
+        let code_extensions = terms.iter().any(|term| term.starts_with("ISO 2022"));
 
-        if character_set == "ISO_IR 109" || character_set == "ISO 2022 IR 109" {
-            self.decoder = ISO_8859_3;
+        if code_extensions {
+            match Self::initial_code_elements(initial_term) {
+                Some(elements) => {
+                    self.code_extensions = Some(elements);
+                    self.designations = terms.iter().filter_map(|term| Self::designation(term)).collect();
+                },
+                None => log::warn!("Unsupported character set: {}, keeping {:?}", initial_term, self),
+            }
             return;
         }
 
-        if character_set == "ISO_IR 110" || character_set == "ISO 2022 IR 110" {
-            self.decoder = ISO_8859_4;
-            return;
+        match Self::single_byte_or_multi_byte_decoder(initial_term) {
+            Some(decoder) => {
+                self.decoder = decoder;
+                self.code_extensions = None;
+                self.designations.clear();
+            },
+            None => log::warn!("Unsupported character set: {}, keeping {:?}", initial_term, self),
         }
+    }
 
-        if character_set == "ISO_IR 126" || character_set == "ISO 2022 IR 126" {
-            self.decoder = ISO_8859_7;
-            return;
+    fn single_byte_or_multi_byte_decoder(term: &str) -> Option<EncodingRef> {
+        match term {
+            // Default repertoire is ASCII, UTF-8 keeps reading it and tolerates common mistakes
+            "" | "ISO_IR 6" | "ISO_IR 192" => Some(UTF_8),
+            "ISO_IR 100" => Some(ISO_8859_1),
+            "ISO_IR 101" => Some(ISO_8859_2),
+            "ISO_IR 109" => Some(ISO_8859_3),
+            "ISO_IR 110" => Some(ISO_8859_4),
+            "ISO_IR 144" => Some(ISO_8859_5),
+            "ISO_IR 127" => Some(ISO_8859_6),
+            "ISO_IR 126" => Some(ISO_8859_7),
+            "ISO_IR 138" => Some(ISO_8859_8),
+            // Windows-1254 and Windows-874 are supersets of ISO 8859-9 and TIS 620
+            "ISO_IR 148" => Some(WINDOWS_1254),
+            "ISO_IR 166" => Some(WINDOWS_874),
+            "ISO_IR 13" => Some(WINDOWS_31J),
+            "GBK" => Some(GBK),
+            "GB18030" => Some(GB18030),
+            _ => None
         }
+    }
 
-        if character_set == "ISO_IR 127" || character_set == "ISO 2022 IR 127" {
-            self.decoder = ISO_8859_6;
-            return;
-        }
+    fn initial_code_elements(term: &str) -> Option<CodeElements> {
+        let g1: Option<EncodingRef> = match term {
+            "" | "ISO 2022 IR 6" => None,
+            "ISO 2022 IR 13" => return Some(CodeElements { g0: GraphicSet::JisX0201Romaji, g1: Some(WINDOWS_31J) }),
+            "ISO 2022 IR 87" => return Some(CodeElements { g0: GraphicSet::JisX0208, g1: None }),
+            "ISO 2022 IR 159" => return Some(CodeElements { g0: GraphicSet::JisX0212, g1: None }),
+            "ISO 2022 IR 149" => Some(WINDOWS_949),
+            "ISO 2022 IR 58" => Some(GBK),
+            _ => {
+                let registration_number = term.strip_prefix("ISO 2022 IR ")?;
+                Some(Self::single_byte_or_multi_byte_decoder(&format!("ISO_IR {}", registration_number))?)
+            }
+        };
 
-        if character_set == "ISO_IR 138" || character_set == "ISO 2022 IR 138" {
-            self.decoder = ISO_8859_8;
-            return;
+        Some(CodeElements { g0: GraphicSet::Ascii, g1 })
+    }
+
+    fn designation(term: &str) -> Option<Designation> {
+        let designation = match term {
+            "" | "ISO 2022 IR 6" => Designation::G0(GraphicSet::Ascii, ASCII_ESCAPE_SEQUENCE),
+            "ISO 2022 IR 13" => Designation::G1(WINDOWS_31J, b"\x1b)I"),
+            "ISO 2022 IR 87" => Designation::G0(GraphicSet::JisX0208, b"\x1b$B"),
+            "ISO 2022 IR 159" => Designation::G0(GraphicSet::JisX0212, b"\x1b$(D"),
+            "ISO 2022 IR 149" => Designation::G1(WINDOWS_949, b"\x1b$)C"),
+            "ISO 2022 IR 58" => Designation::G1(GBK, b"\x1b$)A"),
+            "ISO 2022 IR 100" => Designation::G1(ISO_8859_1, b"\x1b-A"),
+            "ISO 2022 IR 101" => Designation::G1(ISO_8859_2, b"\x1b-B"),
+            "ISO 2022 IR 109" => Designation::G1(ISO_8859_3, b"\x1b-C"),
+            "ISO 2022 IR 110" => Designation::G1(ISO_8859_4, b"\x1b-D"),
+            "ISO 2022 IR 144" => Designation::G1(ISO_8859_5, b"\x1b-L"),
+            "ISO 2022 IR 127" => Designation::G1(ISO_8859_6, b"\x1b-G"),
+            "ISO 2022 IR 126" => Designation::G1(ISO_8859_7, b"\x1b-F"),
+            "ISO 2022 IR 138" => Designation::G1(ISO_8859_8, b"\x1b-H"),
+            "ISO 2022 IR 148" => Designation::G1(WINDOWS_1254, b"\x1b-M"),
+            "ISO 2022 IR 166" => Designation::G1(WINDOWS_874, b"\x1b-T"),
+            _ => return None,
+        };
+
+        Some(designation)
+    }
+
+    /// Recognizes the escape sequence which follows ESC, returns its length and the designated
+    /// code element.
+    fn designate(sequence: &[u8], elements: &mut CodeElements) -> Option<usize> {
+        match sequence {
+            [b'(', b'B', ..] => elements.g0 = GraphicSet::Ascii,
+            [b'(', b'J', ..] => elements.g0 = GraphicSet::JisX0201Romaji,
+            [b'$', b'B', ..] => elements.g0 = GraphicSet::JisX0208,
+            [b'$', b'(', b'D', ..] => { elements.g0 = GraphicSet::JisX0212; return Some(3); },
+            [b')', b'I', ..] => elements.g1 = Some(WINDOWS_31J),
+            [b'$', b')', b'C', ..] => { elements.g1 = Some(WINDOWS_949); return Some(3); },
+            [b'$', b')', b'A', ..] => { elements.g1 = Some(GBK); return Some(3); },
+            [b'-', b'A', ..] => elements.g1 = Some(ISO_8859_1),
+            [b'-', b'B', ..] => elements.g1 = Some(ISO_8859_2),
+            [b'-', b'C', ..] => elements.g1 = Some(ISO_8859_3),
+            [b'-', b'D', ..] => elements.g1 = Some(ISO_8859_4),
+            [b'-', b'L', ..] => elements.g1 = Some(ISO_8859_5),
+            [b'-', b'G', ..] => elements.g1 = Some(ISO_8859_6),
+            [b'-', b'F', ..] => elements.g1 = Some(ISO_8859_7),
+            [b'-', b'H', ..] => elements.g1 = Some(ISO_8859_8),
+            [b'-', b'M', ..] => elements.g1 = Some(WINDOWS_1254),
+            [b'-', b'T', ..] => elements.g1 = Some(WINDOWS_874),
+            _ => return None
         }
 
-        if character_set == "ISO_IR 144" || character_set == "ISO 2022 IR 144" {
-            self.decoder = ISO_8859_5;
-            return;
+        Some(2)
+    }
+
+    /// Decodes a value split by escape sequences into runs of G0 and G1 bytes. Delimiters of
+    /// multi-valued elements and control characters restore the initial code elements,
+    /// as required by PS3.5 6.1.2.5.3.
+    fn decode_with_code_extensions(initial: CodeElements, value: &[u8]) -> String {
+        let mut result = String::with_capacity(value.len());
+        let mut elements = initial;
+        let mut run_start = 0;
+        let mut pos = 0;
+
+        while pos < value.len() {
+            let byte = value[pos];
+            let single_byte_g0 = matches!(elements.g0, GraphicSet::Ascii | GraphicSet::JisX0201Romaji);
+
+            if byte == ESCAPE {
+                Self::decode_run(elements, &value[run_start..pos], &mut result);
+
+                match Self::designate(&value[pos + 1..], &mut elements) {
+                    Some(length) => pos += 1 + length,
+                    None => {
+                        log::warn!("Unsupported escape sequence in {:?}", value);
+                        pos += 1;
+                    }
+                }
+
+                run_start = pos;
+                continue;
+            }
+
+            if single_byte_g0 && matches!(byte, b'\\' | b'\r' | b'\n' | b'\t' | 0x0C) {
+                Self::decode_run(elements, &value[run_start..=pos], &mut result);
+                elements = initial;
+                pos += 1;
+                run_start = pos;
+                continue;
+            }
+
+            pos += 1;
         }
 
-        if character_set == "ISO_IR 148" || character_set == "ISO 2022 IR 148" {
-            // self.decoder = ISO_8859_9;
+        Self::decode_run(elements, &value[run_start..], &mut result);
+        result
+    }
+
+    /// Counterpart of `decode_with_code_extensions`. ASCII is always written in G0 designated to
+    /// ASCII, so delimiters are recognized, and the value ends in that state. Components of person
+    /// names designate their code elements again, as in the examples of PS3.5 Annex H and I.
+    fn encode_with_code_extensions(&self, initial: CodeElements, value: &str) -> Option<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(value.len());
+        let mut elements = initial;
+        let single_byte_g0 = |elements: &CodeElements| matches!(elements.g0, GraphicSet::Ascii | GraphicSet::JisX0201Romaji);
+
+        for character in value.chars() {
+            if character.is_ascii() {
+                if !single_byte_g0(&elements) {
+                    bytes.extend_from_slice(ASCII_ESCAPE_SEQUENCE);
+                    elements.g0 = GraphicSet::Ascii;
+                }
+                bytes.push(character as u8);
+
+                match character {
+                    '\\' | '\r' | '\n' | '\t' | '\x0C' => elements = initial,
+                    '^' | '=' => elements.g1 = None,
+                    _ => {},
+                }
+                continue;
+            }
+
+            let in_current_elements = Self::encode_character(Designation::G0(elements.g0, &[]), character)
+                .or_else(|| Self::encode_character(Designation::G1(elements.g1?, &[]), character));
+
+            if let Some(encoded) = in_current_elements {
+                bytes.extend_from_slice(&encoded);
+                continue;
+            }
+
+            let (designation, encoded) = self.designations.iter()
+                .find_map(|designation| Some((*designation, Self::encode_character(*designation, character)?)))?;
+
+            match designation {
+                Designation::G0(g0, escape_sequence) => {
+                    elements.g0 = g0;
+                    bytes.extend_from_slice(escape_sequence);
+                },
+                Designation::G1(g1, escape_sequence) => {
+                    elements.g1 = Some(g1);
+                    bytes.extend_from_slice(escape_sequence);
+                },
+            }
+            bytes.extend_from_slice(&encoded);
         }
 
-        if character_set == "ISO_IR 166" || character_set == "ISO 2022 IR 166" {
-            self.decoder = ISO_8859_10;
-            return;
+        if !single_byte_g0(&elements) {
+            bytes.extend_from_slice(ASCII_ESCAPE_SEQUENCE);
         }
 
-        if character_set == "ISO_IR 192" || character_set == "ISO 2022 IR 192" {
-            self.decoder = UTF_8;
-            return;
+        Some(bytes)
+    }
+
+    /// Bytes of a non-ASCII character in the code element, if it can represent it.
+    fn encode_character(designation: Designation, character: char) -> Option<Vec<u8>> {
+        let mut buffer = [0; 4];
+        let character = character.encode_utf8(&mut buffer);
+
+        match designation {
+            // JIS X 0208 and JIS X 0212 are encoded as their EUC-JP form without the high bit
+            Designation::G0(GraphicSet::JisX0208, _) => match EUC_JP.encode(character, EncoderTrap::Strict).ok()?.as_slice() {
+                [first, second] if *first >= 0xA1 && *second >= 0xA1 => Some(vec![first & 0x7F, second & 0x7F]),
+                _ => None,
+            },
+            Designation::G0(GraphicSet::JisX0212, _) => match EUC_JP.encode(character, EncoderTrap::Strict).ok()?.as_slice() {
+                [0x8F, first, second] => Some(vec![first & 0x7F, second & 0x7F]),
+                _ => None,
+            },
+            Designation::G0(GraphicSet::Ascii | GraphicSet::JisX0201Romaji, _) => None,
+            Designation::G1(g1, _) => g1.encode(character, EncoderTrap::Strict).ok()
+                .filter(|bytes| bytes.iter().all(|byte| *byte >= 0x80)),
         }
+    }
 
-        if character_set == "GBK" || character_set == "GB18030" {
-            self.decoder = GBK;
-            return;
+    fn decode_run(elements: CodeElements, run: &[u8], result: &mut String) {
+        let mut start = 0;
+
+        while start < run.len() {
+            let high = run[start] >= 0x80;
+            let length = run[start..].iter().take_while(|byte| (**byte >= 0x80) == high).count();
+            let bytes = &run[start..start + length];
+
+            match (high, elements.g1) {
+                (true, Some(g1)) => result.push_str(&g1.decode(bytes, DecoderTrap::Ignore).unwrap()),
+                (true, None) => result.push_str(&ISO_8859_1.decode(bytes, DecoderTrap::Ignore).unwrap()),
+                (false, _) => Self::decode_g0(elements.g0, bytes, result),
+            }
+
+            start += length;
         }
+    }
 
-        panic!("Unsupported character set: {}", character_set)
+    fn decode_g0(g0: GraphicSet, bytes: &[u8], result: &mut String) {
+        // JIS X 0208 and JIS X 0212 are decoded as their EUC-JP form with the high bit set
+        let euc_jp: Vec<u8> = match g0 {
+            GraphicSet::Ascii | GraphicSet::JisX0201Romaji => {
+                result.push_str(&ASCII.decode(bytes, DecoderTrap::Ignore).unwrap());
+                return;
+            },
+            GraphicSet::JisX0208 => bytes.iter().map(|byte| byte | 0x80).collect(),
+            GraphicSet::JisX0212 => bytes.chunks(2)
+                .flat_map(|pair| std::iter::once(0x8F).chain(pair.iter().map(|byte| byte | 0x80)))
+                .collect(),
+        };
+
+        result.push_str(&EUC_JP.decode(&euc_jp, DecoderTrap::Ignore).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decoder(character_set: &str) -> StringDecoder {
+        let mut decoder = StringDecoder::new();
+        decoder.change_decoder(character_set.to_string());
+        decoder
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_single_byte_character_sets() {
+        assert_eq!(decoder("ISO_IR 100").decode(b"Buc^J\xe9r\xf4me".to_vec()), "Buc^Jérôme");
+        assert_eq!(decoder("ISO_IR 148").decode(b"\xc7EL\xddK".to_vec()), "ÇELİK");
+
+        let mut unsupported = decoder("ISO_IR 144");
+        unsupported.change_decoder("ISO_IR 999".to_string());
+        assert_eq!(unsupported.decode(b"\xbb\xee\xda\xe1\xd5\xdc\xd1\xe3\xe0\xd3".to_vec()), "Люксембург");
+    }
+
+    #[test]
+    fn test_japanese_code_extensions() {
+        // PS3.5 H.3.1
+        let value = b"Yamada^Tarou=\x1b$B;3ED\x1b(B^\x1b$BB@O:\x1b(B=\x1b$B$d$^$@\x1b(B^\x1b$B$?$m$&\x1b(B";
+        assert_eq!(decoder("\\ISO 2022 IR 87").decode(value.to_vec()), "Yamada^Tarou=山田^太郎=やまだ^たろう");
+
+        // PS3.5 H.3.2, half-width katakana in G1
+        let value = b"\xd4\xcf\xc0\xde^\xc0\xdb\xb3=\x1b$B;3ED\x1b(J^\x1b$BB@O:\x1b(J=\x1b$B$d$^$@\x1b(J^\x1b$B$?$m$&\x1b(J";
+        assert_eq!(decoder("ISO 2022 IR 13\\ISO 2022 IR 87").decode(value.to_vec()),
+                   "ﾔﾏﾀﾞ^ﾀﾛｳ=山田^太郎=やまだ^たろう");
+    }
+
+    #[test]
+    fn test_encode_with_escape_sequences() {
+        let japanese = decoder("\\ISO 2022 IR 87");
+        assert_eq!(japanese.encode("Yamada^Tarou=山田^太郎=やまだ^たろう").unwrap(),
+                   b"Yamada^Tarou=\x1b$B;3ED\x1b(B^\x1b$BB@O:\x1b(B=\x1b$B$d$^$@\x1b(B^\x1b$B$?$m$&\x1b(B");
+
+        let korean = decoder("\\ISO 2022 IR 149");
+        assert_eq!(korean.encode("Hong^Gildong=洪^吉洞=홍^길동").unwrap(),
+                   b"Hong^Gildong=\x1b$)C\xfb\xf3^\x1b$)C\xd1\xce\xd4\xd7=\x1b$)C\xc8\xab^\x1b$)C\xb1\xe6\xb5\xbf");
+        assert_eq!(korean.decode(korean.encode("홍^길동\\Hong").unwrap()), "홍^길동\\Hong");
+
+        assert!(korean.encode("กข").is_err());
+        assert!(decoder("ISO_IR 100").encode("Люксембург").is_err());
+    }
+
+    #[test]
+    fn test_korean_and_chinese_code_extensions() {
+        // PS3.5 I.2
+        let value = b"Hong^Gildong=\x1b$)C\xfb\xf3^\x1b$)C\xd1\xce\xd4\xd7=\x1b$)C\xc8\xab^\x1b$)C\xb1\xe6\xb5\xbf";
+        assert_eq!(decoder("\\ISO 2022 IR 149").decode(value.to_vec()), "Hong^Gildong=洪^吉洞=홍^길동");
+
+        // PS3.5 K.2
+        let value = b"Zhang^XiaoDong=\x1b$)A\xd5\xc5^\x1b$)A\xd0\xa1\xb6\xab=";
+        assert_eq!(decoder("\\ISO 2022 IR 58").decode(value.to_vec()), "Zhang^XiaoDong=张^小东=");
+    }
+}
@@ -0,0 +1,224 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::dataset::data_element::DataElement;
+use crate::dataset::path_error::PathError;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_field::ValueField;
+use crate::traits::cast::{Cast, CastArray};
+
+const WILDCARD: &str = "*";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Items {
+    Unspecified,
+    All,
+    Index(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Segment {
+    text: String,
+    /// `None` for the wildcard, matching any attribute.
+    tag: Option<Tag>,
+    items: Items,
+}
+
+impl Segment {
+    fn parse(text: &str) -> Result<Self, PathError> {
+        let invalid = |reason| PathError::InvalidSegment { segment: text.to_string(), reason };
+
+        let (attribute, items) = match text.split_once('[') {
+            Some((attribute, index)) => {
+                let index = index.strip_suffix(']').ok_or_else(|| invalid("Missing ']'"))?;
+                let items = match index {
+                    WILDCARD => Items::All,
+                    index => Items::Index(index.parse().map_err(|_| invalid("Item index is not a number"))?),
+                };
+                (attribute, items)
+            },
+            None => (text, Items::Unspecified),
+        };
+
+        let tag = match attribute {
+            WILDCARD => None,
+            attribute => Some(parse_tag(attribute).ok_or_else(|| invalid("Unknown keyword or malformed tag"))?),
+        };
+
+        Ok(Self { text: text.to_string(), tag, items })
+    }
+
+    fn matches(&self, tag: Tag) -> bool {
+        self.tag.is_none_or(|segment_tag| segment_tag == tag)
+    }
+}
+
+/// Keyword, e.g. `PixelSpacing`, or tag as `00280030` or `(0028,0030)`.
+fn parse_tag(text: &str) -> Option<Tag> {
+    if let Some(tag) = Tag::from_keyword(text) {
+        return Some(tag);
+    }
+
+    let hex = match text.strip_prefix('(').and_then(|text| text.strip_suffix(')')) {
+        Some(tag) => tag.split_once(',').map(|(group, element)| format!("{}{}", group, element))?,
+        None => text.to_string(),
+    };
+
+    if hex.len() != 8 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(Tag {
+        group: u16::from_str_radix(&hex[..4], 16).ok()?,
+        element: u16::from_str_radix(&hex[4..], 16).ok()?,
+    })
+}
+
+/// Path to attributes nested in sequences, e.g.
+/// `SharedFunctionalGroupsSequence[0].PixelMeasuresSequence[0].PixelSpacing`.
+///
+/// Segments are keywords or tags (`00280030` or `(0028,0030)`), `*` matches any attribute.
+/// Items of a sequence are selected by an index counted from 0, `[*]` or no index selects all of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributePath {
+    segments: Vec<Segment>,
+}
+
+impl AttributePath {
+    pub fn parse(path: &str) -> Result<Self, PathError> {
+        let segments = path.split('.')
+            .map(Segment::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let last = segments.last().expect("Split should return at least one segment");
+        if last.items != Items::Unspecified {
+            return Err(PathError::InvalidSegment { segment: last.text.clone(), reason: "The last segment cannot select items" });
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// All attributes matching the path, in the order of the dataset.
+    pub fn find<'a>(&self, data_elements: &'a [DataElement]) -> Result<Vec<&'a DataElement>, PathError> {
+        let mut datasets = vec![data_elements];
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            let not_found = || PathError::NotFound { segment: segment.text.clone() };
+
+            let matches: Vec<&DataElement> = datasets.iter()
+                .flat_map(|dataset| dataset.iter())
+                .filter(|data_element| segment.matches(data_element.tag))
+                .collect();
+
+            if matches.is_empty() {
+                return Err(not_found());
+            }
+
+            if index == self.segments.len() - 1 {
+                return Ok(matches);
+            }
+
+            datasets = Vec::new();
+
+            for data_element in matches {
+                let ValueField::SequenceOfItems(sequence) = &data_element.value else {
+                    // The wildcard passes through sequences only
+                    if segment.tag.is_none() {
+                        continue;
+                    }
+                    return Err(PathError::NotASequence { segment: segment.text.clone() });
+                };
+
+                match segment.items {
+                    Items::Index(item) => datasets.extend(sequence.items.get(item).map(|item| item.data_elements.as_slice())),
+                    Items::All | Items::Unspecified => datasets.extend(sequence.items.iter().map(|item| item.data_elements.as_slice())),
+                }
+            }
+
+            if datasets.is_empty() {
+                return Err(not_found());
+            }
+        }
+
+        unreachable!("Path should have at least one segment")
+    }
+
+    pub fn find_first<'a>(&self, data_elements: &'a [DataElement]) -> Result<&'a DataElement, PathError> {
+        Ok(self.find(data_elements)?[0])
+    }
+
+    /// Value of the first matching attribute, e.g. `u16` for Rows.
+    pub fn value<T>(&self, data_elements: &[DataElement]) -> Result<T, PathError> where ValueField: Cast<T> {
+        Cast::<T>::cast(&self.find_first(data_elements)?.value)
+            .map_err(|error| self.cast_error(error.to_string()))
+    }
+
+    /// Exactly `N` values of the first matching attribute, e.g. `[f64; 2]` for Pixel Spacing.
+    pub fn values<T, const N: usize>(&self, data_elements: &[DataElement]) -> Result<[T; N], PathError> where ValueField: CastArray<T, N> {
+        CastArray::<T, N>::cast(&self.find_first(data_elements)?.value)
+            .map_err(|error| self.cast_error(error.to_string()))
+    }
+
+    fn cast_error(&self, reason: String) -> PathError {
+        let segment = self.segments.last().expect("Path should have at least one segment");
+        PathError::Cast { segment: segment.text.clone(), reason }
+    }
+}
+
+impl FromStr for AttributePath {
+    type Err = PathError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::parse(path)
+    }
+}
+
+impl fmt::Display for AttributePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let segments: Vec<&str> = self.segments.iter().map(|segment| segment.text.as_str()).collect();
+        write!(f, "{}", segments.join("."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_json::dicom_json_reader::DicomJsonReader;
+    use super::*;
+
+    fn dataset() -> Vec<DataElement> {
+        DicomJsonReader::new().read(concat!(
+            r#"{"00280010":{"vr":"US","Value":[512]},"#,
+            r#""52009229":{"vr":"SQ","Value":[{"00289110":{"vr":"SQ","Value":[{"00280030":{"vr":"DS","Value":[0.5,0.75]}}]}}]},"#,
+            r#""52009230":{"vr":"SQ","Value":[{"00209111":{"vr":"SQ","Value":[{"00209157":{"vr":"UL","Value":[1,1]}}]}},"#,
+            r#"{"00209111":{"vr":"SQ","Value":[{"00209157":{"vr":"UL","Value":[1,2]}}]}}]}}"#)).unwrap()
+    }
+
+    #[test]
+    fn test_find_values() {
+        let dataset = dataset();
+
+        let path = AttributePath::parse("SharedFunctionalGroupsSequence[0].PixelMeasuresSequence[0].PixelSpacing").unwrap();
+        assert_eq!(path.values::<f64, 2>(&dataset), Ok([0.5, 0.75]));
+        assert_eq!(AttributePath::parse("(0028,0010)").unwrap().value::<u32>(&dataset), Ok(512));
+
+        let path = AttributePath::parse("52009230[*].*.DimensionIndexValues").unwrap();
+        let values: Vec<[u32; 2]> = path.find(&dataset).unwrap().iter()
+            .map(|data_element| CastArray::<u32, 2>::cast(&data_element.value).unwrap())
+            .collect();
+        assert_eq!(values, [[1, 1], [1, 2]]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let dataset = dataset();
+        let error = |path: &str| AttributePath::parse(path).and_then(|path| path.value::<f64>(&dataset)).unwrap_err();
+
+        assert_eq!(error("SharedFunctionalGroupsSequence[1].PixelMeasuresSequence[0].PixelSpacing"),
+                   PathError::NotFound { segment: "SharedFunctionalGroupsSequence[1]".to_string() });
+        assert_eq!(error("SharedFunctionalGroupsSequence[0].PlaneOrientationSequence[0].ImageOrientationPatient"),
+                   PathError::NotFound { segment: "PlaneOrientationSequence[0]".to_string() });
+        assert_eq!(error("Rows.Columns"), PathError::NotASequence { segment: "Rows".to_string() });
+        assert!(matches!(error("PixelSpacingg"), PathError::InvalidSegment { .. }));
+        assert!(matches!(error("SharedFunctionalGroupsSequence[x].Rows"), PathError::InvalidSegment { .. }));
+        assert!(matches!(error("SharedFunctionalGroupsSequence[0].PixelMeasuresSequence"), PathError::Cast { .. }));
+    }
+}
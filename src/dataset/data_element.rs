@@ -8,4 +8,7 @@ pub struct DataElement {
     pub value_representation: Option<ValueRepresentation>,
     pub value_length: u32,
     pub value: ValueField,
+    /// Position of the tag in the parsed data, `None` for elements created in memory. For deflated
+    /// files it is a position in the inflated dataset.
+    pub offset: Option<usize>,
 }
\ No newline at end of file
@@ -1,9 +1,9 @@
-use once_cell::unsync::OnceCell;
+use once_cell::sync::OnceCell;
 
 pub struct DataElementLocation<ReturnType>
 {
     value: OnceCell<ReturnType>,
-    reader: Box<dyn Fn() -> ReturnType>
+    reader: Box<dyn Fn() -> ReturnType + Send + Sync>
 }
 
 impl<ReturnType> DataElementLocation<ReturnType>
@@ -14,7 +14,7 @@ where ReturnType: Clone
         self.value.get_or_init(|| (self.reader)()).clone()
     }
 
-    pub fn new(reader: Box<dyn Fn() -> ReturnType>) -> Self
+    pub fn new(reader: Box<dyn Fn() -> ReturnType + Send + Sync>) -> Self
     {
         Self { value: OnceCell::default(), reader }
     }
@@ -3,3 +3,6 @@ pub mod data_element;
 pub(crate) mod value_representation;
 pub mod value_field;
 pub mod data_element_location;
+pub mod attribute_path;
+pub mod path_error;
+pub mod private_creators;
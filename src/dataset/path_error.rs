@@ -0,0 +1,26 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathError {
+    /// The segment is neither a keyword nor a tag, or has a malformed item index.
+    InvalidSegment { segment: String, reason: &'static str },
+    /// Nothing in the dataset matches the segment - the attribute or the item is missing.
+    NotFound { segment: String },
+    /// The segment is followed by another one, but the attribute is not a sequence.
+    NotASequence { segment: String },
+    /// The attribute was found, but its value cannot be converted to the requested type.
+    Cast { segment: String, reason: String },
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::InvalidSegment { segment, reason } => write!(f, "Invalid path segment '{}': {}", segment, reason),
+            PathError::NotFound { segment } => write!(f, "Nothing matches path segment '{}'", segment),
+            PathError::NotASequence { segment } => write!(f, "Path segment '{}' is not a sequence", segment),
+            PathError::Cast { segment, reason } => write!(f, "Value of '{}' cannot be converted: {}", segment, reason),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+use crate::data_dictionary::data_dictionary::{DataDictionary, PrivateDictionaryEntry};
+use crate::dataset::data_element::DataElement;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_field::ValueField;
+
+const FIRST_BLOCK: u16 = 0x0010;
+const LAST_BLOCK: u16 = 0x00FF;
+
+/// Odd groups which cannot be used for private attributes.
+const RESERVED_GROUPS: [u16; 5] = [0x0001, 0x0003, 0x0005, 0x0007, 0xFFFF];
+
+/// Private Creators of a data set. A creator `(gggg,00xx)` reserves the block of elements
+/// `(gggg,xx00)`-`(gggg,xxFF)`, so the same private attribute may be found in different blocks
+/// of different files.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PrivateCreators {
+    blocks: BTreeMap<(u16, u8), String>,
+}
+
+impl PrivateCreators {
+    pub fn new() -> Self {
+        Self { blocks: BTreeMap::new() }
+    }
+
+    /// Creators of the given data set level; creators of nested items are not included.
+    pub fn from_data_elements(data_elements: &[DataElement]) -> Self {
+        let mut private_creators = Self::new();
+        for data_element in data_elements {
+            private_creators.add(data_element);
+        }
+        private_creators
+    }
+
+    pub fn is_private_group(group: u16) -> bool {
+        group & 1 == 1 && !RESERVED_GROUPS.contains(&group)
+    }
+
+    pub fn is_private_creator(tag: &Tag) -> bool {
+        Self::is_private_group(tag.group) && (FIRST_BLOCK..=LAST_BLOCK).contains(&tag.element)
+    }
+
+    pub fn is_private_attribute(tag: &Tag) -> bool {
+        Self::is_private_group(tag.group) && tag.element >> 8 >= FIRST_BLOCK
+    }
+
+    /// Registers the block of a Private Creator element, other elements are ignored.
+    pub fn add(&mut self, data_element: &DataElement) {
+        if !Self::is_private_creator(&data_element.tag) {
+            return;
+        }
+
+        let creator = match &data_element.value {
+            ValueField::LongString(creator) => Into::<String>::into(creator),
+            _ => return,
+        };
+
+        let block = (data_element.tag.group, data_element.tag.element as u8);
+        self.blocks.insert(block, creator.trim_matches([' ', '\0']).to_string());
+    }
+
+    /// Creator reserving the block of a private attribute, e.g. of (0019,0010) for (0019,100C).
+    pub fn creator(&self, tag: &Tag) -> Option<&str> {
+        if !Self::is_private_attribute(tag) {
+            return None;
+        }
+
+        self.blocks.get(&(tag.group, (tag.element >> 8) as u8)).map(String::as_str)
+    }
+
+    /// Tag of a private attribute in the block reserved by the creator in this data set, in the
+    /// lowest block if the creator reserved several.
+    pub fn tag(&self, creator: &str, group: u16, element: u8) -> Option<Tag> {
+        let creator = creator.trim_end_matches([' ', '\0']);
+
+        self.blocks.range((group, u8::MIN)..=(group, u8::MAX))
+            .find(|(_, block_creator)| block_creator.as_str() == creator)
+            .map(|((_, block), _)| Tag { group, element: (*block as u16) << 8 | element as u16 })
+    }
+
+    pub fn dictionary_entry(&self, tag: &Tag) -> Option<&'static PrivateDictionaryEntry> {
+        DataDictionary::get_private(self.creator(tag)?, tag.group, tag.element as u8)
+    }
+}
+
+/// Finds a private attribute by its creator, group and element within the block, wherever the
+/// creator reserved its block, e.g. `find_private(&data_elements, "SIEMENS MR HEADER", 0x0019, 0x0C)`.
+pub fn find_private<'a>(data_elements: &'a [DataElement], creator: &str, group: u16, element: u8) -> Option<&'a DataElement> {
+    let tag = PrivateCreators::from_data_elements(data_elements).tag(creator, group, element)?;
+
+    data_elements.iter().find(|data_element| data_element.tag == tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::value_representation::LONG_STRING;
+    use crate::value_representations::dicom_string::DicomString;
+    use super::*;
+
+    fn private_creator(group: u16, element: u16, creator: &str) -> DataElement {
+        DataElement {
+            tag: Tag { group, element },
+            value_representation: Some(LONG_STRING),
+            value_length: creator.len() as u32,
+            value: ValueField::LongString(DicomString::from(creator.to_string())),
+            offset: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_blocks() {
+        let private_creators = PrivateCreators::from_data_elements(&[
+            private_creator(0x0019, 0x0010, "SIEMENS MR HEADER "),
+            private_creator(0x0019, 0x0011, "GEMS_ACQU_01"),
+        ]);
+
+        assert_eq!(private_creators.creator(&Tag { group: 0x0019, element: 0x100C }), Some("SIEMENS MR HEADER"));
+        assert_eq!(private_creators.creator(&Tag { group: 0x0019, element: 0x119C }), Some("GEMS_ACQU_01"));
+        assert_eq!(private_creators.creator(&Tag { group: 0x0019, element: 0x120C }), None);
+        assert_eq!(private_creators.creator(&Tag { group: 0x0019, element: 0x0010 }), None);
+
+        assert_eq!(private_creators.tag("GEMS_ACQU_01", 0x0019, 0x9C), Some(Tag { group: 0x0019, element: 0x119C }));
+        assert_eq!(private_creators.tag("GEMS_ACQU_01", 0x0021, 0x9C), None);
+        assert_eq!(private_creators.dictionary_entry(&Tag { group: 0x0019, element: 0x119C }).unwrap().name, "Pulse Sequence Name");
+    }
+    #[test]
+    fn test_lowest_block_of_creator() {
+        let private_creators = PrivateCreators::from_data_elements(&[
+            private_creator(0x0019, 0x0012, "SIEMENS MR HEADER"),
+            private_creator(0x0019, 0x0011, "GEMS_ACQU_01"),
+            private_creator(0x0019, 0x0010, "SIEMENS MR HEADER"),
+        ]);
+
+        assert_eq!(private_creators.tag("SIEMENS MR HEADER ", 0x0019, 0x0C), Some(Tag { group: 0x0019, element: 0x100C }));
+        assert_eq!(private_creators.tag("SIEMENS MR HEADER\0", 0x0019, 0x0C), Some(Tag { group: 0x0019, element: 0x100C }));
+    }
+
+    #[test]
+    fn test_reserved_groups() {
+        for group in [0x0001, 0x0003, 0x0005, 0x0007, 0xFFFF] {
+            assert!(!PrivateCreators::is_private_creator(&Tag { group, element: 0x0010 }));
+            assert!(!PrivateCreators::is_private_attribute(&Tag { group, element: 0x1010 }));
+        }
+
+        assert!(PrivateCreators::is_private_creator(&Tag { group: 0x0009, element: 0x0010 }));
+        assert!(PrivateCreators::is_private_attribute(&Tag { group: 0xFFFD, element: 0x1010 }));
+    }
+}
@@ -1,13 +1,45 @@
 use std::fmt;
+use crate::data_dictionary::data_dictionary::DataDictionary;
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
 pub struct Tag {
     pub group: u16,
     pub element: u16,
 }
 
+impl Tag {
+    /// Tag of the standard attribute with given keyword, e.g. "PatientName".
+    pub fn from_keyword(keyword: &str) -> Option<Tag> {
+        DataDictionary::tag(keyword)
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:04X},{:04X})", self.group, self.element)?;
+
+        match DataDictionary::get(self) {
+            Some(entry) => write!(f, " {}", entry.name),
+            None => Ok(())
+        }
+    }
+}
+
 impl fmt::Debug for Tag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({:#06X}, {:#06X})", self.group, self.element)
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Tag { group: 0x0010, element: 0x0010 }.to_string(), "(0010,0010) Patient's Name");
+        assert_eq!(format!("{:?}", Tag { group: 0x6002, element: 0x3000 }), "(6002,3000) Overlay Data");
+        assert_eq!(Tag { group: 0x0029, element: 0x1010 }.to_string(), "(0029,1010)");
+        assert_eq!(Tag::from_keyword("PixelData"), Some(Tag { group: 0x7FE0, element: 0x0010 }));
     }
 }
@@ -1,10 +1,17 @@
 
+use std::fmt::Debug;
+use std::str::FromStr;
+use num_traits::{Num, NumCast};
+use crate::traits::cast::{Cast, CastArray, CastArrayError, CastError};
+use crate::value_representations::age_string::Age;
 use crate::value_representations::attribute_tag::AttributeTag;
+use crate::value_representations::date_time::{Date, DateTime, Time, ValueRange};
 use crate::value_representations::sequence_of_items::SequenceOfItems;
 use crate::value_representations::dicom_string::DicomString;
 use crate::value_representations::numeric_string::NumericString;
-use crate::value_representations::numeric_type::NumericType;
+use crate::value_representations::numeric_type::{Numeric, NumericType};
 use crate::value_representations::other_type::OtherType;
+use crate::value_representations::person_name::PersonName;
 use crate::value_representations::unknown::Unknown;
 
 #[derive(Debug)]
@@ -43,4 +50,72 @@ pub enum ValueField {
     UnsignedShort(NumericType<u16>),
     UnlimitedText(DicomString),
     Unsigned64bitVeryLong(NumericType<u64>),
-}
\ No newline at end of file
+}
+impl ValueField {
+    /// Values of numeric VRs converted to `T`, `None` for other VRs or values out of range of `T`.
+    fn numbers<T: NumCast>(&self) -> Option<Vec<T>> {
+        fn convert<S: NumCast + Copy, T: NumCast>(values: &[S]) -> Option<Vec<T>> {
+            values.iter().map(|value| T::from(*value)).collect()
+        }
+
+        match self {
+            ValueField::FloatingPointSingle(numbers) => convert(numbers.value()),
+            ValueField::FloatingPointDouble(numbers) => convert(numbers.value()),
+            ValueField::SignedLong(numbers) => convert(numbers.value()),
+            ValueField::SignedShort(numbers) => convert(numbers.value()),
+            ValueField::Signed64bitVeryLong(numbers) => convert(numbers.value()),
+            ValueField::UnsignedLong(numbers) => convert(numbers.value()),
+            ValueField::UnsignedShort(numbers) => convert(numbers.value()),
+            ValueField::Unsigned64bitVeryLong(numbers) => convert(numbers.value()),
+            _ => None,
+        }
+    }
+}
+
+/// First value of DS, IS and numeric VRs.
+impl<T: Num + NumCast + FromStr + Copy> Cast<T> for ValueField {
+    fn cast(&self) -> Result<T, CastError<T>> {
+        match self {
+            ValueField::DecimalString(string) | ValueField::IntegerString(string) => Cast::<T>::cast(string),
+            _ => self.numbers()
+                .and_then(|numbers| numbers.first().copied())
+                .ok_or_else(CastError::default),
+        }
+    }
+}
+
+/// Exactly `N` values of DS, IS and numeric VRs.
+impl<T: Num + NumCast + FromStr + Copy + Default, const N: usize> CastArray<T, N> for ValueField
+    where <T as FromStr>::Err: Debug {
+    fn cast(&self) -> Result<[T; N], CastArrayError<T, N>> {
+        match self {
+            ValueField::DecimalString(string) | ValueField::IntegerString(string) => CastArray::<T, N>::cast(string),
+            _ => self.numbers()
+                .and_then(|numbers| <[T; N]>::try_from(numbers).ok())
+                .ok_or_else(CastArrayError::default),
+        }
+    }
+}
+
+/// Casts of string VRs parsed into typed values, e.g. DA into `Date`.
+macro_rules! impl_string_cast {
+    ($type: ty, $variant: path) => {
+        impl Cast<$type> for ValueField {
+            fn cast(&self) -> Result<$type, CastError<$type>> {
+                match self {
+                    $variant(string) => Cast::<$type>::cast(string),
+                    _ => Err(CastError::default()),
+                }
+            }
+        }
+    }
+}
+
+impl_string_cast!(Date, ValueField::Date);
+impl_string_cast!(Time, ValueField::Time);
+impl_string_cast!(DateTime, ValueField::DateTime);
+impl_string_cast!(ValueRange<Date>, ValueField::Date);
+impl_string_cast!(ValueRange<Time>, ValueField::Time);
+impl_string_cast!(ValueRange<DateTime>, ValueField::DateTime);
+impl_string_cast!(PersonName, ValueField::PersonName);
+impl_string_cast!(Age, ValueField::AgeString);
@@ -1,11 +1,19 @@
 use std::fmt;
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub struct ValueRepresentation {
     pub value: [u8; 2],
 }
 
 impl ValueRepresentation {
+    /// Whether the value length is kept on 2 bytes in Explicit VR transfer syntaxes. The other
+    /// VRs have 2 reserved bytes followed by 4 bytes of value length.
+    pub fn has_short_value_length(&self) -> bool {
+        matches!(&self.value,
+            b"AE" | b"AS" | b"AT" | b"CS" | b"DA" | b"DS" | b"DT" | b"FL" | b"FD" | b"IS" | b"LO" |
+            b"LT" | b"PN" | b"SH" | b"SL" | b"SS" | b"ST" | b"TM" | b"UI" | b"UL" | b"US")
+    }
+
     fn format_value(&self) -> String {
         let chars: [char; 2] = [
             self.value[0] as char,
@@ -0,0 +1,14 @@
+/// What the confidentiality profile does with an attribute (PS3.15 E.1.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// `K` - the value is kept. Sequences are still de-identified item by item.
+    Keep,
+    /// `X` - the attribute is removed.
+    Remove,
+    /// `Z` - the value is replaced with a zero length value.
+    Empty,
+    /// `D` - the value is replaced with a dummy value of the same VR.
+    Dummy,
+    /// `U` - the UID is replaced with another one, the same for all occurrences of the original.
+    ReplaceUid,
+}
@@ -0,0 +1,539 @@
+use crate::dataset::tag::Tag;
+use crate::dicom_constants::tags::*;
+use super::action::Action;
+
+/// Actions of the Basic Application Level Confidentiality Profile (PS3.15 Table E.1-1), sorted by
+/// tag. Where the profile leaves a choice depending on the IOD (e.g. `Z/D`, `X/Z/D`), the action
+/// keeping the dataset conformant whatever the attribute type is used: a dummy value if allowed,
+/// otherwise an empty value.
+pub const BASIC_PROFILE: [(Tag, Action); 394] = [
+    (AFFECTED_SOP_INSTANCE_UID, Action::Remove),
+    (REQUESTED_SOP_INSTANCE_UID, Action::ReplaceUid),
+    (MEDIA_STORAGE_SOP_INSTANCE_UID, Action::ReplaceUid),
+    (REFERENCED_SOP_INSTANCE_UID_IN_FILE, Action::ReplaceUid),
+    (INSTANCE_CREATION_DATE, Action::Dummy),
+    (INSTANCE_CREATION_TIME, Action::Dummy),
+    (INSTANCE_CREATOR_UID, Action::ReplaceUid),
+    (INSTANCE_COERCION_DATE_TIME, Action::Remove),
+    (ACQUISITION_UID, Action::ReplaceUid),
+    (SOP_INSTANCE_UID, Action::ReplaceUid),
+    (PYRAMID_UID, Action::ReplaceUid),
+    (STUDY_DATE, Action::Empty),
+    (SERIES_DATE, Action::Remove),
+    (ACQUISITION_DATE, Action::Empty),
+    (CONTENT_DATE, Action::Dummy),
+    (OVERLAY_DATE, Action::Remove),
+    (CURVE_DATE, Action::Remove),
+    (ACQUISITION_DATE_TIME, Action::Dummy),
+    (STUDY_TIME, Action::Empty),
+    (SERIES_TIME, Action::Remove),
+    (ACQUISITION_TIME, Action::Empty),
+    (CONTENT_TIME, Action::Dummy),
+    (OVERLAY_TIME, Action::Remove),
+    (CURVE_TIME, Action::Remove),
+    (ACCESSION_NUMBER, Action::Empty),
+    (FAILED_SOP_INSTANCE_UID_LIST, Action::ReplaceUid),
+    (INSTITUTION_NAME, Action::Dummy),
+    (INSTITUTION_ADDRESS, Action::Remove),
+    (INSTITUTION_CODE_SEQUENCE, Action::Dummy),
+    (REFERRING_PHYSICIAN_NAME, Action::Empty),
+    (REFERRING_PHYSICIAN_ADDRESS, Action::Remove),
+    (REFERRING_PHYSICIAN_TELEPHONE_NUMBERS, Action::Remove),
+    (REFERRING_PHYSICIAN_IDENTIFICATION_SEQUENCE, Action::Remove),
+    (CONSULTING_PHYSICIAN_NAME, Action::Remove),
+    (CONSULTING_PHYSICIAN_IDENTIFICATION_SEQUENCE, Action::Remove),
+    (CONTEXT_GROUP_EXTENSION_CREATOR_UID, Action::ReplaceUid),
+    (TIMEZONE_OFFSET_FROM_UTC, Action::Remove),
+    (NETWORK_ID, Action::Remove),
+    (STATION_NAME, Action::Dummy),
+    (STUDY_DESCRIPTION, Action::Remove),
+    (SERIES_DESCRIPTION, Action::Remove),
+    (INSTITUTIONAL_DEPARTMENT_NAME, Action::Remove),
+    (INSTITUTIONAL_DEPARTMENT_TYPE_CODE_SEQUENCE, Action::Remove),
+    (PHYSICIANS_OF_RECORD, Action::Remove),
+    (PHYSICIANS_OF_RECORD_IDENTIFICATION_SEQUENCE, Action::Remove),
+    (PERFORMING_PHYSICIAN_NAME, Action::Remove),
+    (PERFORMING_PHYSICIAN_IDENTIFICATION_SEQUENCE, Action::Remove),
+    (NAME_OF_PHYSICIANS_READING_STUDY, Action::Remove),
+    (PHYSICIANS_READING_STUDY_IDENTIFICATION_SEQUENCE, Action::Remove),
+    (OPERATORS_NAME, Action::Dummy),
+    (OPERATOR_IDENTIFICATION_SEQUENCE, Action::Remove),
+    (ADMITTING_DIAGNOSES_DESCRIPTION, Action::Remove),
+    (ADMITTING_DIAGNOSES_CODE_SEQUENCE, Action::Remove),
+    (PYRAMID_DESCRIPTION, Action::Remove),
+    (REFERENCED_STUDY_SEQUENCE, Action::Empty),
+    (REFERENCED_PERFORMED_PROCEDURE_STEP_SEQUENCE, Action::Dummy),
+    (REFERENCED_PATIENT_SEQUENCE, Action::Remove),
+    (REFERENCED_SOP_INSTANCE_UID, Action::ReplaceUid),
+    (TRANSACTION_UID, Action::ReplaceUid),
+    (DERIVATION_DESCRIPTION, Action::Remove),
+    (IRRADIATION_EVENT_UID, Action::ReplaceUid),
+    (IDENTIFYING_COMMENTS, Action::Remove),
+    (CREATOR_VERSION_UID, Action::ReplaceUid),
+    (PATIENT_NAME, Action::Empty),
+    (PATIENT_ID, Action::Empty),
+    (ISSUER_OF_PATIENT_ID, Action::Remove),
+    (ISSUER_OF_PATIENT_ID_QUALIFIERS_SEQUENCE, Action::Remove),
+    (PATIENT_BIRTH_DATE, Action::Empty),
+    (PATIENT_BIRTH_TIME, Action::Remove),
+    (PATIENT_BIRTH_DATE_IN_ALTERNATIVE_CALENDAR, Action::Remove),
+    (PATIENT_DEATH_DATE_IN_ALTERNATIVE_CALENDAR, Action::Remove),
+    (PATIENT_ALTERNATIVE_CALENDAR, Action::Remove),
+    (PATIENT_SEX, Action::Empty),
+    (PATIENT_INSURANCE_PLAN_CODE_SEQUENCE, Action::Remove),
+    (PATIENT_PRIMARY_LANGUAGE_CODE_SEQUENCE, Action::Remove),
+    (PATIENT_PRIMARY_LANGUAGE_MODIFIER_CODE_SEQUENCE, Action::Remove),
+    (OTHER_PATIENT_IDS, Action::Remove),
+    (OTHER_PATIENT_NAMES, Action::Remove),
+    (OTHER_PATIENT_IDS_SEQUENCE, Action::Remove),
+    (PATIENT_BIRTH_NAME, Action::Remove),
+    (PATIENT_AGE, Action::Remove),
+    (PATIENT_SIZE, Action::Remove),
+    (PATIENT_WEIGHT, Action::Remove),
+    (PATIENT_ADDRESS, Action::Remove),
+    (INSURANCE_PLAN_IDENTIFICATION, Action::Remove),
+    (PATIENT_MOTHER_BIRTH_NAME, Action::Remove),
+    (MILITARY_RANK, Action::Remove),
+    (BRANCH_OF_SERVICE, Action::Remove),
+    (MEDICAL_RECORD_LOCATOR, Action::Remove),
+    (REFERENCED_PATIENT_PHOTO_SEQUENCE, Action::Remove),
+    (MEDICAL_ALERTS, Action::Remove),
+    (ALLERGIES, Action::Remove),
+    (COUNTRY_OF_RESIDENCE, Action::Remove),
+    (REGION_OF_RESIDENCE, Action::Remove),
+    (PATIENT_TELEPHONE_NUMBERS, Action::Remove),
+    (PATIENT_TELECOM_INFORMATION, Action::Remove),
+    (ETHNIC_GROUP, Action::Remove),
+    (OCCUPATION, Action::Remove),
+    (SMOKING_STATUS, Action::Remove),
+    (ADDITIONAL_PATIENT_HISTORY, Action::Remove),
+    (PREGNANCY_STATUS, Action::Remove),
+    (LAST_MENSTRUAL_DATE, Action::Remove),
+    (PATIENT_RELIGIOUS_PREFERENCE, Action::Remove),
+    (PATIENT_SEX_NEUTERED, Action::Empty),
+    (RESPONSIBLE_PERSON, Action::Remove),
+    (RESPONSIBLE_PERSON_ROLE, Action::Remove),
+    (RESPONSIBLE_ORGANIZATION, Action::Remove),
+    (PATIENT_COMMENTS, Action::Remove),
+    (CONTRAST_BOLUS_AGENT, Action::Dummy),
+    (INTERVENTION_DRUG_STOP_TIME, Action::Remove),
+    (INTERVENTION_DRUG_START_TIME, Action::Remove),
+    (DEVICE_SERIAL_NUMBER, Action::Dummy),
+    (DEVICE_UID, Action::ReplaceUid),
+    (PLATE_ID, Action::Remove),
+    (GENERATOR_ID, Action::Remove),
+    (CASSETTE_ID, Action::Remove),
+    (GANTRY_ID, Action::Remove),
+    (UNIQUE_DEVICE_IDENTIFIER, Action::Remove),
+    (UDI_SEQUENCE, Action::Remove),
+    (DATE_OF_SECONDARY_CAPTURE, Action::Remove),
+    (TIME_OF_SECONDARY_CAPTURE, Action::Remove),
+    (PROTOCOL_NAME, Action::Dummy),
+    (CONTRAST_BOLUS_START_TIME, Action::Remove),
+    (CONTRAST_BOLUS_STOP_TIME, Action::Remove),
+    (RADIOPHARMACEUTICAL_START_TIME, Action::Remove),
+    (RADIOPHARMACEUTICAL_STOP_TIME, Action::Remove),
+    (RADIOPHARMACEUTICAL_START_DATE_TIME, Action::Remove),
+    (RADIOPHARMACEUTICAL_STOP_DATE_TIME, Action::Remove),
+    (DATE_OF_LAST_CALIBRATION, Action::Remove),
+    (TIME_OF_LAST_CALIBRATION, Action::Remove),
+    (ACQUISITION_DEVICE_PROCESSING_DESCRIPTION, Action::Dummy),
+    (ACQUISITION_COMMENTS, Action::Remove),
+    (DETECTOR_ID, Action::Dummy),
+    (DATE_OF_LAST_DETECTOR_CALIBRATION, Action::Remove),
+    (TIME_OF_LAST_DETECTOR_CALIBRATION, Action::Remove),
+    (FRAME_ACQUISITION_DATE_TIME, Action::Dummy),
+    (FRAME_REFERENCE_DATE_TIME, Action::Dummy),
+    (RESPIRATORY_MOTION_COMPENSATION_TECHNIQUE_DESCRIPTION, Action::Remove),
+    (X_RAY_SOURCE_ID, Action::Remove),
+    (X_RAY_DETECTOR_ID, Action::Remove),
+    (X_RAY_DETECTOR_LABEL, Action::Remove),
+    (MULTIENERGY_ACQUISITION_DESCRIPTION, Action::Remove),
+    (DECOMPOSITION_DESCRIPTION, Action::Remove),
+    (ACQUISITION_PROTOCOL_DESCRIPTION, Action::Remove),
+    (START_ACQUISITION_DATE_TIME, Action::Dummy),
+    (END_ACQUISITION_DATE_TIME, Action::Dummy),
+    (FUNCTIONAL_SYNC_PULSE, Action::Dummy),
+    (DECAY_CORRECTION_DATE_TIME, Action::Dummy),
+    (EXCLUSION_START_DATE_TIME, Action::Remove),
+    (CONTRIBUTION_DATE_TIME, Action::Dummy),
+    (CONTRIBUTION_DESCRIPTION, Action::Remove),
+    (STUDY_INSTANCE_UID, Action::ReplaceUid),
+    (SERIES_INSTANCE_UID, Action::ReplaceUid),
+    (STUDY_ID, Action::Empty),
+    (FRAME_OF_REFERENCE_UID, Action::ReplaceUid),
+    (SYNCHRONIZATION_FRAME_OF_REFERENCE_UID, Action::ReplaceUid),
+    (MODIFYING_DEVICE_ID, Action::Remove),
+    (MODIFYING_DEVICE_MANUFACTURER, Action::Remove),
+    (MODIFIED_IMAGE_DESCRIPTION, Action::Remove),
+    (IMAGE_COMMENTS, Action::Remove),
+    (FRAME_COMMENTS, Action::Remove),
+    (CONCATENATION_UID, Action::ReplaceUid),
+    (DIMENSION_ORGANIZATION_UID, Action::ReplaceUid),
+    (PALETTE_COLOR_LOOKUP_TABLE_UID, Action::ReplaceUid),
+    (LARGE_PALETTE_COLOR_LOOKUP_TABLE_UID, Action::ReplaceUid),
+    (IMAGE_PRESENTATION_COMMENTS, Action::Remove),
+    (STUDY_ID_ISSUER, Action::Remove),
+    (STUDY_VERIFIED_DATE, Action::Remove),
+    (STUDY_VERIFIED_TIME, Action::Remove),
+    (STUDY_READ_DATE, Action::Remove),
+    (STUDY_READ_TIME, Action::Remove),
+    (SCHEDULED_STUDY_START_DATE, Action::Remove),
+    (SCHEDULED_STUDY_START_TIME, Action::Remove),
+    (SCHEDULED_STUDY_STOP_DATE, Action::Remove),
+    (SCHEDULED_STUDY_STOP_TIME, Action::Remove),
+    (SCHEDULED_STUDY_LOCATION, Action::Remove),
+    (SCHEDULED_STUDY_LOCATION_AE_TITLE, Action::Remove),
+    (REASON_FOR_STUDY, Action::Remove),
+    (REQUESTING_PHYSICIAN, Action::Remove),
+    (REQUESTING_SERVICE, Action::Remove),
+    (STUDY_ARRIVAL_DATE, Action::Remove),
+    (STUDY_ARRIVAL_TIME, Action::Remove),
+    (STUDY_COMPLETION_DATE, Action::Remove),
+    (STUDY_COMPLETION_TIME, Action::Remove),
+    (REQUESTED_PROCEDURE_DESCRIPTION, Action::Empty),
+    (REASON_FOR_VISIT, Action::Remove),
+    (REASON_FOR_VISIT_CODE_SEQUENCE, Action::Remove),
+    (REQUESTED_CONTRAST_AGENT, Action::Remove),
+    (STUDY_COMMENTS, Action::Remove),
+    (REFERENCED_PATIENT_ALIAS_SEQUENCE, Action::Remove),
+    (ADMISSION_ID, Action::Remove),
+    (ISSUER_OF_ADMISSION_ID, Action::Remove),
+    (ISSUER_OF_ADMISSION_ID_SEQUENCE, Action::Remove),
+    (SCHEDULED_ADMISSION_DATE, Action::Remove),
+    (SCHEDULED_ADMISSION_TIME, Action::Remove),
+    (SCHEDULED_DISCHARGE_DATE, Action::Remove),
+    (SCHEDULED_DISCHARGE_TIME, Action::Remove),
+    (SCHEDULED_PATIENT_INSTITUTION_RESIDENCE, Action::Remove),
+    (ADMITTING_DATE, Action::Remove),
+    (ADMITTING_TIME, Action::Remove),
+    (DISCHARGE_DATE, Action::Remove),
+    (DISCHARGE_TIME, Action::Remove),
+    (DISCHARGE_DIAGNOSIS_DESCRIPTION, Action::Remove),
+    (SPECIAL_NEEDS, Action::Remove),
+    (SERVICE_EPISODE_ID, Action::Remove),
+    (ISSUER_OF_SERVICE_EPISODE_ID, Action::Remove),
+    (SERVICE_EPISODE_DESCRIPTION, Action::Remove),
+    (ISSUER_OF_SERVICE_EPISODE_ID_SEQUENCE, Action::Remove),
+    (CURRENT_PATIENT_LOCATION, Action::Remove),
+    (PATIENT_INSTITUTION_RESIDENCE, Action::Remove),
+    (PATIENT_STATE, Action::Remove),
+    (VISIT_COMMENTS, Action::Remove),
+    (SCHEDULED_STATION_AE_TITLE, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_START_DATE, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_START_TIME, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_END_DATE, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_END_TIME, Action::Remove),
+    (SCHEDULED_PERFORMING_PHYSICIAN_NAME, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_DESCRIPTION, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_ID, Action::Remove),
+    (SCHEDULED_PERFORMING_PHYSICIAN_IDENTIFICATION_SEQUENCE, Action::Remove),
+    (SCHEDULED_STATION_NAME, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_LOCATION, Action::Remove),
+    (PRE_MEDICATION, Action::Remove),
+    (PERFORMED_STATION_AE_TITLE, Action::Remove),
+    (PERFORMED_STATION_NAME, Action::Remove),
+    (PERFORMED_LOCATION, Action::Remove),
+    (PERFORMED_PROCEDURE_STEP_START_DATE, Action::Remove),
+    (PERFORMED_PROCEDURE_STEP_START_TIME, Action::Remove),
+    (PERFORMED_PROCEDURE_STEP_END_DATE, Action::Remove),
+    (PERFORMED_PROCEDURE_STEP_END_TIME, Action::Remove),
+    (PERFORMED_PROCEDURE_STEP_ID, Action::Remove),
+    (PERFORMED_PROCEDURE_STEP_DESCRIPTION, Action::Remove),
+    (REQUEST_ATTRIBUTES_SEQUENCE, Action::Remove),
+    (COMMENTS_ON_THE_PERFORMED_PROCEDURE_STEP, Action::Remove),
+    (COMMENTS_ON_RADIATION_DOSE, Action::Remove),
+    (SPECIMEN_ACCESSION_NUMBER, Action::Remove),
+    (CONTAINER_DESCRIPTION, Action::Remove),
+    (SPECIMEN_IDENTIFIER, Action::Remove),
+    (SPECIMEN_UID, Action::ReplaceUid),
+    (ACQUISITION_CONTEXT_SEQUENCE, Action::Remove),
+    (SPECIMEN_SHORT_DESCRIPTION, Action::Remove),
+    (SPECIMEN_DETAILED_DESCRIPTION, Action::Remove),
+    (SLIDE_IDENTIFIER, Action::Remove),
+    (REQUESTED_PROCEDURE_ID, Action::Remove),
+    (PATIENT_TRANSPORT_ARRANGEMENTS, Action::Remove),
+    (REQUESTED_PROCEDURE_LOCATION, Action::Remove),
+    (NAMES_OF_INTENDED_RECIPIENTS_OF_RESULTS, Action::Remove),
+    (INTENDED_RECIPIENTS_OF_RESULTS_IDENTIFICATION_SEQUENCE, Action::Remove),
+    (PERSON_IDENTIFICATION_CODE_SEQUENCE, Action::Dummy),
+    (PERSON_ADDRESS, Action::Remove),
+    (PERSON_TELEPHONE_NUMBERS, Action::Remove),
+    (PERSON_TELECOM_INFORMATION, Action::Remove),
+    (REQUESTED_PROCEDURE_COMMENTS, Action::Remove),
+    (REASON_FOR_THE_IMAGING_SERVICE_REQUEST, Action::Remove),
+    (ISSUE_DATE_OF_IMAGING_SERVICE_REQUEST, Action::Remove),
+    (ISSUE_TIME_OF_IMAGING_SERVICE_REQUEST, Action::Remove),
+    (ORDER_ENTERED_BY, Action::Remove),
+    (ORDER_ENTERER_LOCATION, Action::Remove),
+    (ORDER_CALLBACK_PHONE_NUMBER, Action::Remove),
+    (ORDER_CALLBACK_TELECOM_INFORMATION, Action::Remove),
+    (PLACER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST, Action::Empty),
+    (FILLER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST, Action::Empty),
+    (IMAGING_SERVICE_REQUEST_COMMENTS, Action::Remove),
+    (CONFIDENTIALITY_CONSTRAINT_ON_PATIENT_DATA_DESCRIPTION, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_START_DATE_TIME, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_EXPIRATION_DATE_TIME, Action::Remove),
+    (SCHEDULED_PROCEDURE_STEP_MODIFICATION_DATE_TIME, Action::Remove),
+    (EXPECTED_COMPLETION_DATE_TIME, Action::Remove),
+    (REFERENCED_GENERAL_PURPOSE_SCHEDULED_PROCEDURE_STEP_TRANSACTION_UID, Action::ReplaceUid),
+    (SCHEDULED_STATION_NAME_CODE_SEQUENCE, Action::Remove),
+    (SCHEDULED_STATION_GEOGRAPHIC_LOCATION_CODE_SEQUENCE, Action::Remove),
+    (PERFORMED_STATION_NAME_CODE_SEQUENCE, Action::Remove),
+    (PERFORMED_STATION_GEOGRAPHIC_LOCATION_CODE_SEQUENCE, Action::Remove),
+    (SCHEDULED_HUMAN_PERFORMERS_SEQUENCE, Action::Remove),
+    (ACTUAL_HUMAN_PERFORMERS_SEQUENCE, Action::Remove),
+    (HUMAN_PERFORMER_ORGANIZATION, Action::Remove),
+    (HUMAN_PERFORMER_NAME, Action::Remove),
+    (PERFORMED_PROCEDURE_STEP_START_DATE_TIME, Action::Remove),
+    (PERFORMED_PROCEDURE_STEP_END_DATE_TIME, Action::Remove),
+    (PROCEDURE_STEP_CANCELLATION_DATE_TIME, Action::Remove),
+    (VERIFYING_ORGANIZATION, Action::Remove),
+    (VERIFICATION_DATE_TIME, Action::Dummy),
+    (OBSERVATION_DATE_TIME, Action::Dummy),
+    (VERIFYING_OBSERVER_SEQUENCE, Action::Dummy),
+    (VERIFYING_OBSERVER_NAME, Action::Dummy),
+    (AUTHOR_OBSERVER_SEQUENCE, Action::Remove),
+    (PARTICIPANT_SEQUENCE, Action::Remove),
+    (CUSTODIAL_ORGANIZATION_SEQUENCE, Action::Remove),
+    (PARTICIPATION_DATE_TIME, Action::Remove),
+    (VERIFYING_OBSERVER_IDENTIFICATION_CODE_SEQUENCE, Action::Empty),
+    (DATE_OF_DOCUMENT_OR_VERBAL_TRANSACTION_TRIAL, Action::Remove),
+    (TIME_OF_DOCUMENT_CREATION_OR_VERBAL_TRANSACTION_TRIAL, Action::Remove),
+    (DATE_TIME, Action::Remove),
+    (DATE, Action::Dummy),
+    (TIME, Action::Dummy),
+    (PERSON_NAME, Action::Dummy),
+    (UID, Action::ReplaceUid),
+    (REFERENCED_DATE_TIME, Action::Dummy),
+    (OBSERVATION_UID, Action::ReplaceUid),
+    (REFERENCED_OBSERVATION_UID_TRIAL, Action::ReplaceUid),
+    (OBSERVATION_DATE_TRIAL, Action::Remove),
+    (OBSERVATION_TIME_TRIAL, Action::Remove),
+    (CURRENT_OBSERVER_TRIAL, Action::Remove),
+    (VERBAL_SOURCE_TRIAL, Action::Remove),
+    (ADDRESS_TRIAL, Action::Remove),
+    (TELEPHONE_NUMBER_TRIAL, Action::Remove),
+    (VERBAL_SOURCE_IDENTIFIER_CODE_SEQUENCE_TRIAL, Action::Remove),
+    (CONTENT_SEQUENCE, Action::Remove),
+    (TEMPLATE_EXTENSION_ORGANIZATION_UID, Action::ReplaceUid),
+    (TEMPLATE_EXTENSION_CREATOR_UID, Action::ReplaceUid),
+    (CONTAINER_COMPONENT_ID, Action::Remove),
+    (DEVICE_DESCRIPTION, Action::Remove),
+    (LONG_DEVICE_DESCRIPTION, Action::Remove),
+    (TRACKING_UID, Action::ReplaceUid),
+    (SOURCE_FRAME_OF_REFERENCE_UID, Action::ReplaceUid),
+    (GRAPHIC_ANNOTATION_SEQUENCE, Action::Dummy),
+    (CONTENT_CREATOR_NAME, Action::Empty),
+    (CONTENT_CREATOR_IDENTIFICATION_CODE_SEQUENCE, Action::Remove),
+    (FIDUCIAL_UID, Action::ReplaceUid),
+    (PRESENTATION_DISPLAY_COLLECTION_UID, Action::ReplaceUid),
+    (PRESENTATION_SEQUENCE_COLLECTION_UID, Action::ReplaceUid),
+    (STORAGE_MEDIA_FILE_SET_UID, Action::ReplaceUid),
+    (ICON_IMAGE_SEQUENCE, Action::Remove),
+    (TOPIC_TITLE, Action::Remove),
+    (TOPIC_SUBJECT, Action::Remove),
+    (TOPIC_AUTHOR, Action::Remove),
+    (TOPIC_KEYWORDS, Action::Remove),
+    (SOP_AUTHORIZATION_DATE_TIME, Action::Remove),
+    (SOP_AUTHORIZATION_COMMENT, Action::Remove),
+    (AUTHORIZATION_EQUIPMENT_CERTIFICATION_NUMBER, Action::Remove),
+    (DIGITAL_SIGNATURE_UID, Action::Remove),
+    (DIGITAL_SIGNATURE_DATE_TIME, Action::Remove),
+    (CERTIFICATE_OF_SIGNER, Action::Remove),
+    (SIGNATURE, Action::Remove),
+    (CERTIFIED_TIMESTAMP, Action::Remove),
+    (REFERENCED_DIGITAL_SIGNATURE_SEQUENCE, Action::Remove),
+    (REFERENCED_SOP_INSTANCE_MAC_SEQUENCE, Action::Remove),
+    (MAC, Action::Remove),
+    (MODIFIED_ATTRIBUTES_SEQUENCE, Action::Remove),
+    (ORIGINAL_ATTRIBUTES_SEQUENCE, Action::Remove),
+    (ATTRIBUTE_MODIFICATION_DATE_TIME, Action::Remove),
+    (MODIFYING_SYSTEM, Action::Remove),
+    (SOURCE_OF_PREVIOUS_VALUES, Action::Remove),
+    (REASON_FOR_THE_ATTRIBUTE_MODIFICATION, Action::Remove),
+    (TEXT_STRING, Action::Remove),
+    (LABEL_TEXT, Action::Remove),
+    (BARCODE_VALUE, Action::Remove),
+    (STRUCTURE_SET_LABEL, Action::Dummy),
+    (STRUCTURE_SET_NAME, Action::Remove),
+    (STRUCTURE_SET_DESCRIPTION, Action::Remove),
+    (STRUCTURE_SET_DATE, Action::Dummy),
+    (STRUCTURE_SET_TIME, Action::Dummy),
+    (REFERENCED_FRAME_OF_REFERENCE_UID, Action::ReplaceUid),
+    (ROI_DESCRIPTION, Action::Remove),
+    (ROI_INTERPRETER, Action::Remove),
+    (RELATED_FRAME_OF_REFERENCE_UID, Action::ReplaceUid),
+    (RT_PLAN_LABEL, Action::Dummy),
+    (RT_PLAN_NAME, Action::Remove),
+    (RT_PLAN_DESCRIPTION, Action::Remove),
+    (RT_PLAN_DATE, Action::Dummy),
+    (RT_PLAN_TIME, Action::Dummy),
+    (DOSE_REFERENCE_UID, Action::ReplaceUid),
+    (DOSE_REFERENCE_DESCRIPTION, Action::Remove),
+    (FRACTION_GROUP_DESCRIPTION, Action::Remove),
+    (BEAM_DESCRIPTION, Action::Remove),
+    (FIXATION_DEVICE_DESCRIPTION, Action::Remove),
+    (SHIELDING_DEVICE_DESCRIPTION, Action::Remove),
+    (REASON_FOR_OMISSION_DESCRIPTION, Action::Remove),
+    (REVIEWER_NAME, Action::Empty),
+    (ARBITRARY, Action::Remove),
+    (TEXT_COMMENTS, Action::Remove),
+    (RESULTS_ID_ISSUER, Action::Remove),
+    (INTERPRETATION_RECORDED_DATE, Action::Remove),
+    (INTERPRETATION_RECORDED_TIME, Action::Remove),
+    (INTERPRETATION_RECORDER, Action::Remove),
+    (INTERPRETATION_TRANSCRIPTION_DATE, Action::Remove),
+    (INTERPRETATION_TRANSCRIPTION_TIME, Action::Remove),
+    (INTERPRETATION_TRANSCRIBER, Action::Remove),
+    (INTERPRETATION_TEXT, Action::Remove),
+    (INTERPRETATION_AUTHOR, Action::Remove),
+    (INTERPRETATION_APPROVER_SEQUENCE, Action::Remove),
+    (INTERPRETATION_APPROVAL_DATE, Action::Remove),
+    (INTERPRETATION_APPROVAL_TIME, Action::Remove),
+    (PHYSICIAN_APPROVING_INTERPRETATION, Action::Remove),
+    (INTERPRETATION_DIAGNOSIS_DESCRIPTION, Action::Remove),
+    (RESULTS_DISTRIBUTION_LIST_SEQUENCE, Action::Remove),
+    (DISTRIBUTION_NAME, Action::Remove),
+    (DISTRIBUTION_ADDRESS, Action::Remove),
+    (INTERPRETATION_ID_ISSUER, Action::Remove),
+    (IMPRESSIONS, Action::Remove),
+    (RESULTS_COMMENTS, Action::Remove),
+    (DIGITAL_SIGNATURES_SEQUENCE, Action::Remove),
+    (DATA_SET_TRAILING_PADDING, Action::Remove),
+];
+
+/// Dates and times of the profile which the Retain Longitudinal Temporal Information options
+/// keep (PS3.15 Table E.1-1). Other temporal attributes of the profile, e.g. Patient's Birth Date,
+/// are de-identified whatever the option.
+pub const RETAINED_TEMPORAL_ATTRIBUTES: [Tag; 99] = [
+    INSTANCE_CREATION_DATE,
+    INSTANCE_CREATION_TIME,
+    INSTANCE_COERCION_DATE_TIME,
+    STUDY_DATE,
+    SERIES_DATE,
+    ACQUISITION_DATE,
+    CONTENT_DATE,
+    OVERLAY_DATE,
+    CURVE_DATE,
+    ACQUISITION_DATE_TIME,
+    STUDY_TIME,
+    SERIES_TIME,
+    ACQUISITION_TIME,
+    CONTENT_TIME,
+    OVERLAY_TIME,
+    CURVE_TIME,
+    LAST_MENSTRUAL_DATE,
+    INTERVENTION_DRUG_STOP_TIME,
+    INTERVENTION_DRUG_START_TIME,
+    DATE_OF_SECONDARY_CAPTURE,
+    TIME_OF_SECONDARY_CAPTURE,
+    CONTRAST_BOLUS_START_TIME,
+    CONTRAST_BOLUS_STOP_TIME,
+    RADIOPHARMACEUTICAL_START_TIME,
+    RADIOPHARMACEUTICAL_STOP_TIME,
+    RADIOPHARMACEUTICAL_START_DATE_TIME,
+    RADIOPHARMACEUTICAL_STOP_DATE_TIME,
+    DATE_OF_LAST_CALIBRATION,
+    TIME_OF_LAST_CALIBRATION,
+    DATE_OF_LAST_DETECTOR_CALIBRATION,
+    TIME_OF_LAST_DETECTOR_CALIBRATION,
+    FRAME_ACQUISITION_DATE_TIME,
+    FRAME_REFERENCE_DATE_TIME,
+    START_ACQUISITION_DATE_TIME,
+    END_ACQUISITION_DATE_TIME,
+    FUNCTIONAL_SYNC_PULSE,
+    DECAY_CORRECTION_DATE_TIME,
+    EXCLUSION_START_DATE_TIME,
+    CONTRIBUTION_DATE_TIME,
+    STUDY_VERIFIED_DATE,
+    STUDY_VERIFIED_TIME,
+    STUDY_READ_DATE,
+    STUDY_READ_TIME,
+    SCHEDULED_STUDY_START_DATE,
+    SCHEDULED_STUDY_START_TIME,
+    SCHEDULED_STUDY_STOP_DATE,
+    SCHEDULED_STUDY_STOP_TIME,
+    STUDY_ARRIVAL_DATE,
+    STUDY_ARRIVAL_TIME,
+    STUDY_COMPLETION_DATE,
+    STUDY_COMPLETION_TIME,
+    SCHEDULED_ADMISSION_DATE,
+    SCHEDULED_ADMISSION_TIME,
+    SCHEDULED_DISCHARGE_DATE,
+    SCHEDULED_DISCHARGE_TIME,
+    ADMITTING_DATE,
+    ADMITTING_TIME,
+    DISCHARGE_DATE,
+    DISCHARGE_TIME,
+    SCHEDULED_PROCEDURE_STEP_START_DATE,
+    SCHEDULED_PROCEDURE_STEP_START_TIME,
+    SCHEDULED_PROCEDURE_STEP_END_DATE,
+    SCHEDULED_PROCEDURE_STEP_END_TIME,
+    PERFORMED_PROCEDURE_STEP_START_DATE,
+    PERFORMED_PROCEDURE_STEP_START_TIME,
+    PERFORMED_PROCEDURE_STEP_END_DATE,
+    PERFORMED_PROCEDURE_STEP_END_TIME,
+    ISSUE_DATE_OF_IMAGING_SERVICE_REQUEST,
+    ISSUE_TIME_OF_IMAGING_SERVICE_REQUEST,
+    SCHEDULED_PROCEDURE_STEP_START_DATE_TIME,
+    SCHEDULED_PROCEDURE_STEP_EXPIRATION_DATE_TIME,
+    SCHEDULED_PROCEDURE_STEP_MODIFICATION_DATE_TIME,
+    EXPECTED_COMPLETION_DATE_TIME,
+    PERFORMED_PROCEDURE_STEP_START_DATE_TIME,
+    PERFORMED_PROCEDURE_STEP_END_DATE_TIME,
+    PROCEDURE_STEP_CANCELLATION_DATE_TIME,
+    VERIFICATION_DATE_TIME,
+    OBSERVATION_DATE_TIME,
+    PARTICIPATION_DATE_TIME,
+    DATE_OF_DOCUMENT_OR_VERBAL_TRANSACTION_TRIAL,
+    TIME_OF_DOCUMENT_CREATION_OR_VERBAL_TRANSACTION_TRIAL,
+    DATE_TIME,
+    DATE,
+    TIME,
+    REFERENCED_DATE_TIME,
+    OBSERVATION_DATE_TRIAL,
+    OBSERVATION_TIME_TRIAL,
+    SOP_AUTHORIZATION_DATE_TIME,
+    STRUCTURE_SET_DATE,
+    STRUCTURE_SET_TIME,
+    RT_PLAN_DATE,
+    RT_PLAN_TIME,
+    INTERPRETATION_RECORDED_DATE,
+    INTERPRETATION_RECORDED_TIME,
+    INTERPRETATION_TRANSCRIPTION_DATE,
+    INTERPRETATION_TRANSCRIPTION_TIME,
+    INTERPRETATION_APPROVAL_DATE,
+    INTERPRETATION_APPROVAL_TIME,
+    TIMEZONE_OFFSET_FROM_UTC,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action(tag: Tag) -> Option<Action> {
+        BASIC_PROFILE.iter().find(|(profile_tag, _)| *profile_tag == tag).map(|(_, action)| *action)
+    }
+
+    #[test]
+    fn test_profile_is_sorted() {
+        assert!(BASIC_PROFILE.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(RETAINED_TEMPORAL_ATTRIBUTES.iter().all(|tag| action(*tag).is_some()));
+    }
+
+    #[test]
+    fn test_profile_actions() {
+        let removed = [
+            OTHER_PATIENT_IDS, PATIENT_TELECOM_INFORMATION, LAST_MENSTRUAL_DATE, REFERENCED_PATIENT_PHOTO_SEQUENCE,
+            ISSUER_OF_PATIENT_ID_QUALIFIERS_SEQUENCE, PATIENT_ALTERNATIVE_CALENDAR, RESPONSIBLE_PERSON_ROLE,
+            PERSON_TELECOM_INFORMATION, SCHEDULED_PROCEDURE_STEP_ID, PERFORMED_PROCEDURE_STEP_END_DATE,
+            ROI_INTERPRETER, DIGITAL_SIGNATURE_UID, ACQUISITION_COMMENTS,
+        ];
+        assert!(removed.iter().all(|tag| action(*tag) == Some(Action::Remove)));
+
+        assert_eq!(action(PATIENT_NAME), Some(Action::Empty));
+        assert_eq!(action(PALETTE_COLOR_LOOKUP_TABLE_UID), Some(Action::ReplaceUid));
+        assert_eq!(action(PIXEL_DATA), None);
+    }
+}
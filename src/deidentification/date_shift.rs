@@ -0,0 +1,81 @@
+/// Shifts the dates of a DA value by `days`. Each value of a multi-valued DA is shifted; `None` is
+/// returned if any of them isn't a `YYYYMMDD` date.
+pub fn shift_dates(dates: &str, days: i64) -> Option<String> {
+    shift_values(dates, days, shift_date)
+}
+
+/// Shifts the date part of a DT value by `days`, keeping the time and the UTC offset.
+pub fn shift_date_times(date_times: &str, days: i64) -> Option<String> {
+    shift_values(date_times, days, |date_time, days| {
+        let date = date_time.get(..8)?;
+        Some(shift_date(date, days)? + &date_time[8..])
+    })
+}
+
+fn shift_values(values: &str, days: i64, shift: impl Fn(&str, i64) -> Option<String>) -> Option<String> {
+    values.trim_end_matches([' ', '\0'])
+        .split('\\')
+        .map(|value| shift(value, days))
+        .collect::<Option<Vec<String>>>()
+        .map(|values| values.join("\\"))
+}
+
+fn shift_date(date: &str, days: i64) -> Option<String> {
+    if date.len() != 8 || !date.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let year: i64 = date[..4].parse().ok()?;
+    let month: i64 = date[4..6].parse().ok()?;
+    let day: i64 = date[6..].parse().ok()?;
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let (year, month, day) = civil_from_days(days_from_civil(year, month, day) + days);
+
+    if !(0..=9999).contains(&year) {
+        return None;
+    }
+
+    Some(format!("{:04}{:02}{:02}", year, month, day))
+}
+
+/// Days since 1970-01-01 of a date of the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_dates() {
+        assert_eq!(shift_dates("20240228", 2).as_deref(), Some("20240301"));
+        assert_eq!(shift_dates("20230101\\19991231", -1).as_deref(), Some("20221231\\19991230"));
+        assert_eq!(shift_date_times("20201231235959.000+0100", 1).as_deref(), Some("20210101235959.000+0100"));
+        assert_eq!(shift_dates("2020", 1), None);
+    }
+}
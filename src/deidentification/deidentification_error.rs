@@ -0,0 +1,38 @@
+use std::fmt;
+use crate::dicom_file_parser::parse_error::ParseError;
+
+#[derive(Debug)]
+pub enum DeidentificationError {
+    Parse(ParseError),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for DeidentificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeidentificationError::Parse(error) => write!(f, "Cannot parse file: {}", error),
+            DeidentificationError::Io(error) => write!(f, "I/O error: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for DeidentificationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DeidentificationError::Parse(error) => Some(error),
+            DeidentificationError::Io(error) => Some(error),
+        }
+    }
+}
+
+impl From<ParseError> for DeidentificationError {
+    fn from(error: ParseError) -> Self {
+        DeidentificationError::Parse(error)
+    }
+}
+
+impl From<std::io::Error> for DeidentificationError {
+    fn from(error: std::io::Error) -> Self {
+        DeidentificationError::Io(error)
+    }
+}
@@ -0,0 +1,452 @@
+use crate::dataset::data_element::DataElement;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_field::ValueField;
+use crate::dicom_constants::tags::{CODE_MEANING, CODE_VALUE, CODING_SCHEME_DESIGNATOR, DEIDENTIFICATION_METHOD, DEIDENTIFICATION_METHOD_CODE_SEQUENCE, LONGITUDINAL_TEMPORAL_INFORMATION_MODIFIED, PATIENT_IDENTITY_REMOVED};
+use crate::dicom_constants::numeric::UNDEFINED_LENGTH;
+use crate::dicom_constants::value_representation::{CODE_STRING, LONG_STRING, SEQUENCE_OF_ITEMS, SHORT_STRING};
+use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+use crate::dicom_file_writer::dicom_file_writer::DicomFileWriter;
+use crate::utils::submap::Submap;
+use crate::value_representations::attribute_tag::AttributeTag;
+use crate::value_representations::dicom_string::DicomString;
+use crate::value_representations::numeric_string::NumericString;
+use crate::value_representations::numeric_type::NumericType;
+use crate::value_representations::other_type::{Other, OtherType};
+use crate::value_representations::sequence_of_items::{SequenceItem, SequenceOfItems};
+use crate::value_representations::unknown::Unknown;
+use super::action::Action;
+use super::basic_profile::{BASIC_PROFILE, RETAINED_TEMPORAL_ATTRIBUTES};
+use super::date_shift::{shift_date_times, shift_dates};
+use super::deidentification_error::DeidentificationError;
+use super::uid_map::UidMap;
+
+const FILE_META_GROUP: u16 = 0x0002;
+const CURVE_GROUPS: std::ops::RangeInclusive<u16> = 0x5000..=0x50FF;
+const OVERLAY_GROUPS: std::ops::RangeInclusive<u16> = 0x6000..=0x60FF;
+const OVERLAY_DATA: u16 = 0x3000;
+const OVERLAY_COMMENTS: u16 = 0x4000;
+
+const DUMMY_STRING: &str = "ANONYMIZED";
+const DUMMY_DATE: &str = "19000101";
+const DUMMY_TIME: &str = "000000";
+const DUMMY_DATE_TIME: &str = "19000101000000";
+const DUMMY_AGE: &str = "000Y";
+const DUMMY_NUMBER: &str = "0";
+
+/// Codes of the de-identification methods (PS3.16 CID 7050), with their meaning.
+const BASIC_PROFILE_CODE: (&str, &str) = ("113100", "Basic Application Confidentiality Profile");
+const RETAIN_FULL_DATES_CODE: (&str, &str) = ("113106", "Retain Longitudinal Temporal Information Full Dates Option");
+const RETAIN_MODIFIED_DATES_CODE: (&str, &str) = ("113107", "Retain Longitudinal Temporal Information Modified Dates Option");
+
+/// What happens to dates and times which the profile would remove or replace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemporalInformation {
+    /// The profile's actions apply.
+    Removed,
+    /// Retain Longitudinal Temporal Information with Full Dates Option - dates and times are kept.
+    RetainedFullDates,
+    /// Retain Longitudinal Temporal Information with Modified Dates Option - dates are shifted by
+    /// the number of days, times are kept. Use the same shift for all the studies of a patient to
+    /// keep the intervals between them.
+    RetainedModifiedDates(i64),
+}
+
+/// De-identifies datasets following the Basic Application Level Confidentiality Profile of PS3.15
+/// Annex E. Private attributes, curves and overlay comments and data are removed. UIDs are replaced
+/// consistently for all the datasets de-identified with the same `Deidentifier`, so a study should
+/// be processed by a single instance.
+pub struct Deidentifier {
+    temporal_information: TemporalInformation,
+    uid_map: UidMap,
+}
+
+impl Default for Deidentifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deidentifier {
+    pub fn new() -> Self {
+        Self {
+            temporal_information: TemporalInformation::Removed,
+            uid_map: UidMap::new(),
+        }
+    }
+
+    pub fn temporal_information(mut self, temporal_information: TemporalInformation) -> Self {
+        self.temporal_information = temporal_information;
+        self
+    }
+
+    /// Continues with replacements of a previous de-identification, e.g. read with
+    /// `UidMap::read_csv`.
+    pub fn uid_map(mut self, uid_map: UidMap) -> Self {
+        self.uid_map = uid_map;
+        self
+    }
+
+    /// Replacements of UIDs made so far, to be exported for re-identification.
+    pub fn replaced_uids(&self) -> &UidMap {
+        &self.uid_map
+    }
+
+    /// De-identifies the file at `input_path` and writes the result to `output_path`, in the same
+    /// transfer syntax - or in Explicit VR Little Endian if it was big endian.
+    pub fn deidentify_file(&mut self, input_path: &str, output_path: &str) -> Result<(), DeidentificationError> {
+        let parsed = DicomFileParser::new()
+            .file_path(input_path)
+            .read_all_tags()
+            .parse()?;
+
+        let transfer_syntax = parsed.transfer_syntax.writable();
+
+        let data_elements = self.deidentify(parsed.data_elements);
+
+        // Values may still be mapped from the input file, so the output is created only once they
+        // are all serialized.
+        let bytes = DicomFileWriter::new()
+            .transfer_syntax(transfer_syntax)
+            .to_bytes(&data_elements)?;
+        std::fs::write(output_path, bytes)?;
+
+        Ok(())
+    }
+
+    /// Applies the profile to the dataset and records the de-identification in Patient Identity
+    /// Removed, De-identification Method and De-identification Method Code Sequence. The file meta
+    /// group is dropped - `DicomFileWriter` recreates it from the de-identified SOP Instance UID.
+    pub fn deidentify(&mut self, data_elements: Vec<DataElement>) -> Vec<DataElement> {
+        let data_elements = data_elements.into_iter()
+            .filter(|data_element| data_element.tag.group != FILE_META_GROUP)
+            .collect();
+
+        let mut data_elements = self.deidentify_dataset(data_elements);
+
+        let mut methods = vec![BASIC_PROFILE_CODE];
+        match self.temporal_information {
+            TemporalInformation::Removed => {},
+            TemporalInformation::RetainedFullDates => {
+                methods.push(RETAIN_FULL_DATES_CODE);
+                replace(&mut data_elements, string_element(LONGITUDINAL_TEMPORAL_INFORMATION_MODIFIED, "UNMODIFIED"));
+            },
+            TemporalInformation::RetainedModifiedDates(_) => {
+                methods.push(RETAIN_MODIFIED_DATES_CODE);
+                replace(&mut data_elements, string_element(LONGITUDINAL_TEMPORAL_INFORMATION_MODIFIED, "MODIFIED"));
+            },
+        }
+
+        let method = methods.iter().map(|(_, meaning)| *meaning).collect::<Vec<_>>().join("\\");
+        let codes = methods.iter().map(|(code, meaning)| SequenceItem { data_elements: vec![
+            string_element(CODE_VALUE, code),
+            string_element(CODING_SCHEME_DESIGNATOR, "DCM"),
+            string_element(CODE_MEANING, meaning),
+        ] }).collect();
+
+        replace(&mut data_elements, string_element(PATIENT_IDENTITY_REMOVED, "YES"));
+        replace(&mut data_elements, string_element(DEIDENTIFICATION_METHOD, &method));
+        replace(&mut data_elements, DataElement {
+            tag: DEIDENTIFICATION_METHOD_CODE_SEQUENCE,
+            value_representation: Some(SEQUENCE_OF_ITEMS),
+            value_length: UNDEFINED_LENGTH,
+            value: ValueField::SequenceOfItems(SequenceOfItems::new(codes)),
+            offset: None,
+        });
+
+        data_elements
+    }
+
+    fn deidentify_dataset(&mut self, data_elements: Vec<DataElement>) -> Vec<DataElement> {
+        data_elements.into_iter()
+            .filter_map(|data_element| self.deidentify_data_element(data_element))
+            .collect()
+    }
+
+    fn deidentify_data_element(&mut self, data_element: DataElement) -> Option<DataElement> {
+        let DataElement { tag, value_representation, value_length, value, .. } = data_element;
+
+        let action = if self.retains_temporal_information(tag) { Action::Keep } else { self.action(tag) };
+
+        let value = match action {
+            Action::Remove => return None,
+            Action::Keep => self.keep(value),
+            Action::Empty => empty(value),
+            Action::Dummy => self.dummy(value),
+            Action::ReplaceUid => self.replace_uids(value),
+        };
+
+        // Kept values may be encapsulated pixel data, told apart by its undefined length
+        let value_length = if action == Action::Keep { value_length } else { 0 };
+
+        Some(DataElement { tag, value_representation, value_length, value, offset: None })
+    }
+
+    fn action(&self, tag: Tag) -> Action {
+        let is_curve = CURVE_GROUPS.contains(&tag.group);
+        let is_overlay = OVERLAY_GROUPS.contains(&tag.group) && matches!(tag.element, OVERLAY_DATA | OVERLAY_COMMENTS);
+
+        if tag.group & 1 == 1 || is_curve || is_overlay {
+            return Action::Remove;
+        }
+
+        BASIC_PROFILE.binary_search_by_key(&tag, |(tag, _)| *tag)
+            .map_or(Action::Keep, |index| BASIC_PROFILE[index].1)
+    }
+
+    /// With longitudinal temporal information retained, the dates and times listed by the option are
+    /// kept - the dates being shifted in `keep` when requested.
+    fn retains_temporal_information(&self, tag: Tag) -> bool {
+        self.temporal_information != TemporalInformation::Removed && RETAINED_TEMPORAL_ATTRIBUTES.contains(&tag)
+    }
+
+    fn keep(&mut self, value: ValueField) -> ValueField {
+        let TemporalInformation::RetainedModifiedDates(days) = self.temporal_information else {
+            return self.deidentify_items(value);
+        };
+
+        // Dates which cannot be shifted would reveal the original ones
+        match value {
+            ValueField::Date(dates) => ValueField::Date(DicomString::from(
+                shift_dates(&Into::<String>::into(&dates), days).unwrap_or_default())),
+            ValueField::DateTime(date_times) => ValueField::DateTime(DicomString::from(
+                shift_date_times(&Into::<String>::into(&date_times), days).unwrap_or_default())),
+            value => self.deidentify_items(value),
+        }
+    }
+
+    /// The profile applies to nested datasets as well.
+    fn deidentify_items(&mut self, value: ValueField) -> ValueField {
+        match value {
+            ValueField::SequenceOfItems(sequence) => ValueField::SequenceOfItems(SequenceOfItems::new(
+                sequence.items.into_iter()
+                    .map(|item| SequenceItem { data_elements: self.deidentify_dataset(item.data_elements) })
+                    .collect())),
+            value => value,
+        }
+    }
+
+    /// Dummy sequences keep their items, de-identified.
+    fn dummy(&mut self, value: ValueField) -> ValueField {
+        let string = |dummy: &str| DicomString::from(dummy.to_string());
+        let number = || NumericString::from(DUMMY_NUMBER.to_string());
+
+        match value {
+            ValueField::ApplicationEntity(_) => ValueField::ApplicationEntity(string(DUMMY_STRING)),
+            ValueField::AgeString(_) => ValueField::AgeString(string(DUMMY_AGE)),
+            ValueField::CodeString(_) => ValueField::CodeString(string(DUMMY_STRING)),
+            ValueField::Date(_) => ValueField::Date(string(DUMMY_DATE)),
+            ValueField::DateTime(_) => ValueField::DateTime(string(DUMMY_DATE_TIME)),
+            ValueField::DecimalString(_) => ValueField::DecimalString(number()),
+            ValueField::IntegerString(_) => ValueField::IntegerString(number()),
+            ValueField::LongString(_) => ValueField::LongString(string(DUMMY_STRING)),
+            ValueField::LongText(_) => ValueField::LongText(string(DUMMY_STRING)),
+            ValueField::PersonName(_) => ValueField::PersonName(string(DUMMY_STRING)),
+            ValueField::ShortString(_) => ValueField::ShortString(string(DUMMY_STRING)),
+            ValueField::ShortText(_) => ValueField::ShortText(string(DUMMY_STRING)),
+            ValueField::Time(_) => ValueField::Time(string(DUMMY_TIME)),
+            ValueField::UnlimitedCharacters(_) => ValueField::UnlimitedCharacters(string(DUMMY_STRING)),
+            ValueField::UnlimitedText(_) => ValueField::UnlimitedText(string(DUMMY_STRING)),
+            ValueField::UniqueIdentifier(_) => self.replace_uids(value),
+            ValueField::SequenceOfItems(_) => self.deidentify_items(value),
+            value => empty(value),
+        }
+    }
+
+    fn replace_uids(&mut self, value: ValueField) -> ValueField {
+        let ValueField::UniqueIdentifier(uids) = value else {
+            return self.dummy(value);
+        };
+
+        let uids: String = (&uids).into();
+        let replaced = uids.trim_end_matches(['\0', ' '])
+            .split('\\')
+            .map(|uid| self.uid_map.replace(uid))
+            .collect::<Vec<_>>()
+            .join("\\");
+
+        ValueField::UniqueIdentifier(DicomString::from(replaced))
+    }
+}
+
+/// Zero length value of the same VR. Binary values become an empty range of the map they were read from.
+fn empty(value: ValueField) -> ValueField {
+    let string = || DicomString::from(String::new());
+    let number = || NumericString::from(String::new());
+    let bytes = |location: &Submap| Submap::new(location.file.clone(), location.start, location.start, location.endianness);
+
+    match value {
+        ValueField::ApplicationEntity(_) => ValueField::ApplicationEntity(string()),
+        ValueField::AgeString(_) => ValueField::AgeString(string()),
+        ValueField::AttributeTag(_) => ValueField::AttributeTag(AttributeTag::new(Vec::new())),
+        ValueField::CodeString(_) => ValueField::CodeString(string()),
+        ValueField::Date(_) => ValueField::Date(string()),
+        ValueField::DateTime(_) => ValueField::DateTime(string()),
+        ValueField::DecimalString(_) => ValueField::DecimalString(number()),
+        ValueField::FloatingPointSingle(_) => ValueField::FloatingPointSingle(NumericType::from(Vec::new())),
+        ValueField::FloatingPointDouble(_) => ValueField::FloatingPointDouble(NumericType::from(Vec::new())),
+        ValueField::IntegerString(_) => ValueField::IntegerString(number()),
+        ValueField::LongString(_) => ValueField::LongString(string()),
+        ValueField::LongText(_) => ValueField::LongText(string()),
+        ValueField::OtherByte(value) => ValueField::OtherByte(OtherType::from(bytes(value.data_location()))),
+        ValueField::OtherDouble(value) => ValueField::OtherDouble(OtherType::from(bytes(value.data_location()))),
+        ValueField::OtherFloat(value) => ValueField::OtherFloat(OtherType::from(bytes(value.data_location()))),
+        ValueField::OtherLong(value) => ValueField::OtherLong(OtherType::from(bytes(value.data_location()))),
+        ValueField::Other64bitVeryLong(value) => ValueField::Other64bitVeryLong(OtherType::from(bytes(value.data_location()))),
+        ValueField::OtherWord(value) => ValueField::OtherWord(OtherType::from(bytes(value.data_location()))),
+        ValueField::PersonName(_) => ValueField::PersonName(string()),
+        ValueField::ShortString(_) => ValueField::ShortString(string()),
+        ValueField::SignedLong(_) => ValueField::SignedLong(NumericType::from(Vec::new())),
+        ValueField::SequenceOfItems(_) => ValueField::SequenceOfItems(SequenceOfItems::new(Vec::new())),
+        ValueField::SignedShort(_) => ValueField::SignedShort(NumericType::from(Vec::new())),
+        ValueField::ShortText(_) => ValueField::ShortText(string()),
+        ValueField::Signed64bitVeryLong(_) => ValueField::Signed64bitVeryLong(NumericType::from(Vec::new())),
+        ValueField::Time(_) => ValueField::Time(string()),
+        ValueField::UnlimitedCharacters(_) => ValueField::UnlimitedCharacters(string()),
+        ValueField::UniqueIdentifier(_) => ValueField::UniqueIdentifier(string()),
+        ValueField::UnsignedLong(_) => ValueField::UnsignedLong(NumericType::from(Vec::new())),
+        ValueField::Unknown(value) => ValueField::Unknown(Unknown::from(bytes(value.data_location()))),
+        ValueField::UniversalResourceIdentifier(_) => ValueField::UniversalResourceIdentifier(string()),
+        ValueField::UnsignedShort(_) => ValueField::UnsignedShort(NumericType::from(Vec::new())),
+        ValueField::UnlimitedText(_) => ValueField::UnlimitedText(string()),
+        ValueField::Unsigned64bitVeryLong(_) => ValueField::Unsigned64bitVeryLong(NumericType::from(Vec::new())),
+    }
+}
+
+fn replace(data_elements: &mut Vec<DataElement>, data_element: DataElement) {
+    data_elements.retain(|existing| existing.tag != data_element.tag);
+    data_elements.push(data_element);
+}
+
+fn string_element(tag: Tag, value: &str) -> DataElement {
+    let (value_representation, value_field) = match tag {
+        CODE_VALUE => (SHORT_STRING, ValueField::ShortString(DicomString::from(value.to_string()))),
+        CODE_MEANING | DEIDENTIFICATION_METHOD => (LONG_STRING, ValueField::LongString(DicomString::from(value.to_string()))),
+        _ => (CODE_STRING, ValueField::CodeString(DicomString::from(value.to_string()))),
+    };
+
+    DataElement {
+        tag,
+        value_representation: Some(value_representation),
+        value_length: value.len() as u32,
+        value: value_field,
+        offset: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::tags::{ACCESSION_NUMBER, INSTITUTION_NAME, PATIENT_BIRTH_DATE, PATIENT_NAME, REFERENCED_IMAGE_SEQUENCE, REFERENCED_SOP_INSTANCE_UID, SOP_INSTANCE_UID, STUDY_DATE, STUDY_DESCRIPTION, STUDY_INSTANCE_UID};
+    use super::*;
+
+    fn element(tag: Tag, value: ValueField) -> DataElement {
+        DataElement { tag, value_representation: None, value_length: 0, value, offset: None }
+    }
+
+    fn string(tag: Tag, value: &str) -> DataElement {
+        let value = DicomString::from(value.to_string());
+        let value = match tag {
+            PATIENT_NAME => ValueField::PersonName(value),
+            STUDY_DATE | PATIENT_BIRTH_DATE => ValueField::Date(value),
+            SOP_INSTANCE_UID | STUDY_INSTANCE_UID | REFERENCED_SOP_INSTANCE_UID => ValueField::UniqueIdentifier(value),
+            _ => ValueField::LongString(value),
+        };
+
+        element(tag, value)
+    }
+
+    fn dataset() -> Vec<DataElement> {
+        let reference = SequenceItem { data_elements: vec![string(REFERENCED_SOP_INSTANCE_UID, "1.2.3.1")] };
+
+        vec![
+            string(PATIENT_NAME, "Doe^Jane"),
+            string(STUDY_DATE, "20240115"),
+            string(STUDY_DESCRIPTION, "Head"),
+            string(ACCESSION_NUMBER, "A123"),
+            string(INSTITUTION_NAME, "General Hospital"),
+            string(STUDY_INSTANCE_UID, "1.2.3"),
+            string(SOP_INSTANCE_UID, "1.2.3.2"),
+            element(REFERENCED_IMAGE_SEQUENCE, ValueField::SequenceOfItems(SequenceOfItems::new(vec![reference]))),
+            string(Tag { group: 0x0009, element: 0x1001 }, "Private"),
+        ]
+    }
+
+    fn find(data_elements: &[DataElement], tag: Tag) -> Option<String> {
+        data_elements.iter()
+            .find(|data_element| data_element.tag == tag)
+            .map(|data_element| match &data_element.value {
+                ValueField::PersonName(value) | ValueField::Date(value) | ValueField::LongString(value) |
+                ValueField::ShortString(value) | ValueField::UniqueIdentifier(value) | ValueField::CodeString(value) => value.into(),
+                value => panic!("Unexpected value {:?}", value),
+            })
+    }
+
+    #[test]
+    fn test_basic_profile() {
+        let mut deidentifier = Deidentifier::new();
+        let data_elements = deidentifier.deidentify(dataset());
+
+        assert_eq!(find(&data_elements, PATIENT_NAME).as_deref(), Some(""));
+        assert_eq!(find(&data_elements, STUDY_DATE).as_deref(), Some(""));
+        assert_eq!(find(&data_elements, STUDY_DESCRIPTION), None);
+        assert_eq!(find(&data_elements, ACCESSION_NUMBER).as_deref(), Some(""));
+        assert_eq!(find(&data_elements, INSTITUTION_NAME).as_deref(), Some(DUMMY_STRING));
+        assert_eq!(find(&data_elements, Tag { group: 0x0009, element: 0x1001 }), None);
+        assert_eq!(find(&data_elements, PATIENT_IDENTITY_REMOVED).as_deref(), Some("YES"));
+
+        let uids = deidentifier.replaced_uids();
+        assert_eq!(find(&data_elements, STUDY_INSTANCE_UID).as_deref(), uids.get("1.2.3"));
+
+        let sequence = data_elements.iter().find(|data_element| data_element.tag == REFERENCED_IMAGE_SEQUENCE).unwrap();
+        let ValueField::SequenceOfItems(sequence) = &sequence.value else { panic!("Expected sequence of items") };
+        assert_eq!(find(&sequence.items[0].data_elements, REFERENCED_SOP_INSTANCE_UID).as_deref(), uids.get("1.2.3.1"));
+
+        // The same study de-identified again gets the same UIDs
+        let again = deidentifier.deidentify(dataset());
+        assert_eq!(find(&again, STUDY_INSTANCE_UID), find(&data_elements, STUDY_INSTANCE_UID));
+    }
+
+    #[test]
+    fn test_retain_modified_dates() {
+        let data_elements = Deidentifier::new()
+            .temporal_information(TemporalInformation::RetainedModifiedDates(-20))
+            .deidentify(dataset());
+
+        assert_eq!(find(&data_elements, STUDY_DATE).as_deref(), Some("20231226"));
+        assert_eq!(find(&data_elements, LONGITUDINAL_TEMPORAL_INFORMATION_MODIFIED).as_deref(), Some("MODIFIED"));
+        assert_eq!(find(&data_elements, PATIENT_NAME).as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_retain_options_keep_only_listed_dates() {
+        let private_date = Tag { group: 0x0009, element: 0x1002 };
+        let dataset_with_dates = || {
+            let mut data_elements = dataset();
+            data_elements.push(string(PATIENT_BIRTH_DATE, "19700101"));
+            data_elements.push(element(private_date, ValueField::Date(DicomString::from("20240115".to_string()))));
+            data_elements
+        };
+
+        let options = [
+            (TemporalInformation::Removed, ""),
+            (TemporalInformation::RetainedFullDates, "20240115"),
+            (TemporalInformation::RetainedModifiedDates(-20), "20231226"),
+        ];
+        for (temporal_information, study_date) in options {
+            let data_elements = Deidentifier::new()
+                .temporal_information(temporal_information)
+                .deidentify(dataset_with_dates());
+
+            assert_eq!(find(&data_elements, STUDY_DATE).as_deref(), Some(study_date));
+            assert_eq!(find(&data_elements, PATIENT_BIRTH_DATE).as_deref(), Some(""));
+            assert_eq!(find(&data_elements, private_date), None);
+        }
+    }
+
+    #[test]
+    fn test_written_file_is_readable() {
+        let bytes = DicomFileWriter::new().to_bytes(&Deidentifier::new().deidentify(dataset())).unwrap();
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&bytes).unwrap();
+
+        assert!(parsed.data_elements.iter().any(|data_element| data_element.tag == DEIDENTIFICATION_METHOD_CODE_SEQUENCE));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod deidentifier;
+pub mod deidentification_error;
+pub mod action;
+pub mod basic_profile;
+pub mod uid_map;
+pub mod date_shift;
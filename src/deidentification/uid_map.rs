@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::{BufRead, Write};
+use nanorand::ChaCha20;
+use crate::utils::uid::generate_uid;
+
+const CSV_HEADER: &str = "original,replacement";
+
+/// Replacements of UIDs. The same original UID is always replaced with the same UID, so
+/// references between the instances of a study - and across studies de-identified with the same
+/// map - stay consistent. The map can be exported, letting the data owner re-identify the data.
+pub struct UidMap {
+    replacements: HashMap<String, String>,
+    generator: ChaCha20,
+}
+
+impl Default for UidMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UidMap {
+    pub fn new() -> Self {
+        Self { replacements: HashMap::new(), generator: ChaCha20::new() }
+    }
+
+    /// Reads a map exported with `write_csv`, to continue de-identification with it.
+    pub fn read_csv<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut map = Self::new();
+
+        for line in reader.lines().skip(1) {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+
+            let (original, replacement) = line.split_once(',')
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid UID mapping: {}", line)))?;
+            map.replacements.insert(original.to_string(), replacement.to_string());
+        }
+
+        Ok(map)
+    }
+
+    /// Writes the map as CSV with `original,replacement` rows, sorted by the original UID.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "{}", CSV_HEADER)?;
+
+        let mut replacements: Vec<(&String, &String)> = self.replacements.iter().collect();
+        replacements.sort();
+
+        for (original, replacement) in replacements {
+            writeln!(writer, "{},{}", original, replacement)?;
+        }
+
+        writer.flush()
+    }
+
+    /// Replacement of the UID, generated on first use.
+    pub fn replace(&mut self, uid: &str) -> String {
+        if let Some(replacement) = self.replacements.get(uid) {
+            return replacement.clone();
+        }
+
+        let replacement = generate_uid(&mut self.generator);
+        self.replacements.insert(uid.to_string(), replacement.clone());
+        replacement
+    }
+
+    pub fn get(&self, uid: &str) -> Option<&str> {
+        self.replacements.get(uid).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.replacements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.replacements.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replacements_are_consistent_and_exportable() {
+        let mut map = UidMap::new();
+
+        let replacement = map.replace("1.2.3");
+        assert!(replacement.starts_with("2.25."));
+        assert!(replacement.len() <= 64);
+        assert_eq!(map.replace("1.2.3"), replacement);
+        assert_ne!(map.replace("1.2.4"), replacement);
+
+        let mut csv = Vec::new();
+        map.write_csv(&mut csv).unwrap();
+
+        let read = UidMap::read_csv(csv.as_slice()).unwrap();
+        assert_eq!(read.len(), 2);
+        assert_eq!(read.get("1.2.3"), Some(replacement.as_str()));
+    }
+}
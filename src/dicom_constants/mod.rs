@@ -1,4 +1,4 @@
 pub mod transfer_syntaxes;
 pub mod tags;
 pub mod numeric;
-mod value_representation;
\ No newline at end of file
+pub mod value_representation;
\ No newline at end of file
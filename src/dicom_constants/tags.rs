@@ -1,44 +1,2951 @@
+// Generated by `cargo xtask gen-dictionary` from the DICOM standard (PS3.6, PS3.7). Do not edit.
 use crate::dataset::tag::Tag;
 
-pub const FILE_META_INFO_GROUP_LENGTH_TAG: Tag = Tag { group: 0x0002, element: 0x0000 };
-pub const FILE_META_INFO_VERSION: Tag = Tag { group: 0x0002, element: 0x0001 };
+pub const COMMAND_GROUP_LENGTH: Tag = Tag { group: 0x0000, element: 0x0000 };
+pub const AFFECTED_SOP_CLASS_UID: Tag = Tag { group: 0x0000, element: 0x0002 };
+pub const REQUESTED_SOP_CLASS_UID: Tag = Tag { group: 0x0000, element: 0x0003 };
+pub const COMMAND_FIELD: Tag = Tag { group: 0x0000, element: 0x0100 };
+pub const MESSAGE_ID: Tag = Tag { group: 0x0000, element: 0x0110 };
+pub const MESSAGE_ID_BEING_RESPONDED_TO: Tag = Tag { group: 0x0000, element: 0x0120 };
+pub const MOVE_DESTINATION: Tag = Tag { group: 0x0000, element: 0x0600 };
+pub const PRIORITY: Tag = Tag { group: 0x0000, element: 0x0700 };
+pub const COMMAND_DATA_SET_TYPE: Tag = Tag { group: 0x0000, element: 0x0800 };
+pub const STATUS: Tag = Tag { group: 0x0000, element: 0x0900 };
+pub const OFFENDING_ELEMENT: Tag = Tag { group: 0x0000, element: 0x0901 };
+pub const ERROR_COMMENT: Tag = Tag { group: 0x0000, element: 0x0902 };
+pub const ERROR_ID: Tag = Tag { group: 0x0000, element: 0x0903 };
+pub const AFFECTED_SOP_INSTANCE_UID: Tag = Tag { group: 0x0000, element: 0x1000 };
+pub const REQUESTED_SOP_INSTANCE_UID: Tag = Tag { group: 0x0000, element: 0x1001 };
+pub const EVENT_TYPE_ID: Tag = Tag { group: 0x0000, element: 0x1002 };
+pub const ATTRIBUTE_IDENTIFIER_LIST: Tag = Tag { group: 0x0000, element: 0x1005 };
+pub const ACTION_TYPE_ID: Tag = Tag { group: 0x0000, element: 0x1008 };
+pub const NUMBER_OF_REMAINING_SUBOPERATIONS: Tag = Tag { group: 0x0000, element: 0x1020 };
+pub const NUMBER_OF_COMPLETED_SUBOPERATIONS: Tag = Tag { group: 0x0000, element: 0x1021 };
+pub const NUMBER_OF_FAILED_SUBOPERATIONS: Tag = Tag { group: 0x0000, element: 0x1022 };
+pub const NUMBER_OF_WARNING_SUBOPERATIONS: Tag = Tag { group: 0x0000, element: 0x1023 };
+pub const MOVE_ORIGINATOR_APPLICATION_ENTITY_TITLE: Tag = Tag { group: 0x0000, element: 0x1030 };
+pub const MOVE_ORIGINATOR_MESSAGE_ID: Tag = Tag { group: 0x0000, element: 0x1031 };
+pub const FILE_META_INFORMATION_GROUP_LENGTH: Tag = Tag { group: 0x0002, element: 0x0000 };
+pub const FILE_META_INFORMATION_VERSION: Tag = Tag { group: 0x0002, element: 0x0001 };
 pub const MEDIA_STORAGE_SOP_CLASS_UID: Tag = Tag { group: 0x0002, element: 0x0002 };
 pub const MEDIA_STORAGE_SOP_INSTANCE_UID: Tag = Tag { group: 0x0002, element: 0x0003 };
 pub const TRANSFER_SYNTAX_UID: Tag = Tag { group: 0x0002, element: 0x0010 };
 pub const IMPLEMENTATION_CLASS_UID: Tag = Tag { group: 0x0002, element: 0x0012 };
 pub const IMPLEMENTATION_VERSION_NAME: Tag = Tag { group: 0x0002, element: 0x0013 };
-pub const TRANSFER_SYNTAX_UID_TAG: Tag = Tag { group: 0x0002, element: 0x0010 };
-pub const IMPLEMENTATION_CLASS_UID_TAG: Tag = Tag { group: 0x0002, element: 0x0012 };
-pub const IMPLEMENTATION_VERSION_NAME_TAG: Tag = Tag { group: 0x0002, element: 0x0013 };
-pub const MODALITY: Tag = Tag { group: 0x0008, element: 0x0060 };
-pub const SERIES_NUMBER: Tag = Tag { group: 0x0020, element: 0x0011 };
-pub const INSTANCE_NUMBER: Tag = Tag { group: 0x0020, element: 0x0013 };
-
-pub const ITEM: Tag = Tag { group: 0xFFFE, element: 0xE000 };
-pub const ITEM_DELIMITATION: Tag = Tag { group: 0xFFFE, element: 0xE00D };
-pub const SEQUENCE_DELIMITATION: Tag = Tag { group: 0xFFFE, element: 0xE0DD };
+pub const SOURCE_APPLICATION_ENTITY_TITLE: Tag = Tag { group: 0x0002, element: 0x0016 };
+pub const SENDING_APPLICATION_ENTITY_TITLE: Tag = Tag { group: 0x0002, element: 0x0017 };
+pub const RECEIVING_APPLICATION_ENTITY_TITLE: Tag = Tag { group: 0x0002, element: 0x0018 };
+pub const SOURCE_PRESENTATION_ADDRESS: Tag = Tag { group: 0x0002, element: 0x0026 };
+pub const SENDING_PRESENTATION_ADDRESS: Tag = Tag { group: 0x0002, element: 0x0027 };
+pub const RECEIVING_PRESENTATION_ADDRESS: Tag = Tag { group: 0x0002, element: 0x0028 };
+pub const RTV_META_INFORMATION_VERSION: Tag = Tag { group: 0x0002, element: 0x0031 };
+pub const RTV_COMMUNICATION_SOP_CLASS_UID: Tag = Tag { group: 0x0002, element: 0x0032 };
+pub const RTV_COMMUNICATION_SOP_INSTANCE_UID: Tag = Tag { group: 0x0002, element: 0x0033 };
+pub const RTV_SOURCE_IDENTIFIER: Tag = Tag { group: 0x0002, element: 0x0035 };
+pub const RTV_FLOW_IDENTIFIER: Tag = Tag { group: 0x0002, element: 0x0036 };
+pub const RTV_FLOW_RTP_SAMPLING_RATE: Tag = Tag { group: 0x0002, element: 0x0037 };
+pub const RTV_FLOW_ACTUAL_FRAME_DURATION: Tag = Tag { group: 0x0002, element: 0x0038 };
+pub const PRIVATE_INFORMATION_CREATOR_UID: Tag = Tag { group: 0x0002, element: 0x0100 };
+pub const PRIVATE_INFORMATION: Tag = Tag { group: 0x0002, element: 0x0102 };
+pub const FILE_SET_ID: Tag = Tag { group: 0x0004, element: 0x1130 };
+pub const FILE_SET_DESCRIPTOR_FILE_ID: Tag = Tag { group: 0x0004, element: 0x1141 };
+pub const SPECIFIC_CHARACTER_SET_OF_FILE_SET_DESCRIPTOR_FILE: Tag = Tag { group: 0x0004, element: 0x1142 };
+pub const OFFSET_OF_THE_FIRST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY: Tag = Tag { group: 0x0004, element: 0x1200 };
+pub const OFFSET_OF_THE_LAST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY: Tag = Tag { group: 0x0004, element: 0x1202 };
+pub const FILE_SET_CONSISTENCY_FLAG: Tag = Tag { group: 0x0004, element: 0x1212 };
+pub const DIRECTORY_RECORD_SEQUENCE: Tag = Tag { group: 0x0004, element: 0x1220 };
+pub const OFFSET_OF_THE_NEXT_DIRECTORY_RECORD: Tag = Tag { group: 0x0004, element: 0x1400 };
+pub const RECORD_IN_USE_FLAG: Tag = Tag { group: 0x0004, element: 0x1410 };
+pub const OFFSET_OF_REFERENCED_LOWER_LEVEL_DIRECTORY_ENTITY: Tag = Tag { group: 0x0004, element: 0x1420 };
+pub const DIRECTORY_RECORD_TYPE: Tag = Tag { group: 0x0004, element: 0x1430 };
+pub const PRIVATE_RECORD_UID: Tag = Tag { group: 0x0004, element: 0x1432 };
+pub const REFERENCED_FILE_ID: Tag = Tag { group: 0x0004, element: 0x1500 };
+pub const MRDR_DIRECTORY_RECORD_OFFSET: Tag = Tag { group: 0x0004, element: 0x1504 };
+pub const REFERENCED_SOP_CLASS_UID_IN_FILE: Tag = Tag { group: 0x0004, element: 0x1510 };
+pub const REFERENCED_SOP_INSTANCE_UID_IN_FILE: Tag = Tag { group: 0x0004, element: 0x1511 };
+pub const REFERENCED_TRANSFER_SYNTAX_UID_IN_FILE: Tag = Tag { group: 0x0004, element: 0x1512 };
+pub const REFERENCED_RELATED_GENERAL_SOP_CLASS_UID_IN_FILE: Tag = Tag { group: 0x0004, element: 0x151A };
+pub const NUMBER_OF_REFERENCES: Tag = Tag { group: 0x0004, element: 0x1600 };
+pub const LENGTH_TO_END: Tag = Tag { group: 0x0008, element: 0x0001 };
+pub const SPECIFIC_CHARACTER_SET: Tag = Tag { group: 0x0008, element: 0x0005 };
+pub const LANGUAGE_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0006 };
+pub const IMAGE_TYPE: Tag = Tag { group: 0x0008, element: 0x0008 };
+pub const RECOGNITION_CODE: Tag = Tag { group: 0x0008, element: 0x0010 };
+pub const INSTANCE_CREATION_DATE: Tag = Tag { group: 0x0008, element: 0x0012 };
+pub const INSTANCE_CREATION_TIME: Tag = Tag { group: 0x0008, element: 0x0013 };
+pub const INSTANCE_CREATOR_UID: Tag = Tag { group: 0x0008, element: 0x0014 };
+pub const INSTANCE_COERCION_DATE_TIME: Tag = Tag { group: 0x0008, element: 0x0015 };
+pub const SOP_CLASS_UID: Tag = Tag { group: 0x0008, element: 0x0016 };
+pub const ACQUISITION_UID: Tag = Tag { group: 0x0008, element: 0x0017 };
+pub const SOP_INSTANCE_UID: Tag = Tag { group: 0x0008, element: 0x0018 };
+pub const PYRAMID_UID: Tag = Tag { group: 0x0008, element: 0x0019 };
+pub const RELATED_GENERAL_SOP_CLASS_UID: Tag = Tag { group: 0x0008, element: 0x001A };
+pub const ORIGINAL_SPECIALIZED_SOP_CLASS_UID: Tag = Tag { group: 0x0008, element: 0x001B };
 pub const STUDY_DATE: Tag = Tag { group: 0x0008, element: 0x0020 };
+pub const SERIES_DATE: Tag = Tag { group: 0x0008, element: 0x0021 };
+pub const ACQUISITION_DATE: Tag = Tag { group: 0x0008, element: 0x0022 };
+pub const CONTENT_DATE: Tag = Tag { group: 0x0008, element: 0x0023 };
+pub const OVERLAY_DATE: Tag = Tag { group: 0x0008, element: 0x0024 };
+pub const CURVE_DATE: Tag = Tag { group: 0x0008, element: 0x0025 };
+pub const ACQUISITION_DATE_TIME: Tag = Tag { group: 0x0008, element: 0x002A };
+pub const STUDY_TIME: Tag = Tag { group: 0x0008, element: 0x0030 };
+pub const SERIES_TIME: Tag = Tag { group: 0x0008, element: 0x0031 };
+pub const ACQUISITION_TIME: Tag = Tag { group: 0x0008, element: 0x0032 };
+pub const CONTENT_TIME: Tag = Tag { group: 0x0008, element: 0x0033 };
+pub const OVERLAY_TIME: Tag = Tag { group: 0x0008, element: 0x0034 };
+pub const CURVE_TIME: Tag = Tag { group: 0x0008, element: 0x0035 };
+pub const DATA_SET_TYPE: Tag = Tag { group: 0x0008, element: 0x0040 };
+pub const DATA_SET_SUBTYPE: Tag = Tag { group: 0x0008, element: 0x0041 };
+pub const NUCLEAR_MEDICINE_SERIES_TYPE: Tag = Tag { group: 0x0008, element: 0x0042 };
+pub const ACCESSION_NUMBER: Tag = Tag { group: 0x0008, element: 0x0050 };
+pub const ISSUER_OF_ACCESSION_NUMBER_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0051 };
+pub const QUERY_RETRIEVE_LEVEL: Tag = Tag { group: 0x0008, element: 0x0052 };
+pub const QUERY_RETRIEVE_VIEW: Tag = Tag { group: 0x0008, element: 0x0053 };
+pub const RETRIEVE_AE_TITLE: Tag = Tag { group: 0x0008, element: 0x0054 };
+pub const STATION_AE_TITLE: Tag = Tag { group: 0x0008, element: 0x0055 };
+pub const INSTANCE_AVAILABILITY: Tag = Tag { group: 0x0008, element: 0x0056 };
+pub const FAILED_SOP_INSTANCE_UID_LIST: Tag = Tag { group: 0x0008, element: 0x0058 };
+pub const MODALITY: Tag = Tag { group: 0x0008, element: 0x0060 };
+pub const MODALITIES_IN_STUDY: Tag = Tag { group: 0x0008, element: 0x0061 };
+pub const SOP_CLASSES_IN_STUDY: Tag = Tag { group: 0x0008, element: 0x0062 };
+pub const ANATOMIC_REGIONS_IN_STUDY_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0063 };
+pub const CONVERSION_TYPE: Tag = Tag { group: 0x0008, element: 0x0064 };
+pub const PRESENTATION_INTENT_TYPE: Tag = Tag { group: 0x0008, element: 0x0068 };
+pub const MANUFACTURER: Tag = Tag { group: 0x0008, element: 0x0070 };
+pub const INSTITUTION_NAME: Tag = Tag { group: 0x0008, element: 0x0080 };
+pub const INSTITUTION_ADDRESS: Tag = Tag { group: 0x0008, element: 0x0081 };
+pub const INSTITUTION_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0082 };
+pub const REFERRING_PHYSICIAN_NAME: Tag = Tag { group: 0x0008, element: 0x0090 };
+pub const REFERRING_PHYSICIAN_ADDRESS: Tag = Tag { group: 0x0008, element: 0x0092 };
+pub const REFERRING_PHYSICIAN_TELEPHONE_NUMBERS: Tag = Tag { group: 0x0008, element: 0x0094 };
+pub const REFERRING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0096 };
+pub const CONSULTING_PHYSICIAN_NAME: Tag = Tag { group: 0x0008, element: 0x009C };
+pub const CONSULTING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x009D };
+pub const CODE_VALUE: Tag = Tag { group: 0x0008, element: 0x0100 };
+pub const EXTENDED_CODE_VALUE: Tag = Tag { group: 0x0008, element: 0x0101 };
+pub const CODING_SCHEME_DESIGNATOR: Tag = Tag { group: 0x0008, element: 0x0102 };
+pub const CODING_SCHEME_VERSION: Tag = Tag { group: 0x0008, element: 0x0103 };
+pub const CODE_MEANING: Tag = Tag { group: 0x0008, element: 0x0104 };
+pub const MAPPING_RESOURCE: Tag = Tag { group: 0x0008, element: 0x0105 };
+pub const CONTEXT_GROUP_VERSION: Tag = Tag { group: 0x0008, element: 0x0106 };
+pub const CONTEXT_GROUP_LOCAL_VERSION: Tag = Tag { group: 0x0008, element: 0x0107 };
+pub const EXTENDED_CODE_MEANING: Tag = Tag { group: 0x0008, element: 0x0108 };
+pub const CODING_SCHEME_RESOURCES_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0109 };
+pub const CODING_SCHEME_URL_TYPE: Tag = Tag { group: 0x0008, element: 0x010A };
+pub const CONTEXT_GROUP_EXTENSION_FLAG: Tag = Tag { group: 0x0008, element: 0x010B };
+pub const CODING_SCHEME_UID: Tag = Tag { group: 0x0008, element: 0x010C };
+pub const CONTEXT_GROUP_EXTENSION_CREATOR_UID: Tag = Tag { group: 0x0008, element: 0x010D };
+pub const CODING_SCHEME_URL: Tag = Tag { group: 0x0008, element: 0x010E };
+pub const CONTEXT_IDENTIFIER: Tag = Tag { group: 0x0008, element: 0x010F };
+pub const CODING_SCHEME_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0110 };
+pub const CODING_SCHEME_REGISTRY: Tag = Tag { group: 0x0008, element: 0x0112 };
+pub const CODING_SCHEME_EXTERNAL_ID: Tag = Tag { group: 0x0008, element: 0x0114 };
+pub const CODING_SCHEME_NAME: Tag = Tag { group: 0x0008, element: 0x0115 };
+pub const CODING_SCHEME_RESPONSIBLE_ORGANIZATION: Tag = Tag { group: 0x0008, element: 0x0116 };
+pub const CONTEXT_UID: Tag = Tag { group: 0x0008, element: 0x0117 };
+pub const MAPPING_RESOURCE_UID: Tag = Tag { group: 0x0008, element: 0x0118 };
+pub const LONG_CODE_VALUE: Tag = Tag { group: 0x0008, element: 0x0119 };
+pub const URN_CODE_VALUE: Tag = Tag { group: 0x0008, element: 0x0120 };
+pub const EQUIVALENT_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0121 };
+pub const MAPPING_RESOURCE_NAME: Tag = Tag { group: 0x0008, element: 0x0122 };
+pub const CONTEXT_GROUP_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0123 };
+pub const MAPPING_RESOURCE_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0124 };
+pub const TIMEZONE_OFFSET_FROM_UTC: Tag = Tag { group: 0x0008, element: 0x0201 };
+pub const RESPONSIBLE_GROUP_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0220 };
+pub const EQUIPMENT_MODALITY: Tag = Tag { group: 0x0008, element: 0x0221 };
+pub const MANUFACTURER_RELATED_MODEL_GROUP: Tag = Tag { group: 0x0008, element: 0x0222 };
+pub const PRIVATE_DATA_ELEMENT_CHARACTERISTICS_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0300 };
+pub const PRIVATE_GROUP_REFERENCE: Tag = Tag { group: 0x0008, element: 0x0301 };
+pub const PRIVATE_CREATOR_REFERENCE: Tag = Tag { group: 0x0008, element: 0x0302 };
+pub const BLOCK_IDENTIFYING_INFORMATION_STATUS: Tag = Tag { group: 0x0008, element: 0x0303 };
+pub const NONIDENTIFYING_PRIVATE_ELEMENTS: Tag = Tag { group: 0x0008, element: 0x0304 };
+pub const DEIDENTIFICATION_ACTION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0305 };
+pub const IDENTIFYING_PRIVATE_ELEMENTS: Tag = Tag { group: 0x0008, element: 0x0306 };
+pub const DEIDENTIFICATION_ACTION: Tag = Tag { group: 0x0008, element: 0x0307 };
+pub const PRIVATE_DATA_ELEMENT: Tag = Tag { group: 0x0008, element: 0x0308 };
+pub const PRIVATE_DATA_ELEMENT_VALUE_MULTIPLICITY: Tag = Tag { group: 0x0008, element: 0x0309 };
+pub const PRIVATE_DATA_ELEMENT_VALUE_REPRESENTATION: Tag = Tag { group: 0x0008, element: 0x030A };
+pub const PRIVATE_DATA_ELEMENT_NUMBER_OF_ITEMS: Tag = Tag { group: 0x0008, element: 0x030B };
+pub const PRIVATE_DATA_ELEMENT_NAME: Tag = Tag { group: 0x0008, element: 0x030C };
+pub const PRIVATE_DATA_ELEMENT_KEYWORD: Tag = Tag { group: 0x0008, element: 0x030D };
+pub const PRIVATE_DATA_ELEMENT_DESCRIPTION: Tag = Tag { group: 0x0008, element: 0x030E };
+pub const PRIVATE_DATA_ELEMENT_ENCODING: Tag = Tag { group: 0x0008, element: 0x030F };
+pub const PRIVATE_DATA_ELEMENT_DEFINITION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x0310 };
+pub const NETWORK_ID: Tag = Tag { group: 0x0008, element: 0x1000 };
+pub const STATION_NAME: Tag = Tag { group: 0x0008, element: 0x1010 };
+pub const STUDY_DESCRIPTION: Tag = Tag { group: 0x0008, element: 0x1030 };
+pub const PROCEDURE_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1032 };
+pub const SERIES_DESCRIPTION: Tag = Tag { group: 0x0008, element: 0x103E };
+pub const SERIES_DESCRIPTION_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x103F };
+pub const INSTITUTIONAL_DEPARTMENT_NAME: Tag = Tag { group: 0x0008, element: 0x1040 };
+pub const INSTITUTIONAL_DEPARTMENT_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1041 };
+pub const PHYSICIANS_OF_RECORD: Tag = Tag { group: 0x0008, element: 0x1048 };
+pub const PHYSICIANS_OF_RECORD_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1049 };
+pub const PERFORMING_PHYSICIAN_NAME: Tag = Tag { group: 0x0008, element: 0x1050 };
+pub const PERFORMING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1052 };
+pub const NAME_OF_PHYSICIANS_READING_STUDY: Tag = Tag { group: 0x0008, element: 0x1060 };
+pub const PHYSICIANS_READING_STUDY_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1062 };
+pub const OPERATORS_NAME: Tag = Tag { group: 0x0008, element: 0x1070 };
+pub const OPERATOR_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1072 };
+pub const ADMITTING_DIAGNOSES_DESCRIPTION: Tag = Tag { group: 0x0008, element: 0x1080 };
+pub const ADMITTING_DIAGNOSES_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1084 };
+pub const PYRAMID_DESCRIPTION: Tag = Tag { group: 0x0008, element: 0x1088 };
+pub const MANUFACTURER_MODEL_NAME: Tag = Tag { group: 0x0008, element: 0x1090 };
+pub const REFERENCED_RESULTS_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1100 };
+pub const REFERENCED_STUDY_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1110 };
+pub const REFERENCED_PERFORMED_PROCEDURE_STEP_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1111 };
+pub const REFERENCED_INSTANCES_BY_SOP_CLASS_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1112 };
+pub const REFERENCED_SERIES_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1115 };
+pub const REFERENCED_PATIENT_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1120 };
+pub const REFERENCED_VISIT_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1125 };
+pub const REFERENCED_OVERLAY_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1130 };
+pub const REFERENCED_STEREOMETRIC_INSTANCE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1134 };
+pub const REFERENCED_WAVEFORM_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x113A };
+pub const REFERENCED_IMAGE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1140 };
+pub const REFERENCED_CURVE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1145 };
+pub const REFERENCED_INSTANCE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x114A };
+pub const REFERENCED_REAL_WORLD_VALUE_MAPPING_INSTANCE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x114B };
+pub const REFERENCED_SOP_CLASS_UID: Tag = Tag { group: 0x0008, element: 0x1150 };
+pub const REFERENCED_SOP_INSTANCE_UID: Tag = Tag { group: 0x0008, element: 0x1155 };
+pub const DEFINITION_SOURCE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1156 };
+pub const SOP_CLASSES_SUPPORTED: Tag = Tag { group: 0x0008, element: 0x115A };
+pub const REFERENCED_FRAME_NUMBER: Tag = Tag { group: 0x0008, element: 0x1160 };
+pub const SIMPLE_FRAME_LIST: Tag = Tag { group: 0x0008, element: 0x1161 };
+pub const CALCULATED_FRAME_LIST: Tag = Tag { group: 0x0008, element: 0x1162 };
+pub const TIME_RANGE: Tag = Tag { group: 0x0008, element: 0x1163 };
+pub const FRAME_EXTRACTION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1164 };
+pub const MULTI_FRAME_SOURCE_SOP_INSTANCE_UID: Tag = Tag { group: 0x0008, element: 0x1167 };
+pub const RETRIEVE_URL: Tag = Tag { group: 0x0008, element: 0x1190 };
+pub const TRANSACTION_UID: Tag = Tag { group: 0x0008, element: 0x1195 };
+pub const WARNING_REASON: Tag = Tag { group: 0x0008, element: 0x1196 };
+pub const FAILURE_REASON: Tag = Tag { group: 0x0008, element: 0x1197 };
+pub const FAILED_SOP_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1198 };
+pub const REFERENCED_SOP_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1199 };
+pub const OTHER_FAILURES_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x119A };
+pub const STUDIES_CONTAINING_OTHER_REFERENCED_INSTANCES_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1200 };
+pub const RELATED_SERIES_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x1250 };
+pub const LOSSY_IMAGE_COMPRESSION_RETIRED: Tag = Tag { group: 0x0008, element: 0x2110 };
+pub const DERIVATION_DESCRIPTION: Tag = Tag { group: 0x0008, element: 0x2111 };
+pub const SOURCE_IMAGE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x2112 };
+pub const STAGE_NAME: Tag = Tag { group: 0x0008, element: 0x2120 };
+pub const STAGE_NUMBER: Tag = Tag { group: 0x0008, element: 0x2122 };
+pub const NUMBER_OF_STAGES: Tag = Tag { group: 0x0008, element: 0x2124 };
+pub const VIEW_NAME: Tag = Tag { group: 0x0008, element: 0x2127 };
+pub const VIEW_NUMBER: Tag = Tag { group: 0x0008, element: 0x2128 };
+pub const NUMBER_OF_EVENT_TIMERS: Tag = Tag { group: 0x0008, element: 0x2129 };
+pub const NUMBER_OF_VIEWS_IN_STAGE: Tag = Tag { group: 0x0008, element: 0x212A };
+pub const EVENT_ELAPSED_TIMES: Tag = Tag { group: 0x0008, element: 0x2130 };
+pub const EVENT_TIMER_NAMES: Tag = Tag { group: 0x0008, element: 0x2132 };
+pub const EVENT_TIMER_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x2133 };
+pub const EVENT_TIME_OFFSET: Tag = Tag { group: 0x0008, element: 0x2134 };
+pub const EVENT_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x2135 };
+pub const START_TRIM: Tag = Tag { group: 0x0008, element: 0x2142 };
+pub const STOP_TRIM: Tag = Tag { group: 0x0008, element: 0x2143 };
+pub const RECOMMENDED_DISPLAY_FRAME_RATE: Tag = Tag { group: 0x0008, element: 0x2144 };
+pub const TRANSDUCER_POSITION: Tag = Tag { group: 0x0008, element: 0x2200 };
+pub const TRANSDUCER_ORIENTATION: Tag = Tag { group: 0x0008, element: 0x2204 };
+pub const ANATOMIC_STRUCTURE: Tag = Tag { group: 0x0008, element: 0x2208 };
+pub const ANATOMIC_REGION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x2218 };
+pub const ANATOMIC_REGION_MODIFIER_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x2220 };
+pub const PRIMARY_ANATOMIC_STRUCTURE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x2228 };
+pub const ANATOMIC_STRUCTURE_SPACE_OR_REGION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x2229 };
+pub const PRIMARY_ANATOMIC_STRUCTURE_MODIFIER_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x2230 };
+pub const ALTERNATE_REPRESENTATION_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x3001 };
+pub const AVAILABLE_TRANSFER_SYNTAX_UID: Tag = Tag { group: 0x0008, element: 0x3002 };
+pub const IRRADIATION_EVENT_UID: Tag = Tag { group: 0x0008, element: 0x3010 };
+pub const SOURCE_IRRADIATION_EVENT_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x3011 };
+pub const RADIOPHARMACEUTICAL_ADMINISTRATION_EVENT_UID: Tag = Tag { group: 0x0008, element: 0x3012 };
+pub const IDENTIFYING_COMMENTS: Tag = Tag { group: 0x0008, element: 0x4000 };
+pub const FRAME_TYPE: Tag = Tag { group: 0x0008, element: 0x9007 };
+pub const REFERENCED_IMAGE_EVIDENCE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x9092 };
+pub const REFERENCED_RAW_DATA_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x9121 };
+pub const CREATOR_VERSION_UID: Tag = Tag { group: 0x0008, element: 0x9123 };
+pub const DERIVATION_IMAGE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x9124 };
+pub const SOURCE_IMAGE_EVIDENCE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x9154 };
+pub const PIXEL_PRESENTATION: Tag = Tag { group: 0x0008, element: 0x9205 };
+pub const VOLUMETRIC_PROPERTIES: Tag = Tag { group: 0x0008, element: 0x9206 };
+pub const VOLUME_BASED_CALCULATION_TECHNIQUE: Tag = Tag { group: 0x0008, element: 0x9207 };
+pub const COMPLEX_IMAGE_COMPONENT: Tag = Tag { group: 0x0008, element: 0x9208 };
+pub const ACQUISITION_CONTRAST: Tag = Tag { group: 0x0008, element: 0x9209 };
+pub const DERIVATION_CODE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x9215 };
+pub const REFERENCED_PRESENTATION_STATE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x9237 };
+pub const REFERENCED_OTHER_PLANE_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x9410 };
+pub const FRAME_DISPLAY_SEQUENCE: Tag = Tag { group: 0x0008, element: 0x9458 };
+pub const RECOMMENDED_DISPLAY_FRAME_RATE_IN_FLOAT: Tag = Tag { group: 0x0008, element: 0x9459 };
+pub const SKIP_FRAME_RANGE_FLAG: Tag = Tag { group: 0x0008, element: 0x9460 };
+pub const PATIENT_NAME: Tag = Tag { group: 0x0010, element: 0x0010 };
+pub const PATIENT_ID: Tag = Tag { group: 0x0010, element: 0x0020 };
+pub const ISSUER_OF_PATIENT_ID: Tag = Tag { group: 0x0010, element: 0x0021 };
+pub const TYPE_OF_PATIENT_ID: Tag = Tag { group: 0x0010, element: 0x0022 };
+pub const ISSUER_OF_PATIENT_ID_QUALIFIERS_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0024 };
+pub const SOURCE_PATIENT_GROUP_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0026 };
+pub const GROUP_OF_PATIENTS_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0027 };
+pub const SUBJECT_RELATIVE_POSITION_IN_IMAGE: Tag = Tag { group: 0x0010, element: 0x0028 };
+pub const PATIENT_BIRTH_DATE: Tag = Tag { group: 0x0010, element: 0x0030 };
+pub const PATIENT_BIRTH_TIME: Tag = Tag { group: 0x0010, element: 0x0032 };
+pub const PATIENT_BIRTH_DATE_IN_ALTERNATIVE_CALENDAR: Tag = Tag { group: 0x0010, element: 0x0033 };
+pub const PATIENT_DEATH_DATE_IN_ALTERNATIVE_CALENDAR: Tag = Tag { group: 0x0010, element: 0x0034 };
+pub const PATIENT_ALTERNATIVE_CALENDAR: Tag = Tag { group: 0x0010, element: 0x0035 };
+pub const PATIENT_SEX: Tag = Tag { group: 0x0010, element: 0x0040 };
+pub const PATIENT_INSURANCE_PLAN_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0050 };
+pub const PATIENT_PRIMARY_LANGUAGE_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0101 };
+pub const PATIENT_PRIMARY_LANGUAGE_MODIFIER_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0102 };
+pub const QUALITY_CONTROL_SUBJECT: Tag = Tag { group: 0x0010, element: 0x0200 };
+pub const QUALITY_CONTROL_SUBJECT_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0201 };
+pub const STRAIN_DESCRIPTION: Tag = Tag { group: 0x0010, element: 0x0212 };
+pub const STRAIN_NOMENCLATURE: Tag = Tag { group: 0x0010, element: 0x0213 };
+pub const STRAIN_STOCK_NUMBER: Tag = Tag { group: 0x0010, element: 0x0214 };
+pub const STRAIN_SOURCE_REGISTRY_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0215 };
+pub const STRAIN_STOCK_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0216 };
+pub const STRAIN_SOURCE: Tag = Tag { group: 0x0010, element: 0x0217 };
+pub const STRAIN_ADDITIONAL_INFORMATION: Tag = Tag { group: 0x0010, element: 0x0218 };
+pub const STRAIN_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0219 };
+pub const GENETIC_MODIFICATIONS_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0221 };
+pub const GENETIC_MODIFICATIONS_DESCRIPTION: Tag = Tag { group: 0x0010, element: 0x0222 };
+pub const GENETIC_MODIFICATIONS_NOMENCLATURE: Tag = Tag { group: 0x0010, element: 0x0223 };
+pub const GENETIC_MODIFICATIONS_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x0229 };
+pub const OTHER_PATIENT_IDS: Tag = Tag { group: 0x0010, element: 0x1000 };
+pub const OTHER_PATIENT_NAMES: Tag = Tag { group: 0x0010, element: 0x1001 };
+pub const OTHER_PATIENT_IDS_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x1002 };
+pub const PATIENT_BIRTH_NAME: Tag = Tag { group: 0x0010, element: 0x1005 };
+pub const PATIENT_AGE: Tag = Tag { group: 0x0010, element: 0x1010 };
+pub const PATIENT_SIZE: Tag = Tag { group: 0x0010, element: 0x1020 };
+pub const PATIENT_SIZE_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x1021 };
+pub const PATIENT_BODY_MASS_INDEX: Tag = Tag { group: 0x0010, element: 0x1022 };
+pub const MEASURED_AP_DIMENSION: Tag = Tag { group: 0x0010, element: 0x1023 };
+pub const MEASURED_LATERAL_DIMENSION: Tag = Tag { group: 0x0010, element: 0x1024 };
+pub const PATIENT_WEIGHT: Tag = Tag { group: 0x0010, element: 0x1030 };
+pub const PATIENT_ADDRESS: Tag = Tag { group: 0x0010, element: 0x1040 };
+pub const INSURANCE_PLAN_IDENTIFICATION: Tag = Tag { group: 0x0010, element: 0x1050 };
+pub const PATIENT_MOTHER_BIRTH_NAME: Tag = Tag { group: 0x0010, element: 0x1060 };
+pub const MILITARY_RANK: Tag = Tag { group: 0x0010, element: 0x1080 };
+pub const BRANCH_OF_SERVICE: Tag = Tag { group: 0x0010, element: 0x1081 };
+pub const MEDICAL_RECORD_LOCATOR: Tag = Tag { group: 0x0010, element: 0x1090 };
+pub const REFERENCED_PATIENT_PHOTO_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x1100 };
+pub const MEDICAL_ALERTS: Tag = Tag { group: 0x0010, element: 0x2000 };
+pub const ALLERGIES: Tag = Tag { group: 0x0010, element: 0x2110 };
+pub const COUNTRY_OF_RESIDENCE: Tag = Tag { group: 0x0010, element: 0x2150 };
+pub const REGION_OF_RESIDENCE: Tag = Tag { group: 0x0010, element: 0x2152 };
+pub const PATIENT_TELEPHONE_NUMBERS: Tag = Tag { group: 0x0010, element: 0x2154 };
+pub const PATIENT_TELECOM_INFORMATION: Tag = Tag { group: 0x0010, element: 0x2155 };
+pub const ETHNIC_GROUP: Tag = Tag { group: 0x0010, element: 0x2160 };
+pub const ETHNIC_GROUP_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x2161 };
+pub const ETHNIC_GROUPS: Tag = Tag { group: 0x0010, element: 0x2162 };
+pub const OCCUPATION: Tag = Tag { group: 0x0010, element: 0x2180 };
+pub const SMOKING_STATUS: Tag = Tag { group: 0x0010, element: 0x21A0 };
+pub const ADDITIONAL_PATIENT_HISTORY: Tag = Tag { group: 0x0010, element: 0x21B0 };
+pub const PREGNANCY_STATUS: Tag = Tag { group: 0x0010, element: 0x21C0 };
+pub const LAST_MENSTRUAL_DATE: Tag = Tag { group: 0x0010, element: 0x21D0 };
+pub const PATIENT_RELIGIOUS_PREFERENCE: Tag = Tag { group: 0x0010, element: 0x21F0 };
+pub const PATIENT_SPECIES_DESCRIPTION: Tag = Tag { group: 0x0010, element: 0x2201 };
+pub const PATIENT_SPECIES_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x2202 };
+pub const PATIENT_SEX_NEUTERED: Tag = Tag { group: 0x0010, element: 0x2203 };
+pub const ANATOMICAL_ORIENTATION_TYPE: Tag = Tag { group: 0x0010, element: 0x2210 };
+pub const PATIENT_BREED_DESCRIPTION: Tag = Tag { group: 0x0010, element: 0x2292 };
+pub const PATIENT_BREED_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x2293 };
+pub const BREED_REGISTRATION_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x2294 };
+pub const BREED_REGISTRATION_NUMBER: Tag = Tag { group: 0x0010, element: 0x2295 };
+pub const BREED_REGISTRY_CODE_SEQUENCE: Tag = Tag { group: 0x0010, element: 0x2296 };
+pub const RESPONSIBLE_PERSON: Tag = Tag { group: 0x0010, element: 0x2297 };
+pub const RESPONSIBLE_PERSON_ROLE: Tag = Tag { group: 0x0010, element: 0x2298 };
+pub const RESPONSIBLE_ORGANIZATION: Tag = Tag { group: 0x0010, element: 0x2299 };
+pub const PATIENT_COMMENTS: Tag = Tag { group: 0x0010, element: 0x4000 };
+pub const EXAMINED_BODY_THICKNESS: Tag = Tag { group: 0x0010, element: 0x9431 };
+pub const CLINICAL_TRIAL_SPONSOR_NAME: Tag = Tag { group: 0x0012, element: 0x0010 };
+pub const CLINICAL_TRIAL_PROTOCOL_ID: Tag = Tag { group: 0x0012, element: 0x0020 };
+pub const CLINICAL_TRIAL_PROTOCOL_NAME: Tag = Tag { group: 0x0012, element: 0x0021 };
+pub const CLINICAL_TRIAL_SITE_ID: Tag = Tag { group: 0x0012, element: 0x0030 };
+pub const CLINICAL_TRIAL_SITE_NAME: Tag = Tag { group: 0x0012, element: 0x0031 };
+pub const CLINICAL_TRIAL_SUBJECT_ID: Tag = Tag { group: 0x0012, element: 0x0040 };
+pub const CLINICAL_TRIAL_SUBJECT_READING_ID: Tag = Tag { group: 0x0012, element: 0x0042 };
+pub const CLINICAL_TRIAL_TIME_POINT_ID: Tag = Tag { group: 0x0012, element: 0x0050 };
+pub const CLINICAL_TRIAL_TIME_POINT_DESCRIPTION: Tag = Tag { group: 0x0012, element: 0x0051 };
+pub const CLINICAL_TRIAL_COORDINATING_CENTER_NAME: Tag = Tag { group: 0x0012, element: 0x0060 };
+pub const PATIENT_IDENTITY_REMOVED: Tag = Tag { group: 0x0012, element: 0x0062 };
+pub const DEIDENTIFICATION_METHOD: Tag = Tag { group: 0x0012, element: 0x0063 };
+pub const DEIDENTIFICATION_METHOD_CODE_SEQUENCE: Tag = Tag { group: 0x0012, element: 0x0064 };
+pub const CLINICAL_TRIAL_SERIES_ID: Tag = Tag { group: 0x0012, element: 0x0071 };
+pub const CLINICAL_TRIAL_SERIES_DESCRIPTION: Tag = Tag { group: 0x0012, element: 0x0072 };
+pub const CLINICAL_TRIAL_PROTOCOL_ETHICS_COMMITTEE_NAME: Tag = Tag { group: 0x0012, element: 0x0081 };
+pub const CLINICAL_TRIAL_PROTOCOL_ETHICS_COMMITTEE_APPROVAL_NUMBER: Tag = Tag { group: 0x0012, element: 0x0082 };
+pub const CONSENT_FOR_CLINICAL_TRIAL_USE_SEQUENCE: Tag = Tag { group: 0x0012, element: 0x0083 };
+pub const DISTRIBUTION_TYPE: Tag = Tag { group: 0x0012, element: 0x0084 };
+pub const CONSENT_FOR_DISTRIBUTION_FLAG: Tag = Tag { group: 0x0012, element: 0x0085 };
+pub const ETHICS_COMMITTEE_APPROVAL_EFFECTIVENESS_START_DATE: Tag = Tag { group: 0x0012, element: 0x0086 };
+pub const ETHICS_COMMITTEE_APPROVAL_EFFECTIVENESS_END_DATE: Tag = Tag { group: 0x0012, element: 0x0087 };
+pub const CONTRAST_BOLUS_AGENT: Tag = Tag { group: 0x0018, element: 0x0010 };
+pub const CONTRAST_BOLUS_AGENT_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x0012 };
+pub const CONTRAST_BOLUS_T1_RELAXIVITY: Tag = Tag { group: 0x0018, element: 0x0013 };
+pub const CONTRAST_BOLUS_ADMINISTRATION_ROUTE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x0014 };
+pub const BODY_PART_EXAMINED: Tag = Tag { group: 0x0018, element: 0x0015 };
+pub const SCANNING_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x0020 };
+pub const SEQUENCE_VARIANT: Tag = Tag { group: 0x0018, element: 0x0021 };
+pub const SCAN_OPTIONS: Tag = Tag { group: 0x0018, element: 0x0022 };
+pub const MR_ACQUISITION_TYPE: Tag = Tag { group: 0x0018, element: 0x0023 };
+pub const SEQUENCE_NAME: Tag = Tag { group: 0x0018, element: 0x0024 };
+pub const ANGIO_FLAG: Tag = Tag { group: 0x0018, element: 0x0025 };
+pub const INTERVENTION_DRUG_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x0026 };
+pub const INTERVENTION_DRUG_STOP_TIME: Tag = Tag { group: 0x0018, element: 0x0027 };
+pub const INTERVENTION_DRUG_DOSE: Tag = Tag { group: 0x0018, element: 0x0028 };
+pub const INTERVENTION_DRUG_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x0029 };
+pub const ADDITIONAL_DRUG_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x002A };
+pub const RADIONUCLIDE: Tag = Tag { group: 0x0018, element: 0x0030 };
+pub const RADIOPHARMACEUTICAL: Tag = Tag { group: 0x0018, element: 0x0031 };
+pub const ENERGY_WINDOW_CENTERLINE: Tag = Tag { group: 0x0018, element: 0x0032 };
+pub const ENERGY_WINDOW_TOTAL_WIDTH: Tag = Tag { group: 0x0018, element: 0x0033 };
+pub const INTERVENTION_DRUG_NAME: Tag = Tag { group: 0x0018, element: 0x0034 };
+pub const INTERVENTION_DRUG_START_TIME: Tag = Tag { group: 0x0018, element: 0x0035 };
+pub const INTERVENTION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x0036 };
+pub const THERAPY_TYPE: Tag = Tag { group: 0x0018, element: 0x0037 };
+pub const INTERVENTION_STATUS: Tag = Tag { group: 0x0018, element: 0x0038 };
+pub const THERAPY_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x0039 };
+pub const INTERVENTION_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x003A };
+pub const CINE_RATE: Tag = Tag { group: 0x0018, element: 0x0040 };
+pub const INITIAL_CINE_RUN_STATE: Tag = Tag { group: 0x0018, element: 0x0042 };
+pub const SLICE_THICKNESS: Tag = Tag { group: 0x0018, element: 0x0050 };
+pub const KVP: Tag = Tag { group: 0x0018, element: 0x0060 };
+pub const COUNTS_ACCUMULATED: Tag = Tag { group: 0x0018, element: 0x0070 };
+pub const ACQUISITION_TERMINATION_CONDITION: Tag = Tag { group: 0x0018, element: 0x0071 };
+pub const EFFECTIVE_DURATION: Tag = Tag { group: 0x0018, element: 0x0072 };
+pub const ACQUISITION_START_CONDITION: Tag = Tag { group: 0x0018, element: 0x0073 };
+pub const ACQUISITION_START_CONDITION_DATA: Tag = Tag { group: 0x0018, element: 0x0074 };
+pub const ACQUISITION_TERMINATION_CONDITION_DATA: Tag = Tag { group: 0x0018, element: 0x0075 };
+pub const REPETITION_TIME: Tag = Tag { group: 0x0018, element: 0x0080 };
+pub const ECHO_TIME: Tag = Tag { group: 0x0018, element: 0x0081 };
+pub const INVERSION_TIME: Tag = Tag { group: 0x0018, element: 0x0082 };
+pub const NUMBER_OF_AVERAGES: Tag = Tag { group: 0x0018, element: 0x0083 };
+pub const IMAGING_FREQUENCY: Tag = Tag { group: 0x0018, element: 0x0084 };
+pub const IMAGED_NUCLEUS: Tag = Tag { group: 0x0018, element: 0x0085 };
+pub const ECHO_NUMBERS: Tag = Tag { group: 0x0018, element: 0x0086 };
+pub const MAGNETIC_FIELD_STRENGTH: Tag = Tag { group: 0x0018, element: 0x0087 };
+pub const SPACING_BETWEEN_SLICES: Tag = Tag { group: 0x0018, element: 0x0088 };
+pub const NUMBER_OF_PHASE_ENCODING_STEPS: Tag = Tag { group: 0x0018, element: 0x0089 };
+pub const DATA_COLLECTION_DIAMETER: Tag = Tag { group: 0x0018, element: 0x0090 };
+pub const ECHO_TRAIN_LENGTH: Tag = Tag { group: 0x0018, element: 0x0091 };
+pub const PERCENT_SAMPLING: Tag = Tag { group: 0x0018, element: 0x0093 };
+pub const PERCENT_PHASE_FIELD_OF_VIEW: Tag = Tag { group: 0x0018, element: 0x0094 };
+pub const PIXEL_BANDWIDTH: Tag = Tag { group: 0x0018, element: 0x0095 };
+pub const DEVICE_SERIAL_NUMBER: Tag = Tag { group: 0x0018, element: 0x1000 };
+pub const DEVICE_UID: Tag = Tag { group: 0x0018, element: 0x1002 };
+pub const DEVICE_ID: Tag = Tag { group: 0x0018, element: 0x1003 };
+pub const PLATE_ID: Tag = Tag { group: 0x0018, element: 0x1004 };
+pub const GENERATOR_ID: Tag = Tag { group: 0x0018, element: 0x1005 };
+pub const GRID_ID: Tag = Tag { group: 0x0018, element: 0x1006 };
+pub const CASSETTE_ID: Tag = Tag { group: 0x0018, element: 0x1007 };
+pub const GANTRY_ID: Tag = Tag { group: 0x0018, element: 0x1008 };
+pub const UNIQUE_DEVICE_IDENTIFIER: Tag = Tag { group: 0x0018, element: 0x1009 };
+pub const UDI_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x100A };
+pub const MANUFACTURER_DEVICE_CLASS_UID: Tag = Tag { group: 0x0018, element: 0x100B };
+pub const SECONDARY_CAPTURE_DEVICE_ID: Tag = Tag { group: 0x0018, element: 0x1010 };
+pub const HARDCOPY_CREATION_DEVICE_ID: Tag = Tag { group: 0x0018, element: 0x1011 };
+pub const DATE_OF_SECONDARY_CAPTURE: Tag = Tag { group: 0x0018, element: 0x1012 };
+pub const TIME_OF_SECONDARY_CAPTURE: Tag = Tag { group: 0x0018, element: 0x1014 };
+pub const SECONDARY_CAPTURE_DEVICE_MANUFACTURER: Tag = Tag { group: 0x0018, element: 0x1016 };
+pub const HARDCOPY_DEVICE_MANUFACTURER: Tag = Tag { group: 0x0018, element: 0x1017 };
+pub const SECONDARY_CAPTURE_DEVICE_MANUFACTURER_MODEL_NAME: Tag = Tag { group: 0x0018, element: 0x1018 };
+pub const SECONDARY_CAPTURE_DEVICE_SOFTWARE_VERSIONS: Tag = Tag { group: 0x0018, element: 0x1019 };
+pub const HARDCOPY_DEVICE_SOFTWARE_VERSION: Tag = Tag { group: 0x0018, element: 0x101A };
+pub const HARDCOPY_DEVICE_MANUFACTURER_MODEL_NAME: Tag = Tag { group: 0x0018, element: 0x101B };
+pub const SOFTWARE_VERSIONS: Tag = Tag { group: 0x0018, element: 0x1020 };
+pub const VIDEO_IMAGE_FORMAT_ACQUIRED: Tag = Tag { group: 0x0018, element: 0x1022 };
+pub const DIGITAL_IMAGE_FORMAT_ACQUIRED: Tag = Tag { group: 0x0018, element: 0x1023 };
+pub const PROTOCOL_NAME: Tag = Tag { group: 0x0018, element: 0x1030 };
+pub const CONTRAST_BOLUS_ROUTE: Tag = Tag { group: 0x0018, element: 0x1040 };
+pub const CONTRAST_BOLUS_VOLUME: Tag = Tag { group: 0x0018, element: 0x1041 };
+pub const CONTRAST_BOLUS_START_TIME: Tag = Tag { group: 0x0018, element: 0x1042 };
+pub const CONTRAST_BOLUS_STOP_TIME: Tag = Tag { group: 0x0018, element: 0x1043 };
+pub const CONTRAST_BOLUS_TOTAL_DOSE: Tag = Tag { group: 0x0018, element: 0x1044 };
+pub const SYRINGE_COUNTS: Tag = Tag { group: 0x0018, element: 0x1045 };
+pub const CONTRAST_FLOW_RATE: Tag = Tag { group: 0x0018, element: 0x1046 };
+pub const CONTRAST_FLOW_DURATION: Tag = Tag { group: 0x0018, element: 0x1047 };
+pub const CONTRAST_BOLUS_INGREDIENT: Tag = Tag { group: 0x0018, element: 0x1048 };
+pub const CONTRAST_BOLUS_INGREDIENT_CONCENTRATION: Tag = Tag { group: 0x0018, element: 0x1049 };
+pub const SPATIAL_RESOLUTION: Tag = Tag { group: 0x0018, element: 0x1050 };
+pub const TRIGGER_TIME: Tag = Tag { group: 0x0018, element: 0x1060 };
+pub const TRIGGER_SOURCE_OR_TYPE: Tag = Tag { group: 0x0018, element: 0x1061 };
+pub const NOMINAL_INTERVAL: Tag = Tag { group: 0x0018, element: 0x1062 };
+pub const FRAME_TIME: Tag = Tag { group: 0x0018, element: 0x1063 };
+pub const CARDIAC_FRAMING_TYPE: Tag = Tag { group: 0x0018, element: 0x1064 };
+pub const FRAME_TIME_VECTOR: Tag = Tag { group: 0x0018, element: 0x1065 };
+pub const FRAME_DELAY: Tag = Tag { group: 0x0018, element: 0x1066 };
+pub const IMAGE_TRIGGER_DELAY: Tag = Tag { group: 0x0018, element: 0x1067 };
+pub const MULTIPLEX_GROUP_TIME_OFFSET: Tag = Tag { group: 0x0018, element: 0x1068 };
+pub const TRIGGER_TIME_OFFSET: Tag = Tag { group: 0x0018, element: 0x1069 };
+pub const SYNCHRONIZATION_TRIGGER: Tag = Tag { group: 0x0018, element: 0x106A };
+pub const SYNCHRONIZATION_CHANNEL: Tag = Tag { group: 0x0018, element: 0x106C };
+pub const TRIGGER_SAMPLE_POSITION: Tag = Tag { group: 0x0018, element: 0x106E };
+pub const RADIOPHARMACEUTICAL_ROUTE: Tag = Tag { group: 0x0018, element: 0x1070 };
+pub const RADIOPHARMACEUTICAL_VOLUME: Tag = Tag { group: 0x0018, element: 0x1071 };
+pub const RADIOPHARMACEUTICAL_START_TIME: Tag = Tag { group: 0x0018, element: 0x1072 };
+pub const RADIOPHARMACEUTICAL_STOP_TIME: Tag = Tag { group: 0x0018, element: 0x1073 };
+pub const RADIONUCLIDE_TOTAL_DOSE: Tag = Tag { group: 0x0018, element: 0x1074 };
+pub const RADIONUCLIDE_HALF_LIFE: Tag = Tag { group: 0x0018, element: 0x1075 };
+pub const RADIONUCLIDE_POSITRON_FRACTION: Tag = Tag { group: 0x0018, element: 0x1076 };
+pub const RADIOPHARMACEUTICAL_SPECIFIC_ACTIVITY: Tag = Tag { group: 0x0018, element: 0x1077 };
+pub const RADIOPHARMACEUTICAL_START_DATE_TIME: Tag = Tag { group: 0x0018, element: 0x1078 };
+pub const RADIOPHARMACEUTICAL_STOP_DATE_TIME: Tag = Tag { group: 0x0018, element: 0x1079 };
+pub const BEAT_REJECTION_FLAG: Tag = Tag { group: 0x0018, element: 0x1080 };
+pub const LOW_RR_VALUE: Tag = Tag { group: 0x0018, element: 0x1081 };
+pub const HIGH_RR_VALUE: Tag = Tag { group: 0x0018, element: 0x1082 };
+pub const INTERVALS_ACQUIRED: Tag = Tag { group: 0x0018, element: 0x1083 };
+pub const INTERVALS_REJECTED: Tag = Tag { group: 0x0018, element: 0x1084 };
+pub const PVC_REJECTION: Tag = Tag { group: 0x0018, element: 0x1085 };
+pub const SKIP_BEATS: Tag = Tag { group: 0x0018, element: 0x1086 };
+pub const HEART_RATE: Tag = Tag { group: 0x0018, element: 0x1088 };
+pub const CARDIAC_NUMBER_OF_IMAGES: Tag = Tag { group: 0x0018, element: 0x1090 };
+pub const TRIGGER_WINDOW: Tag = Tag { group: 0x0018, element: 0x1094 };
+pub const RECONSTRUCTION_DIAMETER: Tag = Tag { group: 0x0018, element: 0x1100 };
+pub const DISTANCE_SOURCE_TO_DETECTOR: Tag = Tag { group: 0x0018, element: 0x1110 };
+pub const DISTANCE_SOURCE_TO_PATIENT: Tag = Tag { group: 0x0018, element: 0x1111 };
+pub const ESTIMATED_RADIOGRAPHIC_MAGNIFICATION_FACTOR: Tag = Tag { group: 0x0018, element: 0x1114 };
+pub const GANTRY_DETECTOR_TILT: Tag = Tag { group: 0x0018, element: 0x1120 };
+pub const GANTRY_DETECTOR_SLEW: Tag = Tag { group: 0x0018, element: 0x1121 };
+pub const TABLE_HEIGHT: Tag = Tag { group: 0x0018, element: 0x1130 };
+pub const TABLE_TRAVERSE: Tag = Tag { group: 0x0018, element: 0x1131 };
+pub const TABLE_MOTION: Tag = Tag { group: 0x0018, element: 0x1134 };
+pub const TABLE_VERTICAL_INCREMENT: Tag = Tag { group: 0x0018, element: 0x1135 };
+pub const TABLE_LATERAL_INCREMENT: Tag = Tag { group: 0x0018, element: 0x1136 };
+pub const TABLE_LONGITUDINAL_INCREMENT: Tag = Tag { group: 0x0018, element: 0x1137 };
+pub const TABLE_ANGLE: Tag = Tag { group: 0x0018, element: 0x1138 };
+pub const TABLE_TYPE: Tag = Tag { group: 0x0018, element: 0x113A };
+pub const ROTATION_DIRECTION: Tag = Tag { group: 0x0018, element: 0x1140 };
+pub const ANGULAR_POSITION: Tag = Tag { group: 0x0018, element: 0x1141 };
+pub const RADIAL_POSITION: Tag = Tag { group: 0x0018, element: 0x1142 };
+pub const SCAN_ARC: Tag = Tag { group: 0x0018, element: 0x1143 };
+pub const ANGULAR_STEP: Tag = Tag { group: 0x0018, element: 0x1144 };
+pub const CENTER_OF_ROTATION_OFFSET: Tag = Tag { group: 0x0018, element: 0x1145 };
+pub const ROTATION_OFFSET: Tag = Tag { group: 0x0018, element: 0x1146 };
+pub const FIELD_OF_VIEW_SHAPE: Tag = Tag { group: 0x0018, element: 0x1147 };
+pub const FIELD_OF_VIEW_DIMENSIONS: Tag = Tag { group: 0x0018, element: 0x1149 };
+pub const EXPOSURE_TIME: Tag = Tag { group: 0x0018, element: 0x1150 };
+pub const X_RAY_TUBE_CURRENT: Tag = Tag { group: 0x0018, element: 0x1151 };
+pub const EXPOSURE: Tag = Tag { group: 0x0018, element: 0x1152 };
+pub const EXPOSURE_INU_AS: Tag = Tag { group: 0x0018, element: 0x1153 };
+pub const AVERAGE_PULSE_WIDTH: Tag = Tag { group: 0x0018, element: 0x1154 };
+pub const RADIATION_SETTING: Tag = Tag { group: 0x0018, element: 0x1155 };
+pub const RECTIFICATION_TYPE: Tag = Tag { group: 0x0018, element: 0x1156 };
+pub const RADIATION_MODE: Tag = Tag { group: 0x0018, element: 0x115A };
+pub const IMAGE_AND_FLUOROSCOPY_AREA_DOSE_PRODUCT: Tag = Tag { group: 0x0018, element: 0x115E };
+pub const FILTER_TYPE: Tag = Tag { group: 0x0018, element: 0x1160 };
+pub const TYPE_OF_FILTERS: Tag = Tag { group: 0x0018, element: 0x1161 };
+pub const INTENSIFIER_SIZE: Tag = Tag { group: 0x0018, element: 0x1162 };
+pub const IMAGER_PIXEL_SPACING: Tag = Tag { group: 0x0018, element: 0x1164 };
+pub const GRID: Tag = Tag { group: 0x0018, element: 0x1166 };
+pub const GENERATOR_POWER: Tag = Tag { group: 0x0018, element: 0x1170 };
+pub const COLLIMATOR_GRID_NAME: Tag = Tag { group: 0x0018, element: 0x1180 };
+pub const COLLIMATOR_TYPE: Tag = Tag { group: 0x0018, element: 0x1181 };
+pub const FOCAL_DISTANCE: Tag = Tag { group: 0x0018, element: 0x1182 };
+pub const X_FOCUS_CENTER: Tag = Tag { group: 0x0018, element: 0x1183 };
+pub const Y_FOCUS_CENTER: Tag = Tag { group: 0x0018, element: 0x1184 };
+pub const FOCAL_SPOTS: Tag = Tag { group: 0x0018, element: 0x1190 };
+pub const ANODE_TARGET_MATERIAL: Tag = Tag { group: 0x0018, element: 0x1191 };
+pub const BODY_PART_THICKNESS: Tag = Tag { group: 0x0018, element: 0x11A0 };
+pub const COMPRESSION_FORCE: Tag = Tag { group: 0x0018, element: 0x11A2 };
+pub const COMPRESSION_PRESSURE: Tag = Tag { group: 0x0018, element: 0x11A3 };
+pub const PADDLE_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x11A4 };
+pub const COMPRESSION_CONTACT_AREA: Tag = Tag { group: 0x0018, element: 0x11A5 };
+pub const DATE_OF_LAST_CALIBRATION: Tag = Tag { group: 0x0018, element: 0x1200 };
+pub const TIME_OF_LAST_CALIBRATION: Tag = Tag { group: 0x0018, element: 0x1201 };
+pub const DATE_TIME_OF_LAST_CALIBRATION: Tag = Tag { group: 0x0018, element: 0x1202 };
+pub const CONVOLUTION_KERNEL: Tag = Tag { group: 0x0018, element: 0x1210 };
+pub const UPPER_LOWER_PIXEL_VALUES: Tag = Tag { group: 0x0018, element: 0x1240 };
+pub const ACTUAL_FRAME_DURATION: Tag = Tag { group: 0x0018, element: 0x1242 };
+pub const COUNT_RATE: Tag = Tag { group: 0x0018, element: 0x1243 };
+pub const RECEIVE_COIL_NAME: Tag = Tag { group: 0x0018, element: 0x1250 };
+pub const TRANSMIT_COIL_NAME: Tag = Tag { group: 0x0018, element: 0x1251 };
+pub const PLATE_TYPE: Tag = Tag { group: 0x0018, element: 0x1260 };
+pub const PHOSPHOR_TYPE: Tag = Tag { group: 0x0018, element: 0x1261 };
+pub const WATER_EQUIVALENT_DIAMETER: Tag = Tag { group: 0x0018, element: 0x1271 };
+pub const WATER_EQUIVALENT_DIAMETER_CALCULATION_METHOD_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x1272 };
+pub const SCAN_VELOCITY: Tag = Tag { group: 0x0018, element: 0x1300 };
+pub const WHOLE_BODY_TECHNIQUE: Tag = Tag { group: 0x0018, element: 0x1301 };
+pub const SCAN_LENGTH: Tag = Tag { group: 0x0018, element: 0x1302 };
+pub const ACQUISITION_MATRIX: Tag = Tag { group: 0x0018, element: 0x1310 };
+pub const IN_PLANE_PHASE_ENCODING_DIRECTION: Tag = Tag { group: 0x0018, element: 0x1312 };
+pub const FLIP_ANGLE: Tag = Tag { group: 0x0018, element: 0x1314 };
+pub const VARIABLE_FLIP_ANGLE_FLAG: Tag = Tag { group: 0x0018, element: 0x1315 };
+pub const SAR: Tag = Tag { group: 0x0018, element: 0x1316 };
+pub const D_BDT: Tag = Tag { group: 0x0018, element: 0x1318 };
+pub const B1RMS: Tag = Tag { group: 0x0018, element: 0x1320 };
+pub const ACQUISITION_DEVICE_PROCESSING_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x1400 };
+pub const ACQUISITION_DEVICE_PROCESSING_CODE: Tag = Tag { group: 0x0018, element: 0x1401 };
+pub const CASSETTE_ORIENTATION: Tag = Tag { group: 0x0018, element: 0x1402 };
+pub const CASSETTE_SIZE: Tag = Tag { group: 0x0018, element: 0x1403 };
+pub const EXPOSURES_ON_PLATE: Tag = Tag { group: 0x0018, element: 0x1404 };
+pub const RELATIVE_X_RAY_EXPOSURE: Tag = Tag { group: 0x0018, element: 0x1405 };
+pub const EXPOSURE_INDEX: Tag = Tag { group: 0x0018, element: 0x1411 };
+pub const TARGET_EXPOSURE_INDEX: Tag = Tag { group: 0x0018, element: 0x1412 };
+pub const DEVIATION_INDEX: Tag = Tag { group: 0x0018, element: 0x1413 };
+pub const COLUMN_ANGULATION: Tag = Tag { group: 0x0018, element: 0x1450 };
+pub const TOMO_LAYER_HEIGHT: Tag = Tag { group: 0x0018, element: 0x1460 };
+pub const TOMO_ANGLE: Tag = Tag { group: 0x0018, element: 0x1470 };
+pub const TOMO_TIME: Tag = Tag { group: 0x0018, element: 0x1480 };
+pub const TOMO_TYPE: Tag = Tag { group: 0x0018, element: 0x1490 };
+pub const TOMO_CLASS: Tag = Tag { group: 0x0018, element: 0x1491 };
+pub const NUMBER_OF_TOMOSYNTHESIS_SOURCE_IMAGES: Tag = Tag { group: 0x0018, element: 0x1495 };
+pub const POSITIONER_MOTION: Tag = Tag { group: 0x0018, element: 0x1500 };
+pub const POSITIONER_TYPE: Tag = Tag { group: 0x0018, element: 0x1508 };
+pub const POSITIONER_PRIMARY_ANGLE: Tag = Tag { group: 0x0018, element: 0x1510 };
+pub const POSITIONER_SECONDARY_ANGLE: Tag = Tag { group: 0x0018, element: 0x1511 };
+pub const POSITIONER_PRIMARY_ANGLE_INCREMENT: Tag = Tag { group: 0x0018, element: 0x1520 };
+pub const POSITIONER_SECONDARY_ANGLE_INCREMENT: Tag = Tag { group: 0x0018, element: 0x1521 };
+pub const DETECTOR_PRIMARY_ANGLE: Tag = Tag { group: 0x0018, element: 0x1530 };
+pub const DETECTOR_SECONDARY_ANGLE: Tag = Tag { group: 0x0018, element: 0x1531 };
+pub const SHUTTER_SHAPE: Tag = Tag { group: 0x0018, element: 0x1600 };
+pub const SHUTTER_LEFT_VERTICAL_EDGE: Tag = Tag { group: 0x0018, element: 0x1602 };
+pub const SHUTTER_RIGHT_VERTICAL_EDGE: Tag = Tag { group: 0x0018, element: 0x1604 };
+pub const SHUTTER_UPPER_HORIZONTAL_EDGE: Tag = Tag { group: 0x0018, element: 0x1606 };
+pub const SHUTTER_LOWER_HORIZONTAL_EDGE: Tag = Tag { group: 0x0018, element: 0x1608 };
+pub const CENTER_OF_CIRCULAR_SHUTTER: Tag = Tag { group: 0x0018, element: 0x1610 };
+pub const RADIUS_OF_CIRCULAR_SHUTTER: Tag = Tag { group: 0x0018, element: 0x1612 };
+pub const VERTICES_OF_THE_POLYGONAL_SHUTTER: Tag = Tag { group: 0x0018, element: 0x1620 };
+pub const SHUTTER_PRESENTATION_VALUE: Tag = Tag { group: 0x0018, element: 0x1622 };
+pub const SHUTTER_OVERLAY_GROUP: Tag = Tag { group: 0x0018, element: 0x1623 };
+pub const SHUTTER_PRESENTATION_COLOR_CIE_LAB_VALUE: Tag = Tag { group: 0x0018, element: 0x1624 };
+pub const COLLIMATOR_SHAPE: Tag = Tag { group: 0x0018, element: 0x1700 };
+pub const COLLIMATOR_LEFT_VERTICAL_EDGE: Tag = Tag { group: 0x0018, element: 0x1702 };
+pub const COLLIMATOR_RIGHT_VERTICAL_EDGE: Tag = Tag { group: 0x0018, element: 0x1704 };
+pub const COLLIMATOR_UPPER_HORIZONTAL_EDGE: Tag = Tag { group: 0x0018, element: 0x1706 };
+pub const COLLIMATOR_LOWER_HORIZONTAL_EDGE: Tag = Tag { group: 0x0018, element: 0x1708 };
+pub const CENTER_OF_CIRCULAR_COLLIMATOR: Tag = Tag { group: 0x0018, element: 0x1710 };
+pub const RADIUS_OF_CIRCULAR_COLLIMATOR: Tag = Tag { group: 0x0018, element: 0x1712 };
+pub const VERTICES_OF_THE_POLYGONAL_COLLIMATOR: Tag = Tag { group: 0x0018, element: 0x1720 };
+pub const ACQUISITION_TIME_SYNCHRONIZED: Tag = Tag { group: 0x0018, element: 0x1800 };
+pub const TIME_SOURCE: Tag = Tag { group: 0x0018, element: 0x1801 };
+pub const TIME_DISTRIBUTION_PROTOCOL: Tag = Tag { group: 0x0018, element: 0x1802 };
+pub const NTP_SOURCE_ADDRESS: Tag = Tag { group: 0x0018, element: 0x1803 };
+pub const PAGE_NUMBER_VECTOR: Tag = Tag { group: 0x0018, element: 0x2001 };
+pub const FRAME_LABEL_VECTOR: Tag = Tag { group: 0x0018, element: 0x2002 };
+pub const FRAME_PRIMARY_ANGLE_VECTOR: Tag = Tag { group: 0x0018, element: 0x2003 };
+pub const FRAME_SECONDARY_ANGLE_VECTOR: Tag = Tag { group: 0x0018, element: 0x2004 };
+pub const SLICE_LOCATION_VECTOR: Tag = Tag { group: 0x0018, element: 0x2005 };
+pub const DISPLAY_WINDOW_LABEL_VECTOR: Tag = Tag { group: 0x0018, element: 0x2006 };
+pub const NOMINAL_SCANNED_PIXEL_SPACING: Tag = Tag { group: 0x0018, element: 0x2010 };
+pub const DIGITIZING_DEVICE_TRANSPORT_DIRECTION: Tag = Tag { group: 0x0018, element: 0x2020 };
+pub const ROTATION_OF_SCANNED_FILM: Tag = Tag { group: 0x0018, element: 0x2030 };
+pub const IVUS_ACQUISITION: Tag = Tag { group: 0x0018, element: 0x3100 };
+pub const IVUS_PULLBACK_RATE: Tag = Tag { group: 0x0018, element: 0x3101 };
+pub const IVUS_GATED_RATE: Tag = Tag { group: 0x0018, element: 0x3102 };
+pub const IVUS_PULLBACK_START_FRAME_NUMBER: Tag = Tag { group: 0x0018, element: 0x3103 };
+pub const IVUS_PULLBACK_STOP_FRAME_NUMBER: Tag = Tag { group: 0x0018, element: 0x3104 };
+pub const LESION_NUMBER: Tag = Tag { group: 0x0018, element: 0x3105 };
+pub const ACQUISITION_COMMENTS: Tag = Tag { group: 0x0018, element: 0x4000 };
+pub const OUTPUT_POWER: Tag = Tag { group: 0x0018, element: 0x5000 };
+pub const TRANSDUCER_DATA: Tag = Tag { group: 0x0018, element: 0x5010 };
+pub const FOCUS_DEPTH: Tag = Tag { group: 0x0018, element: 0x5012 };
+pub const PROCESSING_FUNCTION: Tag = Tag { group: 0x0018, element: 0x5020 };
+pub const POSTPROCESSING_FUNCTION: Tag = Tag { group: 0x0018, element: 0x5021 };
+pub const MECHANICAL_INDEX: Tag = Tag { group: 0x0018, element: 0x5022 };
+pub const BONE_THERMAL_INDEX: Tag = Tag { group: 0x0018, element: 0x5024 };
+pub const CRANIAL_THERMAL_INDEX: Tag = Tag { group: 0x0018, element: 0x5026 };
+pub const SOFT_TISSUE_THERMAL_INDEX: Tag = Tag { group: 0x0018, element: 0x5027 };
+pub const SOFT_TISSUE_FOCUS_THERMAL_INDEX: Tag = Tag { group: 0x0018, element: 0x5028 };
+pub const SOFT_TISSUE_SURFACE_THERMAL_INDEX: Tag = Tag { group: 0x0018, element: 0x5029 };
+pub const DEPTH_OF_SCAN_FIELD: Tag = Tag { group: 0x0018, element: 0x5050 };
+pub const PATIENT_POSITION: Tag = Tag { group: 0x0018, element: 0x5100 };
+pub const VIEW_POSITION: Tag = Tag { group: 0x0018, element: 0x5101 };
+pub const PROJECTION_EPONYMOUS_NAME_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x5104 };
+pub const SENSITIVITY: Tag = Tag { group: 0x0018, element: 0x6000 };
+pub const SEQUENCE_OF_ULTRASOUND_REGIONS: Tag = Tag { group: 0x0018, element: 0x6011 };
+pub const REGION_SPATIAL_FORMAT: Tag = Tag { group: 0x0018, element: 0x6012 };
+pub const REGION_DATA_TYPE: Tag = Tag { group: 0x0018, element: 0x6014 };
+pub const REGION_FLAGS: Tag = Tag { group: 0x0018, element: 0x6016 };
+pub const REGION_LOCATION_MIN_X0: Tag = Tag { group: 0x0018, element: 0x6018 };
+pub const REGION_LOCATION_MIN_Y0: Tag = Tag { group: 0x0018, element: 0x601A };
+pub const REGION_LOCATION_MAX_X1: Tag = Tag { group: 0x0018, element: 0x601C };
+pub const REGION_LOCATION_MAX_Y1: Tag = Tag { group: 0x0018, element: 0x601E };
+pub const REFERENCE_PIXEL_X0: Tag = Tag { group: 0x0018, element: 0x6020 };
+pub const REFERENCE_PIXEL_Y0: Tag = Tag { group: 0x0018, element: 0x6022 };
+pub const PHYSICAL_UNITS_X_DIRECTION: Tag = Tag { group: 0x0018, element: 0x6024 };
+pub const PHYSICAL_UNITS_Y_DIRECTION: Tag = Tag { group: 0x0018, element: 0x6026 };
+pub const REFERENCE_PIXEL_PHYSICAL_VALUE_X: Tag = Tag { group: 0x0018, element: 0x6028 };
+pub const REFERENCE_PIXEL_PHYSICAL_VALUE_Y: Tag = Tag { group: 0x0018, element: 0x602A };
+pub const PHYSICAL_DELTA_X: Tag = Tag { group: 0x0018, element: 0x602C };
+pub const PHYSICAL_DELTA_Y: Tag = Tag { group: 0x0018, element: 0x602E };
+pub const TRANSDUCER_FREQUENCY: Tag = Tag { group: 0x0018, element: 0x6030 };
+pub const TRANSDUCER_TYPE: Tag = Tag { group: 0x0018, element: 0x6031 };
+pub const PULSE_REPETITION_FREQUENCY: Tag = Tag { group: 0x0018, element: 0x6032 };
+pub const DOPPLER_CORRECTION_ANGLE: Tag = Tag { group: 0x0018, element: 0x6034 };
+pub const STEERING_ANGLE: Tag = Tag { group: 0x0018, element: 0x6036 };
+pub const DOPPLER_SAMPLE_VOLUME_X_POSITION: Tag = Tag { group: 0x0018, element: 0x6039 };
+pub const DOPPLER_SAMPLE_VOLUME_Y_POSITION: Tag = Tag { group: 0x0018, element: 0x603B };
+pub const TM_LINE_POSITION_X0: Tag = Tag { group: 0x0018, element: 0x603D };
+pub const TM_LINE_POSITION_Y0: Tag = Tag { group: 0x0018, element: 0x603F };
+pub const TM_LINE_POSITION_X1: Tag = Tag { group: 0x0018, element: 0x6041 };
+pub const TM_LINE_POSITION_Y1: Tag = Tag { group: 0x0018, element: 0x6043 };
+pub const PIXEL_COMPONENT_ORGANIZATION: Tag = Tag { group: 0x0018, element: 0x6044 };
+pub const PIXEL_COMPONENT_MASK: Tag = Tag { group: 0x0018, element: 0x6046 };
+pub const PIXEL_COMPONENT_RANGE_START: Tag = Tag { group: 0x0018, element: 0x6048 };
+pub const PIXEL_COMPONENT_RANGE_STOP: Tag = Tag { group: 0x0018, element: 0x604A };
+pub const PIXEL_COMPONENT_PHYSICAL_UNITS: Tag = Tag { group: 0x0018, element: 0x604C };
+pub const PIXEL_COMPONENT_DATA_TYPE: Tag = Tag { group: 0x0018, element: 0x604E };
+pub const NUMBER_OF_TABLE_BREAK_POINTS: Tag = Tag { group: 0x0018, element: 0x6050 };
+pub const TABLE_OF_X_BREAK_POINTS: Tag = Tag { group: 0x0018, element: 0x6052 };
+pub const TABLE_OF_Y_BREAK_POINTS: Tag = Tag { group: 0x0018, element: 0x6054 };
+pub const NUMBER_OF_TABLE_ENTRIES: Tag = Tag { group: 0x0018, element: 0x6056 };
+pub const TABLE_OF_PIXEL_VALUES: Tag = Tag { group: 0x0018, element: 0x6058 };
+pub const TABLE_OF_PARAMETER_VALUES: Tag = Tag { group: 0x0018, element: 0x605A };
+pub const DETECTOR_CONDITIONS_NOMINAL_FLAG: Tag = Tag { group: 0x0018, element: 0x7000 };
+pub const DETECTOR_TEMPERATURE: Tag = Tag { group: 0x0018, element: 0x7001 };
+pub const DETECTOR_TYPE: Tag = Tag { group: 0x0018, element: 0x7004 };
+pub const DETECTOR_CONFIGURATION: Tag = Tag { group: 0x0018, element: 0x7005 };
+pub const DETECTOR_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x7006 };
+pub const DETECTOR_MODE: Tag = Tag { group: 0x0018, element: 0x7008 };
+pub const DETECTOR_ID: Tag = Tag { group: 0x0018, element: 0x700A };
+pub const DATE_OF_LAST_DETECTOR_CALIBRATION: Tag = Tag { group: 0x0018, element: 0x700C };
+pub const TIME_OF_LAST_DETECTOR_CALIBRATION: Tag = Tag { group: 0x0018, element: 0x700E };
+pub const EXPOSURES_ON_DETECTOR_SINCE_LAST_CALIBRATION: Tag = Tag { group: 0x0018, element: 0x7010 };
+pub const EXPOSURES_ON_DETECTOR_SINCE_MANUFACTURED: Tag = Tag { group: 0x0018, element: 0x7011 };
+pub const DETECTOR_TIME_SINCE_LAST_EXPOSURE: Tag = Tag { group: 0x0018, element: 0x7012 };
+pub const DETECTOR_ACTIVE_TIME: Tag = Tag { group: 0x0018, element: 0x7014 };
+pub const DETECTOR_ACTIVATION_OFFSET_FROM_EXPOSURE: Tag = Tag { group: 0x0018, element: 0x7016 };
+pub const DETECTOR_BINNING: Tag = Tag { group: 0x0018, element: 0x701A };
+pub const DETECTOR_ELEMENT_PHYSICAL_SIZE: Tag = Tag { group: 0x0018, element: 0x7020 };
+pub const DETECTOR_ELEMENT_SPACING: Tag = Tag { group: 0x0018, element: 0x7022 };
+pub const DETECTOR_ACTIVE_SHAPE: Tag = Tag { group: 0x0018, element: 0x7024 };
+pub const DETECTOR_ACTIVE_DIMENSIONS: Tag = Tag { group: 0x0018, element: 0x7026 };
+pub const DETECTOR_ACTIVE_ORIGIN: Tag = Tag { group: 0x0018, element: 0x7028 };
+pub const DETECTOR_MANUFACTURER_NAME: Tag = Tag { group: 0x0018, element: 0x702A };
+pub const DETECTOR_MANUFACTURER_MODEL_NAME: Tag = Tag { group: 0x0018, element: 0x702B };
+pub const FIELD_OF_VIEW_ORIGIN: Tag = Tag { group: 0x0018, element: 0x7030 };
+pub const FIELD_OF_VIEW_ROTATION: Tag = Tag { group: 0x0018, element: 0x7032 };
+pub const FIELD_OF_VIEW_HORIZONTAL_FLIP: Tag = Tag { group: 0x0018, element: 0x7034 };
+pub const PIXEL_DATA_AREA_ORIGIN_RELATIVE_TO_FOV: Tag = Tag { group: 0x0018, element: 0x7036 };
+pub const PIXEL_DATA_AREA_ROTATION_ANGLE_RELATIVE_TO_FOV: Tag = Tag { group: 0x0018, element: 0x7038 };
+pub const GRID_ABSORBING_MATERIAL: Tag = Tag { group: 0x0018, element: 0x7040 };
+pub const GRID_SPACING_MATERIAL: Tag = Tag { group: 0x0018, element: 0x7041 };
+pub const GRID_THICKNESS: Tag = Tag { group: 0x0018, element: 0x7042 };
+pub const GRID_PITCH: Tag = Tag { group: 0x0018, element: 0x7044 };
+pub const GRID_ASPECT_RATIO: Tag = Tag { group: 0x0018, element: 0x7046 };
+pub const GRID_PERIOD: Tag = Tag { group: 0x0018, element: 0x7048 };
+pub const GRID_FOCAL_DISTANCE: Tag = Tag { group: 0x0018, element: 0x704C };
+pub const FILTER_MATERIAL: Tag = Tag { group: 0x0018, element: 0x7050 };
+pub const FILTER_THICKNESS_MINIMUM: Tag = Tag { group: 0x0018, element: 0x7052 };
+pub const FILTER_THICKNESS_MAXIMUM: Tag = Tag { group: 0x0018, element: 0x7054 };
+pub const FILTER_BEAM_PATH_LENGTH_MINIMUM: Tag = Tag { group: 0x0018, element: 0x7056 };
+pub const FILTER_BEAM_PATH_LENGTH_MAXIMUM: Tag = Tag { group: 0x0018, element: 0x7058 };
+pub const EXPOSURE_CONTROL_MODE: Tag = Tag { group: 0x0018, element: 0x7060 };
+pub const EXPOSURE_CONTROL_MODE_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x7062 };
+pub const EXPOSURE_STATUS: Tag = Tag { group: 0x0018, element: 0x7064 };
+pub const PHOTOTIMER_SETTING: Tag = Tag { group: 0x0018, element: 0x7065 };
+pub const EXPOSURE_TIME_INU_S: Tag = Tag { group: 0x0018, element: 0x8150 };
+pub const X_RAY_TUBE_CURRENT_INU_A: Tag = Tag { group: 0x0018, element: 0x8151 };
+pub const CONTENT_QUALIFICATION: Tag = Tag { group: 0x0018, element: 0x9004 };
+pub const PULSE_SEQUENCE_NAME: Tag = Tag { group: 0x0018, element: 0x9005 };
+pub const MR_IMAGING_MODIFIER_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9006 };
+pub const ECHO_PULSE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9008 };
+pub const INVERSION_RECOVERY: Tag = Tag { group: 0x0018, element: 0x9009 };
+pub const FLOW_COMPENSATION: Tag = Tag { group: 0x0018, element: 0x9010 };
+pub const MULTIPLE_SPIN_ECHO: Tag = Tag { group: 0x0018, element: 0x9011 };
+pub const MULTI_PLANAR_EXCITATION: Tag = Tag { group: 0x0018, element: 0x9012 };
+pub const PHASE_CONTRAST: Tag = Tag { group: 0x0018, element: 0x9014 };
+pub const TIME_OF_FLIGHT_CONTRAST: Tag = Tag { group: 0x0018, element: 0x9015 };
+pub const SPOILING: Tag = Tag { group: 0x0018, element: 0x9016 };
+pub const STEADY_STATE_PULSE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9017 };
+pub const ECHO_PLANAR_PULSE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9018 };
+pub const TAG_ANGLE_FIRST_AXIS: Tag = Tag { group: 0x0018, element: 0x9019 };
+pub const MAGNETIZATION_TRANSFER: Tag = Tag { group: 0x0018, element: 0x9020 };
+pub const T2_PREPARATION: Tag = Tag { group: 0x0018, element: 0x9021 };
+pub const BLOOD_SIGNAL_NULLING: Tag = Tag { group: 0x0018, element: 0x9022 };
+pub const SATURATION_RECOVERY: Tag = Tag { group: 0x0018, element: 0x9024 };
+pub const SPECTRALLY_SELECTED_SUPPRESSION: Tag = Tag { group: 0x0018, element: 0x9025 };
+pub const SPECTRALLY_SELECTED_EXCITATION: Tag = Tag { group: 0x0018, element: 0x9026 };
+pub const SPATIAL_PRESATURATION: Tag = Tag { group: 0x0018, element: 0x9027 };
+pub const TAGGING: Tag = Tag { group: 0x0018, element: 0x9028 };
+pub const OVERSAMPLING_PHASE: Tag = Tag { group: 0x0018, element: 0x9029 };
+pub const TAG_SPACING_FIRST_DIMENSION: Tag = Tag { group: 0x0018, element: 0x9030 };
+pub const GEOMETRY_OF_K_SPACE_TRAVERSAL: Tag = Tag { group: 0x0018, element: 0x9032 };
+pub const SEGMENTED_K_SPACE_TRAVERSAL: Tag = Tag { group: 0x0018, element: 0x9033 };
+pub const RECTILINEAR_PHASE_ENCODE_REORDERING: Tag = Tag { group: 0x0018, element: 0x9034 };
+pub const TAG_THICKNESS: Tag = Tag { group: 0x0018, element: 0x9035 };
+pub const PARTIAL_FOURIER_DIRECTION: Tag = Tag { group: 0x0018, element: 0x9036 };
+pub const CARDIAC_SYNCHRONIZATION_TECHNIQUE: Tag = Tag { group: 0x0018, element: 0x9037 };
+pub const RECEIVE_COIL_MANUFACTURER_NAME: Tag = Tag { group: 0x0018, element: 0x9041 };
+pub const MR_RECEIVE_COIL_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9042 };
+pub const RECEIVE_COIL_TYPE: Tag = Tag { group: 0x0018, element: 0x9043 };
+pub const QUADRATURE_RECEIVE_COIL: Tag = Tag { group: 0x0018, element: 0x9044 };
+pub const MULTI_COIL_DEFINITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9045 };
+pub const MULTI_COIL_CONFIGURATION: Tag = Tag { group: 0x0018, element: 0x9046 };
+pub const MULTI_COIL_ELEMENT_NAME: Tag = Tag { group: 0x0018, element: 0x9047 };
+pub const MULTI_COIL_ELEMENT_USED: Tag = Tag { group: 0x0018, element: 0x9048 };
+pub const MR_TRANSMIT_COIL_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9049 };
+pub const TRANSMIT_COIL_MANUFACTURER_NAME: Tag = Tag { group: 0x0018, element: 0x9050 };
+pub const TRANSMIT_COIL_TYPE: Tag = Tag { group: 0x0018, element: 0x9051 };
+pub const SPECTRAL_WIDTH: Tag = Tag { group: 0x0018, element: 0x9052 };
+pub const CHEMICAL_SHIFT_REFERENCE: Tag = Tag { group: 0x0018, element: 0x9053 };
+pub const VOLUME_LOCALIZATION_TECHNIQUE: Tag = Tag { group: 0x0018, element: 0x9054 };
+pub const MR_ACQUISITION_FREQUENCY_ENCODING_STEPS: Tag = Tag { group: 0x0018, element: 0x9058 };
+pub const DECOUPLING: Tag = Tag { group: 0x0018, element: 0x9059 };
+pub const DECOUPLED_NUCLEUS: Tag = Tag { group: 0x0018, element: 0x9060 };
+pub const DECOUPLING_FREQUENCY: Tag = Tag { group: 0x0018, element: 0x9061 };
+pub const DECOUPLING_METHOD: Tag = Tag { group: 0x0018, element: 0x9062 };
+pub const DECOUPLING_CHEMICAL_SHIFT_REFERENCE: Tag = Tag { group: 0x0018, element: 0x9063 };
+pub const K_SPACE_FILTERING: Tag = Tag { group: 0x0018, element: 0x9064 };
+pub const TIME_DOMAIN_FILTERING: Tag = Tag { group: 0x0018, element: 0x9065 };
+pub const NUMBER_OF_ZERO_FILLS: Tag = Tag { group: 0x0018, element: 0x9066 };
+pub const BASELINE_CORRECTION: Tag = Tag { group: 0x0018, element: 0x9067 };
+pub const PARALLEL_REDUCTION_FACTOR_IN_PLANE: Tag = Tag { group: 0x0018, element: 0x9069 };
+pub const CARDIAC_RR_INTERVAL_SPECIFIED: Tag = Tag { group: 0x0018, element: 0x9070 };
+pub const ACQUISITION_DURATION: Tag = Tag { group: 0x0018, element: 0x9073 };
+pub const FRAME_ACQUISITION_DATE_TIME: Tag = Tag { group: 0x0018, element: 0x9074 };
+pub const DIFFUSION_DIRECTIONALITY: Tag = Tag { group: 0x0018, element: 0x9075 };
+pub const DIFFUSION_GRADIENT_DIRECTION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9076 };
+pub const PARALLEL_ACQUISITION: Tag = Tag { group: 0x0018, element: 0x9077 };
+pub const PARALLEL_ACQUISITION_TECHNIQUE: Tag = Tag { group: 0x0018, element: 0x9078 };
+pub const INVERSION_TIMES: Tag = Tag { group: 0x0018, element: 0x9079 };
+pub const METABOLITE_MAP_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x9080 };
+pub const PARTIAL_FOURIER: Tag = Tag { group: 0x0018, element: 0x9081 };
+pub const EFFECTIVE_ECHO_TIME: Tag = Tag { group: 0x0018, element: 0x9082 };
+pub const METABOLITE_MAP_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9083 };
+pub const CHEMICAL_SHIFT_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9084 };
+pub const CARDIAC_SIGNAL_SOURCE: Tag = Tag { group: 0x0018, element: 0x9085 };
+pub const DIFFUSION_B_VALUE: Tag = Tag { group: 0x0018, element: 0x9087 };
+pub const DIFFUSION_GRADIENT_ORIENTATION: Tag = Tag { group: 0x0018, element: 0x9089 };
+pub const VELOCITY_ENCODING_DIRECTION: Tag = Tag { group: 0x0018, element: 0x9090 };
+pub const VELOCITY_ENCODING_MINIMUM_VALUE: Tag = Tag { group: 0x0018, element: 0x9091 };
+pub const VELOCITY_ENCODING_ACQUISITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9092 };
+pub const NUMBER_OF_K_SPACE_TRAJECTORIES: Tag = Tag { group: 0x0018, element: 0x9093 };
+pub const COVERAGE_OF_K_SPACE: Tag = Tag { group: 0x0018, element: 0x9094 };
+pub const SPECTROSCOPY_ACQUISITION_PHASE_ROWS: Tag = Tag { group: 0x0018, element: 0x9095 };
+pub const PARALLEL_REDUCTION_FACTOR_IN_PLANE_RETIRED: Tag = Tag { group: 0x0018, element: 0x9096 };
+pub const TRANSMITTER_FREQUENCY: Tag = Tag { group: 0x0018, element: 0x9098 };
+pub const RESONANT_NUCLEUS: Tag = Tag { group: 0x0018, element: 0x9100 };
+pub const FREQUENCY_CORRECTION: Tag = Tag { group: 0x0018, element: 0x9101 };
+pub const MR_SPECTROSCOPY_FOV_GEOMETRY_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9103 };
+pub const SLAB_THICKNESS: Tag = Tag { group: 0x0018, element: 0x9104 };
+pub const SLAB_ORIENTATION: Tag = Tag { group: 0x0018, element: 0x9105 };
+pub const MID_SLAB_POSITION: Tag = Tag { group: 0x0018, element: 0x9106 };
+pub const MR_SPATIAL_SATURATION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9107 };
+pub const MR_TIMING_AND_RELATED_PARAMETERS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9112 };
+pub const MR_ECHO_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9114 };
+pub const MR_MODIFIER_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9115 };
+pub const MR_DIFFUSION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9117 };
+pub const CARDIAC_SYNCHRONIZATION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9118 };
+pub const MR_AVERAGES_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9119 };
+pub const MRFOV_GEOMETRY_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9125 };
+pub const VOLUME_LOCALIZATION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9126 };
+pub const SPECTROSCOPY_ACQUISITION_DATA_COLUMNS: Tag = Tag { group: 0x0018, element: 0x9127 };
+pub const DIFFUSION_ANISOTROPY_TYPE: Tag = Tag { group: 0x0018, element: 0x9147 };
+pub const FRAME_REFERENCE_DATE_TIME: Tag = Tag { group: 0x0018, element: 0x9151 };
+pub const MR_METABOLITE_MAP_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9152 };
+pub const PARALLEL_REDUCTION_FACTOR_OUT_OF_PLANE: Tag = Tag { group: 0x0018, element: 0x9155 };
+pub const SPECTROSCOPY_ACQUISITION_OUT_OF_PLANE_PHASE_STEPS: Tag = Tag { group: 0x0018, element: 0x9159 };
+pub const BULK_MOTION_STATUS: Tag = Tag { group: 0x0018, element: 0x9166 };
+pub const PARALLEL_REDUCTION_FACTOR_SECOND_IN_PLANE: Tag = Tag { group: 0x0018, element: 0x9168 };
+pub const CARDIAC_BEAT_REJECTION_TECHNIQUE: Tag = Tag { group: 0x0018, element: 0x9169 };
+pub const RESPIRATORY_MOTION_COMPENSATION_TECHNIQUE: Tag = Tag { group: 0x0018, element: 0x9170 };
+pub const RESPIRATORY_SIGNAL_SOURCE: Tag = Tag { group: 0x0018, element: 0x9171 };
+pub const BULK_MOTION_COMPENSATION_TECHNIQUE: Tag = Tag { group: 0x0018, element: 0x9172 };
+pub const BULK_MOTION_SIGNAL_SOURCE: Tag = Tag { group: 0x0018, element: 0x9173 };
+pub const APPLICABLE_SAFETY_STANDARD_AGENCY: Tag = Tag { group: 0x0018, element: 0x9174 };
+pub const APPLICABLE_SAFETY_STANDARD_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x9175 };
+pub const OPERATING_MODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9176 };
+pub const OPERATING_MODE_TYPE: Tag = Tag { group: 0x0018, element: 0x9177 };
+pub const OPERATING_MODE: Tag = Tag { group: 0x0018, element: 0x9178 };
+pub const SPECIFIC_ABSORPTION_RATE_DEFINITION: Tag = Tag { group: 0x0018, element: 0x9179 };
+pub const GRADIENT_OUTPUT_TYPE: Tag = Tag { group: 0x0018, element: 0x9180 };
+pub const SPECIFIC_ABSORPTION_RATE_VALUE: Tag = Tag { group: 0x0018, element: 0x9181 };
+pub const GRADIENT_OUTPUT: Tag = Tag { group: 0x0018, element: 0x9182 };
+pub const FLOW_COMPENSATION_DIRECTION: Tag = Tag { group: 0x0018, element: 0x9183 };
+pub const TAGGING_DELAY: Tag = Tag { group: 0x0018, element: 0x9184 };
+pub const RESPIRATORY_MOTION_COMPENSATION_TECHNIQUE_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x9185 };
+pub const RESPIRATORY_SIGNAL_SOURCE_ID: Tag = Tag { group: 0x0018, element: 0x9186 };
+pub const CHEMICAL_SHIFT_MINIMUM_INTEGRATION_LIMIT_IN_HZ: Tag = Tag { group: 0x0018, element: 0x9195 };
+pub const CHEMICAL_SHIFT_MAXIMUM_INTEGRATION_LIMIT_IN_HZ: Tag = Tag { group: 0x0018, element: 0x9196 };
+pub const MR_VELOCITY_ENCODING_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9197 };
+pub const FIRST_ORDER_PHASE_CORRECTION: Tag = Tag { group: 0x0018, element: 0x9198 };
+pub const WATER_REFERENCED_PHASE_CORRECTION: Tag = Tag { group: 0x0018, element: 0x9199 };
+pub const MR_SPECTROSCOPY_ACQUISITION_TYPE: Tag = Tag { group: 0x0018, element: 0x9200 };
+pub const RESPIRATORY_CYCLE_POSITION: Tag = Tag { group: 0x0018, element: 0x9214 };
+pub const VELOCITY_ENCODING_MAXIMUM_VALUE: Tag = Tag { group: 0x0018, element: 0x9217 };
+pub const TAG_SPACING_SECOND_DIMENSION: Tag = Tag { group: 0x0018, element: 0x9218 };
+pub const TAG_ANGLE_SECOND_AXIS: Tag = Tag { group: 0x0018, element: 0x9219 };
+pub const FRAME_ACQUISITION_DURATION: Tag = Tag { group: 0x0018, element: 0x9220 };
+pub const MR_IMAGE_FRAME_TYPE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9226 };
+pub const MR_SPECTROSCOPY_FRAME_TYPE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9227 };
+pub const MR_ACQUISITION_PHASE_ENCODING_STEPS_IN_PLANE: Tag = Tag { group: 0x0018, element: 0x9231 };
+pub const MR_ACQUISITION_PHASE_ENCODING_STEPS_OUT_OF_PLANE: Tag = Tag { group: 0x0018, element: 0x9232 };
+pub const SPECTROSCOPY_ACQUISITION_PHASE_COLUMNS: Tag = Tag { group: 0x0018, element: 0x9234 };
+pub const CARDIAC_CYCLE_POSITION: Tag = Tag { group: 0x0018, element: 0x9236 };
+pub const SPECIFIC_ABSORPTION_RATE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9239 };
+pub const RF_ECHO_TRAIN_LENGTH: Tag = Tag { group: 0x0018, element: 0x9240 };
+pub const GRADIENT_ECHO_TRAIN_LENGTH: Tag = Tag { group: 0x0018, element: 0x9241 };
+pub const ARTERIAL_SPIN_LABELING_CONTRAST: Tag = Tag { group: 0x0018, element: 0x9250 };
+pub const MR_ARTERIAL_SPIN_LABELING_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9251 };
+pub const ASL_TECHNIQUE_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x9252 };
+pub const ASL_SLAB_NUMBER: Tag = Tag { group: 0x0018, element: 0x9253 };
+pub const ASL_SLAB_THICKNESS: Tag = Tag { group: 0x0018, element: 0x9254 };
+pub const ASL_SLAB_ORIENTATION: Tag = Tag { group: 0x0018, element: 0x9255 };
+pub const ASL_MID_SLAB_POSITION: Tag = Tag { group: 0x0018, element: 0x9256 };
+pub const ASL_CONTEXT: Tag = Tag { group: 0x0018, element: 0x9257 };
+pub const ASL_PULSE_TRAIN_DURATION: Tag = Tag { group: 0x0018, element: 0x9258 };
+pub const ASL_CRUSHER_FLAG: Tag = Tag { group: 0x0018, element: 0x9259 };
+pub const ASL_CRUSHER_FLOW_LIMIT: Tag = Tag { group: 0x0018, element: 0x925A };
+pub const ASL_CRUSHER_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x925B };
+pub const ASL_BOLUS_CUTOFF_FLAG: Tag = Tag { group: 0x0018, element: 0x925C };
+pub const ASL_BOLUS_CUTOFF_TIMING_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x925D };
+pub const ASL_BOLUS_CUTOFF_TECHNIQUE: Tag = Tag { group: 0x0018, element: 0x925E };
+pub const ASL_BOLUS_CUTOFF_DELAY_TIME: Tag = Tag { group: 0x0018, element: 0x925F };
+pub const ASL_SLAB_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9260 };
+pub const CHEMICAL_SHIFT_MINIMUM_INTEGRATION_LIMIT_INPPM: Tag = Tag { group: 0x0018, element: 0x9295 };
+pub const CHEMICAL_SHIFT_MAXIMUM_INTEGRATION_LIMIT_INPPM: Tag = Tag { group: 0x0018, element: 0x9296 };
+pub const WATER_REFERENCE_ACQUISITION: Tag = Tag { group: 0x0018, element: 0x9297 };
+pub const ECHO_PEAK_POSITION: Tag = Tag { group: 0x0018, element: 0x9298 };
+pub const CT_ACQUISITION_TYPE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9301 };
+pub const ACQUISITION_TYPE: Tag = Tag { group: 0x0018, element: 0x9302 };
+pub const TUBE_ANGLE: Tag = Tag { group: 0x0018, element: 0x9303 };
+pub const CT_ACQUISITION_DETAILS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9304 };
+pub const REVOLUTION_TIME: Tag = Tag { group: 0x0018, element: 0x9305 };
+pub const SINGLE_COLLIMATION_WIDTH: Tag = Tag { group: 0x0018, element: 0x9306 };
+pub const TOTAL_COLLIMATION_WIDTH: Tag = Tag { group: 0x0018, element: 0x9307 };
+pub const CT_TABLE_DYNAMICS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9308 };
+pub const TABLE_SPEED: Tag = Tag { group: 0x0018, element: 0x9309 };
+pub const TABLE_FEED_PER_ROTATION: Tag = Tag { group: 0x0018, element: 0x9310 };
+pub const SPIRAL_PITCH_FACTOR: Tag = Tag { group: 0x0018, element: 0x9311 };
+pub const CT_GEOMETRY_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9312 };
+pub const DATA_COLLECTION_CENTER_PATIENT: Tag = Tag { group: 0x0018, element: 0x9313 };
+pub const CT_RECONSTRUCTION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9314 };
+pub const RECONSTRUCTION_ALGORITHM: Tag = Tag { group: 0x0018, element: 0x9315 };
+pub const CONVOLUTION_KERNEL_GROUP: Tag = Tag { group: 0x0018, element: 0x9316 };
+pub const RECONSTRUCTION_FIELD_OF_VIEW: Tag = Tag { group: 0x0018, element: 0x9317 };
+pub const RECONSTRUCTION_TARGET_CENTER_PATIENT: Tag = Tag { group: 0x0018, element: 0x9318 };
+pub const RECONSTRUCTION_ANGLE: Tag = Tag { group: 0x0018, element: 0x9319 };
+pub const IMAGE_FILTER: Tag = Tag { group: 0x0018, element: 0x9320 };
+pub const CT_EXPOSURE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9321 };
+pub const RECONSTRUCTION_PIXEL_SPACING: Tag = Tag { group: 0x0018, element: 0x9322 };
+pub const EXPOSURE_MODULATION_TYPE: Tag = Tag { group: 0x0018, element: 0x9323 };
+pub const ESTIMATED_DOSE_SAVING: Tag = Tag { group: 0x0018, element: 0x9324 };
+pub const CTX_RAY_DETAILS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9325 };
+pub const CT_POSITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9326 };
+pub const TABLE_POSITION: Tag = Tag { group: 0x0018, element: 0x9327 };
+pub const EXPOSURE_TIME_INMS: Tag = Tag { group: 0x0018, element: 0x9328 };
+pub const CT_IMAGE_FRAME_TYPE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9329 };
+pub const X_RAY_TUBE_CURRENT_INM_A: Tag = Tag { group: 0x0018, element: 0x9330 };
+pub const EXPOSURE_INM_AS: Tag = Tag { group: 0x0018, element: 0x9332 };
+pub const CONSTANT_VOLUME_FLAG: Tag = Tag { group: 0x0018, element: 0x9333 };
+pub const FLUOROSCOPY_FLAG: Tag = Tag { group: 0x0018, element: 0x9334 };
+pub const DISTANCE_SOURCE_TO_DATA_COLLECTION_CENTER: Tag = Tag { group: 0x0018, element: 0x9335 };
+pub const CONTRAST_BOLUS_AGENT_NUMBER: Tag = Tag { group: 0x0018, element: 0x9337 };
+pub const CONTRAST_BOLUS_INGREDIENT_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9338 };
+pub const CONTRAST_ADMINISTRATION_PROFILE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9340 };
+pub const CONTRAST_BOLUS_USAGE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9341 };
+pub const CONTRAST_BOLUS_AGENT_ADMINISTERED: Tag = Tag { group: 0x0018, element: 0x9342 };
+pub const CONTRAST_BOLUS_AGENT_DETECTED: Tag = Tag { group: 0x0018, element: 0x9343 };
+pub const CONTRAST_BOLUS_AGENT_PHASE: Tag = Tag { group: 0x0018, element: 0x9344 };
+pub const CTD_IVOL: Tag = Tag { group: 0x0018, element: 0x9345 };
+pub const CTDI_PHANTOM_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9346 };
+pub const CALCIUM_SCORING_MASS_FACTOR_PATIENT: Tag = Tag { group: 0x0018, element: 0x9351 };
+pub const CALCIUM_SCORING_MASS_FACTOR_DEVICE: Tag = Tag { group: 0x0018, element: 0x9352 };
+pub const ENERGY_WEIGHTING_FACTOR: Tag = Tag { group: 0x0018, element: 0x9353 };
+pub const CT_ADDITIONAL_X_RAY_SOURCE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9360 };
+pub const MULTIENERGY_CT_ACQUISITION: Tag = Tag { group: 0x0018, element: 0x9361 };
+pub const X_RAY_SOURCE_ID: Tag = Tag { group: 0x0018, element: 0x9367 };
+pub const X_RAY_DETECTOR_ID: Tag = Tag { group: 0x0018, element: 0x9371 };
+pub const X_RAY_DETECTOR_LABEL: Tag = Tag { group: 0x0018, element: 0x9373 };
+pub const MULTIENERGY_ACQUISITION_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x937B };
+pub const DECOMPOSITION_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x937F };
+pub const PROJECTION_PIXEL_CALIBRATION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9401 };
+pub const DISTANCE_SOURCE_TO_ISOCENTER: Tag = Tag { group: 0x0018, element: 0x9402 };
+pub const DISTANCE_OBJECT_TO_TABLE_TOP: Tag = Tag { group: 0x0018, element: 0x9403 };
+pub const OBJECT_PIXEL_SPACING_IN_CENTER_OF_BEAM: Tag = Tag { group: 0x0018, element: 0x9404 };
+pub const POSITIONER_POSITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9405 };
+pub const TABLE_POSITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9406 };
+pub const COLLIMATOR_SHAPE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9407 };
+pub const PLANES_IN_ACQUISITION: Tag = Tag { group: 0x0018, element: 0x9410 };
+pub const XAXRF_FRAME_CHARACTERISTICS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9412 };
+pub const FRAME_ACQUISITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9417 };
+pub const X_RAY_RECEPTOR_TYPE: Tag = Tag { group: 0x0018, element: 0x9420 };
+pub const ACQUISITION_PROTOCOL_NAME: Tag = Tag { group: 0x0018, element: 0x9423 };
+pub const ACQUISITION_PROTOCOL_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x9424 };
+pub const CONTRAST_BOLUS_INGREDIENT_OPAQUE: Tag = Tag { group: 0x0018, element: 0x9425 };
+pub const DISTANCE_RECEPTOR_PLANE_TO_DETECTOR_HOUSING: Tag = Tag { group: 0x0018, element: 0x9426 };
+pub const INTENSIFIER_ACTIVE_SHAPE: Tag = Tag { group: 0x0018, element: 0x9427 };
+pub const INTENSIFIER_ACTIVE_DIMENSIONS: Tag = Tag { group: 0x0018, element: 0x9428 };
+pub const PHYSICAL_DETECTOR_SIZE: Tag = Tag { group: 0x0018, element: 0x9429 };
+pub const POSITION_OF_ISOCENTER_PROJECTION: Tag = Tag { group: 0x0018, element: 0x9430 };
+pub const FIELD_OF_VIEW_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9432 };
+pub const FIELD_OF_VIEW_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x9433 };
+pub const EXPOSURE_CONTROL_SENSING_REGIONS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9434 };
+pub const EXPOSURE_CONTROL_SENSING_REGION_SHAPE: Tag = Tag { group: 0x0018, element: 0x9435 };
+pub const EXPOSURE_CONTROL_SENSING_REGION_LEFT_VERTICAL_EDGE: Tag = Tag { group: 0x0018, element: 0x9436 };
+pub const EXPOSURE_CONTROL_SENSING_REGION_RIGHT_VERTICAL_EDGE: Tag = Tag { group: 0x0018, element: 0x9437 };
+pub const EXPOSURE_CONTROL_SENSING_REGION_UPPER_HORIZONTAL_EDGE: Tag = Tag { group: 0x0018, element: 0x9438 };
+pub const EXPOSURE_CONTROL_SENSING_REGION_LOWER_HORIZONTAL_EDGE: Tag = Tag { group: 0x0018, element: 0x9439 };
+pub const CENTER_OF_CIRCULAR_EXPOSURE_CONTROL_SENSING_REGION: Tag = Tag { group: 0x0018, element: 0x9440 };
+pub const RADIUS_OF_CIRCULAR_EXPOSURE_CONTROL_SENSING_REGION: Tag = Tag { group: 0x0018, element: 0x9441 };
+pub const VERTICES_OF_THE_POLYGONAL_EXPOSURE_CONTROL_SENSING_REGION: Tag = Tag { group: 0x0018, element: 0x9442 };
+pub const COLUMN_ANGULATION_PATIENT: Tag = Tag { group: 0x0018, element: 0x9447 };
+pub const BEAM_ANGLE: Tag = Tag { group: 0x0018, element: 0x9449 };
+pub const FRAME_DETECTOR_PARAMETERS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9451 };
+pub const CALCULATED_ANATOMY_THICKNESS: Tag = Tag { group: 0x0018, element: 0x9452 };
+pub const CALIBRATION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9455 };
+pub const OBJECT_THICKNESS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9456 };
+pub const PLANE_IDENTIFICATION: Tag = Tag { group: 0x0018, element: 0x9457 };
+pub const FIELD_OF_VIEW_DIMENSIONS_IN_FLOAT: Tag = Tag { group: 0x0018, element: 0x9461 };
+pub const ISOCENTER_REFERENCE_SYSTEM_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9462 };
+pub const POSITIONER_ISOCENTER_PRIMARY_ANGLE: Tag = Tag { group: 0x0018, element: 0x9463 };
+pub const POSITIONER_ISOCENTER_SECONDARY_ANGLE: Tag = Tag { group: 0x0018, element: 0x9464 };
+pub const POSITIONER_ISOCENTER_DETECTOR_ROTATION_ANGLE: Tag = Tag { group: 0x0018, element: 0x9465 };
+pub const TABLE_X_POSITION_TO_ISOCENTER: Tag = Tag { group: 0x0018, element: 0x9466 };
+pub const TABLE_Y_POSITION_TO_ISOCENTER: Tag = Tag { group: 0x0018, element: 0x9467 };
+pub const TABLE_Z_POSITION_TO_ISOCENTER: Tag = Tag { group: 0x0018, element: 0x9468 };
+pub const TABLE_HORIZONTAL_ROTATION_ANGLE: Tag = Tag { group: 0x0018, element: 0x9469 };
+pub const TABLE_HEAD_TILT_ANGLE: Tag = Tag { group: 0x0018, element: 0x9470 };
+pub const TABLE_CRADLE_TILT_ANGLE: Tag = Tag { group: 0x0018, element: 0x9471 };
+pub const FRAME_DISPLAY_SHUTTER_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9472 };
+pub const ACQUIRED_IMAGE_AREA_DOSE_PRODUCT: Tag = Tag { group: 0x0018, element: 0x9473 };
+pub const C_ARM_POSITIONER_TABLETOP_RELATIONSHIP: Tag = Tag { group: 0x0018, element: 0x9474 };
+pub const X_RAY_GEOMETRY_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9476 };
+pub const IRRADIATION_EVENT_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9477 };
+pub const X_RAY_3D_FRAME_TYPE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9504 };
+pub const CONTRIBUTING_SOURCES_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9506 };
+pub const X_RAY_3D_ACQUISITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9507 };
+pub const PRIMARY_POSITIONER_SCAN_ARC: Tag = Tag { group: 0x0018, element: 0x9508 };
+pub const SECONDARY_POSITIONER_SCAN_ARC: Tag = Tag { group: 0x0018, element: 0x9509 };
+pub const PRIMARY_POSITIONER_SCAN_START_ANGLE: Tag = Tag { group: 0x0018, element: 0x9510 };
+pub const SECONDARY_POSITIONER_SCAN_START_ANGLE: Tag = Tag { group: 0x0018, element: 0x9511 };
+pub const PRIMARY_POSITIONER_INCREMENT: Tag = Tag { group: 0x0018, element: 0x9514 };
+pub const SECONDARY_POSITIONER_INCREMENT: Tag = Tag { group: 0x0018, element: 0x9515 };
+pub const START_ACQUISITION_DATE_TIME: Tag = Tag { group: 0x0018, element: 0x9516 };
+pub const END_ACQUISITION_DATE_TIME: Tag = Tag { group: 0x0018, element: 0x9517 };
+pub const PRIMARY_POSITIONER_INCREMENT_SIGN: Tag = Tag { group: 0x0018, element: 0x9518 };
+pub const SECONDARY_POSITIONER_INCREMENT_SIGN: Tag = Tag { group: 0x0018, element: 0x9519 };
+pub const APPLICATION_NAME: Tag = Tag { group: 0x0018, element: 0x9524 };
+pub const APPLICATION_VERSION: Tag = Tag { group: 0x0018, element: 0x9525 };
+pub const APPLICATION_MANUFACTURER: Tag = Tag { group: 0x0018, element: 0x9526 };
+pub const ALGORITHM_TYPE: Tag = Tag { group: 0x0018, element: 0x9527 };
+pub const ALGORITHM_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x9528 };
+pub const X_RAY_3D_RECONSTRUCTION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9530 };
+pub const RECONSTRUCTION_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0x9531 };
+pub const PER_PROJECTION_ACQUISITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9538 };
+pub const DIFFUSION_B_MATRIX_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9601 };
+pub const DIFFUSION_B_VALUE_XX: Tag = Tag { group: 0x0018, element: 0x9602 };
+pub const DIFFUSION_B_VALUE_XY: Tag = Tag { group: 0x0018, element: 0x9603 };
+pub const DIFFUSION_B_VALUE_XZ: Tag = Tag { group: 0x0018, element: 0x9604 };
+pub const DIFFUSION_B_VALUE_YY: Tag = Tag { group: 0x0018, element: 0x9605 };
+pub const DIFFUSION_B_VALUE_YZ: Tag = Tag { group: 0x0018, element: 0x9606 };
+pub const DIFFUSION_B_VALUE_ZZ: Tag = Tag { group: 0x0018, element: 0x9607 };
+pub const FUNCTIONAL_SYNC_PULSE: Tag = Tag { group: 0x0018, element: 0x9623 };
+pub const DECAY_CORRECTION_DATE_TIME: Tag = Tag { group: 0x0018, element: 0x9701 };
+pub const START_DENSITY_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9715 };
+pub const START_RELATIVE_DENSITY_DIFFERENCE_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9716 };
+pub const START_CARDIAC_TRIGGER_COUNT_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9717 };
+pub const START_RESPIRATORY_TRIGGER_COUNT_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9718 };
+pub const TERMINATION_COUNTS_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9719 };
+pub const TERMINATION_DENSITY_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9720 };
+pub const TERMINATION_RELATIVE_DENSITY_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9721 };
+pub const TERMINATION_TIME_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9722 };
+pub const TERMINATION_CARDIAC_TRIGGER_COUNT_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9723 };
+pub const TERMINATION_RESPIRATORY_TRIGGER_COUNT_THRESHOLD: Tag = Tag { group: 0x0018, element: 0x9724 };
+pub const DETECTOR_GEOMETRY: Tag = Tag { group: 0x0018, element: 0x9725 };
+pub const TRANSVERSE_DETECTOR_SEPARATION: Tag = Tag { group: 0x0018, element: 0x9726 };
+pub const AXIAL_DETECTOR_DIMENSION: Tag = Tag { group: 0x0018, element: 0x9727 };
+pub const RADIOPHARMACEUTICAL_AGENT_NUMBER: Tag = Tag { group: 0x0018, element: 0x9729 };
+pub const PET_FRAME_ACQUISITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9732 };
+pub const PET_DETECTOR_MOTION_DETAILS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9733 };
+pub const PET_TABLE_DYNAMICS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9734 };
+pub const PET_POSITION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9735 };
+pub const PET_FRAME_CORRECTION_FACTORS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9736 };
+pub const RADIOPHARMACEUTICAL_USAGE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9737 };
+pub const ATTENUATION_CORRECTION_SOURCE: Tag = Tag { group: 0x0018, element: 0x9738 };
+pub const NUMBER_OF_ITERATIONS: Tag = Tag { group: 0x0018, element: 0x9739 };
+pub const NUMBER_OF_SUBSETS: Tag = Tag { group: 0x0018, element: 0x9740 };
+pub const PET_RECONSTRUCTION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9749 };
+pub const PET_FRAME_TYPE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9751 };
+pub const TIME_OF_FLIGHT_INFORMATION_USED: Tag = Tag { group: 0x0018, element: 0x9755 };
+pub const RECONSTRUCTION_TYPE: Tag = Tag { group: 0x0018, element: 0x9756 };
+pub const DECAY_CORRECTED: Tag = Tag { group: 0x0018, element: 0x9758 };
+pub const ATTENUATION_CORRECTED: Tag = Tag { group: 0x0018, element: 0x9759 };
+pub const SCATTER_CORRECTED: Tag = Tag { group: 0x0018, element: 0x9760 };
+pub const DEAD_TIME_CORRECTED: Tag = Tag { group: 0x0018, element: 0x9761 };
+pub const GANTRY_MOTION_CORRECTED: Tag = Tag { group: 0x0018, element: 0x9762 };
+pub const PATIENT_MOTION_CORRECTED: Tag = Tag { group: 0x0018, element: 0x9763 };
+pub const COUNT_LOSS_NORMALIZATION_CORRECTED: Tag = Tag { group: 0x0018, element: 0x9764 };
+pub const RANDOMS_CORRECTED: Tag = Tag { group: 0x0018, element: 0x9765 };
+pub const NON_UNIFORM_RADIAL_SAMPLING_CORRECTED: Tag = Tag { group: 0x0018, element: 0x9766 };
+pub const SENSITIVITY_CALIBRATED: Tag = Tag { group: 0x0018, element: 0x9767 };
+pub const DETECTOR_NORMALIZATION_CORRECTION: Tag = Tag { group: 0x0018, element: 0x9768 };
+pub const ITERATIVE_RECONSTRUCTION_METHOD: Tag = Tag { group: 0x0018, element: 0x9769 };
+pub const ATTENUATION_CORRECTION_TEMPORAL_RELATIONSHIP: Tag = Tag { group: 0x0018, element: 0x9770 };
+pub const PATIENT_PHYSIOLOGICAL_STATE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9771 };
+pub const PATIENT_PHYSIOLOGICAL_STATE_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9772 };
+pub const DEPTHS_OF_FOCUS: Tag = Tag { group: 0x0018, element: 0x9801 };
+pub const EXCLUDED_INTERVALS_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9803 };
+pub const EXCLUSION_START_DATE_TIME: Tag = Tag { group: 0x0018, element: 0x9804 };
+pub const EXCLUSION_DURATION: Tag = Tag { group: 0x0018, element: 0x9805 };
+pub const US_IMAGE_DESCRIPTION_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9806 };
+pub const IMAGE_DATA_TYPE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9807 };
+pub const DATA_TYPE: Tag = Tag { group: 0x0018, element: 0x9808 };
+pub const TRANSDUCER_SCAN_PATTERN_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x9809 };
+pub const ALIASED_DATA_TYPE: Tag = Tag { group: 0x0018, element: 0x980B };
+pub const POSITION_MEASURING_DEVICE_USED: Tag = Tag { group: 0x0018, element: 0x980C };
+pub const TRANSDUCER_GEOMETRY_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x980D };
+pub const TRANSDUCER_BEAM_STEERING_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x980E };
+pub const TRANSDUCER_APPLICATION_CODE_SEQUENCE: Tag = Tag { group: 0x0018, element: 0x980F };
+pub const ZERO_VELOCITY_PIXEL_VALUE: Tag = Tag { group: 0x0018, element: 0x9810 };
+pub const CONTRIBUTING_EQUIPMENT_SEQUENCE: Tag = Tag { group: 0x0018, element: 0xA001 };
+pub const CONTRIBUTION_DATE_TIME: Tag = Tag { group: 0x0018, element: 0xA002 };
+pub const CONTRIBUTION_DESCRIPTION: Tag = Tag { group: 0x0018, element: 0xA003 };
 pub const STUDY_INSTANCE_UID: Tag = Tag { group: 0x0020, element: 0x000D };
 pub const SERIES_INSTANCE_UID: Tag = Tag { group: 0x0020, element: 0x000E };
-pub const IMAGE_POSITION: Tag = Tag { group: 0x0020, element: 0x0032 };
-pub const IMAGE_ORIENTATION: Tag = Tag { group: 0x0020, element: 0x0037 };
+pub const STUDY_ID: Tag = Tag { group: 0x0020, element: 0x0010 };
+pub const SERIES_NUMBER: Tag = Tag { group: 0x0020, element: 0x0011 };
+pub const ACQUISITION_NUMBER: Tag = Tag { group: 0x0020, element: 0x0012 };
+pub const INSTANCE_NUMBER: Tag = Tag { group: 0x0020, element: 0x0013 };
+pub const ISOTOPE_NUMBER: Tag = Tag { group: 0x0020, element: 0x0014 };
+pub const PHASE_NUMBER: Tag = Tag { group: 0x0020, element: 0x0015 };
+pub const INTERVAL_NUMBER: Tag = Tag { group: 0x0020, element: 0x0016 };
+pub const TIME_SLOT_NUMBER: Tag = Tag { group: 0x0020, element: 0x0017 };
+pub const ANGLE_NUMBER: Tag = Tag { group: 0x0020, element: 0x0018 };
+pub const ITEM_NUMBER: Tag = Tag { group: 0x0020, element: 0x0019 };
+pub const PATIENT_ORIENTATION: Tag = Tag { group: 0x0020, element: 0x0020 };
+pub const OVERLAY_NUMBER: Tag = Tag { group: 0x0020, element: 0x0022 };
+pub const CURVE_NUMBER: Tag = Tag { group: 0x0020, element: 0x0024 };
+pub const LUT_NUMBER: Tag = Tag { group: 0x0020, element: 0x0026 };
+pub const PYRAMID_LABEL: Tag = Tag { group: 0x0020, element: 0x0027 };
+pub const IMAGE_POSITION: Tag = Tag { group: 0x0020, element: 0x0030 };
+pub const IMAGE_POSITION_PATIENT: Tag = Tag { group: 0x0020, element: 0x0032 };
+pub const IMAGE_ORIENTATION: Tag = Tag { group: 0x0020, element: 0x0035 };
+pub const IMAGE_ORIENTATION_PATIENT: Tag = Tag { group: 0x0020, element: 0x0037 };
+pub const LOCATION: Tag = Tag { group: 0x0020, element: 0x0050 };
+pub const FRAME_OF_REFERENCE_UID: Tag = Tag { group: 0x0020, element: 0x0052 };
+pub const LATERALITY: Tag = Tag { group: 0x0020, element: 0x0060 };
+pub const IMAGE_LATERALITY: Tag = Tag { group: 0x0020, element: 0x0062 };
+pub const IMAGE_GEOMETRY_TYPE: Tag = Tag { group: 0x0020, element: 0x0070 };
+pub const MASKING_IMAGE: Tag = Tag { group: 0x0020, element: 0x0080 };
+pub const REPORT_NUMBER: Tag = Tag { group: 0x0020, element: 0x00AA };
+pub const TEMPORAL_POSITION_IDENTIFIER: Tag = Tag { group: 0x0020, element: 0x0100 };
+pub const NUMBER_OF_TEMPORAL_POSITIONS: Tag = Tag { group: 0x0020, element: 0x0105 };
+pub const TEMPORAL_RESOLUTION: Tag = Tag { group: 0x0020, element: 0x0110 };
+pub const SYNCHRONIZATION_FRAME_OF_REFERENCE_UID: Tag = Tag { group: 0x0020, element: 0x0200 };
+pub const SOP_INSTANCE_UID_OF_CONCATENATION_SOURCE: Tag = Tag { group: 0x0020, element: 0x0242 };
+pub const SERIES_IN_STUDY: Tag = Tag { group: 0x0020, element: 0x1000 };
+pub const ACQUISITIONS_IN_SERIES: Tag = Tag { group: 0x0020, element: 0x1001 };
+pub const IMAGES_IN_ACQUISITION: Tag = Tag { group: 0x0020, element: 0x1002 };
+pub const IMAGES_IN_SERIES: Tag = Tag { group: 0x0020, element: 0x1003 };
+pub const ACQUISITIONS_IN_STUDY: Tag = Tag { group: 0x0020, element: 0x1004 };
+pub const IMAGES_IN_STUDY: Tag = Tag { group: 0x0020, element: 0x1005 };
+pub const REFERENCE: Tag = Tag { group: 0x0020, element: 0x1020 };
+pub const TARGET_POSITION_REFERENCE_INDICATOR: Tag = Tag { group: 0x0020, element: 0x103F };
+pub const POSITION_REFERENCE_INDICATOR: Tag = Tag { group: 0x0020, element: 0x1040 };
+pub const SLICE_LOCATION: Tag = Tag { group: 0x0020, element: 0x1041 };
+pub const OTHER_STUDY_NUMBERS: Tag = Tag { group: 0x0020, element: 0x1070 };
+pub const NUMBER_OF_PATIENT_RELATED_STUDIES: Tag = Tag { group: 0x0020, element: 0x1200 };
+pub const NUMBER_OF_PATIENT_RELATED_SERIES: Tag = Tag { group: 0x0020, element: 0x1202 };
+pub const NUMBER_OF_PATIENT_RELATED_INSTANCES: Tag = Tag { group: 0x0020, element: 0x1204 };
+pub const NUMBER_OF_STUDY_RELATED_SERIES: Tag = Tag { group: 0x0020, element: 0x1206 };
+pub const NUMBER_OF_STUDY_RELATED_INSTANCES: Tag = Tag { group: 0x0020, element: 0x1208 };
+pub const NUMBER_OF_SERIES_RELATED_INSTANCES: Tag = Tag { group: 0x0020, element: 0x1209 };
+pub const MODIFYING_DEVICE_ID: Tag = Tag { group: 0x0020, element: 0x3401 };
+pub const MODIFIED_IMAGE_ID: Tag = Tag { group: 0x0020, element: 0x3402 };
+pub const MODIFIED_IMAGE_DATE: Tag = Tag { group: 0x0020, element: 0x3403 };
+pub const MODIFYING_DEVICE_MANUFACTURER: Tag = Tag { group: 0x0020, element: 0x3404 };
+pub const MODIFIED_IMAGE_TIME: Tag = Tag { group: 0x0020, element: 0x3405 };
+pub const MODIFIED_IMAGE_DESCRIPTION: Tag = Tag { group: 0x0020, element: 0x3406 };
+pub const IMAGE_COMMENTS: Tag = Tag { group: 0x0020, element: 0x4000 };
+pub const ORIGINAL_IMAGE_IDENTIFICATION: Tag = Tag { group: 0x0020, element: 0x5000 };
+pub const ORIGINAL_IMAGE_IDENTIFICATION_NOMENCLATURE: Tag = Tag { group: 0x0020, element: 0x5002 };
+pub const STACK_ID: Tag = Tag { group: 0x0020, element: 0x9056 };
+pub const IN_STACK_POSITION_NUMBER: Tag = Tag { group: 0x0020, element: 0x9057 };
+pub const FRAME_ANATOMY_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9071 };
+pub const FRAME_LATERALITY: Tag = Tag { group: 0x0020, element: 0x9072 };
+pub const FRAME_CONTENT_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9111 };
+pub const PLANE_POSITION_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9113 };
+pub const PLANE_ORIENTATION_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9116 };
+pub const TEMPORAL_POSITION_INDEX: Tag = Tag { group: 0x0020, element: 0x9128 };
+pub const NOMINAL_CARDIAC_TRIGGER_DELAY_TIME: Tag = Tag { group: 0x0020, element: 0x9153 };
+pub const NOMINAL_CARDIAC_TRIGGER_TIME_PRIOR_TO_R_PEAK: Tag = Tag { group: 0x0020, element: 0x9154 };
+pub const ACTUAL_CARDIAC_TRIGGER_TIME_PRIOR_TO_R_PEAK: Tag = Tag { group: 0x0020, element: 0x9155 };
+pub const FRAME_ACQUISITION_NUMBER: Tag = Tag { group: 0x0020, element: 0x9156 };
+pub const DIMENSION_INDEX_VALUES: Tag = Tag { group: 0x0020, element: 0x9157 };
+pub const FRAME_COMMENTS: Tag = Tag { group: 0x0020, element: 0x9158 };
+pub const CONCATENATION_UID: Tag = Tag { group: 0x0020, element: 0x9161 };
+pub const IN_CONCATENATION_NUMBER: Tag = Tag { group: 0x0020, element: 0x9162 };
+pub const IN_CONCATENATION_TOTAL_NUMBER: Tag = Tag { group: 0x0020, element: 0x9163 };
+pub const DIMENSION_ORGANIZATION_UID: Tag = Tag { group: 0x0020, element: 0x9164 };
+pub const DIMENSION_INDEX_POINTER: Tag = Tag { group: 0x0020, element: 0x9165 };
+pub const FUNCTIONAL_GROUP_POINTER: Tag = Tag { group: 0x0020, element: 0x9167 };
+pub const UNASSIGNED_SHARED_CONVERTED_ATTRIBUTES_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9170 };
+pub const UNASSIGNED_PER_FRAME_CONVERTED_ATTRIBUTES_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9171 };
+pub const CONVERSION_SOURCE_ATTRIBUTES_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9172 };
+pub const DIMENSION_INDEX_PRIVATE_CREATOR: Tag = Tag { group: 0x0020, element: 0x9213 };
+pub const DIMENSION_ORGANIZATION_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9221 };
+pub const DIMENSION_INDEX_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9222 };
+pub const CONCATENATION_FRAME_OFFSET_NUMBER: Tag = Tag { group: 0x0020, element: 0x9228 };
+pub const FUNCTIONAL_GROUP_PRIVATE_CREATOR: Tag = Tag { group: 0x0020, element: 0x9238 };
+pub const NOMINAL_PERCENTAGE_OF_CARDIAC_PHASE: Tag = Tag { group: 0x0020, element: 0x9241 };
+pub const NOMINAL_PERCENTAGE_OF_RESPIRATORY_PHASE: Tag = Tag { group: 0x0020, element: 0x9245 };
+pub const STARTING_RESPIRATORY_AMPLITUDE: Tag = Tag { group: 0x0020, element: 0x9246 };
+pub const STARTING_RESPIRATORY_PHASE: Tag = Tag { group: 0x0020, element: 0x9247 };
+pub const ENDING_RESPIRATORY_AMPLITUDE: Tag = Tag { group: 0x0020, element: 0x9248 };
+pub const ENDING_RESPIRATORY_PHASE: Tag = Tag { group: 0x0020, element: 0x9249 };
+pub const RESPIRATORY_TRIGGER_TYPE: Tag = Tag { group: 0x0020, element: 0x9250 };
+pub const RR_INTERVAL_TIME_NOMINAL: Tag = Tag { group: 0x0020, element: 0x9251 };
+pub const ACTUAL_CARDIAC_TRIGGER_DELAY_TIME: Tag = Tag { group: 0x0020, element: 0x9252 };
+pub const RESPIRATORY_SYNCHRONIZATION_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9253 };
+pub const RESPIRATORY_INTERVAL_TIME: Tag = Tag { group: 0x0020, element: 0x9254 };
+pub const NOMINAL_RESPIRATORY_TRIGGER_DELAY_TIME: Tag = Tag { group: 0x0020, element: 0x9255 };
+pub const RESPIRATORY_TRIGGER_DELAY_THRESHOLD: Tag = Tag { group: 0x0020, element: 0x9256 };
+pub const ACTUAL_RESPIRATORY_TRIGGER_DELAY_TIME: Tag = Tag { group: 0x0020, element: 0x9257 };
+pub const IMAGE_POSITION_VOLUME: Tag = Tag { group: 0x0020, element: 0x9301 };
+pub const IMAGE_ORIENTATION_VOLUME: Tag = Tag { group: 0x0020, element: 0x9302 };
+pub const ULTRASOUND_ACQUISITION_GEOMETRY: Tag = Tag { group: 0x0020, element: 0x9307 };
+pub const APEX_POSITION: Tag = Tag { group: 0x0020, element: 0x9308 };
+pub const VOLUME_TO_TRANSDUCER_MAPPING_MATRIX: Tag = Tag { group: 0x0020, element: 0x9309 };
+pub const VOLUME_TO_TABLE_MAPPING_MATRIX: Tag = Tag { group: 0x0020, element: 0x930A };
+pub const VOLUME_TO_TRANSDUCER_RELATIONSHIP: Tag = Tag { group: 0x0020, element: 0x930B };
+pub const PATIENT_FRAME_OF_REFERENCE_SOURCE: Tag = Tag { group: 0x0020, element: 0x930C };
+pub const TEMPORAL_POSITION_TIME_OFFSET: Tag = Tag { group: 0x0020, element: 0x930D };
+pub const PLANE_POSITION_VOLUME_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x930E };
+pub const PLANE_ORIENTATION_VOLUME_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x930F };
+pub const TEMPORAL_POSITION_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9310 };
+pub const DIMENSION_ORGANIZATION_TYPE: Tag = Tag { group: 0x0020, element: 0x9311 };
+pub const VOLUME_FRAME_OF_REFERENCE_UID: Tag = Tag { group: 0x0020, element: 0x9312 };
+pub const TABLE_FRAME_OF_REFERENCE_UID: Tag = Tag { group: 0x0020, element: 0x9313 };
+pub const DIMENSION_DESCRIPTION_LABEL: Tag = Tag { group: 0x0020, element: 0x9421 };
+pub const PATIENT_ORIENTATION_IN_FRAME_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9450 };
+pub const FRAME_LABEL: Tag = Tag { group: 0x0020, element: 0x9453 };
+pub const ACQUISITION_INDEX: Tag = Tag { group: 0x0020, element: 0x9518 };
+pub const CONTRIBUTING_SOP_INSTANCES_REFERENCE_SEQUENCE: Tag = Tag { group: 0x0020, element: 0x9529 };
+pub const RECONSTRUCTION_INDEX: Tag = Tag { group: 0x0020, element: 0x9536 };
+pub const LIGHT_PATH_FILTER_PASS_THROUGH_WAVELENGTH: Tag = Tag { group: 0x0022, element: 0x0001 };
+pub const LIGHT_PATH_FILTER_PASS_BAND: Tag = Tag { group: 0x0022, element: 0x0002 };
+pub const IMAGE_PATH_FILTER_PASS_THROUGH_WAVELENGTH: Tag = Tag { group: 0x0022, element: 0x0003 };
+pub const IMAGE_PATH_FILTER_PASS_BAND: Tag = Tag { group: 0x0022, element: 0x0004 };
+pub const PATIENT_EYE_MOVEMENT_COMMANDED: Tag = Tag { group: 0x0022, element: 0x0005 };
+pub const PATIENT_EYE_MOVEMENT_COMMAND_CODE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x0006 };
+pub const SPHERICAL_LENS_POWER: Tag = Tag { group: 0x0022, element: 0x0007 };
+pub const CYLINDER_LENS_POWER: Tag = Tag { group: 0x0022, element: 0x0008 };
+pub const CYLINDER_AXIS: Tag = Tag { group: 0x0022, element: 0x0009 };
+pub const EMMETROPIC_MAGNIFICATION: Tag = Tag { group: 0x0022, element: 0x000A };
+pub const INTRA_OCULAR_PRESSURE: Tag = Tag { group: 0x0022, element: 0x000B };
+pub const HORIZONTAL_FIELD_OF_VIEW: Tag = Tag { group: 0x0022, element: 0x000C };
+pub const PUPIL_DILATED: Tag = Tag { group: 0x0022, element: 0x000D };
+pub const DEGREE_OF_DILATION: Tag = Tag { group: 0x0022, element: 0x000E };
+pub const STEREO_BASELINE_ANGLE: Tag = Tag { group: 0x0022, element: 0x0010 };
+pub const STEREO_BASELINE_DISPLACEMENT: Tag = Tag { group: 0x0022, element: 0x0011 };
+pub const STEREO_HORIZONTAL_PIXEL_OFFSET: Tag = Tag { group: 0x0022, element: 0x0012 };
+pub const STEREO_VERTICAL_PIXEL_OFFSET: Tag = Tag { group: 0x0022, element: 0x0013 };
+pub const STEREO_ROTATION: Tag = Tag { group: 0x0022, element: 0x0014 };
+pub const ACQUISITION_DEVICE_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x0015 };
+pub const ILLUMINATION_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x0016 };
+pub const LIGHT_PATH_FILTER_TYPE_STACK_CODE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x0017 };
+pub const IMAGE_PATH_FILTER_TYPE_STACK_CODE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x0018 };
+pub const LENSES_CODE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x0019 };
+pub const CHANNEL_DESCRIPTION_CODE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x001A };
+pub const REFRACTIVE_STATE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x001B };
+pub const MYDRIATIC_AGENT_CODE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x001C };
+pub const RELATIVE_IMAGE_POSITION_CODE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x001D };
+pub const CAMERA_ANGLE_OF_VIEW: Tag = Tag { group: 0x0022, element: 0x001E };
+pub const STEREO_PAIRS_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x0020 };
+pub const LEFT_IMAGE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x0021 };
+pub const RIGHT_IMAGE_SEQUENCE: Tag = Tag { group: 0x0022, element: 0x0022 };
 pub const SAMPLES_PER_PIXEL: Tag = Tag { group: 0x0028, element: 0x0002 };
+pub const SAMPLES_PER_PIXEL_USED: Tag = Tag { group: 0x0028, element: 0x0003 };
 pub const PHOTOMETRIC_INTERPRETATION: Tag = Tag { group: 0x0028, element: 0x0004 };
+pub const IMAGE_DIMENSIONS: Tag = Tag { group: 0x0028, element: 0x0005 };
+pub const PLANAR_CONFIGURATION: Tag = Tag { group: 0x0028, element: 0x0006 };
+pub const NUMBER_OF_FRAMES: Tag = Tag { group: 0x0028, element: 0x0008 };
+pub const FRAME_INCREMENT_POINTER: Tag = Tag { group: 0x0028, element: 0x0009 };
+pub const FRAME_DIMENSION_POINTER: Tag = Tag { group: 0x0028, element: 0x000A };
 pub const ROWS: Tag = Tag { group: 0x0028, element: 0x0010 };
 pub const COLUMNS: Tag = Tag { group: 0x0028, element: 0x0011 };
+pub const PLANES: Tag = Tag { group: 0x0028, element: 0x0012 };
+pub const ULTRASOUND_COLOR_DATA_PRESENT: Tag = Tag { group: 0x0028, element: 0x0014 };
 pub const PIXEL_SPACING: Tag = Tag { group: 0x0028, element: 0x0030 };
+pub const ZOOM_FACTOR: Tag = Tag { group: 0x0028, element: 0x0031 };
+pub const ZOOM_CENTER: Tag = Tag { group: 0x0028, element: 0x0032 };
+pub const PIXEL_ASPECT_RATIO: Tag = Tag { group: 0x0028, element: 0x0034 };
+pub const IMAGE_FORMAT: Tag = Tag { group: 0x0028, element: 0x0040 };
+pub const MANIPULATED_IMAGE: Tag = Tag { group: 0x0028, element: 0x0050 };
+pub const CORRECTED_IMAGE: Tag = Tag { group: 0x0028, element: 0x0051 };
+pub const COMPRESSION_RECOGNITION_CODE: Tag = Tag { group: 0x0028, element: 0x005F };
+pub const COMPRESSION_CODE: Tag = Tag { group: 0x0028, element: 0x0060 };
+pub const COMPRESSION_ORIGINATOR: Tag = Tag { group: 0x0028, element: 0x0061 };
+pub const COMPRESSION_LABEL: Tag = Tag { group: 0x0028, element: 0x0062 };
+pub const COMPRESSION_DESCRIPTION: Tag = Tag { group: 0x0028, element: 0x0063 };
+pub const COMPRESSION_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x0065 };
+pub const COMPRESSION_STEP_POINTERS: Tag = Tag { group: 0x0028, element: 0x0066 };
+pub const REPEAT_INTERVAL: Tag = Tag { group: 0x0028, element: 0x0068 };
+pub const BITS_GROUPED: Tag = Tag { group: 0x0028, element: 0x0069 };
+pub const PERIMETER_TABLE: Tag = Tag { group: 0x0028, element: 0x0070 };
+pub const PERIMETER_VALUE: Tag = Tag { group: 0x0028, element: 0x0071 };
+pub const PREDICTOR_ROWS: Tag = Tag { group: 0x0028, element: 0x0080 };
+pub const PREDICTOR_COLUMNS: Tag = Tag { group: 0x0028, element: 0x0081 };
+pub const PREDICTOR_CONSTANTS: Tag = Tag { group: 0x0028, element: 0x0082 };
+pub const BLOCKED_PIXELS: Tag = Tag { group: 0x0028, element: 0x0090 };
+pub const BLOCK_ROWS: Tag = Tag { group: 0x0028, element: 0x0091 };
+pub const BLOCK_COLUMNS: Tag = Tag { group: 0x0028, element: 0x0092 };
+pub const ROW_OVERLAP: Tag = Tag { group: 0x0028, element: 0x0093 };
+pub const COLUMN_OVERLAP: Tag = Tag { group: 0x0028, element: 0x0094 };
 pub const BITS_ALLOCATED: Tag = Tag { group: 0x0028, element: 0x0100 };
 pub const BITS_STORED: Tag = Tag { group: 0x0028, element: 0x0101 };
 pub const HIGH_BIT: Tag = Tag { group: 0x0028, element: 0x0102 };
 pub const PIXEL_REPRESENTATION: Tag = Tag { group: 0x0028, element: 0x0103 };
+pub const SMALLEST_VALID_PIXEL_VALUE: Tag = Tag { group: 0x0028, element: 0x0104 };
+pub const LARGEST_VALID_PIXEL_VALUE: Tag = Tag { group: 0x0028, element: 0x0105 };
+pub const SMALLEST_IMAGE_PIXEL_VALUE: Tag = Tag { group: 0x0028, element: 0x0106 };
+pub const LARGEST_IMAGE_PIXEL_VALUE: Tag = Tag { group: 0x0028, element: 0x0107 };
+pub const SMALLEST_PIXEL_VALUE_IN_SERIES: Tag = Tag { group: 0x0028, element: 0x0108 };
+pub const LARGEST_PIXEL_VALUE_IN_SERIES: Tag = Tag { group: 0x0028, element: 0x0109 };
+pub const SMALLEST_IMAGE_PIXEL_VALUE_IN_PLANE: Tag = Tag { group: 0x0028, element: 0x0110 };
+pub const LARGEST_IMAGE_PIXEL_VALUE_IN_PLANE: Tag = Tag { group: 0x0028, element: 0x0111 };
+pub const PIXEL_PADDING_VALUE: Tag = Tag { group: 0x0028, element: 0x0120 };
+pub const PIXEL_PADDING_RANGE_LIMIT: Tag = Tag { group: 0x0028, element: 0x0121 };
+pub const FLOAT_PIXEL_PADDING_VALUE: Tag = Tag { group: 0x0028, element: 0x0122 };
+pub const DOUBLE_FLOAT_PIXEL_PADDING_VALUE: Tag = Tag { group: 0x0028, element: 0x0123 };
+pub const FLOAT_PIXEL_PADDING_RANGE_LIMIT: Tag = Tag { group: 0x0028, element: 0x0124 };
+pub const DOUBLE_FLOAT_PIXEL_PADDING_RANGE_LIMIT: Tag = Tag { group: 0x0028, element: 0x0125 };
+pub const IMAGE_LOCATION: Tag = Tag { group: 0x0028, element: 0x0200 };
+pub const QUALITY_CONTROL_IMAGE: Tag = Tag { group: 0x0028, element: 0x0300 };
+pub const BURNED_IN_ANNOTATION: Tag = Tag { group: 0x0028, element: 0x0301 };
+pub const RECOGNIZABLE_VISUAL_FEATURES: Tag = Tag { group: 0x0028, element: 0x0302 };
+pub const LONGITUDINAL_TEMPORAL_INFORMATION_MODIFIED: Tag = Tag { group: 0x0028, element: 0x0303 };
+pub const REFERENCED_COLOR_PALETTE_INSTANCE_UID: Tag = Tag { group: 0x0028, element: 0x0304 };
+pub const TRANSFORM_LABEL: Tag = Tag { group: 0x0028, element: 0x0400 };
+pub const TRANSFORM_VERSION_NUMBER: Tag = Tag { group: 0x0028, element: 0x0401 };
+pub const NUMBER_OF_TRANSFORM_STEPS: Tag = Tag { group: 0x0028, element: 0x0402 };
+pub const SEQUENCE_OF_COMPRESSED_DATA: Tag = Tag { group: 0x0028, element: 0x0403 };
+pub const DETAILS_OF_COEFFICIENTS: Tag = Tag { group: 0x0028, element: 0x0404 };
+pub const DCT_LABEL: Tag = Tag { group: 0x0028, element: 0x0700 };
+pub const DATA_BLOCK_DESCRIPTION: Tag = Tag { group: 0x0028, element: 0x0701 };
+pub const DATA_BLOCK: Tag = Tag { group: 0x0028, element: 0x0702 };
+pub const NORMALIZATION_FACTOR_FORMAT: Tag = Tag { group: 0x0028, element: 0x0710 };
+pub const ZONAL_MAP_NUMBER_FORMAT: Tag = Tag { group: 0x0028, element: 0x0720 };
+pub const ZONAL_MAP_LOCATION: Tag = Tag { group: 0x0028, element: 0x0721 };
+pub const ZONAL_MAP_FORMAT: Tag = Tag { group: 0x0028, element: 0x0722 };
+pub const ADAPTIVE_MAP_FORMAT: Tag = Tag { group: 0x0028, element: 0x0730 };
+pub const CODE_NUMBER_FORMAT: Tag = Tag { group: 0x0028, element: 0x0740 };
+pub const PIXEL_SPACING_CALIBRATION_TYPE: Tag = Tag { group: 0x0028, element: 0x0A02 };
+pub const PIXEL_SPACING_CALIBRATION_DESCRIPTION: Tag = Tag { group: 0x0028, element: 0x0A04 };
+pub const PIXEL_INTENSITY_RELATIONSHIP: Tag = Tag { group: 0x0028, element: 0x1040 };
+pub const PIXEL_INTENSITY_RELATIONSHIP_SIGN: Tag = Tag { group: 0x0028, element: 0x1041 };
 pub const WINDOW_CENTER: Tag = Tag { group: 0x0028, element: 0x1050 };
 pub const WINDOW_WIDTH: Tag = Tag { group: 0x0028, element: 0x1051 };
 pub const RESCALE_INTERCEPT: Tag = Tag { group: 0x0028, element: 0x1052 };
 pub const RESCALE_SLOPE: Tag = Tag { group: 0x0028, element: 0x1053 };
-pub const RED_PALETTE_COLOR_LUT_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1101 };
-pub const GREEN_PALETTE_COLOR_LUT_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1102 };
-pub const BLUE_PALETTE_COLOR_LUT_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1103 };
-pub const RED_PALETTE_COLOR_LUT_DATA: Tag = Tag { group: 0x0028, element: 0x1201 };
-pub const GREEN_PALETTE_COLOR_LUT_DATA: Tag = Tag { group: 0x0028, element: 0x1202 };
-pub const BLUE_PALETTE_COLOR_LUT_DATA: Tag = Tag { group: 0x0028, element: 0x1203 };
+pub const RESCALE_TYPE: Tag = Tag { group: 0x0028, element: 0x1054 };
+pub const WINDOW_CENTER_WIDTH_EXPLANATION: Tag = Tag { group: 0x0028, element: 0x1055 };
+pub const VOILUT_FUNCTION: Tag = Tag { group: 0x0028, element: 0x1056 };
+pub const GRAY_SCALE: Tag = Tag { group: 0x0028, element: 0x1080 };
+pub const RECOMMENDED_VIEWING_MODE: Tag = Tag { group: 0x0028, element: 0x1090 };
+pub const GRAY_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1100 };
+pub const RED_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1101 };
+pub const GREEN_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1102 };
+pub const BLUE_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1103 };
+pub const ALPHA_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1104 };
+pub const LARGE_RED_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1111 };
+pub const LARGE_GREEN_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1112 };
+pub const LARGE_BLUE_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1113 };
+pub const PALETTE_COLOR_LOOKUP_TABLE_UID: Tag = Tag { group: 0x0028, element: 0x1199 };
+pub const GRAY_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1200 };
+pub const RED_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1201 };
+pub const GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1202 };
+pub const BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1203 };
+pub const ALPHA_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1204 };
+pub const LARGE_RED_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1211 };
+pub const LARGE_GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1212 };
+pub const LARGE_BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1213 };
+pub const LARGE_PALETTE_COLOR_LOOKUP_TABLE_UID: Tag = Tag { group: 0x0028, element: 0x1214 };
+pub const SEGMENTED_RED_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1221 };
+pub const SEGMENTED_GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1222 };
+pub const SEGMENTED_BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1223 };
+pub const SEGMENTED_ALPHA_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1224 };
+pub const STORED_VALUE_COLOR_RANGE_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x1230 };
+pub const MINIMUM_STORED_VALUE_MAPPED: Tag = Tag { group: 0x0028, element: 0x1231 };
+pub const MAXIMUM_STORED_VALUE_MAPPED: Tag = Tag { group: 0x0028, element: 0x1232 };
+pub const BREAST_IMPLANT_PRESENT: Tag = Tag { group: 0x0028, element: 0x1300 };
+pub const PARTIAL_VIEW: Tag = Tag { group: 0x0028, element: 0x1350 };
+pub const PARTIAL_VIEW_DESCRIPTION: Tag = Tag { group: 0x0028, element: 0x1351 };
+pub const PARTIAL_VIEW_CODE_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x1352 };
+pub const SPATIAL_LOCATIONS_PRESERVED: Tag = Tag { group: 0x0028, element: 0x135A };
+pub const DATA_FRAME_ASSIGNMENT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x1401 };
+pub const DATA_PATH_ASSIGNMENT: Tag = Tag { group: 0x0028, element: 0x1402 };
+pub const BITS_MAPPED_TO_COLOR_LOOKUP_TABLE: Tag = Tag { group: 0x0028, element: 0x1403 };
+pub const BLENDING_LUT1_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x1404 };
+pub const BLENDING_LUT1_TRANSFER_FUNCTION: Tag = Tag { group: 0x0028, element: 0x1405 };
+pub const BLENDING_WEIGHT_CONSTANT: Tag = Tag { group: 0x0028, element: 0x1406 };
+pub const BLENDING_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x1407 };
+pub const BLENDING_LOOKUP_TABLE_DATA: Tag = Tag { group: 0x0028, element: 0x1408 };
+pub const ENHANCED_PALETTE_COLOR_LOOKUP_TABLE_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x140B };
+pub const BLENDING_LUT2_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x140C };
+pub const BLENDING_LUT2_TRANSFER_FUNCTION: Tag = Tag { group: 0x0028, element: 0x140D };
+pub const DATA_PATH_ID: Tag = Tag { group: 0x0028, element: 0x140E };
+pub const RGBLUT_TRANSFER_FUNCTION: Tag = Tag { group: 0x0028, element: 0x140F };
+pub const ALPHA_LUT_TRANSFER_FUNCTION: Tag = Tag { group: 0x0028, element: 0x1410 };
+pub const ICC_PROFILE: Tag = Tag { group: 0x0028, element: 0x2000 };
+pub const COLOR_SPACE: Tag = Tag { group: 0x0028, element: 0x2002 };
+pub const LOSSY_IMAGE_COMPRESSION: Tag = Tag { group: 0x0028, element: 0x2110 };
+pub const LOSSY_IMAGE_COMPRESSION_RATIO: Tag = Tag { group: 0x0028, element: 0x2112 };
+pub const LOSSY_IMAGE_COMPRESSION_METHOD: Tag = Tag { group: 0x0028, element: 0x2114 };
+pub const MODALITY_LUT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x3000 };
+pub const VARIABLE_MODALITY_LUT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x3001 };
+pub const LUT_DESCRIPTOR: Tag = Tag { group: 0x0028, element: 0x3002 };
+pub const LUT_EXPLANATION: Tag = Tag { group: 0x0028, element: 0x3003 };
+pub const MODALITY_LUT_TYPE: Tag = Tag { group: 0x0028, element: 0x3004 };
+pub const LUT_DATA: Tag = Tag { group: 0x0028, element: 0x3006 };
+pub const VOILUT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x3010 };
+pub const SOFTCOPY_VOILUT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x3110 };
+pub const IMAGE_PRESENTATION_COMMENTS: Tag = Tag { group: 0x0028, element: 0x4000 };
+pub const BI_PLANE_ACQUISITION_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x5000 };
+pub const REPRESENTATIVE_FRAME_NUMBER: Tag = Tag { group: 0x0028, element: 0x6010 };
+pub const FRAME_NUMBERS_OF_INTEREST: Tag = Tag { group: 0x0028, element: 0x6020 };
+pub const FRAME_OF_INTEREST_DESCRIPTION: Tag = Tag { group: 0x0028, element: 0x6022 };
+pub const FRAME_OF_INTEREST_TYPE: Tag = Tag { group: 0x0028, element: 0x6023 };
+pub const MASK_POINTERS: Tag = Tag { group: 0x0028, element: 0x6030 };
+pub const R_WAVE_POINTER: Tag = Tag { group: 0x0028, element: 0x6040 };
+pub const MASK_SUBTRACTION_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x6100 };
+pub const MASK_OPERATION: Tag = Tag { group: 0x0028, element: 0x6101 };
+pub const APPLICABLE_FRAME_RANGE: Tag = Tag { group: 0x0028, element: 0x6102 };
+pub const MASK_FRAME_NUMBERS: Tag = Tag { group: 0x0028, element: 0x6110 };
+pub const CONTRAST_FRAME_AVERAGING: Tag = Tag { group: 0x0028, element: 0x6112 };
+pub const MASK_SUB_PIXEL_SHIFT: Tag = Tag { group: 0x0028, element: 0x6114 };
+pub const TID_OFFSET: Tag = Tag { group: 0x0028, element: 0x6120 };
+pub const MASK_OPERATION_EXPLANATION: Tag = Tag { group: 0x0028, element: 0x6190 };
+pub const EQUIPMENT_ADMINISTRATOR_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x7000 };
+pub const NUMBER_OF_DISPLAY_SUBSYSTEMS: Tag = Tag { group: 0x0028, element: 0x7001 };
+pub const PIXEL_DATA_PROVIDER_URL: Tag = Tag { group: 0x0028, element: 0x7FE0 };
+pub const DATA_POINT_ROWS: Tag = Tag { group: 0x0028, element: 0x9001 };
+pub const DATA_POINT_COLUMNS: Tag = Tag { group: 0x0028, element: 0x9002 };
+pub const SIGNAL_DOMAIN_COLUMNS: Tag = Tag { group: 0x0028, element: 0x9003 };
+pub const LARGEST_MONOCHROME_PIXEL_VALUE: Tag = Tag { group: 0x0028, element: 0x9099 };
+pub const DATA_REPRESENTATION: Tag = Tag { group: 0x0028, element: 0x9108 };
+pub const PIXEL_MEASURES_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x9110 };
+pub const FRAME_VOILUT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x9132 };
+pub const PIXEL_VALUE_TRANSFORMATION_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x9145 };
+pub const SIGNAL_DOMAIN_ROWS: Tag = Tag { group: 0x0028, element: 0x9235 };
+pub const DISPLAY_FILTER_PERCENTAGE: Tag = Tag { group: 0x0028, element: 0x9411 };
+pub const FRAME_PIXEL_SHIFT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x9415 };
+pub const SUBTRACTION_ITEM_ID: Tag = Tag { group: 0x0028, element: 0x9416 };
+pub const PIXEL_INTENSITY_RELATIONSHIP_LUT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x9422 };
+pub const FRAME_PIXEL_DATA_PROPERTIES_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x9443 };
+pub const GEOMETRICAL_PROPERTIES: Tag = Tag { group: 0x0028, element: 0x9444 };
+pub const GEOMETRIC_MAXIMUM_DISTORTION: Tag = Tag { group: 0x0028, element: 0x9445 };
+pub const IMAGE_PROCESSING_APPLIED: Tag = Tag { group: 0x0028, element: 0x9446 };
+pub const MASK_SELECTION_MODE: Tag = Tag { group: 0x0028, element: 0x9454 };
+pub const LUT_FUNCTION: Tag = Tag { group: 0x0028, element: 0x9474 };
+pub const MASK_VISIBILITY_PERCENTAGE: Tag = Tag { group: 0x0028, element: 0x9478 };
+pub const PIXEL_SHIFT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x9501 };
+pub const REGION_PIXEL_SHIFT_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x9502 };
+pub const VERTICES_OF_THE_REGION: Tag = Tag { group: 0x0028, element: 0x9503 };
+pub const MULTI_FRAME_PRESENTATION_SEQUENCE: Tag = Tag { group: 0x0028, element: 0x9505 };
+pub const PIXEL_SHIFT_FRAME_RANGE: Tag = Tag { group: 0x0028, element: 0x9506 };
+pub const LUT_FRAME_RANGE: Tag = Tag { group: 0x0028, element: 0x9507 };
+pub const IMAGE_TO_EQUIPMENT_MAPPING_MATRIX: Tag = Tag { group: 0x0028, element: 0x9520 };
+pub const EQUIPMENT_COORDINATE_SYSTEM_IDENTIFICATION: Tag = Tag { group: 0x0028, element: 0x9537 };
+pub const STUDY_STATUS_ID: Tag = Tag { group: 0x0032, element: 0x000A };
+pub const STUDY_PRIORITY_ID: Tag = Tag { group: 0x0032, element: 0x000C };
+pub const STUDY_ID_ISSUER: Tag = Tag { group: 0x0032, element: 0x0012 };
+pub const STUDY_VERIFIED_DATE: Tag = Tag { group: 0x0032, element: 0x0032 };
+pub const STUDY_VERIFIED_TIME: Tag = Tag { group: 0x0032, element: 0x0033 };
+pub const STUDY_READ_DATE: Tag = Tag { group: 0x0032, element: 0x0034 };
+pub const STUDY_READ_TIME: Tag = Tag { group: 0x0032, element: 0x0035 };
+pub const SCHEDULED_STUDY_START_DATE: Tag = Tag { group: 0x0032, element: 0x1000 };
+pub const SCHEDULED_STUDY_START_TIME: Tag = Tag { group: 0x0032, element: 0x1001 };
+pub const SCHEDULED_STUDY_STOP_DATE: Tag = Tag { group: 0x0032, element: 0x1010 };
+pub const SCHEDULED_STUDY_STOP_TIME: Tag = Tag { group: 0x0032, element: 0x1011 };
+pub const SCHEDULED_STUDY_LOCATION: Tag = Tag { group: 0x0032, element: 0x1020 };
+pub const SCHEDULED_STUDY_LOCATION_AE_TITLE: Tag = Tag { group: 0x0032, element: 0x1021 };
+pub const REASON_FOR_STUDY: Tag = Tag { group: 0x0032, element: 0x1030 };
+pub const REQUESTING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0032, element: 0x1031 };
+pub const REQUESTING_PHYSICIAN: Tag = Tag { group: 0x0032, element: 0x1032 };
+pub const REQUESTING_SERVICE: Tag = Tag { group: 0x0032, element: 0x1033 };
+pub const REQUESTING_SERVICE_CODE_SEQUENCE: Tag = Tag { group: 0x0032, element: 0x1034 };
+pub const STUDY_ARRIVAL_DATE: Tag = Tag { group: 0x0032, element: 0x1040 };
+pub const STUDY_ARRIVAL_TIME: Tag = Tag { group: 0x0032, element: 0x1041 };
+pub const STUDY_COMPLETION_DATE: Tag = Tag { group: 0x0032, element: 0x1050 };
+pub const STUDY_COMPLETION_TIME: Tag = Tag { group: 0x0032, element: 0x1051 };
+pub const STUDY_COMPONENT_STATUS_ID: Tag = Tag { group: 0x0032, element: 0x1055 };
+pub const REQUESTED_PROCEDURE_DESCRIPTION: Tag = Tag { group: 0x0032, element: 0x1060 };
+pub const REQUESTED_PROCEDURE_CODE_SEQUENCE: Tag = Tag { group: 0x0032, element: 0x1064 };
+pub const REASON_FOR_VISIT: Tag = Tag { group: 0x0032, element: 0x1066 };
+pub const REASON_FOR_VISIT_CODE_SEQUENCE: Tag = Tag { group: 0x0032, element: 0x1067 };
+pub const REQUESTED_CONTRAST_AGENT: Tag = Tag { group: 0x0032, element: 0x1070 };
+pub const STUDY_COMMENTS: Tag = Tag { group: 0x0032, element: 0x4000 };
+pub const REFERENCED_PATIENT_ALIAS_SEQUENCE: Tag = Tag { group: 0x0038, element: 0x0004 };
+pub const VISIT_STATUS_ID: Tag = Tag { group: 0x0038, element: 0x0008 };
+pub const ADMISSION_ID: Tag = Tag { group: 0x0038, element: 0x0010 };
+pub const ISSUER_OF_ADMISSION_ID: Tag = Tag { group: 0x0038, element: 0x0011 };
+pub const ISSUER_OF_ADMISSION_ID_SEQUENCE: Tag = Tag { group: 0x0038, element: 0x0014 };
+pub const ROUTE_OF_ADMISSIONS: Tag = Tag { group: 0x0038, element: 0x0016 };
+pub const SCHEDULED_ADMISSION_DATE: Tag = Tag { group: 0x0038, element: 0x001A };
+pub const SCHEDULED_ADMISSION_TIME: Tag = Tag { group: 0x0038, element: 0x001B };
+pub const SCHEDULED_DISCHARGE_DATE: Tag = Tag { group: 0x0038, element: 0x001C };
+pub const SCHEDULED_DISCHARGE_TIME: Tag = Tag { group: 0x0038, element: 0x001D };
+pub const SCHEDULED_PATIENT_INSTITUTION_RESIDENCE: Tag = Tag { group: 0x0038, element: 0x001E };
+pub const ADMITTING_DATE: Tag = Tag { group: 0x0038, element: 0x0020 };
+pub const ADMITTING_TIME: Tag = Tag { group: 0x0038, element: 0x0021 };
+pub const DISCHARGE_DATE: Tag = Tag { group: 0x0038, element: 0x0030 };
+pub const DISCHARGE_TIME: Tag = Tag { group: 0x0038, element: 0x0032 };
+pub const DISCHARGE_DIAGNOSIS_DESCRIPTION: Tag = Tag { group: 0x0038, element: 0x0040 };
+pub const DISCHARGE_DIAGNOSIS_CODE_SEQUENCE: Tag = Tag { group: 0x0038, element: 0x0044 };
+pub const SPECIAL_NEEDS: Tag = Tag { group: 0x0038, element: 0x0050 };
+pub const SERVICE_EPISODE_ID: Tag = Tag { group: 0x0038, element: 0x0060 };
+pub const ISSUER_OF_SERVICE_EPISODE_ID: Tag = Tag { group: 0x0038, element: 0x0061 };
+pub const SERVICE_EPISODE_DESCRIPTION: Tag = Tag { group: 0x0038, element: 0x0062 };
+pub const ISSUER_OF_SERVICE_EPISODE_ID_SEQUENCE: Tag = Tag { group: 0x0038, element: 0x0064 };
+pub const PERTINENT_DOCUMENTS_SEQUENCE: Tag = Tag { group: 0x0038, element: 0x0100 };
+pub const CURRENT_PATIENT_LOCATION: Tag = Tag { group: 0x0038, element: 0x0300 };
+pub const PATIENT_INSTITUTION_RESIDENCE: Tag = Tag { group: 0x0038, element: 0x0400 };
+pub const PATIENT_STATE: Tag = Tag { group: 0x0038, element: 0x0500 };
+pub const PATIENT_CLINICAL_TRIAL_PARTICIPATION_SEQUENCE: Tag = Tag { group: 0x0038, element: 0x0502 };
+pub const VISIT_COMMENTS: Tag = Tag { group: 0x0038, element: 0x4000 };
+pub const WAVEFORM_ORIGINALITY: Tag = Tag { group: 0x003A, element: 0x0004 };
+pub const NUMBER_OF_WAVEFORM_CHANNELS: Tag = Tag { group: 0x003A, element: 0x0005 };
+pub const NUMBER_OF_WAVEFORM_SAMPLES: Tag = Tag { group: 0x003A, element: 0x0010 };
+pub const SAMPLING_FREQUENCY: Tag = Tag { group: 0x003A, element: 0x001A };
+pub const MULTIPLEX_GROUP_LABEL: Tag = Tag { group: 0x003A, element: 0x0020 };
+pub const CHANNEL_DEFINITION_SEQUENCE: Tag = Tag { group: 0x003A, element: 0x0200 };
+pub const WAVEFORM_CHANNEL_NUMBER: Tag = Tag { group: 0x003A, element: 0x0202 };
+pub const CHANNEL_LABEL: Tag = Tag { group: 0x003A, element: 0x0203 };
+pub const CHANNEL_STATUS: Tag = Tag { group: 0x003A, element: 0x0205 };
+pub const CHANNEL_SOURCE_SEQUENCE: Tag = Tag { group: 0x003A, element: 0x0208 };
+pub const CHANNEL_SOURCE_MODIFIERS_SEQUENCE: Tag = Tag { group: 0x003A, element: 0x0209 };
+pub const SOURCE_WAVEFORM_SEQUENCE: Tag = Tag { group: 0x003A, element: 0x020A };
+pub const CHANNEL_DERIVATION_DESCRIPTION: Tag = Tag { group: 0x003A, element: 0x020C };
+pub const CHANNEL_SENSITIVITY: Tag = Tag { group: 0x003A, element: 0x0210 };
+pub const CHANNEL_SENSITIVITY_UNITS_SEQUENCE: Tag = Tag { group: 0x003A, element: 0x0211 };
+pub const CHANNEL_SENSITIVITY_CORRECTION_FACTOR: Tag = Tag { group: 0x003A, element: 0x0212 };
+pub const CHANNEL_BASELINE: Tag = Tag { group: 0x003A, element: 0x0213 };
+pub const CHANNEL_TIME_SKEW: Tag = Tag { group: 0x003A, element: 0x0214 };
+pub const CHANNEL_SAMPLE_SKEW: Tag = Tag { group: 0x003A, element: 0x0215 };
+pub const CHANNEL_OFFSET: Tag = Tag { group: 0x003A, element: 0x0218 };
+pub const WAVEFORM_BITS_STORED: Tag = Tag { group: 0x003A, element: 0x021A };
+pub const FILTER_LOW_FREQUENCY: Tag = Tag { group: 0x003A, element: 0x0220 };
+pub const FILTER_HIGH_FREQUENCY: Tag = Tag { group: 0x003A, element: 0x0221 };
+pub const NOTCH_FILTER_FREQUENCY: Tag = Tag { group: 0x003A, element: 0x0222 };
+pub const NOTCH_FILTER_BANDWIDTH: Tag = Tag { group: 0x003A, element: 0x0223 };
+pub const WAVEFORM_DATA_DISPLAY_SCALE: Tag = Tag { group: 0x003A, element: 0x0230 };
+pub const WAVEFORM_DISPLAY_BACKGROUND_CIE_LAB_VALUE: Tag = Tag { group: 0x003A, element: 0x0231 };
+pub const WAVEFORM_PRESENTATION_GROUP_SEQUENCE: Tag = Tag { group: 0x003A, element: 0x0240 };
+pub const PRESENTATION_GROUP_NUMBER: Tag = Tag { group: 0x003A, element: 0x0241 };
+pub const CHANNEL_DISPLAY_SEQUENCE: Tag = Tag { group: 0x003A, element: 0x0242 };
+pub const CHANNEL_RECOMMENDED_DISPLAY_CIE_LAB_VALUE: Tag = Tag { group: 0x003A, element: 0x0244 };
+pub const CHANNEL_POSITION: Tag = Tag { group: 0x003A, element: 0x0245 };
+pub const DISPLAY_SHADING_FLAG: Tag = Tag { group: 0x003A, element: 0x0246 };
+pub const FRACTIONAL_CHANNEL_DISPLAY_SCALE: Tag = Tag { group: 0x003A, element: 0x0247 };
+pub const ABSOLUTE_CHANNEL_DISPLAY_SCALE: Tag = Tag { group: 0x003A, element: 0x0248 };
+pub const MULTIPLEXED_AUDIO_CHANNELS_DESCRIPTION_CODE_SEQUENCE: Tag = Tag { group: 0x003A, element: 0x0300 };
+pub const CHANNEL_IDENTIFICATION_CODE: Tag = Tag { group: 0x003A, element: 0x0301 };
+pub const CHANNEL_MODE: Tag = Tag { group: 0x003A, element: 0x0302 };
+pub const SCHEDULED_STATION_AE_TITLE: Tag = Tag { group: 0x0040, element: 0x0001 };
+pub const SCHEDULED_PROCEDURE_STEP_START_DATE: Tag = Tag { group: 0x0040, element: 0x0002 };
+pub const SCHEDULED_PROCEDURE_STEP_START_TIME: Tag = Tag { group: 0x0040, element: 0x0003 };
+pub const SCHEDULED_PROCEDURE_STEP_END_DATE: Tag = Tag { group: 0x0040, element: 0x0004 };
+pub const SCHEDULED_PROCEDURE_STEP_END_TIME: Tag = Tag { group: 0x0040, element: 0x0005 };
+pub const SCHEDULED_PERFORMING_PHYSICIAN_NAME: Tag = Tag { group: 0x0040, element: 0x0006 };
+pub const SCHEDULED_PROCEDURE_STEP_DESCRIPTION: Tag = Tag { group: 0x0040, element: 0x0007 };
+pub const SCHEDULED_PROTOCOL_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0008 };
+pub const SCHEDULED_PROCEDURE_STEP_ID: Tag = Tag { group: 0x0040, element: 0x0009 };
+pub const STAGE_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x000A };
+pub const SCHEDULED_PERFORMING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x000B };
+pub const SCHEDULED_STATION_NAME: Tag = Tag { group: 0x0040, element: 0x0010 };
+pub const SCHEDULED_PROCEDURE_STEP_LOCATION: Tag = Tag { group: 0x0040, element: 0x0011 };
+pub const PRE_MEDICATION: Tag = Tag { group: 0x0040, element: 0x0012 };
+pub const SCHEDULED_PROCEDURE_STEP_STATUS: Tag = Tag { group: 0x0040, element: 0x0020 };
+pub const ORDER_PLACER_IDENTIFIER_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0026 };
+pub const ORDER_FILLER_IDENTIFIER_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0027 };
+pub const LOCAL_NAMESPACE_ENTITY_ID: Tag = Tag { group: 0x0040, element: 0x0031 };
+pub const UNIVERSAL_ENTITY_ID: Tag = Tag { group: 0x0040, element: 0x0032 };
+pub const UNIVERSAL_ENTITY_ID_TYPE: Tag = Tag { group: 0x0040, element: 0x0033 };
+pub const IDENTIFIER_TYPE_CODE: Tag = Tag { group: 0x0040, element: 0x0035 };
+pub const ASSIGNING_FACILITY_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0036 };
+pub const ASSIGNING_JURISDICTION_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0039 };
+pub const ASSIGNING_AGENCY_OR_DEPARTMENT_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x003A };
+pub const SCHEDULED_PROCEDURE_STEP_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0100 };
+pub const REFERENCED_NON_IMAGE_COMPOSITE_SOP_INSTANCE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0220 };
+pub const PERFORMED_STATION_AE_TITLE: Tag = Tag { group: 0x0040, element: 0x0241 };
+pub const PERFORMED_STATION_NAME: Tag = Tag { group: 0x0040, element: 0x0242 };
+pub const PERFORMED_LOCATION: Tag = Tag { group: 0x0040, element: 0x0243 };
+pub const PERFORMED_PROCEDURE_STEP_START_DATE: Tag = Tag { group: 0x0040, element: 0x0244 };
+pub const PERFORMED_PROCEDURE_STEP_START_TIME: Tag = Tag { group: 0x0040, element: 0x0245 };
+pub const PERFORMED_PROCEDURE_STEP_END_DATE: Tag = Tag { group: 0x0040, element: 0x0250 };
+pub const PERFORMED_PROCEDURE_STEP_END_TIME: Tag = Tag { group: 0x0040, element: 0x0251 };
+pub const PERFORMED_PROCEDURE_STEP_STATUS: Tag = Tag { group: 0x0040, element: 0x0252 };
+pub const PERFORMED_PROCEDURE_STEP_ID: Tag = Tag { group: 0x0040, element: 0x0253 };
+pub const PERFORMED_PROCEDURE_STEP_DESCRIPTION: Tag = Tag { group: 0x0040, element: 0x0254 };
+pub const PERFORMED_PROCEDURE_TYPE_DESCRIPTION: Tag = Tag { group: 0x0040, element: 0x0255 };
+pub const PERFORMED_PROTOCOL_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0260 };
+pub const PERFORMED_PROTOCOL_TYPE: Tag = Tag { group: 0x0040, element: 0x0261 };
+pub const SCHEDULED_STEP_ATTRIBUTES_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0270 };
+pub const REQUEST_ATTRIBUTES_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0275 };
+pub const COMMENTS_ON_THE_PERFORMED_PROCEDURE_STEP: Tag = Tag { group: 0x0040, element: 0x0280 };
+pub const PERFORMED_PROCEDURE_STEP_DISCONTINUATION_REASON_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0281 };
+pub const QUANTITY_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0293 };
+pub const QUANTITY: Tag = Tag { group: 0x0040, element: 0x0294 };
+pub const MEASURING_UNITS_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0295 };
+pub const BILLING_ITEM_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0296 };
+pub const TOTAL_TIME_OF_FLUOROSCOPY: Tag = Tag { group: 0x0040, element: 0x0300 };
+pub const TOTAL_NUMBER_OF_EXPOSURES: Tag = Tag { group: 0x0040, element: 0x0301 };
+pub const ENTRANCE_DOSE: Tag = Tag { group: 0x0040, element: 0x0302 };
+pub const EXPOSED_AREA: Tag = Tag { group: 0x0040, element: 0x0303 };
+pub const DISTANCE_SOURCE_TO_ENTRANCE: Tag = Tag { group: 0x0040, element: 0x0306 };
+pub const DISTANCE_SOURCE_TO_SUPPORT: Tag = Tag { group: 0x0040, element: 0x0307 };
+pub const EXPOSURE_DOSE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x030E };
+pub const COMMENTS_ON_RADIATION_DOSE: Tag = Tag { group: 0x0040, element: 0x0310 };
+pub const X_RAY_OUTPUT: Tag = Tag { group: 0x0040, element: 0x0312 };
+pub const HALF_VALUE_LAYER: Tag = Tag { group: 0x0040, element: 0x0314 };
+pub const ORGAN_DOSE: Tag = Tag { group: 0x0040, element: 0x0316 };
+pub const ORGAN_EXPOSED: Tag = Tag { group: 0x0040, element: 0x0318 };
+pub const BILLING_PROCEDURES_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0320 };
+pub const FILM_CONSUMPTION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0321 };
+pub const BILLING_SUPPLIES_AND_DEVICES_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0324 };
+pub const REFERENCED_PROCEDURE_STEP_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0330 };
+pub const PERFORMED_SERIES_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0340 };
+pub const COMMENTS_ON_THE_SCHEDULED_PROCEDURE_STEP: Tag = Tag { group: 0x0040, element: 0x0400 };
+pub const PROTOCOL_CONTEXT_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0440 };
+pub const CONTENT_ITEM_MODIFIER_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0441 };
+pub const SCHEDULED_SPECIMEN_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0500 };
+pub const SPECIMEN_ACCESSION_NUMBER: Tag = Tag { group: 0x0040, element: 0x050A };
+pub const CONTAINER_IDENTIFIER: Tag = Tag { group: 0x0040, element: 0x0512 };
+pub const ISSUER_OF_THE_CONTAINER_IDENTIFIER_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0513 };
+pub const ALTERNATE_CONTAINER_IDENTIFIER_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0515 };
+pub const CONTAINER_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0518 };
+pub const CONTAINER_DESCRIPTION: Tag = Tag { group: 0x0040, element: 0x051A };
+pub const CONTAINER_COMPONENT_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0520 };
+pub const SPECIMEN_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0550 };
+pub const SPECIMEN_IDENTIFIER: Tag = Tag { group: 0x0040, element: 0x0551 };
+pub const SPECIMEN_DESCRIPTION_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0x0552 };
+pub const SPECIMEN_DESCRIPTION_TRIAL: Tag = Tag { group: 0x0040, element: 0x0553 };
+pub const SPECIMEN_UID: Tag = Tag { group: 0x0040, element: 0x0554 };
+pub const ACQUISITION_CONTEXT_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0555 };
+pub const ACQUISITION_CONTEXT_DESCRIPTION: Tag = Tag { group: 0x0040, element: 0x0556 };
+pub const SPECIMEN_DESCRIPTION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0560 };
+pub const ISSUER_OF_THE_SPECIMEN_IDENTIFIER_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0562 };
+pub const SPECIMEN_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x059A };
+pub const SPECIMEN_SHORT_DESCRIPTION: Tag = Tag { group: 0x0040, element: 0x0600 };
+pub const SPECIMEN_DETAILED_DESCRIPTION: Tag = Tag { group: 0x0040, element: 0x0602 };
+pub const SPECIMEN_PREPARATION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0610 };
+pub const SPECIMEN_PREPARATION_STEP_CONTENT_ITEM_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0612 };
+pub const SPECIMEN_LOCALIZATION_CONTENT_ITEM_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0620 };
+pub const SLIDE_IDENTIFIER: Tag = Tag { group: 0x0040, element: 0x06FA };
+pub const WHOLE_SLIDE_MICROSCOPY_IMAGE_FRAME_TYPE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x0710 };
+pub const IMAGE_CENTER_POINT_COORDINATES_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x071A };
+pub const X_OFFSET_IN_SLIDE_COORDINATE_SYSTEM: Tag = Tag { group: 0x0040, element: 0x072A };
+pub const Y_OFFSET_IN_SLIDE_COORDINATE_SYSTEM: Tag = Tag { group: 0x0040, element: 0x073A };
+pub const Z_OFFSET_IN_SLIDE_COORDINATE_SYSTEM: Tag = Tag { group: 0x0040, element: 0x074A };
+pub const PIXEL_SPACING_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x08D8 };
+pub const COORDINATE_SYSTEM_AXIS_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x08DA };
+pub const MEASUREMENT_UNITS_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x08EA };
+pub const VITAL_STAIN_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0x09F8 };
+pub const REQUESTED_PROCEDURE_ID: Tag = Tag { group: 0x0040, element: 0x1001 };
+pub const REASON_FOR_THE_REQUESTED_PROCEDURE: Tag = Tag { group: 0x0040, element: 0x1002 };
+pub const REQUESTED_PROCEDURE_PRIORITY: Tag = Tag { group: 0x0040, element: 0x1003 };
+pub const PATIENT_TRANSPORT_ARRANGEMENTS: Tag = Tag { group: 0x0040, element: 0x1004 };
+pub const REQUESTED_PROCEDURE_LOCATION: Tag = Tag { group: 0x0040, element: 0x1005 };
+pub const PLACER_ORDER_NUMBER_PROCEDURE: Tag = Tag { group: 0x0040, element: 0x1006 };
+pub const FILLER_ORDER_NUMBER_PROCEDURE: Tag = Tag { group: 0x0040, element: 0x1007 };
+pub const CONFIDENTIALITY_CODE: Tag = Tag { group: 0x0040, element: 0x1008 };
+pub const REPORTING_PRIORITY: Tag = Tag { group: 0x0040, element: 0x1009 };
+pub const REASON_FOR_REQUESTED_PROCEDURE_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x100A };
+pub const NAMES_OF_INTENDED_RECIPIENTS_OF_RESULTS: Tag = Tag { group: 0x0040, element: 0x1010 };
+pub const INTENDED_RECIPIENTS_OF_RESULTS_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x1011 };
+pub const REASON_FOR_PERFORMED_PROCEDURE_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x1012 };
+pub const REQUESTED_PROCEDURE_DESCRIPTION_TRIAL: Tag = Tag { group: 0x0040, element: 0x1060 };
+pub const PERSON_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x1101 };
+pub const PERSON_ADDRESS: Tag = Tag { group: 0x0040, element: 0x1102 };
+pub const PERSON_TELEPHONE_NUMBERS: Tag = Tag { group: 0x0040, element: 0x1103 };
+pub const PERSON_TELECOM_INFORMATION: Tag = Tag { group: 0x0040, element: 0x1104 };
+pub const REQUESTED_PROCEDURE_COMMENTS: Tag = Tag { group: 0x0040, element: 0x1400 };
+pub const REASON_FOR_THE_IMAGING_SERVICE_REQUEST: Tag = Tag { group: 0x0040, element: 0x2001 };
+pub const ISSUE_DATE_OF_IMAGING_SERVICE_REQUEST: Tag = Tag { group: 0x0040, element: 0x2004 };
+pub const ISSUE_TIME_OF_IMAGING_SERVICE_REQUEST: Tag = Tag { group: 0x0040, element: 0x2005 };
+pub const PLACER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST_RETIRED: Tag = Tag { group: 0x0040, element: 0x2006 };
+pub const FILLER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST_RETIRED: Tag = Tag { group: 0x0040, element: 0x2007 };
+pub const ORDER_ENTERED_BY: Tag = Tag { group: 0x0040, element: 0x2008 };
+pub const ORDER_ENTERER_LOCATION: Tag = Tag { group: 0x0040, element: 0x2009 };
+pub const ORDER_CALLBACK_PHONE_NUMBER: Tag = Tag { group: 0x0040, element: 0x2010 };
+pub const ORDER_CALLBACK_TELECOM_INFORMATION: Tag = Tag { group: 0x0040, element: 0x2011 };
+pub const PLACER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST: Tag = Tag { group: 0x0040, element: 0x2016 };
+pub const FILLER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST: Tag = Tag { group: 0x0040, element: 0x2017 };
+pub const IMAGING_SERVICE_REQUEST_COMMENTS: Tag = Tag { group: 0x0040, element: 0x2400 };
+pub const CONFIDENTIALITY_CONSTRAINT_ON_PATIENT_DATA_DESCRIPTION: Tag = Tag { group: 0x0040, element: 0x3001 };
+pub const GENERAL_PURPOSE_SCHEDULED_PROCEDURE_STEP_STATUS: Tag = Tag { group: 0x0040, element: 0x4001 };
+pub const GENERAL_PURPOSE_PERFORMED_PROCEDURE_STEP_STATUS: Tag = Tag { group: 0x0040, element: 0x4002 };
+pub const GENERAL_PURPOSE_SCHEDULED_PROCEDURE_STEP_PRIORITY: Tag = Tag { group: 0x0040, element: 0x4003 };
+pub const SCHEDULED_PROCEDURE_STEP_START_DATE_TIME: Tag = Tag { group: 0x0040, element: 0x4005 };
+pub const SCHEDULED_PROCEDURE_STEP_EXPIRATION_DATE_TIME: Tag = Tag { group: 0x0040, element: 0x4008 };
+pub const HUMAN_PERFORMER_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4009 };
+pub const SCHEDULED_PROCEDURE_STEP_MODIFICATION_DATE_TIME: Tag = Tag { group: 0x0040, element: 0x4010 };
+pub const EXPECTED_COMPLETION_DATE_TIME: Tag = Tag { group: 0x0040, element: 0x4011 };
+pub const SCHEDULED_WORKITEM_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4018 };
+pub const PERFORMED_WORKITEM_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4019 };
+pub const INPUT_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4021 };
+pub const REFERENCED_GENERAL_PURPOSE_SCHEDULED_PROCEDURE_STEP_TRANSACTION_UID: Tag = Tag { group: 0x0040, element: 0x4023 };
+pub const SCHEDULED_STATION_NAME_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4025 };
+pub const SCHEDULED_STATION_CLASS_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4026 };
+pub const SCHEDULED_STATION_GEOGRAPHIC_LOCATION_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4027 };
+pub const PERFORMED_STATION_NAME_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4028 };
+pub const PERFORMED_STATION_CLASS_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4029 };
+pub const PERFORMED_STATION_GEOGRAPHIC_LOCATION_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4030 };
+pub const OUTPUT_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4033 };
+pub const SCHEDULED_HUMAN_PERFORMERS_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4034 };
+pub const ACTUAL_HUMAN_PERFORMERS_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x4035 };
+pub const HUMAN_PERFORMER_ORGANIZATION: Tag = Tag { group: 0x0040, element: 0x4036 };
+pub const HUMAN_PERFORMER_NAME: Tag = Tag { group: 0x0040, element: 0x4037 };
+pub const PERFORMED_PROCEDURE_STEP_START_DATE_TIME: Tag = Tag { group: 0x0040, element: 0x4050 };
+pub const PERFORMED_PROCEDURE_STEP_END_DATE_TIME: Tag = Tag { group: 0x0040, element: 0x4051 };
+pub const PROCEDURE_STEP_CANCELLATION_DATE_TIME: Tag = Tag { group: 0x0040, element: 0x4052 };
+pub const ENTRANCE_DOSE_INM_GY: Tag = Tag { group: 0x0040, element: 0x8302 };
+pub const PARAMETRIC_MAP_FRAME_TYPE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x9092 };
+pub const REFERENCED_IMAGE_REAL_WORLD_VALUE_MAPPING_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x9094 };
+pub const REAL_WORLD_VALUE_MAPPING_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x9096 };
+pub const PIXEL_VALUE_MAPPING_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x9098 };
+pub const LUT_LABEL: Tag = Tag { group: 0x0040, element: 0x9210 };
+pub const REAL_WORLD_VALUE_LAST_VALUE_MAPPED: Tag = Tag { group: 0x0040, element: 0x9211 };
+pub const REAL_WORLD_VALUE_LUT_DATA: Tag = Tag { group: 0x0040, element: 0x9212 };
+pub const DOUBLE_FLOAT_REAL_WORLD_VALUE_LAST_VALUE_MAPPED: Tag = Tag { group: 0x0040, element: 0x9213 };
+pub const DOUBLE_FLOAT_REAL_WORLD_VALUE_FIRST_VALUE_MAPPED: Tag = Tag { group: 0x0040, element: 0x9214 };
+pub const REAL_WORLD_VALUE_FIRST_VALUE_MAPPED: Tag = Tag { group: 0x0040, element: 0x9216 };
+pub const QUANTITY_DEFINITION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0x9220 };
+pub const REAL_WORLD_VALUE_INTERCEPT: Tag = Tag { group: 0x0040, element: 0x9224 };
+pub const REAL_WORLD_VALUE_SLOPE: Tag = Tag { group: 0x0040, element: 0x9225 };
+pub const FINDINGS_FLAG_TRIAL: Tag = Tag { group: 0x0040, element: 0xA007 };
+pub const RELATIONSHIP_TYPE: Tag = Tag { group: 0x0040, element: 0xA010 };
+pub const FINDINGS_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA020 };
+pub const FINDINGS_GROUP_UID_TRIAL: Tag = Tag { group: 0x0040, element: 0xA021 };
+pub const REFERENCED_FINDINGS_GROUP_UID_TRIAL: Tag = Tag { group: 0x0040, element: 0xA022 };
+pub const FINDINGS_GROUP_RECORDING_DATE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA023 };
+pub const FINDINGS_GROUP_RECORDING_TIME_TRIAL: Tag = Tag { group: 0x0040, element: 0xA024 };
+pub const FINDINGS_SOURCE_CATEGORY_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA026 };
+pub const VERIFYING_ORGANIZATION: Tag = Tag { group: 0x0040, element: 0xA027 };
+pub const DOCUMENTING_ORGANIZATION_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA028 };
+pub const VERIFICATION_DATE_TIME: Tag = Tag { group: 0x0040, element: 0xA030 };
+pub const OBSERVATION_DATE_TIME: Tag = Tag { group: 0x0040, element: 0xA032 };
+pub const OBSERVATION_START_DATE_TIME: Tag = Tag { group: 0x0040, element: 0xA033 };
+pub const VALUE_TYPE: Tag = Tag { group: 0x0040, element: 0xA040 };
+pub const CONCEPT_NAME_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA043 };
+pub const MEASUREMENT_PRECISION_DESCRIPTION_TRIAL: Tag = Tag { group: 0x0040, element: 0xA047 };
+pub const CONTINUITY_OF_CONTENT: Tag = Tag { group: 0x0040, element: 0xA050 };
+pub const URGENCY_OR_PRIORITY_ALERTS_TRIAL: Tag = Tag { group: 0x0040, element: 0xA057 };
+pub const SEQUENCING_INDICATOR_TRIAL: Tag = Tag { group: 0x0040, element: 0xA060 };
+pub const DOCUMENT_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA066 };
+pub const DOCUMENT_AUTHOR_TRIAL: Tag = Tag { group: 0x0040, element: 0xA067 };
+pub const DOCUMENT_AUTHOR_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA068 };
+pub const IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA070 };
+pub const VERIFYING_OBSERVER_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA073 };
+pub const OBJECT_BINARY_IDENTIFIER_TRIAL: Tag = Tag { group: 0x0040, element: 0xA074 };
+pub const VERIFYING_OBSERVER_NAME: Tag = Tag { group: 0x0040, element: 0xA075 };
+pub const DOCUMENTING_OBSERVER_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA076 };
+pub const AUTHOR_OBSERVER_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA078 };
+pub const PARTICIPANT_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA07A };
+pub const CUSTODIAL_ORGANIZATION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA07C };
+pub const PARTICIPATION_TYPE: Tag = Tag { group: 0x0040, element: 0xA080 };
+pub const PARTICIPATION_DATE_TIME: Tag = Tag { group: 0x0040, element: 0xA082 };
+pub const OBSERVER_TYPE: Tag = Tag { group: 0x0040, element: 0xA084 };
+pub const PROCEDURE_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA085 };
+pub const VERIFYING_OBSERVER_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA088 };
+pub const OBJECT_DIRECTORY_BINARY_IDENTIFIER_TRIAL: Tag = Tag { group: 0x0040, element: 0xA089 };
+pub const EQUIVALENT_CDA_DOCUMENT_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA090 };
+pub const REFERENCED_WAVEFORM_CHANNELS: Tag = Tag { group: 0x0040, element: 0xA0B0 };
+pub const DATE_OF_DOCUMENT_OR_VERBAL_TRANSACTION_TRIAL: Tag = Tag { group: 0x0040, element: 0xA110 };
+pub const TIME_OF_DOCUMENT_CREATION_OR_VERBAL_TRANSACTION_TRIAL: Tag = Tag { group: 0x0040, element: 0xA112 };
+pub const DATE_TIME: Tag = Tag { group: 0x0040, element: 0xA120 };
+pub const DATE: Tag = Tag { group: 0x0040, element: 0xA121 };
+pub const TIME: Tag = Tag { group: 0x0040, element: 0xA122 };
+pub const PERSON_NAME: Tag = Tag { group: 0x0040, element: 0xA123 };
+pub const UID: Tag = Tag { group: 0x0040, element: 0xA124 };
+pub const REPORT_STATUS_ID_TRIAL: Tag = Tag { group: 0x0040, element: 0xA125 };
+pub const TEMPORAL_RANGE_TYPE: Tag = Tag { group: 0x0040, element: 0xA130 };
+pub const REFERENCED_SAMPLE_POSITIONS: Tag = Tag { group: 0x0040, element: 0xA132 };
+pub const REFERENCED_FRAME_NUMBERS: Tag = Tag { group: 0x0040, element: 0xA136 };
+pub const REFERENCED_TIME_OFFSETS: Tag = Tag { group: 0x0040, element: 0xA138 };
+pub const REFERENCED_DATE_TIME: Tag = Tag { group: 0x0040, element: 0xA13A };
+pub const TEXT_VALUE: Tag = Tag { group: 0x0040, element: 0xA160 };
+pub const FLOATING_POINT_VALUE: Tag = Tag { group: 0x0040, element: 0xA161 };
+pub const RATIONAL_NUMERATOR_VALUE: Tag = Tag { group: 0x0040, element: 0xA162 };
+pub const RATIONAL_DENOMINATOR_VALUE: Tag = Tag { group: 0x0040, element: 0xA163 };
+pub const OBSERVATION_CATEGORY_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA167 };
+pub const CONCEPT_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA168 };
+pub const BIBLIOGRAPHIC_CITATION_TRIAL: Tag = Tag { group: 0x0040, element: 0xA16A };
+pub const PURPOSE_OF_REFERENCE_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA170 };
+pub const OBSERVATION_UID: Tag = Tag { group: 0x0040, element: 0xA171 };
+pub const REFERENCED_OBSERVATION_UID_TRIAL: Tag = Tag { group: 0x0040, element: 0xA172 };
+pub const REFERENCED_OBSERVATION_CLASS_TRIAL: Tag = Tag { group: 0x0040, element: 0xA173 };
+pub const REFERENCED_OBJECT_OBSERVATION_CLASS_TRIAL: Tag = Tag { group: 0x0040, element: 0xA174 };
+pub const ANNOTATION_GROUP_NUMBER: Tag = Tag { group: 0x0040, element: 0xA180 };
+pub const OBSERVATION_DATE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA192 };
+pub const OBSERVATION_TIME_TRIAL: Tag = Tag { group: 0x0040, element: 0xA193 };
+pub const MEASUREMENT_AUTOMATION_TRIAL: Tag = Tag { group: 0x0040, element: 0xA194 };
+pub const MODIFIER_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA195 };
+pub const IDENTIFICATION_DESCRIPTION_TRIAL: Tag = Tag { group: 0x0040, element: 0xA224 };
+pub const COORDINATES_SET_GEOMETRIC_TYPE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA290 };
+pub const ALGORITHM_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA296 };
+pub const ALGORITHM_DESCRIPTION_TRIAL: Tag = Tag { group: 0x0040, element: 0xA297 };
+pub const PIXEL_COORDINATES_SET_TRIAL: Tag = Tag { group: 0x0040, element: 0xA29A };
+pub const MEASURED_VALUE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA300 };
+pub const NUMERIC_VALUE_QUALIFIER_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA301 };
+pub const CURRENT_OBSERVER_TRIAL: Tag = Tag { group: 0x0040, element: 0xA307 };
+pub const NUMERIC_VALUE: Tag = Tag { group: 0x0040, element: 0xA30A };
+pub const REFERENCED_ACCESSION_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA313 };
+pub const REPORT_STATUS_COMMENT_TRIAL: Tag = Tag { group: 0x0040, element: 0xA33A };
+pub const PROCEDURE_CONTEXT_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA340 };
+pub const VERBAL_SOURCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA352 };
+pub const ADDRESS_TRIAL: Tag = Tag { group: 0x0040, element: 0xA353 };
+pub const TELEPHONE_NUMBER_TRIAL: Tag = Tag { group: 0x0040, element: 0xA354 };
+pub const VERBAL_SOURCE_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA358 };
+pub const PREDECESSOR_DOCUMENTS_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA360 };
+pub const REFERENCED_REQUEST_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA370 };
+pub const PERFORMED_PROCEDURE_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA372 };
+pub const CURRENT_REQUESTED_PROCEDURE_EVIDENCE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA375 };
+pub const REPORT_DETAIL_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA380 };
+pub const PERTINENT_OTHER_EVIDENCE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA385 };
+pub const HL7_STRUCTURED_DOCUMENT_REFERENCE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA390 };
+pub const OBSERVATION_SUBJECT_UID_TRIAL: Tag = Tag { group: 0x0040, element: 0xA402 };
+pub const OBSERVATION_SUBJECT_CLASS_TRIAL: Tag = Tag { group: 0x0040, element: 0xA403 };
+pub const OBSERVATION_SUBJECT_TYPE_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA404 };
+pub const COMPLETION_FLAG: Tag = Tag { group: 0x0040, element: 0xA491 };
+pub const COMPLETION_FLAG_DESCRIPTION: Tag = Tag { group: 0x0040, element: 0xA492 };
+pub const VERIFICATION_FLAG: Tag = Tag { group: 0x0040, element: 0xA493 };
+pub const ARCHIVE_REQUESTED: Tag = Tag { group: 0x0040, element: 0xA494 };
+pub const PRELIMINARY_FLAG: Tag = Tag { group: 0x0040, element: 0xA496 };
+pub const CONTENT_TEMPLATE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA504 };
+pub const IDENTICAL_DOCUMENTS_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA525 };
+pub const OBSERVATION_SUBJECT_CONTEXT_FLAG_TRIAL: Tag = Tag { group: 0x0040, element: 0xA600 };
+pub const OBSERVER_CONTEXT_FLAG_TRIAL: Tag = Tag { group: 0x0040, element: 0xA601 };
+pub const PROCEDURE_CONTEXT_FLAG_TRIAL: Tag = Tag { group: 0x0040, element: 0xA603 };
+pub const CONTENT_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA730 };
+pub const RELATIONSHIP_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA731 };
+pub const RELATIONSHIP_TYPE_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA732 };
+pub const LANGUAGE_CODE_SEQUENCE_TRIAL: Tag = Tag { group: 0x0040, element: 0xA744 };
+pub const TABULATED_VALUES_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xA801 };
+pub const UNIFORM_RESOURCE_LOCATOR_TRIAL: Tag = Tag { group: 0x0040, element: 0xA992 };
+pub const WAVEFORM_ANNOTATION_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xB020 };
+pub const TEMPLATE_IDENTIFIER: Tag = Tag { group: 0x0040, element: 0xDB00 };
+pub const TEMPLATE_VERSION: Tag = Tag { group: 0x0040, element: 0xDB06 };
+pub const TEMPLATE_LOCAL_VERSION: Tag = Tag { group: 0x0040, element: 0xDB07 };
+pub const TEMPLATE_EXTENSION_FLAG: Tag = Tag { group: 0x0040, element: 0xDB0B };
+pub const TEMPLATE_EXTENSION_ORGANIZATION_UID: Tag = Tag { group: 0x0040, element: 0xDB0C };
+pub const TEMPLATE_EXTENSION_CREATOR_UID: Tag = Tag { group: 0x0040, element: 0xDB0D };
+pub const REFERENCED_CONTENT_ITEM_IDENTIFIER: Tag = Tag { group: 0x0040, element: 0xDB73 };
+pub const HL7_INSTANCE_IDENTIFIER: Tag = Tag { group: 0x0040, element: 0xE001 };
+pub const HL7_DOCUMENT_EFFECTIVE_TIME: Tag = Tag { group: 0x0040, element: 0xE004 };
+pub const HL7_DOCUMENT_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xE006 };
+pub const DOCUMENT_CLASS_CODE_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xE008 };
+pub const RETRIEVE_URI: Tag = Tag { group: 0x0040, element: 0xE010 };
+pub const RETRIEVE_LOCATION_UID: Tag = Tag { group: 0x0040, element: 0xE011 };
+pub const TYPE_OF_INSTANCES: Tag = Tag { group: 0x0040, element: 0xE020 };
+pub const DICOM_RETRIEVAL_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xE021 };
+pub const DICOM_MEDIA_RETRIEVAL_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xE022 };
+pub const WADO_RETRIEVAL_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xE023 };
+pub const XDS_RETRIEVAL_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xE024 };
+pub const WADORS_RETRIEVAL_SEQUENCE: Tag = Tag { group: 0x0040, element: 0xE025 };
+pub const REPOSITORY_UNIQUE_ID: Tag = Tag { group: 0x0040, element: 0xE030 };
+pub const HOME_COMMUNITY_ID: Tag = Tag { group: 0x0040, element: 0xE031 };
+pub const DOCUMENT_TITLE: Tag = Tag { group: 0x0042, element: 0x0010 };
+pub const ENCAPSULATED_DOCUMENT: Tag = Tag { group: 0x0042, element: 0x0011 };
+pub const MIME_TYPE_OF_ENCAPSULATED_DOCUMENT: Tag = Tag { group: 0x0042, element: 0x0012 };
+pub const SOURCE_INSTANCE_SEQUENCE: Tag = Tag { group: 0x0042, element: 0x0013 };
+pub const LIST_OF_MIME_TYPES: Tag = Tag { group: 0x0042, element: 0x0014 };
+pub const ENCAPSULATED_DOCUMENT_LENGTH: Tag = Tag { group: 0x0042, element: 0x0015 };
+pub const CALIBRATION_IMAGE: Tag = Tag { group: 0x0050, element: 0x0004 };
+pub const DEVICE_SEQUENCE: Tag = Tag { group: 0x0050, element: 0x0010 };
+pub const CONTAINER_COMPONENT_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0050, element: 0x0012 };
+pub const CONTAINER_COMPONENT_THICKNESS: Tag = Tag { group: 0x0050, element: 0x0013 };
+pub const DEVICE_LENGTH: Tag = Tag { group: 0x0050, element: 0x0014 };
+pub const CONTAINER_COMPONENT_WIDTH: Tag = Tag { group: 0x0050, element: 0x0015 };
+pub const DEVICE_DIAMETER: Tag = Tag { group: 0x0050, element: 0x0016 };
+pub const DEVICE_DIAMETER_UNITS: Tag = Tag { group: 0x0050, element: 0x0017 };
+pub const DEVICE_VOLUME: Tag = Tag { group: 0x0050, element: 0x0018 };
+pub const INTER_MARKER_DISTANCE: Tag = Tag { group: 0x0050, element: 0x0019 };
+pub const CONTAINER_COMPONENT_MATERIAL: Tag = Tag { group: 0x0050, element: 0x001A };
+pub const CONTAINER_COMPONENT_ID: Tag = Tag { group: 0x0050, element: 0x001B };
+pub const CONTAINER_COMPONENT_LENGTH: Tag = Tag { group: 0x0050, element: 0x001C };
+pub const CONTAINER_COMPONENT_DIAMETER: Tag = Tag { group: 0x0050, element: 0x001D };
+pub const CONTAINER_COMPONENT_DESCRIPTION: Tag = Tag { group: 0x0050, element: 0x001E };
+pub const DEVICE_DESCRIPTION: Tag = Tag { group: 0x0050, element: 0x0020 };
+pub const LONG_DEVICE_DESCRIPTION: Tag = Tag { group: 0x0050, element: 0x0021 };
+pub const ENERGY_WINDOW_VECTOR: Tag = Tag { group: 0x0054, element: 0x0010 };
+pub const NUMBER_OF_ENERGY_WINDOWS: Tag = Tag { group: 0x0054, element: 0x0011 };
+pub const ENERGY_WINDOW_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0012 };
+pub const ENERGY_WINDOW_RANGE_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0013 };
+pub const ENERGY_WINDOW_LOWER_LIMIT: Tag = Tag { group: 0x0054, element: 0x0014 };
+pub const ENERGY_WINDOW_UPPER_LIMIT: Tag = Tag { group: 0x0054, element: 0x0015 };
+pub const RADIOPHARMACEUTICAL_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0016 };
+pub const RESIDUAL_SYRINGE_COUNTS: Tag = Tag { group: 0x0054, element: 0x0017 };
+pub const ENERGY_WINDOW_NAME: Tag = Tag { group: 0x0054, element: 0x0018 };
+pub const DETECTOR_VECTOR: Tag = Tag { group: 0x0054, element: 0x0020 };
+pub const NUMBER_OF_DETECTORS: Tag = Tag { group: 0x0054, element: 0x0021 };
+pub const DETECTOR_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0022 };
+pub const PHASE_VECTOR: Tag = Tag { group: 0x0054, element: 0x0030 };
+pub const NUMBER_OF_PHASES: Tag = Tag { group: 0x0054, element: 0x0031 };
+pub const PHASE_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0032 };
+pub const NUMBER_OF_FRAMES_IN_PHASE: Tag = Tag { group: 0x0054, element: 0x0033 };
+pub const PHASE_DELAY: Tag = Tag { group: 0x0054, element: 0x0036 };
+pub const PAUSE_BETWEEN_FRAMES: Tag = Tag { group: 0x0054, element: 0x0038 };
+pub const PHASE_DESCRIPTION: Tag = Tag { group: 0x0054, element: 0x0039 };
+pub const ROTATION_VECTOR: Tag = Tag { group: 0x0054, element: 0x0050 };
+pub const NUMBER_OF_ROTATIONS: Tag = Tag { group: 0x0054, element: 0x0051 };
+pub const ROTATION_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0052 };
+pub const NUMBER_OF_FRAMES_IN_ROTATION: Tag = Tag { group: 0x0054, element: 0x0053 };
+pub const RR_INTERVAL_VECTOR: Tag = Tag { group: 0x0054, element: 0x0060 };
+pub const NUMBER_OF_RR_INTERVALS: Tag = Tag { group: 0x0054, element: 0x0061 };
+pub const GATED_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0062 };
+pub const DATA_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0063 };
+pub const TIME_SLOT_VECTOR: Tag = Tag { group: 0x0054, element: 0x0070 };
+pub const NUMBER_OF_TIME_SLOTS: Tag = Tag { group: 0x0054, element: 0x0071 };
+pub const TIME_SLOT_INFORMATION_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0072 };
+pub const TIME_SLOT_TIME: Tag = Tag { group: 0x0054, element: 0x0073 };
+pub const SLICE_VECTOR: Tag = Tag { group: 0x0054, element: 0x0080 };
+pub const NUMBER_OF_SLICES: Tag = Tag { group: 0x0054, element: 0x0081 };
+pub const ANGULAR_VIEW_VECTOR: Tag = Tag { group: 0x0054, element: 0x0090 };
+pub const TIME_SLICE_VECTOR: Tag = Tag { group: 0x0054, element: 0x0100 };
+pub const NUMBER_OF_TIME_SLICES: Tag = Tag { group: 0x0054, element: 0x0101 };
+pub const START_ANGLE: Tag = Tag { group: 0x0054, element: 0x0200 };
+pub const TYPE_OF_DETECTOR_MOTION: Tag = Tag { group: 0x0054, element: 0x0202 };
+pub const TRIGGER_VECTOR: Tag = Tag { group: 0x0054, element: 0x0210 };
+pub const NUMBER_OF_TRIGGERS_IN_PHASE: Tag = Tag { group: 0x0054, element: 0x0211 };
+pub const VIEW_CODE_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0220 };
+pub const VIEW_MODIFIER_CODE_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0222 };
+pub const RADIONUCLIDE_CODE_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0300 };
+pub const ADMINISTRATION_ROUTE_CODE_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0302 };
+pub const RADIOPHARMACEUTICAL_CODE_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0304 };
+pub const CALIBRATION_DATA_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0306 };
+pub const ENERGY_WINDOW_NUMBER: Tag = Tag { group: 0x0054, element: 0x0308 };
+pub const IMAGE_ID: Tag = Tag { group: 0x0054, element: 0x0400 };
+pub const PATIENT_ORIENTATION_CODE_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0410 };
+pub const PATIENT_ORIENTATION_MODIFIER_CODE_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0412 };
+pub const PATIENT_GANTRY_RELATIONSHIP_CODE_SEQUENCE: Tag = Tag { group: 0x0054, element: 0x0414 };
+pub const SLICE_PROGRESSION_DIRECTION: Tag = Tag { group: 0x0054, element: 0x0500 };
+pub const SCAN_PROGRESSION_DIRECTION: Tag = Tag { group: 0x0054, element: 0x0501 };
+pub const SERIES_TYPE: Tag = Tag { group: 0x0054, element: 0x1000 };
+pub const UNITS: Tag = Tag { group: 0x0054, element: 0x1001 };
+pub const COUNTS_SOURCE: Tag = Tag { group: 0x0054, element: 0x1002 };
+pub const REPROJECTION_METHOD: Tag = Tag { group: 0x0054, element: 0x1004 };
+pub const SUV_TYPE: Tag = Tag { group: 0x0054, element: 0x1006 };
+pub const RANDOMS_CORRECTION_METHOD: Tag = Tag { group: 0x0054, element: 0x1100 };
+pub const ATTENUATION_CORRECTION_METHOD: Tag = Tag { group: 0x0054, element: 0x1101 };
+pub const DECAY_CORRECTION: Tag = Tag { group: 0x0054, element: 0x1102 };
+pub const RECONSTRUCTION_METHOD: Tag = Tag { group: 0x0054, element: 0x1103 };
+pub const DETECTOR_LINES_OF_RESPONSE_USED: Tag = Tag { group: 0x0054, element: 0x1104 };
+pub const SCATTER_CORRECTION_METHOD: Tag = Tag { group: 0x0054, element: 0x1105 };
+pub const AXIAL_ACCEPTANCE: Tag = Tag { group: 0x0054, element: 0x1200 };
+pub const AXIAL_MASH: Tag = Tag { group: 0x0054, element: 0x1201 };
+pub const TRANSVERSE_MASH: Tag = Tag { group: 0x0054, element: 0x1202 };
+pub const DETECTOR_ELEMENT_SIZE: Tag = Tag { group: 0x0054, element: 0x1203 };
+pub const COINCIDENCE_WINDOW_WIDTH: Tag = Tag { group: 0x0054, element: 0x1210 };
+pub const SECONDARY_COUNTS_TYPE: Tag = Tag { group: 0x0054, element: 0x1220 };
+pub const FRAME_REFERENCE_TIME: Tag = Tag { group: 0x0054, element: 0x1300 };
+pub const PRIMARY_PROMPTS_COUNTS_ACCUMULATED: Tag = Tag { group: 0x0054, element: 0x1310 };
+pub const SECONDARY_COUNTS_ACCUMULATED: Tag = Tag { group: 0x0054, element: 0x1311 };
+pub const SLICE_SENSITIVITY_FACTOR: Tag = Tag { group: 0x0054, element: 0x1320 };
+pub const DECAY_FACTOR: Tag = Tag { group: 0x0054, element: 0x1321 };
+pub const DOSE_CALIBRATION_FACTOR: Tag = Tag { group: 0x0054, element: 0x1322 };
+pub const SCATTER_FRACTION_FACTOR: Tag = Tag { group: 0x0054, element: 0x1323 };
+pub const DEAD_TIME_FACTOR: Tag = Tag { group: 0x0054, element: 0x1324 };
+pub const IMAGE_INDEX: Tag = Tag { group: 0x0054, element: 0x1330 };
+pub const COUNTS_INCLUDED: Tag = Tag { group: 0x0054, element: 0x1400 };
+pub const DEAD_TIME_CORRECTION_FLAG: Tag = Tag { group: 0x0054, element: 0x1401 };
+pub const HISTOGRAM_SEQUENCE: Tag = Tag { group: 0x0060, element: 0x3000 };
+pub const HISTOGRAM_NUMBER_OF_BINS: Tag = Tag { group: 0x0060, element: 0x3002 };
+pub const HISTOGRAM_FIRST_BIN_VALUE: Tag = Tag { group: 0x0060, element: 0x3004 };
+pub const HISTOGRAM_LAST_BIN_VALUE: Tag = Tag { group: 0x0060, element: 0x3006 };
+pub const HISTOGRAM_BIN_WIDTH: Tag = Tag { group: 0x0060, element: 0x3008 };
+pub const HISTOGRAM_EXPLANATION: Tag = Tag { group: 0x0060, element: 0x3010 };
+pub const HISTOGRAM_DATA: Tag = Tag { group: 0x0060, element: 0x3020 };
+pub const SEGMENTATION_TYPE: Tag = Tag { group: 0x0062, element: 0x0001 };
+pub const SEGMENT_SEQUENCE: Tag = Tag { group: 0x0062, element: 0x0002 };
+pub const SEGMENTED_PROPERTY_CATEGORY_CODE_SEQUENCE: Tag = Tag { group: 0x0062, element: 0x0003 };
+pub const SEGMENT_NUMBER: Tag = Tag { group: 0x0062, element: 0x0004 };
+pub const SEGMENT_LABEL: Tag = Tag { group: 0x0062, element: 0x0005 };
+pub const SEGMENT_DESCRIPTION: Tag = Tag { group: 0x0062, element: 0x0006 };
+pub const SEGMENT_ALGORITHM_TYPE: Tag = Tag { group: 0x0062, element: 0x0008 };
+pub const SEGMENT_ALGORITHM_NAME: Tag = Tag { group: 0x0062, element: 0x0009 };
+pub const SEGMENT_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x0062, element: 0x000A };
+pub const REFERENCED_SEGMENT_NUMBER: Tag = Tag { group: 0x0062, element: 0x000B };
+pub const RECOMMENDED_DISPLAY_GRAYSCALE_VALUE: Tag = Tag { group: 0x0062, element: 0x000C };
+pub const RECOMMENDED_DISPLAY_CIE_LAB_VALUE: Tag = Tag { group: 0x0062, element: 0x000D };
+pub const MAXIMUM_FRACTIONAL_VALUE: Tag = Tag { group: 0x0062, element: 0x000E };
+pub const SEGMENTED_PROPERTY_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0062, element: 0x000F };
+pub const SEGMENTATION_FRACTIONAL_TYPE: Tag = Tag { group: 0x0062, element: 0x0010 };
+pub const SEGMENTED_PROPERTY_TYPE_MODIFIER_CODE_SEQUENCE: Tag = Tag { group: 0x0062, element: 0x0011 };
+pub const USED_SEGMENTS_SEQUENCE: Tag = Tag { group: 0x0062, element: 0x0012 };
+pub const SEGMENTS_OVERLAP: Tag = Tag { group: 0x0062, element: 0x0013 };
+pub const TRACKING_ID: Tag = Tag { group: 0x0062, element: 0x0020 };
+pub const TRACKING_UID: Tag = Tag { group: 0x0062, element: 0x0021 };
+pub const DEFORMABLE_REGISTRATION_SEQUENCE: Tag = Tag { group: 0x0064, element: 0x0002 };
+pub const SOURCE_FRAME_OF_REFERENCE_UID: Tag = Tag { group: 0x0064, element: 0x0003 };
+pub const DEFORMABLE_REGISTRATION_GRID_SEQUENCE: Tag = Tag { group: 0x0064, element: 0x0005 };
+pub const GRID_DIMENSIONS: Tag = Tag { group: 0x0064, element: 0x0007 };
+pub const GRID_RESOLUTION: Tag = Tag { group: 0x0064, element: 0x0008 };
+pub const VECTOR_GRID_DATA: Tag = Tag { group: 0x0064, element: 0x0009 };
+pub const PRE_DEFORMATION_MATRIX_REGISTRATION_SEQUENCE: Tag = Tag { group: 0x0064, element: 0x000F };
+pub const POST_DEFORMATION_MATRIX_REGISTRATION_SEQUENCE: Tag = Tag { group: 0x0064, element: 0x0010 };
+pub const NUMBER_OF_SURFACES: Tag = Tag { group: 0x0066, element: 0x0001 };
+pub const SURFACE_SEQUENCE: Tag = Tag { group: 0x0066, element: 0x0002 };
+pub const SURFACE_NUMBER: Tag = Tag { group: 0x0066, element: 0x0003 };
+pub const SURFACE_COMMENTS: Tag = Tag { group: 0x0066, element: 0x0004 };
+pub const SURFACE_PROCESSING: Tag = Tag { group: 0x0066, element: 0x0009 };
+pub const FINITE_VOLUME: Tag = Tag { group: 0x0066, element: 0x0010 };
+pub const MANIFOLD: Tag = Tag { group: 0x0066, element: 0x0011 };
+pub const SURFACE_POINTS_SEQUENCE: Tag = Tag { group: 0x0066, element: 0x0012 };
+pub const NUMBER_OF_SURFACE_POINTS: Tag = Tag { group: 0x0066, element: 0x0015 };
+pub const POINT_COORDINATES_DATA: Tag = Tag { group: 0x0066, element: 0x0016 };
+pub const VECTOR_COORDINATE_DATA: Tag = Tag { group: 0x0066, element: 0x0021 };
+pub const LONG_PRIMITIVE_POINT_INDEX_LIST: Tag = Tag { group: 0x0066, element: 0x0040 };
+pub const LONG_TRIANGLE_POINT_INDEX_LIST: Tag = Tag { group: 0x0066, element: 0x0041 };
+pub const GRAPHIC_ANNOTATION_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0001 };
+pub const GRAPHIC_LAYER: Tag = Tag { group: 0x0070, element: 0x0002 };
+pub const BOUNDING_BOX_ANNOTATION_UNITS: Tag = Tag { group: 0x0070, element: 0x0003 };
+pub const ANCHOR_POINT_ANNOTATION_UNITS: Tag = Tag { group: 0x0070, element: 0x0004 };
+pub const GRAPHIC_ANNOTATION_UNITS: Tag = Tag { group: 0x0070, element: 0x0005 };
+pub const UNFORMATTED_TEXT_VALUE: Tag = Tag { group: 0x0070, element: 0x0006 };
+pub const TEXT_OBJECT_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0008 };
+pub const GRAPHIC_OBJECT_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0009 };
+pub const BOUNDING_BOX_TOP_LEFT_HAND_CORNER: Tag = Tag { group: 0x0070, element: 0x0010 };
+pub const BOUNDING_BOX_BOTTOM_RIGHT_HAND_CORNER: Tag = Tag { group: 0x0070, element: 0x0011 };
+pub const BOUNDING_BOX_TEXT_HORIZONTAL_JUSTIFICATION: Tag = Tag { group: 0x0070, element: 0x0012 };
+pub const ANCHOR_POINT: Tag = Tag { group: 0x0070, element: 0x0014 };
+pub const ANCHOR_POINT_VISIBILITY: Tag = Tag { group: 0x0070, element: 0x0015 };
+pub const GRAPHIC_DIMENSIONS: Tag = Tag { group: 0x0070, element: 0x0020 };
+pub const NUMBER_OF_GRAPHIC_POINTS: Tag = Tag { group: 0x0070, element: 0x0021 };
+pub const GRAPHIC_DATA: Tag = Tag { group: 0x0070, element: 0x0022 };
+pub const GRAPHIC_TYPE: Tag = Tag { group: 0x0070, element: 0x0023 };
+pub const GRAPHIC_FILLED: Tag = Tag { group: 0x0070, element: 0x0024 };
+pub const IMAGE_ROTATION_RETIRED: Tag = Tag { group: 0x0070, element: 0x0040 };
+pub const IMAGE_HORIZONTAL_FLIP: Tag = Tag { group: 0x0070, element: 0x0041 };
+pub const IMAGE_ROTATION: Tag = Tag { group: 0x0070, element: 0x0042 };
+pub const DISPLAYED_AREA_TOP_LEFT_HAND_CORNER_TRIAL: Tag = Tag { group: 0x0070, element: 0x0050 };
+pub const DISPLAYED_AREA_BOTTOM_RIGHT_HAND_CORNER_TRIAL: Tag = Tag { group: 0x0070, element: 0x0051 };
+pub const DISPLAYED_AREA_TOP_LEFT_HAND_CORNER: Tag = Tag { group: 0x0070, element: 0x0052 };
+pub const DISPLAYED_AREA_BOTTOM_RIGHT_HAND_CORNER: Tag = Tag { group: 0x0070, element: 0x0053 };
+pub const DISPLAYED_AREA_SELECTION_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x005A };
+pub const GRAPHIC_LAYER_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0060 };
+pub const GRAPHIC_LAYER_ORDER: Tag = Tag { group: 0x0070, element: 0x0062 };
+pub const GRAPHIC_LAYER_RECOMMENDED_DISPLAY_GRAYSCALE_VALUE: Tag = Tag { group: 0x0070, element: 0x0066 };
+pub const GRAPHIC_LAYER_RECOMMENDED_DISPLAY_RGB_VALUE: Tag = Tag { group: 0x0070, element: 0x0067 };
+pub const GRAPHIC_LAYER_DESCRIPTION: Tag = Tag { group: 0x0070, element: 0x0068 };
+pub const CONTENT_LABEL: Tag = Tag { group: 0x0070, element: 0x0080 };
+pub const CONTENT_DESCRIPTION: Tag = Tag { group: 0x0070, element: 0x0081 };
+pub const PRESENTATION_CREATION_DATE: Tag = Tag { group: 0x0070, element: 0x0082 };
+pub const PRESENTATION_CREATION_TIME: Tag = Tag { group: 0x0070, element: 0x0083 };
+pub const CONTENT_CREATOR_NAME: Tag = Tag { group: 0x0070, element: 0x0084 };
+pub const CONTENT_CREATOR_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0086 };
+pub const ALTERNATE_CONTENT_DESCRIPTION_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0087 };
+pub const PRESENTATION_SIZE_MODE: Tag = Tag { group: 0x0070, element: 0x0100 };
+pub const PRESENTATION_PIXEL_SPACING: Tag = Tag { group: 0x0070, element: 0x0101 };
+pub const PRESENTATION_PIXEL_ASPECT_RATIO: Tag = Tag { group: 0x0070, element: 0x0102 };
+pub const PRESENTATION_PIXEL_MAGNIFICATION_RATIO: Tag = Tag { group: 0x0070, element: 0x0103 };
+pub const GRAPHIC_GROUP_LABEL: Tag = Tag { group: 0x0070, element: 0x0207 };
+pub const GRAPHIC_GROUP_DESCRIPTION: Tag = Tag { group: 0x0070, element: 0x0208 };
+pub const COMPOUND_GRAPHIC_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0209 };
+pub const COMPOUND_GRAPHIC_INSTANCE_ID: Tag = Tag { group: 0x0070, element: 0x0226 };
+pub const FONT_NAME: Tag = Tag { group: 0x0070, element: 0x0227 };
+pub const FONT_NAME_TYPE: Tag = Tag { group: 0x0070, element: 0x0228 };
+pub const CSS_FONT_NAME: Tag = Tag { group: 0x0070, element: 0x0229 };
+pub const ROTATION_ANGLE: Tag = Tag { group: 0x0070, element: 0x0230 };
+pub const TEXT_STYLE_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0231 };
+pub const LINE_STYLE_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0232 };
+pub const FILL_STYLE_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0233 };
+pub const GRAPHIC_GROUP_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0234 };
+pub const TEXT_COLOR_CIE_LAB_VALUE: Tag = Tag { group: 0x0070, element: 0x0241 };
+pub const HORIZONTAL_ALIGNMENT: Tag = Tag { group: 0x0070, element: 0x0242 };
+pub const VERTICAL_ALIGNMENT: Tag = Tag { group: 0x0070, element: 0x0243 };
+pub const SHADOW_STYLE: Tag = Tag { group: 0x0070, element: 0x0244 };
+pub const SHADOW_OFFSET_X: Tag = Tag { group: 0x0070, element: 0x0245 };
+pub const SHADOW_OFFSET_Y: Tag = Tag { group: 0x0070, element: 0x0246 };
+pub const SHADOW_COLOR_CIE_LAB_VALUE: Tag = Tag { group: 0x0070, element: 0x0247 };
+pub const UNDERLINED: Tag = Tag { group: 0x0070, element: 0x0248 };
+pub const BOLD: Tag = Tag { group: 0x0070, element: 0x0249 };
+pub const ITALIC: Tag = Tag { group: 0x0070, element: 0x0250 };
+pub const PATTERN_ON_COLOR_CIE_LAB_VALUE: Tag = Tag { group: 0x0070, element: 0x0251 };
+pub const PATTERN_OFF_COLOR_CIE_LAB_VALUE: Tag = Tag { group: 0x0070, element: 0x0252 };
+pub const LINE_THICKNESS: Tag = Tag { group: 0x0070, element: 0x0253 };
+pub const LINE_DASHING_STYLE: Tag = Tag { group: 0x0070, element: 0x0254 };
+pub const LINE_PATTERN: Tag = Tag { group: 0x0070, element: 0x0255 };
+pub const FILL_PATTERN: Tag = Tag { group: 0x0070, element: 0x0256 };
+pub const FILL_MODE: Tag = Tag { group: 0x0070, element: 0x0257 };
+pub const SHADOW_OPACITY: Tag = Tag { group: 0x0070, element: 0x0258 };
+pub const GAP_LENGTH: Tag = Tag { group: 0x0070, element: 0x0261 };
+pub const DIAMETER_OF_VISIBILITY: Tag = Tag { group: 0x0070, element: 0x0262 };
+pub const ROTATION_POINT: Tag = Tag { group: 0x0070, element: 0x0273 };
+pub const TICK_ALIGNMENT: Tag = Tag { group: 0x0070, element: 0x0274 };
+pub const SHOW_TICK_LABEL: Tag = Tag { group: 0x0070, element: 0x0278 };
+pub const TICK_LABEL_ALIGNMENT: Tag = Tag { group: 0x0070, element: 0x0279 };
+pub const COMPOUND_GRAPHIC_UNITS: Tag = Tag { group: 0x0070, element: 0x0282 };
+pub const PATTERN_ON_OPACITY: Tag = Tag { group: 0x0070, element: 0x0284 };
+pub const PATTERN_OFF_OPACITY: Tag = Tag { group: 0x0070, element: 0x0285 };
+pub const MAJOR_TICKS_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0287 };
+pub const TICK_POSITION: Tag = Tag { group: 0x0070, element: 0x0288 };
+pub const TICK_LABEL: Tag = Tag { group: 0x0070, element: 0x0289 };
+pub const COMPOUND_GRAPHIC_TYPE: Tag = Tag { group: 0x0070, element: 0x0294 };
+pub const GRAPHIC_GROUP_ID: Tag = Tag { group: 0x0070, element: 0x0295 };
+pub const SHAPE_TYPE: Tag = Tag { group: 0x0070, element: 0x0306 };
+pub const REGISTRATION_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0308 };
+pub const MATRIX_REGISTRATION_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0309 };
+pub const MATRIX_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x030A };
+pub const FRAME_OF_REFERENCE_TO_DISPLAYED_COORDINATE_SYSTEM_TRANSFORMATION_MATRIX: Tag = Tag { group: 0x0070, element: 0x030B };
+pub const FRAME_OF_REFERENCE_TRANSFORMATION_MATRIX_TYPE: Tag = Tag { group: 0x0070, element: 0x030C };
+pub const REGISTRATION_TYPE_CODE_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x030D };
+pub const FIDUCIAL_DESCRIPTION: Tag = Tag { group: 0x0070, element: 0x030F };
+pub const FIDUCIAL_IDENTIFIER: Tag = Tag { group: 0x0070, element: 0x0310 };
+pub const FIDUCIAL_IDENTIFIER_CODE_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0311 };
+pub const CONTOUR_UNCERTAINTY_RADIUS: Tag = Tag { group: 0x0070, element: 0x0312 };
+pub const USED_FIDUCIALS_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0314 };
+pub const GRAPHIC_COORDINATES_DATA_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0318 };
+pub const FIDUCIAL_UID: Tag = Tag { group: 0x0070, element: 0x031A };
+pub const FIDUCIAL_SET_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x031C };
+pub const FIDUCIAL_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x031E };
+pub const GRAPHIC_LAYER_RECOMMENDED_DISPLAY_CIE_LAB_VALUE: Tag = Tag { group: 0x0070, element: 0x0401 };
+pub const BLENDING_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0402 };
+pub const RELATIVE_OPACITY: Tag = Tag { group: 0x0070, element: 0x0403 };
+pub const REFERENCED_SPATIAL_REGISTRATION_SEQUENCE: Tag = Tag { group: 0x0070, element: 0x0404 };
+pub const BLENDING_POSITION: Tag = Tag { group: 0x0070, element: 0x0405 };
+pub const PRESENTATION_DISPLAY_COLLECTION_UID: Tag = Tag { group: 0x0070, element: 0x1101 };
+pub const PRESENTATION_SEQUENCE_COLLECTION_UID: Tag = Tag { group: 0x0070, element: 0x1102 };
+pub const HANGING_PROTOCOL_NAME: Tag = Tag { group: 0x0072, element: 0x0002 };
+pub const HANGING_PROTOCOL_DESCRIPTION: Tag = Tag { group: 0x0072, element: 0x0004 };
+pub const HANGING_PROTOCOL_LEVEL: Tag = Tag { group: 0x0072, element: 0x0006 };
+pub const HANGING_PROTOCOL_CREATOR: Tag = Tag { group: 0x0072, element: 0x0008 };
+pub const HANGING_PROTOCOL_CREATION_DATE_TIME: Tag = Tag { group: 0x0072, element: 0x000A };
+pub const HANGING_PROTOCOL_DEFINITION_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x000C };
+pub const HANGING_PROTOCOL_USER_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x000E };
+pub const HANGING_PROTOCOL_USER_GROUP_NAME: Tag = Tag { group: 0x0072, element: 0x0010 };
+pub const SOURCE_HANGING_PROTOCOL_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0012 };
+pub const NUMBER_OF_PRIORS_REFERENCED: Tag = Tag { group: 0x0072, element: 0x0014 };
+pub const IMAGE_SETS_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0020 };
+pub const IMAGE_SET_SELECTOR_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0022 };
+pub const IMAGE_SET_SELECTOR_USAGE_FLAG: Tag = Tag { group: 0x0072, element: 0x0024 };
+pub const SELECTOR_ATTRIBUTE: Tag = Tag { group: 0x0072, element: 0x0026 };
+pub const SELECTOR_VALUE_NUMBER: Tag = Tag { group: 0x0072, element: 0x0028 };
+pub const TIME_BASED_IMAGE_SETS_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0030 };
+pub const IMAGE_SET_NUMBER: Tag = Tag { group: 0x0072, element: 0x0032 };
+pub const IMAGE_SET_SELECTOR_CATEGORY: Tag = Tag { group: 0x0072, element: 0x0034 };
+pub const RELATIVE_TIME: Tag = Tag { group: 0x0072, element: 0x0038 };
+pub const RELATIVE_TIME_UNITS: Tag = Tag { group: 0x0072, element: 0x003A };
+pub const ABSTRACT_PRIOR_VALUE: Tag = Tag { group: 0x0072, element: 0x003C };
+pub const ABSTRACT_PRIOR_CODE_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x003E };
+pub const IMAGE_SET_LABEL: Tag = Tag { group: 0x0072, element: 0x0040 };
+pub const SELECTOR_ATTRIBUTE_VR: Tag = Tag { group: 0x0072, element: 0x0050 };
+pub const SELECTOR_SEQUENCE_POINTER: Tag = Tag { group: 0x0072, element: 0x0052 };
+pub const SELECTOR_SEQUENCE_POINTER_PRIVATE_CREATOR: Tag = Tag { group: 0x0072, element: 0x0054 };
+pub const SELECTOR_ATTRIBUTE_PRIVATE_CREATOR: Tag = Tag { group: 0x0072, element: 0x0056 };
+pub const SELECTOR_AT_VALUE: Tag = Tag { group: 0x0072, element: 0x0060 };
+pub const SELECTOR_CS_VALUE: Tag = Tag { group: 0x0072, element: 0x0062 };
+pub const SELECTOR_IS_VALUE: Tag = Tag { group: 0x0072, element: 0x0064 };
+pub const SELECTOR_LO_VALUE: Tag = Tag { group: 0x0072, element: 0x0066 };
+pub const SELECTOR_LT_VALUE: Tag = Tag { group: 0x0072, element: 0x0068 };
+pub const SELECTOR_PN_VALUE: Tag = Tag { group: 0x0072, element: 0x006A };
+pub const SELECTOR_SH_VALUE: Tag = Tag { group: 0x0072, element: 0x006C };
+pub const SELECTOR_ST_VALUE: Tag = Tag { group: 0x0072, element: 0x006E };
+pub const SELECTOR_UT_VALUE: Tag = Tag { group: 0x0072, element: 0x0070 };
+pub const SELECTOR_DS_VALUE: Tag = Tag { group: 0x0072, element: 0x0072 };
+pub const SELECTOR_FD_VALUE: Tag = Tag { group: 0x0072, element: 0x0074 };
+pub const SELECTOR_FL_VALUE: Tag = Tag { group: 0x0072, element: 0x0076 };
+pub const SELECTOR_UL_VALUE: Tag = Tag { group: 0x0072, element: 0x0078 };
+pub const SELECTOR_US_VALUE: Tag = Tag { group: 0x0072, element: 0x007A };
+pub const SELECTOR_SL_VALUE: Tag = Tag { group: 0x0072, element: 0x007C };
+pub const SELECTOR_SS_VALUE: Tag = Tag { group: 0x0072, element: 0x007E };
+pub const SELECTOR_CODE_SEQUENCE_VALUE: Tag = Tag { group: 0x0072, element: 0x0080 };
+pub const NUMBER_OF_SCREENS: Tag = Tag { group: 0x0072, element: 0x0100 };
+pub const NOMINAL_SCREEN_DEFINITION_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0102 };
+pub const NUMBER_OF_VERTICAL_PIXELS: Tag = Tag { group: 0x0072, element: 0x0104 };
+pub const NUMBER_OF_HORIZONTAL_PIXELS: Tag = Tag { group: 0x0072, element: 0x0106 };
+pub const DISPLAY_ENVIRONMENT_SPATIAL_POSITION: Tag = Tag { group: 0x0072, element: 0x0108 };
+pub const SCREEN_MINIMUM_GRAYSCALE_BIT_DEPTH: Tag = Tag { group: 0x0072, element: 0x010A };
+pub const SCREEN_MINIMUM_COLOR_BIT_DEPTH: Tag = Tag { group: 0x0072, element: 0x010C };
+pub const APPLICATION_MAXIMUM_REPAINT_TIME: Tag = Tag { group: 0x0072, element: 0x010E };
+pub const DISPLAY_SETS_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0200 };
+pub const DISPLAY_SET_NUMBER: Tag = Tag { group: 0x0072, element: 0x0202 };
+pub const DISPLAY_SET_LABEL: Tag = Tag { group: 0x0072, element: 0x0203 };
+pub const DISPLAY_SET_PRESENTATION_GROUP: Tag = Tag { group: 0x0072, element: 0x0204 };
+pub const DISPLAY_SET_PRESENTATION_GROUP_DESCRIPTION: Tag = Tag { group: 0x0072, element: 0x0206 };
+pub const PARTIAL_DATA_DISPLAY_HANDLING: Tag = Tag { group: 0x0072, element: 0x0208 };
+pub const SYNCHRONIZED_SCROLLING_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0210 };
+pub const DISPLAY_SET_SCROLLING_GROUP: Tag = Tag { group: 0x0072, element: 0x0212 };
+pub const NAVIGATION_INDICATOR_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0214 };
+pub const NAVIGATION_DISPLAY_SET: Tag = Tag { group: 0x0072, element: 0x0216 };
+pub const REFERENCE_DISPLAY_SETS: Tag = Tag { group: 0x0072, element: 0x0218 };
+pub const IMAGE_BOXES_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0300 };
+pub const IMAGE_BOX_NUMBER: Tag = Tag { group: 0x0072, element: 0x0302 };
+pub const IMAGE_BOX_LAYOUT_TYPE: Tag = Tag { group: 0x0072, element: 0x0304 };
+pub const IMAGE_BOX_TILE_HORIZONTAL_DIMENSION: Tag = Tag { group: 0x0072, element: 0x0306 };
+pub const IMAGE_BOX_TILE_VERTICAL_DIMENSION: Tag = Tag { group: 0x0072, element: 0x0308 };
+pub const IMAGE_BOX_SCROLL_DIRECTION: Tag = Tag { group: 0x0072, element: 0x0310 };
+pub const IMAGE_BOX_SMALL_SCROLL_TYPE: Tag = Tag { group: 0x0072, element: 0x0312 };
+pub const IMAGE_BOX_SMALL_SCROLL_AMOUNT: Tag = Tag { group: 0x0072, element: 0x0314 };
+pub const IMAGE_BOX_LARGE_SCROLL_TYPE: Tag = Tag { group: 0x0072, element: 0x0316 };
+pub const IMAGE_BOX_LARGE_SCROLL_AMOUNT: Tag = Tag { group: 0x0072, element: 0x0318 };
+pub const IMAGE_BOX_OVERLAP_PRIORITY: Tag = Tag { group: 0x0072, element: 0x0320 };
+pub const CINE_RELATIVE_TO_REAL_TIME: Tag = Tag { group: 0x0072, element: 0x0330 };
+pub const FILTER_OPERATIONS_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0400 };
+pub const FILTER_BY_CATEGORY: Tag = Tag { group: 0x0072, element: 0x0402 };
+pub const FILTER_BY_ATTRIBUTE_PRESENCE: Tag = Tag { group: 0x0072, element: 0x0404 };
+pub const FILTER_BY_OPERATOR: Tag = Tag { group: 0x0072, element: 0x0406 };
+pub const STRUCTURED_DISPLAY_BACKGROUND_CIE_LAB_VALUE: Tag = Tag { group: 0x0072, element: 0x0420 };
+pub const EMPTY_IMAGE_BOX_CIE_LAB_VALUE: Tag = Tag { group: 0x0072, element: 0x0421 };
+pub const STRUCTURED_DISPLAY_IMAGE_BOX_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0422 };
+pub const STRUCTURED_DISPLAY_TEXT_BOX_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0424 };
+pub const REFERENCED_FIRST_FRAME_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0427 };
+pub const IMAGE_BOX_SYNCHRONIZATION_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0430 };
+pub const SYNCHRONIZED_IMAGE_BOX_LIST: Tag = Tag { group: 0x0072, element: 0x0432 };
+pub const TYPE_OF_SYNCHRONIZATION: Tag = Tag { group: 0x0072, element: 0x0434 };
+pub const BLENDING_OPERATION_TYPE: Tag = Tag { group: 0x0072, element: 0x0500 };
+pub const REFORMATTING_OPERATION_TYPE: Tag = Tag { group: 0x0072, element: 0x0510 };
+pub const REFORMATTING_THICKNESS: Tag = Tag { group: 0x0072, element: 0x0512 };
+pub const REFORMATTING_INTERVAL: Tag = Tag { group: 0x0072, element: 0x0514 };
+pub const REFORMATTING_OPERATION_INITIAL_VIEW_DIRECTION: Tag = Tag { group: 0x0072, element: 0x0516 };
+pub const THREE_D_RENDERING_TYPE: Tag = Tag { group: 0x0072, element: 0x0520 };
+pub const SORTING_OPERATIONS_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0600 };
+pub const SORT_BY_CATEGORY: Tag = Tag { group: 0x0072, element: 0x0602 };
+pub const SORTING_DIRECTION: Tag = Tag { group: 0x0072, element: 0x0604 };
+pub const DISPLAY_SET_PATIENT_ORIENTATION: Tag = Tag { group: 0x0072, element: 0x0700 };
+pub const VOI_TYPE: Tag = Tag { group: 0x0072, element: 0x0702 };
+pub const PSEUDO_COLOR_TYPE: Tag = Tag { group: 0x0072, element: 0x0704 };
+pub const PSEUDO_COLOR_PALETTE_INSTANCE_REFERENCE_SEQUENCE: Tag = Tag { group: 0x0072, element: 0x0705 };
+pub const SHOW_GRAYSCALE_INVERTED: Tag = Tag { group: 0x0072, element: 0x0706 };
+pub const SHOW_IMAGE_TRUE_SIZE_FLAG: Tag = Tag { group: 0x0072, element: 0x0710 };
+pub const SHOW_GRAPHIC_ANNOTATION_FLAG: Tag = Tag { group: 0x0072, element: 0x0712 };
+pub const SHOW_PATIENT_DEMOGRAPHICS_FLAG: Tag = Tag { group: 0x0072, element: 0x0714 };
+pub const SHOW_ACQUISITION_TECHNIQUES_FLAG: Tag = Tag { group: 0x0072, element: 0x0716 };
+pub const DISPLAY_SET_HORIZONTAL_JUSTIFICATION: Tag = Tag { group: 0x0072, element: 0x0717 };
+pub const DISPLAY_SET_VERTICAL_JUSTIFICATION: Tag = Tag { group: 0x0072, element: 0x0718 };
+pub const STORAGE_MEDIA_FILE_SET_ID: Tag = Tag { group: 0x0088, element: 0x0130 };
+pub const STORAGE_MEDIA_FILE_SET_UID: Tag = Tag { group: 0x0088, element: 0x0140 };
+pub const ICON_IMAGE_SEQUENCE: Tag = Tag { group: 0x0088, element: 0x0200 };
+pub const TOPIC_TITLE: Tag = Tag { group: 0x0088, element: 0x0904 };
+pub const TOPIC_SUBJECT: Tag = Tag { group: 0x0088, element: 0x0906 };
+pub const TOPIC_AUTHOR: Tag = Tag { group: 0x0088, element: 0x0910 };
+pub const TOPIC_KEYWORDS: Tag = Tag { group: 0x0088, element: 0x0912 };
+pub const SOP_INSTANCE_STATUS: Tag = Tag { group: 0x0100, element: 0x0410 };
+pub const SOP_AUTHORIZATION_DATE_TIME: Tag = Tag { group: 0x0100, element: 0x0420 };
+pub const SOP_AUTHORIZATION_COMMENT: Tag = Tag { group: 0x0100, element: 0x0424 };
+pub const AUTHORIZATION_EQUIPMENT_CERTIFICATION_NUMBER: Tag = Tag { group: 0x0100, element: 0x0426 };
+pub const MACID_NUMBER: Tag = Tag { group: 0x0400, element: 0x0005 };
+pub const MAC_CALCULATION_TRANSFER_SYNTAX_UID: Tag = Tag { group: 0x0400, element: 0x0010 };
+pub const MAC_ALGORITHM: Tag = Tag { group: 0x0400, element: 0x0015 };
+pub const DATA_ELEMENTS_SIGNED: Tag = Tag { group: 0x0400, element: 0x0020 };
+pub const DIGITAL_SIGNATURE_UID: Tag = Tag { group: 0x0400, element: 0x0100 };
+pub const DIGITAL_SIGNATURE_DATE_TIME: Tag = Tag { group: 0x0400, element: 0x0105 };
+pub const CERTIFICATE_TYPE: Tag = Tag { group: 0x0400, element: 0x0110 };
+pub const CERTIFICATE_OF_SIGNER: Tag = Tag { group: 0x0400, element: 0x0115 };
+pub const SIGNATURE: Tag = Tag { group: 0x0400, element: 0x0120 };
+pub const CERTIFIED_TIMESTAMP_TYPE: Tag = Tag { group: 0x0400, element: 0x0305 };
+pub const CERTIFIED_TIMESTAMP: Tag = Tag { group: 0x0400, element: 0x0310 };
+pub const DIGITAL_SIGNATURE_PURPOSE_CODE_SEQUENCE: Tag = Tag { group: 0x0400, element: 0x0401 };
+pub const REFERENCED_DIGITAL_SIGNATURE_SEQUENCE: Tag = Tag { group: 0x0400, element: 0x0402 };
+pub const REFERENCED_SOP_INSTANCE_MAC_SEQUENCE: Tag = Tag { group: 0x0400, element: 0x0403 };
+pub const MAC: Tag = Tag { group: 0x0400, element: 0x0404 };
+pub const ENCRYPTED_ATTRIBUTES_SEQUENCE: Tag = Tag { group: 0x0400, element: 0x0500 };
+pub const ENCRYPTED_CONTENT_TRANSFER_SYNTAX_UID: Tag = Tag { group: 0x0400, element: 0x0510 };
+pub const ENCRYPTED_CONTENT: Tag = Tag { group: 0x0400, element: 0x0520 };
+pub const MODIFIED_ATTRIBUTES_SEQUENCE: Tag = Tag { group: 0x0400, element: 0x0550 };
+pub const NONCONFORMING_MODIFIED_ATTRIBUTES_SEQUENCE: Tag = Tag { group: 0x0400, element: 0x0551 };
+pub const NONCONFORMING_DATA_ELEMENT_VALUE: Tag = Tag { group: 0x0400, element: 0x0552 };
+pub const ORIGINAL_ATTRIBUTES_SEQUENCE: Tag = Tag { group: 0x0400, element: 0x0561 };
+pub const ATTRIBUTE_MODIFICATION_DATE_TIME: Tag = Tag { group: 0x0400, element: 0x0562 };
+pub const MODIFYING_SYSTEM: Tag = Tag { group: 0x0400, element: 0x0563 };
+pub const SOURCE_OF_PREVIOUS_VALUES: Tag = Tag { group: 0x0400, element: 0x0564 };
+pub const REASON_FOR_THE_ATTRIBUTE_MODIFICATION: Tag = Tag { group: 0x0400, element: 0x0565 };
+pub const INSTANCE_ORIGIN_STATUS: Tag = Tag { group: 0x0400, element: 0x0600 };
+pub const TEXT_STRING: Tag = Tag { group: 0x2030, element: 0x0020 };
+pub const PRESENTATION_LUT_SEQUENCE: Tag = Tag { group: 0x2050, element: 0x0010 };
+pub const PRESENTATION_LUT_SHAPE: Tag = Tag { group: 0x2050, element: 0x0020 };
+pub const REFERENCED_PRESENTATION_LUT_SEQUENCE: Tag = Tag { group: 0x2050, element: 0x0500 };
+pub const LABEL_TEXT: Tag = Tag { group: 0x2200, element: 0x0002 };
+pub const BARCODE_VALUE: Tag = Tag { group: 0x2200, element: 0x0005 };
+pub const RT_IMAGE_LABEL: Tag = Tag { group: 0x3002, element: 0x0002 };
+pub const RT_IMAGE_NAME: Tag = Tag { group: 0x3002, element: 0x0003 };
+pub const RT_IMAGE_DESCRIPTION: Tag = Tag { group: 0x3002, element: 0x0004 };
+pub const REPORTED_VALUES_ORIGIN: Tag = Tag { group: 0x3002, element: 0x000A };
+pub const RT_IMAGE_PLANE: Tag = Tag { group: 0x3002, element: 0x000C };
+pub const X_RAY_IMAGE_RECEPTOR_TRANSLATION: Tag = Tag { group: 0x3002, element: 0x000D };
+pub const X_RAY_IMAGE_RECEPTOR_ANGLE: Tag = Tag { group: 0x3002, element: 0x000E };
+pub const RT_IMAGE_ORIENTATION: Tag = Tag { group: 0x3002, element: 0x0010 };
+pub const IMAGE_PLANE_PIXEL_SPACING: Tag = Tag { group: 0x3002, element: 0x0011 };
+pub const RT_IMAGE_POSITION: Tag = Tag { group: 0x3002, element: 0x0012 };
+pub const RADIATION_MACHINE_NAME: Tag = Tag { group: 0x3002, element: 0x0020 };
+pub const RADIATION_MACHINE_SAD: Tag = Tag { group: 0x3002, element: 0x0022 };
+pub const RADIATION_MACHINE_SSD: Tag = Tag { group: 0x3002, element: 0x0024 };
+pub const RT_IMAGE_SID: Tag = Tag { group: 0x3002, element: 0x0026 };
+pub const SOURCE_TO_REFERENCE_OBJECT_DISTANCE: Tag = Tag { group: 0x3002, element: 0x0028 };
+pub const FRACTION_NUMBER: Tag = Tag { group: 0x3002, element: 0x0029 };
+pub const EXPOSURE_SEQUENCE: Tag = Tag { group: 0x3002, element: 0x0030 };
+pub const METERSET_EXPOSURE: Tag = Tag { group: 0x3002, element: 0x0032 };
+pub const DIAPHRAGM_POSITION: Tag = Tag { group: 0x3002, element: 0x0034 };
+pub const FLUENCE_MAP_SEQUENCE: Tag = Tag { group: 0x3002, element: 0x0040 };
+pub const FLUENCE_DATA_SOURCE: Tag = Tag { group: 0x3002, element: 0x0041 };
+pub const FLUENCE_DATA_SCALE: Tag = Tag { group: 0x3002, element: 0x0042 };
+pub const PRIMARY_FLUENCE_MODE_SEQUENCE: Tag = Tag { group: 0x3002, element: 0x0050 };
+pub const FLUENCE_MODE: Tag = Tag { group: 0x3002, element: 0x0051 };
+pub const FLUENCE_MODE_ID: Tag = Tag { group: 0x3002, element: 0x0052 };
+pub const DVH_TYPE: Tag = Tag { group: 0x3004, element: 0x0001 };
+pub const DOSE_UNITS: Tag = Tag { group: 0x3004, element: 0x0002 };
+pub const DOSE_TYPE: Tag = Tag { group: 0x3004, element: 0x0004 };
+pub const SPATIAL_TRANSFORM_OF_DOSE: Tag = Tag { group: 0x3004, element: 0x0005 };
+pub const DOSE_COMMENT: Tag = Tag { group: 0x3004, element: 0x0006 };
+pub const NORMALIZATION_POINT: Tag = Tag { group: 0x3004, element: 0x0008 };
+pub const DOSE_SUMMATION_TYPE: Tag = Tag { group: 0x3004, element: 0x000A };
+pub const GRID_FRAME_OFFSET_VECTOR: Tag = Tag { group: 0x3004, element: 0x000C };
+pub const DOSE_GRID_SCALING: Tag = Tag { group: 0x3004, element: 0x000E };
+pub const RT_DOSE_ROI_SEQUENCE: Tag = Tag { group: 0x3004, element: 0x0010 };
+pub const DOSE_VALUE: Tag = Tag { group: 0x3004, element: 0x0012 };
+pub const TISSUE_HETEROGENEITY_CORRECTION: Tag = Tag { group: 0x3004, element: 0x0014 };
+pub const DVH_NORMALIZATION_POINT: Tag = Tag { group: 0x3004, element: 0x0040 };
+pub const DVH_NORMALIZATION_DOSE_VALUE: Tag = Tag { group: 0x3004, element: 0x0042 };
+pub const DVH_SEQUENCE: Tag = Tag { group: 0x3004, element: 0x0050 };
+pub const DVH_DOSE_SCALING: Tag = Tag { group: 0x3004, element: 0x0052 };
+pub const DVH_VOLUME_UNITS: Tag = Tag { group: 0x3004, element: 0x0054 };
+pub const DVH_NUMBER_OF_BINS: Tag = Tag { group: 0x3004, element: 0x0056 };
+pub const DVH_DATA: Tag = Tag { group: 0x3004, element: 0x0058 };
+pub const DVH_REFERENCED_ROI_SEQUENCE: Tag = Tag { group: 0x3004, element: 0x0060 };
+pub const DVHROI_CONTRIBUTION_TYPE: Tag = Tag { group: 0x3004, element: 0x0062 };
+pub const DVH_MINIMUM_DOSE: Tag = Tag { group: 0x3004, element: 0x0070 };
+pub const DVH_MAXIMUM_DOSE: Tag = Tag { group: 0x3004, element: 0x0072 };
+pub const DVH_MEAN_DOSE: Tag = Tag { group: 0x3004, element: 0x0074 };
+pub const STRUCTURE_SET_LABEL: Tag = Tag { group: 0x3006, element: 0x0002 };
+pub const STRUCTURE_SET_NAME: Tag = Tag { group: 0x3006, element: 0x0004 };
+pub const STRUCTURE_SET_DESCRIPTION: Tag = Tag { group: 0x3006, element: 0x0006 };
+pub const STRUCTURE_SET_DATE: Tag = Tag { group: 0x3006, element: 0x0008 };
+pub const STRUCTURE_SET_TIME: Tag = Tag { group: 0x3006, element: 0x0009 };
+pub const REFERENCED_FRAME_OF_REFERENCE_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0010 };
+pub const RT_REFERENCED_STUDY_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0012 };
+pub const RT_REFERENCED_SERIES_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0014 };
+pub const CONTOUR_IMAGE_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0016 };
+pub const PREDECESSOR_STRUCTURE_SET_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0018 };
+pub const STRUCTURE_SET_ROI_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0020 };
+pub const ROI_NUMBER: Tag = Tag { group: 0x3006, element: 0x0022 };
+pub const REFERENCED_FRAME_OF_REFERENCE_UID: Tag = Tag { group: 0x3006, element: 0x0024 };
+pub const ROI_NAME: Tag = Tag { group: 0x3006, element: 0x0026 };
+pub const ROI_DESCRIPTION: Tag = Tag { group: 0x3006, element: 0x0028 };
+pub const ROI_DISPLAY_COLOR: Tag = Tag { group: 0x3006, element: 0x002A };
+pub const ROI_VOLUME: Tag = Tag { group: 0x3006, element: 0x002C };
+pub const RT_RELATED_ROI_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0030 };
+pub const RTROI_RELATIONSHIP: Tag = Tag { group: 0x3006, element: 0x0033 };
+pub const ROI_GENERATION_ALGORITHM: Tag = Tag { group: 0x3006, element: 0x0036 };
+pub const ROI_DERIVATION_ALGORITHM_IDENTIFICATION_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0037 };
+pub const ROI_GENERATION_DESCRIPTION: Tag = Tag { group: 0x3006, element: 0x0038 };
+pub const ROI_CONTOUR_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0039 };
+pub const CONTOUR_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0040 };
+pub const CONTOUR_GEOMETRIC_TYPE: Tag = Tag { group: 0x3006, element: 0x0042 };
+pub const CONTOUR_SLAB_THICKNESS: Tag = Tag { group: 0x3006, element: 0x0044 };
+pub const CONTOUR_OFFSET_VECTOR: Tag = Tag { group: 0x3006, element: 0x0045 };
+pub const NUMBER_OF_CONTOUR_POINTS: Tag = Tag { group: 0x3006, element: 0x0046 };
+pub const CONTOUR_NUMBER: Tag = Tag { group: 0x3006, element: 0x0048 };
+pub const ATTACHED_CONTOURS: Tag = Tag { group: 0x3006, element: 0x0049 };
+pub const CONTOUR_DATA: Tag = Tag { group: 0x3006, element: 0x0050 };
+pub const RTROI_OBSERVATIONS_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0080 };
+pub const OBSERVATION_NUMBER: Tag = Tag { group: 0x3006, element: 0x0082 };
+pub const REFERENCED_ROI_NUMBER: Tag = Tag { group: 0x3006, element: 0x0084 };
+pub const ROI_OBSERVATION_LABEL: Tag = Tag { group: 0x3006, element: 0x0085 };
+pub const RTROI_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x0086 };
+pub const ROI_OBSERVATION_DESCRIPTION: Tag = Tag { group: 0x3006, element: 0x0088 };
+pub const RELATED_RTROI_OBSERVATIONS_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x00A0 };
+pub const RTROI_INTERPRETED_TYPE: Tag = Tag { group: 0x3006, element: 0x00A4 };
+pub const ROI_INTERPRETER: Tag = Tag { group: 0x3006, element: 0x00A6 };
+pub const ROI_PHYSICAL_PROPERTIES_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x00B0 };
+pub const ROI_PHYSICAL_PROPERTY: Tag = Tag { group: 0x3006, element: 0x00B2 };
+pub const ROI_PHYSICAL_PROPERTY_VALUE: Tag = Tag { group: 0x3006, element: 0x00B4 };
+pub const ROI_ELEMENTAL_COMPOSITION_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x00B6 };
+pub const ROI_ELEMENTAL_COMPOSITION_ATOMIC_NUMBER: Tag = Tag { group: 0x3006, element: 0x00B7 };
+pub const ROI_ELEMENTAL_COMPOSITION_ATOMIC_MASS_FRACTION: Tag = Tag { group: 0x3006, element: 0x00B8 };
+pub const FRAME_OF_REFERENCE_RELATIONSHIP_SEQUENCE: Tag = Tag { group: 0x3006, element: 0x00C0 };
+pub const RELATED_FRAME_OF_REFERENCE_UID: Tag = Tag { group: 0x3006, element: 0x00C2 };
+pub const FRAME_OF_REFERENCE_TRANSFORMATION_TYPE: Tag = Tag { group: 0x3006, element: 0x00C4 };
+pub const FRAME_OF_REFERENCE_TRANSFORMATION_MATRIX: Tag = Tag { group: 0x3006, element: 0x00C6 };
+pub const FRAME_OF_REFERENCE_TRANSFORMATION_COMMENT: Tag = Tag { group: 0x3006, element: 0x00C8 };
+pub const MEASURED_DOSE_REFERENCE_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0010 };
+pub const MEASURED_DOSE_DESCRIPTION: Tag = Tag { group: 0x3008, element: 0x0012 };
+pub const MEASURED_DOSE_TYPE: Tag = Tag { group: 0x3008, element: 0x0014 };
+pub const MEASURED_DOSE_VALUE: Tag = Tag { group: 0x3008, element: 0x0016 };
+pub const TREATMENT_SESSION_BEAM_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0020 };
+pub const CURRENT_FRACTION_NUMBER: Tag = Tag { group: 0x3008, element: 0x0022 };
+pub const TREATMENT_CONTROL_POINT_DATE: Tag = Tag { group: 0x3008, element: 0x0024 };
+pub const TREATMENT_CONTROL_POINT_TIME: Tag = Tag { group: 0x3008, element: 0x0025 };
+pub const TREATMENT_TERMINATION_STATUS: Tag = Tag { group: 0x3008, element: 0x002A };
+pub const TREATMENT_TERMINATION_CODE: Tag = Tag { group: 0x3008, element: 0x002B };
+pub const TREATMENT_VERIFICATION_STATUS: Tag = Tag { group: 0x3008, element: 0x002C };
+pub const REFERENCED_TREATMENT_RECORD_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0030 };
+pub const SPECIFIED_PRIMARY_METERSET: Tag = Tag { group: 0x3008, element: 0x0032 };
+pub const SPECIFIED_SECONDARY_METERSET: Tag = Tag { group: 0x3008, element: 0x0033 };
+pub const DELIVERED_PRIMARY_METERSET: Tag = Tag { group: 0x3008, element: 0x0036 };
+pub const DELIVERED_SECONDARY_METERSET: Tag = Tag { group: 0x3008, element: 0x0037 };
+pub const SPECIFIED_TREATMENT_TIME: Tag = Tag { group: 0x3008, element: 0x003A };
+pub const DELIVERED_TREATMENT_TIME: Tag = Tag { group: 0x3008, element: 0x003B };
+pub const CONTROL_POINT_DELIVERY_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0040 };
+pub const SPECIFIED_METERSET: Tag = Tag { group: 0x3008, element: 0x0042 };
+pub const DELIVERED_METERSET: Tag = Tag { group: 0x3008, element: 0x0044 };
+pub const DOSE_RATE_DELIVERED: Tag = Tag { group: 0x3008, element: 0x0048 };
+pub const TREATMENT_SUMMARY_CALCULATED_DOSE_REFERENCE_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0050 };
+pub const FIRST_TREATMENT_DATE: Tag = Tag { group: 0x3008, element: 0x0054 };
+pub const MOST_RECENT_TREATMENT_DATE: Tag = Tag { group: 0x3008, element: 0x0056 };
+pub const NUMBER_OF_FRACTIONS_DELIVERED: Tag = Tag { group: 0x3008, element: 0x005A };
+pub const OVERRIDE_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0060 };
+pub const OVERRIDE_REASON: Tag = Tag { group: 0x3008, element: 0x0066 };
+pub const CALCULATED_DOSE_REFERENCE_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0080 };
+pub const REFERENCED_MEASURED_DOSE_REFERENCE_NUMBER: Tag = Tag { group: 0x3008, element: 0x0082 };
+pub const CALCULATED_DOSE_REFERENCE_DESCRIPTION: Tag = Tag { group: 0x3008, element: 0x0090 };
+pub const CALCULATED_DOSE_REFERENCE_DOSE_VALUE: Tag = Tag { group: 0x3008, element: 0x0092 };
+pub const BEAM_LIMITING_DEVICE_LEAF_PAIRS_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x00A0 };
+pub const RECORDED_WEDGE_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x00B0 };
+pub const RECORDED_COMPENSATOR_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x00C0 };
+pub const RECORDED_BLOCK_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x00D0 };
+pub const TREATMENT_SUMMARY_MEASURED_DOSE_REFERENCE_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x00E0 };
+pub const RECORDED_SOURCE_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0100 };
+pub const SOURCE_SERIAL_NUMBER: Tag = Tag { group: 0x3008, element: 0x0105 };
+pub const TREATMENT_SESSION_APPLICATION_SETUP_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0110 };
+pub const APPLICATION_SETUP_CHECK: Tag = Tag { group: 0x3008, element: 0x0116 };
+pub const RECORDED_BRACHY_ACCESSORY_DEVICE_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0120 };
+pub const REFERENCED_BRACHY_ACCESSORY_DEVICE_NUMBER: Tag = Tag { group: 0x3008, element: 0x0122 };
+pub const RECORDED_CHANNEL_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0130 };
+pub const SPECIFIED_CHANNEL_TOTAL_TIME: Tag = Tag { group: 0x3008, element: 0x0132 };
+pub const DELIVERED_CHANNEL_TOTAL_TIME: Tag = Tag { group: 0x3008, element: 0x0134 };
+pub const SPECIFIED_NUMBER_OF_PULSES: Tag = Tag { group: 0x3008, element: 0x0136 };
+pub const DELIVERED_NUMBER_OF_PULSES: Tag = Tag { group: 0x3008, element: 0x0138 };
+pub const SPECIFIED_PULSE_REPETITION_INTERVAL: Tag = Tag { group: 0x3008, element: 0x013A };
+pub const DELIVERED_PULSE_REPETITION_INTERVAL: Tag = Tag { group: 0x3008, element: 0x013C };
+pub const RECORDED_SOURCE_APPLICATOR_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0140 };
+pub const REFERENCED_SOURCE_APPLICATOR_NUMBER: Tag = Tag { group: 0x3008, element: 0x0142 };
+pub const RECORDED_CHANNEL_SHIELD_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0150 };
+pub const REFERENCED_CHANNEL_SHIELD_NUMBER: Tag = Tag { group: 0x3008, element: 0x0152 };
+pub const BRACHY_CONTROL_POINT_DELIVERED_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0160 };
+pub const SAFE_POSITION_EXIT_DATE: Tag = Tag { group: 0x3008, element: 0x0162 };
+pub const SAFE_POSITION_EXIT_TIME: Tag = Tag { group: 0x3008, element: 0x0164 };
+pub const SAFE_POSITION_RETURN_DATE: Tag = Tag { group: 0x3008, element: 0x0166 };
+pub const SAFE_POSITION_RETURN_TIME: Tag = Tag { group: 0x3008, element: 0x0168 };
+pub const CURRENT_TREATMENT_STATUS: Tag = Tag { group: 0x3008, element: 0x0200 };
+pub const TREATMENT_STATUS_COMMENT: Tag = Tag { group: 0x3008, element: 0x0202 };
+pub const FRACTION_GROUP_SUMMARY_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0220 };
+pub const REFERENCED_FRACTION_NUMBER: Tag = Tag { group: 0x3008, element: 0x0223 };
+pub const FRACTION_GROUP_TYPE: Tag = Tag { group: 0x3008, element: 0x0224 };
+pub const BEAM_STOPPER_POSITION: Tag = Tag { group: 0x3008, element: 0x0230 };
+pub const FRACTION_STATUS_SUMMARY_SEQUENCE: Tag = Tag { group: 0x3008, element: 0x0240 };
+pub const TREATMENT_DATE: Tag = Tag { group: 0x3008, element: 0x0250 };
+pub const TREATMENT_TIME: Tag = Tag { group: 0x3008, element: 0x0251 };
+pub const RT_PLAN_LABEL: Tag = Tag { group: 0x300A, element: 0x0002 };
+pub const RT_PLAN_NAME: Tag = Tag { group: 0x300A, element: 0x0003 };
+pub const RT_PLAN_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x0004 };
+pub const RT_PLAN_DATE: Tag = Tag { group: 0x300A, element: 0x0006 };
+pub const RT_PLAN_TIME: Tag = Tag { group: 0x300A, element: 0x0007 };
+pub const TREATMENT_PROTOCOLS: Tag = Tag { group: 0x300A, element: 0x0009 };
+pub const PLAN_INTENT: Tag = Tag { group: 0x300A, element: 0x000A };
+pub const TREATMENT_SITES: Tag = Tag { group: 0x300A, element: 0x000B };
+pub const RT_PLAN_GEOMETRY: Tag = Tag { group: 0x300A, element: 0x000C };
+pub const PRESCRIPTION_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x000E };
+pub const DOSE_REFERENCE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0010 };
+pub const DOSE_REFERENCE_NUMBER: Tag = Tag { group: 0x300A, element: 0x0012 };
+pub const DOSE_REFERENCE_UID: Tag = Tag { group: 0x300A, element: 0x0013 };
+pub const DOSE_REFERENCE_STRUCTURE_TYPE: Tag = Tag { group: 0x300A, element: 0x0014 };
+pub const NOMINAL_BEAM_ENERGY_UNIT: Tag = Tag { group: 0x300A, element: 0x0015 };
+pub const DOSE_REFERENCE_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x0016 };
+pub const DOSE_REFERENCE_POINT_COORDINATES: Tag = Tag { group: 0x300A, element: 0x0018 };
+pub const NOMINAL_PRIOR_DOSE: Tag = Tag { group: 0x300A, element: 0x001A };
+pub const DOSE_REFERENCE_TYPE: Tag = Tag { group: 0x300A, element: 0x0020 };
+pub const CONSTRAINT_WEIGHT: Tag = Tag { group: 0x300A, element: 0x0021 };
+pub const DELIVERY_WARNING_DOSE: Tag = Tag { group: 0x300A, element: 0x0022 };
+pub const DELIVERY_MAXIMUM_DOSE: Tag = Tag { group: 0x300A, element: 0x0023 };
+pub const TARGET_MINIMUM_DOSE: Tag = Tag { group: 0x300A, element: 0x0025 };
+pub const TARGET_PRESCRIPTION_DOSE: Tag = Tag { group: 0x300A, element: 0x0026 };
+pub const TARGET_MAXIMUM_DOSE: Tag = Tag { group: 0x300A, element: 0x0027 };
+pub const TARGET_UNDERDOSE_VOLUME_FRACTION: Tag = Tag { group: 0x300A, element: 0x0028 };
+pub const ORGAN_AT_RISK_FULL_VOLUME_DOSE: Tag = Tag { group: 0x300A, element: 0x002A };
+pub const ORGAN_AT_RISK_LIMIT_DOSE: Tag = Tag { group: 0x300A, element: 0x002B };
+pub const ORGAN_AT_RISK_MAXIMUM_DOSE: Tag = Tag { group: 0x300A, element: 0x002C };
+pub const ORGAN_AT_RISK_OVERDOSE_VOLUME_FRACTION: Tag = Tag { group: 0x300A, element: 0x002D };
+pub const TOLERANCE_TABLE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0040 };
+pub const TOLERANCE_TABLE_NUMBER: Tag = Tag { group: 0x300A, element: 0x0042 };
+pub const TOLERANCE_TABLE_LABEL: Tag = Tag { group: 0x300A, element: 0x0043 };
+pub const GANTRY_ANGLE_TOLERANCE: Tag = Tag { group: 0x300A, element: 0x0044 };
+pub const BEAM_LIMITING_DEVICE_ANGLE_TOLERANCE: Tag = Tag { group: 0x300A, element: 0x0046 };
+pub const BEAM_LIMITING_DEVICE_TOLERANCE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0048 };
+pub const BEAM_LIMITING_DEVICE_POSITION_TOLERANCE: Tag = Tag { group: 0x300A, element: 0x004A };
+pub const PATIENT_SUPPORT_ANGLE_TOLERANCE: Tag = Tag { group: 0x300A, element: 0x004C };
+pub const TABLE_TOP_ECCENTRIC_ANGLE_TOLERANCE: Tag = Tag { group: 0x300A, element: 0x004E };
+pub const TABLE_TOP_VERTICAL_POSITION_TOLERANCE: Tag = Tag { group: 0x300A, element: 0x0051 };
+pub const TABLE_TOP_LONGITUDINAL_POSITION_TOLERANCE: Tag = Tag { group: 0x300A, element: 0x0052 };
+pub const TABLE_TOP_LATERAL_POSITION_TOLERANCE: Tag = Tag { group: 0x300A, element: 0x0053 };
+pub const RT_PLAN_RELATIONSHIP: Tag = Tag { group: 0x300A, element: 0x0055 };
+pub const FRACTION_GROUP_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0070 };
+pub const FRACTION_GROUP_NUMBER: Tag = Tag { group: 0x300A, element: 0x0071 };
+pub const FRACTION_GROUP_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x0072 };
+pub const NUMBER_OF_FRACTIONS_PLANNED: Tag = Tag { group: 0x300A, element: 0x0078 };
+pub const NUMBER_OF_FRACTION_PATTERN_DIGITS_PER_DAY: Tag = Tag { group: 0x300A, element: 0x0079 };
+pub const REPEAT_FRACTION_CYCLE_LENGTH: Tag = Tag { group: 0x300A, element: 0x007A };
+pub const FRACTION_PATTERN: Tag = Tag { group: 0x300A, element: 0x007B };
+pub const NUMBER_OF_BEAMS: Tag = Tag { group: 0x300A, element: 0x0080 };
+pub const BEAM_DOSE_SPECIFICATION_POINT: Tag = Tag { group: 0x300A, element: 0x0082 };
+pub const BEAM_DOSE: Tag = Tag { group: 0x300A, element: 0x0084 };
+pub const BEAM_METERSET: Tag = Tag { group: 0x300A, element: 0x0086 };
+pub const NUMBER_OF_BRACHY_APPLICATION_SETUPS: Tag = Tag { group: 0x300A, element: 0x00A0 };
+pub const BRACHY_APPLICATION_SETUP_DOSE_SPECIFICATION_POINT: Tag = Tag { group: 0x300A, element: 0x00A2 };
+pub const BRACHY_APPLICATION_SETUP_DOSE: Tag = Tag { group: 0x300A, element: 0x00A4 };
+pub const BEAM_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x00B0 };
+pub const TREATMENT_MACHINE_NAME: Tag = Tag { group: 0x300A, element: 0x00B2 };
+pub const PRIMARY_DOSIMETER_UNIT: Tag = Tag { group: 0x300A, element: 0x00B3 };
+pub const SOURCE_AXIS_DISTANCE: Tag = Tag { group: 0x300A, element: 0x00B4 };
+pub const BEAM_LIMITING_DEVICE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x00B6 };
+pub const RT_BEAM_LIMITING_DEVICE_TYPE: Tag = Tag { group: 0x300A, element: 0x00B8 };
+pub const SOURCE_TO_BEAM_LIMITING_DEVICE_DISTANCE: Tag = Tag { group: 0x300A, element: 0x00BA };
+pub const ISOCENTER_TO_BEAM_LIMITING_DEVICE_DISTANCE: Tag = Tag { group: 0x300A, element: 0x00BB };
+pub const NUMBER_OF_LEAF_JAW_PAIRS: Tag = Tag { group: 0x300A, element: 0x00BC };
+pub const LEAF_POSITION_BOUNDARIES: Tag = Tag { group: 0x300A, element: 0x00BE };
+pub const BEAM_NUMBER: Tag = Tag { group: 0x300A, element: 0x00C0 };
+pub const BEAM_NAME: Tag = Tag { group: 0x300A, element: 0x00C2 };
+pub const BEAM_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x00C3 };
+pub const BEAM_TYPE: Tag = Tag { group: 0x300A, element: 0x00C4 };
+pub const RADIATION_TYPE: Tag = Tag { group: 0x300A, element: 0x00C6 };
+pub const HIGH_DOSE_TECHNIQUE_TYPE: Tag = Tag { group: 0x300A, element: 0x00C7 };
+pub const REFERENCE_IMAGE_NUMBER: Tag = Tag { group: 0x300A, element: 0x00C8 };
+pub const PLANNED_VERIFICATION_IMAGE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x00CA };
+pub const IMAGING_DEVICE_SPECIFIC_ACQUISITION_PARAMETERS: Tag = Tag { group: 0x300A, element: 0x00CC };
+pub const TREATMENT_DELIVERY_TYPE: Tag = Tag { group: 0x300A, element: 0x00CE };
+pub const NUMBER_OF_WEDGES: Tag = Tag { group: 0x300A, element: 0x00D0 };
+pub const WEDGE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x00D1 };
+pub const WEDGE_NUMBER: Tag = Tag { group: 0x300A, element: 0x00D2 };
+pub const WEDGE_TYPE: Tag = Tag { group: 0x300A, element: 0x00D3 };
+pub const WEDGE_ID: Tag = Tag { group: 0x300A, element: 0x00D4 };
+pub const WEDGE_ANGLE: Tag = Tag { group: 0x300A, element: 0x00D5 };
+pub const WEDGE_FACTOR: Tag = Tag { group: 0x300A, element: 0x00D6 };
+pub const WEDGE_ORIENTATION: Tag = Tag { group: 0x300A, element: 0x00D8 };
+pub const SOURCE_TO_WEDGE_TRAY_DISTANCE: Tag = Tag { group: 0x300A, element: 0x00DA };
+pub const NUMBER_OF_COMPENSATORS: Tag = Tag { group: 0x300A, element: 0x00E0 };
+pub const MATERIAL_ID: Tag = Tag { group: 0x300A, element: 0x00E1 };
+pub const TOTAL_COMPENSATOR_TRAY_FACTOR: Tag = Tag { group: 0x300A, element: 0x00E2 };
+pub const COMPENSATOR_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x00E3 };
+pub const COMPENSATOR_NUMBER: Tag = Tag { group: 0x300A, element: 0x00E4 };
+pub const COMPENSATOR_ID: Tag = Tag { group: 0x300A, element: 0x00E5 };
+pub const SOURCE_TO_COMPENSATOR_TRAY_DISTANCE: Tag = Tag { group: 0x300A, element: 0x00E6 };
+pub const COMPENSATOR_ROWS: Tag = Tag { group: 0x300A, element: 0x00E7 };
+pub const COMPENSATOR_COLUMNS: Tag = Tag { group: 0x300A, element: 0x00E8 };
+pub const COMPENSATOR_PIXEL_SPACING: Tag = Tag { group: 0x300A, element: 0x00E9 };
+pub const COMPENSATOR_POSITION: Tag = Tag { group: 0x300A, element: 0x00EA };
+pub const COMPENSATOR_TRANSMISSION_DATA: Tag = Tag { group: 0x300A, element: 0x00EB };
+pub const COMPENSATOR_THICKNESS_DATA: Tag = Tag { group: 0x300A, element: 0x00EC };
+pub const NUMBER_OF_BOLI: Tag = Tag { group: 0x300A, element: 0x00ED };
+pub const COMPENSATOR_TYPE: Tag = Tag { group: 0x300A, element: 0x00EE };
+pub const NUMBER_OF_BLOCKS: Tag = Tag { group: 0x300A, element: 0x00F0 };
+pub const TOTAL_BLOCK_TRAY_FACTOR: Tag = Tag { group: 0x300A, element: 0x00F2 };
+pub const BLOCK_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x00F4 };
+pub const BLOCK_TRAY_ID: Tag = Tag { group: 0x300A, element: 0x00F5 };
+pub const SOURCE_TO_BLOCK_TRAY_DISTANCE: Tag = Tag { group: 0x300A, element: 0x00F6 };
+pub const BLOCK_TYPE: Tag = Tag { group: 0x300A, element: 0x00F8 };
+pub const BLOCK_DIVERGENCE: Tag = Tag { group: 0x300A, element: 0x00FA };
+pub const BLOCK_NUMBER: Tag = Tag { group: 0x300A, element: 0x00FC };
+pub const BLOCK_NAME: Tag = Tag { group: 0x300A, element: 0x00FE };
+pub const BLOCK_THICKNESS: Tag = Tag { group: 0x300A, element: 0x0100 };
+pub const BLOCK_TRANSMISSION: Tag = Tag { group: 0x300A, element: 0x0102 };
+pub const BLOCK_NUMBER_OF_POINTS: Tag = Tag { group: 0x300A, element: 0x0104 };
+pub const BLOCK_DATA: Tag = Tag { group: 0x300A, element: 0x0106 };
+pub const APPLICATOR_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0107 };
+pub const APPLICATOR_ID: Tag = Tag { group: 0x300A, element: 0x0108 };
+pub const APPLICATOR_TYPE: Tag = Tag { group: 0x300A, element: 0x0109 };
+pub const APPLICATOR_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x010A };
+pub const CUMULATIVE_DOSE_REFERENCE_COEFFICIENT: Tag = Tag { group: 0x300A, element: 0x010C };
+pub const FINAL_CUMULATIVE_METERSET_WEIGHT: Tag = Tag { group: 0x300A, element: 0x010E };
+pub const NUMBER_OF_CONTROL_POINTS: Tag = Tag { group: 0x300A, element: 0x0110 };
+pub const CONTROL_POINT_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0111 };
+pub const CONTROL_POINT_INDEX: Tag = Tag { group: 0x300A, element: 0x0112 };
+pub const NOMINAL_BEAM_ENERGY: Tag = Tag { group: 0x300A, element: 0x0114 };
+pub const DOSE_RATE_SET: Tag = Tag { group: 0x300A, element: 0x0115 };
+pub const WEDGE_POSITION_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0116 };
+pub const WEDGE_POSITION: Tag = Tag { group: 0x300A, element: 0x0118 };
+pub const BEAM_LIMITING_DEVICE_POSITION_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x011A };
+pub const LEAF_JAW_POSITIONS: Tag = Tag { group: 0x300A, element: 0x011C };
+pub const GANTRY_ANGLE: Tag = Tag { group: 0x300A, element: 0x011E };
+pub const GANTRY_ROTATION_DIRECTION: Tag = Tag { group: 0x300A, element: 0x011F };
+pub const BEAM_LIMITING_DEVICE_ANGLE: Tag = Tag { group: 0x300A, element: 0x0120 };
+pub const BEAM_LIMITING_DEVICE_ROTATION_DIRECTION: Tag = Tag { group: 0x300A, element: 0x0121 };
+pub const PATIENT_SUPPORT_ANGLE: Tag = Tag { group: 0x300A, element: 0x0122 };
+pub const PATIENT_SUPPORT_ROTATION_DIRECTION: Tag = Tag { group: 0x300A, element: 0x0123 };
+pub const TABLE_TOP_ECCENTRIC_AXIS_DISTANCE: Tag = Tag { group: 0x300A, element: 0x0124 };
+pub const TABLE_TOP_ECCENTRIC_ANGLE: Tag = Tag { group: 0x300A, element: 0x0125 };
+pub const TABLE_TOP_ECCENTRIC_ROTATION_DIRECTION: Tag = Tag { group: 0x300A, element: 0x0126 };
+pub const TABLE_TOP_VERTICAL_POSITION: Tag = Tag { group: 0x300A, element: 0x0128 };
+pub const TABLE_TOP_LONGITUDINAL_POSITION: Tag = Tag { group: 0x300A, element: 0x0129 };
+pub const TABLE_TOP_LATERAL_POSITION: Tag = Tag { group: 0x300A, element: 0x012A };
+pub const ISOCENTER_POSITION: Tag = Tag { group: 0x300A, element: 0x012C };
+pub const SURFACE_ENTRY_POINT: Tag = Tag { group: 0x300A, element: 0x012E };
+pub const SOURCE_TO_SURFACE_DISTANCE: Tag = Tag { group: 0x300A, element: 0x0130 };
+pub const SOURCE_TO_EXTERNAL_CONTOUR_DISTANCE: Tag = Tag { group: 0x300A, element: 0x0131 };
+pub const EXTERNAL_CONTOUR_ENTRY_POINT: Tag = Tag { group: 0x300A, element: 0x0132 };
+pub const CUMULATIVE_METERSET_WEIGHT: Tag = Tag { group: 0x300A, element: 0x0134 };
+pub const TABLE_TOP_PITCH_ANGLE: Tag = Tag { group: 0x300A, element: 0x0140 };
+pub const TABLE_TOP_PITCH_ROTATION_DIRECTION: Tag = Tag { group: 0x300A, element: 0x0142 };
+pub const TABLE_TOP_ROLL_ANGLE: Tag = Tag { group: 0x300A, element: 0x0144 };
+pub const TABLE_TOP_ROLL_ROTATION_DIRECTION: Tag = Tag { group: 0x300A, element: 0x0146 };
+pub const HEAD_FIXATION_ANGLE: Tag = Tag { group: 0x300A, element: 0x0148 };
+pub const GANTRY_PITCH_ANGLE: Tag = Tag { group: 0x300A, element: 0x014A };
+pub const GANTRY_PITCH_ROTATION_DIRECTION: Tag = Tag { group: 0x300A, element: 0x014C };
+pub const GANTRY_PITCH_ANGLE_TOLERANCE: Tag = Tag { group: 0x300A, element: 0x014E };
+pub const PATIENT_SETUP_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0180 };
+pub const PATIENT_SETUP_NUMBER: Tag = Tag { group: 0x300A, element: 0x0182 };
+pub const PATIENT_SETUP_LABEL: Tag = Tag { group: 0x300A, element: 0x0183 };
+pub const PATIENT_ADDITIONAL_POSITION: Tag = Tag { group: 0x300A, element: 0x0184 };
+pub const FIXATION_DEVICE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0190 };
+pub const FIXATION_DEVICE_TYPE: Tag = Tag { group: 0x300A, element: 0x0192 };
+pub const FIXATION_DEVICE_LABEL: Tag = Tag { group: 0x300A, element: 0x0194 };
+pub const FIXATION_DEVICE_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x0196 };
+pub const FIXATION_DEVICE_POSITION: Tag = Tag { group: 0x300A, element: 0x0198 };
+pub const FIXATION_DEVICE_PITCH_ANGLE: Tag = Tag { group: 0x300A, element: 0x0199 };
+pub const FIXATION_DEVICE_ROLL_ANGLE: Tag = Tag { group: 0x300A, element: 0x019A };
+pub const SHIELDING_DEVICE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x01A0 };
+pub const SHIELDING_DEVICE_TYPE: Tag = Tag { group: 0x300A, element: 0x01A2 };
+pub const SHIELDING_DEVICE_LABEL: Tag = Tag { group: 0x300A, element: 0x01A4 };
+pub const SHIELDING_DEVICE_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x01A6 };
+pub const SHIELDING_DEVICE_POSITION: Tag = Tag { group: 0x300A, element: 0x01A8 };
+pub const SETUP_TECHNIQUE: Tag = Tag { group: 0x300A, element: 0x01B0 };
+pub const SETUP_TECHNIQUE_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x01B2 };
+pub const SETUP_DEVICE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x01B4 };
+pub const SETUP_DEVICE_TYPE: Tag = Tag { group: 0x300A, element: 0x01B6 };
+pub const SETUP_DEVICE_LABEL: Tag = Tag { group: 0x300A, element: 0x01B8 };
+pub const SETUP_DEVICE_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x01BA };
+pub const SETUP_DEVICE_PARAMETER: Tag = Tag { group: 0x300A, element: 0x01BC };
+pub const SETUP_REFERENCE_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x01D0 };
+pub const TABLE_TOP_VERTICAL_SETUP_DISPLACEMENT: Tag = Tag { group: 0x300A, element: 0x01D2 };
+pub const TABLE_TOP_LONGITUDINAL_SETUP_DISPLACEMENT: Tag = Tag { group: 0x300A, element: 0x01D4 };
+pub const TABLE_TOP_LATERAL_SETUP_DISPLACEMENT: Tag = Tag { group: 0x300A, element: 0x01D6 };
+pub const BRACHY_TREATMENT_TECHNIQUE: Tag = Tag { group: 0x300A, element: 0x0200 };
+pub const BRACHY_TREATMENT_TYPE: Tag = Tag { group: 0x300A, element: 0x0202 };
+pub const TREATMENT_MACHINE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0206 };
+pub const SOURCE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0210 };
+pub const SOURCE_NUMBER: Tag = Tag { group: 0x300A, element: 0x0212 };
+pub const SOURCE_TYPE: Tag = Tag { group: 0x300A, element: 0x0214 };
+pub const SOURCE_MANUFACTURER: Tag = Tag { group: 0x300A, element: 0x0216 };
+pub const ACTIVE_SOURCE_DIAMETER: Tag = Tag { group: 0x300A, element: 0x0218 };
+pub const ACTIVE_SOURCE_LENGTH: Tag = Tag { group: 0x300A, element: 0x021A };
+pub const SOURCE_MODEL_ID: Tag = Tag { group: 0x300A, element: 0x021B };
+pub const SOURCE_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x021C };
+pub const SOURCE_ENCAPSULATION_NOMINAL_THICKNESS: Tag = Tag { group: 0x300A, element: 0x0222 };
+pub const SOURCE_ENCAPSULATION_NOMINAL_TRANSMISSION: Tag = Tag { group: 0x300A, element: 0x0224 };
+pub const SOURCE_ISOTOPE_NAME: Tag = Tag { group: 0x300A, element: 0x0226 };
+pub const SOURCE_ISOTOPE_HALF_LIFE: Tag = Tag { group: 0x300A, element: 0x0228 };
+pub const SOURCE_STRENGTH_UNITS: Tag = Tag { group: 0x300A, element: 0x0229 };
+pub const REFERENCE_AIR_KERMA_RATE: Tag = Tag { group: 0x300A, element: 0x022A };
+pub const SOURCE_STRENGTH: Tag = Tag { group: 0x300A, element: 0x022B };
+pub const SOURCE_STRENGTH_REFERENCE_DATE: Tag = Tag { group: 0x300A, element: 0x022C };
+pub const SOURCE_STRENGTH_REFERENCE_TIME: Tag = Tag { group: 0x300A, element: 0x022E };
+pub const APPLICATION_SETUP_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0230 };
+pub const APPLICATION_SETUP_TYPE: Tag = Tag { group: 0x300A, element: 0x0232 };
+pub const APPLICATION_SETUP_NUMBER: Tag = Tag { group: 0x300A, element: 0x0234 };
+pub const APPLICATION_SETUP_NAME: Tag = Tag { group: 0x300A, element: 0x0236 };
+pub const APPLICATION_SETUP_MANUFACTURER: Tag = Tag { group: 0x300A, element: 0x0238 };
+pub const TEMPLATE_NUMBER: Tag = Tag { group: 0x300A, element: 0x0240 };
+pub const TEMPLATE_TYPE: Tag = Tag { group: 0x300A, element: 0x0242 };
+pub const TEMPLATE_NAME: Tag = Tag { group: 0x300A, element: 0x0244 };
+pub const TOTAL_REFERENCE_AIR_KERMA: Tag = Tag { group: 0x300A, element: 0x0250 };
+pub const BRACHY_ACCESSORY_DEVICE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0260 };
+pub const BRACHY_ACCESSORY_DEVICE_NUMBER: Tag = Tag { group: 0x300A, element: 0x0262 };
+pub const BRACHY_ACCESSORY_DEVICE_ID: Tag = Tag { group: 0x300A, element: 0x0263 };
+pub const BRACHY_ACCESSORY_DEVICE_TYPE: Tag = Tag { group: 0x300A, element: 0x0264 };
+pub const BRACHY_ACCESSORY_DEVICE_NAME: Tag = Tag { group: 0x300A, element: 0x0266 };
+pub const BRACHY_ACCESSORY_DEVICE_NOMINAL_THICKNESS: Tag = Tag { group: 0x300A, element: 0x026A };
+pub const BRACHY_ACCESSORY_DEVICE_NOMINAL_TRANSMISSION: Tag = Tag { group: 0x300A, element: 0x026C };
+pub const CHANNEL_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0280 };
+pub const CHANNEL_NUMBER: Tag = Tag { group: 0x300A, element: 0x0282 };
+pub const CHANNEL_LENGTH: Tag = Tag { group: 0x300A, element: 0x0284 };
+pub const CHANNEL_TOTAL_TIME: Tag = Tag { group: 0x300A, element: 0x0286 };
+pub const SOURCE_MOVEMENT_TYPE: Tag = Tag { group: 0x300A, element: 0x0288 };
+pub const NUMBER_OF_PULSES: Tag = Tag { group: 0x300A, element: 0x028A };
+pub const PULSE_REPETITION_INTERVAL: Tag = Tag { group: 0x300A, element: 0x028C };
+pub const SOURCE_APPLICATOR_NUMBER: Tag = Tag { group: 0x300A, element: 0x0290 };
+pub const SOURCE_APPLICATOR_ID: Tag = Tag { group: 0x300A, element: 0x0291 };
+pub const SOURCE_APPLICATOR_TYPE: Tag = Tag { group: 0x300A, element: 0x0292 };
+pub const SOURCE_APPLICATOR_NAME: Tag = Tag { group: 0x300A, element: 0x0294 };
+pub const SOURCE_APPLICATOR_LENGTH: Tag = Tag { group: 0x300A, element: 0x0296 };
+pub const SOURCE_APPLICATOR_MANUFACTURER: Tag = Tag { group: 0x300A, element: 0x0298 };
+pub const SOURCE_APPLICATOR_WALL_NOMINAL_THICKNESS: Tag = Tag { group: 0x300A, element: 0x029C };
+pub const SOURCE_APPLICATOR_WALL_NOMINAL_TRANSMISSION: Tag = Tag { group: 0x300A, element: 0x029E };
+pub const SOURCE_APPLICATOR_STEP_SIZE: Tag = Tag { group: 0x300A, element: 0x02A0 };
+pub const TRANSFER_TUBE_NUMBER: Tag = Tag { group: 0x300A, element: 0x02A2 };
+pub const TRANSFER_TUBE_LENGTH: Tag = Tag { group: 0x300A, element: 0x02A4 };
+pub const CHANNEL_SHIELD_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x02B0 };
+pub const CHANNEL_SHIELD_NUMBER: Tag = Tag { group: 0x300A, element: 0x02B2 };
+pub const CHANNEL_SHIELD_ID: Tag = Tag { group: 0x300A, element: 0x02B3 };
+pub const CHANNEL_SHIELD_NAME: Tag = Tag { group: 0x300A, element: 0x02B4 };
+pub const CHANNEL_SHIELD_NOMINAL_THICKNESS: Tag = Tag { group: 0x300A, element: 0x02B8 };
+pub const CHANNEL_SHIELD_NOMINAL_TRANSMISSION: Tag = Tag { group: 0x300A, element: 0x02BA };
+pub const FINAL_CUMULATIVE_TIME_WEIGHT: Tag = Tag { group: 0x300A, element: 0x02C8 };
+pub const BRACHY_CONTROL_POINT_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x02D0 };
+pub const CONTROL_POINT_RELATIVE_POSITION: Tag = Tag { group: 0x300A, element: 0x02D2 };
+pub const CONTROL_POINT_3D_POSITION: Tag = Tag { group: 0x300A, element: 0x02D4 };
+pub const CUMULATIVE_TIME_WEIGHT: Tag = Tag { group: 0x300A, element: 0x02D6 };
+pub const COMPENSATOR_DIVERGENCE: Tag = Tag { group: 0x300A, element: 0x02E0 };
+pub const COMPENSATOR_MOUNTING_POSITION: Tag = Tag { group: 0x300A, element: 0x02E1 };
+pub const SOURCE_TO_COMPENSATOR_DISTANCE: Tag = Tag { group: 0x300A, element: 0x02E2 };
+pub const TOTAL_COMPENSATOR_TRAY_WATER_EQUIVALENT_THICKNESS: Tag = Tag { group: 0x300A, element: 0x02E3 };
+pub const ISOCENTER_TO_COMPENSATOR_TRAY_DISTANCE: Tag = Tag { group: 0x300A, element: 0x02E4 };
+pub const COMPENSATOR_COLUMN_OFFSET: Tag = Tag { group: 0x300A, element: 0x02E5 };
+pub const ISOCENTER_TO_COMPENSATOR_DISTANCES: Tag = Tag { group: 0x300A, element: 0x02E6 };
+pub const COMPENSATOR_RELATIVE_STOPPING_POWER_RATIO: Tag = Tag { group: 0x300A, element: 0x02E7 };
+pub const COMPENSATOR_MILLING_TOOL_DIAMETER: Tag = Tag { group: 0x300A, element: 0x02E8 };
+pub const ION_RANGE_COMPENSATOR_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x02EA };
+pub const COMPENSATOR_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x02EB };
+pub const RADIATION_MASS_NUMBER: Tag = Tag { group: 0x300A, element: 0x0302 };
+pub const RADIATION_ATOMIC_NUMBER: Tag = Tag { group: 0x300A, element: 0x0304 };
+pub const RADIATION_CHARGE_STATE: Tag = Tag { group: 0x300A, element: 0x0306 };
+pub const SCAN_MODE: Tag = Tag { group: 0x300A, element: 0x0308 };
+pub const VIRTUAL_SOURCE_AXIS_DISTANCES: Tag = Tag { group: 0x300A, element: 0x030A };
+pub const SNOUT_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x030C };
+pub const SNOUT_POSITION: Tag = Tag { group: 0x300A, element: 0x030D };
+pub const SNOUT_ID: Tag = Tag { group: 0x300A, element: 0x030F };
+pub const NUMBER_OF_RANGE_SHIFTERS: Tag = Tag { group: 0x300A, element: 0x0312 };
+pub const RANGE_SHIFTER_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0314 };
+pub const RANGE_SHIFTER_NUMBER: Tag = Tag { group: 0x300A, element: 0x0316 };
+pub const RANGE_SHIFTER_ID: Tag = Tag { group: 0x300A, element: 0x0318 };
+pub const RANGE_SHIFTER_TYPE: Tag = Tag { group: 0x300A, element: 0x0320 };
+pub const RANGE_SHIFTER_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x0322 };
+pub const NUMBER_OF_LATERAL_SPREADING_DEVICES: Tag = Tag { group: 0x300A, element: 0x0330 };
+pub const LATERAL_SPREADING_DEVICE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0332 };
+pub const LATERAL_SPREADING_DEVICE_NUMBER: Tag = Tag { group: 0x300A, element: 0x0334 };
+pub const LATERAL_SPREADING_DEVICE_ID: Tag = Tag { group: 0x300A, element: 0x0336 };
+pub const LATERAL_SPREADING_DEVICE_TYPE: Tag = Tag { group: 0x300A, element: 0x0338 };
+pub const LATERAL_SPREADING_DEVICE_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x033A };
+pub const LATERAL_SPREADING_DEVICE_WATER_EQUIVALENT_THICKNESS: Tag = Tag { group: 0x300A, element: 0x033C };
+pub const NUMBER_OF_RANGE_MODULATORS: Tag = Tag { group: 0x300A, element: 0x0340 };
+pub const RANGE_MODULATOR_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0342 };
+pub const RANGE_MODULATOR_NUMBER: Tag = Tag { group: 0x300A, element: 0x0344 };
+pub const RANGE_MODULATOR_ID: Tag = Tag { group: 0x300A, element: 0x0346 };
+pub const RANGE_MODULATOR_TYPE: Tag = Tag { group: 0x300A, element: 0x0348 };
+pub const RANGE_MODULATOR_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x034A };
+pub const BEAM_CURRENT_MODULATION_ID: Tag = Tag { group: 0x300A, element: 0x034C };
+pub const PATIENT_SUPPORT_TYPE: Tag = Tag { group: 0x300A, element: 0x0350 };
+pub const PATIENT_SUPPORT_ID: Tag = Tag { group: 0x300A, element: 0x0352 };
+pub const PATIENT_SUPPORT_ACCESSORY_CODE: Tag = Tag { group: 0x300A, element: 0x0354 };
+pub const TRAY_ACCESSORY_CODE: Tag = Tag { group: 0x300A, element: 0x0355 };
+pub const FIXATION_LIGHT_AZIMUTHAL_ANGLE: Tag = Tag { group: 0x300A, element: 0x0356 };
+pub const FIXATION_LIGHT_POLAR_ANGLE: Tag = Tag { group: 0x300A, element: 0x0358 };
+pub const METERSET_RATE: Tag = Tag { group: 0x300A, element: 0x035A };
+pub const RANGE_SHIFTER_SETTINGS_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0360 };
+pub const RANGE_SHIFTER_SETTING: Tag = Tag { group: 0x300A, element: 0x0362 };
+pub const ISOCENTER_TO_RANGE_SHIFTER_DISTANCE: Tag = Tag { group: 0x300A, element: 0x0364 };
+pub const RANGE_SHIFTER_WATER_EQUIVALENT_THICKNESS: Tag = Tag { group: 0x300A, element: 0x0366 };
+pub const LATERAL_SPREADING_DEVICE_SETTINGS_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0370 };
+pub const LATERAL_SPREADING_DEVICE_SETTING: Tag = Tag { group: 0x300A, element: 0x0372 };
+pub const ISOCENTER_TO_LATERAL_SPREADING_DEVICE_DISTANCE: Tag = Tag { group: 0x300A, element: 0x0374 };
+pub const RANGE_MODULATOR_SETTINGS_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0380 };
+pub const RANGE_MODULATOR_GATING_START_VALUE: Tag = Tag { group: 0x300A, element: 0x0382 };
+pub const RANGE_MODULATOR_GATING_STOP_VALUE: Tag = Tag { group: 0x300A, element: 0x0384 };
+pub const RANGE_MODULATOR_GATING_START_WATER_EQUIVALENT_THICKNESS: Tag = Tag { group: 0x300A, element: 0x0386 };
+pub const RANGE_MODULATOR_GATING_STOP_WATER_EQUIVALENT_THICKNESS: Tag = Tag { group: 0x300A, element: 0x0388 };
+pub const ISOCENTER_TO_RANGE_MODULATOR_DISTANCE: Tag = Tag { group: 0x300A, element: 0x038A };
+pub const SCAN_SPOT_TUNE_ID: Tag = Tag { group: 0x300A, element: 0x0390 };
+pub const NUMBER_OF_SCAN_SPOT_POSITIONS: Tag = Tag { group: 0x300A, element: 0x0392 };
+pub const SCAN_SPOT_POSITION_MAP: Tag = Tag { group: 0x300A, element: 0x0394 };
+pub const SCAN_SPOT_METERSET_WEIGHTS: Tag = Tag { group: 0x300A, element: 0x0396 };
+pub const SCANNING_SPOT_SIZE: Tag = Tag { group: 0x300A, element: 0x0398 };
+pub const NUMBER_OF_PAINTINGS: Tag = Tag { group: 0x300A, element: 0x039A };
+pub const ION_TOLERANCE_TABLE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x03A0 };
+pub const ION_BEAM_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x03A2 };
+pub const ION_BEAM_LIMITING_DEVICE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x03A4 };
+pub const ION_BLOCK_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x03A6 };
+pub const ION_CONTROL_POINT_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x03A8 };
+pub const ION_WEDGE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x03AA };
+pub const ION_WEDGE_POSITION_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x03AC };
+pub const REFERENCED_SETUP_IMAGE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0401 };
+pub const SETUP_IMAGE_COMMENT: Tag = Tag { group: 0x300A, element: 0x0402 };
+pub const MOTION_SYNCHRONIZATION_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0410 };
+pub const CONTROL_POINT_ORIENTATION: Tag = Tag { group: 0x300A, element: 0x0412 };
+pub const GENERAL_ACCESSORY_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0420 };
+pub const GENERAL_ACCESSORY_ID: Tag = Tag { group: 0x300A, element: 0x0421 };
+pub const GENERAL_ACCESSORY_DESCRIPTION: Tag = Tag { group: 0x300A, element: 0x0422 };
+pub const GENERAL_ACCESSORY_TYPE: Tag = Tag { group: 0x300A, element: 0x0423 };
+pub const GENERAL_ACCESSORY_NUMBER: Tag = Tag { group: 0x300A, element: 0x0424 };
+pub const SOURCE_TO_GENERAL_ACCESSORY_DISTANCE: Tag = Tag { group: 0x300A, element: 0x0425 };
+pub const APPLICATOR_GEOMETRY_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0431 };
+pub const APPLICATOR_APERTURE_SHAPE: Tag = Tag { group: 0x300A, element: 0x0432 };
+pub const APPLICATOR_OPENING: Tag = Tag { group: 0x300A, element: 0x0433 };
+pub const APPLICATOR_OPENING_X: Tag = Tag { group: 0x300A, element: 0x0434 };
+pub const APPLICATOR_OPENING_Y: Tag = Tag { group: 0x300A, element: 0x0435 };
+pub const SOURCE_TO_APPLICATOR_MOUNTING_POSITION_DISTANCE: Tag = Tag { group: 0x300A, element: 0x0436 };
+pub const NUMBER_OF_BLOCK_SLAB_ITEMS: Tag = Tag { group: 0x300A, element: 0x0440 };
+pub const BLOCK_SLAB_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0441 };
+pub const BLOCK_SLAB_THICKNESS: Tag = Tag { group: 0x300A, element: 0x0442 };
+pub const BLOCK_SLAB_NUMBER: Tag = Tag { group: 0x300A, element: 0x0443 };
+pub const DEVICE_MOTION_CONTROL_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0450 };
+pub const DEVICE_MOTION_EXECUTION_MODE: Tag = Tag { group: 0x300A, element: 0x0451 };
+pub const DEVICE_MOTION_OBSERVATION_MODE: Tag = Tag { group: 0x300A, element: 0x0452 };
+pub const DEVICE_MOTION_PARAMETER_CODE_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0453 };
+pub const DISTAL_DEPTH_FRACTION: Tag = Tag { group: 0x300A, element: 0x0501 };
+pub const DISTAL_DEPTH: Tag = Tag { group: 0x300A, element: 0x0502 };
+pub const NOMINAL_RANGE_MODULATION_FRACTIONS: Tag = Tag { group: 0x300A, element: 0x0503 };
+pub const NOMINAL_RANGE_MODULATED_REGION_DEPTHS: Tag = Tag { group: 0x300A, element: 0x0504 };
+pub const DEPTH_DOSE_PARAMETERS_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0505 };
+pub const DELIVERED_DEPTH_DOSE_PARAMETERS_SEQUENCE: Tag = Tag { group: 0x300A, element: 0x0506 };
+pub const DELIVERED_DISTAL_DEPTH_FRACTION: Tag = Tag { group: 0x300A, element: 0x0507 };
+pub const DELIVERED_DISTAL_DEPTH: Tag = Tag { group: 0x300A, element: 0x0508 };
+pub const DELIVERED_NOMINAL_RANGE_MODULATION_FRACTIONS: Tag = Tag { group: 0x300A, element: 0x0509 };
+pub const DELIVERED_NOMINAL_RANGE_MODULATED_REGION_DEPTHS: Tag = Tag { group: 0x300A, element: 0x0510 };
+pub const DELIVERED_REFERENCE_DOSE_DEFINITION: Tag = Tag { group: 0x300A, element: 0x0511 };
+pub const REFERENCE_DOSE_DEFINITION: Tag = Tag { group: 0x300A, element: 0x0512 };
+pub const REFERENCED_RT_PLAN_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0002 };
+pub const REFERENCED_BEAM_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0004 };
+pub const REFERENCED_BEAM_NUMBER: Tag = Tag { group: 0x300C, element: 0x0006 };
+pub const REFERENCED_REFERENCE_IMAGE_NUMBER: Tag = Tag { group: 0x300C, element: 0x0007 };
+pub const START_CUMULATIVE_METERSET_WEIGHT: Tag = Tag { group: 0x300C, element: 0x0008 };
+pub const END_CUMULATIVE_METERSET_WEIGHT: Tag = Tag { group: 0x300C, element: 0x0009 };
+pub const REFERENCED_BRACHY_APPLICATION_SETUP_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x000A };
+pub const REFERENCED_BRACHY_APPLICATION_SETUP_NUMBER: Tag = Tag { group: 0x300C, element: 0x000C };
+pub const REFERENCED_SOURCE_NUMBER: Tag = Tag { group: 0x300C, element: 0x000E };
+pub const REFERENCED_FRACTION_GROUP_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0020 };
+pub const REFERENCED_FRACTION_GROUP_NUMBER: Tag = Tag { group: 0x300C, element: 0x0022 };
+pub const REFERENCED_VERIFICATION_IMAGE_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0040 };
+pub const REFERENCED_REFERENCE_IMAGE_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0042 };
+pub const REFERENCED_DOSE_REFERENCE_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0050 };
+pub const REFERENCED_DOSE_REFERENCE_NUMBER: Tag = Tag { group: 0x300C, element: 0x0051 };
+pub const BRACHY_REFERENCED_DOSE_REFERENCE_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0055 };
+pub const REFERENCED_STRUCTURE_SET_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0060 };
+pub const REFERENCED_PATIENT_SETUP_NUMBER: Tag = Tag { group: 0x300C, element: 0x006A };
+pub const REFERENCED_DOSE_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0080 };
+pub const REFERENCED_TOLERANCE_TABLE_NUMBER: Tag = Tag { group: 0x300C, element: 0x00A0 };
+pub const REFERENCED_BOLUS_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x00B0 };
+pub const REFERENCED_WEDGE_NUMBER: Tag = Tag { group: 0x300C, element: 0x00C0 };
+pub const REFERENCED_COMPENSATOR_NUMBER: Tag = Tag { group: 0x300C, element: 0x00D0 };
+pub const REFERENCED_BLOCK_NUMBER: Tag = Tag { group: 0x300C, element: 0x00E0 };
+pub const REFERENCED_CONTROL_POINT_INDEX: Tag = Tag { group: 0x300C, element: 0x00F0 };
+pub const REFERENCED_CONTROL_POINT_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x00F2 };
+pub const REFERENCED_START_CONTROL_POINT_INDEX: Tag = Tag { group: 0x300C, element: 0x00F4 };
+pub const REFERENCED_STOP_CONTROL_POINT_INDEX: Tag = Tag { group: 0x300C, element: 0x00F6 };
+pub const REFERENCED_RANGE_SHIFTER_NUMBER: Tag = Tag { group: 0x300C, element: 0x0100 };
+pub const REFERENCED_LATERAL_SPREADING_DEVICE_NUMBER: Tag = Tag { group: 0x300C, element: 0x0102 };
+pub const REFERENCED_RANGE_MODULATOR_NUMBER: Tag = Tag { group: 0x300C, element: 0x0104 };
+pub const OMITTED_BEAM_TASK_SEQUENCE: Tag = Tag { group: 0x300C, element: 0x0111 };
+pub const REASON_FOR_OMISSION: Tag = Tag { group: 0x300C, element: 0x0112 };
+pub const REASON_FOR_OMISSION_DESCRIPTION: Tag = Tag { group: 0x300C, element: 0x0113 };
+pub const APPROVAL_STATUS: Tag = Tag { group: 0x300E, element: 0x0002 };
+pub const REVIEW_DATE: Tag = Tag { group: 0x300E, element: 0x0004 };
+pub const REVIEW_TIME: Tag = Tag { group: 0x300E, element: 0x0005 };
+pub const REVIEWER_NAME: Tag = Tag { group: 0x300E, element: 0x0008 };
+pub const ARBITRARY: Tag = Tag { group: 0x4000, element: 0x0010 };
+pub const TEXT_COMMENTS: Tag = Tag { group: 0x4000, element: 0x4000 };
+pub const RESULTS_ID: Tag = Tag { group: 0x4008, element: 0x0040 };
+pub const RESULTS_ID_ISSUER: Tag = Tag { group: 0x4008, element: 0x0042 };
+pub const REFERENCED_INTERPRETATION_SEQUENCE: Tag = Tag { group: 0x4008, element: 0x0050 };
+pub const REPORT_PRODUCTION_STATUS_TRIAL: Tag = Tag { group: 0x4008, element: 0x00FF };
+pub const INTERPRETATION_RECORDED_DATE: Tag = Tag { group: 0x4008, element: 0x0100 };
+pub const INTERPRETATION_RECORDED_TIME: Tag = Tag { group: 0x4008, element: 0x0101 };
+pub const INTERPRETATION_RECORDER: Tag = Tag { group: 0x4008, element: 0x0102 };
+pub const REFERENCE_TO_RECORDED_SOUND: Tag = Tag { group: 0x4008, element: 0x0103 };
+pub const INTERPRETATION_TRANSCRIPTION_DATE: Tag = Tag { group: 0x4008, element: 0x0108 };
+pub const INTERPRETATION_TRANSCRIPTION_TIME: Tag = Tag { group: 0x4008, element: 0x0109 };
+pub const INTERPRETATION_TRANSCRIBER: Tag = Tag { group: 0x4008, element: 0x010A };
+pub const INTERPRETATION_TEXT: Tag = Tag { group: 0x4008, element: 0x010B };
+pub const INTERPRETATION_AUTHOR: Tag = Tag { group: 0x4008, element: 0x010C };
+pub const INTERPRETATION_APPROVER_SEQUENCE: Tag = Tag { group: 0x4008, element: 0x0111 };
+pub const INTERPRETATION_APPROVAL_DATE: Tag = Tag { group: 0x4008, element: 0x0112 };
+pub const INTERPRETATION_APPROVAL_TIME: Tag = Tag { group: 0x4008, element: 0x0113 };
+pub const PHYSICIAN_APPROVING_INTERPRETATION: Tag = Tag { group: 0x4008, element: 0x0114 };
+pub const INTERPRETATION_DIAGNOSIS_DESCRIPTION: Tag = Tag { group: 0x4008, element: 0x0115 };
+pub const INTERPRETATION_DIAGNOSIS_CODE_SEQUENCE: Tag = Tag { group: 0x4008, element: 0x0117 };
+pub const RESULTS_DISTRIBUTION_LIST_SEQUENCE: Tag = Tag { group: 0x4008, element: 0x0118 };
+pub const DISTRIBUTION_NAME: Tag = Tag { group: 0x4008, element: 0x0119 };
+pub const DISTRIBUTION_ADDRESS: Tag = Tag { group: 0x4008, element: 0x011A };
+pub const INTERPRETATION_ID: Tag = Tag { group: 0x4008, element: 0x0200 };
+pub const INTERPRETATION_ID_ISSUER: Tag = Tag { group: 0x4008, element: 0x0202 };
+pub const INTERPRETATION_TYPE_ID: Tag = Tag { group: 0x4008, element: 0x0210 };
+pub const INTERPRETATION_STATUS_ID: Tag = Tag { group: 0x4008, element: 0x0212 };
+pub const IMPRESSIONS: Tag = Tag { group: 0x4008, element: 0x0300 };
+pub const RESULTS_COMMENTS: Tag = Tag { group: 0x4008, element: 0x4000 };
+pub const MAC_PARAMETERS_SEQUENCE: Tag = Tag { group: 0x4FFE, element: 0x0001 };
+pub const SHARED_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag { group: 0x5200, element: 0x9229 };
+pub const PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag { group: 0x5200, element: 0x9230 };
+pub const WAVEFORM_SEQUENCE: Tag = Tag { group: 0x5400, element: 0x0100 };
+pub const CHANNEL_MINIMUM_VALUE: Tag = Tag { group: 0x5400, element: 0x0110 };
+pub const CHANNEL_MAXIMUM_VALUE: Tag = Tag { group: 0x5400, element: 0x0112 };
+pub const WAVEFORM_BITS_ALLOCATED: Tag = Tag { group: 0x5400, element: 0x1004 };
+pub const WAVEFORM_SAMPLE_INTERPRETATION: Tag = Tag { group: 0x5400, element: 0x1006 };
+pub const WAVEFORM_PADDING_VALUE: Tag = Tag { group: 0x5400, element: 0x100A };
+pub const WAVEFORM_DATA: Tag = Tag { group: 0x5400, element: 0x1010 };
+pub const FIRST_ORDER_PHASE_CORRECTION_ANGLE: Tag = Tag { group: 0x5600, element: 0x0010 };
+pub const SPECTROSCOPY_DATA: Tag = Tag { group: 0x5600, element: 0x0020 };
+pub const EXTENDED_OFFSET_TABLE: Tag = Tag { group: 0x7FE0, element: 0x0001 };
+pub const EXTENDED_OFFSET_TABLE_LENGTHS: Tag = Tag { group: 0x7FE0, element: 0x0002 };
+pub const ENCAPSULATED_PIXEL_DATA_VALUE_TOTAL_LENGTH: Tag = Tag { group: 0x7FE0, element: 0x0003 };
+pub const FLOAT_PIXEL_DATA: Tag = Tag { group: 0x7FE0, element: 0x0008 };
+pub const DOUBLE_FLOAT_PIXEL_DATA: Tag = Tag { group: 0x7FE0, element: 0x0009 };
 pub const PIXEL_DATA: Tag = Tag { group: 0x7FE0, element: 0x0010 };
+pub const COEFFICIENTS_SDVN: Tag = Tag { group: 0x7FE0, element: 0x0020 };
+pub const COEFFICIENTS_SDHN: Tag = Tag { group: 0x7FE0, element: 0x0030 };
+pub const COEFFICIENTS_SDDN: Tag = Tag { group: 0x7FE0, element: 0x0040 };
+pub const DIGITAL_SIGNATURES_SEQUENCE: Tag = Tag { group: 0xFFFA, element: 0xFFFA };
+pub const DATA_SET_TRAILING_PADDING: Tag = Tag { group: 0xFFFC, element: 0xFFFC };
+pub const ITEM: Tag = Tag { group: 0xFFFE, element: 0xE000 };
+pub const ITEM_DELIMITATION_ITEM: Tag = Tag { group: 0xFFFE, element: 0xE00D };
+pub const SEQUENCE_DELIMITATION_ITEM: Tag = Tag { group: 0xFFFE, element: 0xE0DD };
+pub const CURVE_DIMENSIONS: Tag = Tag { group: 0x5000, element: 0x0005 };
+pub const NUMBER_OF_POINTS: Tag = Tag { group: 0x5000, element: 0x0010 };
+pub const TYPE_OF_DATA: Tag = Tag { group: 0x5000, element: 0x0020 };
+pub const CURVE_DESCRIPTION: Tag = Tag { group: 0x5000, element: 0x0022 };
+pub const AXIS_UNITS: Tag = Tag { group: 0x5000, element: 0x0030 };
+pub const AXIS_LABELS: Tag = Tag { group: 0x5000, element: 0x0040 };
+pub const DATA_VALUE_REPRESENTATION: Tag = Tag { group: 0x5000, element: 0x0103 };
+pub const MINIMUM_COORDINATE_VALUE: Tag = Tag { group: 0x5000, element: 0x0104 };
+pub const MAXIMUM_COORDINATE_VALUE: Tag = Tag { group: 0x5000, element: 0x0105 };
+pub const CURVE_RANGE: Tag = Tag { group: 0x5000, element: 0x0106 };
+pub const CURVE_DATA_DESCRIPTOR: Tag = Tag { group: 0x5000, element: 0x0110 };
+pub const COORDINATE_START_VALUE: Tag = Tag { group: 0x5000, element: 0x0112 };
+pub const COORDINATE_STEP_VALUE: Tag = Tag { group: 0x5000, element: 0x0114 };
+pub const CURVE_ACTIVATION_LAYER: Tag = Tag { group: 0x5000, element: 0x1001 };
+pub const AUDIO_TYPE: Tag = Tag { group: 0x5000, element: 0x2000 };
+pub const AUDIO_SAMPLE_FORMAT: Tag = Tag { group: 0x5000, element: 0x2002 };
+pub const NUMBER_OF_CHANNELS: Tag = Tag { group: 0x5000, element: 0x2004 };
+pub const NUMBER_OF_SAMPLES: Tag = Tag { group: 0x5000, element: 0x2006 };
+pub const SAMPLE_RATE: Tag = Tag { group: 0x5000, element: 0x2008 };
+pub const TOTAL_TIME: Tag = Tag { group: 0x5000, element: 0x200A };
+pub const AUDIO_SAMPLE_DATA: Tag = Tag { group: 0x5000, element: 0x200C };
+pub const AUDIO_COMMENTS: Tag = Tag { group: 0x5000, element: 0x200E };
+pub const CURVE_LABEL: Tag = Tag { group: 0x5000, element: 0x2500 };
+pub const CURVE_REFERENCED_OVERLAY_SEQUENCE: Tag = Tag { group: 0x5000, element: 0x2600 };
+pub const CURVE_REFERENCED_OVERLAY_GROUP: Tag = Tag { group: 0x5000, element: 0x2610 };
+pub const CURVE_DATA: Tag = Tag { group: 0x5000, element: 0x3000 };
+pub const OVERLAY_ROWS: Tag = Tag { group: 0x6000, element: 0x0010 };
+pub const OVERLAY_COLUMNS: Tag = Tag { group: 0x6000, element: 0x0011 };
+pub const OVERLAY_PLANES: Tag = Tag { group: 0x6000, element: 0x0012 };
+pub const NUMBER_OF_FRAMES_IN_OVERLAY: Tag = Tag { group: 0x6000, element: 0x0015 };
+pub const OVERLAY_DESCRIPTION: Tag = Tag { group: 0x6000, element: 0x0022 };
+pub const OVERLAY_TYPE: Tag = Tag { group: 0x6000, element: 0x0040 };
+pub const OVERLAY_SUBTYPE: Tag = Tag { group: 0x6000, element: 0x0045 };
+pub const OVERLAY_ORIGIN: Tag = Tag { group: 0x6000, element: 0x0050 };
+pub const IMAGE_FRAME_ORIGIN: Tag = Tag { group: 0x6000, element: 0x0051 };
+pub const OVERLAY_PLANE_ORIGIN: Tag = Tag { group: 0x6000, element: 0x0052 };
+pub const OVERLAY_COMPRESSION_CODE: Tag = Tag { group: 0x6000, element: 0x0060 };
+pub const OVERLAY_COMPRESSION_ORIGINATOR: Tag = Tag { group: 0x6000, element: 0x0061 };
+pub const OVERLAY_COMPRESSION_LABEL: Tag = Tag { group: 0x6000, element: 0x0062 };
+pub const OVERLAY_COMPRESSION_DESCRIPTION: Tag = Tag { group: 0x6000, element: 0x0063 };
+pub const OVERLAY_COMPRESSION_STEP_POINTERS: Tag = Tag { group: 0x6000, element: 0x0066 };
+pub const OVERLAY_REPEAT_INTERVAL: Tag = Tag { group: 0x6000, element: 0x0068 };
+pub const OVERLAY_BITS_GROUPED: Tag = Tag { group: 0x6000, element: 0x0069 };
+pub const OVERLAY_BITS_ALLOCATED: Tag = Tag { group: 0x6000, element: 0x0100 };
+pub const OVERLAY_BIT_POSITION: Tag = Tag { group: 0x6000, element: 0x0102 };
+pub const OVERLAY_FORMAT: Tag = Tag { group: 0x6000, element: 0x0110 };
+pub const OVERLAY_LOCATION: Tag = Tag { group: 0x6000, element: 0x0200 };
+pub const OVERLAY_CODE_LABEL: Tag = Tag { group: 0x6000, element: 0x0800 };
+pub const OVERLAY_NUMBER_OF_TABLES: Tag = Tag { group: 0x6000, element: 0x0802 };
+pub const OVERLAY_CODE_TABLE_LOCATION: Tag = Tag { group: 0x6000, element: 0x0803 };
+pub const OVERLAY_BITS_FOR_CODE_WORD: Tag = Tag { group: 0x6000, element: 0x0804 };
+pub const OVERLAY_ACTIVATION_LAYER: Tag = Tag { group: 0x6000, element: 0x1001 };
+pub const OVERLAY_DESCRIPTOR_GRAY: Tag = Tag { group: 0x6000, element: 0x1100 };
+pub const OVERLAY_DESCRIPTOR_RED: Tag = Tag { group: 0x6000, element: 0x1101 };
+pub const OVERLAY_DESCRIPTOR_GREEN: Tag = Tag { group: 0x6000, element: 0x1102 };
+pub const OVERLAY_DESCRIPTOR_BLUE: Tag = Tag { group: 0x6000, element: 0x1103 };
+pub const OVERLAYS_GRAY: Tag = Tag { group: 0x6000, element: 0x1200 };
+pub const OVERLAYS_RED: Tag = Tag { group: 0x6000, element: 0x1201 };
+pub const OVERLAYS_GREEN: Tag = Tag { group: 0x6000, element: 0x1202 };
+pub const OVERLAYS_BLUE: Tag = Tag { group: 0x6000, element: 0x1203 };
+pub const ROI_AREA: Tag = Tag { group: 0x6000, element: 0x1301 };
+pub const ROI_MEAN: Tag = Tag { group: 0x6000, element: 0x1302 };
+pub const ROI_STANDARD_DEVIATION: Tag = Tag { group: 0x6000, element: 0x1303 };
+pub const OVERLAY_LABEL: Tag = Tag { group: 0x6000, element: 0x1500 };
+pub const OVERLAY_DATA: Tag = Tag { group: 0x6000, element: 0x3000 };
+pub const OVERLAY_COMMENTS: Tag = Tag { group: 0x6000, element: 0x4000 };
+pub const VARIABLE_PIXEL_DATA: Tag = Tag { group: 0x7F00, element: 0x0010 };
+pub const VARIABLE_NEXT_DATA_GROUP: Tag = Tag { group: 0x7F00, element: 0x0011 };
+pub const VARIABLE_COEFFICIENTS_SDVN: Tag = Tag { group: 0x7F00, element: 0x0020 };
+pub const VARIABLE_COEFFICIENTS_SDHN: Tag = Tag { group: 0x7F00, element: 0x0030 };
+pub const VARIABLE_COEFFICIENTS_SDDN: Tag = Tag { group: 0x7F00, element: 0x0040 };
+pub const SOURCE_IMAGE_IDS: Tag = Tag { group: 0x0020, element: 0x3100 };
+pub const ROWS_FOR_NTH_ORDER_COEFFICIENTS: Tag = Tag { group: 0x0028, element: 0x0400 };
+pub const COLUMNS_FOR_NTH_ORDER_COEFFICIENTS: Tag = Tag { group: 0x0028, element: 0x0401 };
+pub const COEFFICIENT_CODING: Tag = Tag { group: 0x0028, element: 0x0402 };
+pub const COEFFICIENT_CODING_POINTERS: Tag = Tag { group: 0x0028, element: 0x0403 };
+pub const CODE_LABEL: Tag = Tag { group: 0x0028, element: 0x0800 };
+pub const NUMBER_OF_TABLES: Tag = Tag { group: 0x0028, element: 0x0802 };
+pub const CODE_TABLE_LOCATION: Tag = Tag { group: 0x0028, element: 0x0803 };
+pub const BITS_FOR_CODE_WORD: Tag = Tag { group: 0x0028, element: 0x0804 };
+pub const IMAGE_DATA_LOCATION: Tag = Tag { group: 0x0028, element: 0x0808 };
+pub const ESCAPE_TRIPLET: Tag = Tag { group: 0x1000, element: 0x0000 };
+pub const RUN_LENGTH_TRIPLET: Tag = Tag { group: 0x1000, element: 0x0001 };
+pub const HUFFMAN_TABLE_SIZE: Tag = Tag { group: 0x1000, element: 0x0002 };
+pub const HUFFMAN_TABLE_TRIPLET: Tag = Tag { group: 0x1000, element: 0x0003 };
+pub const SHIFT_TABLE_SIZE: Tag = Tag { group: 0x1000, element: 0x0004 };
+pub const SHIFT_TABLE_TRIPLET: Tag = Tag { group: 0x1000, element: 0x0005 };
+pub const ZONAL_MAP: Tag = Tag { group: 0x1010, element: 0x0000 };
@@ -1,25 +1,151 @@
-// transfer_syntaxes.rs
+// Generated by `cargo xtask gen-dictionary` from the DICOM standard (PS3.6, PS3.7). Do not edit.
 pub const IMPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2";
 pub const EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1";
+pub const ENCAPSULATED_UNCOMPRESSED_EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1.98";
+pub const DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1.99";
 pub const EXPLICIT_VR_BIG_ENDIAN: &str = "1.2.840.10008.1.2.2";
-pub const JPEG_BASELINE: &str = "1.2.840.10008.1.2.4.50";
-pub const JPEG_BASELINE_PROCESS2_4: &str = "1.2.840.10008.1.2.4.51";
-pub const JPEG_LOSSLESS: &str = "1.2.840.10008.1.2.4.70";
-pub const JPEG_LOSSLESS_NONHIERARCHICAL: &str = "1.2.840.10008.1.2.4.71";
-pub const JPEG_LS_LOSSLESS: &str = "1.2.840.10008.1.2.4.80";
-pub const JPEG_LS_LOSSY: &str = "1.2.840.10008.1.2.4.81";
+pub const JPEG_BASELINE_8_BIT: &str = "1.2.840.10008.1.2.4.50";
+pub const JPEG_EXTENDED_12_BIT: &str = "1.2.840.10008.1.2.4.51";
+pub const JPEG_EXTENDED_35: &str = "1.2.840.10008.1.2.4.52";
+pub const JPEG_SPECTRAL_SELECTION_NON_HIERARCHICAL_68: &str = "1.2.840.10008.1.2.4.53";
+pub const JPEG_SPECTRAL_SELECTION_NON_HIERARCHICAL_79: &str = "1.2.840.10008.1.2.4.54";
+pub const JPEG_FULL_PROGRESSION_NON_HIERARCHICAL_1012: &str = "1.2.840.10008.1.2.4.55";
+pub const JPEG_FULL_PROGRESSION_NON_HIERARCHICAL_1113: &str = "1.2.840.10008.1.2.4.56";
+pub const JPEG_LOSSLESS: &str = "1.2.840.10008.1.2.4.57";
+pub const JPEG_LOSSLESS_NON_HIERARCHICAL_15: &str = "1.2.840.10008.1.2.4.58";
+pub const JPEG_EXTENDED_HIERARCHICAL_1618: &str = "1.2.840.10008.1.2.4.59";
+pub const JPEG_EXTENDED_HIERARCHICAL_1719: &str = "1.2.840.10008.1.2.4.60";
+pub const JPEG_SPECTRAL_SELECTION_HIERARCHICAL_2022: &str = "1.2.840.10008.1.2.4.61";
+pub const JPEG_SPECTRAL_SELECTION_HIERARCHICAL_2123: &str = "1.2.840.10008.1.2.4.62";
+pub const JPEG_FULL_PROGRESSION_HIERARCHICAL_2426: &str = "1.2.840.10008.1.2.4.63";
+pub const JPEG_FULL_PROGRESSION_HIERARCHICAL_2527: &str = "1.2.840.10008.1.2.4.64";
+pub const JPEG_LOSSLESS_HIERARCHICAL_28: &str = "1.2.840.10008.1.2.4.65";
+pub const JPEG_LOSSLESS_HIERARCHICAL_29: &str = "1.2.840.10008.1.2.4.66";
+pub const JPEG_LOSSLESS_SV1: &str = "1.2.840.10008.1.2.4.70";
+pub const JPEGLS_LOSSLESS: &str = "1.2.840.10008.1.2.4.80";
+pub const JPEGLS_NEAR_LOSSLESS: &str = "1.2.840.10008.1.2.4.81";
 pub const JPEG2000_LOSSLESS: &str = "1.2.840.10008.1.2.4.90";
-pub const JPEG2000_LOSSY: &str = "1.2.840.10008.1.2.4.91";
-pub const JPEG2000_PART2_LOSSLESS: &str = "1.2.840.10008.1.2.4.92";
-pub const JPEG2000_PART2_LOSSY: &str = "1.2.840.10008.1.2.4.93";
-pub const DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1.99";
+pub const JPEG2000: &str = "1.2.840.10008.1.2.4.91";
+pub const JPEG2000MC_LOSSLESS: &str = "1.2.840.10008.1.2.4.92";
+pub const JPEG2000MC: &str = "1.2.840.10008.1.2.4.93";
+pub const JPIP_REFERENCED: &str = "1.2.840.10008.1.2.4.94";
+pub const JPIP_REFERENCED_DEFLATE: &str = "1.2.840.10008.1.2.4.95";
+pub const MPEG2MPML: &str = "1.2.840.10008.1.2.4.100";
+pub const MPEG2MPMLF: &str = "1.2.840.10008.1.2.4.100.1";
+pub const MPEG2MPHL: &str = "1.2.840.10008.1.2.4.101";
+pub const MPEG2MPHLF: &str = "1.2.840.10008.1.2.4.101.1";
+pub const MPEG4HP41: &str = "1.2.840.10008.1.2.4.102";
+pub const MPEG4HP41F: &str = "1.2.840.10008.1.2.4.102.1";
+pub const MPEG4HP41BD: &str = "1.2.840.10008.1.2.4.103";
+pub const MPEG4HP41BDF: &str = "1.2.840.10008.1.2.4.103.1";
+pub const MPEG4HP422D: &str = "1.2.840.10008.1.2.4.104";
+pub const MPEG4HP422DF: &str = "1.2.840.10008.1.2.4.104.1";
+pub const MPEG4HP423D: &str = "1.2.840.10008.1.2.4.105";
+pub const MPEG4HP423DF: &str = "1.2.840.10008.1.2.4.105.1";
+pub const MPEG4HP42STEREO: &str = "1.2.840.10008.1.2.4.106";
+pub const MPEG4HP42STEREOF: &str = "1.2.840.10008.1.2.4.106.1";
+pub const HEVCMP51: &str = "1.2.840.10008.1.2.4.107";
+pub const HEVCM10P51: &str = "1.2.840.10008.1.2.4.108";
+pub const JPEGXL_LOSSLESS: &str = "1.2.840.10008.1.2.4.110";
+pub const JPEGXLJPEG_RECOMPRESSION: &str = "1.2.840.10008.1.2.4.111";
+pub const JPEGXL: &str = "1.2.840.10008.1.2.4.112";
+pub const HTJ2K_LOSSLESS: &str = "1.2.840.10008.1.2.4.201";
+pub const HTJ2K_LOSSLESS_RPCL: &str = "1.2.840.10008.1.2.4.202";
+pub const HTJ2K: &str = "1.2.840.10008.1.2.4.203";
+pub const JPIPHTJ2K_REFERENCED: &str = "1.2.840.10008.1.2.4.204";
+pub const JPIPHTJ2K_REFERENCED_DEFLATE: &str = "1.2.840.10008.1.2.4.205";
 pub const RLE_LOSSLESS: &str = "1.2.840.10008.1.2.5";
-pub const MPEG2_MAIN_PROFILE_MAIN_LEVEL: &str = "1.2.840.10008.1.2.4.100";
-pub const MPEG2_MAIN_PROFILE_HIGH_LEVEL: &str = "1.2.840.10008.1.2.4.101";
-pub const MPEG4_AVC_H264_HIGH_PROFILE_LEVEL_41: &str = "1.2.840.10008.1.2.4.102";
-pub const MPEG4_AVC_H264_BD_COMPATIBLE_HIGH_PROFILE_LEVEL_41: &str = "1.2.840.10008.1.2.4.103";
-pub const MPEG4_AVC_H264_HIGH_PROFILE_LEVEL_42_FOR_2D_VIDEO: &str = "1.2.840.10008.1.2.4.104";
-pub const MPEG4_AVC_H264_HIGH_PROFILE_LEVEL_42_FOR_3D_VIDEO: &str = "1.2.840.10008.1.2.4.105";
-pub const MPEG4_AVC_H264_STEREO_HIGH_PROFILE_LEVEL_42: &str = "1.2.840.10008.1.2.4.106";
-pub const HEVC_H265_MAIN_PROFILE_LEVEL_51: &str = "1.2.840.10008.1.2.4.107";
-pub const HEVC_H265_MAIN_10_PROFILE_LEVEL_51: &str = "1.2.840.10008.1.2.4.108";
\ No newline at end of file
+pub const RFC2557MIME_ENCAPSULATION: &str = "1.2.840.10008.1.2.6.1";
+pub const XML_ENCODING: &str = "1.2.840.10008.1.2.6.2";
+pub const TRANSFER_SYNTAXES: [&str; 58] = [
+    IMPLICIT_VR_LITTLE_ENDIAN,
+    EXPLICIT_VR_LITTLE_ENDIAN,
+    ENCAPSULATED_UNCOMPRESSED_EXPLICIT_VR_LITTLE_ENDIAN,
+    DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN,
+    EXPLICIT_VR_BIG_ENDIAN,
+    JPEG_BASELINE_8_BIT,
+    JPEG_EXTENDED_12_BIT,
+    JPEG_EXTENDED_35,
+    JPEG_SPECTRAL_SELECTION_NON_HIERARCHICAL_68,
+    JPEG_SPECTRAL_SELECTION_NON_HIERARCHICAL_79,
+    JPEG_FULL_PROGRESSION_NON_HIERARCHICAL_1012,
+    JPEG_FULL_PROGRESSION_NON_HIERARCHICAL_1113,
+    JPEG_LOSSLESS,
+    JPEG_LOSSLESS_NON_HIERARCHICAL_15,
+    JPEG_EXTENDED_HIERARCHICAL_1618,
+    JPEG_EXTENDED_HIERARCHICAL_1719,
+    JPEG_SPECTRAL_SELECTION_HIERARCHICAL_2022,
+    JPEG_SPECTRAL_SELECTION_HIERARCHICAL_2123,
+    JPEG_FULL_PROGRESSION_HIERARCHICAL_2426,
+    JPEG_FULL_PROGRESSION_HIERARCHICAL_2527,
+    JPEG_LOSSLESS_HIERARCHICAL_28,
+    JPEG_LOSSLESS_HIERARCHICAL_29,
+    JPEG_LOSSLESS_SV1,
+    JPEGLS_LOSSLESS,
+    JPEGLS_NEAR_LOSSLESS,
+    JPEG2000_LOSSLESS,
+    JPEG2000,
+    JPEG2000MC_LOSSLESS,
+    JPEG2000MC,
+    JPIP_REFERENCED,
+    JPIP_REFERENCED_DEFLATE,
+    MPEG2MPML,
+    MPEG2MPMLF,
+    MPEG2MPHL,
+    MPEG2MPHLF,
+    MPEG4HP41,
+    MPEG4HP41F,
+    MPEG4HP41BD,
+    MPEG4HP41BDF,
+    MPEG4HP422D,
+    MPEG4HP422DF,
+    MPEG4HP423D,
+    MPEG4HP423DF,
+    MPEG4HP42STEREO,
+    MPEG4HP42STEREOF,
+    HEVCMP51,
+    HEVCM10P51,
+    JPEGXL_LOSSLESS,
+    JPEGXLJPEG_RECOMPRESSION,
+    JPEGXL,
+    HTJ2K_LOSSLESS,
+    HTJ2K_LOSSLESS_RPCL,
+    HTJ2K,
+    JPIPHTJ2K_REFERENCED,
+    JPIPHTJ2K_REFERENCED_DEFLATE,
+    RLE_LOSSLESS,
+    RFC2557MIME_ENCAPSULATION,
+    XML_ENCODING,
+];
+#[deprecated(note = "renamed to JPEG_BASELINE_8_BIT")]
+pub const JPEG_BASELINE: &str = JPEG_BASELINE_8_BIT;
+#[deprecated(note = "renamed to JPEG_EXTENDED_12_BIT")]
+pub const JPEG_BASELINE_PROCESS2_4: &str = JPEG_EXTENDED_12_BIT;
+#[deprecated(note = "renamed to JPEGLS_LOSSLESS")]
+pub const JPEG_LS_LOSSLESS: &str = JPEGLS_LOSSLESS;
+#[deprecated(note = "renamed to JPEGLS_NEAR_LOSSLESS")]
+pub const JPEG_LS_LOSSY: &str = JPEGLS_NEAR_LOSSLESS;
+#[deprecated(note = "renamed to JPEG2000")]
+pub const JPEG2000_LOSSY: &str = JPEG2000;
+#[deprecated(note = "renamed to JPEG2000MC_LOSSLESS")]
+pub const JPEG2000_PART2_LOSSLESS: &str = JPEG2000MC_LOSSLESS;
+#[deprecated(note = "renamed to JPEG2000MC")]
+pub const JPEG2000_PART2_LOSSY: &str = JPEG2000MC;
+#[deprecated(note = "renamed to MPEG2MPML")]
+pub const MPEG2_MAIN_PROFILE_MAIN_LEVEL: &str = MPEG2MPML;
+#[deprecated(note = "renamed to MPEG2MPHL")]
+pub const MPEG2_MAIN_PROFILE_HIGH_LEVEL: &str = MPEG2MPHL;
+#[deprecated(note = "renamed to MPEG4HP41")]
+pub const MPEG4_AVC_H264_HIGH_PROFILE_LEVEL_41: &str = MPEG4HP41;
+#[deprecated(note = "renamed to MPEG4HP41BD")]
+pub const MPEG4_AVC_H264_BD_COMPATIBLE_HIGH_PROFILE_LEVEL_41: &str = MPEG4HP41BD;
+#[deprecated(note = "renamed to MPEG4HP422D")]
+pub const MPEG4_AVC_H264_HIGH_PROFILE_LEVEL_42_FOR_2D_VIDEO: &str = MPEG4HP422D;
+#[deprecated(note = "renamed to MPEG4HP423D")]
+pub const MPEG4_AVC_H264_HIGH_PROFILE_LEVEL_42_FOR_3D_VIDEO: &str = MPEG4HP423D;
+#[deprecated(note = "renamed to MPEG4HP42STEREO")]
+pub const MPEG4_AVC_H264_STEREO_HIGH_PROFILE_LEVEL_42: &str = MPEG4HP42STEREO;
+#[deprecated(note = "renamed to HEVCMP51")]
+pub const HEVC_H265_MAIN_PROFILE_LEVEL_51: &str = HEVCMP51;
+#[deprecated(note = "renamed to HEVCM10P51")]
+pub const HEVC_H265_MAIN_10_PROFILE_LEVEL_51: &str = HEVCM10P51;
@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use crate::dataset::data_element::DataElement;
+use crate::data_dictionary::data_dictionary::DataDictionary;
+use crate::dicom_constants::numeric::UNDEFINED_LENGTH;
+use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+use crate::dicom_file_parser::parse_error::ParseError;
+use crate::dicom_file_writer::value_writer::value_representation;
+use crate::dicom_json::dicom_json_writer::inline_binary;
+use crate::dicom_xml::dicom_xml_writer::string_values;
+use crate::dataset::value_field::ValueField;
+
+const FILE_META_INFORMATION_GROUP: u16 = 0x0002;
+
+/// Longer values are truncated, e.g. long texts or lists of numbers.
+const MAX_VALUE_CHARACTERS: usize = 64;
+/// Binary values show only their first bytes.
+const MAX_BINARY_BYTES: usize = 16;
+
+const INDENTATION: &str = "    ";
+
+/// Prints datasets as text, one element per line: offset, tag, VR, value length, keyword and value.
+/// Items of sequences are listed below the sequence, indented.
+pub struct DicomDumper {
+    groups: HashSet<u16>,
+    separate_file_meta_information: bool,
+}
+
+impl Default for DicomDumper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DicomDumper {
+    pub fn new() -> Self {
+        Self {
+            groups: HashSet::new(),
+            separate_file_meta_information: false,
+        }
+    }
+
+    /// Prints only top-level elements of the given groups. All groups are printed when none is given.
+    pub fn groups(mut self, groups: &[u16]) -> Self {
+        self.groups = groups.iter().copied().collect();
+        self
+    }
+
+    /// Prints the file meta group under its own heading, before the dataset.
+    pub fn separate_file_meta_information(mut self) -> Self {
+        self.separate_file_meta_information = true;
+        self
+    }
+
+    pub fn dump_file(&self, file_path: &str) -> Result<String, ParseError> {
+        let parsed = DicomFileParser::new().read_all_tags().file_path(file_path).parse()?;
+        Ok(self.dump(&parsed.data_elements))
+    }
+
+    pub fn dump(&self, data_elements: &[DataElement]) -> String {
+        let mut data_elements: Vec<&DataElement> = data_elements.iter()
+            .filter(|data_element| self.groups.is_empty() || self.groups.contains(&data_element.tag.group))
+            .collect();
+        data_elements.sort_by_key(|data_element| data_element.tag);
+
+        let mut dump = String::new();
+
+        if !self.separate_file_meta_information {
+            data_elements.iter().for_each(|data_element| element(&mut dump, data_element, 0));
+            return dump;
+        }
+
+        let (file_meta_information, dataset): (Vec<&DataElement>, Vec<&DataElement>) = data_elements.into_iter()
+            .partition(|data_element| data_element.tag.group == FILE_META_INFORMATION_GROUP);
+
+        dump.push_str("# File Meta Information\n");
+        file_meta_information.iter().for_each(|data_element| element(&mut dump, data_element, 0));
+        dump.push_str("# Dataset\n");
+        dataset.iter().for_each(|data_element| element(&mut dump, data_element, 0));
+
+        dump
+    }
+}
+
+fn element(dump: &mut String, data_element: &DataElement, depth: usize) {
+    let tag = data_element.tag;
+
+    let offset = data_element.offset.map_or("-".to_string(), |offset| offset.to_string());
+    let length = match data_element.value_length {
+        UNDEFINED_LENGTH => "undefined".to_string(),
+        length => length.to_string(),
+    };
+    let keyword = DataDictionary::get(&tag).map_or("?", |entry| entry.keyword);
+
+    writeln!(dump, "{:>10} {}({:04X},{:04X}) {} {:>9} {:<40} {}",
+             offset, INDENTATION.repeat(depth), tag.group, tag.element,
+             value_representation(data_element), length, keyword, value(data_element)).unwrap();
+
+    if let ValueField::SequenceOfItems(sequence) = &data_element.value {
+        for (index, item) in sequence.items.iter().enumerate() {
+            writeln!(dump, "{:>10} {}Item #{}", "", INDENTATION.repeat(depth + 1), index + 1).unwrap();
+
+            let mut item_elements: Vec<&DataElement> = item.data_elements.iter().collect();
+            item_elements.sort_by_key(|data_element| data_element.tag);
+            item_elements.iter().for_each(|data_element| element(dump, data_element, depth + 2));
+        }
+    }
+}
+
+/// Values separated by backslashes, binary values as hexadecimal Little Endian bytes.
+fn value(data_element: &DataElement) -> String {
+    if let Some(bytes) = inline_binary(data_element) {
+        let mut value = bytes.iter()
+            .take(MAX_BINARY_BYTES)
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if bytes.len() > MAX_BINARY_BYTES {
+            value.push_str(" ...");
+        }
+
+        return value;
+    }
+
+    let value = string_values(&data_element.value).join("\\");
+
+    match value.char_indices().nth(MAX_VALUE_CHARACTERS) {
+        Some((end, _)) => format!("[{}...]", &value[..end]),
+        None => format!("[{}]", value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_file_writer::dicom_file_writer::DicomFileWriter;
+    use crate::dicom_json::dicom_json_reader::DicomJsonReader;
+    use super::*;
+
+    #[test]
+    fn test_dump() {
+        let data_elements = DicomJsonReader::new().read(concat!(
+            r#"{"00080018":{"vr":"UI","Value":["1.2.3"]},"#,
+            r#""00081140":{"vr":"SQ","Value":[{"00081155":{"vr":"UI","Value":["1.2.4"]}}]},"#,
+            r#""00280010":{"vr":"US","Value":[512]},"#,
+            r#""7FE00010":{"vr":"OB","InlineBinary":"AAECAwQFBgcICQoLDA0ODxAR"}}"#)).unwrap();
+        let bytes = DicomFileWriter::new().to_bytes(&data_elements).unwrap();
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&bytes).unwrap();
+
+        let dump = DicomDumper::new().groups(&[0x0002, 0x0008, 0x7FE0]).separate_file_meta_information().dump(&parsed.data_elements);
+        let lines: Vec<&str> = dump.lines().collect();
+
+        assert_eq!(lines[0], "# File Meta Information");
+        assert_eq!(lines[1].split_whitespace().collect::<Vec<_>>(), ["144", "(0002,0001)", "OB", "2", "FileMetaInformationVersion", "00", "01"]);
+        assert!(lines.contains(&"# Dataset"));
+        assert!(lines.iter().any(|line| line.trim_start().starts_with("Item #1")));
+        assert!(lines.iter().any(|line| line.contains("        (0008,1155) UI         6 ReferencedSOPInstanceUID")));
+        assert!(lines.last().unwrap().ends_with("PixelData                                00 01 02 03 04 05 06 07 08 09 0A 0B 0C 0D 0E 0F ..."));
+        assert!(!dump.contains("Rows"));
+    }
+}
@@ -2,12 +2,15 @@ use crate::dataset::data_element::DataElement;
 use crate::dataset::value_field::ValueField;
 use crate::traits::cast::{Cast, CastArray};
 use crate::dicom_constants::tags::*;
+use crate::dicom_file_parser::transfer_syntax::TransferSyntax;
 use crate::information_object_definitions::general_series::GeneralSeries;
 use crate::information_object_definitions::general_study::GeneralStudy;
 use crate::information_object_definitions::image_pixel::ImagePixel;
 use crate::information_object_definitions::image_plane::ImagePlane;
 use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
 use crate::information_object_definitions::modality_lut::ModalityLut;
+use crate::value_representations::date_time::{Date, Time};
+use crate::value_representations::person_name::PersonName;
 
 macro_rules! get {
     ($pat: path, $target: expr, $err: expr) => {
@@ -16,7 +19,7 @@ macro_rules! get {
                 $pat(val) => val,
                 _ => {
                     $err.push(DicomFileInconsistency::UnexpectedValueRepresentation(
-                        format!("Expected {} but got {:?} for tag {:?}",
+                        format!("Expected {} but got {:?} for tag {}",
                             stringify!($pat),
                             $target.value,
                             $target.tag)));
@@ -60,6 +63,7 @@ macro_rules! cast_array {
 
 pub struct DicomFile {
     pub file_path: String,
+    pub transfer_syntax: TransferSyntax,
     pub general_study: GeneralStudy,
     pub general_series: GeneralSeries,
     pub image_pixel: ImagePixel,
@@ -84,11 +88,16 @@ impl DicomFileFactory {
         let mut image_pixel = ImagePixel::builder();
         let mut image_plane = ImagePlane::builder();
         let mut modality_lut = ModalityLut::builder();
+        let mut transfer_syntax = None;
 
         for data_elem in data_elems {
             let tag = data_elem.tag;
 
             match tag {
+                TRANSFER_SYNTAX_UID => {
+                    let uid: String = (&get!(ValueField::UniqueIdentifier, data_elem, inconsistencies)).into();
+                    transfer_syntax = TransferSyntax::from_uid(&uid);
+                }
                 MODALITY => {
                     let modality = &get!(ValueField::CodeString, data_elem, inconsistencies);
                     general_series.modality(modality.into());
@@ -97,9 +106,27 @@ impl DicomFileFactory {
                     let study_instance_uid = &get!(ValueField::UniqueIdentifier, data_elem, inconsistencies);
                     general_study.study_instance_uid(study_instance_uid.into());
                 }
+                // Study and patient attributes are informative only, values that cannot be parsed,
+                // e.g. empty ones or ones with an unexpected VR, are left out rather than rejecting the file
                 STUDY_DATE => {
-                    let study_date = &get!(ValueField::Date, data_elem, inconsistencies);
-                    general_study.study_date(study_date.into());
+                    if let Ok(study_date) = Cast::<Date>::cast(&data_elem.value) {
+                        general_study.study_date(study_date);
+                    }
+                }
+                STUDY_TIME => {
+                    if let Ok(study_time) = Cast::<Time>::cast(&data_elem.value) {
+                        general_study.study_time(study_time);
+                    }
+                }
+                PATIENT_NAME => {
+                    if let Ok(patient_name) = Cast::<PersonName>::cast(&data_elem.value) {
+                        general_study.patient_name(patient_name);
+                    }
+                }
+                PATIENT_BIRTH_DATE => {
+                    if let Ok(patient_birth_date) = Cast::<Date>::cast(&data_elem.value) {
+                        general_study.patient_birth_date(patient_birth_date);
+                    }
                 }
                 SERIES_NUMBER => {
                     let number = &get!(ValueField::IntegerString, data_elem, inconsistencies);
@@ -148,11 +175,11 @@ impl DicomFileFactory {
                     let pixel_spacing = &get!(ValueField::DecimalString, data_elem, inconsistencies);
                     image_plane.pixel_spacing(cast_array!(f32, 2, pixel_spacing, inconsistencies));
                 }
-                IMAGE_ORIENTATION => {
+                IMAGE_ORIENTATION_PATIENT => {
                     let image_orientation = &get!(ValueField::DecimalString, data_elem, inconsistencies);
                     image_plane.image_orientation(cast_array!(f32, 6, image_orientation, inconsistencies));
                 }
-                IMAGE_POSITION => {
+                IMAGE_POSITION_PATIENT => {
                     let image_position = &get!(ValueField::DecimalString, data_elem, inconsistencies);
                     image_plane.image_position(cast_array!(f32, 3, image_position, inconsistencies));
                 }
@@ -174,19 +201,24 @@ impl DicomFileFactory {
         let image_plane = image_plane.build();
         let modality_lut = modality_lut.build();
 
-        let inconsistensies =
+        let mut inconsistensies =
             self.accumulate_inconsistencies(&general_study,
                                             &general_series,
                                             &image_pixel,
                                             &image_plane,
                                             &modality_lut);
 
+        if transfer_syntax.is_none() {
+            inconsistensies.push(DicomFileInconsistency::MissingAttribute(TRANSFER_SYNTAX_UID));
+        }
+
         if !inconsistensies.is_empty() {
             return Err(inconsistensies);
         }
 
         Ok(DicomFile {
             file_path: file_path.to_string(),
+            transfer_syntax: transfer_syntax.unwrap(),
             general_study: general_study?,
             general_series: general_series?,
             image_pixel: image_pixel?,
@@ -226,3 +258,54 @@ impl DicomFileFactory {
         inconsistencies
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::transfer_syntaxes::{EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN};
+    use crate::dicom_constants::value_representation::{CODE_STRING, DATE, LONG_STRING, TIME, UNIQUE_IDENTIFIER_UID};
+    use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+    use crate::test_support::dicom_generator::{dicom_file, image_series, Dataset, SeriesGeometry};
+    use crate::value_representations::date_time::Date;
+    use super::*;
+
+    #[test]
+    fn test_create_from_generated_slice() {
+        let geometry = SeriesGeometry::new(4, 3, 2);
+        let dicom_file = dicom_file(&image_series(&geometry)[1], IMPLICIT_VR_LITTLE_ENDIAN);
+
+        assert_eq!(dicom_file.general_series.modality, "CT");
+        assert_eq!(dicom_file.general_series.series_number, Some(1));
+        assert_eq!(dicom_file.general_study.study_date, Some(Date { year: 2024, month: 3, day: 1 }));
+        assert_eq!(dicom_file.general_study.patient_name.unwrap().alphabetic.family_name, "Doe");
+        assert_eq!((dicom_file.image_pixel.rows, dicom_file.image_pixel.columns), (4, 3));
+        assert_eq!(dicom_file.image_pixel.pixel_representation, 1);
+        assert_eq!(dicom_file.image_plane.pixel_spacing, [0.5, 0.5]);
+        assert_eq!(dicom_file.image_plane.image_position, [-100.0, -100.0, 2.5]);
+        assert_eq!(dicom_file.modality_lut.rescale_intercept, -1024.0);
+    }
+
+    #[test]
+    fn test_create_skips_unparseable_study_attributes() {
+        let dataset = image_series(&SeriesGeometry::new(4, 3, 1)).remove(0)
+            .string(PATIENT_NAME, LONG_STRING, "Doe^John")
+            .string(STUDY_DATE, DATE, "")
+            .string(STUDY_TIME, TIME, "25");
+
+        let dicom_file = dicom_file(&dataset, EXPLICIT_VR_LITTLE_ENDIAN);
+        assert!(dicom_file.general_study.patient_name.is_none());
+        assert!(dicom_file.general_study.study_date.is_none());
+        assert!(dicom_file.general_study.study_time.is_none());
+    }
+
+    #[test]
+    fn test_create_reports_missing_attributes() {
+        let dataset = Dataset::new()
+            .string(MODALITY, CODE_STRING, "CT")
+            .string(STUDY_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, "1.2.3");
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&dataset.to_part10(EXPLICIT_VR_LITTLE_ENDIAN)).unwrap();
+
+        let inconsistencies = DicomFile::factory().create("", parsed.data_elements).err().unwrap();
+        assert!(inconsistencies.iter().any(|inconsistency| matches!(inconsistency, DicomFileInconsistency::MissingAttribute(SERIES_INSTANCE_UID))));
+        assert!(inconsistencies.iter().any(|inconsistency| matches!(inconsistency, DicomFileInconsistency::MissingAttribute(ROWS))));
+    }
+}
@@ -1,14 +1,23 @@
+extern crate test;
+
 use std::cell::Cell;
-use std::rc::Rc;
+use std::io::Read;
+use std::sync::Arc;
+use flate2::read::DeflateDecoder;
 use memmap2::Mmap;
 use crate::data_reader::data_reader::{DataReader, Whence};
 use crate::dataset::tag::Tag;
 use crate::dataset::data_element::DataElement;
+use crate::dataset::private_creators::PrivateCreators;
 use crate::dataset::value_field::ValueField;
 use crate::dicom_constants::numeric::HEADER_END;
-use crate::dicom_constants::tags::{ITEM, ITEM_DELIMITATION, SEQUENCE_DELIMITATION};
-use crate::dicom_file_parser::value_reader::{ExplicitValueReader, ValueReader};
+use crate::dicom_constants::tags::{ITEM, ITEM_DELIMITATION_ITEM, PIXEL_DATA, SEQUENCE_DELIMITATION_ITEM, SPECIFIC_CHARACTER_SET, TRANSFER_SYNTAX_UID};
+use crate::dicom_file_parser::parse_error::{ParseError, ParseErrorKind};
+use crate::dicom_file_parser::parsed_dicom_file::ParsedDicomFile;
+use crate::dicom_file_parser::transfer_syntax::TransferSyntax;
+use crate::dicom_file_parser::value_reader::{change_character_set, ValueReader};
 use crate::utils::endianness::Endianness;
+use crate::utils::memory_map::{map_anonymous, read_anonymous};
 use crate::value_representations::numeric_type::Numeric;
 use super::validator::{Validator, ValidationResult};
 
@@ -17,7 +26,15 @@ pub struct DicomFileParser {
     file_path: String,
     tags_to_read : std::collections::HashSet<Tag>,
     read_all_tags : Cell<bool>,
-    dicom_dataset_reader: ValueReader
+    size_of_lazy_read_element: Option<u32>,
+    stop_before_tag: Option<Tag>,
+    stop_after_requested_tags: bool,
+}
+
+impl Default for DicomFileParser {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DicomFileParser {
@@ -26,7 +43,9 @@ impl DicomFileParser {
             file_path: "".parse().unwrap(),
             tags_to_read: std::collections::HashSet::new(),
             read_all_tags: Cell::new(false),
-            dicom_dataset_reader: ValueReader::Explicit(ExplicitValueReader::new())
+            size_of_lazy_read_element: None,
+            stop_before_tag: None,
+            stop_after_requested_tags: false,
         }
     }
 
@@ -46,86 +65,211 @@ impl DicomFileParser {
     }
 
     pub fn with_lazy_read_element(mut self, start: Option<u32>) -> Self {
-        self.dicom_dataset_reader.set_size_of_lazy_read_element(start);
+        self.size_of_lazy_read_element = start;
+        self
+    }
+
+    /// Stops parsing the dataset at the first top-level element whose tag is not lower than the given one.
+    pub fn stop_before_tag(mut self, tag: Tag) -> Self {
+        self.stop_before_tag = Some(tag);
         self
     }
 
-    pub fn parse(&self) -> Result<Vec<DataElement>, Box<dyn std::error::Error>> {
+    /// Reads only the header - stops before Pixel Data.
+    pub fn header_only(self) -> Self {
+        self.stop_before_tag(PIXEL_DATA)
+    }
+
+    /// Stops parsing the dataset once the highest tag passed to `read_tags` has been read.
+    /// Ignored when all tags are read.
+    pub fn stop_after_requested_tags(mut self) -> Self {
+        self.stop_after_requested_tags = true;
+        self
+    }
+
+    pub fn parse(&self) -> Result<ParsedDicomFile, ParseError> {
         let content = self.open_file()?;
+        self.parse_content(content)
+    }
+
+    /// Parses a DICOM file kept in memory, e.g. extracted from an archive. The bytes are copied
+    /// into an anonymous memory map, so lazily read elements do not borrow from the caller.
+    pub fn parse_bytes(&self, bytes: &[u8]) -> Result<ParsedDicomFile, ParseError> {
+        self.parse_content(Arc::new(map_anonymous(bytes)?))
+    }
 
+    /// Parses a DICOM file read until the end from any source, e.g. stdin or a network stream.
+    /// The content is read directly into an anonymous memory map.
+    pub fn parse_reader<R: Read>(&self, reader: R) -> Result<ParsedDicomFile, ParseError> {
+        self.parse_content(Arc::new(read_anonymous(reader)?))
+    }
+
+    fn parse_content(&self, content: Arc<Mmap>) -> Result<ParsedDicomFile, ParseError> {
         if Validator::new(&content).validate() == ValidationResult::NotDicom {
-            return Err("Not a DICOM file".into());
+            return Err(ParseError::new(0, ParseErrorKind::NotDicom));
         }
 
-        let mut reader = DataReader::new(content, Endianness::Little);
-        reader.seek(Whence::Start, HEADER_END);
+        let mut reader = DataReader::new(content.clone(), Endianness::Little);
+        reader.seek(Whence::Start, HEADER_END)?;
 
         let old_value_read_all_tags = self.read_all_tags.get();
         self.read_all_tags.set(true);
 
-        let data_elems = self.read_meta_data(&mut reader);
+        let meta_data_reader = self.create_value_reader(true);
+        let data_elems = self.read_meta_data(&meta_data_reader, &mut reader);
 
         self.read_all_tags.set(old_value_read_all_tags);
 
-        if let Err(e) = data_elems {
-            return Err(e);
+        let mut data_elems = data_elems?;
+
+        let transfer_syntax = self.get_transfer_syntax(&data_elems)?;
+        reader.change_byte_order(transfer_syntax.endianness);
+
+        let source = (!transfer_syntax.deflated).then(|| content.clone());
+
+        if transfer_syntax.deflated {
+            let dataset_start = reader.position();
+            reader = DataReader::new(self.inflate(&content[dataset_start..])?, Endianness::Little);
         }
 
-        let mut data_elems = data_elems.unwrap();
+        let dataset_reader = self.create_value_reader(transfer_syntax.explicit_value_representation);
+
+        let last_requested_tag = self.last_requested_tag();
 
         while reader.unconsumed() > 0 {
-            let tag = self.dicom_dataset_reader.read_tag(&mut reader);
-            let data_element = self.read_data_element(&tag, &mut reader);
+            let tag = dataset_reader.read_tag(&mut reader)?;
+
+            if self.stop_before_tag.is_some_and(|stop_tag| tag >= stop_tag) ||
+               last_requested_tag.is_some_and(|last_tag| tag > last_tag) {
+                break;
+            }
+
+            if tag == SPECIFIC_CHARACTER_SET {
+                // Read even when not requested, following strings depend on it
+                let character_set = dataset_reader.read_data_element(&tag, &mut reader)
+                    .map_err(|error| error.with_tag(tag))?;
+                change_character_set(&character_set, &mut reader);
+
+                if self.read_all_tags.get() || self.tags_to_read.contains(&tag) {
+                    data_elems.push(character_set);
+                }
+                continue;
+            }
+
+            if PrivateCreators::is_private_creator(&tag) {
+                // Read even when not requested, VRs of private attributes in its block depend on it
+                let private_creator = dataset_reader.read_data_element(&tag, &mut reader)
+                    .map_err(|error| error.with_tag(tag))?;
+                reader.private_creators_mut().add(&private_creator);
+
+                if self.read_all_tags.get() || self.tags_to_read.contains(&tag) {
+                    data_elems.push(private_creator);
+                }
+                continue;
+            }
+
+            let data_element = self.read_data_element(&dataset_reader, &tag, &mut reader)?;
 
             if let Some(data_element) = data_element {
                 data_elems.push(data_element);
             }
         }
 
-        Ok(data_elems)
+        Ok(ParsedDicomFile { transfer_syntax, data_elements: data_elems, source })
     }
 
-    fn open_file(&self) -> Result<Rc<Mmap>, std::io::Error> {
+    fn last_requested_tag(&self) -> Option<Tag> {
+        if !self.stop_after_requested_tags || self.read_all_tags.get() {
+            return None;
+        }
+
+        self.tags_to_read.iter().max().copied()
+    }
+
+    fn create_value_reader(&self, explicit_value_representation: bool) -> ValueReader {
+        let mut value_reader = if explicit_value_representation {
+            ValueReader::new_explicit()
+        } else {
+            ValueReader::new_implicit()
+        };
+
+        value_reader.set_size_of_lazy_read_element(self.size_of_lazy_read_element);
+        value_reader
+    }
+
+    fn get_transfer_syntax(&self, meta_data: &[DataElement]) -> Result<TransferSyntax, ParseError> {
+        let transfer_syntax_uid = meta_data.iter()
+            .find(|elem| elem.tag == TRANSFER_SYNTAX_UID)
+            .map(|elem| &elem.value);
+
+        let uid: String = match transfer_syntax_uid {
+            Some(ValueField::UniqueIdentifier(uid)) => uid.into(),
+            Some(_) => return Err(Self::invalid_file_meta_information("Transfer syntax UID should be kept as unique identifier")),
+            None => return Err(Self::invalid_file_meta_information("Missing transfer syntax UID"))
+        };
+
+        match TransferSyntax::from_uid(&uid) {
+            Some(transfer_syntax) => Ok(transfer_syntax),
+            None => Err(ParseError::new(HEADER_END, ParseErrorKind::UnknownTransferSyntax(uid.trim_end_matches('\0').to_string()))
+                .with_tag(TRANSFER_SYNTAX_UID))
+        }
+    }
+
+    fn invalid_file_meta_information(reason: &'static str) -> ParseError {
+        ParseError::new(HEADER_END, ParseErrorKind::InvalidFileMetaInformation(reason))
+    }
+
+    /// Deflated Explicit VR Little Endian compresses everything after the file meta group as a raw
+    /// deflate stream. The inflated dataset is kept in an anonymous memory map, so lazily read
+    /// strings and `OtherType` values can refer to it the same way as to the mapped file.
+    fn inflate(&self, deflated_dataset: &[u8]) -> Result<Arc<Mmap>, std::io::Error> {
+        let mut inflated = Vec::new();
+        DeflateDecoder::new(deflated_dataset).read_to_end(&mut inflated)?;
+
+        Ok(Arc::new(map_anonymous(&inflated)?))
+    }
+
+    fn open_file(&self) -> Result<Arc<Mmap>, std::io::Error> {
         let file = std::fs::File::open(&self.file_path)?;
         let mapped_file = unsafe { Mmap::map(&file)? };
-        Ok(Rc::new(mapped_file))
+        Ok(Arc::new(mapped_file))
     }
 
     fn sequence_of_item_special_tag(&self, tag: &Tag) -> bool {
-        tag == &ITEM || tag == &ITEM_DELIMITATION || tag == &SEQUENCE_DELIMITATION
+        tag == &ITEM || tag == &ITEM_DELIMITATION_ITEM || tag == &SEQUENCE_DELIMITATION_ITEM
     }
 
-    fn read_data_element(&self, tag: &Tag, reader: &mut DataReader) -> Option<DataElement> {
+    fn read_data_element(&self, value_reader: &ValueReader, tag: &Tag, reader: &mut DataReader) -> Result<Option<DataElement>, ParseError> {
         if self.sequence_of_item_special_tag(tag) {
-            reader.seek(Whence::Current, 4);
-
+            reader.seek(Whence::Current, 4)?;
+            return Ok(None);
         }
 
-        if self.read_all_tags.get() || self.tags_to_read.contains(tag)
+        let data_element = if self.read_all_tags.get() || self.tags_to_read.contains(tag)
         {
-            return Some(self.dicom_dataset_reader.read_data_element(&tag, reader));
+            value_reader.read_data_element(tag, reader).map(Some)
         }
         else
         {
-            self.dicom_dataset_reader.skip_value(&tag, reader);
-            return None;
-        }
+            value_reader.skip_value(tag, reader).map(|_| None)
+        };
 
+        data_element.map_err(|error| error.with_tag(*tag))
     }
 
-    fn read_meta_data(&self, reader: &mut DataReader) -> Result<Vec<DataElement>,
-                                                                Box<dyn std::error::Error>>
+    fn read_meta_data(&self, value_reader: &ValueReader, reader: &mut DataReader) -> Result<Vec<DataElement>, ParseError>
     {
-        let tag = self.dicom_dataset_reader.read_tag(reader);
-        let file_meta_information_group_length = self.dicom_dataset_reader.read_data_element(&tag, reader);
+        let tag = value_reader.read_tag(reader)?;
+        let file_meta_information_group_length = value_reader.read_data_element(&tag, reader)
+            .map_err(|error| error.with_tag(tag))?;
 
         let filemeta_length = match file_meta_information_group_length.value {
             ValueField::UnsignedLong(u32) => u32.value().first().copied(),
-            _ => return Err("File meta information group length should be kept as unsigned long".into())
+            _ => return Err(Self::invalid_file_meta_information("Group length should be kept as unsigned long"))
         };
 
-        if let None = filemeta_length {
-            return Err("Cannot read file meta information group length".into());
+        if filemeta_length.is_none() {
+            return Err(Self::invalid_file_meta_information("Cannot read group length"));
         }
 
         const EXPECTED_MAX_NUM_OF_ELEMENTS: usize = 20;
@@ -133,12 +277,354 @@ impl DicomFileParser {
         let end_of_file_meta = reader.unconsumed() - filemeta_length.unwrap() as isize;
 
         while reader.unconsumed() > end_of_file_meta {
-            let tag = self.dicom_dataset_reader.read_tag(reader);
+            let tag = value_reader.read_tag(reader)?;
 
-            elems.push(
-                self.read_data_element(&tag, reader).unwrap());
+            if let Some(data_element) = self.read_data_element(value_reader, &tag, reader)? {
+                elems.push(data_element);
+            }
         }
 
         Ok(elems)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use test::Bencher;
+    use crate::dataset::attribute_path::AttributePath;
+    use crate::dataset::private_creators::find_private;
+    use crate::dicom_constants::tags::{COLUMNS, DIFFUSION_B_VALUE, PATIENT_ID, PATIENT_NAME, SERIES_DESCRIPTION, PIXEL_SPACING, REFERENCED_INSTANCE_SEQUENCE, REFERENCED_SERIES_SEQUENCE, REFERENCED_SOP_CLASS_UID, REFERENCED_SOP_INSTANCE_UID, ROWS, SERIES_INSTANCE_UID};
+    use crate::dicom_constants::value_representation::{CODE_STRING, DECIMAL_STRING, FLOATING_POINT_DOUBLE, FLOATING_POINT_SINGLE, INTEGER_STRING, LONG_STRING, OTHER_WORD, PERSON_NAME, UNIQUE_IDENTIFIER_UID, UNSIGNED_SHORT};
+    use crate::test_support::dicom_generator::{temp_file, Dataset};
+    use crate::value_representations::person_name::PersonName;
+    use crate::value_representations::other_type::Other;
+    use crate::dicom_constants::transfer_syntaxes::{DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN, EXPLICIT_VR_BIG_ENDIAN, EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN};
+    use crate::traits::cast::Cast;
+    use super::*;
+
+    #[test]
+    fn test_parse_implicit_little_endian() {
+        let dataset = Dataset::new().numbers(ROWS, UNSIGNED_SHORT, &[256u16]);
+
+        let path = temp_file("implicit", &dataset.to_part10(IMPLICIT_VR_LITTLE_ENDIAN));
+        let parsed = DicomFileParser::new().file_path(&path).read_all_tags().parse().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(parsed.transfer_syntax.uid, IMPLICIT_VR_LITTLE_ENDIAN);
+
+        let rows = parsed.data_elements.iter().find(|elem| elem.tag == ROWS).unwrap();
+        match &rows.value {
+            ValueField::UnsignedShort(rows) => assert_eq!(Cast::<u16>::cast(rows).unwrap(), 256),
+            _ => panic!("Rows should be read as US")
+        }
+    }
+
+    #[test]
+    fn test_parse_explicit_big_endian() {
+        let dataset = Dataset::new().numbers(ROWS, UNSIGNED_SHORT, &[256u16]);
+
+        let path = temp_file("big_endian", &dataset.to_part10(EXPLICIT_VR_BIG_ENDIAN));
+        let parsed = DicomFileParser::new().file_path(&path).read_all_tags().parse().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(parsed.transfer_syntax.endianness, Endianness::Big);
+
+        let rows = parsed.data_elements.iter().find(|elem| elem.tag == ROWS).unwrap();
+        match &rows.value {
+            ValueField::UnsignedShort(rows) => assert_eq!(Cast::<u16>::cast(rows).unwrap(), 256),
+            _ => panic!("Rows should be read as US")
+        }
+    }
+
+    #[test]
+    fn test_parse_deflated_explicit_little_endian() {
+        let dataset = Dataset::new()
+            .string(SERIES_DESCRIPTION, LONG_STRING, "DEFLATED")
+            .numbers(PIXEL_DATA, OTHER_WORD, &[1u16, 2u16]);
+
+        let path = temp_file("deflated", &dataset.to_part10(DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN));
+        let parsed = DicomFileParser::new()
+            .file_path(&path)
+            .read_all_tags()
+            .with_lazy_read_element(Some(256))
+            .parse()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(parsed.transfer_syntax.deflated);
+
+        let series_description = parsed.data_elements.iter().find(|elem| elem.tag == SERIES_DESCRIPTION).unwrap();
+        match &series_description.value {
+            ValueField::LongString(description) => assert_eq!(Into::<String>::into(description), "DEFLATED"),
+            _ => panic!("Series description should be read as LO")
+        }
+
+        let pixel_data = parsed.data_elements.iter().find(|elem| elem.tag == PIXEL_DATA).unwrap();
+        match &pixel_data.value {
+            ValueField::OtherWord(pixels) => assert_eq!(pixels.value(), &vec![1, 2]),
+            _ => panic!("Pixel data should be read as OW")
+        }
+    }
+
+    #[test]
+    fn test_parse_bytes_and_reader() {
+        let content = Dataset::new().numbers(ROWS, UNSIGNED_SHORT, &[512u16]).to_part10(IMPLICIT_VR_LITTLE_ENDIAN);
+
+        let parser = DicomFileParser::new().read_all_tags();
+        let from_bytes = parser.parse_bytes(&content).unwrap();
+        let from_reader = parser.parse_reader(std::io::Cursor::new(content)).unwrap();
+
+        for parsed in [from_bytes, from_reader] {
+            let rows = parsed.data_elements.iter().find(|elem| elem.tag == ROWS).unwrap();
+            match &rows.value {
+                ValueField::UnsignedShort(rows) => assert_eq!(Cast::<u16>::cast(rows).unwrap(), 512),
+                _ => panic!("Rows should be read as US")
+            }
+        }
+
+        let error = parser.parse_bytes(b"DICM").unwrap_err();
+        assert!(matches!(error.kind, ParseErrorKind::NotDicom));
+    }
+
+    #[test]
+    fn test_parse_specific_character_set() {
+        let dataset = Dataset::new()
+            .string(SPECIFIC_CHARACTER_SET, CODE_STRING, "ISO_IR 100")
+            .bytes(PATIENT_NAME, PERSON_NAME, b"Buc^J\xe9r\xf4me");
+
+        let parsed = DicomFileParser::new()
+            .read_tags(&[PATIENT_NAME])
+            .with_lazy_read_element(Some(256))
+            .parse_bytes(&dataset.to_part10(IMPLICIT_VR_LITTLE_ENDIAN))
+            .unwrap();
+
+        assert!(parsed.data_elements.iter().all(|elem| elem.tag != SPECIFIC_CHARACTER_SET));
+
+        let name = parsed.data_elements.iter().find(|elem| elem.tag == PATIENT_NAME).unwrap();
+        match &name.value {
+            ValueField::PersonName(name) => assert_eq!(Into::<String>::into(name), "Buc^Jérôme"),
+            _ => panic!("Patient name should be read as PN")
+        }
+    }
+
+    #[test]
+    fn test_parse_generated_dataset_in_all_transfer_syntaxes() {
+        let instance = |uid: &str| Dataset::new()
+            .string(REFERENCED_SOP_CLASS_UID, UNIQUE_IDENTIFIER_UID, "1.2.840.10008.5.1.4.1.1.2")
+            .string(REFERENCED_SOP_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, uid);
+        let series = Dataset::new()
+            .string(SERIES_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, "1.2.3")
+            .sequence_with_defined_length(REFERENCED_INSTANCE_SEQUENCE, vec![instance("1.2.3.1"), instance("1.2.3.2")]);
+        let dataset = Dataset::new()
+            .string(PATIENT_NAME, PERSON_NAME, "Doe^John")
+            .numbers(ROWS, UNSIGNED_SHORT, &[512u16])
+            .string(PIXEL_SPACING, DECIMAL_STRING, "0.5\\0.75")
+            .numbers(DIFFUSION_B_VALUE, FLOATING_POINT_DOUBLE, &[1000.0f64])
+            .sequence(REFERENCED_SERIES_SEQUENCE, vec![series]);
+
+        for transfer_syntax in [IMPLICIT_VR_LITTLE_ENDIAN, EXPLICIT_VR_LITTLE_ENDIAN, EXPLICIT_VR_BIG_ENDIAN, DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN] {
+            let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&dataset.to_part10(transfer_syntax)).unwrap();
+            let path = |path: &str| AttributePath::parse(path).unwrap();
+
+            assert_eq!(parsed.transfer_syntax.uid, transfer_syntax);
+            assert_eq!(path("Rows").value::<u16>(&parsed.data_elements), Ok(512));
+            assert_eq!(path("PixelSpacing").values::<f64, 2>(&parsed.data_elements), Ok([0.5, 0.75]));
+            assert_eq!(path("DiffusionBValue").value::<f64>(&parsed.data_elements), Ok(1000.0));
+            assert_eq!(path("PatientName").value::<PersonName>(&parsed.data_elements).unwrap().alphabetic.given_name, "John");
+
+            let uid = path("ReferencedSeriesSequence[0].ReferencedInstanceSequence[1].ReferencedSOPInstanceUID")
+                .find_first(&parsed.data_elements).unwrap();
+            match &uid.value {
+                ValueField::UniqueIdentifier(uid) => assert_eq!(Into::<String>::into(uid).trim_end_matches('\0'), "1.2.3.2"),
+                _ => panic!("Referenced SOP Instance UID should be read as UI")
+            }
+        }
+    }
+
+    #[test]
+    fn test_sequence_of_item_special_tag() {
+        let parser = DicomFileParser::new();
+
+        assert!(parser.sequence_of_item_special_tag(&ITEM));
+        assert!(parser.sequence_of_item_special_tag(&ITEM_DELIMITATION_ITEM));
+        assert!(parser.sequence_of_item_special_tag(&SEQUENCE_DELIMITATION_ITEM));
+        assert!(!parser.sequence_of_item_special_tag(&Tag { group: 0x0002, element: 0x0000 }));
+
+        // Empty items still have an item tag and, with undefined length, an item delimitation tag
+        let dataset = Dataset::new()
+            .sequence(REFERENCED_SERIES_SEQUENCE, vec![Dataset::new(), Dataset::new()])
+            .numbers(ROWS, UNSIGNED_SHORT, &[512u16]);
+
+        let parser = parser.read_all_tags();
+        for transfer_syntax in [IMPLICIT_VR_LITTLE_ENDIAN, EXPLICIT_VR_LITTLE_ENDIAN] {
+            let parsed = parser.parse_bytes(&dataset.to_part10(transfer_syntax)).unwrap();
+
+            let sequence = parsed.data_elements.iter().find(|elem| elem.tag == REFERENCED_SERIES_SEQUENCE).unwrap();
+            match &sequence.value {
+                ValueField::SequenceOfItems(sequence) => {
+                    assert_eq!(sequence.items.len(), 2);
+                    assert!(sequence.items.iter().all(|item| item.data_elements.is_empty()));
+                },
+                _ => panic!("Referenced Series Sequence should be read as SQ")
+            }
+            assert_eq!(AttributePath::parse("Rows").unwrap().value::<u16>(&parsed.data_elements), Ok(512));
+        }
+    }
+
+    #[test]
+    fn test_implicit_private_attributes_decoded_with_creator_dictionary() {
+        let private = |group: u16, element: u16| Tag { group, element };
+        let stack = Dataset::new()
+            .string(private(0x2005, 0x0014), LONG_STRING, "Philips MR Imaging DD 001")
+            .numbers(private(0x2005, 0x140E), FLOATING_POINT_SINGLE, &[2.5f32]);
+        let dataset = Dataset::new()
+            .string(private(0x0019, 0x0010), LONG_STRING, "SIEMENS MR HEADER")
+            .string(private(0x0019, 0x100C), INTEGER_STRING, "1000")
+            .string(private(0x2001, 0x0010), LONG_STRING, "Philips Imaging DD 001")
+            .sequence(private(0x2001, 0x105F), vec![stack])
+            .string(private(0x2005, 0x0010), LONG_STRING, "ACME")
+            .numbers(private(0x2005, 0x100E), FLOATING_POINT_SINGLE, &[2.5f32])
+            .to_part10(IMPLICIT_VR_LITTLE_ENDIAN);
+
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&dataset).unwrap();
+        let b_value = find_private(&parsed.data_elements, "SIEMENS MR HEADER", 0x0019, 0x0C).unwrap();
+        assert_eq!(b_value.value_representation, Some(INTEGER_STRING));
+        assert_eq!(AttributePath::parse("(2001,105F)[0].(2005,140E)").unwrap().value::<f32>(&parsed.data_elements), Ok(2.5));
+
+        let unknown_block = parsed.data_elements.iter().find(|data_element| data_element.tag == private(0x2005, 0x100E)).unwrap();
+        assert!(matches!(unknown_block.value, ValueField::Unknown(_)));
+
+        // Private Creator is read even when only the private attribute is requested
+        let parsed = DicomFileParser::new().read_tags(&[private(0x0019, 0x100C)]).parse_bytes(&dataset).unwrap();
+        let b_value = parsed.data_elements.iter().find(|data_element| data_element.tag == private(0x0019, 0x100C)).unwrap();
+        assert_eq!(Cast::<i32>::cast(&b_value.value).unwrap(), 1000);
+    }
+
+    #[test]
+    fn test_lazy_values_read_on_other_threads() {
+        let dataset = Dataset::new().string(PATIENT_NAME, PERSON_NAME, "DOE^JOHN");
+
+        let parsed = DicomFileParser::new()
+            .read_all_tags()
+            .with_lazy_read_element(Some(4))
+            .parse_bytes(&dataset.to_part10(IMPLICIT_VR_LITTLE_ENDIAN))
+            .unwrap();
+
+        let parsed = std::sync::Arc::new(parsed);
+        let names: Vec<String> = (0..2).map(|_| {
+            let parsed = parsed.clone();
+            std::thread::spawn(move || match &parsed.data_elements.iter().find(|elem| elem.tag == PATIENT_NAME).unwrap().value {
+                ValueField::PersonName(name) => Into::<String>::into(name),
+                _ => panic!("Patient name should be read as PN")
+            })
+        }).collect::<Vec<_>>().into_iter().map(|thread| thread.join().unwrap()).collect();
+
+        assert_eq!(names, ["DOE^JOHN", "DOE^JOHN"]);
+    }
+
+    #[test]
+    fn test_parse_unknown_transfer_syntax() {
+        let path = temp_file("unknown", &Dataset::new().to_part10("1.2.3.4.5"));
+        let result = DicomFileParser::new().file_path(&path).parse();
+        std::fs::remove_file(&path).unwrap();
+
+        let error = result.unwrap_err();
+        assert_eq!(error.tag, Some(TRANSFER_SYNTAX_UID));
+        assert!(matches!(error.kind, ParseErrorKind::UnknownTransferSyntax(uid) if uid == "1.2.3.4.5"));
+    }
+
+    #[test]
+    fn test_parse_truncated_element() {
+        // Patient ID is cut after 4 of its 16 bytes
+        let dataset = Dataset::new()
+            .string(PATIENT_NAME, PERSON_NAME, "DOE^JOHN")
+            .string(PATIENT_ID, LONG_STRING, "0123456789ABCDEF");
+        let mut content = dataset.to_part10(IMPLICIT_VR_LITTLE_ENDIAN);
+        let dataset_start = content.len() - dataset.to_bytes(IMPLICIT_VR_LITTLE_ENDIAN).len();
+        content.truncate(content.len() - 12);
+
+        let path = temp_file("truncated", &content);
+        let read_all = DicomFileParser::new().file_path(&path).read_all_tags().parse();
+        let skip_all = DicomFileParser::new().file_path(&path).parse();
+        std::fs::remove_file(&path).unwrap();
+
+        for result in [read_all, skip_all] {
+            let error = result.unwrap_err();
+            assert!(matches!(error.kind, ParseErrorKind::UnexpectedEndOfData));
+            assert_eq!(error.tag, Some(PATIENT_ID));
+            assert_eq!(error.offset, dataset_start + 24);
+        }
+    }
+
+    fn image_with_pixel_data(pixel_data: &[u8]) -> Dataset {
+        Dataset::new()
+            .numbers(ROWS, UNSIGNED_SHORT, &[512u16])
+            .numbers(COLUMNS, UNSIGNED_SHORT, &[512u16])
+            .bytes(PIXEL_DATA, OTHER_WORD, pixel_data)
+    }
+
+    #[test]
+    fn test_stop_before_pixel_data() {
+        // Pixel Data is truncated, so only a parser which stops before it succeeds
+        let mut content = image_with_pixel_data(&[0; 1024]).to_part10(IMPLICIT_VR_LITTLE_ENDIAN);
+        content.truncate(content.len() - 1008);
+
+        let full = DicomFileParser::new().read_all_tags().parse_bytes(&content);
+        assert!(full.is_err());
+
+        let header = DicomFileParser::new().read_all_tags().header_only().parse_bytes(&content).unwrap();
+        assert!(header.data_elements.iter().any(|elem| elem.tag == COLUMNS));
+        assert!(header.data_elements.iter().all(|elem| elem.tag != PIXEL_DATA));
+
+        let rows_only = DicomFileParser::new()
+            .read_tags(&[ROWS])
+            .stop_after_requested_tags()
+            .parse_bytes(&content)
+            .unwrap();
+        assert_eq!(rows_only.data_elements.last().unwrap().tag, ROWS);
+    }
+
+    fn write_study(name: &str, num_of_files: usize) -> std::path::PathBuf {
+        let directory = std::env::temp_dir().join(format!("dicom_file_parser_{}_{}", name, std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        const PIXEL_DATA_LENGTH: usize = 512 * 512 * 2;
+        let content = image_with_pixel_data(&vec![0; PIXEL_DATA_LENGTH]).to_part10(IMPLICIT_VR_LITTLE_ENDIAN);
+
+        for i in 0..num_of_files {
+            std::fs::write(directory.join(format!("{}.dcm", i)), &content).unwrap();
+        }
+
+        directory
+    }
+
+    fn bench_parse_study<F: Fn(&str) -> DicomFileParser>(bench: &mut Bencher, name: &str, create_parser: F) {
+        const NUM_OF_FILES: usize = 20;
+        let directory = write_study(name, NUM_OF_FILES);
+
+        let files: Vec<String> = std::fs::read_dir(&directory).unwrap()
+            .map(|entry| entry.unwrap().path().to_str().unwrap().to_string())
+            .collect();
+
+        bench.iter(|| {
+            files.iter()
+                .map(|file| create_parser(file).parse().unwrap().data_elements.len())
+                .sum::<usize>()
+        });
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[bench]
+    fn bench_parse_full(bench: &mut Bencher) {
+        bench_parse_study(bench, "full", |file| {
+            DicomFileParser::new().file_path(file).read_tags(&[ROWS, COLUMNS, PIXEL_DATA])
+        });
+    }
+
+    #[bench]
+    fn bench_parse_header_only(bench: &mut Bencher) {
+        bench_parse_study(bench, "header_only", |file| {
+            DicomFileParser::new().file_path(file).read_tags(&[ROWS, COLUMNS]).header_only()
+        });
+    }
+}
@@ -1,3 +1,6 @@
 pub mod dicom_file_parser;
 mod validator;
-pub mod value_reader;
\ No newline at end of file
+pub mod value_reader;
+pub mod transfer_syntax;
+pub mod parsed_dicom_file;
+pub mod parse_error;
\ No newline at end of file
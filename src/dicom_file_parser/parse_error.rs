@@ -0,0 +1,76 @@
+use std::fmt;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_representation::ValueRepresentation;
+
+#[derive(Debug)]
+pub enum ParseErrorKind {
+    Io(std::io::Error),
+    NotDicom,
+    UnexpectedEndOfData,
+    UnknownValueRepresentation(ValueRepresentation),
+    UnexpectedTagInSequence(Tag),
+    InvalidFileMetaInformation(&'static str),
+    UnknownTransferSyntax(String),
+}
+
+/// Error of parsing a DICOM file. The offset is counted from the beginning of the data being read -
+/// the file, or the inflated dataset for Deflated Explicit VR Little Endian. The tag is the one of
+/// the innermost data element being read, if any.
+#[derive(Debug)]
+pub struct ParseError {
+    pub offset: usize,
+    pub tag: Option<Tag>,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub fn new(offset: usize, kind: ParseErrorKind) -> Self {
+        ParseError { offset, tag: None, kind }
+    }
+
+    pub fn with_tag(mut self, tag: Tag) -> Self {
+        self.tag.get_or_insert(tag);
+        self
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::Io(error) => write!(f, "I/O error: {}", error),
+            ParseErrorKind::NotDicom => write!(f, "Not a DICOM file"),
+            ParseErrorKind::UnexpectedEndOfData => write!(f, "Unexpected end of data"),
+            ParseErrorKind::UnknownValueRepresentation(vr) => write!(f, "Unknown value representation: {}", vr),
+            ParseErrorKind::UnexpectedTagInSequence(tag) => write!(f, "Expected item tag in sequence, found {}", tag),
+            ParseErrorKind::InvalidFileMetaInformation(reason) => write!(f, "Invalid file meta information: {}", reason),
+            ParseErrorKind::UnknownTransferSyntax(uid) => write!(f, "Unknown transfer syntax: {}", uid),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)?;
+
+        if let Some(tag) = self.tag {
+            write!(f, " while reading {}", tag)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::Io(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<std::io::Error> for ParseError {
+    fn from(error: std::io::Error) -> Self {
+        ParseError::new(0, ParseErrorKind::Io(error))
+    }
+}
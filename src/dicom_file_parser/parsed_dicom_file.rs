@@ -0,0 +1,13 @@
+use std::sync::Arc;
+use memmap2::Mmap;
+use crate::dataset::data_element::DataElement;
+use crate::dicom_file_parser::transfer_syntax::TransferSyntax;
+
+#[derive(Debug)]
+pub struct ParsedDicomFile {
+    pub transfer_syntax: TransferSyntax,
+    pub data_elements: Vec<DataElement>,
+    /// Memory map of the parsed file, the offsets of values read from it refer to. `None` for
+    /// deflated files, whose values are read from the inflated dataset.
+    pub source: Option<Arc<Mmap>>,
+}
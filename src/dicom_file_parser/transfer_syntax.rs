@@ -0,0 +1,92 @@
+use crate::dicom_constants::transfer_syntaxes::*;
+use crate::utils::endianness::Endianness;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferSyntax {
+    pub uid: &'static str,
+    pub explicit_value_representation: bool,
+    pub endianness: Endianness,
+    pub deflated: bool,
+    pub encapsulated: bool,
+}
+
+impl TransferSyntax {
+    const fn native(uid: &'static str, explicit_value_representation: bool, endianness: Endianness) -> Self {
+        Self {
+            uid,
+            explicit_value_representation,
+            endianness,
+            deflated: false,
+            encapsulated: false,
+        }
+    }
+
+    const fn deflated(uid: &'static str) -> Self {
+        Self { deflated: true, ..Self::native(uid, true, Endianness::Little) }
+    }
+
+    pub fn from_uid(uid: &str) -> Option<Self> {
+        let uid = uid.trim_end_matches(|c: char| c == '\0' || c.is_whitespace());
+
+        match uid {
+            IMPLICIT_VR_LITTLE_ENDIAN =>
+                Some(Self::native(IMPLICIT_VR_LITTLE_ENDIAN, false, Endianness::Little)),
+            EXPLICIT_VR_LITTLE_ENDIAN =>
+                Some(Self::native(EXPLICIT_VR_LITTLE_ENDIAN, true, Endianness::Little)),
+            EXPLICIT_VR_BIG_ENDIAN =>
+                Some(Self::native(EXPLICIT_VR_BIG_ENDIAN, true, Endianness::Big)),
+            DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN =>
+                Some(Self::deflated(DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN)),
+            // Pixel data is referenced, not part of the dataset
+            JPIP_REFERENCED =>
+                Some(Self::native(JPIP_REFERENCED, true, Endianness::Little)),
+            JPIPHTJ2K_REFERENCED =>
+                Some(Self::native(JPIPHTJ2K_REFERENCED, true, Endianness::Little)),
+            JPIP_REFERENCED_DEFLATE =>
+                Some(Self::deflated(JPIP_REFERENCED_DEFLATE)),
+            JPIPHTJ2K_REFERENCED_DEFLATE =>
+                Some(Self::deflated(JPIPHTJ2K_REFERENCED_DEFLATE)),
+            // Retired, these don't encode datasets
+            RFC2557MIME_ENCAPSULATION | XML_ENCODING => None,
+            // Every other transfer syntax of the standard encapsulates the pixel data
+            _ => TRANSFER_SYNTAXES.iter()
+                .find(|encapsulated| **encapsulated == uid)
+                .map(|encapsulated| Self { encapsulated: true, ..Self::native(encapsulated, true, Endianness::Little) })
+        }
+    }
+
+    /// Transfer syntax to write a dataset read in this one - the same, or Explicit VR Little Endian
+    /// for big endian datasets, which `DicomFileWriter` does not encode.
+    pub fn writable(self) -> Self {
+        match self.endianness {
+            Endianness::Little => self,
+            Endianness::Big => Self::native(EXPLICIT_VR_LITTLE_ENDIAN, true, Endianness::Little),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_uid() {
+        let implicit = TransferSyntax::from_uid(IMPLICIT_VR_LITTLE_ENDIAN).unwrap();
+        assert!(!implicit.explicit_value_representation);
+
+        let jpeg2000 = TransferSyntax::from_uid("1.2.840.10008.1.2.4.90\0").unwrap();
+        assert_eq!(jpeg2000.uid, JPEG2000_LOSSLESS);
+        assert!(jpeg2000.encapsulated);
+        assert!(jpeg2000.explicit_value_representation);
+
+        for encapsulated in [JPEG_EXTENDED_35, HTJ2K_LOSSLESS, JPEGXL, MPEG4HP41F, ENCAPSULATED_UNCOMPRESSED_EXPLICIT_VR_LITTLE_ENDIAN] {
+            assert!(TransferSyntax::from_uid(encapsulated).unwrap().encapsulated);
+        }
+
+        let jpip = TransferSyntax::from_uid(JPIP_REFERENCED_DEFLATE).unwrap();
+        assert!(jpip.deflated && !jpip.encapsulated);
+
+        assert!(TransferSyntax::from_uid("1.2.3.4").is_none());
+        assert!(TransferSyntax::from_uid(XML_ENCODING).is_none());
+    }
+}
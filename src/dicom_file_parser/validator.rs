@@ -27,7 +27,10 @@ impl<'a> Validator<'a> {
     }
 }
 
+#[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_new() {
         let data = b"some data";
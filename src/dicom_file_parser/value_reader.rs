@@ -1,15 +1,38 @@
 use once_cell::sync::OnceCell;
 use crate::dataset::tag::Tag;
+use crate::data_dictionary::data_dictionary::DataDictionary;
 use crate::data_reader::data_reader::{DataReader, Whence};
 use crate::dataset::data_element::DataElement;
 use crate::dataset::data_element_location::DataElementLocation;
+use crate::dataset::private_creators::PrivateCreators;
 use crate::dataset::value_field::ValueField;
 use crate::dataset::value_representation::ValueRepresentation;
-use crate::dicom_constants::numeric::UNDEFINED_LENGTH;
-use crate::dicom_constants::tags::SEQUENCE_DELIMITATION;
+use crate::dicom_constants::numeric::{TAG_LENGTH, UNDEFINED_LENGTH};
+use crate::dicom_constants::tags::{ITEM, ITEM_DELIMITATION_ITEM, SEQUENCE_DELIMITATION_ITEM, SPECIFIC_CHARACTER_SET};
+use crate::dicom_constants::value_representation::{SEQUENCE_OF_ITEMS, UNKNOWN};
+use crate::dicom_file_parser::parse_error::{ParseError, ParseErrorKind};
+use crate::utils::endianness::Endianness;
 use crate::utils::submap::Submap;
 use crate::value_representations::attribute_tag::AttributeTag;
 use crate::value_representations::numeric_type::Numeric;
+use crate::value_representations::sequence_of_items::{SequenceItem, SequenceOfItems};
+
+/// Switches the string decoder of the reader to the character set kept in Specific Character Set.
+pub fn change_character_set(specific_character_set: &DataElement, reader: &mut DataReader) {
+    match &specific_character_set.value {
+        ValueField::CodeString(character_set) => reader.change_decoder(character_set.into()),
+        _ => log::warn!("Specific Character Set should be kept as code string"),
+    }
+}
+
+/// Value representation of the tag known from the data dictionary. Private attributes are looked up
+/// by the Private Creator of their block.
+pub fn dictionary_value_representation(tag: &Tag, reader: &DataReader) -> Option<ValueRepresentation> {
+    match reader.private_creators().creator(tag) {
+        Some(creator) => DataDictionary::private_implicit_value_representation(creator, tag),
+        None => DataDictionary::implicit_value_representation(tag),
+    }
+}
 
 mod private {
     pub struct Local {}
@@ -18,321 +41,361 @@ mod private {
 }
 
 pub trait ValueReaderBase {
-    fn read_tag(&self, reader: &mut DataReader) -> Tag {
-        let group = reader.read_u16();
-        let element = reader.read_u16();
+    fn read_tag(&self, reader: &mut DataReader) -> Result<Tag, ParseError> {
+        let group = reader.read_u16()?;
+        let element = reader.read_u16()?;
 
-        Tag { group, element }
+        Ok(Tag { group, element })
     }
 
-    fn read_value_representation(&self, reader: &mut DataReader) -> Option<ValueRepresentation>  {
+    fn read_value_representation(&self, reader: &mut DataReader) -> Result<Option<ValueRepresentation>, ParseError>  {
         let mut vr = [0; 2];
-        reader.read_exact(&mut vr);
-        Some(ValueRepresentation {value: vr })
+        reader.read_exact(&mut vr)?;
+        Ok(Some(ValueRepresentation {value: vr }))
     }
 
-    fn read_value_length(&self, value_representation: &ValueRepresentation, reader: &mut DataReader) -> u32 {
+    fn read_value_length(&self, value_representation: &ValueRepresentation, reader: &mut DataReader) -> Result<u32, ParseError> {
         if self.value_length_kept_on_2_bytes(value_representation, private::LOCAL) {
-            return reader.read_u16() as u32
+            return Ok(reader.read_u16()? as u32)
         }
 
         const RESERVED_BYTES: usize = 2;
-        reader.seek(Whence::Current, RESERVED_BYTES);
+        reader.seek(Whence::Current, RESERVED_BYTES)?;
         reader.read_u32()
     }
 
     fn read_value(&self,
                   value_representation: ValueRepresentation,
                   value_length : u32,
-                  reader: &mut DataReader) -> ValueField {
+                  reader: &mut DataReader) -> Result<ValueField, ParseError> {
         if value_representation.value == *b"AE" {
-            return ValueField::ApplicationEntity(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::ApplicationEntity(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"AS" {
-            return ValueField::AgeString(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::AgeString(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"AT" {
-            return ValueField::AttributeTag(self.read_attribute_tag(reader, value_length, private::LOCAL));
+            return Ok(ValueField::AttributeTag(self.read_attribute_tag(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"CS" {
-            return ValueField::CodeString(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::CodeString(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"DA" {
-            return ValueField::Date(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::Date(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"DS" {
-            return ValueField::DecimalString(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::DecimalString(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"DT" {
-            return ValueField::DateTime(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::DateTime(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"FL" {
-            return ValueField::FloatingPointSingle(
+            return Ok(ValueField::FloatingPointSingle(
                 self.read_numeric_types(
-                    || -> f32 { reader.read_f32() },
+                    || reader.read_f32(),
                     value_length,
-                    private::LOCAL));
+                    private::LOCAL)?));
         }
 
         if value_representation.value == *b"FD" {
-            return ValueField::FloatingPointDouble(
+            return Ok(ValueField::FloatingPointDouble(
                 self.read_numeric_types(
-                    || -> f64 { reader.read_f64() },
+                    || reader.read_f64(),
                     value_length,
-                    private::LOCAL));
+                    private::LOCAL)?));
         }
 
         if value_representation.value == *b"IS" {
-            return ValueField::IntegerString(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::IntegerString(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"LO" {
-            return ValueField::LongString(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::LongString(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"LT" {
-            return ValueField::LongText(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::LongText(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"OB" {
-            return ValueField::OtherByte(self.read_other_bytes(reader, value_length, private::LOCAL));
+            return Ok(ValueField::OtherByte(self.read_other_bytes(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"OD" {
-            return ValueField::OtherDouble(self.read_other_bytes(reader, value_length, private::LOCAL));
+            return Ok(ValueField::OtherDouble(self.read_other_bytes(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"OF" {
-            return ValueField::OtherFloat(self.read_other_bytes(reader, value_length, private::LOCAL));
+            return Ok(ValueField::OtherFloat(self.read_other_bytes(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"OL" {
-            return ValueField::OtherLong(self.read_other_bytes(reader, value_length, private::LOCAL));
+            return Ok(ValueField::OtherLong(self.read_other_bytes(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"OV" {
-            return ValueField::Other64bitVeryLong(self.read_other_bytes(reader, value_length, private::LOCAL));
+            return Ok(ValueField::Other64bitVeryLong(self.read_other_bytes(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"OW" {
-            return ValueField::OtherWord(self.read_other_bytes(reader, value_length, private::LOCAL));
+            return Ok(ValueField::OtherWord(self.read_other_bytes(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"PN" {
-            return ValueField::PersonName(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::PersonName(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"SH" {
-            return ValueField::ShortString(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::ShortString(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"SL" {
-            return ValueField::SignedLong(
+            return Ok(ValueField::SignedLong(
                 self.read_numeric_types(
-                    || -> i32 { reader.read_i32() },
+                    || reader.read_i32(),
                     value_length,
-                    private::LOCAL));
+                    private::LOCAL)?));
         }
 
         if value_representation.value == *b"SQ" {
-            //return ValueField::SequenceOfItems(self.read_other_bytes(reader, value_length, private::LOCAL));
+            return Ok(ValueField::SequenceOfItems(self.read_sequence_of_items(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"SS" {
-            return ValueField::SignedShort(
+            return Ok(ValueField::SignedShort(
                 self.read_numeric_types(
-                    || -> i16 { reader.read_i16() },
+                    || reader.read_i16(),
                     value_length,
-                    private::LOCAL));
+                    private::LOCAL)?));
         }
 
         if value_representation.value == *b"ST" {
-            return ValueField::ShortText(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::ShortText(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"SV" {
-            return ValueField::Signed64bitVeryLong(
+            return Ok(ValueField::Signed64bitVeryLong(
                 self.read_numeric_types(
-                    || -> i64 { reader.read_i64() },
+                    || reader.read_i64(),
                     value_length,
-                    private::LOCAL));
+                    private::LOCAL)?));
         }
 
         if value_representation.value == *b"TM" {
-            return ValueField::Time(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::Time(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"UC" {
-            return ValueField::UnlimitedCharacters(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::UnlimitedCharacters(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"UI" {
-            return ValueField::UniqueIdentifier(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::UniqueIdentifier(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"UL" {
-            return ValueField::UnsignedLong(
+            return Ok(ValueField::UnsignedLong(
                 self.read_numeric_types(
-                    || -> u32 { reader.read_u32() },
+                    || reader.read_u32(),
                     value_length,
-                    private::LOCAL));
+                    private::LOCAL)?));
         }
 
         if value_representation.value == *b"UN" {
-            //return ValueField::Unknown(self.read_other_bytes(reader, value_length, private::LOCAL));
+            return Ok(ValueField::Unknown(self.read_other_bytes(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"UR" {
-            return ValueField::UniversalResourceIdentifier(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::UniversalResourceIdentifier(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"US" {
-            return ValueField::UnsignedShort(
+            return Ok(ValueField::UnsignedShort(
                 self.read_numeric_types(
-                    || -> u16 { reader.read_u16() },
+                    || reader.read_u16(),
                     value_length,
-                    private::LOCAL));
+                    private::LOCAL)?));
         }
 
         if value_representation.value == *b"UT" {
-            return ValueField::UnlimitedText(self.read_string(reader, value_length, private::LOCAL));
+            return Ok(ValueField::UnlimitedText(self.read_string(reader, value_length, private::LOCAL)?));
         }
 
         if value_representation.value == *b"UV" {
-            return ValueField::Unsigned64bitVeryLong(
+            return Ok(ValueField::Unsigned64bitVeryLong(
                 self.read_numeric_types(
-                    || -> u64 { reader.read_u64() },
+                    || reader.read_u64(),
                     value_length,
-                    private::LOCAL));
+                    private::LOCAL)?));
         }
 
-        panic!("Unknown value representation: {:?}", value_representation);
+        Err(ParseError::new(reader.position(), ParseErrorKind::UnknownValueRepresentation(value_representation)))
     }
     fn value_length_kept_on_2_bytes(&self, value_representation: &ValueRepresentation, _ : private::Local) -> bool {
-        let vr = value_representation.value;
-
-        vr == *b"AE" ||
-        vr == *b"AS" ||
-        vr == *b"AT" ||
-        vr == *b"CS" ||
-        vr == *b"DA" ||
-        vr == *b"DS" ||
-        vr == *b"DT" ||
-        vr == *b"FL" ||
-        vr == *b"FD" ||
-        vr == *b"IS" ||
-        vr == *b"LO" ||
-        vr == *b"LT" ||
-        vr == *b"PN" ||
-        vr == *b"SH" ||
-        vr == *b"SL" ||
-        vr == *b"SS" ||
-        vr == *b"ST" ||
-        vr == *b"TM" ||
-        vr == *b"UI" ||
-        vr == *b"UL" ||
-        vr == *b"US"
-    }
-
-    fn read_string<VR: From<String> + From<DataElementLocation<String>>>(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> VR {
+        value_representation.has_short_value_length()
+    }
+
+    fn read_string<VR: From<String> + From<DataElementLocation<String>>>(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> Result<VR, ParseError> {
         let lazy_read = self.get_size_of_lazy_read_element();
 
         if lazy_read.is_some() && lazy_read.unwrap()  >= length {
             return self.read_lazy_dicom_string::<VR>(reader, length, private::LOCAL);
         }
 
-        let str = reader.read_string(length as usize);
-        VR::from(str)
+        let str = reader.read_string(length as usize)?;
+        Ok(VR::from(str))
     }
 
-    fn read_attribute_tag(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> AttributeTag {
+    fn read_attribute_tag(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> Result<AttributeTag, ParseError> {
         let num_of_elems = length as usize / (2 * std::mem::size_of::<u16>());
 
-        let mut val = Vec::with_capacity(num_of_elems);
+        let mut val = Vec::new();
 
         for _ in 0..num_of_elems {
-            val.push([reader.read_u16(), reader.read_u16()]);
+            val.push([reader.read_u16()?, reader.read_u16()?]);
         }
-        AttributeTag::new(val)
+        Ok(AttributeTag::new(val))
     }
 
-    fn read_other_bytes<VR: From<Submap>>(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> VR {
+    fn read_other_bytes<VR: From<Submap>>(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> Result<VR, ParseError> {
         let mut real_length = length as usize;
 
         // TODO Refactor it - it should be realized by object specialized to read JPEG data
         if length == UNDEFINED_LENGTH {
             let start_pos = reader.position();
 
-            reader.seek(Whence::Current, 4); // read offsetTable item tag
-            let offset_table_length = reader.read_u32() as usize;
-            reader.seek(Whence::Current, offset_table_length);
+            reader.seek(Whence::Current, 4)?; // read offsetTable item tag
+            let offset_table_length = reader.read_u32()? as usize;
+            reader.seek(Whence::Current, offset_table_length)?;
 
             if offset_table_length != 0 {
                 let num_of_entries = offset_table_length / std::mem::size_of::<u32>();
                 let num_of_slices = if num_of_entries == 0 { 1 } else { num_of_entries };
 
                 for _ in 0..num_of_slices {
-                    reader.seek(Whence::Current, 4); // read item tag
-                    let item_length = reader.read_u32() as usize;
-                    reader.seek(Whence::Current, item_length);
+                    reader.seek(Whence::Current, 4)?; // read item tag
+                    let item_length = reader.read_u32()? as usize;
+                    reader.seek(Whence::Current, item_length)?;
                 }
 
-                reader.seek(Whence::Current, 4); //Sequence Delimitation Item Tag
-                reader.seek(Whence::Current, 4); //Sequence Delimitation Item Length - equal to 0
+                reader.seek(Whence::Current, 4)?; //Sequence Delimitation Item Tag
+                reader.seek(Whence::Current, 4)?; //Sequence Delimitation Item Length - equal to 0
             }
             else {
                 while {
-                    let tag = self.read_tag(reader);
-                    let length = reader.read_u32();
-                    reader.seek(Whence::Current, length as usize);
-                    tag != SEQUENCE_DELIMITATION
+                    let tag = self.read_tag(reader)?;
+                    let length = reader.read_u32()?;
+                    reader.seek(Whence::Current, length as usize)?;
+                    tag != SEQUENCE_DELIMITATION_ITEM
                 } {}
-
-                reader.seek(Whence::Current, 4); //Sequence Delimitation Item Length - equal to 0
             }
 
             let end_pos = reader.position();
             real_length = end_pos - start_pos;
 
-            reader.seek(Whence::Start, start_pos);
+            reader.seek(Whence::Start, start_pos)?;
         }
 
         let vr = {
-            let desc = reader.get_subreader_desc(real_length);
+            let desc = reader.get_subreader_desc(real_length)?;
             VR::from(desc.submap)
         };
 
-        reader.seek(Whence::Current, real_length);
-        vr
+        reader.seek(Whence::Current, real_length)?;
+        Ok(vr)
+    }
+
+    /// Reads items until the sequence length is consumed or, for undefined length, until
+    /// the Sequence Delimitation Item. Each item is read as a nested dataset.
+    fn read_sequence_of_items(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> Result<SequenceOfItems, ParseError> {
+        let end = if length == UNDEFINED_LENGTH { None } else { Some(reader.position() + length as usize) };
+        let mut items = Vec::new();
+
+        while end.map_or(reader.unconsumed() > 0, |end| reader.position() < end) {
+            let tag = self.read_tag(reader)?;
+
+            if tag == SEQUENCE_DELIMITATION_ITEM {
+                reader.seek(Whence::Current, 4)?; // Sequence Delimitation Item Length - equal to 0
+                break;
+            }
+
+            if tag != ITEM {
+                return Err(ParseError::new(reader.position() - 4, ParseErrorKind::UnexpectedTagInSequence(tag)));
+            }
+
+            let item_length = reader.read_u32()?;
+            items.push(self.read_sequence_item(reader, item_length, private::LOCAL)?);
+        }
+
+        Ok(SequenceOfItems::new(items))
+    }
+
+    fn read_sequence_item(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> Result<SequenceItem, ParseError> {
+        // Specific Character Set and Private Creators of an item apply only to that item
+        let string_decoder = reader.string_decoder().clone();
+        let private_creators = reader.private_creators().clone();
+        reader.set_private_creators(PrivateCreators::new());
+        let item = self.read_sequence_item_elements(reader, length, private::LOCAL);
+        reader.set_string_decoder(string_decoder);
+        reader.set_private_creators(private_creators);
+
+        Ok(SequenceItem { data_elements: item? })
+    }
+
+    fn read_sequence_item_elements(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> Result<Vec<DataElement>, ParseError> {
+        let end = if length == UNDEFINED_LENGTH { None } else { Some(reader.position() + length as usize) };
+        let mut data_elements = Vec::new();
+
+        while end.map_or(reader.unconsumed() > 0, |end| reader.position() < end) {
+            let tag = self.read_tag(reader)?;
+
+            if tag == ITEM_DELIMITATION_ITEM {
+                reader.seek(Whence::Current, 4)?; // Item Delimitation Item Length - equal to 0
+                break;
+            }
+
+            let data_element = self.read_data_element(&tag, reader)
+                .map_err(|error| error.with_tag(tag))?;
+
+            if tag == SPECIFIC_CHARACTER_SET {
+                change_character_set(&data_element, reader);
+            }
+            reader.private_creators_mut().add(&data_element);
+
+            data_elements.push(data_element);
+        }
+
+        Ok(data_elements)
     }
 
-    fn read_numeric_types<VR: Numeric, F: FnMut() -> VR::Type>(
+    fn read_numeric_types<VR: Numeric, F: FnMut() -> Result<VR::Type, ParseError>>(
         &self,
         mut read_function: F,
-        length: u32, _ : private::Local) -> VR
+        length: u32, _ : private::Local) -> Result<VR, ParseError>
         where
             VR::Type: Default + Copy
     {
         let length = length as usize / std::mem::size_of::<VR::Type>();
-        let mut vec = Vec::<VR::Type>::with_capacity(length as usize);
+        // The length comes from the file, the vector grows only with values actually read
+        let mut vec = Vec::<VR::Type>::new();
 
         for _ in 0..length {
-            vec.push(read_function());
+            vec.push(read_function()?);
         }
 
-        VR::from(vec)
+        Ok(VR::from(vec))
     }
 
-    fn read_lazy_dicom_string<VR: From<DataElementLocation<String>>>(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> VR {
+    fn read_lazy_dicom_string<VR: From<DataElementLocation<String>>>(&self, reader: &mut DataReader, length: u32, _ : private::Local) -> Result<VR, ParseError> {
         let vr = {
-            let desc = reader.get_subreader_desc(length as usize);
+            let desc = reader.get_subreader_desc(length as usize)?;
 
             let read_func = Box::new(move || -> String {
                 let value: OnceCell<String> = OnceCell::new();
@@ -340,18 +403,19 @@ pub trait ValueReaderBase {
 
                 value.get_or_init(|| {
                     reader.read_string(length as usize)
+                        .expect("Subreader should cover the whole string")
                 }).to_string()
             });
 
             VR::from(DataElementLocation::new(read_func))
         };
 
-        reader.seek(Whence::Current, length as usize);
-        vr
+        reader.seek(Whence::Current, length as usize)?;
+        Ok(vr)
     }
 
-    fn read_data_element(&self, tag: &Tag, reader: &mut DataReader) -> DataElement;
-    fn skip_data_element(&self, tag: &Tag, reader: &mut DataReader);
+    fn read_data_element(&self, tag: &Tag, reader: &mut DataReader) -> Result<DataElement, ParseError>;
+    fn skip_data_element(&self, tag: &Tag, reader: &mut DataReader) -> Result<(), ParseError>;
 
     fn set_size_of_lazy_read_element(&mut self, _size: Option<u32>);
 
@@ -362,25 +426,76 @@ pub struct ExplicitValueReader {
     size_of_lazy_read_element: Option<u32>
 }
 
+impl Default for ExplicitValueReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ExplicitValueReader {
     pub fn new() -> Self {
         ExplicitValueReader { size_of_lazy_read_element: None }
     }
+
+    /// Elements with UN value representation are re-decoded with the VR known from the dictionary.
+    /// Undefined length UN is a sequence encoded with Implicit VR Little Endian (PS3.5 6.2.2),
+    /// the same applies to UN with defined length which the dictionary describes as a sequence.
+    fn read_unknown_value(&self, tag: &Tag, value_length: u32, reader: &mut DataReader) -> Result<(Option<ValueRepresentation>, ValueField), ParseError> {
+        let value_representation = dictionary_value_representation(tag, reader)
+            .filter(|vr| vr.value != UNKNOWN.value);
+
+        if value_length == UNDEFINED_LENGTH || value_representation == Some(SEQUENCE_OF_ITEMS) {
+            let implicit_reader = ImplicitValueReader { size_of_lazy_read_element: self.size_of_lazy_read_element };
+            let byte_order = reader.byte_order();
+
+            reader.change_byte_order(Endianness::Little);
+            let value = ValueReaderBase::read_value(&implicit_reader, SEQUENCE_OF_ITEMS, value_length, reader);
+            reader.change_byte_order(byte_order);
+
+            return Ok((Some(SEQUENCE_OF_ITEMS), value?));
+        }
+
+        match value_representation {
+            Some(value_representation) =>
+                Ok((Some(value_representation), self.read_value(value_representation, value_length, reader)?)),
+            None =>
+                Ok((Some(UNKNOWN), self.read_value(UNKNOWN, value_length, reader)?)),
+        }
+    }
 }
+
 impl ValueReaderBase for ExplicitValueReader {
-    fn read_data_element(&self, tag: &Tag, reader: &mut DataReader) -> DataElement {
+    fn read_data_element(&self, tag: &Tag, reader: &mut DataReader) -> Result<DataElement, ParseError> {
         let tag = *tag;
-        let value_representation = self.read_value_representation(reader);
-        let value_length = self.read_value_length(&value_representation.unwrap(), reader);
-        let value = self.read_value(value_representation.unwrap(), value_length, reader);
+        let offset = Some(reader.position() - TAG_LENGTH);
+        let value_representation = self.read_value_representation(reader)?;
+        let value_length = self.read_value_length(&value_representation.unwrap(), reader)?;
+
+        if value_representation == Some(UNKNOWN) {
+            let (value_representation, value) = self.read_unknown_value(&tag, value_length, reader)?;
+            return Ok(DataElement { tag, value_representation, value_length, value, offset });
+        }
+
+        let value = self.read_value(value_representation.unwrap(), value_length, reader)?;
 
-        DataElement { tag, value_representation, value_length, value }
+        Ok(DataElement { tag, value_representation, value_length, value, offset })
     }
 
-    fn skip_data_element(&self, _tag: &Tag, reader: &mut DataReader) {
-        let value_representation = self.read_value_representation(reader);
-        let value_length = self.read_value_length(&value_representation.unwrap(), reader);
-        reader.seek(Whence::Current, value_length as usize);
+    fn skip_data_element(&self, tag: &Tag, reader: &mut DataReader) -> Result<(), ParseError> {
+        let value_representation = self.read_value_representation(reader)?;
+        let value_length = self.read_value_length(&value_representation.unwrap(), reader)?;
+
+        if value_length == UNDEFINED_LENGTH {
+            // End of the value is known only after walking through its items
+            if value_representation == Some(UNKNOWN) {
+                self.read_unknown_value(tag, value_length, reader)?;
+            } else {
+                self.read_value(value_representation.unwrap(), value_length, reader)?;
+            }
+            return Ok(());
+        }
+
+        reader.seek(Whence::Current, value_length as usize)
     }
     fn set_size_of_lazy_read_element(&mut self, size: Option<u32>) {
         self.size_of_lazy_read_element = size;
@@ -396,23 +511,38 @@ pub struct ImplicitValueReader {
     size_of_lazy_read_element: Option<u32>
 }
 
+impl Default for ImplicitValueReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ImplicitValueReader {
     pub fn new() -> Self {
         ImplicitValueReader { size_of_lazy_read_element: None }
     }
 }
 impl ValueReaderBase for ImplicitValueReader {
-    fn read_data_element(&self, _tag: &Tag, reader: &mut DataReader) -> DataElement {
-        let tag = self.read_tag(reader);
-        let value_length = self.read_value_length(reader);
-        let value = self.read_value(value_length, reader);
+    fn read_data_element(&self, tag: &Tag, reader: &mut DataReader) -> Result<DataElement, ParseError> {
+        let tag = *tag;
+        let offset = Some(reader.position() - TAG_LENGTH);
+        let value_length = self.read_value_length(reader)?;
+        let value_representation = self.lookup_value_representation(&tag, reader);
+        let value = self.read_value(value_representation, value_length, reader)?;
 
-        DataElement { tag, value_length, value, value_representation: None }
+        Ok(DataElement { tag, value_length, value, value_representation, offset })
     }
 
-    fn skip_data_element(&self, _tag: &Tag, reader: &mut DataReader) {
-        let value_length = self.read_value_length(reader);
-        reader.seek(Whence::Current, value_length as usize);
+    fn skip_data_element(&self, tag: &Tag, reader: &mut DataReader) -> Result<(), ParseError> {
+        let value_length = self.read_value_length(reader)?;
+
+        if value_length == UNDEFINED_LENGTH {
+            // End of the value is known only after walking through its items
+            self.read_value(self.lookup_value_representation(tag, reader), value_length, reader)?;
+            return Ok(());
+        }
+
+        reader.seek(Whence::Current, value_length as usize)
     }
     fn set_size_of_lazy_read_element(&mut self, size: Option<u32>) {
         self.size_of_lazy_read_element = size;
@@ -425,25 +555,27 @@ impl ValueReaderBase for ImplicitValueReader {
 
 impl ImplicitValueReader {
     pub fn read_value(&self,
-                      _value_length : u32,
-                      _reader: &mut DataReader) -> ValueField {
-        if self.find_element_in_dict() {
-            panic!("Not implemented")
+                      value_representation: Option<ValueRepresentation>,
+                      value_length : u32,
+                      reader: &mut DataReader) -> Result<ValueField, ParseError> {
+        match value_representation {
+            Some(value_representation) =>
+                ValueReaderBase::read_value(self, value_representation, value_length, reader),
+            // Undefined length of an element missing from the dictionary means a sequence,
+            // e.g. a private one
+            None if value_length == UNDEFINED_LENGTH =>
+                ValueReaderBase::read_value(self, SEQUENCE_OF_ITEMS, value_length, reader),
+            None =>
+                ValueReaderBase::read_value(self, UNKNOWN, value_length, reader),
         }
-
-        panic!("Not implemented")
     }
 
-    pub fn read_value_length(&self, reader: &mut DataReader) -> u32 {
+    pub fn read_value_length(&self, reader: &mut DataReader) -> Result<u32, ParseError> {
         reader.read_u32()
     }
 
-    pub fn read_value_representation(&self, _reader: &mut DataReader) -> Option<ValueRepresentation> {
-        None
-    }
-
-    fn find_element_in_dict(&self) -> bool {
-        false
+    pub fn lookup_value_representation(&self, tag: &Tag, reader: &DataReader) -> Option<ValueRepresentation> {
+        dictionary_value_representation(tag, reader)
     }
 }
 
@@ -464,21 +596,21 @@ impl ValueReader {
         ValueReader::Implicit(ImplicitValueReader::new())
     }
 
-    pub fn read_tag(&self, reader: &mut DataReader) -> Tag {
+    pub fn read_tag(&self, reader: &mut DataReader) -> Result<Tag, ParseError> {
         match self {
             ValueReader::Explicit(explicit_reader) => explicit_reader.read_tag(reader),
             ValueReader::Implicit(implicit_reader) => implicit_reader.read_tag(reader),
         }
     }
 
-    pub fn read_value_representation(&self, reader: &mut DataReader) -> Option<ValueRepresentation> {
+    pub fn read_value_representation(&self, reader: &mut DataReader) -> Result<Option<ValueRepresentation>, ParseError> {
         match self {
             ValueReader::Explicit(explicit_reader) => explicit_reader.read_value_representation(reader),
-            ValueReader::Implicit(implicit_reader) => implicit_reader.read_value_representation(reader),
+            ValueReader::Implicit(_) => Ok(None),
         }
     }
 
-    pub fn read_value_length(&self, value_representation: Option<ValueRepresentation>, reader: &mut DataReader) -> u32 {
+    pub fn read_value_length(&self, value_representation: Option<ValueRepresentation>, reader: &mut DataReader) -> Result<u32, ParseError> {
         match self {
             ValueReader::Explicit(explicit_reader) => explicit_reader.read_value_length(&value_representation.unwrap(), reader),
             ValueReader::Implicit(implicit_reader) => implicit_reader.read_value_length(reader),
@@ -488,24 +620,24 @@ impl ValueReader {
     pub fn read_value(&self,
                       value_representation: Option<ValueRepresentation>,
                       value_length : u32,
-                      reader: &mut DataReader) -> ValueField {
+                      reader: &mut DataReader) -> Result<ValueField, ParseError> {
         match self {
             ValueReader::Explicit(explicit_reader) =>
                 explicit_reader.read_value(value_representation.unwrap(), value_length, reader),
 
             ValueReader::Implicit(implicit_reader) =>
-                implicit_reader.read_value(value_length, reader),
+                implicit_reader.read_value(value_representation, value_length, reader),
         }
     }
 
-    pub fn skip_value(&self, tag: &Tag, reader: &mut DataReader) {
+    pub fn skip_value(&self, tag: &Tag, reader: &mut DataReader) -> Result<(), ParseError> {
         match self {
             ValueReader::Explicit(explicit_reader) => explicit_reader.skip_data_element(tag, reader),
             ValueReader::Implicit(implicit_reader) => implicit_reader.skip_data_element(tag, reader),
         }
     }
 
-    pub fn read_data_element(&self, tag: &Tag, reader: &mut DataReader) -> DataElement {
+    pub fn read_data_element(&self, tag: &Tag, reader: &mut DataReader) -> Result<DataElement, ParseError> {
         match self {
             ValueReader::Explicit(explicit_reader) => explicit_reader.read_data_element(tag, reader),
             ValueReader::Implicit(implicit_reader) => implicit_reader.read_data_element(tag, reader),
@@ -519,107 +651,223 @@ impl ValueReader {
         }
     }
 }
-// mod tests {
-//     use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
-//     use super::*;
-//
-//     #[test]
-//     fn test_sequence_of_item_special_tag() {
-//         let parser = DicomFileParser::new();
-//
-//         let item_tag = Tag { group: 0xFFFE, element: 0xE000 };
-//         assert!(parser.sequence_of_item_special_tag(&item_tag));
-//
-//         let item_delimitation_tag = Tag { group: 0xFFFE, element: 0xE00D };
-//         assert!(parser.sequence_of_item_special_tag(&item_delimitation_tag));
-//
-//         let sequence_delimitation_tag = Tag { group: 0xFFFE, element: 0xE0DD };
-//         assert!(parser.sequence_of_item_special_tag(&sequence_delimitation_tag));
-//
-//         let non_special_tag = Tag { group: 0x0002, element: 0x0000 };
-//         assert!(!parser.sequence_of_item_special_tag(&non_special_tag));
-//     }
-//
-//     #[test]
-//     fn test_value_length_kept_on_2_bytes() {
-//         let parser = DicomFileParser::new();
-//
-//         let vr = ValueRepresentation { value: *b"AE" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"AS" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"AT" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"CS" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"DA" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"DS" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"DT" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"FL" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"FD" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"IS" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"LT" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"PN" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"SH" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"SL" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"SS" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"ST" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"TM" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"UI" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"UL" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"US" };
-//         assert!(parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"OB" };
-//         assert!(!parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"OW" };
-//         assert!(!parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"OF" };
-//         assert!(!parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"SQ" };
-//         assert!(!parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"UN" };
-//         assert!(!parser.value_length_kept_on_2_bytes(&vr));
-//
-//         let vr = ValueRepresentation { value: *b"UT" };
-//         assert!(!parser.value_length_kept_on_2_bytes(&vr));
-//     }
-// }
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::tags::{CODE_VALUE, DIFFUSION_B_VALUE, PATIENT_NAME, PIXEL_DATA, REFERENCED_INSTANCE_SEQUENCE, REFERENCED_SERIES_SEQUENCE, REFERENCED_SOP_INSTANCE_UID, ROWS, SERIES_INSTANCE_UID};
+    use crate::dicom_constants::transfer_syntaxes::{EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN};
+    use crate::dicom_constants::value_representation::{FLOATING_POINT_DOUBLE, OTHER_WORD, PERSON_NAME, SHORT_STRING, UNIQUE_IDENTIFIER_UID, UNSIGNED_SHORT};
+    use crate::test_support::dicom_generator::{data_reader, Dataset};
+    use crate::traits::cast::Cast;
+    use crate::value_representations::other_type::Other;
+    use super::*;
+
+    #[test]
+    fn test_implicit_reader_uses_dictionary() {
+        let bytes = Dataset::new()
+            .string(PATIENT_NAME, PERSON_NAME, "DOE^JOHN")
+            .numbers(ROWS, UNSIGNED_SHORT, &[512u16])
+            .numbers(PIXEL_DATA, OTHER_WORD, &[1u16, 2u16])
+            .to_bytes(IMPLICIT_VR_LITTLE_ENDIAN);
+
+        let value_reader = ImplicitValueReader::new();
+        let mut reader = data_reader(&bytes, Endianness::Little);
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let patient_name = value_reader.read_data_element(&tag, &mut reader).unwrap();
+        assert_eq!(patient_name.value_representation.unwrap().value, *b"PN");
+        match patient_name.value {
+            ValueField::PersonName(name) => assert_eq!(Into::<String>::into(&name), "DOE^JOHN"),
+            _ => panic!("Patient name should be read as PN")
+        }
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let rows = value_reader.read_data_element(&tag, &mut reader).unwrap();
+        match rows.value {
+            ValueField::UnsignedShort(rows) => assert_eq!(Cast::<u16>::cast(&rows).unwrap(), 512),
+            _ => panic!("Rows should be read as US")
+        }
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let pixel_data = value_reader.read_data_element(&tag, &mut reader).unwrap();
+        match pixel_data.value {
+            ValueField::OtherWord(pixels) => assert_eq!(pixels.value(), &vec![1u16, 2u16]),
+            _ => panic!("Pixel data should be read as OW")
+        }
+
+        assert_eq!(reader.unconsumed(), 0);
+    }
+
+    #[test]
+    fn test_implicit_reader_unknown_tag_keeps_raw_bytes() {
+        let bytes = Dataset::new()
+            .bytes(Tag { group: 0x0029, element: 0x1010 }, UNKNOWN, &[0xDE, 0xAD, 0xBE, 0xEF])
+            .to_bytes(IMPLICIT_VR_LITTLE_ENDIAN);
+
+        let value_reader = ImplicitValueReader::new();
+        let mut reader = data_reader(&bytes, Endianness::Little);
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let element = value_reader.read_data_element(&tag, &mut reader).unwrap();
+
+        assert!(element.value_representation.is_none());
+        match element.value {
+            ValueField::Unknown(unknown) => assert_eq!(unknown.as_raw_data(), &[0xDE, 0xAD, 0xBE, 0xEF]),
+            _ => panic!("Unknown tag should be kept as raw bytes")
+        }
+    }
+
+    #[test]
+    fn test_value_length_kept_on_2_bytes() {
+        let value_representations: [(&[u8; 2], bool); 26] = [
+            (b"AE", true), (b"AS", true), (b"AT", true), (b"CS", true), (b"DA", true), (b"DS", true),
+            (b"DT", true), (b"FL", true), (b"FD", true), (b"IS", true), (b"LT", true), (b"PN", true),
+            (b"SH", true), (b"SL", true), (b"SS", true), (b"ST", true), (b"TM", true), (b"UI", true),
+            (b"UL", true), (b"US", true), (b"OB", false), (b"OW", false), (b"OF", false), (b"SQ", false),
+            (b"UN", false), (b"UT", false)];
+
+        let value_reader = ValueReader::new_explicit();
+        for (value, short_length) in value_representations {
+            let value_representation = ValueRepresentation { value: *value };
+            let bytes = Dataset::new()
+                .bytes(Tag { group: 0x0029, element: 0x1010 }, value_representation, &[0; 8])
+                .to_bytes(EXPLICIT_VR_LITTLE_ENDIAN);
+            assert_eq!(bytes.len(), if short_length { 16 } else { 20 });
+
+            let mut reader = data_reader(&bytes, Endianness::Little);
+            value_reader.read_tag(&mut reader).unwrap();
+            let read_value_representation = value_reader.read_value_representation(&mut reader).unwrap();
+            assert_eq!(read_value_representation, Some(value_representation));
+            assert_eq!(value_reader.read_value_length(read_value_representation, &mut reader).unwrap(), 8);
+            assert_eq!(reader.unconsumed(), 8);
+        }
+    }
+
+    #[test]
+    fn test_length_beyond_end_of_data() {
+        let mut bytes = Dataset::new()
+            .numbers(DIFFUSION_B_VALUE, FLOATING_POINT_DOUBLE, &[1000.0f64])
+            .to_bytes(IMPLICIT_VR_LITTLE_ENDIAN);
+        bytes[4..8].copy_from_slice(&0xFFFF_FFF0u32.to_le_bytes());
+
+        let value_reader = ImplicitValueReader::new();
+        let mut reader = data_reader(&bytes, Endianness::Little);
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let error = value_reader.read_data_element(&tag, &mut reader).unwrap_err();
+        assert!(matches!(error.kind, ParseErrorKind::UnexpectedEndOfData));
+    }
+
+    fn sequence_items(value: &ValueField) -> &Vec<SequenceItem> {
+        match value {
+            ValueField::SequenceOfItems(sequence) => &sequence.items,
+            _ => panic!("Value should be read as SQ")
+        }
+    }
+
+    #[test]
+    fn test_implicit_reader_nested_sequences_with_defined_length() {
+        let instance = Dataset::new().string(REFERENCED_SOP_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, "1.2.3");
+        let series = Dataset::new()
+            .string(SERIES_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, "1.2.4")
+            .sequence_with_defined_length(REFERENCED_INSTANCE_SEQUENCE, vec![instance]);
+        let bytes = Dataset::new()
+            .sequence_with_defined_length(REFERENCED_SERIES_SEQUENCE, vec![series])
+            .numbers(ROWS, UNSIGNED_SHORT, &[512u16])
+            .to_bytes(IMPLICIT_VR_LITTLE_ENDIAN);
+
+        let value_reader = ImplicitValueReader::new();
+        let mut reader = data_reader(&bytes, Endianness::Little);
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let sequence = value_reader.read_data_element(&tag, &mut reader).unwrap();
+        let items = sequence_items(&sequence.value);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].data_elements.len(), 2);
+
+        let inner_items = sequence_items(&items[0].data_elements[0].value);
+        assert_eq!(inner_items.len(), 1);
+        match &inner_items[0].data_elements[0].value {
+            ValueField::UniqueIdentifier(uid) => assert_eq!(Into::<String>::into(uid), "1.2.3"),
+            _ => panic!("Referenced SOP Instance UID should be read as UI")
+        }
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        assert_eq!(tag, ROWS);
+    }
+
+    #[test]
+    fn test_explicit_reader_sequence_with_undefined_length() {
+        let code = Dataset::new().string(CODE_VALUE, SHORT_STRING, "T-D1");
+        let bytes = Dataset::new()
+            .sequence(Tag { group: 0x0008, element: 0x2218 }, vec![code, Dataset::new()])
+            .to_bytes(EXPLICIT_VR_LITTLE_ENDIAN);
+
+        let value_reader = ExplicitValueReader::new();
+        let mut reader = data_reader(&bytes, Endianness::Little);
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let sequence = value_reader.read_data_element(&tag, &mut reader).unwrap();
+        let items = sequence_items(&sequence.value);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].data_elements[0].tag, CODE_VALUE);
+        assert!(items[1].data_elements.is_empty());
+        assert_eq!(reader.unconsumed(), 0);
+
+        let mut reader = data_reader(&bytes, Endianness::Little);
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        value_reader.skip_data_element(&tag, &mut reader).unwrap();
+        assert_eq!(reader.unconsumed(), 0);
+    }
+
+    #[test]
+    fn test_explicit_reader_reinterprets_unknown_value_representation() {
+        let bytes = Dataset::new()
+            .bytes(ROWS, UNKNOWN, &512u16.to_le_bytes())
+            .bytes(Tag { group: 0x0029, element: 0x1010 }, UNKNOWN, &[0xAB, 0xCD])
+            .to_bytes(EXPLICIT_VR_LITTLE_ENDIAN);
+
+        let value_reader = ExplicitValueReader::new();
+        let mut reader = data_reader(&bytes, Endianness::Little);
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let rows = value_reader.read_data_element(&tag, &mut reader).unwrap();
+        assert_eq!(rows.value_representation.unwrap().value, *b"US");
+        match rows.value {
+            ValueField::UnsignedShort(rows) => assert_eq!(Cast::<u16>::cast(&rows).unwrap(), 512),
+            _ => panic!("Rows should be re-decoded as US")
+        }
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let private = value_reader.read_data_element(&tag, &mut reader).unwrap();
+        assert_eq!(private.value_representation.unwrap().value, *b"UN");
+        match private.value {
+            ValueField::Unknown(unknown) => assert_eq!(unknown.as_raw_data(), &[0xAB, 0xCD]),
+            _ => panic!("Private tag should be kept as raw bytes")
+        }
+    }
+
+    #[test]
+    fn test_explicit_reader_unknown_undefined_length_is_implicit_sequence() {
+        let item = Dataset::new().numbers(ROWS, UNSIGNED_SHORT, &[256u16]);
+        let bytes = Dataset::new()
+            .unknown_sequence(Tag { group: 0x0029, element: 0x1020 }, vec![item])
+            .bytes(Tag { group: 0x0029, element: 0x1030 }, UNKNOWN, &[])
+            .to_bytes(EXPLICIT_VR_LITTLE_ENDIAN);
+
+        let value_reader = ExplicitValueReader::new();
+        let mut reader = data_reader(&bytes, Endianness::Little);
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        let sequence = value_reader.read_data_element(&tag, &mut reader).unwrap();
+        assert_eq!(sequence.value_representation.unwrap().value, *b"SQ");
+
+        let items = sequence_items(&sequence.value);
+        match &items[0].data_elements[0].value {
+            ValueField::UnsignedShort(rows) => assert_eq!(Cast::<u16>::cast(rows).unwrap(), 256),
+            _ => panic!("Item content should be read with implicit VR")
+        }
+
+        let tag = value_reader.read_tag(&mut reader).unwrap();
+        assert_eq!(tag, Tag { group: 0x0029, element: 0x1030 });
+    }
+}
@@ -0,0 +1,331 @@
+use std::io;
+use std::io::Write;
+use flate2::Compression;
+use flate2::write::DeflateEncoder;
+use crate::dataset::data_element::DataElement;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_field::ValueField;
+use crate::dicom_constants::numeric::{HEADER, HEADER_START};
+use crate::dicom_constants::tags::{FILE_META_INFORMATION_GROUP_LENGTH, FILE_META_INFORMATION_VERSION, IMPLEMENTATION_CLASS_UID, IMPLEMENTATION_VERSION_NAME, MEDIA_STORAGE_SOP_CLASS_UID, MEDIA_STORAGE_SOP_INSTANCE_UID, PIXEL_DATA, SOP_CLASS_UID, SOP_INSTANCE_UID, TRANSFER_SYNTAX_UID};
+use crate::dicom_constants::transfer_syntaxes::EXPLICIT_VR_LITTLE_ENDIAN;
+use crate::dicom_constants::value_representation::{OTHER_BYTE, SHORT_STRING, UNIQUE_IDENTIFIER_UID, UNSIGNED_LONG};
+use crate::dicom_file_parser::transfer_syntax::TransferSyntax;
+use crate::utils::endianness::Endianness;
+use crate::utils::submap::Submap;
+use crate::value_representations::dicom_string::DicomString;
+use crate::value_representations::numeric_type::NumericType;
+use crate::value_representations::other_type::OtherType;
+use super::value_writer::{is_encapsulated, ValueWriter};
+
+const FILE_META_GROUP: u16 = 0x0002;
+const FILE_META_INFORMATION_VERSION_VALUE: [u8; 2] = [0x00, 0x01];
+
+/// UID of this implementation, derived from a UUID as described in PS3.5 B.2.
+pub const IMPLEMENTATION_CLASS_UID_VALUE: &str = "2.25.207893402918306275637474081519856417443";
+pub const IMPLEMENTATION_VERSION_NAME_VALUE: &str = concat!("DCMVIEWER_", env!("CARGO_PKG_VERSION"));
+
+/// Writes datasets as DICOM Part 10 files: preamble, `DICM` prefix, file meta group and the
+/// dataset encoded in the chosen transfer syntax.
+pub struct DicomFileWriter {
+    transfer_syntax: TransferSyntax,
+}
+
+impl Default for DicomFileWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DicomFileWriter {
+    pub fn new() -> Self {
+        Self {
+            transfer_syntax: TransferSyntax::from_uid(EXPLICIT_VR_LITTLE_ENDIAN)
+                .expect("Explicit VR Little Endian should be a known transfer syntax"),
+        }
+    }
+
+    /// Transfer syntax of the written dataset. Encapsulated transfer syntaxes require the pixel data
+    /// to be already encapsulated - the writer does not compress frames.
+    pub fn transfer_syntax(mut self, transfer_syntax: TransferSyntax) -> Self {
+        self.transfer_syntax = transfer_syntax;
+        self
+    }
+
+    pub fn write(&self, file_path: &str, data_elements: &[DataElement]) -> io::Result<()> {
+        let file = std::fs::File::create(file_path)?;
+        self.write_to(io::BufWriter::new(file), data_elements)
+    }
+
+    pub fn write_to<W: Write>(&self, mut writer: W, data_elements: &[DataElement]) -> io::Result<()> {
+        writer.write_all(&self.to_bytes(data_elements)?)?;
+        writer.flush()
+    }
+
+    /// Serializes the file. File meta elements of the dataset are kept, except those describing
+    /// the encoding, which are recomputed. Media Storage SOP Class and Instance UIDs missing from
+    /// the file meta group are copied from SOP Class and Instance UIDs of the dataset.
+    pub fn to_bytes(&self, data_elements: &[DataElement]) -> io::Result<Vec<u8>> {
+        self.check_transfer_syntax(data_elements)?;
+
+        let (file_meta, dataset): (Vec<&DataElement>, Vec<&DataElement>) = data_elements.iter()
+            .partition(|data_element| data_element.tag.group == FILE_META_GROUP);
+
+        let mut output = vec![0u8; HEADER_START];
+        output.extend_from_slice(HEADER);
+        self.write_file_meta(&file_meta, &dataset, &mut output)?;
+
+        let mut encoded_dataset = Vec::new();
+        let mut value_writer = ValueWriter::new(self.transfer_syntax.explicit_value_representation);
+        value_writer.write_data_elements(dataset, &mut encoded_dataset)?;
+
+        if self.transfer_syntax.deflated {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&encoded_dataset)?;
+            encoded_dataset = encoder.finish()?;
+        }
+
+        output.extend_from_slice(&encoded_dataset);
+        Ok(output)
+    }
+
+    fn check_transfer_syntax(&self, data_elements: &[DataElement]) -> io::Result<()> {
+        if self.transfer_syntax.endianness != Endianness::Little {
+            return Err(io::Error::new(io::ErrorKind::Unsupported,
+                                      format!("Writing in transfer syntax {} is not supported", self.transfer_syntax.uid)));
+        }
+
+        let encapsulated_pixel_data = data_elements.iter()
+            .find(|data_element| data_element.tag == PIXEL_DATA)
+            .map(is_encapsulated);
+
+        match encapsulated_pixel_data {
+            Some(true) if !self.transfer_syntax.encapsulated => Err(io::Error::new(io::ErrorKind::InvalidInput,
+                format!("Encapsulated pixel data cannot be written in transfer syntax {}", self.transfer_syntax.uid))),
+            Some(false) if self.transfer_syntax.encapsulated => Err(io::Error::new(io::ErrorKind::InvalidInput,
+                format!("Native pixel data cannot be written in transfer syntax {}", self.transfer_syntax.uid))),
+            _ => Ok(())
+        }
+    }
+
+    /// The file meta group is always Explicit VR Little Endian, preceded by its group length.
+    fn write_file_meta(&self, file_meta: &[&DataElement], dataset: &[&DataElement], output: &mut Vec<u8>) -> io::Result<()> {
+        const RECOMPUTED: [Tag; 5] = [
+            FILE_META_INFORMATION_GROUP_LENGTH,
+            FILE_META_INFORMATION_VERSION,
+            TRANSFER_SYNTAX_UID,
+            IMPLEMENTATION_CLASS_UID,
+            IMPLEMENTATION_VERSION_NAME,
+        ];
+
+        let mut elements: Vec<DataElement> = Vec::new();
+
+        for (media_storage_tag, dataset_tag) in [(MEDIA_STORAGE_SOP_CLASS_UID, SOP_CLASS_UID), (MEDIA_STORAGE_SOP_INSTANCE_UID, SOP_INSTANCE_UID)] {
+            if file_meta.iter().any(|data_element| data_element.tag == media_storage_tag) {
+                continue;
+            }
+
+            let uid = dataset.iter()
+                .find(|data_element| data_element.tag == dataset_tag)
+                .and_then(|data_element| match &data_element.value {
+                    ValueField::UniqueIdentifier(uid) => Some(Into::<String>::into(uid)),
+                    _ => None,
+                });
+
+            if let Some(uid) = uid {
+                elements.push(unique_identifier(media_storage_tag, &uid));
+            }
+        }
+
+        elements.push(DataElement {
+            tag: FILE_META_INFORMATION_VERSION,
+            value_representation: Some(OTHER_BYTE),
+            value_length: FILE_META_INFORMATION_VERSION_VALUE.len() as u32,
+            value: ValueField::OtherByte(OtherType::from(Submap::from_bytes(&FILE_META_INFORMATION_VERSION_VALUE, Endianness::Little)?)),
+            offset: None,
+        });
+        elements.push(unique_identifier(TRANSFER_SYNTAX_UID, self.transfer_syntax.uid));
+        elements.push(unique_identifier(IMPLEMENTATION_CLASS_UID, IMPLEMENTATION_CLASS_UID_VALUE));
+        elements.push(DataElement {
+            tag: IMPLEMENTATION_VERSION_NAME,
+            value_representation: Some(SHORT_STRING),
+            value_length: IMPLEMENTATION_VERSION_NAME_VALUE.len() as u32,
+            value: ValueField::ShortString(DicomString::from(IMPLEMENTATION_VERSION_NAME_VALUE.to_string())),
+            offset: None,
+        });
+
+        let mut value_writer = ValueWriter::new(true);
+
+        let mut group = Vec::new();
+        let kept = file_meta.iter().copied().filter(|data_element| !RECOMPUTED.contains(&data_element.tag));
+        value_writer.write_data_elements(kept.chain(&elements), &mut group)?;
+
+        let group_length = DataElement {
+            tag: FILE_META_INFORMATION_GROUP_LENGTH,
+            value_representation: Some(UNSIGNED_LONG),
+            value_length: 4,
+            value: ValueField::UnsignedLong(NumericType::from(vec![group.len() as u32])),
+            offset: None,
+        };
+        value_writer.write_data_element(&group_length, output)?;
+        output.extend_from_slice(&group);
+
+        Ok(())
+    }
+}
+
+fn unique_identifier(tag: Tag, uid: &str) -> DataElement {
+    DataElement {
+        tag,
+        value_representation: Some(UNIQUE_IDENTIFIER_UID),
+        value_length: uid.len() as u32,
+        value: ValueField::UniqueIdentifier(DicomString::from(uid.to_string())),
+        offset: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::numeric::UNDEFINED_LENGTH;
+    use crate::dicom_constants::tags::{ITEM, MODALITY, PATIENT_NAME, REFERENCED_IMAGE_SEQUENCE, ROWS, SEQUENCE_DELIMITATION_ITEM, SPECIFIC_CHARACTER_SET, STATION_NAME};
+    use crate::dicom_constants::transfer_syntaxes::{IMPLICIT_VR_LITTLE_ENDIAN, JPEG_BASELINE_8_BIT};
+    use crate::dicom_constants::value_representation::{CODE_STRING, PERSON_NAME, SEQUENCE_OF_ITEMS, UNSIGNED_SHORT};
+    use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+    use crate::value_representations::numeric_type::Numeric;
+    use crate::value_representations::other_type::Other;
+    use crate::value_representations::sequence_of_items::{SequenceItem, SequenceOfItems};
+    use super::*;
+
+    fn string_element(tag: Tag, value: &str) -> DataElement {
+        let (value_representation, value_field): (_, fn(DicomString) -> ValueField) = match tag {
+            SPECIFIC_CHARACTER_SET | MODALITY | STATION_NAME => (CODE_STRING, ValueField::CodeString),
+            PATIENT_NAME => (PERSON_NAME, ValueField::PersonName),
+            _ => (UNIQUE_IDENTIFIER_UID, ValueField::UniqueIdentifier),
+        };
+
+        DataElement {
+            tag,
+            value_representation: Some(value_representation),
+            value_length: value.len() as u32,
+            value: value_field(DicomString::from(value.to_string())),
+            offset: None,
+        }
+    }
+
+    fn dataset() -> Vec<DataElement> {
+        let item = SequenceItem { data_elements: vec![
+            string_element(SPECIFIC_CHARACTER_SET, "ISO_IR 100"),
+            string_element(PATIENT_NAME, "Buc^Jérôme"),
+        ] };
+
+        vec![
+            DataElement { tag: ROWS, value_representation: Some(UNSIGNED_SHORT), value_length: 2, value: ValueField::UnsignedShort(NumericType::from(vec![512])), offset: None },
+            string_element(PATIENT_NAME, "Doe^Jon"),
+            DataElement { tag: REFERENCED_IMAGE_SEQUENCE, value_representation: Some(SEQUENCE_OF_ITEMS), value_length: UNDEFINED_LENGTH, value: ValueField::SequenceOfItems(SequenceOfItems::new(vec![item])), offset: None },
+            string_element(SOP_INSTANCE_UID, "1.2.3"),
+        ]
+    }
+
+    fn find(data_elements: &[DataElement], tag: Tag) -> &ValueField {
+        &data_elements.iter().find(|data_element| data_element.tag == tag).unwrap().value
+    }
+
+    fn string_value(data_elements: &[DataElement], tag: Tag) -> String {
+        match find(data_elements, tag) {
+            ValueField::UniqueIdentifier(string) | ValueField::PersonName(string) => string.into(),
+            value => panic!("Unexpected value {:?}", value),
+        }
+    }
+
+    #[test]
+    fn test_write_explicit_and_implicit_little_endian() {
+        for transfer_syntax in [EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN] {
+            let bytes = DicomFileWriter::new()
+                .transfer_syntax(TransferSyntax::from_uid(transfer_syntax).unwrap())
+                .to_bytes(&dataset())
+                .unwrap();
+
+            assert_eq!(&bytes[HEADER_START..HEADER_START + HEADER.len()], HEADER);
+
+            let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&bytes).unwrap();
+            assert_eq!(parsed.transfer_syntax.uid, transfer_syntax);
+
+            let data_elements = &parsed.data_elements;
+            assert_eq!(string_value(data_elements, PATIENT_NAME), "Doe^Jon ");
+            assert_eq!(string_value(data_elements, SOP_INSTANCE_UID), "1.2.3");
+            assert_eq!(string_value(data_elements, MEDIA_STORAGE_SOP_INSTANCE_UID), "1.2.3");
+            assert_eq!(string_value(data_elements, IMPLEMENTATION_CLASS_UID), IMPLEMENTATION_CLASS_UID_VALUE);
+            assert!(matches!(find(data_elements, ROWS), ValueField::UnsignedShort(rows) if rows.value() == &vec![512]));
+
+            let ValueField::SequenceOfItems(sequence) = find(data_elements, REFERENCED_IMAGE_SEQUENCE) else {
+                panic!("Expected sequence of items")
+            };
+            assert_eq!(string_value(&sequence.items[0].data_elements, PATIENT_NAME), "Buc^Jérôme");
+        }
+    }
+
+    #[test]
+    fn test_write_pads_odd_length_values() {
+        let dataset = [string_element(SOP_INSTANCE_UID, "1.2.3"),
+                       string_element(PATIENT_NAME, "Doe"),
+                       string_element(MODALITY, "CT")];
+        let bytes = DicomFileWriter::new().to_bytes(&dataset).unwrap();
+
+        let contains = |expected: &[u8]| bytes.windows(expected.len()).any(|window| window == expected);
+        assert!(contains(b"UI\x06\x001.2.3\0"));
+        assert!(contains(b"PN\x04\x00Doe "));
+        assert!(contains(b"CS\x02\x00CT"));
+    }
+
+    #[test]
+    fn test_write_keeps_code_extensions_and_element_vr() {
+        let mut station_name = string_element(STATION_NAME, "CT01");
+        station_name.value_representation = Some(SHORT_STRING);
+        let dataset = [string_element(SPECIFIC_CHARACTER_SET, "\\ISO 2022 IR 87"),
+                       string_element(PATIENT_NAME, "Yamada^Tarou=山田^太郎"),
+                       string_element(SOP_INSTANCE_UID, "1.2.3"),
+                       station_name];
+        let bytes = DicomFileWriter::new().to_bytes(&dataset).unwrap();
+
+        let contains = |expected: &[u8]| bytes.windows(expected.len()).any(|window| window == expected);
+        assert!(contains(b"Yamada^Tarou=\x1b$B;3ED\x1b(B^\x1b$BB@O:\x1b(B"));
+        assert!(contains(b"SH\x04\x00CT01"));
+
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&bytes).unwrap();
+        assert_eq!(string_value(&parsed.data_elements, PATIENT_NAME), "Yamada^Tarou=山田^太郎");
+    }
+
+    #[test]
+    fn test_write_encapsulated_pixel_data() {
+        let mut fragments = Vec::new();
+        for (tag, content) in [(ITEM, &[][..]), (ITEM, &[0xFF, 0xD8, 0xFF, 0xD9][..]), (SEQUENCE_DELIMITATION_ITEM, &[][..])] {
+            fragments.extend_from_slice(&tag.group.to_le_bytes());
+            fragments.extend_from_slice(&tag.element.to_le_bytes());
+            fragments.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            fragments.extend_from_slice(content);
+        }
+
+        let mut dataset = dataset();
+        dataset.push(DataElement {
+            tag: PIXEL_DATA,
+            value_representation: Some(OTHER_BYTE),
+            value_length: UNDEFINED_LENGTH,
+            value: ValueField::OtherByte(OtherType::from(Submap::from_bytes(&fragments, Endianness::Little).unwrap())),
+            offset: None,
+        });
+
+        let native = DicomFileWriter::new().to_bytes(&dataset);
+        assert_eq!(native.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+
+        let bytes = DicomFileWriter::new()
+            .transfer_syntax(TransferSyntax::from_uid(JPEG_BASELINE_8_BIT).unwrap())
+            .to_bytes(&dataset)
+            .unwrap();
+
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&bytes).unwrap();
+        assert!(parsed.transfer_syntax.encapsulated);
+
+        let pixel_data = parsed.data_elements.iter().find(|data_element| data_element.tag == PIXEL_DATA).unwrap();
+        assert_eq!(pixel_data.value_length, UNDEFINED_LENGTH);
+        assert!(matches!(&pixel_data.value, ValueField::OtherByte(other) if other.as_raw_data() == fragments.as_slice()));
+    }
+}
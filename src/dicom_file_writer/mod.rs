@@ -0,0 +1,2 @@
+pub mod dicom_file_writer;
+pub mod value_writer;
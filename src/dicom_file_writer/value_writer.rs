@@ -0,0 +1,255 @@
+use std::io;
+use crate::data_reader::string_decoder::StringDecoder;
+use crate::dataset::data_element::DataElement;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_field::ValueField;
+use crate::dataset::value_representation::ValueRepresentation;
+use crate::dicom_constants::numeric::UNDEFINED_LENGTH;
+use crate::dicom_constants::tags::{ITEM, SPECIFIC_CHARACTER_SET};
+use crate::dicom_constants::value_representation::*;
+use crate::value_representations::numeric_type::Numeric;
+use crate::value_representations::other_type::Other;
+use crate::value_representations::sequence_of_items::SequenceOfItems;
+
+/// Conversion of numeric values to bytes in Little Endian order.
+pub trait LittleEndianBytes: Copy {
+    fn extend_le_bytes(self, output: &mut Vec<u8>);
+}
+
+macro_rules! little_endian_bytes {
+    ($($type:ty),*) => {
+        $(impl LittleEndianBytes for $type {
+            fn extend_le_bytes(self, output: &mut Vec<u8>) {
+                output.extend_from_slice(&self.to_le_bytes());
+            }
+        })*
+    };
+}
+
+little_endian_bytes!(u16, i16, u32, i32, u64, i64, f32, f64);
+
+/// Serializes data elements in Little Endian byte order, with explicit or implicit VR.
+pub struct ValueWriter {
+    explicit_value_representation: bool,
+    string_encoder: StringDecoder,
+}
+
+impl ValueWriter {
+    pub fn new(explicit_value_representation: bool) -> Self {
+        Self { explicit_value_representation, string_encoder: StringDecoder::new() }
+    }
+
+    /// Writes the elements in ascending order of tags. Group length elements are skipped - they
+    /// are retired outside of the file meta group and wouldn't match the written lengths.
+    /// Strings following Specific Character Set are encoded in the character set it specifies.
+    pub fn write_data_elements<'a>(&mut self,
+                                   data_elements: impl IntoIterator<Item = &'a DataElement>,
+                                   output: &mut Vec<u8>) -> io::Result<()> {
+        let mut data_elements: Vec<&DataElement> = data_elements.into_iter()
+            .filter(|data_element| data_element.tag.element != 0x0000)
+            .collect();
+        data_elements.sort_by_key(|data_element| data_element.tag);
+
+        for data_element in data_elements {
+            if data_element.tag == SPECIFIC_CHARACTER_SET {
+                if let ValueField::CodeString(character_set) = &data_element.value {
+                    self.string_encoder.change_decoder(character_set.into());
+                }
+            }
+
+            self.write_data_element(data_element, output)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_data_element(&mut self, data_element: &DataElement, output: &mut Vec<u8>) -> io::Result<()> {
+        let value_representation = value_representation(data_element);
+
+        if is_encapsulated(data_element) {
+            // Fragments are kept as read, together with the Sequence Delimitation Item
+            self.write_header(data_element.tag, value_representation, UNDEFINED_LENGTH, output);
+            output.extend_from_slice(raw_data(&data_element.value));
+            return Ok(());
+        }
+
+        let mut value = self.encode_value(&data_element.value)?;
+
+        if value.len() & 1 == 1 {
+            value.push(padding(value_representation));
+        }
+
+        if self.explicit_value_representation && value_representation.has_short_value_length() && value.len() > u16::MAX as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      format!("Value of {} is too long for {}", data_element.tag, value_representation)));
+        }
+
+        let length = u32::try_from(value.len())
+            .ok()
+            .filter(|length| *length != UNDEFINED_LENGTH)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("Value of {} is too long", data_element.tag)))?;
+
+        self.write_header(data_element.tag, value_representation, length, output);
+        output.extend_from_slice(&value);
+
+        Ok(())
+    }
+
+    fn write_header(&self, tag: Tag, value_representation: ValueRepresentation, length: u32, output: &mut Vec<u8>) {
+        output.extend_from_slice(&tag.group.to_le_bytes());
+        output.extend_from_slice(&tag.element.to_le_bytes());
+
+        if !self.explicit_value_representation {
+            output.extend_from_slice(&length.to_le_bytes());
+            return;
+        }
+
+        output.extend_from_slice(&value_representation.value);
+
+        if value_representation.has_short_value_length() {
+            output.extend_from_slice(&(length as u16).to_le_bytes());
+        } else {
+            const RESERVED_BYTES: [u8; 2] = [0, 0];
+            output.extend_from_slice(&RESERVED_BYTES);
+            output.extend_from_slice(&length.to_le_bytes());
+        }
+    }
+
+    fn encode_value(&mut self, value: &ValueField) -> io::Result<Vec<u8>> {
+        let bytes = match value {
+            ValueField::ApplicationEntity(string) |
+            ValueField::AgeString(string) |
+            ValueField::CodeString(string) |
+            ValueField::Date(string) |
+            ValueField::DateTime(string) |
+            ValueField::LongString(string) |
+            ValueField::LongText(string) |
+            ValueField::PersonName(string) |
+            ValueField::ShortString(string) |
+            ValueField::ShortText(string) |
+            ValueField::Time(string) |
+            ValueField::UnlimitedCharacters(string) |
+            ValueField::UniqueIdentifier(string) |
+            ValueField::UniversalResourceIdentifier(string) |
+            ValueField::UnlimitedText(string) => self.string_encoder.encode(&Into::<String>::into(string))?,
+            ValueField::DecimalString(string) |
+            ValueField::IntegerString(string) => self.string_encoder.encode(&Into::<String>::into(string))?,
+            ValueField::AttributeTag(attribute_tag) => attribute_tag.value.iter()
+                .flat_map(|[group, element]| group.to_le_bytes().into_iter().chain(element.to_le_bytes()))
+                .collect(),
+            ValueField::FloatingPointSingle(numbers) => numeric(numbers.value()),
+            ValueField::FloatingPointDouble(numbers) => numeric(numbers.value()),
+            ValueField::SignedLong(numbers) => numeric(numbers.value()),
+            ValueField::SignedShort(numbers) => numeric(numbers.value()),
+            ValueField::Signed64bitVeryLong(numbers) => numeric(numbers.value()),
+            ValueField::UnsignedLong(numbers) => numeric(numbers.value()),
+            ValueField::UnsignedShort(numbers) => numeric(numbers.value()),
+            ValueField::Unsigned64bitVeryLong(numbers) => numeric(numbers.value()),
+            ValueField::OtherDouble(other) => numeric(other.value()),
+            ValueField::OtherFloat(other) => numeric(other.value()),
+            ValueField::OtherLong(other) => numeric(other.value()),
+            ValueField::Other64bitVeryLong(other) => numeric(other.value()),
+            ValueField::OtherWord(other) => numeric(other.value()),
+            ValueField::OtherByte(_) | ValueField::Unknown(_) => raw_data(value).to_vec(),
+            ValueField::SequenceOfItems(sequence) => self.encode_sequence(sequence)?,
+        };
+
+        Ok(bytes)
+    }
+
+    /// Items are written with defined length. Each item is a nested dataset - a Specific Character
+    /// Set inside applies only to that item.
+    fn encode_sequence(&mut self, sequence: &SequenceOfItems) -> io::Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+
+        for item in &sequence.items {
+            let string_encoder = self.string_encoder.clone();
+            let mut content = Vec::new();
+            let written = self.write_data_elements(&item.data_elements, &mut content);
+            self.string_encoder = string_encoder;
+            written?;
+
+            bytes.extend_from_slice(&ITEM.group.to_le_bytes());
+            bytes.extend_from_slice(&ITEM.element.to_le_bytes());
+            bytes.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&content);
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// VR of the element as read or created, or for elements without one, the VR matching the type
+/// of the value.
+pub fn value_representation(data_element: &DataElement) -> ValueRepresentation {
+    data_element.value_representation.unwrap_or_else(|| value_field_representation(&data_element.value))
+}
+
+fn value_field_representation(value: &ValueField) -> ValueRepresentation {
+    match value {
+        ValueField::ApplicationEntity(_) => APPLICATION_ENTITY,
+        ValueField::AgeString(_) => AGE_STRING,
+        ValueField::AttributeTag(_) => ATTRIBUTE_TAG,
+        ValueField::CodeString(_) => CODE_STRING,
+        ValueField::Date(_) => DATE,
+        ValueField::DateTime(_) => DATE_TIME,
+        ValueField::DecimalString(_) => DECIMAL_STRING,
+        ValueField::FloatingPointSingle(_) => FLOATING_POINT_SINGLE,
+        ValueField::FloatingPointDouble(_) => FLOATING_POINT_DOUBLE,
+        ValueField::IntegerString(_) => INTEGER_STRING,
+        ValueField::LongString(_) => LONG_STRING,
+        ValueField::LongText(_) => LONG_TEXT,
+        ValueField::OtherByte(_) => OTHER_BYTE,
+        ValueField::OtherDouble(_) => OTHER_DOUBLE,
+        ValueField::OtherFloat(_) => OTHER_FLOAT,
+        ValueField::OtherLong(_) => OTHER_LONG,
+        ValueField::Other64bitVeryLong(_) => OTHER_64_BIT_VERY_LONG,
+        ValueField::OtherWord(_) => OTHER_WORD,
+        ValueField::PersonName(_) => PERSON_NAME,
+        ValueField::ShortString(_) => SHORT_STRING,
+        ValueField::SignedLong(_) => SIGNED_LONG,
+        ValueField::SequenceOfItems(_) => SEQUENCE_OF_ITEMS,
+        ValueField::SignedShort(_) => SIGNED_SHORT,
+        ValueField::ShortText(_) => SHORT_TEXT,
+        ValueField::Signed64bitVeryLong(_) => SIGNED_64_BIT_VERY_LONG,
+        ValueField::Time(_) => TIME,
+        ValueField::UnlimitedCharacters(_) => UNLIMITED_CHARACTER,
+        ValueField::UniqueIdentifier(_) => UNIQUE_IDENTIFIER_UID,
+        ValueField::UnsignedLong(_) => UNSIGNED_LONG,
+        ValueField::Unknown(_) => UNKNOWN,
+        ValueField::UniversalResourceIdentifier(_) => UNIVERSAL_RESOURCE,
+        ValueField::UnsignedShort(_) => UNSIGNED_SHORT,
+        ValueField::UnlimitedText(_) => UNLIMITED_TEXT,
+        ValueField::Unsigned64bitVeryLong(_) => UNSIGNED_64_BIT_VERY_LONG,
+    }
+}
+
+/// Encapsulated pixel data is read as OB (or UN) of undefined length, spanning all the fragments.
+pub fn is_encapsulated(data_element: &DataElement) -> bool {
+    data_element.value_length == UNDEFINED_LENGTH &&
+        matches!(data_element.value, ValueField::OtherByte(_) | ValueField::OtherWord(_) | ValueField::Unknown(_))
+}
+
+fn raw_data(value: &ValueField) -> &[u8] {
+    match value {
+        ValueField::OtherByte(other) => other.as_raw_data(),
+        ValueField::OtherWord(other) => other.as_raw_data(),
+        ValueField::Unknown(unknown) => unknown.as_raw_data(),
+        _ => &[],
+    }
+}
+
+/// Numeric values as Little Endian bytes.
+pub fn numeric<T: LittleEndianBytes>(values: &[T]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(std::mem::size_of_val(values));
+    values.iter().for_each(|value| value.extend_le_bytes(&mut bytes));
+    bytes
+}
+
+/// UIDs are padded with a NULL byte, binary values with 0x00 and other strings with a space.
+fn padding(value_representation: ValueRepresentation) -> u8 {
+    match value_representation {
+        UNIQUE_IDENTIFIER_UID | OTHER_BYTE | UNKNOWN => 0x00,
+        _ => b' ',
+    }
+}
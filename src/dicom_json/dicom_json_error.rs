@@ -0,0 +1,26 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum DicomJsonError {
+    /// The text is not valid JSON, or nests values too deeply.
+    Syntax(serde_json::Error),
+    /// The JSON doesn't follow the DICOM JSON model.
+    InvalidDataset(String),
+}
+
+impl fmt::Display for DicomJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DicomJsonError::Syntax(error) => write!(f, "Invalid JSON: {}", error),
+            DicomJsonError::InvalidDataset(reason) => write!(f, "Invalid DICOM JSON: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for DicomJsonError {}
+
+impl From<serde_json::Error> for DicomJsonError {
+    fn from(error: serde_json::Error) -> Self {
+        DicomJsonError::Syntax(error)
+    }
+}
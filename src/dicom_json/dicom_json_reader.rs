@@ -0,0 +1,268 @@
+use std::str::FromStr;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::Value;
+use crate::dataset::data_element::DataElement;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_field::ValueField;
+use crate::dataset::value_representation::ValueRepresentation;
+use crate::dicom_constants::numeric::UNDEFINED_LENGTH;
+use crate::utils::endianness::Endianness;
+use crate::utils::submap::Submap;
+use crate::value_representations::attribute_tag::AttributeTag;
+use crate::value_representations::dicom_string::DicomString;
+use crate::value_representations::numeric_string::NumericString;
+use crate::value_representations::numeric_type::NumericType;
+use crate::value_representations::other_type::OtherType;
+use crate::value_representations::sequence_of_items::{SequenceItem, SequenceOfItems};
+use crate::value_representations::unknown::Unknown;
+use super::dicom_json_error::DicomJsonError;
+use super::dicom_json_error::DicomJsonError::InvalidDataset;
+use super::dicom_json_writer::PERSON_NAME_COMPONENT_GROUPS;
+
+/// Reads datasets from the DICOM JSON model (PS3.18 F.2). Bulk data referenced by `BulkDataURI`
+/// is not retrieved, such attributes are reported as invalid.
+pub struct DicomJsonReader {}
+
+impl Default for DicomJsonReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DicomJsonReader {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Reads a single dataset - a JSON object.
+    pub fn read(&self, json: &str) -> Result<Vec<DataElement>, DicomJsonError> {
+        self.dataset(&serde_json::from_str(json)?)
+    }
+
+    /// Reads an array of datasets, as returned by DICOMweb services.
+    pub fn read_all(&self, json: &str) -> Result<Vec<Vec<DataElement>>, DicomJsonError> {
+        match serde_json::from_str(json)? {
+            Value::Array(datasets) => datasets.iter().map(|dataset| self.dataset(dataset)).collect(),
+            _ => Err(InvalidDataset("Expected an array of datasets".to_string())),
+        }
+    }
+
+    pub fn dataset(&self, json: &Value) -> Result<Vec<DataElement>, DicomJsonError> {
+        let Value::Object(attributes) = json else {
+            return Err(InvalidDataset("Expected a dataset object".to_string()));
+        };
+
+        attributes.iter()
+            .map(|(tag, attribute)| {
+                let tag = parse_tag(tag).ok_or_else(|| InvalidDataset(format!("Invalid tag {}", tag)))?;
+                self.data_element(tag, attribute)
+                    .map_err(|error| match error {
+                        InvalidDataset(reason) => InvalidDataset(format!("{}: {}", tag, reason)),
+                        error => error,
+                    })
+            })
+            .collect()
+    }
+
+    fn data_element(&self, tag: Tag, attribute: &Value) -> Result<DataElement, DicomJsonError> {
+        let value_representation = match attribute.get("vr") {
+            Some(Value::String(vr)) if vr.len() == 2 && vr.is_ascii() =>
+                ValueRepresentation { value: [vr.as_bytes()[0], vr.as_bytes()[1]] },
+            _ => return Err(InvalidDataset("Missing or invalid vr".to_string())),
+        };
+
+        if attribute.get("BulkDataURI").is_some() {
+            return Err(InvalidDataset("BulkDataURI is not supported".to_string()));
+        }
+
+        let values = match attribute.get("Value") {
+            Some(Value::Array(values)) => values.as_slice(),
+            Some(_) => return Err(InvalidDataset("Value is not an array".to_string())),
+            None => &[],
+        };
+
+        let inline_binary = match attribute.get("InlineBinary") {
+            Some(Value::String(encoded)) => STANDARD.decode(encoded)
+                .map_err(|error| InvalidDataset(format!("Invalid base64 in InlineBinary: {}", error)))?,
+            Some(_) => return Err(InvalidDataset("InlineBinary is not a string".to_string())),
+            None => Vec::new(),
+        };
+
+        let value = self.value(value_representation, values, &inline_binary)?;
+
+        let value_length = match &value {
+            ValueField::SequenceOfItems(_) => UNDEFINED_LENGTH,
+            _ if !inline_binary.is_empty() => inline_binary.len() as u32,
+            _ => 0,
+        };
+
+        Ok(DataElement { tag, value_representation: Some(value_representation), value_length, value, offset: None })
+    }
+
+    fn value(&self, value_representation: ValueRepresentation, values: &[Value], inline_binary: &[u8]) -> Result<ValueField, DicomJsonError> {
+        let string = || strings(values).map(DicomString::from);
+        let numeric_string = || strings(values).map(NumericString::from);
+        let bytes = || Submap::from_bytes(inline_binary, Endianness::Little)
+            .map_err(|error| InvalidDataset(error.to_string()));
+
+        let value = match &value_representation.value {
+            b"AE" => ValueField::ApplicationEntity(string()?),
+            b"AS" => ValueField::AgeString(string()?),
+            b"AT" => ValueField::AttributeTag(AttributeTag::new(values.iter()
+                .map(|value| match value {
+                    Value::String(tag) => parse_tag(tag).map(|tag| [tag.group, tag.element]),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| InvalidDataset("Invalid AT value".to_string()))?)),
+            b"CS" => ValueField::CodeString(string()?),
+            b"DA" => ValueField::Date(string()?),
+            b"DS" => ValueField::DecimalString(numeric_string()?),
+            b"DT" => ValueField::DateTime(string()?),
+            b"FL" => ValueField::FloatingPointSingle(numbers(values)?),
+            b"FD" => ValueField::FloatingPointDouble(numbers(values)?),
+            b"IS" => ValueField::IntegerString(numeric_string()?),
+            b"LO" => ValueField::LongString(string()?),
+            b"LT" => ValueField::LongText(string()?),
+            b"OB" => ValueField::OtherByte(OtherType::from(bytes()?)),
+            b"OD" => ValueField::OtherDouble(OtherType::from(bytes()?)),
+            b"OF" => ValueField::OtherFloat(OtherType::from(bytes()?)),
+            b"OL" => ValueField::OtherLong(OtherType::from(bytes()?)),
+            b"OV" => ValueField::Other64bitVeryLong(OtherType::from(bytes()?)),
+            b"OW" => ValueField::OtherWord(OtherType::from(bytes()?)),
+            b"PN" => ValueField::PersonName(DicomString::from(person_names(values)?)),
+            b"SH" => ValueField::ShortString(string()?),
+            b"SL" => ValueField::SignedLong(numbers(values)?),
+            b"SQ" => ValueField::SequenceOfItems(SequenceOfItems::new(values.iter()
+                .map(|item| self.dataset(item).map(|data_elements| SequenceItem { data_elements }))
+                .collect::<Result<_, _>>()?)),
+            b"SS" => ValueField::SignedShort(numbers(values)?),
+            b"ST" => ValueField::ShortText(string()?),
+            b"SV" => ValueField::Signed64bitVeryLong(numbers(values)?),
+            b"TM" => ValueField::Time(string()?),
+            b"UC" => ValueField::UnlimitedCharacters(string()?),
+            b"UI" => ValueField::UniqueIdentifier(string()?),
+            b"UL" => ValueField::UnsignedLong(numbers(values)?),
+            b"UN" => ValueField::Unknown(Unknown::from(bytes()?)),
+            b"UR" => ValueField::UniversalResourceIdentifier(string()?),
+            b"US" => ValueField::UnsignedShort(numbers(values)?),
+            b"UT" => ValueField::UnlimitedText(string()?),
+            b"UV" => ValueField::Unsigned64bitVeryLong(numbers(values)?),
+            _ => return Err(InvalidDataset(format!("Unknown vr {}", value_representation))),
+        };
+
+        Ok(value)
+    }
+}
+
+/// `GGGGEEEE` in hexadecimal.
+fn parse_tag(tag: &str) -> Option<Tag> {
+    if tag.len() != 8 || !tag.is_ascii() {
+        return None;
+    }
+
+    Some(Tag {
+        group: u16::from_str_radix(&tag[..4], 16).ok()?,
+        element: u16::from_str_radix(&tag[4..], 16).ok()?,
+    })
+}
+
+/// Values joined with backslashes. DS and IS values may be numbers or strings, empty values null.
+fn strings(values: &[Value]) -> Result<String, DicomJsonError> {
+    values.iter()
+        .map(|value| match value {
+            Value::String(string) => Ok(string.as_str()),
+            Value::Number(number) => Ok(number.as_str()),
+            Value::Null => Ok(""),
+            value => Err(InvalidDataset(format!("Unexpected value {}", value))),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|values| values.join("\\"))
+}
+
+fn person_names(values: &[Value]) -> Result<String, DicomJsonError> {
+    values.iter()
+        .map(|value| match value {
+            Value::Object(_) => {
+                let groups: Vec<&str> = PERSON_NAME_COMPONENT_GROUPS.iter()
+                    .map(|group| match value.get(group) {
+                        Some(Value::String(name)) => name.as_str(),
+                        _ => "",
+                    })
+                    .collect();
+                Ok(groups.join("=").trim_end_matches('=').to_string())
+            },
+            Value::Null => Ok(String::new()),
+            value => Err(InvalidDataset(format!("Unexpected person name {}", value))),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|values| values.join("\\"))
+}
+
+/// Numbers, or strings for values which JSON numbers cannot represent, e.g. `NaN`.
+fn numbers<T: FromStr>(values: &[Value]) -> Result<NumericType<T>, DicomJsonError> {
+    values.iter()
+        .map(|value| match value {
+            Value::Number(number) => number.as_str().parse().ok(),
+            Value::String(number) => number.parse().ok(),
+            _ => None,
+        })
+        .collect::<Option<Vec<T>>>()
+        .map(NumericType::from)
+        .ok_or_else(|| InvalidDataset("Invalid numeric value".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::tags::{PATIENT_NAME, PIXEL_DATA, REFERENCED_IMAGE_SEQUENCE, ROWS, SLICE_THICKNESS, SOP_INSTANCE_UID};
+    use crate::dicom_constants::value_representation::{PERSON_NAME, UNIQUE_IDENTIFIER_UID};
+    use crate::value_representations::numeric_type::Numeric;
+    use crate::value_representations::other_type::Other;
+    use super::super::dicom_json_writer::DicomJsonWriter;
+    use super::*;
+
+    const JSON: &str = concat!(
+        r#"{"00080018":{"vr":"UI","Value":["1.2.3"]},"#,
+        r#""00081140":{"vr":"SQ","Value":[{"00100010":{"vr":"PN","Value":[{"Alphabetic":"Yamada^Tarou","Ideographic":"山田^太郎"},null]}}]},"#,
+        r#""00180050":{"vr":"DS","Value":[2.5,"+3"]},"#,
+        r#""00280010":{"vr":"US","Value":[512]},"#,
+        r#""7FE00010":{"vr":"OW","InlineBinary":"AQIDBA=="}}"#);
+
+    fn find(data_elements: &[DataElement], tag: Tag) -> &DataElement {
+        data_elements.iter().find(|data_element| data_element.tag == tag).unwrap()
+    }
+
+    #[test]
+    fn test_read_and_write() {
+        let data_elements = DicomJsonReader::new().read(JSON).unwrap();
+
+        assert_eq!(find(&data_elements, SOP_INSTANCE_UID).value_representation, Some(UNIQUE_IDENTIFIER_UID));
+        assert!(matches!(&find(&data_elements, SLICE_THICKNESS).value,
+            ValueField::DecimalString(value) if Into::<String>::into(value) == "2.5\\+3"));
+        assert!(matches!(&find(&data_elements, ROWS).value, ValueField::UnsignedShort(rows) if rows.value() == &vec![512]));
+        assert!(matches!(&find(&data_elements, PIXEL_DATA).value, ValueField::OtherWord(words) if words.value() == &vec![0x0201, 0x0403]));
+
+        let ValueField::SequenceOfItems(sequence) = &find(&data_elements, REFERENCED_IMAGE_SEQUENCE).value else {
+            panic!("Expected sequence of items")
+        };
+        let name = find(&sequence.items[0].data_elements, PATIENT_NAME);
+        assert_eq!(name.value_representation, Some(PERSON_NAME));
+        assert!(matches!(&name.value, ValueField::PersonName(name) if Into::<String>::into(name) == "Yamada^Tarou=山田^太郎\\"));
+
+        assert_eq!(DicomJsonWriter::new().to_json_string(&data_elements), JSON);
+    }
+
+    #[test]
+    fn test_invalid_dataset() {
+        let reader = DicomJsonReader::new();
+
+        assert!(matches!(reader.read(r#"{"00100010":{"vr":"PN""#), Err(DicomJsonError::Syntax(_))));
+        assert!(matches!(reader.read(r#"{"0010001":{"vr":"PN"}}"#), Err(InvalidDataset(_))));
+        assert!(matches!(reader.read(r#"{"7FE00010":{"vr":"OB","BulkDataURI":"http://host/bulk"}}"#), Err(InvalidDataset(_))));
+        assert!(matches!(reader.read(r#"{"00280010":{"vr":"US","Value":["x"]}}"#), Err(InvalidDataset(_))));
+
+        let nested = "[".repeat(100_000) + &"]".repeat(100_000);
+        assert!(matches!(reader.read(&nested), Err(DicomJsonError::Syntax(_))));
+    }
+}
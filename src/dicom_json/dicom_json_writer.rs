@@ -0,0 +1,175 @@
+use std::fmt;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::{Number, Value};
+use crate::dataset::data_element::DataElement;
+use crate::dataset::value_field::ValueField;
+use crate::dicom_file_writer::value_writer::{is_encapsulated, numeric, value_representation};
+use crate::value_representations::numeric_type::Numeric;
+use crate::value_representations::other_type::Other;
+
+/// Person name component groups, in the order of PS3.5 6.2.1.
+pub const PERSON_NAME_COMPONENT_GROUPS: [&str; 3] = ["Alphabetic", "Ideographic", "Phonetic"];
+
+/// Converts datasets to the DICOM JSON model (PS3.18 F.2). Binary values are written as base64
+/// `InlineBinary` in Little Endian byte order. Encapsulated pixel data is written as read - its
+/// fragments including their item headers.
+pub struct DicomJsonWriter {}
+
+impl Default for DicomJsonWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DicomJsonWriter {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn to_json_string(&self, data_elements: &[DataElement]) -> String {
+        self.to_json(data_elements).to_string()
+    }
+
+    /// Members are ordered by tag. Group length elements are skipped.
+    pub fn to_json(&self, data_elements: &[DataElement]) -> Value {
+        let mut data_elements: Vec<&DataElement> = data_elements.iter()
+            .filter(|data_element| data_element.tag.element != 0x0000)
+            .collect();
+        data_elements.sort_by_key(|data_element| data_element.tag);
+
+        Value::Object(data_elements.into_iter()
+            .map(|data_element| (format!("{:04X}{:04X}", data_element.tag.group, data_element.tag.element),
+                                 self.attribute(data_element)))
+            .collect())
+    }
+
+    fn attribute(&self, data_element: &DataElement) -> Value {
+        let mut members = vec![("vr".to_string(), Value::String(value_representation(data_element).to_string()))];
+
+        if let Some(inline_binary) = inline_binary(data_element) {
+            if !inline_binary.is_empty() {
+                members.push(("InlineBinary".to_string(), Value::String(STANDARD.encode(&inline_binary))));
+            }
+        } else {
+            let values = self.values(&data_element.value);
+            if !values.is_empty() {
+                members.push(("Value".to_string(), Value::Array(values)));
+            }
+        }
+
+        Value::Object(members.into_iter().collect())
+    }
+
+    fn values(&self, value: &ValueField) -> Vec<Value> {
+        match value {
+            ValueField::ApplicationEntity(string) |
+            ValueField::AgeString(string) |
+            ValueField::CodeString(string) |
+            ValueField::Date(string) |
+            ValueField::DateTime(string) |
+            ValueField::LongString(string) |
+            ValueField::ShortString(string) |
+            ValueField::Time(string) |
+            ValueField::UnlimitedCharacters(string) |
+            ValueField::UniqueIdentifier(string) => split(&Into::<String>::into(string)).into_iter().map(string_value).collect(),
+            ValueField::LongText(string) |
+            ValueField::ShortText(string) |
+            ValueField::UniversalResourceIdentifier(string) |
+            ValueField::UnlimitedText(string) => text(&Into::<String>::into(string)).into_iter().map(string_value).collect(),
+            ValueField::PersonName(string) => split(&Into::<String>::into(string)).into_iter().map(person_name).collect(),
+            ValueField::DecimalString(string) |
+            ValueField::IntegerString(string) => split(&Into::<String>::into(string)).into_iter().map(numeric_string).collect(),
+            ValueField::AttributeTag(attribute_tag) => attribute_tag.value.iter()
+                .map(|[group, element]| Value::String(format!("{:04X}{:04X}", group, element)))
+                .collect(),
+            ValueField::FloatingPointSingle(numbers) => numbers.value().iter().map(|number| float(*number)).collect(),
+            ValueField::FloatingPointDouble(numbers) => numbers.value().iter().map(|number| float(*number)).collect(),
+            ValueField::SignedLong(numbers) => integers(numbers.value()),
+            ValueField::SignedShort(numbers) => integers(numbers.value()),
+            ValueField::Signed64bitVeryLong(numbers) => integers(numbers.value()),
+            ValueField::UnsignedLong(numbers) => integers(numbers.value()),
+            ValueField::UnsignedShort(numbers) => integers(numbers.value()),
+            ValueField::Unsigned64bitVeryLong(numbers) => integers(numbers.value()),
+            ValueField::SequenceOfItems(sequence) => sequence.items.iter()
+                .map(|item| self.to_json(&item.data_elements))
+                .collect(),
+            ValueField::OtherByte(_) | ValueField::OtherDouble(_) | ValueField::OtherFloat(_) |
+            ValueField::OtherLong(_) | ValueField::Other64bitVeryLong(_) | ValueField::OtherWord(_) |
+            ValueField::Unknown(_) => Vec::new(),
+        }
+    }
+}
+
+/// Bytes of binary values, `None` for other VRs.
+pub fn inline_binary(data_element: &DataElement) -> Option<Vec<u8>> {
+    let bytes = match &data_element.value {
+        ValueField::OtherByte(other) => other.as_raw_data().to_vec(),
+        ValueField::Unknown(unknown) => unknown.as_raw_data().to_vec(),
+        ValueField::OtherWord(other) if is_encapsulated(data_element) => other.as_raw_data().to_vec(),
+        ValueField::OtherWord(other) => numeric(other.value()),
+        ValueField::OtherDouble(other) => numeric(other.value()),
+        ValueField::OtherFloat(other) => numeric(other.value()),
+        ValueField::OtherLong(other) => numeric(other.value()),
+        ValueField::Other64bitVeryLong(other) => numeric(other.value()),
+        _ => return None,
+    };
+
+    Some(bytes)
+}
+
+/// Values of a multi-valued string, without padding. An empty string has no values.
+pub fn split(string: &str) -> Vec<&str> {
+    let string = string.trim_end_matches(['\0', ' ']);
+
+    if string.is_empty() {
+        return Vec::new();
+    }
+
+    string.split('\\').map(|value| value.trim_end_matches(' ')).collect()
+}
+
+/// Texts are single-valued - backslashes are part of the value.
+pub fn text(string: &str) -> Vec<&str> {
+    let string = string.trim_end_matches(['\0', ' ']);
+    if string.is_empty() { Vec::new() } else { vec![string] }
+}
+
+fn string_value(value: &str) -> Value {
+    if value.is_empty() { Value::Null } else { Value::String(value.to_string()) }
+}
+
+/// Person names are objects with a member for each component group present.
+fn person_name(value: &str) -> Value {
+    let groups: Vec<(String, Value)> = value.split('=')
+        .zip(PERSON_NAME_COMPONENT_GROUPS)
+        .filter(|(group, _)| !group.is_empty())
+        .map(|(group, name)| (name.to_string(), Value::String(group.to_string())))
+        .collect();
+
+    if groups.is_empty() { Value::Null } else { Value::Object(groups.into_iter().collect()) }
+}
+
+/// DS and IS values are numbers, written as in the dataset, unless they don't follow the JSON
+/// number grammar - e.g. `+1` or `.5`.
+fn numeric_string(value: &str) -> Value {
+    let value = value.trim();
+
+    match value.parse::<Number>() {
+        _ if value.is_empty() => Value::Null,
+        Ok(number) => Value::Number(number),
+        Err(_) => Value::String(value.to_string()),
+    }
+}
+
+fn integers<T: Into<Number> + Copy>(numbers: &[T]) -> Vec<Value> {
+    numbers.iter().map(|number| Value::Number((*number).into())).collect()
+}
+
+/// Non finite values have no JSON number, they are written as the strings `NaN`, `inf` and `-inf`.
+fn float<T: Copy + fmt::Debug + fmt::Display + Into<f64>>(number: T) -> Value {
+    match format!("{:?}", number).parse::<Number>() {
+        Ok(number) => Value::Number(number),
+        Err(_) => Value::String(number.to_string()),
+    }
+}
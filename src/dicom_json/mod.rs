@@ -0,0 +1,3 @@
+pub mod dicom_json_writer;
+pub mod dicom_json_reader;
+pub mod dicom_json_error;
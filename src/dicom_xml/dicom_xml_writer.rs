@@ -0,0 +1,331 @@
+use std::fmt::Write;
+use std::sync::Arc;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use memmap2::Mmap;
+use crate::dataset::data_element::DataElement;
+use crate::dataset::private_creators::PrivateCreators;
+use crate::dataset::value_field::ValueField;
+use crate::data_dictionary::data_dictionary::DataDictionary;
+use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+use crate::dicom_file_parser::parse_error::ParseError;
+use crate::dicom_file_writer::value_writer::{is_encapsulated, value_representation};
+use crate::dicom_json::dicom_json_writer::{inline_binary, split, text, PERSON_NAME_COMPONENT_GROUPS};
+use crate::utils::submap::Submap;
+use crate::value_representations::numeric_type::Numeric;
+use crate::value_representations::other_type::Other;
+
+/// Person name components, in the order of PS3.5 6.2.
+const PERSON_NAME_COMPONENTS: [&str; 5] = ["FamilyName", "GivenName", "MiddleName", "NamePrefix", "NameSuffix"];
+
+const INDENTATION: &str = "  ";
+
+/// Converts datasets to the Native DICOM Model XML (PS3.19 A.1). Binary values are written as
+/// base64 `InlineBinary` in Little Endian byte order, or - when a bulk data source is given and
+/// they are read from it - as `BulkData` references to their offset and length in that file.
+pub struct DicomXmlWriter {
+    bulk_data: Option<(String, Arc<Mmap>)>,
+}
+
+impl Default for DicomXmlWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DicomXmlWriter {
+    pub fn new() -> Self {
+        Self {
+            bulk_data: None,
+        }
+    }
+
+    /// URI of the file the data elements were parsed from, e.g. `file:///data/ct.dcm`, and its
+    /// memory map (`ParsedDicomFile::source`). Binary values read from that map are referenced as
+    /// `<uri>?offset=<offset>&length=<length>`, keeping the byte order of the file. Values created
+    /// or modified after parsing are written inline.
+    pub fn bulk_data(mut self, uri: &str, source: Arc<Mmap>) -> Self {
+        self.bulk_data = Some((uri.to_string(), source));
+        self
+    }
+
+    /// Parses the file and references its binary values as bulk data. Deflated files have no
+    /// offsets to refer to, their binary values are written inline.
+    pub fn file_to_xml(&self, file_path: &str) -> Result<String, ParseError> {
+        let parsed = DicomFileParser::new().read_all_tags().file_path(file_path).parse()?;
+
+        let writer = match parsed.source {
+            Some(source) => Self::new().bulk_data(&file_uri(&std::fs::canonicalize(file_path)?.to_string_lossy()), source),
+            None => Self::new(),
+        };
+
+        Ok(writer.to_xml(&parsed.data_elements))
+    }
+
+    pub fn to_xml(&self, data_elements: &[DataElement]) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<NativeDicomModel xml:space=\"preserve\">\n");
+        self.dataset(&mut xml, data_elements, 1);
+        xml.push_str("</NativeDicomModel>\n");
+
+        xml
+    }
+
+    /// Attributes are ordered by tag. Group length elements are skipped.
+    fn dataset(&self, xml: &mut String, data_elements: &[DataElement], depth: usize) {
+        let mut sorted: Vec<&DataElement> = data_elements.iter()
+            .filter(|data_element| data_element.tag.element != 0x0000)
+            .collect();
+        sorted.sort_by_key(|data_element| data_element.tag);
+
+        let private_creators = PrivateCreators::from_data_elements(data_elements);
+
+        for data_element in sorted {
+            self.attribute(xml, data_element, &private_creators, depth);
+        }
+    }
+
+    fn attribute(&self, xml: &mut String, data_element: &DataElement, private_creators: &PrivateCreators, depth: usize) {
+        let tag = data_element.tag;
+
+        indent(xml, depth);
+        write!(xml, "<DicomAttribute tag=\"{:04X}{:04X}\" vr=\"{}\"", tag.group, tag.element, value_representation(data_element)).unwrap();
+
+        if tag.group & 1 == 0 {
+            if let Some(entry) = DataDictionary::get(&tag) {
+                write!(xml, " keyword=\"{}\"", entry.keyword).unwrap();
+            }
+        } else if let Some(private_creator) = private_creators.creator(&tag) {
+            write!(xml, " privateCreator=\"{}\"", escape(private_creator)).unwrap();
+        }
+
+        xml.push_str(">\n");
+        self.values(xml, data_element, depth + 1);
+        indent(xml, depth);
+        xml.push_str("</DicomAttribute>\n");
+    }
+
+    fn values(&self, xml: &mut String, data_element: &DataElement, depth: usize) {
+        let bulk_data = self.bulk_data.as_ref()
+            .zip(data_location(&data_element.value))
+            .filter(|((_, source), location)| Arc::ptr_eq(&location.file, source));
+
+        if let Some(((uri, _), location)) = bulk_data {
+            indent(xml, depth);
+            writeln!(xml, "<BulkData uri=\"{}\"/>",
+                     escape(&format!("{}?offset={}&length={}", uri, location.start, location.end - location.start))).unwrap();
+            return;
+        }
+
+        if let Some(inline_binary) = inline_binary(data_element) {
+            if !inline_binary.is_empty() || is_encapsulated(data_element) {
+                indent(xml, depth);
+                writeln!(xml, "<InlineBinary>{}</InlineBinary>", STANDARD.encode(&inline_binary)).unwrap();
+            }
+            return;
+        }
+
+        match &data_element.value {
+            ValueField::PersonName(string) => {
+                for (index, name) in split(&Into::<String>::into(string)).into_iter().enumerate() {
+                    person_name(xml, index + 1, name, depth);
+                }
+            },
+            ValueField::SequenceOfItems(sequence) => {
+                for (index, item) in sequence.items.iter().enumerate() {
+                    indent(xml, depth);
+                    writeln!(xml, "<Item number=\"{}\">", index + 1).unwrap();
+                    self.dataset(xml, &item.data_elements, depth + 1);
+                    indent(xml, depth);
+                    xml.push_str("</Item>\n");
+                }
+            },
+            value => {
+                for (index, value) in string_values(value).iter().enumerate() {
+                    indent(xml, depth);
+                    writeln!(xml, "<Value number=\"{}\">{}</Value>", index + 1, escape(value)).unwrap();
+                }
+            },
+        }
+    }
+}
+
+/// Values as text, without padding. Empty for sequences and binary VRs.
+pub fn string_values(value: &ValueField) -> Vec<String> {
+    let strings = |values: Vec<&str>| values.into_iter().map(str::to_string).collect();
+
+    match value {
+        ValueField::ApplicationEntity(string) |
+        ValueField::AgeString(string) |
+        ValueField::CodeString(string) |
+        ValueField::Date(string) |
+        ValueField::DateTime(string) |
+        ValueField::LongString(string) |
+        ValueField::PersonName(string) |
+        ValueField::ShortString(string) |
+        ValueField::Time(string) |
+        ValueField::UnlimitedCharacters(string) |
+        ValueField::UniqueIdentifier(string) => strings(split(&Into::<String>::into(string))),
+        ValueField::LongText(string) |
+        ValueField::ShortText(string) |
+        ValueField::UniversalResourceIdentifier(string) |
+        ValueField::UnlimitedText(string) => strings(text(&Into::<String>::into(string))),
+        ValueField::DecimalString(string) |
+        ValueField::IntegerString(string) => split(&Into::<String>::into(string)).into_iter()
+            .map(|value| value.trim().to_string())
+            .collect(),
+        ValueField::AttributeTag(attribute_tag) => attribute_tag.value.iter()
+            .map(|[group, element]| format!("{:04X}{:04X}", group, element))
+            .collect(),
+        ValueField::FloatingPointSingle(numbers) => numbers_to_strings(numbers.value()),
+        ValueField::FloatingPointDouble(numbers) => numbers_to_strings(numbers.value()),
+        ValueField::SignedLong(numbers) => numbers_to_strings(numbers.value()),
+        ValueField::SignedShort(numbers) => numbers_to_strings(numbers.value()),
+        ValueField::Signed64bitVeryLong(numbers) => numbers_to_strings(numbers.value()),
+        ValueField::UnsignedLong(numbers) => numbers_to_strings(numbers.value()),
+        ValueField::UnsignedShort(numbers) => numbers_to_strings(numbers.value()),
+        ValueField::Unsigned64bitVeryLong(numbers) => numbers_to_strings(numbers.value()),
+        _ => Vec::new(),
+    }
+}
+
+fn numbers_to_strings<T: ToString>(numbers: &[T]) -> Vec<String> {
+    numbers.iter().map(ToString::to_string).collect()
+}
+
+/// Person names have an element for each component group present, split into components.
+fn person_name(xml: &mut String, number: usize, name: &str, depth: usize) {
+    indent(xml, depth);
+    writeln!(xml, "<PersonName number=\"{}\">", number).unwrap();
+
+    for (group, group_name) in name.split('=').zip(PERSON_NAME_COMPONENT_GROUPS) {
+        if group.is_empty() {
+            continue;
+        }
+
+        indent(xml, depth + 1);
+        writeln!(xml, "<{}>", group_name).unwrap();
+
+        for (component, component_name) in group.split('^').zip(PERSON_NAME_COMPONENTS) {
+            if !component.is_empty() {
+                indent(xml, depth + 2);
+                writeln!(xml, "<{0}>{1}</{0}>", component_name, escape(component)).unwrap();
+            }
+        }
+
+        indent(xml, depth + 1);
+        writeln!(xml, "</{}>", group_name).unwrap();
+    }
+
+    indent(xml, depth);
+    xml.push_str("</PersonName>\n");
+}
+
+/// Storage of binary values, `None` for other VRs.
+fn data_location(value: &ValueField) -> Option<&Submap> {
+    match value {
+        ValueField::OtherByte(other) => Some(other.data_location()),
+        ValueField::OtherDouble(other) => Some(other.data_location()),
+        ValueField::OtherFloat(other) => Some(other.data_location()),
+        ValueField::OtherLong(other) => Some(other.data_location()),
+        ValueField::Other64bitVeryLong(other) => Some(other.data_location()),
+        ValueField::OtherWord(other) => Some(other.data_location()),
+        ValueField::Unknown(unknown) => Some(unknown.data_location()),
+        _ => None,
+    }
+}
+
+fn file_uri(path: &str) -> String {
+    let mut uri = String::from("file://");
+
+    if !path.starts_with('/') {
+        uri.push('/');
+    }
+
+    for byte in path.replace('\\', "/").bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => uri.push(byte as char),
+            byte => write!(uri, "%{:02X}", byte).unwrap(),
+        }
+    }
+
+    uri
+}
+
+/// Escapes markup characters. Control characters other than whitespace cannot appear in XML 1.0
+/// and are dropped, e.g. the escape sequences of ISO 2022 character sets.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if (c as u32) < 0x20 => {},
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn indent(xml: &mut String, depth: usize) {
+    xml.push_str(&INDENTATION.repeat(depth));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::tags::PIXEL_DATA;
+    use crate::dicom_constants::transfer_syntaxes::EXPLICIT_VR_LITTLE_ENDIAN;
+    use crate::dicom_constants::value_representation::OTHER_BYTE;
+    use crate::dicom_json::dicom_json_reader::DicomJsonReader;
+    use crate::test_support::dicom_generator::Dataset;
+    use super::*;
+
+    #[test]
+    fn test_to_xml() {
+        let data_elements = DicomJsonReader::new().read(concat!(
+            r#"{"00081140":{"vr":"SQ","Value":[{"00100010":{"vr":"PN","Value":[{"Alphabetic":"Yamada^Tarou","Ideographic":"山田^太郎"}]}}]},"#,
+            r#""00090010":{"vr":"LO","Value":["ACME <1>"]},"00091001":{"vr":"DS","Value":[2.5,3]},"#,
+            r#""7FE00010":{"vr":"OW","InlineBinary":"AQIDBA=="}}"#)).unwrap();
+
+        let xml = DicomXmlWriter::new().to_xml(&data_elements);
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<NativeDicomModel xml:space=\"preserve\">\n"));
+        assert!(xml.contains(concat!(
+            "  <DicomAttribute tag=\"00081140\" vr=\"SQ\" keyword=\"ReferencedImageSequence\">\n",
+            "    <Item number=\"1\">\n",
+            "      <DicomAttribute tag=\"00100010\" vr=\"PN\" keyword=\"PatientName\">\n",
+            "        <PersonName number=\"1\">\n",
+            "          <Alphabetic>\n",
+            "            <FamilyName>Yamada</FamilyName>\n",
+            "            <GivenName>Tarou</GivenName>\n",
+            "          </Alphabetic>\n",
+            "          <Ideographic>\n",
+            "            <FamilyName>山田</FamilyName>\n")));
+        assert!(xml.contains("<Value number=\"1\">ACME &lt;1&gt;</Value>"));
+        assert!(xml.contains(concat!(
+            "  <DicomAttribute tag=\"00091001\" vr=\"DS\" privateCreator=\"ACME &lt;1&gt;\">\n",
+            "    <Value number=\"1\">2.5</Value>\n",
+            "    <Value number=\"2\">3</Value>\n")));
+        assert!(xml.contains("<InlineBinary>AQIDBA==</InlineBinary>"));
+    }
+
+    #[test]
+    fn test_bulk_data_only_for_values_of_the_source() {
+        let dataset = Dataset::new().bytes(PIXEL_DATA, OTHER_BYTE, &[1, 2, 3, 4]);
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&dataset.to_part10(EXPLICIT_VR_LITTLE_ENDIAN)).unwrap();
+        let pixel_data = parsed.data_elements.iter().find(|data_element| data_element.tag == PIXEL_DATA).unwrap();
+        let offset = data_location(&pixel_data.value).unwrap().start;
+
+        let writer = DicomXmlWriter::new().bulk_data("file:///data/ct.dcm", parsed.source.clone().unwrap());
+        assert!(writer.to_xml(&parsed.data_elements).contains(&format!("<BulkData uri=\"file:///data/ct.dcm?offset={}&amp;length=4\"/>", offset)));
+
+        let created = DicomJsonReader::new().read(r#"{"7FE00010":{"vr":"OB","InlineBinary":"AQIDBA=="}}"#).unwrap();
+        let xml = writer.to_xml(&created);
+        assert!(xml.contains("<InlineBinary>AQIDBA==</InlineBinary>"));
+        assert!(!xml.contains("BulkData"));
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use crate::dataset::data_element::DataElement;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_field::ValueField;
+use crate::dicom_constants::tags::{DIRECTORY_RECORD_SEQUENCE, DIRECTORY_RECORD_TYPE, FILE_SET_ID, INSTANCE_NUMBER, MODALITY, OFFSET_OF_REFERENCED_LOWER_LEVEL_DIRECTORY_ENTITY, OFFSET_OF_THE_FIRST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY, OFFSET_OF_THE_NEXT_DIRECTORY_RECORD, PATIENT_ID, PATIENT_NAME, RECORD_IN_USE_FLAG, REFERENCED_FILE_ID, REFERENCED_SOP_INSTANCE_UID_IN_FILE, SERIES_INSTANCE_UID, SERIES_NUMBER, STUDY_DATE, STUDY_DESCRIPTION, STUDY_INSTANCE_UID};
+use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+use crate::traits::cast::Cast;
+use crate::value_representations::sequence_of_items::SequenceItem;
+use super::dicomdir_error::DicomdirError;
+
+pub const PATIENT_RECORD: &str = "PATIENT";
+pub const STUDY_RECORD: &str = "STUDY";
+pub const SERIES_RECORD: &str = "SERIES";
+pub const IMAGE_RECORD: &str = "IMAGE";
+
+/// Item tag and item length precede the first element of a record.
+const ITEM_HEADER_LENGTH: usize = 8;
+const INACTIVE_RECORD: u16 = 0x0000;
+
+/// Media directory (PS3.10 8, PS3.3 F) - the patients, studies, series and instances of a file set,
+/// e.g. a patient CD.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dicomdir {
+    pub file_set_id: String,
+    pub patients: Vec<PatientRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatientRecord {
+    pub patient_id: String,
+    pub patient_name: String,
+    pub studies: Vec<StudyRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudyRecord {
+    pub study_instance_uid: String,
+    pub study_date: String,
+    pub study_description: String,
+    pub series: Vec<SeriesRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesRecord {
+    pub series_instance_uid: String,
+    pub modality: String,
+    pub series_number: Option<u32>,
+    pub instances: Vec<InstanceRecord>,
+}
+
+/// Record referencing a file, e.g. an `IMAGE` or an `SR DOCUMENT`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstanceRecord {
+    pub record_type: String,
+    /// Path of the referenced file, resolved against the directory of the DICOMDIR.
+    pub file_path: String,
+    pub sop_instance_uid: String,
+    pub instance_number: Option<u32>,
+}
+
+impl Dicomdir {
+    pub fn read(file_path: &str) -> Result<Self, DicomdirError> {
+        let parsed = DicomFileParser::new().read_all_tags().file_path(file_path).parse()?;
+        let directory = Path::new(file_path).parent().unwrap_or(Path::new(""));
+
+        Self::from_data_elements(&parsed.data_elements, directory)
+    }
+
+    /// Builds the hierarchy by following the record offsets, so the data elements have to be parsed
+    /// from a file. Inactive records and records of other types than expected at a level (e.g.
+    /// `HANGING PROTOCOL` next to patients) are skipped.
+    pub fn from_data_elements(data_elements: &[DataElement], directory: &Path) -> Result<Self, DicomdirError> {
+        let records = match find(data_elements, DIRECTORY_RECORD_SEQUENCE) {
+            Some(ValueField::SequenceOfItems(sequence)) => sequence.items.iter()
+                .filter_map(|item| record_offset(item).map(|offset| (offset, item)))
+                .collect(),
+            _ => return Err(DicomdirError::MissingAttribute(DIRECTORY_RECORD_SEQUENCE)),
+        };
+
+        let reader = RecordReader { records, directory };
+        let first_patient = offset(data_elements, OFFSET_OF_THE_FIRST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY)?;
+
+        Ok(Self {
+            file_set_id: string(data_elements, FILE_SET_ID),
+            patients: reader.entity(first_patient, Some(PATIENT_RECORD))?.into_iter()
+                .map(|record| reader.patient(record))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Instances of all series, e.g. to read all files of the file set.
+    pub fn instances(&self) -> impl Iterator<Item = (&StudyRecord, &SeriesRecord, &InstanceRecord)> {
+        self.patients.iter()
+            .flat_map(|patient| patient.studies.iter())
+            .flat_map(|study| study.series.iter().map(move |series| (study, series)))
+            .flat_map(|(study, series)| series.instances.iter().map(move |instance| (study, series, instance)))
+    }
+}
+
+struct RecordReader<'a> {
+    records: HashMap<u32, &'a SequenceItem>,
+    directory: &'a Path,
+}
+
+impl<'a> RecordReader<'a> {
+    /// Active records of a directory entity, following the offsets of the next records. Without
+    /// a record type, records referencing a file are returned.
+    fn entity(&self, first_offset: u32, record_type: Option<&str>) -> Result<Vec<&'a [DataElement]>, DicomdirError> {
+        let mut records = Vec::new();
+        let mut visited = HashSet::new();
+        let mut next_offset = first_offset;
+
+        while next_offset != 0 {
+            if !visited.insert(next_offset) {
+                return Err(DicomdirError::InvalidOffset(next_offset));
+            }
+
+            let record = self.records.get(&next_offset)
+                .ok_or(DicomdirError::InvalidOffset(next_offset))?
+                .data_elements.as_slice();
+
+            let in_use = find(record, RECORD_IN_USE_FLAG)
+                .and_then(|value| Cast::<u16>::cast(value).ok())
+                .is_none_or(|flag| flag != INACTIVE_RECORD);
+
+            let is_expected_type = match record_type {
+                Some(record_type) => string(record, DIRECTORY_RECORD_TYPE) == record_type,
+                None => find(record, REFERENCED_FILE_ID).is_some(),
+            };
+
+            if in_use && is_expected_type {
+                records.push(record);
+            }
+
+            next_offset = offset(record, OFFSET_OF_THE_NEXT_DIRECTORY_RECORD)?;
+        }
+
+        Ok(records)
+    }
+
+    fn lower_level(&self, record: &[DataElement], record_type: Option<&str>) -> Result<Vec<&'a [DataElement]>, DicomdirError> {
+        self.entity(offset(record, OFFSET_OF_REFERENCED_LOWER_LEVEL_DIRECTORY_ENTITY)?, record_type)
+    }
+
+    fn patient(&self, record: &[DataElement]) -> Result<PatientRecord, DicomdirError> {
+        Ok(PatientRecord {
+            patient_id: string(record, PATIENT_ID),
+            patient_name: string(record, PATIENT_NAME),
+            studies: self.lower_level(record, Some(STUDY_RECORD))?.into_iter()
+                .map(|study| self.study(study))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn study(&self, record: &[DataElement]) -> Result<StudyRecord, DicomdirError> {
+        Ok(StudyRecord {
+            study_instance_uid: string(record, STUDY_INSTANCE_UID),
+            study_date: string(record, STUDY_DATE),
+            study_description: string(record, STUDY_DESCRIPTION),
+            series: self.lower_level(record, Some(SERIES_RECORD))?.into_iter()
+                .map(|series| self.series(series))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Instances are all records referencing a file, whatever their type.
+    fn series(&self, record: &[DataElement]) -> Result<SeriesRecord, DicomdirError> {
+        Ok(SeriesRecord {
+            series_instance_uid: string(record, SERIES_INSTANCE_UID),
+            modality: string(record, MODALITY),
+            series_number: number(record, SERIES_NUMBER),
+            instances: self.lower_level(record, None)?.into_iter()
+                .map(|instance| self.instance(instance))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn instance(&self, record: &[DataElement]) -> Result<InstanceRecord, DicomdirError> {
+        Ok(InstanceRecord {
+            record_type: string(record, DIRECTORY_RECORD_TYPE),
+            file_path: resolve_file_id(self.directory, &string(record, REFERENCED_FILE_ID))?,
+            sop_instance_uid: string(record, REFERENCED_SOP_INSTANCE_UID_IN_FILE),
+            instance_number: number(record, INSTANCE_NUMBER),
+        })
+    }
+}
+
+/// Offset of the item holding the record, as used by the record offsets.
+pub fn record_offset(item: &SequenceItem) -> Option<u32> {
+    let first_element_offset = item.data_elements.iter().filter_map(|data_element| data_element.offset).min()?;
+    u32::try_from(first_element_offset.checked_sub(ITEM_HEADER_LENGTH)?).ok()
+}
+
+/// File IDs are paths relative to the DICOMDIR, with components separated by backslashes. File
+/// systems of CDs often present the upper case IDs in lower case, the existing one is used.
+fn resolve_file_id(directory: &Path, file_id: &str) -> Result<String, DicomdirError> {
+    let path = join_file_id(directory, file_id)?;
+
+    if !path.exists() {
+        let lower_case = join_file_id(directory, &file_id.to_lowercase())?;
+        if lower_case.exists() {
+            return Ok(lower_case.to_string_lossy().to_string());
+        }
+    }
+
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Each component has to be a plain file or directory name, so that a file ID cannot point
+/// outside the directory, e.g. with `..` or an absolute path.
+fn join_file_id(directory: &Path, file_id: &str) -> Result<PathBuf, DicomdirError> {
+    file_id.split('\\').try_fold(directory.to_path_buf(), |path, component| {
+        match Path::new(component).components().collect::<Vec<_>>().as_slice() {
+            [Component::Normal(name)] => Ok(path.join(name)),
+            _ => Err(DicomdirError::InvalidFileId(file_id.to_string())),
+        }
+    })
+}
+
+fn find(data_elements: &[DataElement], tag: Tag) -> Option<&ValueField> {
+    data_elements.iter()
+        .find(|data_element| data_element.tag == tag)
+        .map(|data_element| &data_element.value)
+}
+
+fn offset(data_elements: &[DataElement], tag: Tag) -> Result<u32, DicomdirError> {
+    find(data_elements, tag)
+        .and_then(|value| Cast::<u32>::cast(value).ok())
+        .ok_or(DicomdirError::MissingAttribute(tag))
+}
+
+/// IS values may be padded with spaces.
+fn number(data_elements: &[DataElement], tag: Tag) -> Option<u32> {
+    string(data_elements, tag).trim().parse().ok()
+}
+
+/// String value without padding, empty when missing.
+pub(crate) fn string(data_elements: &[DataElement], tag: Tag) -> String {
+    let value: String = match find(data_elements, tag) {
+        Some(ValueField::CodeString(string) | ValueField::Date(string) | ValueField::LongString(string) |
+             ValueField::PersonName(string) | ValueField::ShortString(string) | ValueField::Time(string) | ValueField::UniqueIdentifier(string)) => string.into(),
+        Some(ValueField::IntegerString(string)) => string.into(),
+        _ => return String::new(),
+    };
+
+    value.trim_matches(|c: char| c == '\0' || c == ' ').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_file_writer::dicom_file_writer::DicomFileWriter;
+    use crate::dicom_json::dicom_json_reader::DicomJsonReader;
+    use super::*;
+
+    /// Patient, study, series and two image records - the second one inactive.
+    fn records(offsets: &[u32; 5]) -> Vec<DataElement> {
+        let [patient, study, series, image, inactive_image] = offsets;
+        let record = |next: u32, lower: u32, record_type: &str, attributes: &str| format!(
+            r#"{{"00041400":{{"vr":"UL","Value":[{}]}},"00041420":{{"vr":"UL","Value":[{}]}},"00041430":{{"vr":"CS","Value":["{}"]}},{}}}"#,
+            next, lower, record_type, attributes);
+
+        let items = [
+            record(0, *study, "PATIENT", r#""00100010":{"vr":"PN","Value":[{"Alphabetic":"Doe^Jane"}]},"00100020":{"vr":"LO","Value":["123"]}"#),
+            record(0, *series, "STUDY", r#""00080020":{"vr":"DA","Value":["20240102"]},"0020000D":{"vr":"UI","Value":["1.2.3"]}"#),
+            record(0, *image, "SERIES", r#""00080060":{"vr":"CS","Value":["CT"]},"0020000E":{"vr":"UI","Value":["1.2.3.4"]},"00200011":{"vr":"IS","Value":[2]}"#),
+            record(*inactive_image, 0, "IMAGE", r#""00041500":{"vr":"CS","Value":["IMAGES","IM1"]},"00041511":{"vr":"UI","Value":["1.2.3.4.1"]},"00200013":{"vr":"IS","Value":[1]}"#),
+            record(0, 0, "IMAGE", r#""00041410":{"vr":"US","Value":[0]},"00041500":{"vr":"CS","Value":["IMAGES","IM2"]}"#),
+        ];
+
+        DicomJsonReader::new().read(&format!(
+            r#"{{"00041130":{{"vr":"CS","Value":["CD1"]}},"00041200":{{"vr":"UL","Value":[{}]}},"00041220":{{"vr":"SQ","Value":[{}]}}}}"#,
+            patient, items.join(","))).unwrap()
+    }
+
+    fn parse(data_elements: &[DataElement]) -> Vec<DataElement> {
+        let bytes = DicomFileWriter::new().to_bytes(data_elements).unwrap();
+        DicomFileParser::new().read_all_tags().parse_bytes(&bytes).unwrap().data_elements
+    }
+
+    #[test]
+    fn test_read_hierarchy() {
+        // Offsets have a fixed length, so the records keep their position once offsets are filled in
+        let placeholders = parse(&records(&[0; 5]));
+        let Some(ValueField::SequenceOfItems(records_sequence)) = find(&placeholders, DIRECTORY_RECORD_SEQUENCE) else {
+            panic!("Expected directory records")
+        };
+        let offsets: [u32; 5] = records_sequence.items.iter()
+            .map(|item| record_offset(item).unwrap())
+            .collect::<Vec<_>>().try_into().unwrap();
+
+        let data_elements = parse(&records(&offsets));
+        let dicomdir = Dicomdir::from_data_elements(&data_elements, Path::new("/cd")).unwrap();
+
+        assert_eq!(dicomdir.file_set_id, "CD1");
+        assert_eq!(dicomdir.patients.len(), 1);
+        assert_eq!(dicomdir.patients[0].patient_name, "Doe^Jane");
+
+        let instances: Vec<_> = dicomdir.instances().collect();
+        assert_eq!(instances.len(), 1);
+
+        let (study, series, image) = instances[0];
+        assert_eq!(study.study_date, "20240102");
+        assert_eq!((series.modality.as_str(), series.series_number), ("CT", Some(2)));
+        assert_eq!(image, &InstanceRecord {
+            record_type: IMAGE_RECORD.to_string(),
+            file_path: Path::new("/cd").join("IMAGES").join("IM1").to_string_lossy().to_string(),
+            sop_instance_uid: "1.2.3.4.1".to_string(),
+            instance_number: Some(1),
+        });
+
+        let looped = parse(&records(&[offsets[0], offsets[1], offsets[2], offsets[3], offsets[3]]));
+        assert!(matches!(Dicomdir::from_data_elements(&looped, Path::new("/cd")), Err(DicomdirError::InvalidOffset(_))));
+    }
+    #[test]
+    fn test_file_ids_stay_in_directory() {
+        let directory = Path::new("/cd");
+        assert_eq!(resolve_file_id(directory, "IMAGES\\IM1").unwrap(), directory.join("IMAGES").join("IM1").to_string_lossy());
+
+        for file_id in ["..\\SECRET", "IMAGES\\..\\..\\SECRET", "/etc\\passwd", "IMAGES/../../SECRET", "IMAGES\\\\IM1", ".\\IM1", ""] {
+            assert!(matches!(resolve_file_id(directory, file_id), Err(DicomdirError::InvalidFileId(_))), "{}", file_id);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+use crate::dataset::tag::Tag;
+use crate::dicom_file_parser::parse_error::ParseError;
+
+#[derive(Debug)]
+pub enum DicomdirError {
+    Parse(ParseError),
+    Io(std::io::Error),
+    MissingAttribute(Tag),
+    /// A record offset doesn't point at a directory record, or records refer to each other in a loop.
+    InvalidOffset(u32),
+    /// File IDs are numbered with five digits, so a level has at most 99999 records of the given type.
+    TooManyRecords(&'static str),
+    /// A Referenced File ID which would point outside the directory of the DICOMDIR.
+    InvalidFileId(String),
+    /// A File-set ID longer than 16 characters or with other characters than A-Z, 0-9, space and underscore.
+    InvalidFileSetId(String),
+}
+
+impl fmt::Display for DicomdirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DicomdirError::Parse(error) => write!(f, "Cannot parse DICOMDIR: {}", error),
+            DicomdirError::Io(error) => write!(f, "I/O error: {}", error),
+            DicomdirError::MissingAttribute(tag) => write!(f, "Missing attribute {}", tag),
+            DicomdirError::InvalidOffset(offset) => write!(f, "Invalid directory record offset {}", offset),
+            DicomdirError::TooManyRecords(record_type) => write!(f, "Too many {} records at one level", record_type),
+            DicomdirError::InvalidFileId(file_id) => write!(f, "Invalid referenced file ID {}", file_id),
+            DicomdirError::InvalidFileSetId(file_set_id) => write!(f, "Invalid File-set ID {}", file_set_id),
+        }
+    }
+}
+
+impl std::error::Error for DicomdirError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DicomdirError::Parse(error) => Some(error),
+            DicomdirError::Io(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<ParseError> for DicomdirError {
+    fn from(error: ParseError) -> Self {
+        DicomdirError::Parse(error)
+    }
+}
+
+impl From<std::io::Error> for DicomdirError {
+    fn from(error: std::io::Error) -> Self {
+        DicomdirError::Io(error)
+    }
+}
@@ -0,0 +1,523 @@
+use std::path::Path;
+use nanorand::ChaCha20;
+use crate::dataset::data_element::DataElement;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_field::ValueField;
+use crate::dicom_constants::numeric::UNDEFINED_LENGTH;
+use crate::dicom_constants::tags::{ACCESSION_NUMBER, COMPLETION_FLAG, CONTENT_CREATOR_NAME, CONTENT_DATE, CONTENT_DESCRIPTION, CONTENT_LABEL, CONTENT_TIME, DIRECTORY_RECORD_SEQUENCE, DIRECTORY_RECORD_TYPE, FILE_SET_CONSISTENCY_FLAG, FILE_SET_ID, INSTANCE_NUMBER, MEDIA_STORAGE_SOP_CLASS_UID, MEDIA_STORAGE_SOP_INSTANCE_UID, MODALITY, OFFSET_OF_REFERENCED_LOWER_LEVEL_DIRECTORY_ENTITY, OFFSET_OF_THE_FIRST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY, OFFSET_OF_THE_LAST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY, OFFSET_OF_THE_NEXT_DIRECTORY_RECORD, PATIENT_ID, PATIENT_NAME, PRESENTATION_CREATION_DATE, PRESENTATION_CREATION_TIME, RECORD_IN_USE_FLAG, REFERENCED_FILE_ID, REFERENCED_SOP_CLASS_UID_IN_FILE, REFERENCED_SOP_INSTANCE_UID_IN_FILE, REFERENCED_TRANSFER_SYNTAX_UID_IN_FILE, SERIES_INSTANCE_UID, SERIES_NUMBER, SOP_CLASS_UID, SOP_INSTANCE_UID, SPECIFIC_CHARACTER_SET, STUDY_DATE, STUDY_DESCRIPTION, STUDY_ID, STUDY_INSTANCE_UID, STUDY_TIME, VERIFICATION_FLAG};
+use crate::dicom_constants::value_representation::{CODE_STRING, DATE, INTEGER_STRING, LONG_STRING, PERSON_NAME, SEQUENCE_OF_ITEMS, SHORT_STRING, TIME, UNIQUE_IDENTIFIER_UID, UNSIGNED_LONG, UNSIGNED_SHORT};
+use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+use crate::dicom_file_parser::parsed_dicom_file::ParsedDicomFile;
+use crate::dicom_file_writer::dicom_file_writer::DicomFileWriter;
+use crate::examinations::examinations::Examinations;
+use crate::utils::uid::generate_uid;
+use crate::value_representations::dicom_string::DicomString;
+use crate::value_representations::numeric_string::NumericString;
+use crate::value_representations::numeric_type::NumericType;
+use crate::value_representations::sequence_of_items::{SequenceItem, SequenceOfItems};
+use super::dicomdir::{record_offset, string, Dicomdir, IMAGE_RECORD, PATIENT_RECORD, SERIES_RECORD, STUDY_RECORD};
+use super::dicomdir_error::DicomdirError;
+
+const MEDIA_STORAGE_DIRECTORY_STORAGE: &str = "1.2.840.10008.1.3.10";
+const DICOMDIR_FILE_NAME: &str = "DICOMDIR";
+/// First component of the file IDs, the files are stored in a directory next to the DICOMDIR.
+const FILE_SET_ROOT: &str = "DICOM";
+const MAX_RECORDS_PER_LEVEL: usize = 99999;
+const RECORD_IN_USE: u16 = 0xFFFF;
+const FILE_SET_CONSISTENT: u16 = 0x0000;
+/// The File-set ID is a CS value restricted to the characters of file IDs (PS3.10 8.5).
+const MAX_FILE_SET_ID_LENGTH: usize = 16;
+
+/// Directory levels of the General Purpose CD-R profiles (PS3.11), from the root: the record type,
+/// the attribute grouping the files, the prefix of the file ID component and the attributes copied
+/// into the records (PS3.3 F.5). Record types and attributes of the files are given by
+/// `LEAF_RECORDS`, images being the default.
+const LEVELS: [(&str, Tag, &str, &[Tag]); 4] = [
+    (PATIENT_RECORD, PATIENT_ID, "PAT", &[SPECIFIC_CHARACTER_SET, PATIENT_NAME, PATIENT_ID]),
+    (STUDY_RECORD, STUDY_INSTANCE_UID, "STU", &[SPECIFIC_CHARACTER_SET, STUDY_DATE, STUDY_TIME, ACCESSION_NUMBER, STUDY_DESCRIPTION, STUDY_INSTANCE_UID, STUDY_ID]),
+    (SERIES_RECORD, SERIES_INSTANCE_UID, "SER", &[SPECIFIC_CHARACTER_SET, MODALITY, SERIES_INSTANCE_UID, SERIES_NUMBER]),
+    (IMAGE_RECORD, SOP_INSTANCE_UID, "IMG", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER]),
+];
+
+/// Record types of the files by their SOP Class UID (PS3.4 B.5), UIDs ending with a dot matching
+/// whole families of SOP Classes, and the attributes copied into the records (PS3.3 F.5). Only
+/// attributes with string values are copied, e.g. Concept Name Code Sequence is left out.
+const LEAF_RECORDS: [(&str, &str, &[Tag]); 17] = [
+    ("1.2.840.10008.5.1.4.1.1.88.59", "KEY OBJECT DOC", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, CONTENT_DATE, CONTENT_TIME]),
+    ("1.2.840.10008.5.1.4.1.1.88.", "SR DOCUMENT", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, COMPLETION_FLAG, VERIFICATION_FLAG, CONTENT_DATE, CONTENT_TIME]),
+    ("1.2.840.10008.5.1.4.1.1.11.", "PRESENTATION", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, CONTENT_LABEL, CONTENT_DESCRIPTION, PRESENTATION_CREATION_DATE, PRESENTATION_CREATION_TIME, CONTENT_CREATOR_NAME]),
+    ("1.2.840.10008.5.1.4.1.1.9.", "WAVEFORM", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, CONTENT_DATE, CONTENT_TIME]),
+    ("1.2.840.10008.5.1.4.1.1.481.2", "RT DOSE", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER]),
+    ("1.2.840.10008.5.1.4.1.1.481.3", "RT STRUCTURE SET", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER]),
+    ("1.2.840.10008.5.1.4.1.1.481.4", "RT TREAT RECORD", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER]),
+    ("1.2.840.10008.5.1.4.1.1.481.5", "RT PLAN", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER]),
+    ("1.2.840.10008.5.1.4.1.1.481.6", "RT TREAT RECORD", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER]),
+    ("1.2.840.10008.5.1.4.1.1.481.7", "RT TREAT RECORD", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER]),
+    ("1.2.840.10008.5.1.4.1.1.4.2", "SPECTROSCOPY", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER]),
+    ("1.2.840.10008.5.1.4.1.1.66.2", "FIDUCIAL", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, CONTENT_DATE, CONTENT_TIME]),
+    ("1.2.840.10008.5.1.4.1.1.66.1", "REGISTRATION", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, CONTENT_DATE, CONTENT_TIME]),
+    ("1.2.840.10008.5.1.4.1.1.66.3", "REGISTRATION", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, CONTENT_DATE, CONTENT_TIME]),
+    ("1.2.840.10008.5.1.4.1.1.66", "RAW DATA", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, CONTENT_DATE, CONTENT_TIME]),
+    ("1.2.840.10008.5.1.4.1.1.104.1", "ENCAP DOC", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, CONTENT_DATE, CONTENT_TIME]),
+    ("1.2.840.10008.5.1.4.1.1.104.2", "ENCAP DOC", &[SPECIFIC_CHARACTER_SET, INSTANCE_NUMBER, CONTENT_DATE, CONTENT_TIME]),
+];
+
+/// Directory record before the offsets are known - links are indices of other records.
+struct Record {
+    record_type: &'static str,
+    attributes: Vec<(Tag, String)>,
+    next: Option<usize>,
+    lower: Option<usize>,
+}
+
+/// File to be written at a file ID, as components of the path relative to the DICOMDIR.
+struct ReferencedFile<'a> {
+    file_id: Vec<String>,
+    file: &'a ParsedDicomFile,
+}
+
+/// Writes a file set (PS3.10 8) - the files renamed to PS3.10 compliant file IDs
+/// (`DICOM\PAT00001\STU00001\SER00001\IMG00001`) and the DICOMDIR with a patient, study, series
+/// and image record hierarchy. Big endian files are written in Explicit VR Little Endian.
+pub struct DicomdirWriter {
+    file_set_id: String,
+    files: Vec<ParsedDicomFile>,
+}
+
+impl Default for DicomdirWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DicomdirWriter {
+    pub fn new() -> Self {
+        Self {
+            file_set_id: String::new(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Up to 16 upper case letters, digits, spaces and underscores, checked when writing.
+    pub fn file_set_id(mut self, file_set_id: &str) -> Self {
+        self.file_set_id = file_set_id.to_string();
+        self
+    }
+
+    /// Files are grouped by Patient ID, Study and Series Instance UIDs, in the order they were added.
+    /// Files without a Patient ID are listed under one patient with a generated ID.
+    pub fn add_file(&mut self, file: ParsedDicomFile) {
+        self.files.push(file);
+    }
+
+    /// Adds the files of the examinations, loaded or only referenced, read again with all their
+    /// attributes. Files of an examination are added in its slice order.
+    pub fn add_examinations(&mut self, examinations: &Examinations) -> Result<(), DicomdirError> {
+        for examination in examinations.get_examinations() {
+            let file_paths = examination.get_dicom_files().into_iter()
+                .map(|dicom_file| dicom_file.file_path.as_str())
+                .chain(examination.get_referenced_files().iter().map(String::as_str));
+
+            for file_path in file_paths {
+                self.add_file(DicomFileParser::new().file_path(file_path).read_all_tags().parse()?);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the file set into the directory and returns the DICOMDIR as read back.
+    pub fn write(&self, directory: &str) -> Result<Dicomdir, DicomdirError> {
+        if !is_valid_file_set_id(&self.file_set_id) {
+            return Err(DicomdirError::InvalidFileSetId(self.file_set_id.clone()));
+        }
+
+        let mut records = Vec::new();
+        let mut referenced_files = Vec::new();
+        let files: Vec<&ParsedDicomFile> = self.files.iter().collect();
+        let first_patient = add_level(&mut records, &mut referenced_files, files, 0, &[FILE_SET_ROOT.to_string()])?;
+
+        let media_storage_sop_instance_uid = generate_uid(&mut ChaCha20::new());
+        let mut data_elements = self.dataset(&records, &media_storage_sop_instance_uid);
+
+        // Offsets are positions of the records in the encoded file, known once it is written
+        // with placeholders. The placeholders have the same length, so positions don't change.
+        let placeholders = DicomFileWriter::new().to_bytes(&data_elements)?;
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&placeholders)?;
+        let offsets: Vec<u32> = match parsed.data_elements.iter().find(|data_element| data_element.tag == DIRECTORY_RECORD_SEQUENCE) {
+            Some(DataElement { value: ValueField::SequenceOfItems(sequence), .. }) => sequence.items.iter()
+                .map(|item| record_offset(item).expect("Parsed records should have offsets"))
+                .collect(),
+            _ => return Err(DicomdirError::MissingAttribute(DIRECTORY_RECORD_SEQUENCE)),
+        };
+
+        let offset = |index: Option<usize>| index.map_or(0, |index| offsets[index]);
+        let last_patient = last(&records, first_patient);
+        set_offset(&mut data_elements, OFFSET_OF_THE_FIRST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY, offset(first_patient));
+        set_offset(&mut data_elements, OFFSET_OF_THE_LAST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY, offset(last_patient));
+
+        if let Some(DataElement { value: ValueField::SequenceOfItems(sequence), .. }) = data_elements.iter_mut()
+            .find(|data_element| data_element.tag == DIRECTORY_RECORD_SEQUENCE) {
+            for (item, record) in sequence.items.iter_mut().zip(&records) {
+                set_offset(&mut item.data_elements, OFFSET_OF_THE_NEXT_DIRECTORY_RECORD, offset(record.next));
+                set_offset(&mut item.data_elements, OFFSET_OF_REFERENCED_LOWER_LEVEL_DIRECTORY_ENTITY, offset(record.lower));
+            }
+        }
+
+        let root = Path::new(directory);
+        for referenced_file in &referenced_files {
+            let path = referenced_file.file_id.iter().fold(root.to_path_buf(), |path, component| path.join(component));
+            write_file(&path, &DicomFileWriter::new()
+                .transfer_syntax(referenced_file.file.transfer_syntax.writable())
+                .to_bytes(&referenced_file.file.data_elements)?)?;
+        }
+
+        let bytes = DicomFileWriter::new().to_bytes(&data_elements)?;
+        write_file(&root.join(DICOMDIR_FILE_NAME), &bytes)?;
+
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&bytes)?;
+        Dicomdir::from_data_elements(&parsed.data_elements, root)
+    }
+
+    fn dataset(&self, records: &[Record], media_storage_sop_instance_uid: &str) -> Vec<DataElement> {
+        let items = records.iter().map(|record| {
+            let mut data_elements = vec![
+                unsigned_long(OFFSET_OF_THE_NEXT_DIRECTORY_RECORD, 0),
+                unsigned_short(RECORD_IN_USE_FLAG, RECORD_IN_USE),
+                unsigned_long(OFFSET_OF_REFERENCED_LOWER_LEVEL_DIRECTORY_ENTITY, 0),
+                string_element(DIRECTORY_RECORD_TYPE, record.record_type),
+            ];
+            data_elements.extend(record.attributes.iter().map(|(tag, value)| string_element(*tag, value)));
+            data_elements.sort_by_key(|data_element| data_element.tag);
+
+            SequenceItem { data_elements }
+        }).collect();
+
+        vec![
+            string_element(MEDIA_STORAGE_SOP_CLASS_UID, MEDIA_STORAGE_DIRECTORY_STORAGE),
+            string_element(MEDIA_STORAGE_SOP_INSTANCE_UID, media_storage_sop_instance_uid),
+            string_element(FILE_SET_ID, &self.file_set_id),
+            unsigned_long(OFFSET_OF_THE_FIRST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY, 0),
+            unsigned_long(OFFSET_OF_THE_LAST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY, 0),
+            unsigned_short(FILE_SET_CONSISTENCY_FLAG, FILE_SET_CONSISTENT),
+            DataElement {
+                tag: DIRECTORY_RECORD_SEQUENCE,
+                value_representation: Some(SEQUENCE_OF_ITEMS),
+                value_length: UNDEFINED_LENGTH,
+                value: ValueField::SequenceOfItems(SequenceOfItems::new(items)),
+                offset: None,
+            },
+        ]
+    }
+}
+
+/// Adds the records of a directory entity, each followed by the records of its lower levels, so
+/// the sequence lists them depth first. Returns the index of the first record.
+fn add_level<'a>(records: &mut Vec<Record>,
+                 referenced_files: &mut Vec<ReferencedFile<'a>>,
+                 files: Vec<&'a ParsedDicomFile>,
+                 level: usize,
+                 file_id: &[String]) -> Result<Option<usize>, DicomdirError> {
+    let (record_type, key, prefix, attributes) = LEVELS[level];
+    let mut first = None;
+    let mut previous: Option<usize> = None;
+
+    for (number, group) in group(files, key)?.into_iter().enumerate() {
+        if number >= MAX_RECORDS_PER_LEVEL {
+            return Err(DicomdirError::TooManyRecords(record_type));
+        }
+
+        let mut file_id = file_id.to_vec();
+        file_id.push(format!("{}{:05}", prefix, number + 1));
+
+        let index = records.len();
+
+        if level == LEVELS.len() - 1 {
+            records.push(leaf_record(group[0], &file_id, (record_type, attributes))?);
+            referenced_files.push(ReferencedFile { file_id, file: group[0] });
+        } else {
+            let mut record = Record {
+                record_type,
+                attributes: record_attributes(&group[0].data_elements, attributes),
+                next: None,
+                lower: None,
+            };
+
+            if let Some((_, patient_id)) = record.attributes.iter_mut()
+                .find(|(tag, patient_id)| *tag == PATIENT_ID && patient_id.is_empty()) {
+                *patient_id = generate_uid(&mut ChaCha20::new());
+            }
+
+            records.push(record);
+            records[index].lower = add_level(records, referenced_files, group, level + 1, &file_id)?;
+        }
+
+        match previous {
+            Some(previous) => records[previous].next = Some(index),
+            None => first = Some(index),
+        }
+        previous = Some(index);
+    }
+
+    Ok(first)
+}
+
+/// Record of a file, its type given by the SOP Class - the default one (image) when not listed in
+/// `LEAF_RECORDS`.
+fn leaf_record(file: &ParsedDicomFile, file_id: &[String], default: (&'static str, &'static [Tag])) -> Result<Record, DicomdirError> {
+    let sop_class_uid = match string(&file.data_elements, SOP_CLASS_UID) {
+        uid if uid.is_empty() => string(&file.data_elements, MEDIA_STORAGE_SOP_CLASS_UID),
+        uid => uid,
+    };
+    if sop_class_uid.is_empty() {
+        return Err(DicomdirError::MissingAttribute(SOP_CLASS_UID));
+    }
+
+    let (record_type, attributes) = LEAF_RECORDS.iter()
+        .find(|(uid, _, _)| if uid.ends_with('.') { sop_class_uid.starts_with(uid) } else { sop_class_uid == *uid })
+        .map_or(default, |(_, record_type, attributes)| (*record_type, *attributes));
+
+    let mut attributes = record_attributes(&file.data_elements, attributes);
+    attributes.extend([
+        (REFERENCED_FILE_ID, file_id.join("\\")),
+        (REFERENCED_SOP_CLASS_UID_IN_FILE, sop_class_uid),
+        (REFERENCED_SOP_INSTANCE_UID_IN_FILE, string(&file.data_elements, SOP_INSTANCE_UID)),
+        (REFERENCED_TRANSFER_SYNTAX_UID_IN_FILE, file.transfer_syntax.writable().uid.to_string()),
+    ]);
+
+    Ok(Record { record_type, attributes, next: None, lower: None })
+}
+
+/// Attributes of a record copied from the file, Specific Character Set only when given.
+fn record_attributes(data_elements: &[DataElement], tags: &[Tag]) -> Vec<(Tag, String)> {
+    tags.iter()
+        .filter(|tag| **tag != SPECIFIC_CHARACTER_SET || !string(data_elements, **tag).is_empty())
+        .map(|tag| (*tag, string(data_elements, *tag)))
+        .collect()
+}
+
+/// Groups files with the same value of the key, keeping the order of first appearance. Patients
+/// may lack an ID, the other levels require their UIDs.
+fn group(files: Vec<&ParsedDicomFile>, key: Tag) -> Result<Vec<Vec<&ParsedDicomFile>>, DicomdirError> {
+    let mut groups: Vec<(String, Vec<&ParsedDicomFile>)> = Vec::new();
+
+    for file in files {
+        let value = string(&file.data_elements, key);
+        if value.is_empty() && key != PATIENT_ID {
+            return Err(DicomdirError::MissingAttribute(key));
+        }
+
+        match groups.iter_mut().find(|(existing, _)| *existing == value) {
+            Some((_, group)) => group.push(file),
+            None => groups.push((value, vec![file])),
+        }
+    }
+
+    Ok(groups.into_iter().map(|(_, group)| group).collect())
+}
+
+fn last(records: &[Record], first: Option<usize>) -> Option<usize> {
+    let mut last = first?;
+    while let Some(next) = records[last].next {
+        last = next;
+    }
+    Some(last)
+}
+
+fn is_valid_file_set_id(file_set_id: &str) -> bool {
+    file_set_id.len() <= MAX_FILE_SET_ID_LENGTH
+        && file_set_id.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == ' ' || c == '_')
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, bytes)
+}
+
+fn set_offset(data_elements: &mut [DataElement], tag: Tag, offset: u32) {
+    if let Some(data_element) = data_elements.iter_mut().find(|data_element| data_element.tag == tag) {
+        data_element.value = ValueField::UnsignedLong(NumericType::from(vec![offset]));
+    }
+}
+
+fn unsigned_long(tag: Tag, value: u32) -> DataElement {
+    DataElement {
+        tag,
+        value_representation: Some(UNSIGNED_LONG),
+        value_length: 4,
+        value: ValueField::UnsignedLong(NumericType::from(vec![value])),
+        offset: None,
+    }
+}
+
+fn unsigned_short(tag: Tag, value: u16) -> DataElement {
+    DataElement {
+        tag,
+        value_representation: Some(UNSIGNED_SHORT),
+        value_length: 2,
+        value: ValueField::UnsignedShort(NumericType::from(vec![value])),
+        offset: None,
+    }
+}
+
+/// Attributes of the records are strings - UIDs, codes, names, dates and numbers.
+fn string_element(tag: Tag, value: &str) -> DataElement {
+    let string = || DicomString::from(value.to_string());
+    let (value_representation, value_field) = match tag {
+        PATIENT_NAME => (PERSON_NAME, ValueField::PersonName(string())),
+        PATIENT_ID | STUDY_DESCRIPTION => (LONG_STRING, ValueField::LongString(string())),
+        ACCESSION_NUMBER | STUDY_ID => (SHORT_STRING, ValueField::ShortString(string())),
+        CONTENT_CREATOR_NAME => (PERSON_NAME, ValueField::PersonName(string())),
+        CONTENT_DESCRIPTION => (LONG_STRING, ValueField::LongString(string())),
+        STUDY_DATE | CONTENT_DATE | PRESENTATION_CREATION_DATE => (DATE, ValueField::Date(string())),
+        STUDY_TIME | CONTENT_TIME | PRESENTATION_CREATION_TIME => (TIME, ValueField::Time(string())),
+        SERIES_NUMBER | INSTANCE_NUMBER => (INTEGER_STRING, ValueField::IntegerString(NumericString::from(value.to_string()))),
+        MEDIA_STORAGE_SOP_CLASS_UID | MEDIA_STORAGE_SOP_INSTANCE_UID | STUDY_INSTANCE_UID | SERIES_INSTANCE_UID |
+        REFERENCED_SOP_CLASS_UID_IN_FILE | REFERENCED_SOP_INSTANCE_UID_IN_FILE | REFERENCED_TRANSFER_SYNTAX_UID_IN_FILE =>
+            (UNIQUE_IDENTIFIER_UID, ValueField::UniqueIdentifier(string())),
+        _ => (CODE_STRING, ValueField::CodeString(string())),
+    };
+
+    DataElement {
+        tag,
+        value_representation: Some(value_representation),
+        value_length: value.len() as u32,
+        value: value_field,
+        offset: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::transfer_syntaxes::{EXPLICIT_VR_BIG_ENDIAN, EXPLICIT_VR_LITTLE_ENDIAN};
+    use crate::dicom_file_parser::transfer_syntax::TransferSyntax;
+    use crate::dicom_json::dicom_json_reader::DicomJsonReader;
+    use crate::dicom_constants::value_representation::LONG_STRING;
+    use crate::dicom_file::dicom_file::DicomFile;
+    use crate::test_support::dicom_generator::{image_series, Dataset, SeriesGeometry};
+    use super::*;
+
+    fn file(patient_id: &str, series_instance_uid: &str, sop_instance_uid: &str) -> ParsedDicomFile {
+        let json = format!(concat!(
+            r#"{{"00080016":{{"vr":"UI","Value":["1.2.840.10008.5.1.4.1.1.2"]}},"00080018":{{"vr":"UI","Value":["{}"]}},"#,
+            r#""00080060":{{"vr":"CS","Value":["CT"]}},"00100020":{{"vr":"LO","Value":["{}"]}},"#,
+            r#""0020000D":{{"vr":"UI","Value":["1.2.3.{}"]}},"0020000E":{{"vr":"UI","Value":["{}"]}}}}"#),
+            sop_instance_uid, patient_id, patient_id, series_instance_uid);
+
+        ParsedDicomFile {
+            transfer_syntax: TransferSyntax::from_uid(EXPLICIT_VR_LITTLE_ENDIAN).unwrap(),
+            data_elements: DicomJsonReader::new().read(&json).unwrap(),
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_write_file_set() {
+        let directory = std::env::temp_dir().join(format!("dicomdir_writer_{}", std::process::id()));
+        let mut writer = DicomdirWriter::new().file_set_id("EXPORT");
+        writer.add_file(file("P1", "1.2.3.1.1", "1.2.3.1.1.1"));
+        writer.add_file(file("P2", "1.2.3.2.1", "1.2.3.2.1.1"));
+        writer.add_file(file("P1", "1.2.3.1.2", "1.2.3.1.2.1"));
+        writer.add_file(file("P1", "1.2.3.1.1", "1.2.3.1.1.2"));
+
+        let written = writer.write(directory.to_str().unwrap()).unwrap();
+        let read = Dicomdir::read(directory.join(DICOMDIR_FILE_NAME).to_str().unwrap()).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(written, read);
+        assert_eq!(read.file_set_id, "EXPORT");
+        assert_eq!(read.patients.iter().map(|patient| patient.patient_id.as_str()).collect::<Vec<_>>(), ["P1", "P2"]);
+
+        let instances: Vec<(&str, &str)> = read.instances()
+            .map(|(_, series, instance)| (series.series_instance_uid.as_str(), instance.sop_instance_uid.as_str()))
+            .collect();
+        assert_eq!(instances, [("1.2.3.1.1", "1.2.3.1.1.1"), ("1.2.3.1.1", "1.2.3.1.1.2"), ("1.2.3.1.2", "1.2.3.1.2.1"), ("1.2.3.2.1", "1.2.3.2.1.1")]);
+
+        let (_, _, last) = read.instances().last().unwrap();
+        assert!(last.file_path.ends_with(&["DICOM", "PAT00002", "STU00001", "SER00001", "IMG00001"].join(std::path::MAIN_SEPARATOR_STR)));
+    }
+
+    #[test]
+    fn test_write_big_endian_file_as_little_endian() {
+        let directory = std::env::temp_dir().join(format!("dicomdir_writer_big_endian_{}", std::process::id()));
+        let slice = &image_series(&SeriesGeometry::new(2, 2, 1))[0];
+        let mut writer = DicomdirWriter::new();
+        writer.add_file(DicomFileParser::new().read_all_tags().parse_bytes(&slice.to_part10(EXPLICIT_VR_BIG_ENDIAN)).unwrap());
+
+        let written = writer.write(directory.to_str().unwrap()).unwrap();
+        let (_, _, instance) = written.instances().next().unwrap();
+        let file = DicomFileParser::new().file_path(&instance.file_path).parse().unwrap();
+        let dicomdir = DicomFileParser::new().file_path(directory.join(DICOMDIR_FILE_NAME).to_str().unwrap()).read_all_tags().parse().unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(file.transfer_syntax.uid, EXPLICIT_VR_LITTLE_ENDIAN);
+        let Some(ValueField::SequenceOfItems(records)) = dicomdir.data_elements.iter()
+            .find(|data_element| data_element.tag == DIRECTORY_RECORD_SEQUENCE)
+            .map(|data_element| &data_element.value) else { panic!("DICOMDIR should have records") };
+        assert_eq!(string(&records.items.last().unwrap().data_elements, REFERENCED_TRANSFER_SYNTAX_UID_IN_FILE), EXPLICIT_VR_LITTLE_ENDIAN);
+    }
+
+    #[test]
+    fn test_record_types_and_generated_patient_id() {
+        let directory = std::env::temp_dir().join(format!("dicomdir_writer_record_types_{}", std::process::id()));
+        let image = image_series(&SeriesGeometry::new(2, 2, 1)).remove(0).string(PATIENT_ID, LONG_STRING, "");
+        let report = Dataset::new()
+            .string(SOP_CLASS_UID, UNIQUE_IDENTIFIER_UID, "1.2.840.10008.5.1.4.1.1.88.22")
+            .string(SOP_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, "1.2.3.4.1")
+            .string(STUDY_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, "1.2.3")
+            .string(SERIES_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, "1.2.3.4")
+            .string(MODALITY, CODE_STRING, "SR")
+            .string(COMPLETION_FLAG, CODE_STRING, "COMPLETE");
+
+        let mut writer = DicomdirWriter::new();
+        for dataset in [&image, &report] {
+            writer.add_file(DicomFileParser::new().read_all_tags().parse_bytes(&dataset.to_part10(EXPLICIT_VR_LITTLE_ENDIAN)).unwrap());
+        }
+        let written = writer.write(directory.to_str().unwrap()).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(written.patients.len(), 1);
+        assert!(!written.patients[0].patient_id.is_empty());
+        let record_types: Vec<&str> = written.instances().map(|(_, _, instance)| instance.record_type.as_str()).collect();
+        assert_eq!(record_types, [IMAGE_RECORD, "SR DOCUMENT"]);
+    }
+
+    #[test]
+    fn test_add_examinations() {
+        let directory = std::env::temp_dir().join(format!("dicomdir_writer_examinations_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let mut examinations = Examinations::new();
+        for (number, slice) in image_series(&SeriesGeometry::new(2, 2, 2)).iter().enumerate() {
+            let path = directory.join(format!("slice{}.dcm", number));
+            let bytes = slice.to_part10(EXPLICIT_VR_LITTLE_ENDIAN);
+            std::fs::write(&path, &bytes).unwrap();
+
+            let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&bytes).unwrap();
+            examinations.add_dicom_file(DicomFile::factory().create(path.to_str().unwrap(), parsed.data_elements).unwrap());
+        }
+
+        let mut writer = DicomdirWriter::new();
+        writer.add_examinations(&examinations).unwrap();
+        let written = writer.write(directory.join("export").to_str().unwrap()).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        let instances: Vec<Option<u32>> = written.instances().map(|(_, _, instance)| instance.instance_number).collect();
+        assert_eq!(instances, [Some(1), Some(2)]);
+    }
+    #[test]
+    fn test_invalid_file_set_id() {
+        let directory = std::env::temp_dir().join(format!("dicomdir_writer_file_set_id_{}", std::process::id()));
+
+        for file_set_id in ["export", "EXPORT-1", "CD\\1", "ABCDEFGHIJKLMNOPQ", "ÄRZTE"] {
+            let result = DicomdirWriter::new().file_set_id(file_set_id).write(directory.to_str().unwrap());
+            assert!(matches!(result, Err(DicomdirError::InvalidFileSetId(_))), "{}", file_set_id);
+        }
+        assert!(!directory.exists());
+
+        assert!(is_valid_file_set_id("PATIENT CD_2024"));
+        assert!(is_valid_file_set_id(""));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod dicomdir;
+pub mod dicomdir_error;
+pub mod dicomdir_writer;
@@ -1,20 +1,54 @@
 use std::collections::BTreeMap;
 use glam::Vec3;
+use rayon::prelude::*;
 use crate::dicom_file::dicom_file::DicomFile;
 use crate::pixel_data_processor::pixel_data_processor::PixelDataProcessor;
 use crate::utils::data_dimensions::Dimensions;
 
 pub struct Examination {
-    dicom_files : BTreeMap<i32, DicomFile>
+    dicom_files : BTreeMap<i32, DicomFile>,
+    referenced_files: Vec<String>,
+}
+
+impl Default for Examination {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Examination {
     pub fn new() -> Self {
         Self {
-            dicom_files: BTreeMap::new()
+            dicom_files: BTreeMap::new(),
+            referenced_files: Vec::new(),
         }
     }
 
+    /// File known to belong to the examination, e.g. from a DICOMDIR, but not read yet.
+    pub fn add_referenced_file(&mut self, file_path: String) {
+        self.referenced_files.push(file_path);
+    }
+
+    pub fn get_referenced_files(&self) -> &[String] {
+        &self.referenced_files
+    }
+
+    /// Reads the referenced files into the examination, in parallel. Files for which `read`
+    /// returns `None` are skipped, on error all files stay referenced.
+    pub fn load_referenced_files<E, F>(&mut self, read: F) -> Result<(), E>
+        where E: Send, F: Fn(&str) -> Result<Option<DicomFile>, E> + Sync {
+        let dicom_files = self.referenced_files.par_iter()
+            .map(|file_path| read(file_path))
+            .collect::<Result<Vec<_>, E>>()?;
+
+        self.referenced_files.clear();
+        for dicom_file in dicom_files.into_iter().flatten() {
+            self.add_dicom_file(dicom_file);
+        }
+
+        Ok(())
+    }
+
     pub fn add_dicom_file(&mut self, dicom_file: DicomFile) {
         let orientation = &dicom_file.image_plane.image_orientation;
         let x_dir = Vec3::from_slice(&orientation[0..3]);
@@ -61,3 +95,35 @@ impl Examination {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::transfer_syntaxes::EXPLICIT_VR_LITTLE_ENDIAN;
+    use crate::test_support::dicom_generator::{dicom_file, image_series, SeriesGeometry};
+    use super::*;
+
+    #[test]
+    fn test_files_sorted_along_slice_normal() {
+        // Coronal slices, stacked along the y axis
+        let geometry = SeriesGeometry {
+            image_orientation: [1.0, 0.0, 0.0, 0.0, 0.0, -1.0],
+            ..SeriesGeometry::new(2, 3, 4)
+        };
+        let slices = image_series(&geometry);
+
+        let mut examination = Examination::new();
+        for slice in [2, 0, 3, 1] {
+            examination.add_dicom_file(dicom_file(&slices[slice], EXPLICIT_VR_LITTLE_ENDIAN));
+        }
+
+        let positions: Vec<[f32; 3]> = examination.get_dicom_files().iter()
+            .map(|dicom_file| dicom_file.image_plane.image_position)
+            .collect();
+        let expected: Vec<[f32; 3]> = (0..4).map(|slice| geometry.image_position(slice).map(|value| value as f32)).collect();
+        assert_eq!(positions, expected);
+
+        let dimensions = examination.get_dimensions();
+        assert_eq!((dimensions.width, dimensions.height, dimensions.depth), (3, 2, 4));
+        assert_eq!(dimensions.distance_between_slices, 2.5);
+    }
+}
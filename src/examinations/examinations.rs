@@ -1,11 +1,18 @@
 use wgpu::naga::FastHashMap;
 use crate::dicom_file::dicom_file::DicomFile;
+use crate::dicomdir::dicomdir::{Dicomdir, IMAGE_RECORD};
 use crate::examination::examination::Examination;
 
 pub struct Examinations {
     examinations: FastHashMap<String, Examination>,
 }
 
+impl Default for Examinations {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Examinations {
     pub fn new () -> Self {
         Self {
@@ -17,14 +24,32 @@ impl Examinations {
         let study_instance_uid  = dicom_file.general_study.study_instance_uid.clone();
         let series_instance_uid = dicom_file.general_series.series_instance_uid.clone();
 
-        let examination_id = format!("{}-{}", study_instance_uid, series_instance_uid);
-
-        let examination = self.examinations.entry(examination_id).or_insert(Examination::new());
+        let examination = self.get_or_insert(&study_instance_uid, &series_instance_uid);
         examination.add_dicom_file(dicom_file);
     }
 
+    /// Creates the examinations of all image series listed in the DICOMDIR, with their files
+    /// referenced but not read - see `Examination::load_referenced_files`.
+    pub fn add_dicomdir(&mut self, dicomdir: &Dicomdir) {
+        for (study, series, instance) in dicomdir.instances() {
+            if instance.record_type == IMAGE_RECORD {
+                self.get_or_insert(&study.study_instance_uid, &series.series_instance_uid)
+                    .add_referenced_file(instance.file_path.clone());
+            }
+        }
+    }
+
     pub fn get_examinations(&self) -> Vec<&Examination> {
         self.examinations.values().collect()
     }
+
+    pub fn get_examinations_mut(&mut self) -> Vec<&mut Examination> {
+        self.examinations.values_mut().collect()
+    }
+
+    fn get_or_insert(&mut self, study_instance_uid: &str, series_instance_uid: &str) -> &mut Examination {
+        let examination_id = format!("{}-{}", study_instance_uid, series_instance_uid);
+        self.examinations.entry(examination_id).or_default()
+    }
 }
 
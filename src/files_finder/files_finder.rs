@@ -1,6 +1,8 @@
 use itertools::Itertools;
 use walkdir::WalkDir;
 
+const DICOMDIR: &str = "DICOMDIR";
+
 pub trait FindFiles {
     fn find_files(&self, directory: &str) -> Vec<String> {
         WalkDir::new(directory)
@@ -10,9 +12,24 @@ pub trait FindFiles {
             .unique()
             .collect()
     }
+
+    /// DICOMDIR in the root of the directory, e.g. of a patient CD. Its records list the files
+    /// without walking the whole directory.
+    fn find_dicomdir(&self, directory: &str) -> Option<String> {
+        std::fs::read_dir(directory).ok()?
+            .filter_map(Result::ok)
+            .find(|entry| entry.file_name().eq_ignore_ascii_case(DICOMDIR) && entry.path().is_file())
+            .map(|entry| entry.path().to_string_lossy().to_string())
+    }
 }
 pub struct FilesFinder {}
 
+impl Default for FilesFinder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl FilesFinder {
     pub fn new() -> Self {
         Self{}
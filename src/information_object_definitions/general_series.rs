@@ -1,3 +1,4 @@
+use crate::dicom_constants::tags::{MODALITY, SERIES_INSTANCE_UID};
 use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
 
 #[derive(Clone)]
@@ -79,11 +80,11 @@ impl GeneralSeriesBuilder {
         let mut inconsistencies = Vec::new();
 
         if self.modality.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Modality"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(MODALITY));
         }
 
         if self.series_instance_uid.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Series Instance UID"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(SERIES_INSTANCE_UID));
         }
 
         if !inconsistencies.is_empty() {
@@ -1,17 +1,25 @@
+use crate::dicom_constants::tags::{STUDY_INSTANCE_UID};
 use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
+use crate::value_representations::date_time::{Date, DateTime, Time};
+use crate::value_representations::person_name::PersonName;
 
 #[derive(Clone)]
 pub struct GeneralStudy {
     pub study_instance_uid: String,
-    pub study_date: Option<String>,
-    pub study_time: Option<String>,
+    pub study_date: Option<Date>,
+    pub study_time: Option<Time>,
     pub study_description: Option<String>,
-    pub patient_name: Option<String>,
+    pub patient_name: Option<PersonName>,
     pub patient_id: Option<String>,
-    pub patient_birth_date: Option<String>,
+    pub patient_birth_date: Option<Date>,
 }
 
 impl GeneralStudy {
+    /// Study Date and Time combined, e.g. to sort studies chronologically.
+    pub fn study_date_time(&self) -> Option<DateTime> {
+        Some(DateTime::new(self.study_date?, self.study_time))
+    }
+
     pub fn builder() -> GeneralStudyBuilder {
         GeneralStudyBuilder {
             study_instance_uid: None,
@@ -27,12 +35,12 @@ impl GeneralStudy {
 
 pub struct GeneralStudyBuilder {
     study_instance_uid: Option<String>,
-    study_date: Option<String>,
-    study_time: Option<String>,
+    study_date: Option<Date>,
+    study_time: Option<Time>,
     study_description: Option<String>,
-    patient_name: Option<String>,
+    patient_name: Option<PersonName>,
     patient_id: Option<String>,
-    patient_birth_date: Option<String>,
+    patient_birth_date: Option<Date>,
 }
 
 impl GeneralStudyBuilder {
@@ -41,12 +49,12 @@ impl GeneralStudyBuilder {
         self
     }
 
-    pub fn study_date(&mut self, study_date: String) -> &mut Self {
+    pub fn study_date(&mut self, study_date: Date) -> &mut Self {
         self.study_date = Some(study_date);
         self
     }
 
-    pub fn study_time(&mut self, study_time: String) -> &mut Self {
+    pub fn study_time(&mut self, study_time: Time) -> &mut Self {
         self.study_time = Some(study_time);
         self
     }
@@ -56,7 +64,7 @@ impl GeneralStudyBuilder {
         self
     }
 
-    pub fn patient_name(&mut self, patient_name: String) -> &mut Self {
+    pub fn patient_name(&mut self, patient_name: PersonName) -> &mut Self {
         self.patient_name = Some(patient_name);
         self
     }
@@ -66,7 +74,7 @@ impl GeneralStudyBuilder {
         self
     }
 
-    pub fn patient_birth_date(&mut self, patient_birth_date: String) -> &mut Self {
+    pub fn patient_birth_date(&mut self, patient_birth_date: Date) -> &mut Self {
         self.patient_birth_date = Some(patient_birth_date);
         self
     }
@@ -76,12 +84,12 @@ impl GeneralStudyBuilder {
 
         Ok(GeneralStudy {
             study_instance_uid: self.study_instance_uid.clone().unwrap(),
-            study_date: self.study_date.clone(),
-            study_time: self.study_time.clone(),
+            study_date: self.study_date,
+            study_time: self.study_time,
             study_description: self.study_description.clone(),
             patient_name: self.patient_name.clone(),
             patient_id: self.patient_id.clone(),
-            patient_birth_date: self.patient_birth_date.clone(),
+            patient_birth_date: self.patient_birth_date,
         })
     }
 
@@ -89,7 +97,7 @@ impl GeneralStudyBuilder {
         let mut inconsistencies = Vec::new();
 
         if self.study_instance_uid.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Study Instance UID"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(STUDY_INSTANCE_UID));
         }
 
         if !inconsistencies.is_empty() {
@@ -1,4 +1,5 @@
 use crate::dataset::data_element::DataElement;
+use crate::dicom_constants::tags::{BITS_ALLOCATED, BITS_STORED, COLUMNS, HIGH_BIT, PHOTOMETRIC_INTERPRETATION, PIXEL_DATA, PIXEL_REPRESENTATION, ROWS, SAMPLES_PER_PIXEL};
 use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
 
 pub struct ImagePixel {
@@ -116,39 +117,39 @@ impl ImagePixelBuilder {
         let mut inconsistencies = Vec::new();
 
         if self.samples_per_pixel.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Samples per pixel"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(SAMPLES_PER_PIXEL));
         }
 
         if self.photometric_interpretation.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Photometric interpretation"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(PHOTOMETRIC_INTERPRETATION));
         }
 
         if self.rows.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Rows"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(ROWS));
         }
 
         if self.columns.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Columns"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(COLUMNS));
         }
 
         if self.bits_allocated.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Bits allocated"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(BITS_ALLOCATED));
         }
 
         if self.bits_stored.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Bits stored"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(BITS_STORED));
         }
 
         if self.high_bit.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("High bit"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(HIGH_BIT));
         }
 
         if self.pixel_representation.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Pixel representation"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(PIXEL_REPRESENTATION));
         }
 
         if self.pixel_data.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Pixel data"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(PIXEL_DATA));
         }
 
         if !inconsistencies.is_empty() {
@@ -1,3 +1,4 @@
+use crate::dicom_constants::tags::{IMAGE_ORIENTATION_PATIENT, IMAGE_POSITION_PATIENT, PIXEL_SPACING};
 use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
 
 const DEFAULT_PIXEL_SPACING: [f32; 2] = [1.0, 1.0];
@@ -63,15 +64,15 @@ impl ImagePlaneBuilder {
         let mut inconsistencies = Vec::new();
 
         if self.pixel_spacing.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Pixel Spacing"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(PIXEL_SPACING));
         }
 
         if self.image_orientation.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Image Orientation"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(IMAGE_ORIENTATION_PATIENT));
         }
 
         if self.image_position.is_none() {
-            inconsistencies.push(DicomFileInconsistency::MissingAttribute("Image Position"));
+            inconsistencies.push(DicomFileInconsistency::MissingAttribute(IMAGE_POSITION_PATIENT));
         }
 
         if !inconsistencies.is_empty() {
@@ -1,11 +1,14 @@
+use std::fmt;
+use crate::dataset::tag::Tag;
 use crate::traits::cast::{CastArrayError, CastError};
 
 #[derive(Clone, Debug)]
 pub enum DicomFileInconsistency {
-    MissingAttribute(&'static str),
+    MissingAttribute(Tag),
     UnexpectedValueRepresentation(String),
     CastError(String),
     CannotDecodeJpeg2000,
+    MalformedPixelData(&'static str),
     NotSupported(&'static str),
 }
 
@@ -19,4 +22,17 @@ impl<T: std::fmt::Debug, const N: usize> From<CastArrayError<T, N>> for DicomFil
     fn from(error : CastArrayError<T, N>) -> Self {
         DicomFileInconsistency::CastError(format!("{:?}", error))
     }
-}
\ No newline at end of file
+}
+
+impl fmt::Display for DicomFileInconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DicomFileInconsistency::MissingAttribute(tag) => write!(f, "Missing attribute {}", tag),
+            DicomFileInconsistency::UnexpectedValueRepresentation(message) => write!(f, "{}", message),
+            DicomFileInconsistency::CastError(message) => write!(f, "Cast error: {}", message),
+            DicomFileInconsistency::CannotDecodeJpeg2000 => write!(f, "Cannot decode JPEG 2000 pixel data"),
+            DicomFileInconsistency::MalformedPixelData(reason) => write!(f, "Malformed pixel data: {}", reason),
+            DicomFileInconsistency::NotSupported(feature) => write!(f, "Not supported: {}", feature),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+#![feature(test)]
+
+pub mod data_reader;
+pub mod dicom_file_parser;
+pub mod dicom_constants;
+pub mod dataset;
+pub mod value_representations;
+pub mod utils;
+pub mod rendering;
+pub mod examination;
+pub mod dicom_file;
+pub mod information_object_definitions;
+pub mod traits;
+pub mod examinations;
+pub mod files_finder;
+pub mod pixel_data_processor;
+pub mod data_dictionary;
+pub mod dicom_file_writer;
+pub mod transcoder;
+pub mod deidentification;
+pub mod dicom_json;
+pub mod dicom_xml;
+pub mod dicom_dump;
+pub mod dicomdir;
+#[cfg(test)]
+pub mod test_support;
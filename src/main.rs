@@ -1,42 +1,29 @@
 #![allow(dead_code)]
-#![feature(test)]
 
-use std::io::{ErrorKind, Read, Write};
+use std::io::{Read, Write};
 use std::{io, mem};
 use std::time::Instant;
 use bytemuck::cast_slice;
-use crate::dicom_constants::tags::*;
-use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+use rayon::prelude::*;
+use dicom_viewer::dicom_constants::tags::*;
+use dicom_viewer::dicom_file_parser::dicom_file_parser::DicomFileParser;
 
 use winit::{
     event::*,
 };
 use winit::keyboard::{KeyCode, PhysicalKey};
-use crate::dicom_file::dicom_file::DicomFile;
-use crate::examination::examination::Examination;
-use crate::examinations::examinations::Examinations;
-use crate::rendering::renderers::renderer::Renderer;
-use crate::files_finder::files_finder::{FilesFinder, FindFiles};
-use crate::rendering::compute_shaders::compute_normal_to_surface::ComputeNormalToSurface;
-use crate::rendering::compute_shaders::compute_shader::ComputeShader;
-use crate::rendering::compute_shaders::rescale_values::ComputeRescaleValues;
-use crate::rendering::renderers::raycast_renderer::RayCastRenderer;
-use crate::rendering::utils::{Example, run};
-
-mod data_reader;
-mod dicom_file_parser;
-mod dicom_constants;
-mod dataset;
-mod value_representations;
-mod utils;
-mod rendering;
-mod examination;
-mod dicom_file;
-mod information_object_definitions;
-mod traits;
-mod examinations;
-mod files_finder;
-mod pixel_data_processor;
+use dicom_viewer::dicom_file::dicom_file::DicomFile;
+use dicom_viewer::examination::examination::Examination;
+use dicom_viewer::examinations::examinations::Examinations;
+use dicom_viewer::dicomdir::dicomdir::Dicomdir;
+use dicom_viewer::rendering::renderers::renderer::Renderer;
+use dicom_viewer::files_finder::files_finder::{FilesFinder, FindFiles};
+use dicom_viewer::rendering::compute_shaders::compute_normal_to_surface::ComputeNormalToSurface;
+use dicom_viewer::rendering::compute_shaders::compute_shader::ComputeShader;
+use dicom_viewer::rendering::compute_shaders::rescale_values::ComputeRescaleValues;
+use dicom_viewer::rendering::renderers::raycast_renderer::RayCastRenderer;
+use dicom_viewer::rendering::utils::{Example, run};
+
 
 fn pause() {
     let mut stdin = io::stdin();
@@ -226,67 +213,93 @@ fn is_close_window_requested(event: &Event<()>) -> bool {
     is_escape_pressed(event) || is_close_requested(event)
 }
 
-fn main()  -> std::io::Result<()>
-{
-    let exam_path = "C://Dane//OneDrive_2023-09-13//70 % 1.0  B30f";
-    let files = FilesFinder::new().find_files(exam_path);
-    let mut exams = Examinations::new();
+/// Reads the attributes needed to display the file. Files which are not DICOM are skipped.
+fn read_dicom_file(file: &str) -> std::io::Result<Option<DicomFile>> {
+    let tags_to_read = [
+        MODALITY,
+        STUDY_DATE,
+        STUDY_INSTANCE_UID,
+        SERIES_INSTANCE_UID,
+        IMAGE_POSITION_PATIENT,
+        IMAGE_ORIENTATION_PATIENT,
+        SAMPLES_PER_PIXEL,
+        PHOTOMETRIC_INTERPRETATION,
+        ROWS,
+        COLUMNS,
+        PIXEL_SPACING,
+        BITS_ALLOCATED,
+        BITS_STORED,
+        HIGH_BIT,
+        PIXEL_REPRESENTATION,
+        WINDOW_CENTER,
+        WINDOW_WIDTH,
+        RESCALE_INTERCEPT,
+        RESCALE_SLOPE,
+        PIXEL_DATA].as_ref();
+
+    let dicom_data_elems = DicomFileParser::new()
+        .file_path(file)
+        .read_tags(tags_to_read)
+        .with_lazy_read_element(Some(256))
+        .parse();
+
+    let data_elems = match dicom_data_elems {
+        Ok(parsed) => parsed.data_elements,
+        Err(e) => {
+            eprintln!("Skipping {}: {}", file, e);
+            return Ok(None);
+        }
+    };
 
-    let start = Instant::now();
+    let factory = DicomFile::factory();
+    let dicom_file = factory.create(file, data_elems);
 
-    for file in files {
-        let tags_to_read = [
-            MODALITY,
-            STUDY_DATE,
-            STUDY_INSTANCE_UID,
-            SERIES_INSTANCE_UID,
-            IMAGE_POSITION,
-            IMAGE_ORIENTATION,
-            SAMPLES_PER_PIXEL,
-            PHOTOMETRIC_INTERPRETATION,
-            ROWS,
-            COLUMNS,
-            PIXEL_SPACING,
-            BITS_ALLOCATED,
-            BITS_STORED,
-            HIGH_BIT,
-            PIXEL_REPRESENTATION,
-            WINDOW_CENTER,
-            WINDOW_WIDTH,
-            RESCALE_INTERCEPT,
-            RESCALE_SLOPE,
-            PIXEL_DATA].as_ref();
-
-        let dicom_data_elems = DicomFileParser::new()
-            .file_path(file.as_str())
-            .read_tags(tags_to_read)
-            .with_lazy_read_element(Some(256))
-            .parse();
-
-        if let Err(e) = dicom_data_elems {
-            println!("Error: {}", e);
-            return Err(std::io::Error::new(ErrorKind::Other, "An error occurred"));
-        }
+    if let Err(e) = dicom_file {
+        let as_str = e.into_iter().fold(String::new(),
+                                        |acc, e|
+                                            acc + format!("{}", e).as_str() + "\n");
 
-        let data_elems = dicom_data_elems.unwrap();
+        eprintln!("{}", as_str);
+        return Err(io::Error::other(format!("Inconsistent DICOM file {}", file)));
+    }
 
-        let factory = DicomFile::factory();
-        let dicom_file = factory.create(file.as_str(), data_elems);
+    Ok(dicom_file.ok())
+}
 
-        if let Err(e) = dicom_file {
-            let as_str = e.into_iter().fold(String::new(),
-                                            |acc, e|
-                                                acc + format!("{:?}", e).as_str() + "\n");
+fn main()  -> std::io::Result<()>
+{
+    let exam_path = "C://Dane//OneDrive_2023-09-13//70 % 1.0  B30f";
+    let files_finder = FilesFinder::new();
+    let mut exams = Examinations::new();
 
-            println!("{}", as_str);
-            return Err(std::io::Error::new(ErrorKind::Other, "An error occurred"));
-        }
+    let start = Instant::now();
 
-        let dicom_file = dicom_file.ok().unwrap();
-        exams.add_dicom_file(dicom_file);
+    let dicomdir = files_finder.find_dicomdir(exam_path)
+        .and_then(|dicomdir| Dicomdir::read(&dicomdir)
+            .inspect_err(|e| eprintln!("Ignoring {}: {}", dicomdir, e))
+            .ok());
+
+    match dicomdir {
+        Some(dicomdir) => {
+            exams.add_dicomdir(&dicomdir);
+            for exam in exams.get_examinations_mut() {
+                exam.load_referenced_files(read_dicom_file)?;
+            }
+        },
+        None => {
+            let dicom_files = files_finder.find_files(exam_path).par_iter()
+                .map(|file| read_dicom_file(file))
+                .collect::<io::Result<Vec<_>>>()?;
+
+            for dicom_file in dicom_files.into_iter().flatten() {
+                exams.add_dicom_file(dicom_file);
+            }
+        },
     }
 
-    let exam = exams.get_examinations()[0];
+    let exam = exams.get_examinations().into_iter()
+        .find(|exam| !exam.get_dicom_files().is_empty())
+        .ok_or_else(|| io::Error::other(format!("No DICOM images found in {}", exam_path)))?;
 
     let duration = start.elapsed();
     println!("Time elapsed in expensive_function() is: {:?}", duration);
@@ -0,0 +1,133 @@
+use std::borrow::Cow;
+use crate::dataset::tag::Tag;
+use crate::dicom_constants::tags::{ITEM, SEQUENCE_DELIMITATION_ITEM};
+use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
+use crate::information_object_definitions::inconsistency::DicomFileInconsistency::{MalformedPixelData, NotSupported};
+
+const ITEM_HEADER_LENGTH: usize = 8;
+
+/// Fragment of encapsulated pixel data. The offset is counted from the first byte of the first
+/// fragment item, as are the offsets of the Basic Offset Table.
+struct Fragment<'a> {
+    offset: usize,
+    data: &'a [u8],
+}
+
+/// Splits encapsulated pixel data - the items following the Pixel Data header, up to and including
+/// the Sequence Delimitation Item - into frames (PS3.5 A.4). Frames made of several fragments are
+/// concatenated.
+pub fn frames(pixel_data: &[u8], number_of_frames: usize) -> Result<Vec<Cow<'_, [u8]>>, DicomFileInconsistency> {
+    let mut items = items(pixel_data)?.into_iter();
+
+    let (_, offset_table) = items.next().ok_or(MalformedPixelData("Missing Basic Offset Table"))?;
+    let origin = ITEM_HEADER_LENGTH + offset_table.len();
+    let fragments: Vec<Fragment> = items
+        .map(|(position, data)| Fragment { offset: position - origin, data })
+        .collect();
+
+    if !offset_table.is_empty() {
+        let offsets: Vec<usize> = offset_table.chunks_exact(4)
+            .map(|offset| u32::from_le_bytes([offset[0], offset[1], offset[2], offset[3]]) as usize)
+            .collect();
+
+        return Ok(offsets.iter().enumerate().map(|(index, start)| {
+            let end = offsets.get(index + 1).copied().unwrap_or(usize::MAX);
+            concatenate(fragments.iter().filter(|fragment| (*start..end).contains(&fragment.offset)))
+        }).collect());
+    }
+
+    if number_of_frames == 1 {
+        return Ok(vec![concatenate(fragments.iter())]);
+    }
+
+    if fragments.len() == number_of_frames {
+        return Ok(fragments.iter().map(|fragment| Cow::Borrowed(fragment.data)).collect());
+    }
+
+    Err(NotSupported("Frames split into several fragments without Basic Offset Table"))
+}
+
+/// Positions and contents of the items.
+fn items(pixel_data: &[u8]) -> Result<Vec<(usize, &[u8])>, DicomFileInconsistency> {
+    let mut items = Vec::new();
+    let mut position = 0;
+
+    while position < pixel_data.len() {
+        let header = pixel_data.get(position..position + ITEM_HEADER_LENGTH)
+            .ok_or(MalformedPixelData("Truncated item header"))?;
+
+        let tag = Tag {
+            group: u16::from_le_bytes([header[0], header[1]]),
+            element: u16::from_le_bytes([header[2], header[3]]),
+        };
+        let length = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+
+        if tag == SEQUENCE_DELIMITATION_ITEM {
+            break;
+        }
+
+        if tag != ITEM {
+            return Err(MalformedPixelData("Expected item tag"));
+        }
+
+        let data_start = position + ITEM_HEADER_LENGTH;
+        let data = pixel_data.get(data_start..data_start + length)
+            .ok_or(MalformedPixelData("Truncated item"))?;
+
+        items.push((position, data));
+        position = data_start + length;
+    }
+
+    Ok(items)
+}
+
+fn concatenate<'a, 'b>(mut fragments: impl Iterator<Item = &'b Fragment<'a>>) -> Cow<'a, [u8]> where 'a: 'b {
+    let Some(first) = fragments.next() else {
+        return Cow::Borrowed(&[]);
+    };
+
+    let mut rest = fragments.peekable();
+    if rest.peek().is_none() {
+        return Cow::Borrowed(first.data);
+    }
+
+    let mut frame = first.data.to_vec();
+    rest.for_each(|fragment| frame.extend_from_slice(fragment.data));
+    Cow::Owned(frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encapsulate(offset_table: &[u32], fragments: &[&[u8]]) -> Vec<u8> {
+        let offset_table: Vec<u8> = offset_table.iter().flat_map(|offset| offset.to_le_bytes()).collect();
+
+        let mut pixel_data = Vec::new();
+        for (tag, content) in std::iter::once((ITEM, offset_table.as_slice()))
+            .chain(fragments.iter().map(|fragment| (ITEM, *fragment)))
+            .chain(std::iter::once((SEQUENCE_DELIMITATION_ITEM, &[][..]))) {
+            pixel_data.extend_from_slice(&tag.group.to_le_bytes());
+            pixel_data.extend_from_slice(&tag.element.to_le_bytes());
+            pixel_data.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            pixel_data.extend_from_slice(content);
+        }
+
+        pixel_data
+    }
+
+    #[test]
+    fn test_frames() {
+        let fragments: [&[u8]; 3] = [&[1, 2], &[3, 4], &[5, 6, 7, 8]];
+
+        let with_offset_table = encapsulate(&[0, 20], &fragments);
+        assert_eq!(frames(&with_offset_table, 2).unwrap(), [&[1, 2, 3, 4][..], &[5, 6, 7, 8][..]]);
+
+        let single_frame = encapsulate(&[], &fragments);
+        assert_eq!(frames(&single_frame, 1).unwrap(), [&[1, 2, 3, 4, 5, 6, 7, 8][..]]);
+        assert_eq!(frames(&single_frame, 3).unwrap(), fragments);
+        assert!(frames(&single_frame, 2).is_err());
+
+        assert!(frames(&single_frame[..single_frame.len() - 12], 1).is_err());
+    }
+}
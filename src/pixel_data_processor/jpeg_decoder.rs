@@ -1,35 +1,118 @@
 use jpeg2k::*;
+use crate::dicom_constants::transfer_syntaxes::{JPEG2000, JPEG2000_LOSSLESS};
 use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
-use crate::information_object_definitions::inconsistency::DicomFileInconsistency::CannotDecodeJpeg2000;
+use crate::information_object_definitions::inconsistency::DicomFileInconsistency::{CannotDecodeJpeg2000, MalformedPixelData};
+
+/// Transfer syntaxes of encapsulated pixel data the decoder can read.
+pub const DECODABLE_TRANSFER_SYNTAXES: [&str; 2] = [JPEG2000_LOSSLESS, JPEG2000];
+
+/// Markers of the JPEG 2000 codestream main header (ITU-T T.800 A.2).
+const START_OF_CODESTREAM: [u8; 2] = [0xFF, 0x4F];
+const CODING_STYLE_DEFAULT: [u8; 2] = [0xFF, 0x52];
+const START_OF_TILE_PART: [u8; 2] = [0xFF, 0x90];
+
+/// Offset of the wavelet transformation in the COD marker segment: marker, Lcod, Scod, SGcod
+/// and the first four parameters of SPcod.
+const WAVELET_TRANSFORMATION_OFFSET: usize = 13;
+const REVERSIBLE_WAVELET_TRANSFORMATION: u8 = 1;
 
 pub struct JpegFileDecoder {}
 
+impl Default for JpegFileDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl JpegFileDecoder {
     pub fn new() -> Self {
         Self {}
     }
 
+    /// Decodes a frame into `output`, with samples of multi-component images interleaved pixel by
+    /// pixel (Planar Configuration 0). Colour transformations of the codestream are reverted by the
+    /// decoder, so YBR_ICT and YBR_RCT images come out as RGB.
     pub fn decode(&self, encoded: &[u8], output: &mut [u8], bytes_per_pixel: usize) -> Result<(), DicomFileInconsistency> {
-        let image = Image::from_bytes(&encoded).map_err(|_| CannotDecodeJpeg2000)?;
+        let image = Image::from_bytes(encoded).map_err(|_| CannotDecodeJpeg2000)?;
 
         let components = image.components();
-        let pixels = components[0].data();
+        let num_of_pixels = components.first().map_or(0, |component| component.data().len());
 
-        Self::save_to_output(output, pixels, bytes_per_pixel);
+        if components.iter().any(|component| component.data().len() != num_of_pixels) {
+            return Err(DicomFileInconsistency::NotSupported("Subsampled JPEG 2000 components"));
+        }
+
+        if num_of_pixels * components.len() * bytes_per_pixel != output.len() {
+            return Err(MalformedPixelData("Decoded frame size does not match Image Pixel attributes"));
+        }
+
+        for (index, component) in components.iter().enumerate() {
+            Self::save_to_output(output, component.data(), bytes_per_pixel, index, components.len());
+        }
 
         Ok(())
     }
 
-    fn save_to_output(output: &mut [u8], pixels: &[i32], bytes_per_pixel: usize) {
+    /// Tells whether the codestream was encoded with the reversible 5-3 wavelet transformation,
+    /// that is without loss. Returns `None` when the main header has no COD marker segment.
+    pub fn is_reversible(&self, encoded: &[u8]) -> Option<bool> {
+        if !encoded.starts_with(&START_OF_CODESTREAM) {
+            return None;
+        }
+
+        let mut position = START_OF_CODESTREAM.len();
+
+        while let Some(segment) = encoded.get(position..position + 4) {
+            let marker = [segment[0], segment[1]];
+
+            if marker == START_OF_TILE_PART {
+                return None;
+            }
+
+            if marker == CODING_STYLE_DEFAULT {
+                return encoded.get(position + WAVELET_TRANSFORMATION_OFFSET)
+                    .map(|transformation| *transformation == REVERSIBLE_WAVELET_TRANSFORMATION);
+            }
+
+            let length = u16::from_be_bytes([segment[2], segment[3]]) as usize;
+            position += marker.len() + length;
+        }
+
+        None
+    }
+
+    fn save_to_output(output: &mut [u8], pixels: &[i32], bytes_per_pixel: usize, component: usize, num_of_components: usize) {
         pixels
             .iter()
             .enumerate()
             .for_each(|(idx, &pixel)| {
                 let pixel_bytes = &pixel.to_le_bytes()[0..bytes_per_pixel];
+                let offset = (idx * num_of_components + component) * bytes_per_pixel;
 
-                let output_bytes = &mut output[idx * bytes_per_pixel..(idx + 1) * bytes_per_pixel];
+                let output_bytes = &mut output[offset..offset + bytes_per_pixel];
                 output_bytes.copy_from_slice(pixel_bytes);
         });
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_reversible() {
+        // SOC, SIZ with a truncated body and COD with the 5-3 wavelet transformation
+        let mut codestream = vec![0xFF, 0x4F, 0xFF, 0x51, 0x00, 0x04, 0x00, 0x00];
+        codestream.extend_from_slice(&[0xFF, 0x52, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x00, 0x05, 0x04, 0x04, 0x00, 0x01]);
+
+        let decoder = JpegFileDecoder::new();
+        assert_eq!(decoder.is_reversible(&codestream), Some(true));
+
+        *codestream.last_mut().unwrap() = 0;
+        assert_eq!(decoder.is_reversible(&codestream), Some(false));
+
+        assert_eq!(decoder.is_reversible(&codestream[..8]), None);
+        assert_eq!(decoder.is_reversible(&[0xFF, 0xD8, 0xFF, 0xD9]), None);
+    }
+}
@@ -1,2 +1,3 @@
 pub mod pixel_data_processor;
-mod jpeg_decoder;
\ No newline at end of file
+pub mod jpeg_decoder;
+pub mod encapsulated_pixel_data;
\ No newline at end of file
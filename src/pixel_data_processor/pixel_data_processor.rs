@@ -5,24 +5,33 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use crate::dataset::tag::Tag;
 use crate::dataset::value_field::ValueField;
 use crate::dataset::value_field::ValueField::{OtherByte, OtherWord};
-use crate::dicom_constants::tags::SEQUENCE_DELIMITATION;
+use crate::dicom_constants::numeric::UNDEFINED_LENGTH;
+use crate::dicom_constants::tags::SEQUENCE_DELIMITATION_ITEM;
 use crate::examination::examination::Examination;
 use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
-use crate::pixel_data_processor::jpeg_decoder::JpegFileDecoder;
+use crate::pixel_data_processor::jpeg_decoder::{JpegFileDecoder, DECODABLE_TRANSFER_SYNTAXES};
 use crate::value_representations::other_type::Other;
 
 macro_rules! from_2s_complement {
     ($val:expr, $high_bit:expr) => {{
-        let is_negative = $val & (1 << $high_bit);
+        let stored_bits_mask = (1i64 << ($high_bit + 1)) - 1;
+        let val = ($val as i64) & stored_bits_mask;
+        let is_negative = val & (1i64 << $high_bit);
         if is_negative != 0 {
-            ($val | !((1 << ($high_bit + 1)) - 1)) as f32
+            (val - (1i64 << ($high_bit + 1))) as f32
         } else {
-            $val as f32
+            val as f32
         }
     }};
 }
 pub struct PixelDataProcessor {}
 
+impl Default for PixelDataProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PixelDataProcessor {
     pub fn new() -> Self {
         Self {}
@@ -38,6 +47,7 @@ impl PixelDataProcessor {
                 image_pixel.samples_per_pixel as usize;
 
         let bits_allocated = image_pixel.bits_allocated;
+        let high_bit = image_pixel.high_bit;
         let pixel_representation = image_pixel.pixel_representation;
 
         let bytes_per_pixel = (bits_allocated as f32 / 8.0).ceil() as usize;
@@ -51,17 +61,25 @@ impl PixelDataProcessor {
         let mut voxels = vec![0.0f32; num_of_voxels];
         let mut voxels_chunks = voxels.chunks_mut(num_of_pixels).collect::<Vec<_>>();
 
-        let encoded_pixels = dicom_files.iter()
-            .map(|file| self.get_pixel_data(&file.image_pixel.pixel_data.value))
-            .collect::<Vec<_>>();
+        raw_data_chunks.iter_mut().zip(dicom_files.iter()).zip(voxels_chunks.iter_mut())
+            .try_for_each(|((slice, file), voxels)| {
+                let pixel_data = &file.image_pixel.pixel_data;
 
-        raw_data_chunks.iter_mut().zip(encoded_pixels.iter()).zip(voxels_chunks.iter_mut())
-            .for_each(|((slice, pixels), voxels)| {
-                let slices = self.get_jpeg_encoded_data(pixels);
-                self.decode_jpeg(slices, slice, bytes_per_pixel).unwrap();
+                if pixel_data.value_length == UNDEFINED_LENGTH {
+                    if !DECODABLE_TRANSFER_SYNTAXES.contains(&file.transfer_syntax.uid) {
+                        return Err(DicomFileInconsistency::NotSupported("Encapsulated pixel data other than JPEG 2000"));
+                    }
 
-                self.process_raw_values(voxels, bytes_per_pixel, slice, pixel_representation, bits_allocated);
-            });
+                    let pixels = self.get_pixel_data(&pixel_data.value);
+                    let slices = self.get_jpeg_encoded_data(pixels);
+                    self.decode_jpeg(slices, slice, bytes_per_pixel)?;
+                } else {
+                    self.copy_native_pixel_data(&pixel_data.value, slice);
+                }
+
+                self.process_raw_values(voxels, bytes_per_pixel, slice, pixel_representation, high_bit);
+                Ok(())
+            })?;
 
         Ok(voxels)
     }
@@ -87,6 +105,24 @@ impl PixelDataProcessor {
         }
     }
 
+    /// Copies native (not encapsulated) pixel data as little endian bytes. `OtherType` already
+    /// swaps OW values read from a big endian dataset, so only the output byte order matters here.
+    fn copy_native_pixel_data(&self, pixel_data: &ValueField, slice: &mut [u8]) {
+        match pixel_data {
+            OtherByte(data) => {
+                let data = data.as_raw_data();
+                let length = data.len().min(slice.len());
+                slice[..length].copy_from_slice(&data[..length]);
+            },
+            OtherWord(data) => {
+                slice.chunks_exact_mut(2).zip(data.value().iter()).for_each(|(chunk, value)| {
+                    chunk.copy_from_slice(&value.to_le_bytes());
+                });
+            },
+            _ => panic!("Pixel data is not of type OW or OB")
+        }
+    }
+
     fn get_jpeg_encoded_data<'a>(&self, pixel_data: &'a [u8]) -> Vec<&'a [u8]> {
         let mut slices = Vec::new();
 
@@ -129,7 +165,7 @@ impl PixelDataProcessor {
                 slices.push(slice);
 
                 reader.seek(SeekFrom::Current(length as i64)).unwrap();
-                tag != SEQUENCE_DELIMITATION
+                tag != SEQUENCE_DELIMITATION_ITEM
             } {}
 
             reader.seek(SeekFrom::Current(4)).unwrap(); //Sequence Delimitation Item Length - equal to 0
@@ -188,24 +224,94 @@ impl PixelDataProcessor {
 
 #[cfg(test)]
 mod tests {
+    use crate::utils::endianness::Endianness;
+    use crate::dicom_constants::tags::PIXEL_DATA;
+    use crate::dicom_constants::transfer_syntaxes::{EXPLICIT_VR_BIG_ENDIAN, JPEG2000, JPEG_BASELINE_8_BIT};
+    use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+    use crate::test_support::dicom_generator::{dicom_file, image_series, pixel_value, Dataset, SeriesGeometry};
+    use crate::utils::submap::Submap;
     use super::*;
     use test::Bencher;
 
+    #[test]
+    fn test_copy_native_big_endian_pixel_data() {
+        let bytes = [0x01, 0x02, 0x80, 0x00];
+        let submap = Submap::from_bytes(&bytes, Endianness::Big).unwrap();
+
+        let processor = PixelDataProcessor::new();
+        let mut slice = [0u8; 4];
+        processor.copy_native_pixel_data(&OtherWord(submap.into()), &mut slice);
+
+        assert_eq!(slice, [0x02, 0x01, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn test_process_raw_values_uses_high_bit() {
+        let processor = PixelDataProcessor::new();
+
+        let data = [0xFF, 0x0F, 0x00, 0x08, 0xFF, 0x07, 0xFB, 0xFF];
+        let mut voxels = [0.0f32; 4];
+        processor.process_raw_values(&mut voxels, 2, &data, 1, 11);
+        assert_eq!(voxels, [-1.0, -2048.0, 2047.0, -5.0]);
+
+        processor.process_raw_values(&mut voxels, 2, &data, 1, 15);
+        assert_eq!(voxels, [4095.0, 2048.0, 2047.0, -5.0]);
+    }
+
+    #[test]
+    fn test_process_generated_examination() {
+        let geometry = SeriesGeometry::new(2, 3, 3);
+        let slices = image_series(&geometry);
+
+        let mut examination = Examination::new();
+        for slice in slices.iter().rev() {
+            examination.add_dicom_file(dicom_file(slice, EXPLICIT_VR_BIG_ENDIAN));
+        }
+
+        let voxels = PixelDataProcessor::new().process_examination(&examination).unwrap();
+        let expected: Vec<f32> = (0..3).flat_map(|slice| (0..6).map(move |pixel| pixel_value(slice, pixel) as f32)).collect();
+        assert_eq!(voxels, expected);
+    }
+
+    #[test]
+    fn test_process_examination_rejects_undecodable_transfer_syntax() {
+        let geometry = SeriesGeometry::new(2, 3, 1);
+        let slice = image_series(&geometry).remove(0).encapsulated_pixel_data(&[&[0xFF, 0xD8, 0xFF, 0xD9]]);
+
+        let mut examination = Examination::new();
+        examination.add_dicom_file(dicom_file(&slice, JPEG_BASELINE_8_BIT));
+
+        let result = PixelDataProcessor::new().process_examination(&examination);
+        assert!(matches!(result, Err(DicomFileInconsistency::NotSupported(_))));
+    }
+
+    #[test]
+    fn test_get_jpeg_encoded_data_splits_generated_fragments() {
+        let dataset = Dataset::new().encapsulated_pixel_data(&[&[1, 2, 3], &[4, 5, 6, 7]]);
+        let parsed = DicomFileParser::new().read_all_tags().parse_bytes(&dataset.to_part10(JPEG2000)).unwrap();
+        let pixel_data = parsed.data_elements.iter().find(|data_element| data_element.tag == PIXEL_DATA).unwrap();
+        assert_eq!(pixel_data.value_length, UNDEFINED_LENGTH);
+
+        let processor = PixelDataProcessor::new();
+        let frames = processor.get_jpeg_encoded_data(processor.get_pixel_data(&pixel_data.value));
+        assert_eq!(frames, [&[1u8, 2, 3, 0][..], &[4, 5, 6, 7][..]]);
+    }
+
     #[bench]
-    fn bench_get_pixel_value(bench: &mut Bencher) {
+    fn bench_process_raw_values(bench: &mut Bencher) {
         let processor = PixelDataProcessor::new();
 
         const BYTES_PER_PIXEL: usize = 2;
         const NUM_OF_PIXELS: usize = 100_000;
         const PIXEL_REPRESENTATION: u16 = 1;
+        const HIGH_BIT: u16 = 15;
 
         let data: Vec<u8> = vec![0x04; NUM_OF_PIXELS * BYTES_PER_PIXEL];
+        let mut voxels = vec![0.0f32; NUM_OF_PIXELS];
 
         bench.iter(|| {
-            let chunks = data.chunks(BYTES_PER_PIXEL);
-
-            chunks.map(|chunk| processor.get_pixel_value(chunk, BYTES_PER_PIXEL, PIXEL_REPRESENTATION))
-                  .fold(0.0, |acc, x| acc + x)
+            processor.process_raw_values(&mut voxels, BYTES_PER_PIXEL, &data, PIXEL_REPRESENTATION, HIGH_BIT);
+            voxels.iter().fold(0.0, |acc, x| acc + x)
         });
     }
-}
\ No newline at end of file
+}
@@ -9,6 +9,12 @@ pub struct Sampler {
     mipmap_filter: wgpu::FilterMode,
 }
 
+impl Default for Sampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Sampler {
     pub fn new() -> Self {
         Self {
@@ -0,0 +1,371 @@
+use std::io::Write;
+use std::sync::Arc;
+use flate2::Compression;
+use flate2::write::DeflateEncoder;
+use crate::data_reader::data_reader::DataReader;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_representation::ValueRepresentation;
+use crate::dicom_constants::numeric::{HEADER, HEADER_START, UNDEFINED_LENGTH};
+use crate::dicom_constants::tags::{BITS_ALLOCATED, BITS_STORED, COLUMNS, FILE_META_INFORMATION_GROUP_LENGTH, FILE_META_INFORMATION_VERSION, HIGH_BIT, IMAGE_ORIENTATION_PATIENT, IMAGE_POSITION_PATIENT, INSTANCE_NUMBER, ITEM, ITEM_DELIMITATION_ITEM, MEDIA_STORAGE_SOP_CLASS_UID, MEDIA_STORAGE_SOP_INSTANCE_UID, MODALITY, PATIENT_ID, PATIENT_NAME, PHOTOMETRIC_INTERPRETATION, PIXEL_DATA, PIXEL_REPRESENTATION, PIXEL_SPACING, RESCALE_INTERCEPT, RESCALE_SLOPE, ROWS, SAMPLES_PER_PIXEL, SEQUENCE_DELIMITATION_ITEM, SERIES_INSTANCE_UID, SERIES_NUMBER, SOP_CLASS_UID, SOP_INSTANCE_UID, STUDY_DATE, STUDY_INSTANCE_UID, TRANSFER_SYNTAX_UID};
+use crate::dicom_constants::value_representation::{CODE_STRING, DATE, DECIMAL_STRING, INTEGER_STRING, LONG_STRING, OTHER_BYTE, OTHER_WORD, PERSON_NAME, SEQUENCE_OF_ITEMS, UNIQUE_IDENTIFIER_UID, UNKNOWN, UNSIGNED_LONG, UNSIGNED_SHORT};
+use crate::dicom_file::dicom_file::DicomFile;
+use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+use crate::dicom_file_parser::transfer_syntax::TransferSyntax;
+use crate::dicom_file_writer::value_writer::{numeric, LittleEndianBytes};
+use crate::utils::endianness::Endianness;
+use crate::utils::memory_map::map_anonymous;
+
+const CT_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.2";
+const STUDY_UID: &str = "1.2.826.0.1.3680043.10.1";
+const SERIES_UID: &str = "1.2.826.0.1.3680043.10.1.1";
+
+/// VRs with a 4 byte value length in explicit VR transfer syntaxes (PS3.5 7.1.2).
+const LONG_VALUE_REPRESENTATIONS: [&[u8; 2]; 13] = [b"OB", b"OD", b"OF", b"OL", b"OV", b"OW", b"SQ", b"SV", b"UC", b"UN", b"UR", b"UT", b"UV"];
+
+#[derive(Clone)]
+enum Value {
+    String(String),
+    /// Little endian values of the given size, swapped for big endian transfer syntaxes.
+    Numbers { size: usize, bytes: Vec<u8> },
+    Bytes(Vec<u8>),
+    Sequence { items: Vec<Dataset>, defined_length: bool },
+    /// Sequence of undefined length with UN as VR, its items in Implicit VR Little Endian (PS3.5 6.2.2).
+    UnknownSequence(Vec<Dataset>),
+    Encapsulated(Vec<Vec<u8>>),
+}
+
+#[derive(Clone, Copy)]
+struct Encoding {
+    explicit_value_representation: bool,
+    big_endian: bool,
+}
+
+/// Dataset encoded on demand in any transfer syntax, for tests needing valid DICOM data.
+/// Elements are written in ascending tag order, whatever order they were added in.
+#[derive(Clone)]
+pub struct Dataset {
+    elements: Vec<(Tag, ValueRepresentation, Value)>,
+}
+
+impl Default for Dataset {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dataset {
+    pub fn new() -> Self {
+        Self { elements: Vec::new() }
+    }
+
+    /// String VRs, multiple values separated with backslashes. Padded to even length.
+    pub fn string(self, tag: Tag, value_representation: ValueRepresentation, value: &str) -> Self {
+        self.element(tag, value_representation, Value::String(value.to_string()))
+    }
+
+    /// Binary VRs, e.g. US, FD, OW or AT (as pairs of `u16`).
+    pub fn numbers<T: LittleEndianBytes>(self, tag: Tag, value_representation: ValueRepresentation, values: &[T]) -> Self {
+        let value = Value::Numbers { size: std::mem::size_of::<T>(), bytes: numeric(values) };
+        self.element(tag, value_representation, value)
+    }
+
+    /// Values written as is, e.g. OB or UN.
+    pub fn bytes(self, tag: Tag, value_representation: ValueRepresentation, bytes: &[u8]) -> Self {
+        self.element(tag, value_representation, Value::Bytes(bytes.to_vec()))
+    }
+
+    /// Sequence and items of undefined length, ended with delimitation items.
+    pub fn sequence(self, tag: Tag, items: Vec<Dataset>) -> Self {
+        self.element(tag, SEQUENCE_OF_ITEMS, Value::Sequence { items, defined_length: false })
+    }
+
+    pub fn sequence_with_defined_length(self, tag: Tag, items: Vec<Dataset>) -> Self {
+        self.element(tag, SEQUENCE_OF_ITEMS, Value::Sequence { items, defined_length: true })
+    }
+
+    pub fn unknown_sequence(self, tag: Tag, items: Vec<Dataset>) -> Self {
+        self.element(tag, UNKNOWN, Value::UnknownSequence(items))
+    }
+
+    /// Pixel Data with one fragment per frame, preceded by a Basic Offset Table.
+    pub fn encapsulated_pixel_data(self, frames: &[&[u8]]) -> Self {
+        let frames = frames.iter().map(|frame| frame.to_vec()).collect();
+        self.element(PIXEL_DATA, OTHER_BYTE, Value::Encapsulated(frames))
+    }
+
+    /// Part 10 file: preamble, prefix, file meta group in Explicit VR Little Endian and the dataset
+    /// in the transfer syntax, deflated if needed.
+    pub fn to_part10(&self, transfer_syntax_uid: &str) -> Vec<u8> {
+        let mut meta = Dataset::new()
+            .bytes(FILE_META_INFORMATION_VERSION, OTHER_BYTE, &[0x00, 0x01])
+            .string(MEDIA_STORAGE_SOP_CLASS_UID, UNIQUE_IDENTIFIER_UID, &self.string_value(SOP_CLASS_UID).unwrap_or(CT_IMAGE_STORAGE.to_string()))
+            .string(MEDIA_STORAGE_SOP_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, &self.string_value(SOP_INSTANCE_UID).unwrap_or(format!("{}.1", SERIES_UID)))
+            .string(TRANSFER_SYNTAX_UID, UNIQUE_IDENTIFIER_UID, transfer_syntax_uid)
+            .encode(Encoding { explicit_value_representation: true, big_endian: false });
+
+        let group_length = Dataset::new()
+            .numbers(FILE_META_INFORMATION_GROUP_LENGTH, UNSIGNED_LONG, &[meta.len() as u32])
+            .encode(Encoding { explicit_value_representation: true, big_endian: false });
+
+        let mut dataset = self.to_bytes(transfer_syntax_uid);
+
+        if TransferSyntax::from_uid(transfer_syntax_uid).is_some_and(|transfer_syntax| transfer_syntax.deflated) {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&dataset).unwrap();
+            dataset = encoder.finish().unwrap();
+        }
+
+        let mut content = vec![0u8; HEADER_START];
+        content.extend_from_slice(HEADER);
+        content.extend(group_length);
+        content.append(&mut meta);
+        content.extend(dataset);
+        content
+    }
+
+    /// Dataset alone, never deflated. Unknown transfer syntaxes are encoded in Explicit VR Little Endian.
+    pub fn to_bytes(&self, transfer_syntax_uid: &str) -> Vec<u8> {
+        let encoding = match TransferSyntax::from_uid(transfer_syntax_uid) {
+            Some(transfer_syntax) => Encoding {
+                explicit_value_representation: transfer_syntax.explicit_value_representation,
+                big_endian: transfer_syntax.endianness == Endianness::Big,
+            },
+            None => Encoding { explicit_value_representation: true, big_endian: false },
+        };
+
+        self.encode(encoding)
+    }
+
+    fn element(mut self, tag: Tag, value_representation: ValueRepresentation, value: Value) -> Self {
+        self.elements.retain(|(existing, _, _)| *existing != tag);
+        self.elements.push((tag, value_representation, value));
+        self
+    }
+
+    fn string_value(&self, tag: Tag) -> Option<String> {
+        self.elements.iter().find_map(|(existing, _, value)| match value {
+            Value::String(string) if *existing == tag => Some(string.clone()),
+            _ => None,
+        })
+    }
+
+    fn encode(&self, encoding: Encoding) -> Vec<u8> {
+        let mut elements: Vec<_> = self.elements.iter().collect();
+        elements.sort_by_key(|(tag, _, _)| *tag);
+
+        let mut output = Vec::new();
+        for (tag, value_representation, value) in elements {
+            let (bytes, length) = match value {
+                Value::Sequence { items, defined_length } => {
+                    let bytes = encode_items(items, *defined_length, encoding);
+                    let length = if *defined_length { bytes.len() as u32 } else { UNDEFINED_LENGTH };
+                    (bytes, length)
+                },
+                Value::UnknownSequence(items) => {
+                    let bytes = encode_items(items, false, Encoding { explicit_value_representation: false, big_endian: false });
+                    (bytes, UNDEFINED_LENGTH)
+                },
+                Value::Encapsulated(frames) => (encode_fragments(frames, encoding), UNDEFINED_LENGTH),
+                value => {
+                    let bytes = encode_value(value, *value_representation, encoding);
+                    let length = bytes.len() as u32;
+                    (bytes, length)
+                },
+            };
+
+            write_header(&mut output, *tag, Some(*value_representation), length, encoding);
+            output.extend(bytes);
+        }
+
+        output
+    }
+}
+
+fn encode_value(value: &Value, value_representation: ValueRepresentation, encoding: Encoding) -> Vec<u8> {
+    let mut bytes = match value {
+        Value::String(string) => string.as_bytes().to_vec(),
+        Value::Numbers { size, bytes } if encoding.big_endian => bytes.chunks_exact(*size)
+            .flat_map(|value| value.iter().rev().copied())
+            .collect(),
+        Value::Numbers { bytes, .. } | Value::Bytes(bytes) => bytes.clone(),
+        Value::Sequence { .. } | Value::UnknownSequence(_) | Value::Encapsulated(_) => unreachable!("Items are encoded separately"),
+    };
+
+    if bytes.len() % 2 == 1 {
+        let padding = match value {
+            Value::String(_) if value_representation != UNIQUE_IDENTIFIER_UID => b' ',
+            _ => 0x00,
+        };
+        bytes.push(padding);
+    }
+
+    bytes
+}
+
+fn encode_items(items: &[Dataset], defined_length: bool, encoding: Encoding) -> Vec<u8> {
+    let mut output = Vec::new();
+
+    for item in items {
+        let item = item.encode(encoding);
+        if defined_length {
+            write_header(&mut output, ITEM, None, item.len() as u32, encoding);
+            output.extend(item);
+        } else {
+            write_header(&mut output, ITEM, None, UNDEFINED_LENGTH, encoding);
+            output.extend(item);
+            write_header(&mut output, ITEM_DELIMITATION_ITEM, None, 0, encoding);
+        }
+    }
+
+    if !defined_length {
+        write_header(&mut output, SEQUENCE_DELIMITATION_ITEM, None, 0, encoding);
+    }
+
+    output
+}
+
+fn encode_fragments(frames: &[Vec<u8>], encoding: Encoding) -> Vec<u8> {
+    let frames: Vec<Vec<u8>> = frames.iter()
+        .map(|frame| encode_value(&Value::Bytes(frame.clone()), OTHER_BYTE, encoding))
+        .collect();
+
+    // Offsets of the frames from the first fragment, each fragment has an 8 byte item header
+    let offsets: Vec<u32> = frames.iter()
+        .scan(0u32, |offset, frame| {
+            let current = *offset;
+            *offset += frame.len() as u32 + 8;
+            Some(current)
+        })
+        .collect();
+    let basic_offset_table = encode_value(&Value::Numbers { size: 4, bytes: numeric(&offsets) }, UNSIGNED_LONG, encoding);
+
+    let mut output = Vec::new();
+    for fragment in std::iter::once(&basic_offset_table).chain(&frames) {
+        write_header(&mut output, ITEM, None, fragment.len() as u32, encoding);
+        output.extend_from_slice(fragment);
+    }
+    write_header(&mut output, SEQUENCE_DELIMITATION_ITEM, None, 0, encoding);
+
+    output
+}
+
+/// Items and delimiters have no VR, even in explicit VR transfer syntaxes.
+fn write_header(output: &mut Vec<u8>, tag: Tag, value_representation: Option<ValueRepresentation>, length: u32, encoding: Encoding) {
+    let u16_bytes = |value: u16| if encoding.big_endian { value.to_be_bytes() } else { value.to_le_bytes() };
+    let u32_bytes = |value: u32| if encoding.big_endian { value.to_be_bytes() } else { value.to_le_bytes() };
+
+    output.extend_from_slice(&u16_bytes(tag.group));
+    output.extend_from_slice(&u16_bytes(tag.element));
+
+    match value_representation {
+        Some(value_representation) if encoding.explicit_value_representation => {
+            output.extend_from_slice(&value_representation.value);
+            if LONG_VALUE_REPRESENTATIONS.contains(&&value_representation.value) {
+                output.extend_from_slice(&[0x00, 0x00]);
+                output.extend_from_slice(&u32_bytes(length));
+            } else {
+                output.extend_from_slice(&u16_bytes(length as u16));
+            }
+        },
+        _ => output.extend_from_slice(&u32_bytes(length)),
+    }
+}
+
+/// Geometry of a generated series of parallel slices, stacked along the normal of the orientation.
+#[derive(Debug, Clone, Copy)]
+pub struct SeriesGeometry {
+    pub rows: u16,
+    pub columns: u16,
+    pub slices: usize,
+    pub pixel_spacing: [f64; 2],
+    pub image_orientation: [f64; 6],
+    pub first_position: [f64; 3],
+    pub slice_spacing: f64,
+}
+
+impl SeriesGeometry {
+    pub fn new(rows: u16, columns: u16, slices: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            slices,
+            pixel_spacing: [0.5, 0.5],
+            image_orientation: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            first_position: [-100.0, -100.0, 0.0],
+            slice_spacing: 2.5,
+        }
+    }
+
+    pub fn image_position(&self, slice: usize) -> [f64; 3] {
+        let [x1, y1, z1, x2, y2, z2] = self.image_orientation;
+        let normal = [y1 * z2 - z1 * y2, z1 * x2 - x1 * z2, x1 * y2 - y1 * x2];
+        let distance = slice as f64 * self.slice_spacing;
+
+        [0, 1, 2].map(|axis| self.first_position[axis] + normal[axis] * distance)
+    }
+}
+
+/// Stored value of a pixel of a generated slice, distinct in every slice and negative for some.
+pub fn pixel_value(slice: usize, pixel: usize) -> i16 {
+    (slice * 100 + pixel) as i16 - 50
+}
+
+/// CT slices of one series, with 16 bit signed native pixel data. Slices are numbered from 1 in
+/// the order of their positions.
+pub fn image_series(geometry: &SeriesGeometry) -> Vec<Dataset> {
+    let decimal = |values: &[f64]| values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join("\\");
+    let pixels = geometry.rows as usize * geometry.columns as usize;
+
+    (0..geometry.slices).map(|slice| {
+        let pixel_data: Vec<i16> = (0..pixels).map(|pixel| pixel_value(slice, pixel)).collect();
+
+        Dataset::new()
+            .string(SOP_CLASS_UID, UNIQUE_IDENTIFIER_UID, CT_IMAGE_STORAGE)
+            .string(SOP_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, &format!("{}.{}", SERIES_UID, slice + 1))
+            .string(STUDY_DATE, DATE, "20240301")
+            .string(MODALITY, CODE_STRING, "CT")
+            .string(PATIENT_NAME, PERSON_NAME, "Doe^John")
+            .string(PATIENT_ID, LONG_STRING, "P1")
+            .string(STUDY_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, STUDY_UID)
+            .string(SERIES_INSTANCE_UID, UNIQUE_IDENTIFIER_UID, SERIES_UID)
+            .string(SERIES_NUMBER, INTEGER_STRING, "1")
+            .string(INSTANCE_NUMBER, INTEGER_STRING, &(slice + 1).to_string())
+            .string(IMAGE_POSITION_PATIENT, DECIMAL_STRING, &decimal(&geometry.image_position(slice)))
+            .string(IMAGE_ORIENTATION_PATIENT, DECIMAL_STRING, &decimal(&geometry.image_orientation))
+            .numbers(SAMPLES_PER_PIXEL, UNSIGNED_SHORT, &[1u16])
+            .string(PHOTOMETRIC_INTERPRETATION, CODE_STRING, "MONOCHROME2")
+            .numbers(ROWS, UNSIGNED_SHORT, &[geometry.rows])
+            .numbers(COLUMNS, UNSIGNED_SHORT, &[geometry.columns])
+            .string(PIXEL_SPACING, DECIMAL_STRING, &decimal(&geometry.pixel_spacing))
+            .numbers(BITS_ALLOCATED, UNSIGNED_SHORT, &[16u16])
+            .numbers(BITS_STORED, UNSIGNED_SHORT, &[16u16])
+            .numbers(HIGH_BIT, UNSIGNED_SHORT, &[15u16])
+            .numbers(PIXEL_REPRESENTATION, UNSIGNED_SHORT, &[1u16])
+            .string(RESCALE_INTERCEPT, DECIMAL_STRING, "-1024")
+            .string(RESCALE_SLOPE, DECIMAL_STRING, "1")
+            .numbers(PIXEL_DATA, OTHER_WORD, &pixel_data)
+    }).collect()
+}
+
+/// Parses a generated dataset into a `DicomFile`, as the viewer does with files.
+pub fn dicom_file(dataset: &Dataset, transfer_syntax_uid: &str) -> DicomFile {
+    let parsed = DicomFileParser::new()
+        .read_all_tags()
+        .parse_bytes(&dataset.to_part10(transfer_syntax_uid))
+        .expect("Generated dataset should be parsed");
+
+    DicomFile::factory().create("", parsed.data_elements)
+        .unwrap_or_else(|inconsistencies| panic!("Generated dataset should be consistent: {:?}", inconsistencies))
+}
+
+/// Reader over a copy of the bytes, e.g. a dataset encoded with `Dataset::to_bytes`.
+pub fn data_reader(bytes: &[u8], endianness: Endianness) -> DataReader<'static> {
+    DataReader::new(Arc::new(map_anonymous(bytes).unwrap()), endianness)
+}
+
+/// Writes the content to a file of the temporary directory, unique to the test process, and returns its path.
+pub fn temp_file(name: &str, content: &[u8]) -> String {
+    let path = std::env::temp_dir().join(format!("dicom_generator_{}_{}.dcm", name, std::process::id()));
+    std::fs::write(&path, content).unwrap();
+    path.to_str().unwrap().to_string()
+}
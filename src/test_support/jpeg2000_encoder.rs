@@ -0,0 +1,76 @@
+use std::ffi::c_void;
+use std::io::{Cursor, Seek, SeekFrom, Write};
+use openjpeg_sys as opj;
+
+/// Encodes a single component frame into a reversible JPEG 2000 codestream (5-3 wavelet, one
+/// quality layer), as found in JPEG 2000 Image Compression (Lossless Only) files.
+pub fn encode_lossless(samples: &[i32], rows: u16, columns: u16, bits_stored: u16, signed: bool) -> Vec<u8> {
+    assert_eq!(samples.len(), rows as usize * columns as usize, "One sample per pixel is expected");
+
+    let mut output = Cursor::new(Vec::new());
+
+    unsafe {
+        let mut component = opj::opj_image_cmptparm_t {
+            dx: 1,
+            dy: 1,
+            w: columns as u32,
+            h: rows as u32,
+            x0: 0,
+            y0: 0,
+            prec: bits_stored as u32,
+            bpp: bits_stored as u32,
+            sgnd: signed as u32,
+        };
+
+        let image = opj::opj_image_create(1, &mut component, opj::COLOR_SPACE::OPJ_CLRSPC_GRAY);
+        assert!(!image.is_null(), "Image should be created");
+        (*image).x1 = columns as u32;
+        (*image).y1 = rows as u32;
+        std::slice::from_raw_parts_mut((*(*image).comps).data, samples.len()).copy_from_slice(samples);
+
+        let mut parameters = std::mem::MaybeUninit::<opj::opj_cparameters_t>::zeroed();
+        opj::opj_set_default_encoder_parameters(parameters.as_mut_ptr());
+        let mut parameters = parameters.assume_init();
+        parameters.tcp_numlayers = 1;
+        parameters.tcp_rates[0] = 0.0;
+        parameters.cp_disto_alloc = 1;
+        // Each resolution level halves the frame, which has to keep at least one pixel
+        parameters.numresolution = parameters.numresolution.min(rows.min(columns).ilog2() as i32 + 1);
+
+        let codec = opj::opj_create_compress(opj::CODEC_FORMAT::OPJ_CODEC_J2K);
+        let stream = opj::opj_stream_create(1 << 16, 0);
+        opj::opj_stream_set_user_data(stream, &mut output as *mut Cursor<Vec<u8>> as *mut c_void, None);
+        opj::opj_stream_set_write_function(stream, Some(write));
+        opj::opj_stream_set_skip_function(stream, Some(skip));
+        opj::opj_stream_set_seek_function(stream, Some(seek));
+
+        let encoded = opj::opj_setup_encoder(codec, &mut parameters, image) != 0
+            && opj::opj_start_compress(codec, image, stream) != 0
+            && opj::opj_encode(codec, stream) != 0
+            && opj::opj_end_compress(codec, stream) != 0;
+
+        opj::opj_stream_destroy(stream);
+        opj::opj_destroy_codec(codec);
+        opj::opj_image_destroy(image);
+
+        assert!(encoded, "Frame should be encoded");
+    }
+
+    output.into_inner()
+}
+
+unsafe extern "C" fn write(buffer: *mut c_void, length: usize, output: *mut c_void) -> usize {
+    let output = &mut *(output as *mut Cursor<Vec<u8>>);
+    let buffer = std::slice::from_raw_parts(buffer as *const u8, length);
+    output.write(buffer).unwrap_or(usize::MAX)
+}
+
+unsafe extern "C" fn skip(length: i64, output: *mut c_void) -> i64 {
+    let output = &mut *(output as *mut Cursor<Vec<u8>>);
+    output.seek(SeekFrom::Current(length)).map_or(-1, |_| length)
+}
+
+unsafe extern "C" fn seek(position: i64, output: *mut c_void) -> i32 {
+    let output = &mut *(output as *mut Cursor<Vec<u8>>);
+    output.seek(SeekFrom::Start(position as u64)).is_ok() as i32
+}
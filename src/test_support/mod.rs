@@ -0,0 +1,2 @@
+pub mod dicom_generator;
+pub mod jpeg2000_encoder;
@@ -0,0 +1,2 @@
+pub mod transcoder;
+pub mod transcode_error;
@@ -0,0 +1,50 @@
+use std::fmt;
+use crate::dicom_file_parser::parse_error::ParseError;
+use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
+
+#[derive(Debug)]
+pub enum TranscodeError {
+    Parse(ParseError),
+    Io(std::io::Error),
+    Inconsistency(DicomFileInconsistency),
+    UnsupportedTransferSyntax(&'static str),
+}
+
+impl fmt::Display for TranscodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TranscodeError::Parse(error) => write!(f, "Cannot parse file: {}", error),
+            TranscodeError::Io(error) => write!(f, "I/O error: {}", error),
+            TranscodeError::Inconsistency(inconsistency) => write!(f, "{}", inconsistency),
+            TranscodeError::UnsupportedTransferSyntax(uid) => write!(f, "Decoding transfer syntax {} is not supported", uid),
+        }
+    }
+}
+
+impl std::error::Error for TranscodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TranscodeError::Parse(error) => Some(error),
+            TranscodeError::Io(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<ParseError> for TranscodeError {
+    fn from(error: ParseError) -> Self {
+        TranscodeError::Parse(error)
+    }
+}
+
+impl From<std::io::Error> for TranscodeError {
+    fn from(error: std::io::Error) -> Self {
+        TranscodeError::Io(error)
+    }
+}
+
+impl From<DicomFileInconsistency> for TranscodeError {
+    fn from(inconsistency: DicomFileInconsistency) -> Self {
+        TranscodeError::Inconsistency(inconsistency)
+    }
+}
@@ -0,0 +1,366 @@
+use crate::dataset::data_element::DataElement;
+use crate::dataset::tag::Tag;
+use crate::dataset::value_field::ValueField;
+use crate::dicom_constants::tags::{BITS_ALLOCATED, COLUMNS, ENCAPSULATED_PIXEL_DATA_VALUE_TOTAL_LENGTH, EXTENDED_OFFSET_TABLE, EXTENDED_OFFSET_TABLE_LENGTHS, LOSSY_IMAGE_COMPRESSION, LOSSY_IMAGE_COMPRESSION_METHOD, LOSSY_IMAGE_COMPRESSION_RATIO, NUMBER_OF_FRAMES, PHOTOMETRIC_INTERPRETATION, PIXEL_DATA, PLANAR_CONFIGURATION, ROWS, SAMPLES_PER_PIXEL};
+use crate::dicom_constants::transfer_syntaxes::JPEG2000_LOSSLESS;
+use crate::dicom_constants::value_representation::{CODE_STRING, DECIMAL_STRING, OTHER_BYTE, OTHER_WORD, UNSIGNED_SHORT};
+use crate::dicom_file_parser::dicom_file_parser::DicomFileParser;
+use crate::dicom_file_parser::parsed_dicom_file::ParsedDicomFile;
+use crate::dicom_file_writer::dicom_file_writer::DicomFileWriter;
+use crate::information_object_definitions::inconsistency::DicomFileInconsistency;
+use crate::pixel_data_processor::encapsulated_pixel_data::frames;
+use crate::pixel_data_processor::jpeg_decoder::{JpegFileDecoder, DECODABLE_TRANSFER_SYNTAXES};
+use crate::traits::cast::Cast;
+use crate::utils::endianness::Endianness;
+use crate::utils::submap::Submap;
+use crate::value_representations::dicom_string::DicomString;
+use crate::value_representations::numeric_string::NumericString;
+use crate::value_representations::numeric_type::NumericType;
+use crate::value_representations::other_type::{Other, OtherType};
+use super::transcode_error::TranscodeError;
+
+/// Attributes describing the encapsulated pixel data, meaningless once it is decoded.
+const ENCAPSULATION_ATTRIBUTES: [Tag; 3] = [
+    EXTENDED_OFFSET_TABLE,
+    EXTENDED_OFFSET_TABLE_LENGTHS,
+    ENCAPSULATED_PIXEL_DATA_VALUE_TOTAL_LENGTH,
+];
+
+/// Photometric interpretations of JPEG 2000 colour transformations, which the decoder reverts.
+const COLOUR_TRANSFORMATIONS: [&str; 2] = ["YBR_ICT", "YBR_RCT"];
+
+const LOSSY_COMPRESSION: &str = "01";
+const JPEG2000_COMPRESSION_METHOD: &str = "ISO_15444_1";
+
+/// Rewrites datasets with native pixel data, to be written in Explicit VR Little Endian.
+pub struct Transcoder {}
+
+impl Default for Transcoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transcoder {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Transcodes the file at `input_path` into an Explicit VR Little Endian file at `output_path`.
+    /// Both paths may point to the same file.
+    pub fn transcode_file(&self, input_path: &str, output_path: &str) -> Result<(), TranscodeError> {
+        let parsed = DicomFileParser::new()
+            .file_path(input_path)
+            .read_all_tags()
+            .parse()?;
+
+        let data_elements = self.to_native(parsed)?;
+
+        // Values may still be mapped from the input file, so the output is created only once they
+        // are all serialized.
+        let bytes = DicomFileWriter::new().to_bytes(&data_elements)?;
+        std::fs::write(output_path, bytes)?;
+
+        Ok(())
+    }
+
+    /// Decodes encapsulated pixel data, frame after frame, and updates the Image Pixel attributes to
+    /// describe the decoded frames. When the frames were compressed irreversibly and the dataset
+    /// doesn't say so yet, Lossy Image Compression is set and the method and ratio are appended.
+    /// Datasets with native pixel data are returned as they are - the writer re-encodes them.
+    ///
+    /// The Transfer Syntax UID is left to `DicomFileWriter`, which recomputes the file meta group.
+    pub fn to_native(&self, parsed: ParsedDicomFile) -> Result<Vec<DataElement>, TranscodeError> {
+        let transfer_syntax = parsed.transfer_syntax;
+        let mut data_elements = parsed.data_elements;
+
+        if !transfer_syntax.encapsulated {
+            return Ok(data_elements);
+        }
+
+        if !DECODABLE_TRANSFER_SYNTAXES.contains(&transfer_syntax.uid) {
+            return Err(TranscodeError::UnsupportedTransferSyntax(transfer_syntax.uid));
+        }
+
+        let rows = unsigned_short(&data_elements, ROWS)? as usize;
+        let columns = unsigned_short(&data_elements, COLUMNS)? as usize;
+        let samples_per_pixel = unsigned_short(&data_elements, SAMPLES_PER_PIXEL)?;
+        let bits_allocated = unsigned_short(&data_elements, BITS_ALLOCATED)?;
+        let number_of_frames = number_of_frames(&data_elements)?;
+
+        let bytes_per_sample = (bits_allocated as usize).div_ceil(8);
+
+        let pixel_data_index = data_elements.iter()
+            .position(|data_element| data_element.tag == PIXEL_DATA)
+            .ok_or(DicomFileInconsistency::MissingAttribute(PIXEL_DATA))?;
+
+        let decoder = JpegFileDecoder::new();
+
+        let (decoded, lossy, compression_ratio) = {
+            let encoded = match &data_elements[pixel_data_index].value {
+                ValueField::OtherByte(other) => other.as_raw_data(),
+                value => return Err(unexpected_value(PIXEL_DATA, value).into()),
+            };
+
+            let frames = frames(encoded, number_of_frames)?;
+
+            if frames.len() != number_of_frames {
+                return Err(DicomFileInconsistency::MalformedPixelData("Number of frames does not match Number of Frames").into());
+            }
+
+            // The attributes are only trusted with memory once the fragments are known to match them
+            let frame_length = [columns, samples_per_pixel as usize, bytes_per_sample].into_iter()
+                .try_fold(rows, usize::checked_mul)
+                .ok_or(DicomFileInconsistency::MalformedPixelData("Frame size overflows"))?;
+            let decoded_length = frame_length.checked_mul(number_of_frames)
+                .ok_or(DicomFileInconsistency::MalformedPixelData("Pixel data size overflows"))?;
+
+            let mut decoded = vec![0u8; decoded_length];
+            for (frame, output) in frames.iter().zip(decoded.chunks_exact_mut(frame_length)) {
+                decoder.decode(frame, output, bytes_per_sample)?;
+            }
+
+            // JPEG 2000 Image Compression (Lossless Only) is reversible by definition
+            let lossy = transfer_syntax.uid != JPEG2000_LOSSLESS && frames.iter()
+                .any(|frame| decoder.is_reversible(frame) != Some(true));
+
+            // Item headers and the offset table are not part of the compressed image
+            let compressed_length: usize = frames.iter().map(|frame| frame.len()).sum();
+            let compression_ratio = decoded.len() as f64 / compressed_length as f64;
+
+            (decoded, lossy, compression_ratio)
+        };
+
+        data_elements[pixel_data_index] = pixel_data(&decoded, bits_allocated)?;
+        data_elements.retain(|data_element| !ENCAPSULATION_ATTRIBUTES.contains(&data_element.tag));
+
+        let photometric_interpretation = string(&data_elements, PHOTOMETRIC_INTERPRETATION);
+        if photometric_interpretation.is_some_and(|value| COLOUR_TRANSFORMATIONS.contains(&value.as_str())) {
+            replace(&mut data_elements, code_string(PHOTOMETRIC_INTERPRETATION, "RGB".to_string()));
+        }
+
+        if samples_per_pixel > 1 {
+            replace(&mut data_elements, DataElement {
+                tag: PLANAR_CONFIGURATION,
+                value_representation: Some(UNSIGNED_SHORT),
+                value_length: 2,
+                value: ValueField::UnsignedShort(NumericType::from(vec![0])),
+                offset: None,
+            });
+        }
+
+        if lossy && string(&data_elements, LOSSY_IMAGE_COMPRESSION).as_deref() != Some(LOSSY_COMPRESSION) {
+            let ratio = append(string(&data_elements, LOSSY_IMAGE_COMPRESSION_RATIO), &format!("{:.2}", compression_ratio));
+            let method = append(string(&data_elements, LOSSY_IMAGE_COMPRESSION_METHOD), JPEG2000_COMPRESSION_METHOD);
+
+            replace(&mut data_elements, code_string(LOSSY_IMAGE_COMPRESSION, LOSSY_COMPRESSION.to_string()));
+            replace(&mut data_elements, DataElement {
+                tag: LOSSY_IMAGE_COMPRESSION_RATIO,
+                value_representation: Some(DECIMAL_STRING),
+                value_length: ratio.len() as u32,
+                value: ValueField::DecimalString(NumericString::from(ratio)),
+                offset: None,
+            });
+            replace(&mut data_elements, code_string(LOSSY_IMAGE_COMPRESSION_METHOD, method));
+        }
+
+        Ok(data_elements)
+    }
+}
+
+/// Native pixel data is OW when samples take more than a byte, OB otherwise.
+fn pixel_data(decoded: &[u8], bits_allocated: u16) -> std::io::Result<DataElement> {
+    let submap = Submap::from_bytes(decoded, Endianness::Little)?;
+
+    let (value_representation, value) = if bits_allocated > 8 {
+        (OTHER_WORD, ValueField::OtherWord(OtherType::from(submap)))
+    } else {
+        (OTHER_BYTE, ValueField::OtherByte(OtherType::from(submap)))
+    };
+
+    Ok(DataElement {
+        tag: PIXEL_DATA,
+        value_representation: Some(value_representation),
+        value_length: decoded.len() as u32,
+        value,
+        offset: None,
+    })
+}
+
+fn find(data_elements: &[DataElement], tag: Tag) -> Option<&ValueField> {
+    data_elements.iter()
+        .find(|data_element| data_element.tag == tag)
+        .map(|data_element| &data_element.value)
+}
+
+fn unsigned_short(data_elements: &[DataElement], tag: Tag) -> Result<u16, DicomFileInconsistency> {
+    match find(data_elements, tag) {
+        Some(ValueField::UnsignedShort(value)) => Ok(Cast::<u16>::cast(value)?),
+        Some(value) => Err(unexpected_value(tag, value)),
+        None => Err(DicomFileInconsistency::MissingAttribute(tag)),
+    }
+}
+
+/// Number of Frames is present only in multi-frame images.
+fn number_of_frames(data_elements: &[DataElement]) -> Result<usize, DicomFileInconsistency> {
+    let Some(number_of_frames) = string(data_elements, NUMBER_OF_FRAMES) else {
+        return Ok(1);
+    };
+
+    number_of_frames.parse()
+        .map_err(|_| DicomFileInconsistency::CastError(format!("Invalid Number of Frames {}", number_of_frames)))
+}
+
+/// Value of a string element, without padding.
+fn string(data_elements: &[DataElement], tag: Tag) -> Option<String> {
+    let value: String = match find(data_elements, tag)? {
+        ValueField::CodeString(string) => string.into(),
+        ValueField::DecimalString(string) | ValueField::IntegerString(string) => string.into(),
+        _ => return None,
+    };
+
+    Some(value.trim_matches(|c: char| c == '\0' || c == ' ').to_string())
+}
+
+/// Appends a value to a multi-valued string.
+fn append(values: Option<String>, value: &str) -> String {
+    match values {
+        Some(values) if !values.is_empty() => format!("{}\\{}", values, value),
+        _ => value.to_string(),
+    }
+}
+
+fn replace(data_elements: &mut Vec<DataElement>, data_element: DataElement) {
+    data_elements.retain(|existing| existing.tag != data_element.tag);
+    data_elements.push(data_element);
+}
+
+fn code_string(tag: Tag, value: String) -> DataElement {
+    DataElement {
+        tag,
+        value_representation: Some(CODE_STRING),
+        value_length: value.len() as u32,
+        value: ValueField::CodeString(DicomString::from(value)),
+        offset: None,
+    }
+}
+
+fn unexpected_value(tag: Tag, value: &ValueField) -> DicomFileInconsistency {
+    DicomFileInconsistency::UnexpectedValueRepresentation(format!("Unexpected value {:?} for tag {}", value, tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dicom_constants::numeric::UNDEFINED_LENGTH;
+    use crate::dicom_constants::tags::{ITEM, SEQUENCE_DELIMITATION_ITEM};
+    use crate::dicom_constants::transfer_syntaxes::{EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN, JPEG2000, JPEG_BASELINE_8_BIT};
+    use crate::dicom_constants::value_representation::INTEGER_STRING;
+    use crate::dicom_file_parser::transfer_syntax::TransferSyntax;
+    use crate::test_support::dicom_generator::{image_series, pixel_value, SeriesGeometry};
+    use crate::test_support::jpeg2000_encoder::encode_lossless;
+    use crate::value_representations::numeric_type::Numeric;
+    use super::*;
+
+    fn unsigned_short_element(tag: Tag, value: u16) -> DataElement {
+        DataElement {
+            tag,
+            value_representation: Some(UNSIGNED_SHORT),
+            value_length: 2,
+            value: ValueField::UnsignedShort(NumericType::from(vec![value])),
+            offset: None,
+        }
+    }
+
+    fn image(pixel_data: DataElement) -> Vec<DataElement> {
+        vec![
+            unsigned_short_element(ROWS, 1),
+            unsigned_short_element(COLUMNS, 2),
+            unsigned_short_element(SAMPLES_PER_PIXEL, 1),
+            unsigned_short_element(BITS_ALLOCATED, 16),
+            code_string(PHOTOMETRIC_INTERPRETATION, "MONOCHROME2".to_string()),
+            pixel_data,
+        ]
+    }
+
+    fn parse(bytes: &[u8]) -> ParsedDicomFile {
+        DicomFileParser::new().read_all_tags().parse_bytes(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_native_pixel_data_is_kept() {
+        let pixel_data = pixel_data(&[0x01, 0x02, 0x03, 0x04], 16).unwrap();
+        let implicit = DicomFileWriter::new()
+            .transfer_syntax(TransferSyntax::from_uid(IMPLICIT_VR_LITTLE_ENDIAN).unwrap())
+            .to_bytes(&image(pixel_data))
+            .unwrap();
+
+        let data_elements = Transcoder::new().to_native(parse(&implicit)).unwrap();
+        let parsed = parse(&DicomFileWriter::new().to_bytes(&data_elements).unwrap());
+
+        assert_eq!(parsed.transfer_syntax.uid, EXPLICIT_VR_LITTLE_ENDIAN);
+        assert!(matches!(find(&parsed.data_elements, PIXEL_DATA), Some(ValueField::OtherWord(words)) if words.value() == &vec![0x0201, 0x0403]));
+        assert!(matches!(find(&parsed.data_elements, ROWS), Some(ValueField::UnsignedShort(rows)) if rows.value() == &vec![1]));
+    }
+
+    #[test]
+    fn test_jpeg2000_lossless_round_trip() {
+        let geometry = SeriesGeometry::new(24, 40, 1);
+        let pixels: Vec<i16> = (0..24 * 40).map(|pixel| pixel_value(0, pixel)).collect();
+        let samples: Vec<i32> = pixels.iter().map(|&pixel| pixel as i32).collect();
+        let codestream = encode_lossless(&samples, geometry.rows, geometry.columns, 16, true);
+        let dataset = image_series(&geometry).remove(0).encapsulated_pixel_data(&[&codestream]);
+
+        // Reversible frames are lossless whether or not the transfer syntax guarantees it
+        for transfer_syntax in [JPEG2000_LOSSLESS, JPEG2000] {
+            let data_elements = Transcoder::new().to_native(parse(&dataset.to_part10(transfer_syntax))).unwrap();
+            let parsed = parse(&DicomFileWriter::new().to_bytes(&data_elements).unwrap());
+
+            assert_eq!(parsed.transfer_syntax.uid, EXPLICIT_VR_LITTLE_ENDIAN);
+            match find(&parsed.data_elements, PIXEL_DATA) {
+                Some(ValueField::OtherWord(words)) => assert!(words.value().iter().map(|&word| word as i16).eq(pixels.iter().copied())),
+                _ => panic!("Pixel data should be decoded to OW")
+            }
+            assert!(find(&parsed.data_elements, LOSSY_IMAGE_COMPRESSION).is_none());
+        }
+    }
+
+    #[test]
+    fn test_frames_are_checked_before_decoding() {
+        let geometry = SeriesGeometry::new(24, 40, 1);
+        let samples: Vec<i32> = (0..24 * 40).map(|pixel| pixel_value(0, pixel) as i32).collect();
+        let codestream = encode_lossless(&samples, geometry.rows, geometry.columns, 16, true);
+        let dataset = image_series(&geometry).remove(0)
+            .string(NUMBER_OF_FRAMES, INTEGER_STRING, "2000000000")
+            .encapsulated_pixel_data(&[&codestream]);
+
+        let result = Transcoder::new().to_native(parse(&dataset.to_part10(JPEG2000_LOSSLESS)));
+        assert!(matches!(result, Err(TranscodeError::Inconsistency(DicomFileInconsistency::MalformedPixelData(_)))));
+    }
+
+    #[test]
+    fn test_unsupported_transfer_syntax() {
+        let mut fragments = Vec::new();
+        for (tag, content) in [(ITEM, &[][..]), (ITEM, &[0xFF, 0xD8, 0xFF, 0xD9][..]), (SEQUENCE_DELIMITATION_ITEM, &[][..])] {
+            fragments.extend_from_slice(&tag.group.to_le_bytes());
+            fragments.extend_from_slice(&tag.element.to_le_bytes());
+            fragments.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            fragments.extend_from_slice(content);
+        }
+
+        let pixel_data = DataElement {
+            tag: PIXEL_DATA,
+            value_representation: Some(OTHER_BYTE),
+            value_length: UNDEFINED_LENGTH,
+            value: ValueField::OtherByte(OtherType::from(Submap::from_bytes(&fragments, Endianness::Little).unwrap())),
+            offset: None,
+        };
+
+        let bytes = DicomFileWriter::new()
+            .transfer_syntax(TransferSyntax::from_uid(JPEG_BASELINE_8_BIT).unwrap())
+            .to_bytes(&image(pixel_data))
+            .unwrap();
+
+        let transcoded = Transcoder::new().to_native(parse(&bytes));
+        assert!(matches!(transcoded, Err(TranscodeError::UnsupportedTransferSyntax(JPEG_BASELINE_8_BIT))));
+    }
+}
@@ -36,6 +36,12 @@ pub struct DimensionsBuilder {
     distance_between_slices: f32
 }
 
+impl Default for DimensionsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DimensionsBuilder {
     pub fn new() -> DimensionsBuilder {
         DimensionsBuilder {
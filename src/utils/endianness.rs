@@ -3,3 +3,14 @@ pub enum Endianness {
     Little,
     Big,
 }
+
+
+impl Endianness {
+    pub fn native() -> Self {
+        if cfg!(target_endian = "big") {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+}
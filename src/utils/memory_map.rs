@@ -0,0 +1,63 @@
+use std::io::{self, Read};
+use memmap2::{Mmap, MmapMut};
+
+/// Size of the map `read_anonymous` starts reading into, doubled whenever it is full.
+#[cfg(target_os = "linux")]
+const INITIAL_READ_LENGTH: usize = 64 * 1024;
+
+/// Copies the bytes into an anonymous read-only memory map, so data which does not come directly
+/// from a file can be shared with `DataReader` and `Submap` as if it was mapped from disk.
+pub fn map_anonymous(bytes: &[u8]) -> io::Result<Mmap> {
+    let mut map = MmapMut::map_anon(bytes.len())?;
+    map.copy_from_slice(bytes);
+    map.make_read_only()
+}
+
+/// Reads until the end directly into an anonymous read-only memory map, which grows and is then
+/// trimmed to the content in place.
+#[cfg(target_os = "linux")]
+pub fn read_anonymous<R: Read>(mut reader: R) -> io::Result<Mmap> {
+    use memmap2::RemapOptions;
+
+    let mut map = MmapMut::map_anon(INITIAL_READ_LENGTH)?;
+    let mut length = 0;
+
+    loop {
+        if length == map.len() {
+            // Safe as nothing borrows from the map while it is resized
+            unsafe { map.remap(map.len() * 2, RemapOptions::new().may_move(true))? };
+        }
+
+        match reader.read(&mut map[length..]) {
+            Ok(0) => break,
+            Ok(read) => length += read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+
+    unsafe { map.remap(length, RemapOptions::new())? };
+    map.make_read_only()
+}
+
+/// Reads until the end into an anonymous read-only memory map. Maps cannot be resized on this
+/// platform, so the content is buffered first.
+#[cfg(not(target_os = "linux"))]
+pub fn read_anonymous<R: Read>(mut reader: R) -> io::Result<Mmap> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    map_anonymous(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_anonymous() {
+        let bytes: Vec<u8> = (0..200_000u32).map(|index| (index % 251) as u8).collect();
+        assert_eq!(&read_anonymous(bytes.as_slice()).unwrap()[..], bytes.as_slice());
+
+        assert!(read_anonymous(std::io::empty()).unwrap().is_empty());
+    }
+}
@@ -2,3 +2,5 @@ pub mod submap;
 pub mod endianness;
 pub mod data_dimensions;
 pub mod non_zero_sized;
+pub mod memory_map;
+pub mod uid;
@@ -1,17 +1,24 @@
-use std::rc::Rc;
+use std::io;
+use std::sync::Arc;
 use memmap2::Mmap;
 use crate::utils::endianness::Endianness;
+use crate::utils::memory_map::map_anonymous;
 
 #[derive(Debug, Clone)]
 pub struct Submap {
-    pub file: Rc<Mmap>,
+    pub file: Arc<Mmap>,
     pub start: usize,
     pub end: usize,
     pub endianness: Endianness,
 }
 
 impl Submap {
-    pub fn new(file: Rc<Mmap>, start: usize, end: usize, endianness: Endianness) -> Self {
+    pub fn new(file: Arc<Mmap>, start: usize, end: usize, endianness: Endianness) -> Self {
         Self { file, start, end, endianness }
     }
-}
\ No newline at end of file
+
+    /// Submap over a copy of the bytes, for values created in memory rather than read from a file.
+    pub fn from_bytes(bytes: &[u8], endianness: Endianness) -> io::Result<Self> {
+        Ok(Self::new(Arc::new(map_anonymous(bytes)?), 0, bytes.len(), endianness))
+    }
+}
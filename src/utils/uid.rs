@@ -0,0 +1,14 @@
+use nanorand::{ChaCha20, Rng};
+
+/// UID derived from a random (version 4) UUID, as described in PS3.5 B.2.
+pub fn generate_uid(generator: &mut ChaCha20) -> String {
+    const VERSION_MASK: u128 = 0xF << 76;
+    const VERSION_4: u128 = 0x4 << 76;
+    const VARIANT_MASK: u128 = 0x3 << 62;
+    const VARIANT_RFC_4122: u128 = 0x2 << 62;
+
+    let random = (generator.generate::<u64>() as u128) << 64 | generator.generate::<u64>() as u128;
+    let uuid = (random & !VERSION_MASK | VERSION_4) & !VARIANT_MASK | VARIANT_RFC_4122;
+
+    format!("2.25.{}", uuid)
+}
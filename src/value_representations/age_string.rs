@@ -0,0 +1,88 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::traits::cast::{Cast, CastError};
+use crate::value_representations::dicom_string::DicomString;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AgeUnit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+/// AS value, e.g. `045Y` or `018M`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Age {
+    pub value: u16,
+    pub unit: AgeUnit,
+}
+
+impl Age {
+    /// Approximate age in days, e.g. to compare ages given in different units.
+    pub fn days(&self) -> f64 {
+        let days_per_unit = match self.unit {
+            AgeUnit::Days => 1.0,
+            AgeUnit::Weeks => 7.0,
+            AgeUnit::Months => 365.25 / 12.0,
+            AgeUnit::Years => 365.25,
+        };
+
+        self.value as f64 * days_per_unit
+    }
+}
+
+impl FromStr for Age {
+    type Err = CastError<Age>;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim_end_matches([' ', '\0']);
+        let (digits, unit) = match text.as_bytes() {
+            [digits @ .., unit] if digits.len() == 3 && digits.iter().all(u8::is_ascii_digit) => (&text[..3], *unit),
+            _ => return Err(CastError::default()),
+        };
+
+        let unit = match unit {
+            b'D' => AgeUnit::Days,
+            b'W' => AgeUnit::Weeks,
+            b'M' => AgeUnit::Months,
+            b'Y' => AgeUnit::Years,
+            _ => return Err(CastError::default()),
+        };
+
+        Ok(Self { value: digits.parse().map_err(|_| CastError::default())?, unit })
+    }
+}
+
+impl Cast<Age> for DicomString {
+    fn cast(&self) -> Result<Age, CastError<Age>> {
+        Into::<String>::into(self).parse()
+    }
+}
+
+impl fmt::Display for Age {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unit = match self.unit {
+            AgeUnit::Days => "day",
+            AgeUnit::Weeks => "week",
+            AgeUnit::Months => "month",
+            AgeUnit::Years => "year",
+        };
+        let plural = if self.value == 1 { "" } else { "s" };
+
+        write!(f, "{} {}{}", self.value, unit, plural)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("045Y".parse::<Age>().unwrap(), Age { value: 45, unit: AgeUnit::Years });
+        assert_eq!("001W".parse::<Age>().unwrap().to_string(), "1 week");
+        assert!("45Y".parse::<Age>().is_err());
+        assert!("045X".parse::<Age>().is_err());
+    }
+}
@@ -0,0 +1,397 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use crate::traits::cast::{Cast, CastError};
+use crate::value_representations::dicom_string::DicomString;
+
+const RANGE_SEPARATOR: char = '-';
+const MAX_FRACTION_DIGITS: usize = 6;
+const MAX_UTC_OFFSET_MINUTES: i16 = 14 * 60;
+const MIN_UTC_OFFSET_MINUTES: i16 = -12 * 60;
+
+/// DA value, e.g. `20240301`. Dates of ACR-NEMA files, e.g. `2024.03.01`, are accepted too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// TM value, e.g. `143015.25`. Minutes, seconds and the fraction may be omitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    pub hour: u8,
+    pub minute: Option<u8>,
+    pub second: Option<u8>,
+    pub microsecond: Option<u32>,
+}
+
+/// DT value, e.g. `20240301143015.25+0100`. Any component after the year may be omitted, the
+/// offset from UTC too. Ordering compares the instants the values denote, an omitted component
+/// being its earliest value and an omitted offset UTC; values denoting the same instant are then
+/// ordered by their components, an omitted one first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    pub year: u16,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+    /// Only present with a day.
+    pub time: Option<Time>,
+    /// Offset from UTC in minutes.
+    pub utc_offset: Option<i16>,
+}
+
+/// Range matching of DA, TM and DT values (PS3.4 C.2.2.2.5), e.g. `20240101-20241231`. An open
+/// start or end is `None`, a single value is a range from and to that value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueRange<T> {
+    pub start: Option<T>,
+    pub end: Option<T>,
+}
+
+impl Date {
+    fn parse(text: &str) -> Option<Self> {
+        let date = match text.as_bytes() {
+            [_, _, _, _, b'.', _, _, b'.', _, _] => text.replace('.', ""),
+            _ => text.to_string(),
+        };
+
+        if date.len() != 8 {
+            return None;
+        }
+
+        let year = number(&date[..4])?;
+        let month = number(&date[4..6])?;
+        let day = number(&date[6..])?;
+        valid_date(year, Some(month), Some(day)).then_some(Self { year, month, day })
+    }
+}
+
+impl Time {
+    /// Older files separate the components with colons, e.g. `14:30:15`.
+    fn parse(text: &str) -> Option<Self> {
+        let time = text.replace(':', "");
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (time.as_str(), None),
+        };
+
+        let (hour, minute, second) = match time.len() {
+            2 => (number(time)?, None, None),
+            4 => (number(&time[..2])?, Some(number(&time[2..])?), None),
+            6 => (number(&time[..2])?, Some(number(&time[2..4])?), Some(number(&time[4..])?)),
+            _ => return None,
+        };
+
+        // A fraction requires seconds
+        let microsecond = match fraction {
+            Some(fraction) if second.is_some() => Some(microseconds(fraction)?),
+            Some(_) => return None,
+            None => None,
+        };
+
+        let valid = hour < 24 && minute.is_none_or(|minute| minute < 60) && second.is_none_or(|second| second <= 60);
+        valid.then_some(Self { hour, minute, second, microsecond })
+    }
+}
+
+impl DateTime {
+    pub fn new(date: Date, time: Option<Time>) -> Self {
+        Self {
+            year: date.year,
+            month: Some(date.month),
+            day: Some(date.day),
+            time,
+            utc_offset: None,
+        }
+    }
+
+    /// The date, when the value is precise to a day.
+    pub fn date(&self) -> Option<Date> {
+        Some(Date { year: self.year, month: self.month?, day: self.day? })
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let (date_time, utc_offset) = match text.find(['+', '-']) {
+            Some(index) => (&text[..index], Some(utc_offset(&text[index..])?)),
+            None => (text, None),
+        };
+
+        let digits = date_time.split_once('.').map_or(date_time, |(digits, _)| digits);
+        let (year, month, day) = match digits.len() {
+            4 => (number(&date_time[..4])?, None, None),
+            6 => (number(&date_time[..4])?, Some(number(&date_time[4..6])?), None),
+            8.. => (number(&date_time[..4])?, Some(number(&date_time[4..6])?), Some(number(&date_time[6..8])?)),
+            _ => return None,
+        };
+
+        if !valid_date(year, month, day) || (day.is_none() && date_time.len() != digits.len()) {
+            return None;
+        }
+
+        let time = match date_time.get(8..) {
+            Some(time) if !time.is_empty() => Some(Time::parse(time)?),
+            _ => None,
+        };
+
+        Some(Self { year, month, day, time, utc_offset })
+    }
+
+    /// Microseconds since 0000-03-01 UTC.
+    fn instant(&self) -> i64 {
+        let time = self.time.unwrap_or(Time { hour: 0, minute: None, second: None, microsecond: None });
+        let days = days_from_civil(self.year, self.month.unwrap_or(1), self.day.unwrap_or(1));
+        let minutes = (days * 24 + time.hour as i64) * 60 + time.minute.unwrap_or(0) as i64
+            - self.utc_offset.unwrap_or(0) as i64;
+        (minutes * 60 + time.second.unwrap_or(0) as i64) * 1_000_000 + time.microsecond.unwrap_or(0) as i64
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let components = |value: &Self| (value.year, value.month, value.day, value.time, value.utc_offset);
+        self.instant().cmp(&other.instant()).then_with(|| components(self).cmp(&components(other)))
+    }
+}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: FromStr> ValueRange<T> {
+    /// The separator cannot be told from the sign of a DT offset, e.g. in
+    /// `20240101-0500-20240102`, so the first split with valid parts on both sides is taken.
+    fn parse(text: &str) -> Option<Self> {
+        for (index, _) in text.match_indices(RANGE_SEPARATOR) {
+            let (start, end) = (&text[..index], &text[index + 1..]);
+            let bound = |text: &str| if text.is_empty() { Some(None) } else { text.parse().ok().map(Some) };
+
+            if let (Some(start), Some(end)) = (bound(start), bound(end)) {
+                if start.is_some() || end.is_some() {
+                    return Some(Self { start, end });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<T: PartialOrd> ValueRange<T> {
+    pub fn contains(&self, value: &T) -> bool {
+        self.start.as_ref().is_none_or(|start| start <= value) && self.end.as_ref().is_none_or(|end| value <= end)
+    }
+}
+
+fn number<T: FromStr>(digits: &str) -> Option<T> {
+    if digits.is_empty() || !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn microseconds(fraction: &str) -> Option<u32> {
+    if fraction.len() > MAX_FRACTION_DIGITS {
+        return None;
+    }
+    Some(number::<u32>(fraction)? * 10u32.pow((MAX_FRACTION_DIGITS - fraction.len()) as u32))
+}
+
+/// `&ZZXX`, the sign followed by hours and minutes.
+fn utc_offset(text: &str) -> Option<i16> {
+    if text.len() != 5 {
+        return None;
+    }
+
+    let hours: i16 = number(&text[1..3])?;
+    let minutes: i16 = number(&text[3..])?;
+    let offset = if text.starts_with('-') { -(hours * 60 + minutes) } else { hours * 60 + minutes };
+
+    (minutes < 60 && (MIN_UTC_OFFSET_MINUTES..=MAX_UTC_OFFSET_MINUTES).contains(&offset)).then_some(offset)
+}
+
+/// Days of the proleptic Gregorian calendar since 0000-03-01, years starting in March so leap
+/// days come last.
+fn days_from_civil(year: u16, month: u8, day: u8) -> i64 {
+    let (month, day) = (month as i64, day as i64);
+    let year = year as i64 - (month <= 2) as i64;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    year * 365 + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400) + day_of_year
+}
+
+fn valid_date(year: u16, month: Option<u8>, day: Option<u8>) -> bool {
+    let leap_year = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+
+    match (month, day) {
+        (None, None) => true,
+        (Some(month), None) => (1..=12).contains(&month),
+        (Some(month), Some(day)) => {
+            let days = match month {
+                1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+                4 | 6 | 9 | 11 => 30,
+                2 if leap_year => 29,
+                2 => 28,
+                _ => return false,
+            };
+            (1..=days).contains(&day)
+        },
+        (None, Some(_)) => false,
+    }
+}
+
+/// Values are padded with spaces, DT values sometimes with NULL.
+fn trim(text: &str) -> &str {
+    text.trim_matches(|c: char| c == ' ' || c == '\0')
+}
+
+macro_rules! impl_from_str {
+    ($type: ty) => {
+        impl FromStr for $type {
+            type Err = CastError<$type>;
+
+            fn from_str(text: &str) -> Result<Self, Self::Err> {
+                let text = trim(text);
+                // Components are sliced by byte positions
+                if !text.is_ascii() {
+                    return Err(CastError::default());
+                }
+                Self::parse(text).ok_or_else(CastError::default)
+            }
+        }
+
+        impl Cast<$type> for DicomString {
+            fn cast(&self) -> Result<$type, CastError<$type>> {
+                Into::<String>::into(self).parse()
+            }
+        }
+    }
+}
+
+impl_from_str!(Date);
+impl_from_str!(Time);
+impl_from_str!(DateTime);
+
+/// A value that parses as a whole is a single value, so `2024-0500` is a DT with an offset, not a range.
+impl<T: FromStr + Copy> FromStr for ValueRange<T> {
+    type Err = CastError<ValueRange<T>>;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = trim(text);
+
+        match text.parse() {
+            Ok(value) => Ok(Self { start: Some(value), end: Some(value) }),
+            Err(_) => Self::parse(text).ok_or_else(CastError::default),
+        }
+    }
+}
+
+impl<T: FromStr + Copy> Cast<ValueRange<T>> for DicomString {
+    fn cast(&self) -> Result<ValueRange<T>, CastError<ValueRange<T>>> {
+        Into::<String>::into(self).parse()
+    }
+}
+
+/// ISO 8601, e.g. `2024-03-01`.
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// ISO 8601 with the precision of the value, e.g. `14:30` or `14:30:15.250000`.
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}", self.hour)?;
+        if let Some(minute) = self.minute {
+            write!(f, ":{:02}", minute)?;
+        }
+        if let Some(second) = self.second {
+            write!(f, ":{:02}", second)?;
+        }
+        if let Some(microsecond) = self.microsecond {
+            write!(f, ".{:06}", microsecond)?;
+        }
+        Ok(())
+    }
+}
+
+/// ISO 8601 with the precision of the value, e.g. `2024-03`, `2024-03-01T14:30+01:00`.
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        if let Some(time) = self.time {
+            write!(f, "T{}", time)?;
+        }
+        if let Some(offset) = self.utc_offset {
+            let sign = if offset < 0 { '-' } else { '+' };
+            write!(f, "{}{:02}:{:02}", sign, offset.abs() / 60, offset.abs() % 60)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("20240229".parse::<Date>().unwrap(), Date { year: 2024, month: 2, day: 29 });
+        assert_eq!("1993.08.22 ".parse::<Date>().unwrap(), Date { year: 1993, month: 8, day: 22 });
+        assert!("20230229".parse::<Date>().is_err());
+        assert!("2024".parse::<Date>().is_err());
+
+        assert_eq!("1430".parse::<Time>().unwrap(), Time { hour: 14, minute: Some(30), second: None, microsecond: None });
+        assert_eq!("14:30:15.25".parse::<Time>().unwrap(), Time { hour: 14, minute: Some(30), second: Some(15), microsecond: Some(250000) });
+        assert!("2400".parse::<Time>().is_err());
+        assert!("1430.5".parse::<Time>().is_err());
+
+        let date_time: DateTime = "20240301143015.25-0500".parse().unwrap();
+        assert_eq!(date_time.date(), Some(Date { year: 2024, month: 3, day: 1 }));
+        assert_eq!(date_time.utc_offset, Some(-300));
+        assert_eq!(date_time.to_string(), "2024-03-01T14:30:15.250000-05:00");
+        assert_eq!("202403".parse::<DateTime>().unwrap().to_string(), "2024-03");
+        assert!("202403.5".parse::<DateTime>().is_err());
+    }
+
+    #[test]
+    fn test_date_time_ordering() {
+        let date_time = |text: &str| text.parse::<DateTime>().unwrap();
+
+        assert!(date_time("2024") < date_time("202401"));
+        assert!(date_time("20240301235959") < date_time("20240302"));
+        // 12:00-0500 is 17:00 UTC, after 12:00+0100 which is 11:00 UTC
+        assert!(date_time("20240301120000-0500") > date_time("20240301120000+0100"));
+        assert!(date_time("20240301060000-0500") < date_time("20240301120000+0100"));
+        assert!(date_time("20240229233000-0100") > date_time("20240301000000+0000"));
+        assert!(date_time("20231231") < date_time("20240101"));
+
+        // The same instant, written differently
+        assert_eq!(date_time("20240301120000+0100").instant(), date_time("20240301110000").instant());
+        assert!(date_time("2024") < date_time("20240101000000"));
+    }
+
+    #[test]
+    fn test_ranges() {
+        let range: ValueRange<DateTime> = "20240101-0500-20240102+0100".parse().unwrap();
+        assert_eq!(range.start.and_then(|start| start.utc_offset), Some(-300));
+        assert_eq!(range.end.and_then(|end| end.utc_offset), Some(60));
+
+        let range: ValueRange<Date> = "-20231231".parse().unwrap();
+        assert_eq!(range.start, None);
+        assert!(range.contains(&"20230615".parse().unwrap()));
+        assert!(!range.contains(&"20240101".parse().unwrap()));
+
+        let single: ValueRange<Time> = "0800".parse().unwrap();
+        assert_eq!(single.start, single.end);
+        assert!("-".parse::<ValueRange<Date>>().is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use std::fmt;
-use once_cell::unsync::{OnceCell};
+use once_cell::sync::{OnceCell};
 use crate::dataset::data_element_location::DataElementLocation;
 
 pub struct DicomString
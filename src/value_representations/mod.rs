@@ -4,4 +4,7 @@ pub mod unknown;
 pub mod dicom_string;
 pub mod other_type;
 pub mod numeric_type;
-pub mod numeric_string;
\ No newline at end of file
+pub mod numeric_string;
+pub mod date_time;
+pub mod person_name;
+pub mod age_string;
@@ -37,7 +37,8 @@ impl<T: Num + FromStr> Cast<T> for NumericString {
     fn cast(&self) -> Result<T, CastError<T>> {
         let str: String = self.into();
 
-        match str.parse::<T>() {
+        // DS and IS values may be padded with leading and trailing spaces
+        match str.trim().parse::<T>() {
             Ok(val) => Ok(val),
             Err(_) => Err(CastError::<T>::default())
         }
@@ -48,11 +49,12 @@ impl<T: Num + FromStr + Copy + Default, const N: usize> CastArray<T, N> for Nume
     where <T as FromStr>::Err: std::fmt::Debug {
     fn cast(&self) -> Result<[T; N], CastArrayError<T, N>> {
         let str: String = self.into();
-        let values: Vec<T> = str.split("\\").map(|s| s.parse::<T>().unwrap()).collect();
+        let values: Result<Vec<T>, _> = str.split("\\").map(|s| s.trim().parse::<T>()).collect();
 
-        if values.len() != N {
-            return Err(CastArrayError::<T, N>::default());
-        }
+        let values = match values {
+            Ok(values) if values.len() == N => values,
+            _ => return Err(CastArrayError::<T, N>::default()),
+        };
 
         let mut arr: [T; N] = [Default::default(); N];
         arr.copy_from_slice(&values);
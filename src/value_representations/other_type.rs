@@ -1,5 +1,6 @@
 use std::io::{Cursor, Read};
-use once_cell::unsync::OnceCell;
+use once_cell::sync::OnceCell;
+use crate::utils::endianness::Endianness;
 use crate::utils::submap::Submap;
 
 pub trait Other : From<Submap> {
@@ -7,6 +8,8 @@ pub trait Other : From<Submap> {
 
     fn value(&self) -> &Vec<Self::Type>;
     fn as_raw_data(&self) -> &[u8];
+    /// Where the value is stored, e.g. to refer to it as bulk data.
+    fn data_location(&self) -> &Submap;
 }
 
 #[derive(Debug, Clone)]
@@ -42,6 +45,10 @@ impl<T> Other for OtherType<T> where T: Default + Clone {
 
             cursor.read_exact(slice).expect("Failed to read data from submap");
 
+            if self.data_location.endianness != Endianness::native() {
+                slice.chunks_exact_mut(std::mem::size_of::<T>()).for_each(|elem| elem.reverse());
+            }
+
             vec
         })
     }
@@ -49,5 +56,27 @@ impl<T> Other for OtherType<T> where T: Default + Clone {
     fn as_raw_data(&self) -> &[u8] {
         &self.data_location.file[self.data_location.start..self.data_location.end]
     }
+
+    fn data_location(&self) -> &Submap {
+        &self.data_location
+    }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_swaps_non_native_byte_order() {
+        let words = OtherType::<u16>::from(Submap::from_bytes(&[0x01, 0x02, 0xFF, 0x00], Endianness::Big).unwrap());
+        assert_eq!(words.value(), &vec![0x0102, 0xFF00]);
+        assert_eq!(words.as_raw_data(), &[0x01, 0x02, 0xFF, 0x00]);
+
+        let floats = OtherType::<f32>::from(Submap::from_bytes(&1.5f32.to_be_bytes(), Endianness::Big).unwrap());
+        assert_eq!(floats.value(), &vec![1.5]);
+
+        let words = OtherType::<u16>::from(Submap::from_bytes(&[0x01, 0x02], Endianness::Little).unwrap());
+        assert_eq!(words.value(), &vec![0x0201]);
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::traits::cast::{Cast, CastError};
+use crate::value_representations::dicom_string::DicomString;
+
+const COMPONENT_GROUP_SEPARATOR: char = '=';
+const COMPONENT_SEPARATOR: char = '^';
+const MAX_COMPONENT_GROUPS: usize = 3;
+const MAX_COMPONENTS: usize = 5;
+
+/// PN value, e.g. `Yamada^Tarou=山田^太郎=やまだ^たろう`, with alphabetic, ideographic and
+/// phonetic component groups. Missing groups and components are empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PersonName {
+    pub alphabetic: NameComponents,
+    pub ideographic: NameComponents,
+    pub phonetic: NameComponents,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NameComponents {
+    pub family_name: String,
+    pub given_name: String,
+    pub middle_name: String,
+    pub name_prefix: String,
+    pub name_suffix: String,
+}
+
+impl NameComponents {
+    pub fn is_empty(&self) -> bool {
+        [&self.family_name, &self.given_name, &self.middle_name, &self.name_prefix, &self.name_suffix].iter()
+            .all(|component| component.is_empty())
+    }
+
+    fn parse(group: &str) -> Option<Self> {
+        let components: Vec<String> = group.split(COMPONENT_SEPARATOR)
+            .map(|component| component.trim().to_string())
+            .collect();
+
+        if components.len() > MAX_COMPONENTS {
+            return None;
+        }
+
+        let mut components = components.into_iter();
+        let mut next = || components.next().unwrap_or_default();
+
+        Some(Self {
+            family_name: next(),
+            given_name: next(),
+            middle_name: next(),
+            name_prefix: next(),
+            name_suffix: next(),
+        })
+    }
+}
+
+impl FromStr for PersonName {
+    type Err = CastError<PersonName>;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let groups: Vec<&str> = text.trim_end_matches([' ', '\0']).split(COMPONENT_GROUP_SEPARATOR).collect();
+        if groups.len() > MAX_COMPONENT_GROUPS {
+            return Err(CastError::default());
+        }
+
+        let group = |index: usize| NameComponents::parse(groups.get(index).copied().unwrap_or_default())
+            .ok_or_else(CastError::default);
+
+        Ok(Self {
+            alphabetic: group(0)?,
+            ideographic: group(1)?,
+            phonetic: group(2)?,
+        })
+    }
+}
+
+impl Cast<PersonName> for DicomString {
+    fn cast(&self) -> Result<PersonName, CastError<PersonName>> {
+        Into::<String>::into(self).parse()
+    }
+}
+
+impl NameComponents {
+    fn write(&self, f: &mut fmt::Formatter, family_name_first: bool) -> fmt::Result {
+        let name = if family_name_first {
+            [&self.name_prefix, &self.family_name, &self.given_name, &self.middle_name]
+        } else {
+            [&self.name_prefix, &self.given_name, &self.middle_name, &self.family_name]
+        };
+
+        let name: Vec<&str> = name.iter()
+            .filter(|component| !component.is_empty())
+            .map(|component| component.as_str())
+            .collect();
+
+        write!(f, "{}", name.join(" "))?;
+        if !self.name_suffix.is_empty() {
+            write!(f, ", {}", self.name_suffix)?;
+        }
+        Ok(())
+    }
+}
+
+/// Western reading order, e.g. `Dr. John Quincy Doe, Jr.`.
+impl fmt::Display for NameComponents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, false)
+    }
+}
+
+/// The first non-empty component group. Ideographic and phonetic names are written family name
+/// first, e.g. `山田 太郎`.
+impl fmt::Display for PersonName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match [&self.ideographic, &self.phonetic].into_iter().find(|components| !components.is_empty()) {
+            Some(components) if self.alphabetic.is_empty() => components.write(f, true),
+            _ => self.alphabetic.write(f, false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let name: PersonName = "Doe^John^Quincy^Dr.^Jr. ".parse().unwrap();
+        assert_eq!(name.alphabetic.family_name, "Doe");
+        assert_eq!(name.alphabetic.name_suffix, "Jr.");
+        assert!(name.ideographic.is_empty());
+        assert_eq!(name.to_string(), "Dr. John Quincy Doe, Jr.");
+
+        let name: PersonName = "=山田^太郎=やまだ^たろう".parse().unwrap();
+        assert!(name.alphabetic.is_empty());
+        assert_eq!(name.phonetic.given_name, "たろう");
+        assert_eq!(name.to_string(), "山田 太郎");
+
+        assert!("A^B^C^D^E^F".parse::<PersonName>().is_err());
+        assert!("A=B=C=D".parse::<PersonName>().is_err());
+    }
+}
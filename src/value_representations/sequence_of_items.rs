@@ -1,6 +1,18 @@
 use crate::dataset::data_element::DataElement;
 
+/// Single item of a sequence - a nested dataset, which may contain further sequences.
+#[derive(Debug)]
+pub struct SequenceItem {
+    pub data_elements: Vec<DataElement>
+}
+
 #[derive(Debug)]
 pub struct SequenceOfItems {
-    pub value: Vec<DataElement>
-}
\ No newline at end of file
+    pub items: Vec<SequenceItem>
+}
+
+impl SequenceOfItems {
+    pub fn new(items: Vec<SequenceItem>) -> Self {
+        SequenceOfItems { items }
+    }
+}
@@ -1,6 +1,29 @@
-use crate::dataset::data_element::DataElement;
+use crate::utils::submap::Submap;
+use crate::value_representations::other_type::{Other, OtherType};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Unknown {
-    pub value: Vec<DataElement>,
-}
\ No newline at end of file
+    value: OtherType<u8>,
+}
+
+impl From<Submap> for Unknown {
+    fn from(value: Submap) -> Self {
+        Self { value: OtherType::from(value) }
+    }
+}
+
+impl Other for Unknown {
+    type Type = u8;
+
+    fn value(&self) -> &Vec<Self::Type> {
+        self.value.value()
+    }
+
+    fn as_raw_data(&self) -> &[u8] {
+        self.value.as_raw_data()
+    }
+
+    fn data_location(&self) -> &Submap {
+        self.value.data_location()
+    }
+}
@@ -0,0 +1,45 @@
+use roxmltree::{Document, Node};
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Returns the body rows of the DocBook table with the given `xml:id`, each row as the text of its
+/// cells. Returns `None` if the document has no such table.
+pub fn table_rows(document: &Document, id: &str) -> Option<Vec<Vec<String>>> {
+    let table = document.descendants()
+        .find(|node| node.has_tag_name("table") && node.attribute((XML_NAMESPACE, "id")) == Some(id))?;
+
+    let rows = table.descendants()
+        .filter(|node| node.has_tag_name("tbody"))
+        .flat_map(|body| body.children().filter(|node| node.has_tag_name("tr")))
+        .map(|row| row.children()
+            .filter(|node| node.has_tag_name("td"))
+            .map(cell_text)
+            .collect())
+        .collect();
+
+    Some(rows)
+}
+
+/// The standard breaks long keywords and UIDs with zero width spaces and wraps cell content in
+/// paragraphs - these are removed and whitespace is collapsed.
+fn cell_text(cell: Node) -> String {
+    let paragraphs: Vec<Node> = cell.children().filter(|node| node.has_tag_name("para")).collect();
+
+    let text = if paragraphs.is_empty() {
+        text_of(cell)
+    } else {
+        paragraphs.into_iter().map(text_of).collect::<Vec<_>>().join(" ")
+    };
+
+    text.replace('\u{200B}', "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn text_of(node: Node) -> String {
+    node.descendants()
+        .filter(|node| node.is_text())
+        .filter_map(|node| node.text())
+        .collect()
+}
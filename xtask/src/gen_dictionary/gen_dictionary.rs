@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use roxmltree::{Document, ParsingOptions};
+
+use super::docbook::table_rows;
+use super::registry::{attributes, sort_attributes, transfer_syntaxes, Attribute, Retirement};
+use super::rust_source;
+
+/// Registries of data elements in PS3.6: data elements, file meta elements, directory structuring
+/// elements and dynamic RTP payload elements.
+const DATA_ELEMENT_TABLES: [&str; 4] = ["table_6-1", "table_7-1", "table_8-1", "table_9-1"];
+const UID_TABLE: &str = "table_A-1";
+const COMMAND_FIELD_TABLE: &str = "table_E.1-1";
+const RETIRED_COMMAND_FIELD_TABLE: &str = "table_E.2-1";
+
+const STANDARD_DICTIONARY_PATH: &str = "src/data_dictionary/standard_dictionary.rs";
+const TAGS_PATH: &str = "src/dicom_constants/tags.rs";
+const TRANSFER_SYNTAXES_PATH: &str = "src/dicom_constants/transfer_syntaxes.rs";
+
+/// Regenerates the dictionary sources of the workspace from `part06.xml` and `part07.xml` in
+/// `docbook_dir`, as published at https://dicom.nema.org/medical/dicom/current/source/docbook/.
+pub fn run(docbook_dir: &Path, workspace_root: &Path) -> Result<(), Box<dyn Error>> {
+    let part06 = fs::read_to_string(docbook_dir.join("part06.xml"))?;
+    let part07 = fs::read_to_string(docbook_dir.join("part07.xml"))?;
+
+    let part06 = parse_document(&part06)?;
+    let part07 = parse_document(&part07)?;
+
+    let mut registry = command_fields(&part07)?;
+    for id in DATA_ELEMENT_TABLES {
+        registry.extend(attributes(&table(&part06, id)?, Retirement::Noted)?);
+    }
+    sort_attributes(&mut registry)?;
+
+    let transfer_syntaxes = transfer_syntaxes(&table(&part06, UID_TABLE)?)?;
+
+    write(workspace_root, STANDARD_DICTIONARY_PATH, rust_source::standard_dictionary(&registry)?)?;
+    write(workspace_root, TAGS_PATH, rust_source::tags(&registry)?)?;
+    write(workspace_root, TRANSFER_SYNTAXES_PATH, rust_source::transfer_syntaxes(&transfer_syntaxes)?)?;
+
+    println!("Generated {} attributes and {} transfer syntaxes", registry.len(), transfer_syntaxes.len());
+
+    Ok(())
+}
+
+fn command_fields(part07: &Document) -> Result<Vec<Attribute>, String> {
+    let mut command_fields = attributes(&table(part07, COMMAND_FIELD_TABLE)?, Retirement::None)?;
+    command_fields.extend(attributes(&table(part07, RETIRED_COMMAND_FIELD_TABLE)?, Retirement::All)?);
+
+    Ok(command_fields)
+}
+
+fn parse_document(text: &str) -> Result<Document<'_>, roxmltree::Error> {
+    // The DocBook sources declare a DTD, which roxmltree rejects by default.
+    let options = ParsingOptions { allow_dtd: true, ..ParsingOptions::default() };
+    Document::parse_with_options(text, options)
+}
+
+fn table(document: &Document, id: &str) -> Result<Vec<Vec<String>>, String> {
+    table_rows(document, id).ok_or_else(|| format!("Table {} not found", id))
+}
+
+fn write(workspace_root: &Path, path: &str, source: String) -> Result<(), Box<dyn Error>> {
+    fs::write(workspace_root.join(path), source)?;
+    println!("Wrote {}", path);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART06: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<book xmlns="http://docbook.org/ns/docbook">
+  <table xml:id="table_6-1">
+    <thead><tr><th><para>Tag</para></th><th><para>Name</para></th><th><para>Keyword</para></th><th><para>VR</para></th><th><para>VM</para></th><th/></tr></thead>
+    <tbody>
+      <tr><td><para>(0010,0010)</para></td><td><para>Patient's Name</para></td><td><para>Patient&#8203;Name</para></td><td><para>PN</para></td><td><para>1</para></td><td/></tr>
+      <tr><td><para>(0028,0040)</para></td><td><para><emphasis role="italic">Image Format</emphasis></para></td><td><para><emphasis role="italic">ImageFormat</emphasis></para></td><td><para>CS</para></td><td><para>1</para></td><td><para>RET</para></td></tr>
+      <tr><td><para>(0020,31xx)</para></td><td><para>Source Image IDs</para></td><td><para>SourceImageIDs</para></td><td><para>CS</para></td><td><para>1-n</para></td><td><para>RET</para></td></tr>
+      <tr><td><para>(0028,0106)</para></td><td><para>Smallest Image Pixel Value</para></td><td><para>SmallestImagePixelValue</para></td><td><para>US or SS</para></td><td><para>1</para></td><td/></tr>
+      <tr><td><para>(0028,04x0)</para></td><td><para>Rows For Nth Order Coefficients</para></td><td><para>RowsForNthOrderCoefficients</para></td><td><para>US</para></td><td><para>1</para></td><td><para>RET</para></td></tr>
+      <tr><td><para>(60xx,3000)</para></td><td><para>Overlay Data</para></td><td><para>OverlayData</para></td><td><para>OB or OW</para></td><td><para>1</para></td><td/></tr>
+      <tr><td><para>(FFFE,E000)</para></td><td><para>Item</para></td><td><para>Item</para></td><td><para>See Note 2</para></td><td><para>1</para></td><td/></tr>
+    </tbody>
+  </table>
+  <table xml:id="table_A-1">
+    <tbody>
+      <tr><td><para>1.2.840.10008.1.2</para></td><td><para>Implicit VR Little Endian</para></td><td><para>ImplicitVRLittleEndian</para></td><td><para>Transfer Syntax</para></td><td><para>PS3.5</para></td></tr>
+      <tr><td><para>1.2.840.10008.1.1</para></td><td><para>Verification SOP Class</para></td><td><para>Verification</para></td><td><para>SOP Class</para></td><td><para>PS3.4</para></td></tr>
+    </tbody>
+  </table>
+</book>"#;
+
+    #[test]
+    fn test_read_registry() {
+        let document = parse_document(PART06).unwrap();
+
+        let mut registry = attributes(&table(&document, "table_6-1").unwrap(), Retirement::Noted).unwrap();
+        sort_attributes(&mut registry).unwrap();
+
+        let keywords: Vec<&str> = registry.iter().map(|attribute| attribute.keyword.as_str()).collect();
+        assert_eq!(keywords, ["PatientName", "ImageFormat", "SmallestImagePixelValue", "Item", "OverlayData", "SourceImageIDs", "RowsForNthOrderCoefficients"]);
+
+        assert_eq!(registry[0].name, "Patient's Name");
+        assert!(!registry[0].retired);
+        assert!(registry[1].retired);
+        assert_eq!(registry[2].vr, ["US", "SS"]);
+        assert!(registry[3].vr.is_empty());
+        assert_eq!(registry[4].group, 0x6000);
+        assert_eq!(registry[4].vr, ["OB", "OW"]);
+        assert_eq!((registry[5].element, registry[5].element_mask), (0x3100, 0xFF00));
+        assert_eq!((registry[6].element, registry[6].element_mask), (0x0400, 0xFF0F));
+
+        let dictionary = rust_source::standard_dictionary(&registry).unwrap();
+        assert!(dictionary.contains(r#"DictionaryEntry { tag: Tag { group: 0x6000, element: 0x3000 }, keyword: "OverlayData", name: "Overlay Data", vr: &[OTHER_BYTE, OTHER_WORD], vm: "1", retired: false },"#));
+        assert!(dictionary.contains(r#"(0xFF0F, DictionaryEntry { tag: Tag { group: 0x0028, element: 0x0400 }, keyword: "RowsForNthOrderCoefficients", name: "Rows For Nth Order Coefficients", vr: &[UNSIGNED_SHORT], vm: "1", retired: true }),"#));
+
+        let tags = rust_source::tags(&registry).unwrap();
+        assert!(tags.contains("pub const PATIENT_NAME: Tag = Tag { group: 0x0010, element: 0x0010 };"));
+
+        let transfer_syntaxes = transfer_syntaxes(&table(&document, UID_TABLE).unwrap()).unwrap();
+        assert!(rust_source::transfer_syntaxes(&transfer_syntaxes).unwrap()
+            .contains(r#"pub const IMPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2";"#));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod gen_dictionary;
+mod docbook;
+mod registry;
+mod rust_source;
@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+/// A data element of the registry. For repeating groups (50xx, 60xx, 7Fxx) the low byte of the
+/// group is cleared. For ranges of elements (e.g. `(0020,31xx)`) the masked digits of the element
+/// are cleared and `element_mask` keeps the others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attribute {
+    pub group: u16,
+    pub element: u16,
+    pub repeating_group: bool,
+    pub element_mask: u16,
+    pub keyword: String,
+    pub name: String,
+    pub vr: Vec<String>,
+    pub vm: String,
+    pub retired: bool,
+}
+
+/// Where a registry table tells whether its attributes are retired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Retirement {
+    /// The column after VM starts with `RET` for retired attributes.
+    Noted,
+    /// The table lists only retired attributes.
+    All,
+    /// The table lists only attributes in use.
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferSyntax {
+    pub uid: String,
+    pub keyword: String,
+}
+
+/// Layout of the data element registries: Tag, Name, Keyword, VR, VM and optionally a note or
+/// description. Rows having no keyword are skipped. The VR may list alternatives (`US or SS`) or
+/// refer to a note (`See Note 2`) for elements without a VR, like Item.
+pub fn attributes(rows: &[Vec<String>], retirement: Retirement) -> Result<Vec<Attribute>, String> {
+    let mut attributes = Vec::new();
+
+    for row in rows {
+        let [tag, name, keyword, vr, vm, rest @ ..] = row.as_slice() else {
+            return Err(format!("Unexpected data element row: {:?}", row));
+        };
+
+        if keyword.is_empty() {
+            continue;
+        }
+
+        let (group, element, repeating_group, element_mask) = parse_tag(tag)?;
+
+        let vr = if vr.is_empty() || vr.starts_with("See Note") {
+            Vec::new()
+        } else {
+            vr.split(" or ").map(|vr| vr.trim().to_string()).collect()
+        };
+
+        let retired = match retirement {
+            Retirement::Noted => rest.first().is_some_and(|note| note.starts_with("RET")),
+            Retirement::All => true,
+            Retirement::None => false,
+        };
+
+        attributes.push(Attribute {
+            group,
+            element,
+            repeating_group,
+            element_mask,
+            keyword: keyword.clone(),
+            name: name.clone(),
+            vr,
+            vm: vm.clone(),
+            retired,
+        });
+    }
+
+    Ok(attributes)
+}
+
+/// Layout of the UID registry: UID Value, UID Name, UID Keyword, UID Type and Part.
+pub fn transfer_syntaxes(rows: &[Vec<String>]) -> Result<Vec<TransferSyntax>, String> {
+    let mut transfer_syntaxes = Vec::new();
+
+    for row in rows {
+        let [uid, _name, keyword, uid_type, ..] = row.as_slice() else {
+            return Err(format!("Unexpected UID row: {:?}", row));
+        };
+
+        if uid_type == "Transfer Syntax" {
+            transfer_syntaxes.push(TransferSyntax { uid: uid.clone(), keyword: keyword.clone() });
+        }
+    }
+
+    Ok(transfer_syntaxes)
+}
+
+/// Sorts the attributes by tag, with repeating groups and then ranges of elements at the end, and
+/// checks the tags and keywords are unique.
+pub fn sort_attributes(attributes: &mut [Attribute]) -> Result<(), String> {
+    attributes.sort_by_key(|attribute| (attribute.element_mask != 0xFFFF, attribute.repeating_group, attribute.group, attribute.element));
+
+    let mut tags = HashSet::new();
+    let mut keywords = HashSet::new();
+
+    for attribute in attributes.iter() {
+        if !tags.insert((attribute.repeating_group, attribute.element_mask, attribute.group, attribute.element)) {
+            return Err(format!("Duplicated tag ({:04X},{:04X})", attribute.group, attribute.element));
+        }
+
+        if !keywords.insert(attribute.keyword.as_str()) {
+            return Err(format!("Duplicated keyword {}", attribute.keyword));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses `(GGGG,EEEE)` into the group, element, whether the group is a repeating one and the mask
+/// of the element digits given, e.g. `(0028,04x0)` gives the element 0x0400 with the mask 0xFF0F.
+fn parse_tag(tag: &str) -> Result<(u16, u16, bool, u16), String> {
+    let invalid = || format!("Invalid tag {}", tag);
+
+    let (group, element) = tag.strip_prefix('(')
+        .and_then(|tag| tag.strip_suffix(')'))
+        .and_then(|tag| tag.split_once(','))
+        .ok_or_else(invalid)?;
+
+    if group.len() != 4 || element.len() != 4 {
+        return Err(invalid());
+    }
+
+    let (group, repeating_group) = match group.strip_suffix("xx") {
+        Some(high_byte) => (format!("{}00", high_byte), true),
+        None => (group.to_string(), false),
+    };
+
+    if repeating_group && element.contains('x') {
+        return Err(invalid());
+    }
+
+    let element_mask = element.chars()
+        .fold(0, |mask, digit| mask << 4 | if digit == 'x' { 0x0 } else { 0xF });
+
+    let group = u16::from_str_radix(&group, 16).map_err(|_| invalid())?;
+    let element = u16::from_str_radix(&element.replace('x', "0"), 16).map_err(|_| invalid())?;
+
+    Ok((group, element, repeating_group, element_mask))
+}
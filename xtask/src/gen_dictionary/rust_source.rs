@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use super::registry::{Attribute, TransferSyntax};
+
+const HEADER: &str = "// Generated by `cargo xtask gen-dictionary` from the DICOM standard (PS3.6, PS3.7). Do not edit.";
+
+/// Names the transfer syntax constants had before they were generated, with their UID. They are
+/// kept as deprecated aliases of the generated constants.
+const RENAMED_TRANSFER_SYNTAXES: [(&str, &str); 16] = [
+    ("JPEG_BASELINE", "1.2.840.10008.1.2.4.50"),
+    ("JPEG_BASELINE_PROCESS2_4", "1.2.840.10008.1.2.4.51"),
+    ("JPEG_LS_LOSSLESS", "1.2.840.10008.1.2.4.80"),
+    ("JPEG_LS_LOSSY", "1.2.840.10008.1.2.4.81"),
+    ("JPEG2000_LOSSY", "1.2.840.10008.1.2.4.91"),
+    ("JPEG2000_PART2_LOSSLESS", "1.2.840.10008.1.2.4.92"),
+    ("JPEG2000_PART2_LOSSY", "1.2.840.10008.1.2.4.93"),
+    ("MPEG2_MAIN_PROFILE_MAIN_LEVEL", "1.2.840.10008.1.2.4.100"),
+    ("MPEG2_MAIN_PROFILE_HIGH_LEVEL", "1.2.840.10008.1.2.4.101"),
+    ("MPEG4_AVC_H264_HIGH_PROFILE_LEVEL_41", "1.2.840.10008.1.2.4.102"),
+    ("MPEG4_AVC_H264_BD_COMPATIBLE_HIGH_PROFILE_LEVEL_41", "1.2.840.10008.1.2.4.103"),
+    ("MPEG4_AVC_H264_HIGH_PROFILE_LEVEL_42_FOR_2D_VIDEO", "1.2.840.10008.1.2.4.104"),
+    ("MPEG4_AVC_H264_HIGH_PROFILE_LEVEL_42_FOR_3D_VIDEO", "1.2.840.10008.1.2.4.105"),
+    ("MPEG4_AVC_H264_STEREO_HIGH_PROFILE_LEVEL_42", "1.2.840.10008.1.2.4.106"),
+    ("HEVC_H265_MAIN_PROFILE_LEVEL_51", "1.2.840.10008.1.2.4.107"),
+    ("HEVC_H265_MAIN_10_PROFILE_LEVEL_51", "1.2.840.10008.1.2.4.108"),
+];
+
+/// Names of the constants in `dicom_constants::value_representation`.
+const VALUE_REPRESENTATIONS: [(&str, &str); 34] = [
+    ("AE", "APPLICATION_ENTITY"),
+    ("AS", "AGE_STRING"),
+    ("AT", "ATTRIBUTE_TAG"),
+    ("CS", "CODE_STRING"),
+    ("DA", "DATE"),
+    ("DS", "DECIMAL_STRING"),
+    ("DT", "DATE_TIME"),
+    ("FL", "FLOATING_POINT_SINGLE"),
+    ("FD", "FLOATING_POINT_DOUBLE"),
+    ("IS", "INTEGER_STRING"),
+    ("LO", "LONG_STRING"),
+    ("LT", "LONG_TEXT"),
+    ("OB", "OTHER_BYTE"),
+    ("OD", "OTHER_DOUBLE"),
+    ("OF", "OTHER_FLOAT"),
+    ("OL", "OTHER_LONG"),
+    ("OV", "OTHER_64_BIT_VERY_LONG"),
+    ("OW", "OTHER_WORD"),
+    ("PN", "PERSON_NAME"),
+    ("SH", "SHORT_STRING"),
+    ("SL", "SIGNED_LONG"),
+    ("SQ", "SEQUENCE_OF_ITEMS"),
+    ("SS", "SIGNED_SHORT"),
+    ("ST", "SHORT_TEXT"),
+    ("SV", "SIGNED_64_BIT_VERY_LONG"),
+    ("TM", "TIME"),
+    ("UC", "UNLIMITED_CHARACTER"),
+    ("UI", "UNIQUE_IDENTIFIER_UID"),
+    ("UL", "UNSIGNED_LONG"),
+    ("UN", "UNKNOWN"),
+    ("UR", "UNIVERSAL_RESOURCE"),
+    ("US", "UNSIGNED_SHORT"),
+    ("UT", "UNLIMITED_TEXT"),
+    ("UV", "UNSIGNED_64_BIT_VERY_LONG"),
+];
+
+/// Source of `data_dictionary/standard_dictionary.rs`.
+pub fn standard_dictionary(attributes: &[Attribute]) -> Result<String, String> {
+    let mut source = String::new();
+
+    writeln!(source, "{}", HEADER).unwrap();
+    writeln!(source, "use crate::dataset::tag::Tag;").unwrap();
+    writeln!(source, "use crate::dicom_constants::value_representation::*;").unwrap();
+    writeln!(source, "use super::data_dictionary::DictionaryEntry;").unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "pub(super) const STANDARD_DICTIONARY: &[DictionaryEntry] = &[").unwrap();
+    for attribute in attributes.iter().filter(|attribute| !attribute.repeating_group && attribute.element_mask == 0xFFFF) {
+        writeln!(source, "{}", dictionary_entry(attribute)?).unwrap();
+    }
+    writeln!(source, "];").unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "/// Entries of repeating groups (50xx curves, 60xx overlays, 7Fxx variable pixel data),").unwrap();
+    writeln!(source, "/// stored with the low byte of the group cleared.").unwrap();
+    writeln!(source, "pub(super) const REPEATING_GROUPS_DICTIONARY: &[DictionaryEntry] = &[").unwrap();
+    for attribute in attributes.iter().filter(|attribute| attribute.repeating_group) {
+        writeln!(source, "{}", dictionary_entry(attribute)?).unwrap();
+    }
+    writeln!(source, "];").unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "/// Entries of ranges of elements (e.g. (0020,31xx) Source Image IDs) with the mask of the").unwrap();
+    writeln!(source, "/// element digits they match, stored with the masked digits cleared.").unwrap();
+    writeln!(source, "pub(super) const MASKED_ELEMENTS_DICTIONARY: &[(u16, DictionaryEntry)] = &[").unwrap();
+    for attribute in attributes.iter().filter(|attribute| attribute.element_mask != 0xFFFF) {
+        let entry = dictionary_entry(attribute)?;
+        writeln!(source, "    (0x{:04X}, {}),", attribute.element_mask, entry.trim_start().trim_end_matches(',')).unwrap();
+    }
+    writeln!(source, "];").unwrap();
+
+    Ok(source)
+}
+
+/// Source of `dicom_constants/tags.rs` - a constant named after the keyword of every attribute.
+pub fn tags(attributes: &[Attribute]) -> Result<String, String> {
+    let mut source = String::new();
+
+    writeln!(source, "{}", HEADER).unwrap();
+    writeln!(source, "use crate::dataset::tag::Tag;").unwrap();
+    writeln!(source).unwrap();
+
+    let mut names = HashSet::new();
+    for attribute in attributes {
+        let name = constant_name(&attribute.keyword);
+        if !names.insert(name.clone()) {
+            return Err(format!("Keyword {} gives a duplicated constant name {}", attribute.keyword, name));
+        }
+
+        writeln!(source, "pub const {}: Tag = Tag {{ group: 0x{:04X}, element: 0x{:04X} }};",
+                 name, attribute.group, attribute.element).unwrap();
+    }
+
+    Ok(source)
+}
+
+/// Source of `dicom_constants/transfer_syntaxes.rs`.
+pub fn transfer_syntaxes(transfer_syntaxes: &[TransferSyntax]) -> Result<String, String> {
+    let mut source = String::new();
+
+    writeln!(source, "{}", HEADER).unwrap();
+
+    let mut names = HashSet::new();
+    for transfer_syntax in transfer_syntaxes {
+        let name = constant_name(&transfer_syntax.keyword);
+        if !names.insert(name.clone()) {
+            return Err(format!("Keyword {} gives a duplicated constant name {}", transfer_syntax.keyword, name));
+        }
+
+        writeln!(source, "pub const {}: &str = {:?};", name, transfer_syntax.uid).unwrap();
+    }
+
+    writeln!(source, "pub const TRANSFER_SYNTAXES: [&str; {}] = [", transfer_syntaxes.len()).unwrap();
+    for transfer_syntax in transfer_syntaxes {
+        writeln!(source, "    {},", constant_name(&transfer_syntax.keyword)).unwrap();
+    }
+    writeln!(source, "];").unwrap();
+
+    for (old_name, uid) in RENAMED_TRANSFER_SYNTAXES {
+        let Some(transfer_syntax) = transfer_syntaxes.iter().find(|transfer_syntax| transfer_syntax.uid == uid) else {
+            continue;
+        };
+        let name = constant_name(&transfer_syntax.keyword);
+
+        writeln!(source, "#[deprecated(note = \"renamed to {}\")]", name).unwrap();
+        writeln!(source, "pub const {}: &str = {};", old_name, name).unwrap();
+    }
+
+    Ok(source)
+}
+
+fn dictionary_entry(attribute: &Attribute) -> Result<String, String> {
+    let vr = attribute.vr.iter()
+        .map(|vr| VALUE_REPRESENTATIONS.iter()
+            .find(|(value, _)| value == vr)
+            .map(|(_, name)| *name)
+            .ok_or_else(|| format!("Unknown VR {} of {}", vr, attribute.keyword)))
+        .collect::<Result<Vec<_>, _>>()?
+        .join(", ");
+
+    Ok(format!("    DictionaryEntry {{ tag: Tag {{ group: 0x{:04X}, element: 0x{:04X} }}, keyword: {:?}, name: {:?}, vr: &[{}], vm: {:?}, retired: {} }},",
+               attribute.group, attribute.element, attribute.keyword, attribute.name, vr, attribute.vm, attribute.retired))
+}
+
+/// Converts a keyword to SCREAMING_SNAKE_CASE, keeping acronyms and digits attached to the
+/// preceding word: `SOPInstanceUID` -> `SOP_INSTANCE_UID`, `XRay3DFrameTypeSequence` ->
+/// `X_RAY_3D_FRAME_TYPE_SEQUENCE`, `JPEG2000Lossless` -> `JPEG2000_LOSSLESS`.
+fn constant_name(keyword: &str) -> String {
+    let chars: Vec<char> = keyword.chars().collect();
+    let mut name = String::with_capacity(keyword.len() + 8);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|next| next.is_ascii_lowercase());
+            // The plural of an acronym, e.g. `IDs`, stays one word
+            let next_is_plural = chars.get(i + 1) == Some(&'s')
+                && chars.get(i + 2).is_none_or(|after| !after.is_ascii_lowercase());
+
+            let word_start = if c.is_ascii_uppercase() {
+                previous.is_ascii_lowercase() || (next_is_lowercase && !(previous.is_ascii_uppercase() && next_is_plural))
+            } else {
+                c.is_ascii_digit() && previous.is_ascii_lowercase()
+            };
+
+            if word_start {
+                name.push('_');
+            }
+        }
+
+        name.push(c.to_ascii_uppercase());
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_name() {
+        assert_eq!(constant_name("PatientName"), "PATIENT_NAME");
+        assert_eq!(constant_name("SOPInstanceUID"), "SOP_INSTANCE_UID");
+        assert_eq!(constant_name("XRay3DFrameTypeSequence"), "X_RAY_3D_FRAME_TYPE_SEQUENCE");
+        assert_eq!(constant_name("JPEGBaseline8Bit"), "JPEG_BASELINE_8_BIT");
+        assert_eq!(constant_name("JPEG2000Lossless"), "JPEG2000_LOSSLESS");
+        assert_eq!(constant_name("ImplicitVRLittleEndian"), "IMPLICIT_VR_LITTLE_ENDIAN");
+    }
+
+    #[test]
+    fn test_renamed_transfer_syntaxes() {
+        let transfer_syntax = |uid: &str, keyword: &str| TransferSyntax { uid: uid.to_string(), keyword: keyword.to_string() };
+        let source = transfer_syntaxes(&[
+            transfer_syntax("1.2.840.10008.1.2.4.50", "JPEGBaseline8Bit"),
+            transfer_syntax("1.2.840.10008.1.2.4.91", "JPEG2000"),
+        ]).unwrap();
+
+        assert!(source.contains("#[deprecated(note = \"renamed to JPEG_BASELINE_8_BIT\")]\npub const JPEG_BASELINE: &str = JPEG_BASELINE_8_BIT;"));
+        assert!(source.contains("pub const JPEG2000_LOSSY: &str = JPEG2000;"));
+        assert!(!source.contains("JPEG_LS_LOSSLESS"));
+        assert!(source.contains("pub const TRANSFER_SYNTAXES: [&str; 2] = [\n    JPEG_BASELINE_8_BIT,\n    JPEG2000,\n];"));
+    }
+
+    #[test]
+    fn test_constant_name_of_plural_acronyms() {
+        assert_eq!(constant_name("OtherPatientIDs"), "OTHER_PATIENT_IDS");
+        assert_eq!(constant_name("OtherPatientIDsSequence"), "OTHER_PATIENT_IDS_SEQUENCE");
+        assert_eq!(constant_name("FailedSOPInstanceUIDList"), "FAILED_SOP_INSTANCE_UID_LIST");
+        assert_eq!(constant_name("ReferencedFrameOfReferenceUIDs"), "REFERENCED_FRAME_OF_REFERENCE_UIDS");
+        assert_eq!(constant_name("RTBeamLimitingDeviceSequence"), "RT_BEAM_LIMITING_DEVICE_SEQUENCE");
+    }
+}
@@ -1,3 +1,39 @@
-fn main() {
-    println!("Hello, world!");
+mod gen_dictionary;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: cargo xtask <command>
+
+Commands:
+  gen-dictionary <docbook-dir>  Regenerate the tag dictionary, tag constants and transfer syntaxes
+                                from part06.xml and part07.xml of the DICOM standard DocBook sources";
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask should be a member of the workspace")
+        .to_path_buf()
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["gen-dictionary", docbook_dir] =>
+            gen_dictionary::gen_dictionary::run(Path::new(docbook_dir), &workspace_root()),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }